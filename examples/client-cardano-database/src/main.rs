@@ -193,6 +193,13 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                 }
                 *ancillary_download_pb = None;
             }
+            MithrilEvent::SnapshotFileDownloadedFromLocation {
+                download_id: _,
+                file_name,
+                location,
+            } => {
+                println!("Downloaded snapshot '{file_name}' from '{location}'");
+            }
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id: _,
             } => {
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use slog::Logger;
 use std::{fmt::Display, path::PathBuf, sync::Arc};
 use thiserror::Error;
 
@@ -8,7 +9,11 @@ use mithril_common::entities::CardanoNetwork;
 use crate::chain_observer::ChainObserver;
 use crate::test::double::FakeChainObserver;
 
-use super::{CardanoCliChainObserver, CardanoCliRunner, PallasChainObserver};
+use super::{
+    CachedChainObserver, CardanoCliChainObserver, CardanoCliRunner,
+    ChainObserverCacheMetricsRecorder, ChainObserverRetryPolicy, OgmiosChainObserver,
+    PallasChainObserver,
+};
 
 /// Type of chain observers available
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -19,6 +24,8 @@ pub enum ChainObserverType {
     CardanoCli,
     /// Pallas chain observer.
     Pallas,
+    /// Ogmios chain observer.
+    Ogmios,
     /// `TEST ONLY` Fake chain observer
     Fake,
 }
@@ -28,6 +35,7 @@ impl Display for ChainObserverType {
         match self {
             Self::CardanoCli => write!(f, "cardano-cli"),
             Self::Pallas => write!(f, "pallas"),
+            Self::Ogmios => write!(f, "ogmios"),
             Self::Fake => write!(f, "fake"),
         }
     }
@@ -39,6 +47,10 @@ pub enum ChainObserverBuilderError {
     /// Missing cardano cli runner error.
     #[error("cardano cli runner is missing")]
     MissingCardanoCliRunner,
+
+    /// Missing Ogmios endpoint error.
+    #[error("ogmios endpoint is missing")]
+    MissingOgmiosEndpoint,
 }
 
 /// Chain observer builder
@@ -47,6 +59,14 @@ pub struct ChainObserverBuilder {
     cardano_node_socket_path: PathBuf,
     cardano_network: CardanoNetwork,
     cardano_cli_runner: Option<Box<CardanoCliRunner>>,
+    ogmios_endpoint: Option<String>,
+    cache_and_retry: Option<CacheAndRetryParameters>,
+}
+
+struct CacheAndRetryParameters {
+    retry_policy: ChainObserverRetryPolicy,
+    metrics_recorder: Option<Arc<dyn ChainObserverCacheMetricsRecorder>>,
+    logger: Logger,
 }
 
 impl ChainObserverBuilder {
@@ -62,11 +82,55 @@ impl ChainObserverBuilder {
             cardano_node_socket_path: cardano_node_socket_path.to_owned(),
             cardano_network: cardano_node_network.to_owned(),
             cardano_cli_runner: cardano_cli_runner.map(|c| c.to_owned().into()),
+            ogmios_endpoint: None,
+            cache_and_retry: None,
         }
     }
 
+    /// Set the Ogmios endpoint, required by the [Ogmios][ChainObserverType::Ogmios] chain observer
+    pub fn with_ogmios_endpoint(mut self, ogmios_endpoint: Option<String>) -> Self {
+        self.ogmios_endpoint = ogmios_endpoint;
+        self
+    }
+
+    /// Decorate the built chain observer with a [CachedChainObserver] that caches the
+    /// epoch-scoped results and retries the failed queries
+    pub fn with_cache_and_retry(
+        mut self,
+        retry_policy: ChainObserverRetryPolicy,
+        metrics_recorder: Option<Arc<dyn ChainObserverCacheMetricsRecorder>>,
+        logger: Logger,
+    ) -> Self {
+        self.cache_and_retry = Some(CacheAndRetryParameters {
+            retry_policy,
+            metrics_recorder,
+            logger,
+        });
+        self
+    }
+
     /// Create chain observer
     pub fn build(&self) -> StdResult<Arc<dyn ChainObserver>> {
+        let chain_observer = self.build_chain_observer()?;
+
+        match &self.cache_and_retry {
+            Some(parameters) => {
+                let mut cached_observer = CachedChainObserver::new(
+                    chain_observer,
+                    parameters.retry_policy.clone(),
+                    parameters.logger.clone(),
+                );
+                if let Some(metrics_recorder) = &parameters.metrics_recorder {
+                    cached_observer =
+                        cached_observer.with_metrics_recorder(metrics_recorder.clone());
+                }
+                Ok(Arc::new(cached_observer))
+            }
+            None => Ok(chain_observer),
+        }
+    }
+
+    fn build_chain_observer(&self) -> StdResult<Arc<dyn ChainObserver>> {
         match self.chain_observer_type {
             ChainObserverType::CardanoCli => Ok(Arc::new(CardanoCliChainObserver::new(
                 self.cardano_cli_runner
@@ -79,6 +143,14 @@ impl ChainObserverBuilder {
                     PallasChainObserver::new(&self.cardano_node_socket_path, self.cardano_network);
                 Ok(Arc::new(observer))
             }
+            ChainObserverType::Ogmios => {
+                let observer = OgmiosChainObserver::new(
+                    self.ogmios_endpoint
+                        .as_ref()
+                        .ok_or(ChainObserverBuilderError::MissingOgmiosEndpoint)?,
+                );
+                Ok(Arc::new(observer))
+            }
             ChainObserverType::Fake => Ok(Arc::new(FakeChainObserver::default())),
         }
     }
@@ -0,0 +1,498 @@
+use async_trait::async_trait;
+use slog::{Logger, debug, warn};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use mithril_common::crypto_helper::{KesPeriod, OpCert};
+use mithril_common::entities::{ChainPoint, Epoch, StakeDistribution};
+use mithril_common::logging::LoggerExtensions;
+
+use crate::entities::{ChainAddress, TxDatum};
+
+use super::{ChainObserver, ChainObserverError};
+
+const ERA_QUERY: &str = "era";
+const STAKE_DISTRIBUTION_QUERY: &str = "stake_distribution";
+
+/// Policy for retrying the queries of a [CachedChainObserver]
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChainObserverRetryPolicy {
+    /// Number of attempts of a query
+    pub attempts: u8,
+    /// Delay before the first retry, doubled after each failed attempt
+    pub initial_delay_between_attempts: Duration,
+}
+
+impl ChainObserverRetryPolicy {
+    /// Create a policy that never retries
+    pub fn never() -> Self {
+        Self {
+            attempts: 1,
+            initial_delay_between_attempts: Duration::from_secs(0),
+        }
+    }
+}
+
+impl Default for ChainObserverRetryPolicy {
+    /// Create a default retry policy
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            initial_delay_between_attempts: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Records the cache hits and misses of a [CachedChainObserver]
+pub trait ChainObserverCacheMetricsRecorder: Sync + Send {
+    /// Record a cache hit for the given query
+    fn record_cache_hit(&self, query: &str);
+
+    /// Record a cache miss for the given query
+    fn record_cache_miss(&self, query: &str);
+}
+
+/// Value cached until the epoch of the chain changes
+type EpochScopedValue<T> = RwLock<Option<(Epoch, T)>>;
+
+/// A decorator of [ChainObserver] that:
+/// - caches the epoch-scoped results (era and stake distribution) until a call to
+///   [get_current_epoch][ChainObserver::get_current_epoch] observes a new epoch, which the nodes
+///   do at each cycle of their runtime,
+/// - retries the queries that failed with a [general error][ChainObserverError::General], with an
+///   exponential backoff.
+///
+/// The KES period is not cached since it evolves independently of the epochs.
+pub struct CachedChainObserver {
+    observer: Arc<dyn ChainObserver>,
+    retry_policy: ChainObserverRetryPolicy,
+    metrics_recorder: Option<Arc<dyn ChainObserverCacheMetricsRecorder>>,
+    observed_epoch: RwLock<Option<Epoch>>,
+    era: EpochScopedValue<Option<String>>,
+    stake_distribution: EpochScopedValue<Option<StakeDistribution>>,
+    logger: Logger,
+}
+
+impl CachedChainObserver {
+    /// Creates a new [CachedChainObserver]
+    pub fn new(
+        observer: Arc<dyn ChainObserver>,
+        retry_policy: ChainObserverRetryPolicy,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            observer,
+            retry_policy,
+            metrics_recorder: None,
+            observed_epoch: RwLock::new(None),
+            era: RwLock::new(None),
+            stake_distribution: RwLock::new(None),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Set the recorder of the cache hits and misses
+    pub fn with_metrics_recorder(
+        mut self,
+        metrics_recorder: Arc<dyn ChainObserverCacheMetricsRecorder>,
+    ) -> Self {
+        self.metrics_recorder = Some(metrics_recorder);
+        self
+    }
+
+    async fn with_retry<T, F, Fut>(&self, query: &str, fetch: F) -> Result<T, ChainObserverError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, ChainObserverError>>,
+    {
+        let mut nb_attempts = 0;
+        let mut delay = self.retry_policy.initial_delay_between_attempts;
+        loop {
+            nb_attempts += 1;
+
+            match fetch().await {
+                Err(ChainObserverError::General(error))
+                    if nb_attempts < self.retry_policy.attempts =>
+                {
+                    warn!(
+                        self.logger, "Query '{query}' failed, retrying in {delay:?}";
+                        "attempt" => nb_attempts, "error" => ?error
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn get_or_fetch<T, F, Fut>(
+        &self,
+        query: &str,
+        cache: &EpochScopedValue<T>,
+        fetch: F,
+    ) -> Result<T, ChainObserverError>
+    where
+        T: Clone,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, ChainObserverError>>,
+    {
+        // The epoch is only queried when none was observed yet so that cached reads don't reach
+        // the chain
+        let observed_epoch = *self.observed_epoch.read().await;
+        let current_epoch = match observed_epoch {
+            Some(epoch) => epoch,
+            None => match self.get_current_epoch().await? {
+                Some(epoch) => epoch,
+                None => return self.with_retry(query, fetch).await,
+            },
+        };
+
+        if let Some((_, value)) = cache
+            .read()
+            .await
+            .as_ref()
+            .filter(|(epoch, _)| *epoch == current_epoch)
+        {
+            self.record_cache_hit(query);
+            return Ok(value.clone());
+        }
+
+        self.record_cache_miss(query);
+        let value = self.with_retry(query, fetch).await?;
+        debug!(
+            self.logger, "Caching '{query}' result";
+            "epoch" => ?current_epoch
+        );
+        *cache.write().await = Some((current_epoch, value.clone()));
+
+        Ok(value)
+    }
+
+    fn record_cache_hit(&self, query: &str) {
+        if let Some(recorder) = &self.metrics_recorder {
+            recorder.record_cache_hit(query);
+        }
+    }
+
+    fn record_cache_miss(&self, query: &str) {
+        if let Some(recorder) = &self.metrics_recorder {
+            recorder.record_cache_miss(query);
+        }
+    }
+}
+
+#[async_trait]
+impl ChainObserver for CachedChainObserver {
+    async fn get_current_datums(
+        &self,
+        address: &ChainAddress,
+    ) -> Result<Vec<TxDatum>, ChainObserverError> {
+        self.with_retry("datums", || self.observer.get_current_datums(address))
+            .await
+    }
+
+    async fn get_current_era(&self) -> Result<Option<String>, ChainObserverError> {
+        self.get_or_fetch(ERA_QUERY, &self.era, || self.observer.get_current_era())
+            .await
+    }
+
+    async fn get_current_epoch(&self) -> Result<Option<Epoch>, ChainObserverError> {
+        let epoch = self.with_retry("epoch", || self.observer.get_current_epoch()).await?;
+        if epoch.is_some() {
+            *self.observed_epoch.write().await = epoch;
+        }
+
+        Ok(epoch)
+    }
+
+    async fn get_current_chain_point(&self) -> Result<Option<ChainPoint>, ChainObserverError> {
+        self.with_retry("chain_point", || self.observer.get_current_chain_point())
+            .await
+    }
+
+    async fn get_current_stake_distribution(
+        &self,
+    ) -> Result<Option<StakeDistribution>, ChainObserverError> {
+        self.get_or_fetch(STAKE_DISTRIBUTION_QUERY, &self.stake_distribution, || {
+            self.observer.get_current_stake_distribution()
+        })
+        .await
+    }
+
+    async fn get_current_kes_period(
+        &self,
+        opcert: &OpCert,
+    ) -> Result<Option<KesPeriod>, ChainObserverError> {
+        self.with_retry("kes_period", || {
+            self.observer.get_current_kes_period(opcert)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use kes_summed_ed25519::{kes::Sum6Kes, traits::KesSk};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use mithril_common::crypto_helper::ColdKeyGenerator;
+    use mithril_common::test_utils::double::Dummy;
+
+    use crate::chain_observer::MockChainObserver;
+    use crate::test::TestLogger;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct TestMetricsRecorder {
+        hits: Mutex<HashMap<String, u32>>,
+        misses: Mutex<HashMap<String, u32>>,
+    }
+
+    impl TestMetricsRecorder {
+        fn hits(&self, query: &str) -> u32 {
+            self.hits.lock().unwrap().get(query).copied().unwrap_or_default()
+        }
+
+        fn misses(&self, query: &str) -> u32 {
+            self.misses.lock().unwrap().get(query).copied().unwrap_or_default()
+        }
+    }
+
+    impl ChainObserverCacheMetricsRecorder for TestMetricsRecorder {
+        fn record_cache_hit(&self, query: &str) {
+            *self.hits.lock().unwrap().entry(query.to_string()).or_default() += 1;
+        }
+
+        fn record_cache_miss(&self, query: &str) {
+            *self.misses.lock().unwrap().entry(query.to_string()).or_default() += 1;
+        }
+    }
+
+    fn fast_retry_policy(attempts: u8) -> ChainObserverRetryPolicy {
+        ChainObserverRetryPolicy {
+            attempts,
+            initial_delay_between_attempts: Duration::from_millis(1),
+        }
+    }
+
+    fn fake_stake_distribution() -> StakeDistribution {
+        StakeDistribution::from([("pool1".to_string(), 100), ("pool2".to_string(), 200)])
+    }
+
+    #[tokio::test]
+    async fn stake_distribution_is_cached_while_the_epoch_does_not_change() {
+        let mut observer = MockChainObserver::new();
+        observer
+            .expect_get_current_epoch()
+            .returning(|| Ok(Some(Epoch(12))))
+            .times(1);
+        observer
+            .expect_get_current_stake_distribution()
+            .returning(|| Ok(Some(fake_stake_distribution())))
+            .times(1);
+        let metrics_recorder = Arc::new(TestMetricsRecorder::default());
+        let cached_observer = CachedChainObserver::new(
+            Arc::new(observer),
+            fast_retry_policy(1),
+            TestLogger::stdout(),
+        )
+        .with_metrics_recorder(metrics_recorder.clone());
+
+        for _ in 0..3 {
+            let stake_distribution =
+                cached_observer.get_current_stake_distribution().await.unwrap();
+            assert_eq!(Some(fake_stake_distribution()), stake_distribution);
+        }
+
+        assert_eq!(1, metrics_recorder.misses(STAKE_DISTRIBUTION_QUERY));
+        assert_eq!(2, metrics_recorder.hits(STAKE_DISTRIBUTION_QUERY));
+    }
+
+    #[tokio::test]
+    async fn era_is_fetched_again_when_a_new_epoch_is_observed() {
+        let mut observer = MockChainObserver::new();
+        observer
+            .expect_get_current_epoch()
+            .returning(|| Ok(Some(Epoch(12))))
+            .times(1);
+        observer
+            .expect_get_current_epoch()
+            .returning(|| Ok(Some(Epoch(13))))
+            .times(1);
+        observer
+            .expect_get_current_era()
+            .returning(|| Ok(Some("Babbage".to_string())))
+            .times(1);
+        observer
+            .expect_get_current_era()
+            .returning(|| Ok(Some("Conway".to_string())))
+            .times(1);
+        let metrics_recorder = Arc::new(TestMetricsRecorder::default());
+        let cached_observer = CachedChainObserver::new(
+            Arc::new(observer),
+            fast_retry_policy(1),
+            TestLogger::stdout(),
+        )
+        .with_metrics_recorder(metrics_recorder.clone());
+
+        assert_eq!(
+            Some("Babbage".to_string()),
+            cached_observer.get_current_era().await.unwrap()
+        );
+        assert_eq!(
+            Some("Babbage".to_string()),
+            cached_observer.get_current_era().await.unwrap()
+        );
+        assert_eq!(
+            Some(Epoch(13)),
+            cached_observer.get_current_epoch().await.unwrap()
+        );
+        assert_eq!(
+            Some("Conway".to_string()),
+            cached_observer.get_current_era().await.unwrap()
+        );
+
+        assert_eq!(2, metrics_recorder.misses(ERA_QUERY));
+        assert_eq!(1, metrics_recorder.hits(ERA_QUERY));
+    }
+
+    #[tokio::test]
+    async fn kes_period_is_never_cached() {
+        let mut observer = MockChainObserver::new();
+        observer
+            .expect_get_current_kes_period()
+            .returning(|_| Ok(Some(5)))
+            .times(2);
+        let cached_observer = CachedChainObserver::new(
+            Arc::new(observer),
+            fast_retry_policy(1),
+            TestLogger::stdout(),
+        );
+        let keypair = ColdKeyGenerator::create_deterministic_keypair([0u8; 32]);
+        let mut dummy_key_buffer = [0u8; Sum6Kes::SIZE + 4];
+        let mut dummy_seed = [0u8; 32];
+        let (_, kes_verification_key) = Sum6Kes::keygen(&mut dummy_key_buffer, &mut dummy_seed);
+        let opcert = OpCert::new(kes_verification_key, 0, 0, keypair);
+
+        cached_observer.get_current_kes_period(&opcert).await.unwrap();
+        cached_observer.get_current_kes_period(&opcert).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn general_failures_are_retried_until_success() {
+        let mut observer = MockChainObserver::new();
+        observer
+            .expect_get_current_chain_point()
+            .returning(|| Err(ChainObserverError::General(anyhow!("socket error"))))
+            .times(2);
+        observer
+            .expect_get_current_chain_point()
+            .returning(|| Ok(Some(ChainPoint::dummy())))
+            .times(1);
+        let cached_observer = CachedChainObserver::new(
+            Arc::new(observer),
+            fast_retry_policy(3),
+            TestLogger::stdout(),
+        );
+
+        let chain_point = cached_observer.get_current_chain_point().await.unwrap();
+
+        assert_eq!(Some(ChainPoint::dummy()), chain_point);
+    }
+
+    #[tokio::test]
+    async fn general_failures_are_returned_after_the_last_attempt() {
+        let mut observer = MockChainObserver::new();
+        observer
+            .expect_get_current_chain_point()
+            .returning(|| Err(ChainObserverError::General(anyhow!("socket error"))))
+            .times(3);
+        let cached_observer = CachedChainObserver::new(
+            Arc::new(observer),
+            fast_retry_policy(3),
+            TestLogger::stdout(),
+        );
+
+        cached_observer
+            .get_current_chain_point()
+            .await
+            .expect_err("get_current_chain_point should fail after 3 attempts");
+    }
+
+    #[tokio::test]
+    async fn invalid_content_failures_are_not_retried() {
+        let mut observer = MockChainObserver::new();
+        observer
+            .expect_get_current_epoch()
+            .returning(|| Err(ChainObserverError::InvalidContent(anyhow!("invalid"))))
+            .times(1);
+        let cached_observer = CachedChainObserver::new(
+            Arc::new(observer),
+            fast_retry_policy(3),
+            TestLogger::stdout(),
+        );
+
+        cached_observer
+            .get_current_epoch()
+            .await
+            .expect_err("get_current_epoch should fail without retrying");
+    }
+
+    #[tokio::test]
+    async fn cached_reads_use_the_last_observed_epoch() {
+        let mut observer = MockChainObserver::new();
+        observer
+            .expect_get_current_epoch()
+            .returning(|| Ok(Some(Epoch(12))))
+            .times(1);
+        observer
+            .expect_get_current_stake_distribution()
+            .returning(|| Ok(Some(fake_stake_distribution())))
+            .times(1);
+        let cached_observer = CachedChainObserver::new(
+            Arc::new(observer),
+            fast_retry_policy(1),
+            TestLogger::stdout(),
+        );
+
+        cached_observer.get_current_epoch().await.unwrap();
+        cached_observer.get_current_stake_distribution().await.unwrap();
+        cached_observer.get_current_stake_distribution().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_query_is_not_cached() {
+        let mut observer = MockChainObserver::new();
+        observer
+            .expect_get_current_epoch()
+            .returning(|| Ok(Some(Epoch(12))))
+            .times(1);
+        observer
+            .expect_get_current_stake_distribution()
+            .returning(|| Err(ChainObserverError::General(anyhow!("socket error"))))
+            .times(1);
+        observer
+            .expect_get_current_stake_distribution()
+            .returning(|| Ok(Some(fake_stake_distribution())))
+            .times(1);
+        let cached_observer = CachedChainObserver::new(
+            Arc::new(observer),
+            fast_retry_policy(1),
+            TestLogger::stdout(),
+        );
+
+        cached_observer
+            .get_current_stake_distribution()
+            .await
+            .expect_err("first call should fail");
+        let stake_distribution = cached_observer.get_current_stake_distribution().await.unwrap();
+
+        assert_eq!(Some(fake_stake_distribution()), stake_distribution);
+    }
+}
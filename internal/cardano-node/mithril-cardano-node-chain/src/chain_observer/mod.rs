@@ -1,11 +1,15 @@
 //! Tools to request metadata, like the current epoch or the stake distribution, from the Cardano
 
 mod builder;
+mod cached_observer;
 mod cli_observer;
 mod interface;
+mod ogmios_observer;
 mod pallas_observer;
 
 pub use builder::*;
+pub use cached_observer::*;
 pub use cli_observer::*;
 pub use interface::*;
+pub use ogmios_observer::*;
 pub use pallas_observer::*;
@@ -0,0 +1,637 @@
+use anyhow::{Context, anyhow};
+use async_trait::async_trait;
+use futures::{SinkExt, StreamExt};
+use pallas_primitives::ToCanonicalJson;
+use pallas_traverse::Era;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use mithril_common::StdResult;
+use mithril_common::crypto_helper::{KesPeriod, OpCert};
+use mithril_common::entities::{BlockNumber, ChainPoint, Epoch, SlotNumber, StakeDistribution};
+
+use crate::entities::{ChainAddress, Datum, TxDatum, try_inspect};
+
+use super::{ChainObserver, ChainObserverError};
+
+// The era summaries returned by Ogmios start with the Byron era at index 0, which has an offset
+// of -1 with the era value of the pallas_traverse::Era.
+const ERA_OFFSET: u16 = 1;
+
+/// A [ChainObserver] pulling its data from an [Ogmios](https://ogmios.dev) JSON-RPC endpoint
+/// over WebSocket.
+///
+/// A new connection is opened for each query, the same way the [PallasChainObserver][super::PallasChainObserver]
+/// opens a new node client for each call.
+pub struct OgmiosChainObserver {
+    endpoint: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OgmiosResponse {
+    result: Option<Value>,
+    error: Option<OgmiosResponseError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OgmiosResponseError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OgmiosTip {
+    slot: u64,
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OgmiosUtxo {
+    datum: Option<String>,
+}
+
+/// The stakes of a pool in the ledger stake snapshots, only the `mark` snapshot is read as it's the
+/// one the [PallasChainObserver][super::PallasChainObserver] uses.
+#[derive(Debug, Deserialize)]
+struct OgmiosStakePoolSnapshots {
+    mark: OgmiosValue,
+}
+
+#[derive(Debug, Deserialize)]
+struct OgmiosValue {
+    ada: OgmiosAda,
+}
+
+#[derive(Debug, Deserialize)]
+struct OgmiosAda {
+    lovelace: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OgmiosShelleyGenesisConfiguration {
+    slots_per_kes_period: u64,
+}
+
+impl OgmiosChainObserver {
+    /// Creates a new OgmiosChainObserver targeting the given WebSocket endpoint (ie: `ws://localhost:1337`)
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_owned(),
+        }
+    }
+
+    /// Sends a JSON-RPC request to the Ogmios endpoint and returns its result.
+    async fn query(&self, method: &str, params: Value) -> StdResult<Value> {
+        let (mut stream, _) = connect_async(&self.endpoint).await.with_context(|| {
+            format!(
+                "OgmiosChainObserver failed to connect to '{}'",
+                self.endpoint
+            )
+        })?;
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params,
+            "id": method,
+        });
+        stream
+            .send(Message::Text(request.to_string()))
+            .await
+            .with_context(|| format!("OgmiosChainObserver failed to send '{method}' request"))?;
+
+        let response = loop {
+            match stream.next().await {
+                Some(Ok(Message::Text(text))) => break text,
+                Some(Ok(Message::Binary(bytes))) => {
+                    break String::from_utf8(bytes).with_context(|| {
+                        format!("OgmiosChainObserver received a non UTF-8 '{method}' response")
+                    })?;
+                }
+                Some(Ok(Message::Close(_))) | None => {
+                    return Err(anyhow!(
+                        "OgmiosChainObserver connection closed before receiving '{method}' response"
+                    ));
+                }
+                Some(Ok(_)) => continue,
+                Some(Err(err)) => {
+                    return Err(anyhow!(err)).with_context(|| {
+                        format!("OgmiosChainObserver failed to receive '{method}' response")
+                    });
+                }
+            }
+        };
+        // The response is already received, a failure to close the connection is not an error
+        let _ = stream.close(None).await;
+
+        let response: OgmiosResponse = serde_json::from_str(&response).with_context(|| {
+            format!("OgmiosChainObserver failed to parse '{method}' response: '{response}'")
+        })?;
+
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(anyhow!(
+                "OgmiosChainObserver '{method}' query failed with code {}: {}",
+                error.code,
+                error.message
+            )),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(anyhow!(
+                "OgmiosChainObserver '{method}' response has neither a result nor an error"
+            )),
+        }
+    }
+
+    /// Sends a JSON-RPC request and deserializes its result into `T`.
+    async fn query_as<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> Result<T, ChainObserverError> {
+        let result = self.query(method, params).await?;
+
+        serde_json::from_value(result.clone())
+            .with_context(|| {
+                format!("OgmiosChainObserver failed to parse '{method}' result: '{result}'")
+            })
+            .map_err(ChainObserverError::InvalidContent)
+    }
+
+    /// Fetches the tip of the chain, `None` if the chain is at its origin.
+    async fn get_tip(&self) -> Result<Option<OgmiosTip>, ChainObserverError> {
+        let result = self.query("queryNetwork/tip", json!({})).await?;
+
+        if result == json!("origin") {
+            return Ok(None);
+        }
+
+        serde_json::from_value(result.clone())
+            .with_context(|| format!("OgmiosChainObserver failed to parse tip: '{result}'"))
+            .map_err(ChainObserverError::InvalidContent)
+    }
+
+    /// Fetches the block height of the chain, `0` if the chain is at its origin.
+    async fn get_block_height(&self) -> Result<BlockNumber, ChainObserverError> {
+        let result = self.query("queryNetwork/blockHeight", json!({})).await?;
+
+        match result {
+            Value::String(origin) if origin == "origin" => Ok(BlockNumber(0)),
+            Value::Number(height) => height.as_u64().map(BlockNumber).ok_or_else(|| {
+                ChainObserverError::InvalidContent(anyhow!(
+                    "OgmiosChainObserver could not parse block height as u64: '{height}'"
+                ))
+            }),
+            _ => Err(ChainObserverError::InvalidContent(anyhow!(
+                "OgmiosChainObserver could not parse block height: '{result}'"
+            ))),
+        }
+    }
+
+    /// Deserializes the given hex encoded inline datum to a `TxDatum` instance.
+    fn serialize_datum(&self, datum_hex: &str) -> StdResult<TxDatum> {
+        let datum_bytes = hex::decode(datum_hex)
+            .with_context(|| "OgmiosChainObserver failed to decode hex datum")?;
+        let datum = try_inspect::<Datum>(datum_bytes)?;
+        let serialized = serde_json::to_string(&datum.to_json())
+            .with_context(|| "OgmiosChainObserver failed to serialize datum")?;
+
+        Ok(TxDatum(serialized))
+    }
+
+    /// Computes the KES period of the given slot, see [PallasChainObserver][super::PallasChainObserver]
+    /// for the details of the formula.
+    fn calculate_kes_period(
+        slot_number: SlotNumber,
+        slots_per_kes_period: u64,
+    ) -> Result<KesPeriod, ChainObserverError> {
+        if slots_per_kes_period == 0 {
+            return Err(ChainObserverError::InvalidContent(anyhow!(
+                "OgmiosChainObserver failed to calculate kes period: slots_per_kes_period must be greater than 0"
+            )));
+        }
+
+        let current_kes_period = *slot_number / slots_per_kes_period;
+        Ok(u32::try_from(current_kes_period)
+            .with_context(|| "OgmiosChainObserver failed to convert kes period")?)
+    }
+}
+
+#[async_trait]
+impl ChainObserver for OgmiosChainObserver {
+    async fn get_current_era(&self) -> Result<Option<String>, ChainObserverError> {
+        let era_summaries: Vec<Value> =
+            self.query_as("queryLedgerState/eraSummaries", json!({})).await?;
+
+        match era_summaries.len() {
+            0 => Ok(None),
+            summaries => {
+                let era_index = u16::try_from(summaries - 1)
+                    .with_context(|| "OgmiosChainObserver failed to convert era index")?;
+                let era = Era::try_from(era_index + ERA_OFFSET).with_context(|| {
+                    format!("OgmiosChainObserver failed to convert: '{era_index}' to Era")
+                })?;
+
+                Ok(Some(era.to_string()))
+            }
+        }
+    }
+
+    async fn get_current_epoch(&self) -> Result<Option<Epoch>, ChainObserverError> {
+        let epoch: u64 = self.query_as("queryLedgerState/epoch", json!({})).await?;
+
+        Ok(Some(Epoch(epoch)))
+    }
+
+    async fn get_current_chain_point(&self) -> Result<Option<ChainPoint>, ChainObserverError> {
+        match self.get_tip().await? {
+            Some(tip) => {
+                let block_number = self.get_block_height().await?;
+
+                Ok(Some(ChainPoint {
+                    slot_number: SlotNumber(tip.slot),
+                    block_number,
+                    block_hash: tip.id,
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn get_current_datums(
+        &self,
+        address: &ChainAddress,
+    ) -> Result<Vec<TxDatum>, ChainObserverError> {
+        let utxos: Vec<OgmiosUtxo> = self
+            .query_as("queryLedgerState/utxo", json!({ "addresses": [address] }))
+            .await?;
+
+        Ok(utxos
+            .iter()
+            .filter_map(|utxo| utxo.datum.as_ref().map(|datum| self.serialize_datum(datum)))
+            .collect::<StdResult<Vec<TxDatum>>>()?)
+    }
+
+    async fn get_current_stake_distribution(
+        &self,
+    ) -> Result<Option<StakeDistribution>, ChainObserverError> {
+        // The live stake of the pools moves with each block, the `mark` stake snapshot is the
+        // distribution taken at the start of the epoch, the same for all the nodes.
+        let stake_snapshots: BTreeMap<String, OgmiosStakePoolSnapshots> =
+            self.query_as("queryLedgerState/stakeSnapshots", json!({})).await?;
+
+        let mut stake_distribution = StakeDistribution::new();
+        for (pool_id, snapshots) in stake_snapshots {
+            let stake = snapshots.mark.ada.lovelace;
+            if stake > 0 {
+                stake_distribution.insert(pool_id, stake);
+            }
+        }
+
+        Ok(Some(stake_distribution))
+    }
+
+    async fn get_current_kes_period(
+        &self,
+        _opcert: &OpCert,
+    ) -> Result<Option<KesPeriod>, ChainObserverError> {
+        let Some(tip) = self.get_tip().await? else {
+            return Ok(None);
+        };
+        let genesis_configuration: OgmiosShelleyGenesisConfiguration = self
+            .query_as(
+                "queryNetwork/genesisConfiguration",
+                json!({ "era": "shelley" }),
+            )
+            .await?;
+
+        let current_kes_period = Self::calculate_kes_period(
+            SlotNumber(tip.slot),
+            genesis_configuration.slots_per_kes_period,
+        )?;
+
+        Ok(Some(current_kes_period))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kes_summed_ed25519::{kes::Sum6Kes, traits::KesSk};
+    use std::collections::HashMap;
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+    use tokio_tungstenite::accept_async;
+
+    use mithril_common::crypto_helper::ColdKeyGenerator;
+
+    use super::*;
+
+    /// Starts a local mock Ogmios server answering each JSON-RPC method with the given payload.
+    ///
+    /// A payload with an `error` key is sent as a JSON-RPC error, otherwise it's sent as the result.
+    async fn setup_server(responses: HashMap<&'static str, Value>) -> (String, JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+
+        let server = tokio::spawn(async move {
+            loop {
+                let (tcp_stream, _) = listener.accept().await.unwrap();
+                let mut ws_stream = accept_async(tcp_stream).await.unwrap();
+                let Some(Ok(Message::Text(request))) = ws_stream.next().await else {
+                    continue;
+                };
+                let request: Value = serde_json::from_str(&request).unwrap();
+                let method = request["method"].as_str().unwrap();
+                let response = match responses.get(method) {
+                    Some(payload) if payload.get("error").is_some() => json!({
+                        "jsonrpc": "2.0",
+                        "method": method,
+                        "error": payload["error"],
+                        "id": request["id"],
+                    }),
+                    Some(payload) => json!({
+                        "jsonrpc": "2.0",
+                        "method": method,
+                        "result": payload,
+                        "id": request["id"],
+                    }),
+                    None => json!({
+                        "jsonrpc": "2.0",
+                        "method": method,
+                        "error": { "code": -32601, "message": "Method not found" },
+                        "id": request["id"],
+                    }),
+                };
+                ws_stream.send(Message::Text(response.to_string())).await.unwrap();
+            }
+        });
+
+        (endpoint, server)
+    }
+
+    fn fake_tip() -> Value {
+        json!({
+            "slot": 52_819_001,
+            "id": "0c2c2dfc3b9ba4a5e3b70d0e7ea18d5e4bd1d3c1ab3cf3fa06ac40fd7efcb9d5"
+        })
+    }
+
+    #[tokio::test]
+    async fn get_current_era() {
+        let (endpoint, _server) = setup_server(HashMap::from([(
+            "queryLedgerState/eraSummaries",
+            json!([{}, {}, {}, {}, {}, {}, {}]),
+        )]))
+        .await;
+        let observer = OgmiosChainObserver::new(&endpoint);
+
+        let era = observer.get_current_era().await.unwrap();
+
+        assert_eq!(Some(Era::Conway.to_string()), era);
+    }
+
+    #[tokio::test]
+    async fn get_current_epoch() {
+        let (endpoint, _server) =
+            setup_server(HashMap::from([("queryLedgerState/epoch", json!(8))])).await;
+        let observer = OgmiosChainObserver::new(&endpoint);
+
+        let epoch = observer.get_current_epoch().await.unwrap();
+
+        assert_eq!(Some(Epoch(8)), epoch);
+    }
+
+    #[tokio::test]
+    async fn get_current_chain_point() {
+        let (endpoint, _server) = setup_server(HashMap::from([
+            ("queryNetwork/tip", fake_tip()),
+            ("queryNetwork/blockHeight", json!(2_517_349)),
+        ]))
+        .await;
+        let observer = OgmiosChainObserver::new(&endpoint);
+
+        let chain_point = observer.get_current_chain_point().await.unwrap();
+
+        assert_eq!(
+            Some(ChainPoint {
+                slot_number: SlotNumber(52_819_001),
+                block_number: BlockNumber(2_517_349),
+                block_hash: "0c2c2dfc3b9ba4a5e3b70d0e7ea18d5e4bd1d3c1ab3cf3fa06ac40fd7efcb9d5"
+                    .to_string(),
+            }),
+            chain_point
+        );
+    }
+
+    #[tokio::test]
+    async fn get_current_chain_point_at_origin() {
+        let (endpoint, _server) =
+            setup_server(HashMap::from([("queryNetwork/tip", json!("origin"))])).await;
+        let observer = OgmiosChainObserver::new(&endpoint);
+
+        let chain_point = observer.get_current_chain_point().await.unwrap();
+
+        assert_eq!(None, chain_point);
+    }
+
+    #[tokio::test]
+    async fn get_current_datums() {
+        let (endpoint, _server) = setup_server(HashMap::from([(
+            "queryLedgerState/utxo",
+            json!([
+                {
+                    "transaction": { "id": "a1b2" },
+                    "index": 0,
+                    "address": "addr_test1vr8gd3u8ul3cr4xcsp6jgrnmu9kuq8kqa3fryy64ew4ecfs2gsqfg",
+                    "value": { "ada": { "lovelace": 1_000_000 } },
+                    "datum": "d8799f4c737472696e675f646174756dff"
+                },
+                {
+                    "transaction": { "id": "c3d4" },
+                    "index": 1,
+                    "address": "addr_test1vr8gd3u8ul3cr4xcsp6jgrnmu9kuq8kqa3fryy64ew4ecfs2gsqfg",
+                    "value": { "ada": { "lovelace": 2_000_000 } }
+                }
+            ]),
+        )]))
+        .await;
+        let observer = OgmiosChainObserver::new(&endpoint);
+
+        let datums = observer
+            .get_current_datums(
+                &"addr_test1vr8gd3u8ul3cr4xcsp6jgrnmu9kuq8kqa3fryy64ew4ecfs2gsqfg".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![TxDatum(
+                r#"{"constructor":0,"fields":[{"bytes":"737472696e675f646174756d"}]}"#.to_string()
+            )],
+            datums
+        );
+    }
+
+    #[tokio::test]
+    async fn get_current_stake_distribution() {
+        let (endpoint, _server) = setup_server(HashMap::from([(
+            "queryLedgerState/stakeSnapshots",
+            json!({
+                "pool1qqyjr9pcrv97gwrueunug829fs5znw6p2wxft3fvqkgu5f4qlrg": {
+                    "mark": { "ada": { "lovelace": 2_493_000 } },
+                    "set": { "ada": { "lovelace": 2_490_000 } },
+                    "go": { "ada": { "lovelace": 2_480_000 } }
+                },
+                "pool1qqfnw2fwajdnam7xsqhhrje5cgd8jcltzfrx655rd23eqlxjfef": {
+                    "mark": { "ada": { "lovelace": 21_640 } },
+                    "set": { "ada": { "lovelace": 21_000 } },
+                    "go": { "ada": { "lovelace": 0 } }
+                },
+                "pool1qpqvz90w7qsex2al2ejjej0rfgrwsguch307w8fraw7a7adf6g8": {
+                    "mark": { "ada": { "lovelace": 0 } },
+                    "set": { "ada": { "lovelace": 1_300_000 } },
+                    "go": { "ada": { "lovelace": 1_300_000 } }
+                }
+            }),
+        )]))
+        .await;
+        let observer = OgmiosChainObserver::new(&endpoint);
+
+        let stake_distribution = observer.get_current_stake_distribution().await.unwrap();
+
+        assert_eq!(
+            Some(StakeDistribution::from([
+                (
+                    "pool1qqyjr9pcrv97gwrueunug829fs5znw6p2wxft3fvqkgu5f4qlrg".to_string(),
+                    2_493_000
+                ),
+                (
+                    "pool1qqfnw2fwajdnam7xsqhhrje5cgd8jcltzfrx655rd23eqlxjfef".to_string(),
+                    21_640
+                ),
+            ])),
+            stake_distribution
+        );
+    }
+
+    // Windows does not support Unix sockets needed by the mock server of the PallasChainObserver
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn get_current_stake_distribution_is_the_same_as_the_pallas_chain_observer_one() {
+        use mithril_common::CardanoNetwork;
+        use mithril_common::crypto_helper::encode_bech32;
+
+        use crate::chain_observer::PallasChainObserver;
+        use crate::chain_observer::pallas_observer::tests as pallas_tests;
+
+        let stake_snapshot = pallas_tests::get_fake_stake_snapshot();
+        let ogmios_stake_snapshots: serde_json::Map<String, Value> = stake_snapshot
+            .snapshots
+            .stake_snapshots
+            .iter()
+            .map(|(pool_hash, stakes)| {
+                (
+                    encode_bech32("pool", pool_hash).unwrap(),
+                    json!({
+                        "mark": { "ada": { "lovelace": stakes.snapshot_mark_pool } },
+                        "set": { "ada": { "lovelace": stakes.snapshot_set_pool } },
+                        "go": { "ada": { "lovelace": stakes.snapshot_go_pool } }
+                    }),
+                )
+            })
+            .collect();
+        let (endpoint, _server) = setup_server(HashMap::from([(
+            "queryLedgerState/stakeSnapshots",
+            Value::Object(ogmios_stake_snapshots),
+        )]))
+        .await;
+        let ogmios_observer = OgmiosChainObserver::new(&endpoint);
+
+        let socket_path = pallas_tests::create_temp_dir(
+            "get_current_stake_distribution_is_the_same_as_the_pallas_chain_observer_one",
+        )
+        .join("node.socket");
+        let pallas_server = pallas_tests::setup_server(socket_path.clone(), 2).await;
+        let pallas_client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+            observer.get_current_stake_distribution().await.unwrap()
+        });
+        let (_, pallas_stake_distribution) = tokio::join!(pallas_server, pallas_client);
+
+        let ogmios_stake_distribution =
+            ogmios_observer.get_current_stake_distribution().await.unwrap();
+
+        assert_eq!(
+            pallas_stake_distribution.unwrap(),
+            ogmios_stake_distribution
+        );
+    }
+
+    #[tokio::test]
+    async fn get_current_kes_period() {
+        let (endpoint, _server) = setup_server(HashMap::from([
+            ("queryNetwork/tip", fake_tip()),
+            (
+                "queryNetwork/genesisConfiguration",
+                json!({ "era": "shelley", "slotsPerKesPeriod": 129_600 }),
+            ),
+        ]))
+        .await;
+        let observer = OgmiosChainObserver::new(&endpoint);
+        let keypair = ColdKeyGenerator::create_deterministic_keypair([0u8; 32]);
+        let mut dummy_key_buffer = [0u8; Sum6Kes::SIZE + 4];
+        let mut dummy_seed = [0u8; 32];
+        let (_, kes_verification_key) = Sum6Kes::keygen(&mut dummy_key_buffer, &mut dummy_seed);
+        let operational_certificate = OpCert::new(kes_verification_key, 0, 0, keypair);
+
+        let kes_period = observer
+            .get_current_kes_period(&operational_certificate)
+            .await
+            .unwrap();
+
+        assert_eq!(Some(407), kes_period);
+    }
+
+    #[tokio::test]
+    async fn query_fails_when_ogmios_returns_an_error() {
+        let (endpoint, _server) = setup_server(HashMap::from([(
+            "queryLedgerState/epoch",
+            json!({ "error": { "code": 2001, "message": "Era mismatch" } }),
+        )]))
+        .await;
+        let observer = OgmiosChainObserver::new(&endpoint);
+
+        let error = observer
+            .get_current_epoch()
+            .await
+            .expect_err("get_current_epoch should fail");
+
+        assert!(
+            format!("{error:?}").contains("Era mismatch"),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn query_fails_when_endpoint_is_unreachable() {
+        let observer = OgmiosChainObserver::new("ws://127.0.0.1:1");
+
+        observer
+            .get_current_epoch()
+            .await
+            .expect_err("get_current_epoch should fail");
+    }
+
+    #[test]
+    fn calculate_kes_period() {
+        let kes_period =
+            OgmiosChainObserver::calculate_kes_period(SlotNumber(52_819_001), 129_600).unwrap();
+        assert_eq!(407, kes_period);
+
+        OgmiosChainObserver::calculate_kes_period(SlotNumber(52_819_001), 0)
+            .expect_err("calculate_kes_period should fail when slots_per_kes_period is 0");
+    }
+}
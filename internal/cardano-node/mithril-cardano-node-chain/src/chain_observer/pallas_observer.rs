@@ -509,7 +509,7 @@ impl ChainObserver for PallasChainObserver {
 
 // Windows does not support Unix sockets, nor pallas_network::facades::NodeServer
 #[cfg(all(test, unix))]
-mod tests {
+pub(crate) mod tests {
     use std::fs;
 
     use kes_summed_ed25519::{kes::Sum6Kes, traits::KesSk};
@@ -565,7 +565,7 @@ mod tests {
         UTxOByAddress { utxo }
     }
 
-    fn get_fake_stake_snapshot() -> StakeSnapshot {
+    pub(crate) fn get_fake_stake_snapshot() -> StakeSnapshot {
         let stake_snapshots = KeyValuePairs::from(vec![
             (
                 Bytes::from(
@@ -681,7 +681,7 @@ mod tests {
     }
 
     /// Creates a new work directory in the system's temporary folder.
-    fn create_temp_dir(folder_name: &str) -> PathBuf {
+    pub(crate) fn create_temp_dir(folder_name: &str) -> PathBuf {
         TempDir::create_with_short_path("pallas_chain_observer_test", folder_name)
     }
 
@@ -690,7 +690,10 @@ mod tests {
     /// Use the `intersections` parameter to define exactly how many
     /// local state queries should be intercepted by the `mock_server`
     /// and avoid any panic errors.
-    async fn setup_server(socket_path: PathBuf, intersections: u32) -> tokio::task::JoinHandle<()> {
+    pub(crate) async fn setup_server(
+        socket_path: PathBuf,
+        intersections: u32,
+    ) -> tokio::task::JoinHandle<()> {
         tokio::spawn({
             async move {
                 if socket_path.exists() {
@@ -22,6 +22,7 @@ pub struct PallasChainReader {
     socket: PathBuf,
     network: CardanoNetwork,
     client: Option<NodeClient>,
+    capture_transactions_metadata: bool,
     logger: Logger,
 }
 
@@ -32,10 +33,19 @@ impl PallasChainReader {
             socket: socket.to_owned(),
             network,
             client: None,
+            capture_transactions_metadata: false,
             logger: logger.new_with_component_name::<Self>(),
         }
     }
 
+    /// Capture the metadata labels carried by the transactions of the read blocks
+    ///
+    /// Disabled by default, as hashing the metadata adds a cost to the decoding of every block.
+    pub fn with_transactions_metadata_capture(mut self, enabled: bool) -> Self {
+        self.capture_transactions_metadata = enabled;
+        self
+    }
+
     /// Creates and returns a new `NodeClient` connected to the specified socket.
     async fn new_client(&self) -> StdResult<NodeClient> {
         let magic = self.network.magic_id();
@@ -97,7 +107,8 @@ impl PallasChainReader {
             NextResponse::RollForward(raw_block, _forward_tip) => {
                 let multi_era_block = MultiEraBlock::decode(&raw_block)
                     .with_context(|| "PallasChainReader failed to decode raw block")?;
-                let parsed_block = ScannedBlock::convert(multi_era_block);
+                let parsed_block =
+                    ScannedBlock::convert(multi_era_block, self.capture_transactions_metadata);
                 Ok(Some(ChainBlockNextAction::RollForward { parsed_block }))
             }
             NextResponse::RollBackward(rollback_point, _) => {
@@ -214,7 +225,7 @@ mod tests {
         let raw_block = get_fake_raw_block();
         let multi_era_block = MultiEraBlock::decode(&raw_block).unwrap();
 
-        ScannedBlock::convert(multi_era_block)
+        ScannedBlock::convert(multi_era_block, false)
     }
 
     /// Sets up a mock server for related tests.
@@ -8,7 +8,7 @@ use mithril_common::StdResult;
 use mithril_common::entities::BlockNumber;
 
 use crate::chain_reader::ChainBlockReader;
-use crate::chain_scanner::{BlockScanner, BlockStreamer};
+use crate::chain_scanner::{BlockScanner, BlockStreamer, ChainRollbackHandler};
 use crate::entities::RawCardanoPoint;
 
 use super::ChainReaderBlockStreamer;
@@ -19,6 +19,7 @@ use super::ChainReaderBlockStreamer;
 pub struct CardanoBlockScanner {
     chain_reader: Arc<Mutex<dyn ChainBlockReader>>,
     max_roll_forwards_per_poll: usize,
+    rollback_handler: Arc<ChainRollbackHandler>,
     logger: Logger,
 }
 
@@ -32,9 +33,16 @@ impl CardanoBlockScanner {
         Self {
             chain_reader,
             max_roll_forwards_per_poll,
+            rollback_handler: Arc::new(ChainRollbackHandler::default()),
             logger,
         }
     }
+
+    /// Set the handler of the rollbacks received by the streamers
+    pub fn with_rollback_handler(mut self, rollback_handler: Arc<ChainRollbackHandler>) -> Self {
+        self.rollback_handler = rollback_handler;
+        self
+    }
 }
 
 #[async_trait]
@@ -52,7 +60,8 @@ impl BlockScanner for CardanoBlockScanner {
                 self.max_roll_forwards_per_poll,
                 self.logger.clone(),
             )
-            .await?,
+            .await?
+            .with_rollback_handler(self.rollback_handler.clone()),
         ))
     }
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use slog::{Logger, debug, trace};
+use slog::{Logger, debug, trace, warn};
 use tokio::sync::Mutex;
 
 use mithril_common::StdResult;
@@ -9,7 +9,9 @@ use mithril_common::entities::BlockNumber;
 use mithril_common::logging::LoggerExtensions;
 
 use crate::chain_reader::ChainBlockReader;
-use crate::chain_scanner::{BlockStreamer, ChainScannedBlocks};
+use crate::chain_scanner::{
+    BlockStreamer, ChainRollbackHandler, ChainScannedBlocks, RollbackDepth, RollbackResolution,
+};
 use crate::entities::{ChainBlockNextAction, RawCardanoPoint};
 
 /// The action that indicates what to do next with the streamer
@@ -28,6 +30,7 @@ pub struct ChainReaderBlockStreamer {
     until: BlockNumber,
     max_roll_forwards_per_poll: usize,
     last_polled_point: Option<RawCardanoPoint>,
+    rollback_handler: Arc<ChainRollbackHandler>,
     logger: Logger,
 }
 
@@ -45,6 +48,7 @@ impl BlockStreamer for ChainReaderBlockStreamer {
                     ChainBlockNextAction::RollForward { parsed_block },
                 )) => {
                     self.last_polled_point = Some(RawCardanoPoint::from(&parsed_block));
+                    self.rollback_handler.record_roll_forward(&parsed_block);
                     let parsed_block_number = parsed_block.block_number;
                     roll_forwards.push(parsed_block);
                     if roll_forwards.len() >= self.max_roll_forwards_per_poll
@@ -56,8 +60,28 @@ impl BlockStreamer for ChainReaderBlockStreamer {
                 Some(BlockStreamerNextAction::ChainBlockNextAction(
                     ChainBlockNextAction::RollBackward { rollback_point },
                 )) => {
-                    self.last_polled_point = Some(rollback_point.clone());
                     let rollback_slot_number = rollback_point.slot_number;
+                    let resolution = self.rollback_handler.handle_rollback(rollback_slot_number)?;
+                    let depth = match resolution {
+                        RollbackResolution::Apply(depth) => depth,
+                        RollbackResolution::ApplyWithAlert(depth) => {
+                            warn!(
+                                self.logger,
+                                "Rollback exceeds the security parameter, it is applied as configured";
+                                "slot_number" => ?rollback_slot_number, "depth" => ?depth
+                            );
+                            depth
+                        }
+                        RollbackResolution::ReImport(depth) => {
+                            warn!(
+                                self.logger,
+                                "Rollback exceeds the security parameter, the chain will be imported again from its origin";
+                                "slot_number" => ?rollback_slot_number, "depth" => ?depth
+                            );
+                            return self.restart_from_origin(depth).await.map(Some);
+                        }
+                    };
+                    self.last_polled_point = Some(rollback_point.clone());
                     let index_rollback = roll_forwards
                         .iter()
                         .position(|block| block.slot_number == rollback_slot_number);
@@ -74,8 +98,10 @@ impl BlockStreamer for ChainReaderBlockStreamer {
                                 self.logger,
                                 "ChainScannedBlocks triggered a full RollBackward({rollback_slot_number:?})"
                             );
-                            chain_scanned_blocks =
-                                ChainScannedBlocks::RollBackward(rollback_slot_number);
+                            chain_scanned_blocks = ChainScannedBlocks::RollBackward {
+                                slot_number: rollback_slot_number,
+                                depth,
+                            };
                             return Ok(Some(chain_scanned_blocks));
                         }
                     }
@@ -120,10 +146,35 @@ impl ChainReaderBlockStreamer {
             until,
             max_roll_forwards_per_poll,
             last_polled_point: None,
+            rollback_handler: Arc::new(ChainRollbackHandler::default()),
             logger: logger.new_with_component_name::<Self>(),
         })
     }
 
+    /// Set the handler of the rollbacks, shared with the previous streamers of the same chain reader
+    pub fn with_rollback_handler(mut self, rollback_handler: Arc<ChainRollbackHandler>) -> Self {
+        // Blocks polled after the starting point by a previous streamer are not part of the
+        // chain followed by this streamer
+        rollback_handler.forget_blocks_after(self.from.slot_number);
+        self.rollback_handler = rollback_handler;
+        self
+    }
+
+    async fn restart_from_origin(&mut self, depth: RollbackDepth) -> StdResult<ChainScannedBlocks> {
+        let origin = RawCardanoPoint::origin();
+        {
+            let mut chain_reader = self.chain_reader.try_lock()?;
+            chain_reader.set_chain_point(&origin).await?;
+        }
+        self.from = origin.clone();
+        self.last_polled_point = Some(origin.clone());
+
+        Ok(ChainScannedBlocks::RollBackward {
+            slot_number: origin.slot_number,
+            depth,
+        })
+    }
+
     async fn get_next_chain_block_action(&self) -> StdResult<Option<BlockStreamerNextAction>> {
         let mut chain_reader = self.chain_reader.try_lock()?;
         match chain_reader.get_next_chain_block().await? {
@@ -173,6 +224,7 @@ impl ChainReaderBlockStreamer {
 mod tests {
     use mithril_common::entities::SlotNumber;
 
+    use crate::chain_scanner::RollbackPolicy;
     use crate::entities::ScannedBlock;
     use crate::test::TestLogger;
     use crate::test::double::FakeChainReader;
@@ -465,7 +517,10 @@ mod tests {
         let scanned_blocks = block_streamer.poll_next().await.expect("poll_next failed");
 
         assert_eq!(
-            Some(ChainScannedBlocks::RollBackward(SlotNumber(100))),
+            Some(ChainScannedBlocks::RollBackward {
+                slot_number: SlotNumber(100),
+                depth: RollbackDepth::AtLeast(0),
+            }),
             scanned_blocks,
         );
         assert_eq!(
@@ -575,7 +630,10 @@ mod tests {
         let scanned_blocks = block_streamer.poll_next().await.expect("poll_next failed");
 
         assert_eq!(
-            Some(ChainScannedBlocks::RollBackward(SlotNumber(3))),
+            Some(ChainScannedBlocks::RollBackward {
+                slot_number: SlotNumber(3),
+                depth: RollbackDepth::AtLeast(2),
+            }),
             scanned_blocks,
         );
         assert_eq!(
@@ -617,4 +675,206 @@ mod tests {
 
         assert_eq!(block_streamer.last_polled_point(), None);
     }
+
+    mod rollback_handling {
+        use super::*;
+
+        fn roll_forward(block_number: u64, slot_number: u64) -> ChainBlockNextAction {
+            ChainBlockNextAction::RollForward {
+                parsed_block: ScannedBlock::new(
+                    format!("hash-{block_number}"),
+                    BlockNumber(block_number),
+                    SlotNumber(slot_number),
+                    Vec::<&str>::new(),
+                ),
+            }
+        }
+
+        fn roll_backward(block_number: u64, slot_number: u64) -> ChainBlockNextAction {
+            ChainBlockNextAction::RollBackward {
+                rollback_point: RawCardanoPoint::new(
+                    SlotNumber(slot_number),
+                    format!("hash-{block_number}"),
+                ),
+            }
+        }
+
+        async fn build_streamer(
+            chain_block_next_actions: Vec<ChainBlockNextAction>,
+            from: Option<RawCardanoPoint>,
+            rollback_handler: Arc<ChainRollbackHandler>,
+        ) -> ChainReaderBlockStreamer {
+            let chain_reader = Arc::new(Mutex::new(FakeChainReader::new(chain_block_next_actions)));
+            ChainReaderBlockStreamer::try_new(
+                chain_reader,
+                from,
+                BlockNumber(1000),
+                1,
+                TestLogger::stdout(),
+            )
+            .await
+            .unwrap()
+            .with_rollback_handler(rollback_handler)
+        }
+
+        #[tokio::test]
+        async fn rollback_to_previously_polled_block_has_an_exact_depth() {
+            let mut block_streamer = build_streamer(
+                vec![
+                    roll_forward(1, 10),
+                    roll_forward(2, 20),
+                    roll_forward(3, 30),
+                    roll_backward(1, 10),
+                ],
+                None,
+                Arc::new(ChainRollbackHandler::new(
+                    BlockNumber(10),
+                    RollbackPolicy::Halt,
+                )),
+            )
+            .await;
+
+            for _ in 0..3 {
+                block_streamer.poll_next().await.expect("poll_next failed");
+            }
+            let scanned_blocks = block_streamer.poll_next().await.expect("poll_next failed");
+
+            assert_eq!(
+                Some(ChainScannedBlocks::RollBackward {
+                    slot_number: SlotNumber(10),
+                    depth: RollbackDepth::Exact(2),
+                }),
+                scanned_blocks,
+            );
+        }
+
+        #[tokio::test]
+        async fn rollback_handler_is_shared_between_streamers() {
+            let rollback_handler = Arc::new(ChainRollbackHandler::new(
+                BlockNumber(10),
+                RollbackPolicy::Halt,
+            ));
+            let mut block_streamer = build_streamer(
+                vec![roll_forward(1, 10), roll_forward(2, 20)],
+                None,
+                rollback_handler.clone(),
+            )
+            .await;
+            while block_streamer.poll_next().await.unwrap().is_some() {}
+
+            let mut block_streamer = build_streamer(
+                vec![roll_backward(1, 10)],
+                block_streamer.last_polled_point(),
+                rollback_handler,
+            )
+            .await;
+            let scanned_blocks = block_streamer.poll_next().await.expect("poll_next failed");
+
+            assert_eq!(
+                Some(ChainScannedBlocks::RollBackward {
+                    slot_number: SlotNumber(10),
+                    depth: RollbackDepth::Exact(1),
+                }),
+                scanned_blocks,
+            );
+        }
+
+        #[tokio::test]
+        async fn rollback_exceeding_security_parameter_with_halt_policy_fails() {
+            let mut block_streamer = build_streamer(
+                vec![
+                    roll_forward(1, 10),
+                    roll_forward(2, 20),
+                    roll_forward(3, 30),
+                    roll_backward(1, 10),
+                ],
+                None,
+                Arc::new(ChainRollbackHandler::new(
+                    BlockNumber(1),
+                    RollbackPolicy::Halt,
+                )),
+            )
+            .await;
+
+            for _ in 0..3 {
+                block_streamer.poll_next().await.expect("poll_next failed");
+            }
+            block_streamer.poll_next().await.expect_err("poll_next should fail");
+
+            assert_eq!(
+                block_streamer.last_polled_point(),
+                Some(RawCardanoPoint::new(SlotNumber(30), "hash-3"))
+            );
+        }
+
+        #[tokio::test]
+        async fn rollback_exceeding_security_parameter_with_alert_policy_is_applied() {
+            let mut block_streamer = build_streamer(
+                vec![
+                    roll_forward(1, 10),
+                    roll_forward(2, 20),
+                    roll_forward(3, 30),
+                    roll_backward(1, 10),
+                ],
+                None,
+                Arc::new(ChainRollbackHandler::new(
+                    BlockNumber(1),
+                    RollbackPolicy::Alert,
+                )),
+            )
+            .await;
+
+            for _ in 0..3 {
+                block_streamer.poll_next().await.expect("poll_next failed");
+            }
+            let scanned_blocks = block_streamer.poll_next().await.expect("poll_next failed");
+
+            assert_eq!(
+                Some(ChainScannedBlocks::RollBackward {
+                    slot_number: SlotNumber(10),
+                    depth: RollbackDepth::AtLeast(2),
+                }),
+                scanned_blocks,
+            );
+            assert_eq!(
+                block_streamer.last_polled_point(),
+                Some(RawCardanoPoint::new(SlotNumber(10), "hash-1"))
+            );
+        }
+
+        #[tokio::test]
+        async fn rollback_exceeding_security_parameter_with_reimport_policy_rolls_back_to_origin() {
+            let mut block_streamer = build_streamer(
+                vec![
+                    roll_forward(1, 10),
+                    roll_forward(2, 20),
+                    roll_forward(3, 30),
+                    roll_backward(1, 10),
+                ],
+                None,
+                Arc::new(ChainRollbackHandler::new(
+                    BlockNumber(1),
+                    RollbackPolicy::ReImport,
+                )),
+            )
+            .await;
+
+            for _ in 0..3 {
+                block_streamer.poll_next().await.expect("poll_next failed");
+            }
+            let scanned_blocks = block_streamer.poll_next().await.expect("poll_next failed");
+
+            assert_eq!(
+                Some(ChainScannedBlocks::RollBackward {
+                    slot_number: RawCardanoPoint::origin().slot_number,
+                    depth: RollbackDepth::AtLeast(2),
+                }),
+                scanned_blocks,
+            );
+            assert_eq!(
+                block_streamer.last_polled_point(),
+                Some(RawCardanoPoint::origin())
+            );
+        }
+    }
 }
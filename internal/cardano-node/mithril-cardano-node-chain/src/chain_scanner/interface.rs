@@ -60,7 +60,36 @@ pub enum ChainScannedBlocks {
     /// Roll forward on the chain to the next list of [ScannedBlock]
     RollForwards(Vec<ScannedBlock>),
     /// Roll backward on the chain to the previous [SlotNumber]
-    RollBackward(SlotNumber),
+    RollBackward {
+        /// Slot number of the point the chain rolled back to
+        slot_number: SlotNumber,
+        /// Number of blocks discarded by the rollback
+        depth: RollbackDepth,
+    },
+}
+
+/// Number of blocks discarded by a rollback of the chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollbackDepth {
+    /// The rollback point is known, the depth is exact
+    Exact(u64),
+    /// The rollback point precedes the recent blocks known by the streamer, only a lower bound
+    /// of the depth is known
+    AtLeast(u64),
+}
+
+impl RollbackDepth {
+    /// Get the number of blocks known to be discarded by the rollback
+    pub fn lower_bound(&self) -> u64 {
+        match self {
+            RollbackDepth::Exact(depth) | RollbackDepth::AtLeast(depth) => *depth,
+        }
+    }
+
+    /// Check if the rollback is known to be deeper than the given security parameter
+    pub fn exceeds(&self, security_parameter: BlockNumber) -> bool {
+        self.lower_bound() > *security_parameter
+    }
 }
 
 /// Trait that define how blocks are streamed from a Cardano database
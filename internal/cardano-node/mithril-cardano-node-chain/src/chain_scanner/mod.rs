@@ -2,7 +2,9 @@
 mod block_scanner;
 mod chain_reader_block_streamer;
 mod interface;
+mod rollback_handler;
 
 pub use block_scanner::*;
 pub use chain_reader_block_streamer::*;
 pub use interface::*;
+pub use rollback_handler::*;
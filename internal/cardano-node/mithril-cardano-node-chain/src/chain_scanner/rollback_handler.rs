@@ -0,0 +1,323 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+use mithril_common::StdResult;
+use mithril_common::entities::{BlockNumber, SlotNumber};
+
+use crate::chain_scanner::RollbackDepth;
+use crate::entities::ScannedBlock;
+
+/// Default security parameter of a [ChainRollbackHandler], 2160 is the mainnet value
+pub const DEFAULT_ROLLBACK_SECURITY_PARAMETER: BlockNumber = BlockNumber(2160);
+
+/// Policy applied when a rollback is deeper than the network security parameter
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RollbackPolicy {
+    /// Fail the import, the stored data is left untouched until an operator intervenes
+    Halt,
+    /// Log an alert and apply the rollback like any other rollback
+    #[default]
+    Alert,
+    /// Discard all the imported data and import the chain again from its origin
+    ReImport,
+}
+
+/// Records the rollbacks handled by a [ChainRollbackHandler]
+pub trait ChainRollbackMetricsRecorder: Sync + Send {
+    /// Record a rollback of the chain with its depth
+    fn record_rollback(&self, depth: RollbackDepth, exceeds_security_parameter: bool);
+}
+
+/// Resolution of a rollback by a [ChainRollbackHandler]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RollbackResolution {
+    /// Apply the rollback
+    Apply(RollbackDepth),
+    /// Apply the rollback and raise an alert as its depth exceeds the security parameter
+    ApplyWithAlert(RollbackDepth),
+    /// Import the chain again from its origin
+    ReImport(RollbackDepth),
+}
+
+/// Compute the depth of the rollbacks received by the block streamers and decide how they are
+/// handled when they exceed the network security parameter.
+///
+/// The handler keeps track of the slot and block numbers of the most recent polled blocks, it
+/// is meant to be shared by all the streamers of a single block scanner, each importer of the chain
+/// must use its own handler as they do not poll the same blocks.
+pub struct ChainRollbackHandler {
+    security_parameter: BlockNumber,
+    policy: RollbackPolicy,
+    recent_blocks: Mutex<VecDeque<(SlotNumber, BlockNumber)>>,
+    metrics_recorder: Option<Arc<dyn ChainRollbackMetricsRecorder>>,
+}
+
+impl ChainRollbackHandler {
+    /// Factory
+    pub fn new(security_parameter: BlockNumber, policy: RollbackPolicy) -> Self {
+        Self {
+            security_parameter,
+            policy,
+            recent_blocks: Mutex::new(VecDeque::new()),
+            metrics_recorder: None,
+        }
+    }
+
+    /// Set the recorder of the rollbacks metrics
+    pub fn with_metrics_recorder(
+        mut self,
+        metrics_recorder: Arc<dyn ChainRollbackMetricsRecorder>,
+    ) -> Self {
+        self.metrics_recorder = Some(metrics_recorder);
+        self
+    }
+
+    /// Track a block polled from the chain
+    pub(crate) fn record_roll_forward(&self, block: &ScannedBlock) {
+        let mut recent_blocks = self.recent_blocks.lock().unwrap();
+        recent_blocks.push_back((block.slot_number, block.block_number));
+        // One more block than the security parameter is kept to detect the rollbacks exceeding it
+        while recent_blocks.len() as u64 > *self.security_parameter + 1 {
+            recent_blocks.pop_front();
+        }
+    }
+
+    /// Forget the tracked blocks strictly above the given slot number, without handling a rollback
+    pub(crate) fn forget_blocks_after(&self, slot_number: SlotNumber) {
+        let mut recent_blocks = self.recent_blocks.lock().unwrap();
+        recent_blocks.retain(|(block_slot_number, _)| *block_slot_number <= slot_number);
+    }
+
+    /// Compute the depth of a rollback to the given slot number and decide how to handle it
+    ///
+    /// The tracked blocks are only rolled back if the rollback is applied, a halted rollback
+    /// leaves them untouched so that the same rollback is halted again if it is received again.
+    pub(crate) fn handle_rollback(&self, slot_number: SlotNumber) -> StdResult<RollbackResolution> {
+        let mut recent_blocks = self.recent_blocks.lock().unwrap();
+        let depth = Self::compute_rollback_depth(&recent_blocks, slot_number);
+        let exceeds_security_parameter = depth.exceeds(self.security_parameter);
+        if let Some(metrics_recorder) = &self.metrics_recorder {
+            metrics_recorder.record_rollback(depth, exceeds_security_parameter);
+        }
+
+        let resolution = if !exceeds_security_parameter {
+            RollbackResolution::Apply(depth)
+        } else {
+            match self.policy {
+                RollbackPolicy::Halt => {
+                    return Err(anyhow!(
+                        "Rollback to slot number '{slot_number}' with depth {depth:?} exceeds the security parameter '{}', import halted",
+                        self.security_parameter
+                    ));
+                }
+                RollbackPolicy::Alert => RollbackResolution::ApplyWithAlert(depth),
+                RollbackPolicy::ReImport => RollbackResolution::ReImport(depth),
+            }
+        };
+
+        match resolution {
+            RollbackResolution::ReImport(_) => recent_blocks.clear(),
+            _ => recent_blocks.retain(|(block_slot_number, _)| *block_slot_number <= slot_number),
+        }
+
+        Ok(resolution)
+    }
+
+    fn compute_rollback_depth(
+        recent_blocks: &VecDeque<(SlotNumber, BlockNumber)>,
+        slot_number: SlotNumber,
+    ) -> RollbackDepth {
+        let depth = recent_blocks
+            .iter()
+            .rev()
+            .take_while(|(block_slot_number, _)| *block_slot_number > slot_number)
+            .count() as u64;
+
+        if depth as usize == recent_blocks.len() {
+            RollbackDepth::AtLeast(depth)
+        } else {
+            RollbackDepth::Exact(depth)
+        }
+    }
+}
+
+impl Default for ChainRollbackHandler {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_ROLLBACK_SECURITY_PARAMETER,
+            RollbackPolicy::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scanned_block(slot_number: u64, block_number: u64) -> ScannedBlock {
+        ScannedBlock::new(
+            format!("hash-{block_number}"),
+            BlockNumber(block_number),
+            SlotNumber(slot_number),
+            Vec::<&str>::new(),
+        )
+    }
+
+    fn handler_with_blocks(
+        security_parameter: u64,
+        policy: RollbackPolicy,
+        blocks: &[(u64, u64)],
+    ) -> ChainRollbackHandler {
+        let handler = ChainRollbackHandler::new(BlockNumber(security_parameter), policy);
+        for (slot_number, block_number) in blocks {
+            handler.record_roll_forward(&scanned_block(*slot_number, *block_number));
+        }
+        handler
+    }
+
+    struct TestMetricsRecorder {
+        rollbacks: Mutex<Vec<(RollbackDepth, bool)>>,
+    }
+
+    impl ChainRollbackMetricsRecorder for TestMetricsRecorder {
+        fn record_rollback(&self, depth: RollbackDepth, exceeds_security_parameter: bool) {
+            self.rollbacks
+                .lock()
+                .unwrap()
+                .push((depth, exceeds_security_parameter));
+        }
+    }
+
+    #[test]
+    fn rollback_to_a_tracked_block_has_an_exact_depth() {
+        let handler = handler_with_blocks(
+            10,
+            RollbackPolicy::Halt,
+            &[(10, 1), (20, 2), (30, 3), (40, 4)],
+        );
+
+        let resolution = handler.handle_rollback(SlotNumber(20)).unwrap();
+
+        assert_eq!(
+            RollbackResolution::Apply(RollbackDepth::Exact(2)),
+            resolution
+        );
+    }
+
+    #[test]
+    fn rollback_before_the_tracked_blocks_has_a_lower_bound_depth() {
+        let handler = handler_with_blocks(10, RollbackPolicy::Halt, &[(10, 1), (20, 2)]);
+
+        let resolution = handler.handle_rollback(SlotNumber(5)).unwrap();
+
+        assert_eq!(
+            RollbackResolution::Apply(RollbackDepth::AtLeast(2)),
+            resolution
+        );
+    }
+
+    #[test]
+    fn rolled_back_blocks_are_not_tracked_anymore() {
+        let handler = handler_with_blocks(10, RollbackPolicy::Halt, &[(10, 1), (20, 2), (30, 3)]);
+        handler.handle_rollback(SlotNumber(20)).unwrap();
+        handler.record_roll_forward(&scanned_block(25, 3));
+
+        let resolution = handler.handle_rollback(SlotNumber(10)).unwrap();
+
+        assert_eq!(
+            RollbackResolution::Apply(RollbackDepth::Exact(2)),
+            resolution
+        );
+    }
+
+    #[test]
+    fn only_one_more_block_than_the_security_parameter_is_tracked() {
+        let handler = handler_with_blocks(
+            2,
+            RollbackPolicy::Alert,
+            &[(10, 1), (20, 2), (30, 3), (40, 4), (50, 5)],
+        );
+
+        let resolution = handler.handle_rollback(SlotNumber(10)).unwrap();
+
+        assert_eq!(
+            RollbackResolution::ApplyWithAlert(RollbackDepth::AtLeast(3)),
+            resolution
+        );
+    }
+
+    #[test]
+    fn forget_blocks_after_a_slot_number_does_not_count_as_a_rollback() {
+        let handler = handler_with_blocks(10, RollbackPolicy::Halt, &[(10, 1), (20, 2), (30, 3)]);
+        handler.forget_blocks_after(SlotNumber(20));
+
+        let resolution = handler.handle_rollback(SlotNumber(10)).unwrap();
+
+        assert_eq!(
+            RollbackResolution::Apply(RollbackDepth::Exact(1)),
+            resolution
+        );
+    }
+
+    #[test]
+    fn rollback_exceeding_security_parameter_with_halt_policy_fails() {
+        let handler = handler_with_blocks(1, RollbackPolicy::Halt, &[(10, 1), (20, 2), (30, 3)]);
+
+        handler
+            .handle_rollback(SlotNumber(10))
+            .expect_err("handle_rollback should fail");
+    }
+
+    #[test]
+    fn rollback_exceeding_security_parameter_with_halt_policy_fails_again_when_received_again() {
+        let handler = handler_with_blocks(1, RollbackPolicy::Halt, &[(10, 1), (20, 2), (30, 3)]);
+
+        handler
+            .handle_rollback(SlotNumber(10))
+            .expect_err("handle_rollback should fail");
+        handler
+            .handle_rollback(SlotNumber(10))
+            .expect_err("handle_rollback should fail again for the same rollback");
+    }
+
+    #[test]
+    fn rollback_exceeding_security_parameter_with_reimport_policy_forget_all_tracked_blocks() {
+        let handler =
+            handler_with_blocks(1, RollbackPolicy::ReImport, &[(10, 1), (20, 2), (30, 3)]);
+
+        let resolution = handler.handle_rollback(SlotNumber(10)).unwrap();
+        assert_eq!(
+            RollbackResolution::ReImport(RollbackDepth::AtLeast(2)),
+            resolution
+        );
+
+        let resolution = handler.handle_rollback(SlotNumber(5)).unwrap();
+        assert_eq!(
+            RollbackResolution::Apply(RollbackDepth::AtLeast(0)),
+            resolution
+        );
+    }
+
+    #[test]
+    fn rollbacks_are_recorded_in_metrics() {
+        let metrics_recorder = Arc::new(TestMetricsRecorder {
+            rollbacks: Mutex::new(vec![]),
+        });
+        let handler = handler_with_blocks(1, RollbackPolicy::Alert, &[(10, 1), (20, 2), (30, 3)])
+            .with_metrics_recorder(metrics_recorder.clone());
+
+        handler.handle_rollback(SlotNumber(20)).unwrap();
+        handler.record_roll_forward(&scanned_block(25, 3));
+        handler.record_roll_forward(&scanned_block(35, 4));
+        handler.handle_rollback(SlotNumber(5)).unwrap();
+
+        assert_eq!(
+            vec![(RollbackDepth::Exact(1), false), (RollbackDepth::AtLeast(2), true)],
+            *metrics_recorder.rollbacks.lock().unwrap()
+        );
+    }
+}
@@ -1,8 +1,12 @@
-use pallas_traverse::MultiEraBlock;
+use pallas_codec::minicbor::{self, Decoder, data::Type};
+use pallas_codec::utils::{KeepRaw, Nullable};
+use pallas_crypto::hash::Hasher;
+use pallas_traverse::{MultiEraBlock, MultiEraTx};
 use std::fmt::{Debug, Formatter};
 
 use mithril_common::entities::{
-    BlockNumber, CardanoTransaction, ChainPoint, SlotNumber, TransactionHash,
+    BlockNumber, CardanoBlock, CardanoTransaction, CardanoTransactionMetadata, ChainPoint,
+    MetadataHash, MetadataLabel, SlotNumber, TransactionHash,
 };
 
 /// A block scanned from a Cardano database
@@ -16,6 +20,10 @@ pub struct ScannedBlock {
     pub slot_number: SlotNumber,
     /// Hashes of the transactions in the block
     pub transactions_hashes: Vec<TransactionHash>,
+    /// Metadata labels carried by the transactions in the block, with the hash of their metadatum
+    ///
+    /// Only filled when the metadata capture is enabled on the chain reader.
+    pub transactions_metadata: Vec<(TransactionHash, MetadataLabel, MetadataHash)>,
 }
 
 impl ScannedBlock {
@@ -31,13 +39,35 @@ impl ScannedBlock {
             block_number,
             slot_number,
             transactions_hashes: transaction_hashes.into_iter().map(|h| h.into()).collect(),
+            transactions_metadata: Vec::new(),
         }
     }
 
-    pub(crate) fn convert(multi_era_block: MultiEraBlock) -> Self {
+    /// Set the metadata labels carried by the transactions in the block
+    pub fn with_transactions_metadata<T: Into<TransactionHash>, H: Into<MetadataHash>>(
+        mut self,
+        transactions_metadata: Vec<(T, MetadataLabel, H)>,
+    ) -> Self {
+        self.transactions_metadata = transactions_metadata
+            .into_iter()
+            .map(|(tx_hash, label, metadata_hash)| (tx_hash.into(), label, metadata_hash.into()))
+            .collect();
+        self
+    }
+
+    pub(crate) fn convert(multi_era_block: MultiEraBlock, capture_metadata: bool) -> Self {
         let mut transactions = Vec::new();
+        let mut transactions_metadata = Vec::new();
         for tx in &multi_era_block.txs() {
-            transactions.push(tx.hash().to_string());
+            let tx_hash = tx.hash().to_string();
+            if capture_metadata {
+                transactions_metadata.extend(
+                    Self::hash_transaction_metadata(tx)
+                        .into_iter()
+                        .map(|(label, metadata_hash)| (tx_hash.clone(), label, metadata_hash)),
+                );
+            }
+            transactions.push(tx_hash);
         }
 
         Self::new(
@@ -46,6 +76,108 @@ impl ScannedBlock {
             SlotNumber(multi_era_block.slot()),
             transactions,
         )
+        .with_transactions_metadata(transactions_metadata)
+    }
+
+    /// Compute the Blake2b-256 hash of the on-chain CBOR of each metadatum of the transaction,
+    /// keyed by their label.
+    ///
+    /// The raw bytes of the auxiliary data are hashed rather than a re-encoding of the decoded
+    /// metadata, which may differ from what was signed on-chain (map ordering, indefinite
+    /// lengths, integer widths...).
+    fn hash_transaction_metadata(tx: &MultiEraTx) -> Vec<(MetadataLabel, MetadataHash)> {
+        let raw_auxiliary_data = match tx {
+            MultiEraTx::AlonzoCompatible(tx, _) => Self::raw_cbor(&tx.auxiliary_data),
+            MultiEraTx::Babbage(tx) => Self::raw_cbor(&tx.auxiliary_data),
+            MultiEraTx::Conway(tx) => Self::raw_cbor(&tx.auxiliary_data),
+            _ => None,
+        };
+
+        raw_auxiliary_data
+            // The auxiliary data were already decoded by pallas, a decoding failure can't happen
+            // but the metadata of the transaction are skipped rather than panicking if it ever does
+            .and_then(|raw| Self::split_raw_metadata(raw).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(label, raw_metadatum)| (label, Hasher::<256>::hash(raw_metadatum).to_string()))
+            .collect()
+    }
+
+    fn raw_cbor<'b, T: Clone>(auxiliary_data: &Nullable<KeepRaw<'b, T>>) -> Option<&'b [u8]> {
+        match auxiliary_data {
+            Nullable::Some(auxiliary_data) => Some(auxiliary_data.raw_cbor()),
+            Nullable::Null | Nullable::Undefined => None,
+        }
+    }
+
+    /// Split the raw CBOR of the auxiliary data of a transaction into the raw CBOR of each of
+    /// its metadatum, keyed by their label.
+    fn split_raw_metadata(
+        raw_auxiliary_data: &[u8],
+    ) -> Result<Vec<(MetadataLabel, &[u8])>, minicbor::decode::Error> {
+        let mut decoder = Decoder::new(raw_auxiliary_data);
+        let mut metadata = Vec::new();
+
+        match decoder.datatype()? {
+            // Shelley: the auxiliary data are the metadata
+            Type::Map | Type::MapIndef => {
+                Self::read_raw_metadata(&mut decoder, &mut metadata)?;
+            }
+            // Shelley-MA: [ metadata, auxiliary scripts ]
+            Type::Array | Type::ArrayIndef => {
+                decoder.array()?;
+                Self::read_raw_metadata(&mut decoder, &mut metadata)?;
+            }
+            // Alonzo and later: #6.259({ ? 0 => metadata, ... })
+            _ => {
+                decoder.tag()?;
+                Self::for_each_map_entry(&mut decoder, |decoder| match decoder.u64()? {
+                    0 => Self::read_raw_metadata(decoder, &mut metadata),
+                    _ => decoder.skip(),
+                })?;
+            }
+        }
+
+        Ok(metadata)
+    }
+
+    fn read_raw_metadata<'b>(
+        decoder: &mut Decoder<'b>,
+        metadata: &mut Vec<(MetadataLabel, &'b [u8])>,
+    ) -> Result<(), minicbor::decode::Error> {
+        let input = decoder.input();
+        Self::for_each_map_entry(decoder, |decoder| {
+            let label = decoder.u64()?;
+            let start = decoder.position();
+            decoder.skip()?;
+            metadata.push((label, &input[start..decoder.position()]));
+            Ok(())
+        })
+    }
+
+    fn for_each_map_entry<'b, F>(
+        decoder: &mut Decoder<'b>,
+        mut read_entry: F,
+    ) -> Result<(), minicbor::decode::Error>
+    where
+        F: FnMut(&mut Decoder<'b>) -> Result<(), minicbor::decode::Error>,
+    {
+        match decoder.map()? {
+            Some(length) => {
+                for _ in 0..length {
+                    read_entry(decoder)?;
+                }
+            }
+            None => {
+                while decoder.datatype()? != Type::Break {
+                    read_entry(decoder)?;
+                }
+                // Consume the break byte ending the indefinite map
+                decoder.set_position(decoder.position() + 1);
+            }
+        }
+
+        Ok(())
     }
 
     /// Number of transactions in the block
@@ -70,6 +202,24 @@ impl ScannedBlock {
             })
             .collect::<Vec<_>>()
     }
+
+    /// Convert the metadata labels carried by the transactions of the scanned block into a list
+    /// of Cardano transactions metadata.
+    pub fn transactions_metadata(&self) -> Vec<CardanoTransactionMetadata> {
+        self.transactions_metadata
+            .iter()
+            .map(|(transaction_hash, label, metadata_hash)| {
+                CardanoTransactionMetadata::new(
+                    transaction_hash.clone(),
+                    *label,
+                    metadata_hash.clone(),
+                    self.block_number,
+                    self.slot_number,
+                    hex::encode(&self.block_hash),
+                )
+            })
+            .collect()
+    }
 }
 
 impl Debug for ScannedBlock {
@@ -80,6 +230,7 @@ impl Debug for ScannedBlock {
             .field("block_number", &self.block_number)
             .field("slot_number", &self.slot_number)
             .field("transactions_hashes", &self.transactions_hashes)
+            .field("transactions_metadata", &self.transactions_metadata)
             .finish()
     }
 }
@@ -93,3 +244,122 @@ impl From<&ScannedBlock> for ChainPoint {
         )
     }
 }
+
+impl From<&ScannedBlock> for CardanoBlock {
+    fn from(scanned_block: &ScannedBlock) -> Self {
+        CardanoBlock::new(
+            hex::encode(&scanned_block.block_hash),
+            scanned_block.block_number,
+            scanned_block.slot_number,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_transactions_metadata_of_the_block() {
+        let scanned_block = ScannedBlock::new(
+            "block_hash-1",
+            BlockNumber(10),
+            SlotNumber(100),
+            vec!["tx_hash-1", "tx_hash-2"],
+        )
+        .with_transactions_metadata(vec![
+            ("tx_hash-1", 674, "metadata_hash-1"),
+            ("tx_hash-1", 721, "metadata_hash-2"),
+        ]);
+
+        assert_eq!(
+            vec![
+                CardanoTransactionMetadata::new(
+                    "tx_hash-1",
+                    674,
+                    "metadata_hash-1",
+                    BlockNumber(10),
+                    SlotNumber(100),
+                    hex::encode("block_hash-1")
+                ),
+                CardanoTransactionMetadata::new(
+                    "tx_hash-1",
+                    721,
+                    "metadata_hash-2",
+                    BlockNumber(10),
+                    SlotNumber(100),
+                    hex::encode("block_hash-1")
+                ),
+            ],
+            scanned_block.transactions_metadata()
+        );
+    }
+
+    #[test]
+    fn scanned_block_without_metadata_capture_has_no_transactions_metadata() {
+        let scanned_block = ScannedBlock::new(
+            "block_hash-1",
+            BlockNumber(10),
+            SlotNumber(100),
+            vec!["tx_hash-1"],
+        );
+
+        assert!(scanned_block.transactions_metadata().is_empty());
+    }
+
+    mod split_raw_metadata {
+        use super::*;
+
+        #[test]
+        fn split_shelley_auxiliary_data() {
+            // { 674: "ab", 721: 1 } with `1` in a non minimal encoding
+            let raw_auxiliary_data = hex::decode("a21902a26261621902d11801").unwrap();
+
+            let metadata = ScannedBlock::split_raw_metadata(&raw_auxiliary_data).unwrap();
+
+            assert_eq!(
+                vec![
+                    (674, hex::decode("626162").unwrap().as_slice()),
+                    (721, hex::decode("1801").unwrap().as_slice()),
+                ],
+                metadata
+            );
+        }
+
+        #[test]
+        fn split_shelley_ma_auxiliary_data() {
+            // [ { 674: "ab" }, [] ]
+            let raw_auxiliary_data = hex::decode("82a11902a262616280").unwrap();
+
+            let metadata = ScannedBlock::split_raw_metadata(&raw_auxiliary_data).unwrap();
+
+            assert_eq!(
+                vec![(674, hex::decode("626162").unwrap().as_slice())],
+                metadata
+            );
+        }
+
+        #[test]
+        fn split_alonzo_auxiliary_data_with_indefinite_maps() {
+            // #6.259({_ 1: [], 0: {_ 674: "ab" } })
+            let raw_auxiliary_data = hex::decode("d90103bf018000bf1902a2626162ffff").unwrap();
+
+            let metadata = ScannedBlock::split_raw_metadata(&raw_auxiliary_data).unwrap();
+
+            assert_eq!(
+                vec![(674, hex::decode("626162").unwrap().as_slice())],
+                metadata
+            );
+        }
+
+        #[test]
+        fn split_alonzo_auxiliary_data_without_metadata() {
+            // #6.259({ 1: [] })
+            let raw_auxiliary_data = hex::decode("d90103a10180").unwrap();
+
+            let metadata = ScannedBlock::split_raw_metadata(&raw_auxiliary_data).unwrap();
+
+            assert!(metadata.is_empty());
+        }
+    }
+}
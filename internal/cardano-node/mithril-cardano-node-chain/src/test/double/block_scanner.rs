@@ -6,7 +6,7 @@ use async_trait::async_trait;
 use mithril_common::StdResult;
 use mithril_common::entities::{BlockNumber, ChainPoint};
 
-use crate::chain_scanner::{BlockScanner, BlockStreamer, ChainScannedBlocks};
+use crate::chain_scanner::{BlockScanner, BlockStreamer, ChainScannedBlocks, RollbackDepth};
 use crate::entities::{RawCardanoPoint, ScannedBlock};
 
 /// Dumb block scanner
@@ -112,9 +112,23 @@ impl DumbBlockStreamer {
     }
 
     /// Add to the streamer a [ChainScannedBlocks::RollBackward] response at the end of its queue.
+    ///
+    /// The depth of the rollback is the number of queued blocks above the given chain point.
     pub fn rollback(mut self, chain_point: ChainPoint) -> Self {
-        self.streamer_responses
-            .push_back(ChainScannedBlocks::RollBackward(chain_point.slot_number));
+        let depth = self
+            .streamer_responses
+            .iter()
+            .filter_map(|response| match response {
+                ChainScannedBlocks::RollForwards(blocks) => Some(blocks),
+                ChainScannedBlocks::RollBackward { .. } => None,
+            })
+            .flatten()
+            .filter(|block| block.slot_number > chain_point.slot_number)
+            .count();
+        self.streamer_responses.push_back(ChainScannedBlocks::RollBackward {
+            slot_number: chain_point.slot_number,
+            depth: RollbackDepth::Exact(depth as u64),
+        });
         self
     }
 }
@@ -259,9 +273,10 @@ mod tests {
         let blocks = streamer.poll_next().await.unwrap();
         assert_eq!(
             blocks,
-            Some(ChainScannedBlocks::RollBackward(
-                expected_chain_point.slot_number
-            ))
+            Some(ChainScannedBlocks::RollBackward {
+                slot_number: expected_chain_point.slot_number,
+                depth: RollbackDepth::Exact(0),
+            })
         );
     }
 
@@ -303,9 +318,10 @@ mod tests {
         let blocks = streamer.poll_next().await.unwrap();
         assert_eq!(
             blocks,
-            Some(ChainScannedBlocks::RollBackward(
-                expected_chain_point.slot_number
-            ))
+            Some(ChainScannedBlocks::RollBackward {
+                slot_number: expected_chain_point.slot_number,
+                depth: RollbackDepth::Exact(1),
+            })
         );
 
         let blocks = streamer.poll_next().await.unwrap();
@@ -0,0 +1,250 @@
+use async_trait::async_trait;
+use slog::{Logger, warn};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileName};
+use mithril_common::logging::LoggerExtensions;
+
+use crate::digesters::cache::{CacheProviderResult, ImmutableFileDigestCacheProvider};
+use crate::entities::ImmutableFile;
+
+/// An [ImmutableFileDigestCacheProvider] that layers a local provider over a provider shared with
+/// other processes.
+///
+/// The shared provider is consulted first since it keys the digests by the immutable files
+/// versions, its values are copied to the local provider and new digests are stored in both.
+/// A digest missing from the shared provider is reported as missing even if the local provider
+/// knows it, as the local value may belong to a previous version of a rewritten immutable file.
+/// Failures of the shared provider are logged and the values of the local provider are used
+/// instead.
+pub struct LayeredImmutableFileDigestCacheProvider {
+    local: Arc<dyn ImmutableFileDigestCacheProvider>,
+    shared: Arc<dyn ImmutableFileDigestCacheProvider>,
+    logger: Logger,
+}
+
+impl LayeredImmutableFileDigestCacheProvider {
+    /// [LayeredImmutableFileDigestCacheProvider] factory
+    pub fn new(
+        local: Arc<dyn ImmutableFileDigestCacheProvider>,
+        shared: Arc<dyn ImmutableFileDigestCacheProvider>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            local,
+            shared,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+}
+
+#[async_trait]
+impl ImmutableFileDigestCacheProvider for LayeredImmutableFileDigestCacheProvider {
+    async fn store(
+        &self,
+        digest_per_filenames: Vec<(ImmutableFileName, HexEncodedDigest)>,
+    ) -> CacheProviderResult<()> {
+        if let Err(error) = self.shared.store(digest_per_filenames.clone()).await {
+            warn!(
+                self.logger, "Error while storing immutable files digests to the shared cache";
+                "error" => ?error
+            );
+        }
+
+        self.local.store(digest_per_filenames).await
+    }
+
+    async fn get(
+        &self,
+        immutables: Vec<ImmutableFile>,
+    ) -> CacheProviderResult<BTreeMap<ImmutableFile, Option<HexEncodedDigest>>> {
+        let shared_values = match self.shared.get(immutables.clone()).await {
+            Ok(values) => values,
+            Err(error) => {
+                warn!(
+                    self.logger, "Error while getting immutable files digests from the shared cache";
+                    "error" => ?error
+                );
+                return self.local.get(immutables).await;
+            }
+        };
+
+        let local_values = self.local.get(immutables).await?;
+        let digests_to_copy: Vec<(ImmutableFileName, HexEncodedDigest)> = shared_values
+            .iter()
+            .filter_map(|(immutable, digest)| {
+                let digest = digest.as_ref()?;
+                (local_values.get(immutable).and_then(Option::as_ref) != Some(digest))
+                    .then(|| (immutable.filename.clone(), digest.clone()))
+            })
+            .collect();
+        if !digests_to_copy.is_empty() {
+            self.local.store(digests_to_copy).await?;
+        }
+
+        Ok(shared_values)
+    }
+
+    /// Only the local provider is reset: the shared provider values are owned by all the
+    /// processes using it.
+    async fn reset(&self) -> CacheProviderResult<()> {
+        self.local.reset().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use crate::digesters::cache::{
+        ImmutableDigesterCacheGetError, ImmutableDigesterCacheStoreError,
+        MemoryImmutableFileDigestCacheProvider, MockImmutableFileDigestCacheProvider,
+    };
+    use crate::test::{TestLogger, fake_data};
+
+    use super::*;
+
+    fn memory_provider(values: &[(&str, &str)]) -> Arc<MemoryImmutableFileDigestCacheProvider> {
+        Arc::new(MemoryImmutableFileDigestCacheProvider::from(
+            HashMap::from_iter(
+                values
+                    .iter()
+                    .map(|(name, digest)| (name.to_string(), digest.to_string())),
+            ),
+        ))
+    }
+
+    fn immutables(filenames: &[&str]) -> Vec<ImmutableFile> {
+        filenames
+            .iter()
+            .enumerate()
+            .map(|(number, filename)| {
+                fake_data::immutable_file(PathBuf::default(), number as u64, *filename)
+            })
+            .collect()
+    }
+
+    fn failing_shared_provider() -> Arc<MockImmutableFileDigestCacheProvider> {
+        let mut provider = MockImmutableFileDigestCacheProvider::new();
+        provider.expect_get().returning(|_| {
+            Err(ImmutableDigesterCacheGetError::StoreError(anyhow::anyhow!("failure")).into())
+        });
+        provider.expect_store().returning(|_| {
+            Err(ImmutableDigesterCacheStoreError::StoreError(anyhow::anyhow!("failure")).into())
+        });
+
+        Arc::new(provider)
+    }
+
+    #[tokio::test]
+    async fn get_values_from_shared_provider_and_copy_them_locally() {
+        let local = memory_provider(&[("0.chunk", "stale digest 0")]);
+        let shared = memory_provider(&[("0.chunk", "digest 0"), ("1.chunk", "digest 1")]);
+        let provider = LayeredImmutableFileDigestCacheProvider::new(
+            local.clone(),
+            shared,
+            TestLogger::stdout(),
+        );
+        let immutables = immutables(&["0.chunk", "1.chunk", "2.chunk"]);
+
+        let result = provider.get(immutables.clone()).await.unwrap();
+
+        assert_eq!(
+            BTreeMap::from([
+                (immutables[0].clone(), Some("digest 0".to_string())),
+                (immutables[1].clone(), Some("digest 1".to_string())),
+                (immutables[2].clone(), None),
+            ]),
+            result
+        );
+        assert_eq!(result, local.get(immutables).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn get_does_not_return_local_values_missing_from_shared_provider() {
+        let local = memory_provider(&[("0.chunk", "stale digest 0")]);
+        let shared = memory_provider(&[]);
+        let provider =
+            LayeredImmutableFileDigestCacheProvider::new(local, shared, TestLogger::stdout());
+        let immutables = immutables(&["0.chunk"]);
+
+        let result = provider.get(immutables.clone()).await.unwrap();
+
+        assert_eq!(BTreeMap::from([(immutables[0].clone(), None)]), result);
+    }
+
+    #[tokio::test]
+    async fn reset_does_not_reset_shared_provider() {
+        let local = memory_provider(&[("0.chunk", "digest 0")]);
+        let shared = memory_provider(&[("0.chunk", "digest 0")]);
+        let provider = LayeredImmutableFileDigestCacheProvider::new(
+            local.clone(),
+            shared.clone(),
+            TestLogger::stdout(),
+        );
+        let immutables = immutables(&["0.chunk"]);
+
+        provider.reset().await.unwrap();
+
+        assert_eq!(
+            BTreeMap::from([(immutables[0].clone(), None)]),
+            local.get(immutables.clone()).await.unwrap()
+        );
+        assert_eq!(
+            BTreeMap::from([(immutables[0].clone(), Some("digest 0".to_string()))]),
+            shared.get(immutables).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn store_values_in_both_providers() {
+        let local = memory_provider(&[]);
+        let shared = memory_provider(&[]);
+        let provider = LayeredImmutableFileDigestCacheProvider::new(
+            local.clone(),
+            shared.clone(),
+            TestLogger::stdout(),
+        );
+        let immutables = immutables(&["0.chunk"]);
+
+        provider
+            .store(vec![("0.chunk".to_string(), "digest 0".to_string())])
+            .await
+            .unwrap();
+
+        let expected = BTreeMap::from([(immutables[0].clone(), Some("digest 0".to_string()))]);
+        assert_eq!(expected, local.get(immutables.clone()).await.unwrap());
+        assert_eq!(expected, shared.get(immutables).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn shared_provider_failures_do_not_prevent_using_the_local_provider() {
+        let local = memory_provider(&[("0.chunk", "digest 0")]);
+        let provider = LayeredImmutableFileDigestCacheProvider::new(
+            local.clone(),
+            failing_shared_provider(),
+            TestLogger::stdout(),
+        );
+        let immutables = immutables(&["0.chunk", "1.chunk"]);
+
+        let result = provider.get(immutables.clone()).await.unwrap();
+        assert_eq!(
+            BTreeMap::from([
+                (immutables[0].clone(), Some("digest 0".to_string())),
+                (immutables[1].clone(), None),
+            ]),
+            result
+        );
+
+        provider
+            .store(vec![("1.chunk".to_string(), "digest 1".to_string())])
+            .await
+            .unwrap();
+        assert_eq!(
+            Some("digest 1".to_string()),
+            local.get(immutables.clone()).await.unwrap()[&immutables[1]]
+        );
+    }
+}
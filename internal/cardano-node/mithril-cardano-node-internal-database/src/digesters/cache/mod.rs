@@ -3,11 +3,13 @@
 
 mod json_provider;
 mod json_provider_builder;
+mod layered_provider;
 mod memory_provider;
 mod provider;
 
 pub use json_provider::JsonImmutableFileDigestCacheProvider;
 pub use json_provider_builder::JsonImmutableFileDigestCacheProviderBuilder;
+pub use layered_provider::LayeredImmutableFileDigestCacheProvider;
 pub use memory_provider::MemoryImmutableFileDigestCacheProvider;
 #[cfg(test)]
 pub use provider::MockImmutableFileDigestCacheProvider;
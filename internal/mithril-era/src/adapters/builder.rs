@@ -11,7 +11,7 @@ use crate::{
     EraMarker, EraReaderAdapter,
     adapters::{
         EraReaderBootstrapAdapter, EraReaderCardanoChainAdapter, EraReaderDummyAdapter,
-        EraReaderFileAdapter,
+        EraReaderFileAdapter, EraReaderHttpAdapter,
     },
 };
 
@@ -22,6 +22,8 @@ pub enum AdapterType {
     /// Cardano chain adapter.
     #[serde(rename = "cardano-chain")]
     CardanoChain,
+    /// HTTP adapter.
+    Http,
     /// File adapter.
     File,
     /// Dummy adapter.
@@ -37,6 +39,7 @@ impl Display for AdapterType {
             Self::CardanoChain => write!(f, "cardano chain"),
             Self::Dummy => write!(f, "dummy"),
             Self::File => write!(f, "file"),
+            Self::Http => write!(f, "http"),
         }
     }
 }
@@ -98,6 +101,25 @@ impl AdapterBuilder {
                     adapter_config.verification_key,
                 )))
             }
+            AdapterType::Http => {
+                #[derive(Deserialize)]
+                struct EraReaderHttpAdapterConfig {
+                    url: String,
+                    verification_key: EraMarkersVerifierVerificationKey,
+                }
+
+                let adapter_config: EraReaderHttpAdapterConfig = serde_json::from_str(
+                    self.adapter_params
+                        .as_ref()
+                        .ok_or_else(AdapterBuilderError::MissingParameters)?,
+                )
+                .map_err(AdapterBuilderError::ParseParameters)?;
+
+                Ok(Arc::new(EraReaderHttpAdapter::new(
+                    &adapter_config.url,
+                    adapter_config.verification_key,
+                )))
+            }
             AdapterType::File => {
                 #[derive(Deserialize)]
                 struct EraReaderFileAdapterConfig {
@@ -156,4 +178,19 @@ mod test {
         .build(Arc::new(FakeChainObserver::new(None)))
         .expect("building an cardano chain era reader with golden params should not fail");
     }
+
+    #[test]
+    fn golden_test_for_http() {
+        const GOLDEN_HTTP_ADAPTER_PARAMS: &str = r#"{
+            "url":"https://example.com/era-markers.json",
+            "verification_key":"5b35352c3232382c3134342c38372c3133382c3133362c34382c382c31342c3138372c38352c3134382c39372c3233322c3235352c3232392c33382c3234342c3234372c3230342c3139382c31332c33312c3232322c32352c3136342c35322c3130322c39312c3132302c3230382c3134375d"
+        }"#;
+
+        AdapterBuilder::new(
+            &AdapterType::Http,
+            &Some(GOLDEN_HTTP_ADAPTER_PARAMS.to_owned()),
+        )
+        .build(Arc::new(FakeChainObserver::new(None)))
+        .expect("building an http era reader with golden params should not fail");
+    }
 }
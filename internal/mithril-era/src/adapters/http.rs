@@ -0,0 +1,151 @@
+use anyhow::Context;
+use async_trait::async_trait;
+
+use mithril_common::StdResult;
+use mithril_common::crypto_helper::EraMarkersVerifierVerificationKey;
+
+use crate::{EraMarker, EraReaderAdapter};
+
+use super::cardano_chain::EraMarkersPayload;
+
+/// HTTP adapter retrieves era markers from a signed payload published at an URL
+///
+/// The payload is the JSON serialization of an [EraMarkersPayload], its signature is verified
+/// with the same verification key than the one used by the Cardano chain adapter.
+pub struct HttpAdapter {
+    url: String,
+    verification_key: EraMarkersVerifierVerificationKey,
+    http_client: reqwest::Client,
+}
+
+impl HttpAdapter {
+    /// HttpAdapter factory
+    pub fn new(url: &str, verification_key: EraMarkersVerifierVerificationKey) -> Self {
+        Self {
+            url: url.to_string(),
+            verification_key,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    async fn fetch_payload(&self) -> StdResult<EraMarkersPayload> {
+        let response = self
+            .http_client
+            .get(&self.url)
+            .send()
+            .await
+            .with_context(|| format!("could not fetch era markers from '{}'", self.url))?
+            .error_for_status()
+            .with_context(|| format!("could not fetch era markers from '{}'", self.url))?;
+
+        response
+            .json::<EraMarkersPayload>()
+            .await
+            .with_context(|| format!("could not parse era markers payload from '{}'", self.url))
+    }
+}
+
+#[async_trait]
+impl EraReaderAdapter for HttpAdapter {
+    async fn read(&self) -> StdResult<Vec<EraMarker>> {
+        let era_markers_payload = self.fetch_payload().await?;
+        era_markers_payload
+            .verify_signature(self.verification_key)
+            .with_context(|| format!("invalid era markers payload fetched from '{}'", self.url))?;
+
+        Ok(era_markers_payload.markers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::MockServer;
+
+    use mithril_common::crypto_helper::EraMarkersSigner;
+    use mithril_common::entities::Epoch;
+
+    use super::*;
+
+    fn signed_payload(signer: &EraMarkersSigner) -> EraMarkersPayload {
+        EraMarkersPayload {
+            markers: vec![
+                EraMarker::new("thales", Some(Epoch(1))),
+                EraMarker::new("pythagoras", Some(Epoch(2))),
+            ],
+            signature: None,
+        }
+        .sign(signer)
+        .unwrap()
+    }
+
+    fn serve_payload(payload: &EraMarkersPayload) -> MockServer {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.path("/era-markers.json");
+            then.status(200).body(serde_json::to_string(payload).unwrap());
+        });
+        server
+    }
+
+    #[tokio::test]
+    async fn read_markers_with_valid_signature() {
+        let era_markers_signer = EraMarkersSigner::create_deterministic_signer();
+        let payload = signed_payload(&era_markers_signer);
+        let server = serve_payload(&payload);
+        let adapter = HttpAdapter::new(
+            &server.url("/era-markers.json"),
+            era_markers_signer.create_verifier().to_verification_key(),
+        );
+
+        let markers = adapter.read().await.expect("HttpAdapter read should not fail");
+
+        assert_eq!(payload.markers, markers);
+    }
+
+    #[tokio::test]
+    async fn read_fails_if_payload_is_signed_with_another_key() {
+        let payload = signed_payload(&EraMarkersSigner::create_non_deterministic_signer());
+        let server = serve_payload(&payload);
+        let adapter = HttpAdapter::new(
+            &server.url("/era-markers.json"),
+            EraMarkersSigner::create_deterministic_signer()
+                .create_verifier()
+                .to_verification_key(),
+        );
+
+        adapter.read().await.expect_err("HttpAdapter read should fail");
+    }
+
+    #[tokio::test]
+    async fn read_fails_if_payload_is_not_signed() {
+        let era_markers_signer = EraMarkersSigner::create_deterministic_signer();
+        let payload = EraMarkersPayload {
+            signature: None,
+            ..signed_payload(&era_markers_signer)
+        };
+        let server = serve_payload(&payload);
+        let adapter = HttpAdapter::new(
+            &server.url("/era-markers.json"),
+            era_markers_signer.create_verifier().to_verification_key(),
+        );
+
+        adapter.read().await.expect_err("HttpAdapter read should fail");
+    }
+
+    #[tokio::test]
+    async fn read_fails_if_server_returns_an_error() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.path("/era-markers.json");
+            then.status(404);
+        });
+        let adapter = HttpAdapter::new(
+            &server.url("/era-markers.json"),
+            EraMarkersSigner::create_deterministic_signer()
+                .create_verifier()
+                .to_verification_key(),
+        );
+
+        adapter.read().await.expect_err("HttpAdapter read should fail");
+    }
+}
@@ -4,6 +4,7 @@ mod builder;
 mod cardano_chain;
 mod dummy;
 mod file;
+mod http;
 
 pub use bootstrap::BootstrapAdapter as EraReaderBootstrapAdapter;
 pub use builder::{AdapterBuilder as EraReaderAdapterBuilder, AdapterType as EraReaderAdapterType};
@@ -13,3 +14,4 @@ pub use cardano_chain::{
 };
 pub use dummy::DummyAdapter as EraReaderDummyAdapter;
 pub use file::FileAdapter as EraReaderFileAdapter;
+pub use http::HttpAdapter as EraReaderHttpAdapter;
@@ -0,0 +1,249 @@
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+use mithril_common::entities::{Epoch, EraChanges, SupportedEra};
+
+use crate::{EraEpochToken, EraMarker};
+
+/// Report of the readiness of the running software for the upcoming era transition.
+///
+/// It is built from an [EraEpochToken] and the list of the eras supported by this binary, it
+/// allows operators to check if their node must be upgraded before the transition happens.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EraReadinessReport {
+    /// Epoch at which the report was built
+    pub current_epoch: Epoch,
+
+    /// Marker of the current era
+    pub current_era: EraMarker,
+
+    /// Is the current era supported by this binary
+    pub is_current_era_supported: bool,
+
+    /// Marker of the next era, if any
+    pub next_era: Option<EraMarker>,
+
+    /// Number of epochs until the transition to the next era, if it is scheduled
+    pub epochs_until_transition: Option<u64>,
+
+    /// Is the next era supported by this binary, if there is a next era
+    pub is_next_era_supported: Option<bool>,
+
+    /// Changes introduced by the next era, if it is supported by this binary
+    pub next_era_changes: Option<EraChanges>,
+
+    /// Eras supported by this binary
+    pub supported_eras: Vec<SupportedEra>,
+}
+
+impl EraReadinessReport {
+    /// Build the report from an [EraEpochToken] using the eras supported by this binary
+    pub fn from_era_epoch_token(token: &EraEpochToken) -> Self {
+        let current_epoch = token.get_current_epoch();
+        let next_era = token.get_next_era_marker().cloned();
+        let next_supported_era = token.get_next_supported_era().ok().flatten();
+
+        Self {
+            current_epoch,
+            current_era: token.get_current_era_marker().clone(),
+            is_current_era_supported: token.get_current_supported_era().is_ok(),
+            epochs_until_transition: next_era
+                .as_ref()
+                .and_then(|marker| marker.epoch)
+                .map(|epoch| epoch.saturating_sub(*current_epoch)),
+            is_next_era_supported: next_era.as_ref().map(|_| next_supported_era.is_some()),
+            next_era_changes: next_supported_era.map(|era| era.changes()),
+            next_era,
+            supported_eras: SupportedEra::eras(),
+        }
+    }
+
+    /// Check if this binary supports both the current and the next era
+    pub fn is_ready(&self) -> bool {
+        self.is_current_era_supported && self.is_next_era_supported != Some(false)
+    }
+}
+
+fn format_supported(is_supported: bool) -> &'static str {
+    if is_supported {
+        "supported"
+    } else {
+        "NOT supported, upgrade required"
+    }
+}
+
+fn format_signed_entity_types(changes: &EraChanges) -> Vec<String> {
+    let mut lines = vec![];
+    if !changes.added_signed_entity_types.is_empty() {
+        lines.push(format!(
+            "signed entity types added: {}",
+            changes
+                .added_signed_entity_types
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if !changes.removed_signed_entity_types.is_empty() {
+        lines.push(format!(
+            "signed entity types removed: {}",
+            changes
+                .removed_signed_entity_types
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    lines
+}
+
+impl Display for EraReadinessReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Current epoch: {}", self.current_epoch)?;
+        writeln!(
+            f,
+            "Current era: {} ({})",
+            self.current_era.name,
+            format_supported(self.is_current_era_supported)
+        )?;
+
+        match (&self.next_era, self.is_next_era_supported) {
+            (Some(next_era), Some(is_supported)) => {
+                let transition = match (next_era.epoch, self.epochs_until_transition) {
+                    (Some(epoch), Some(epochs)) => {
+                        format!("at epoch {epoch}, in {epochs} epoch(s)")
+                    }
+                    _ => "not scheduled yet".to_string(),
+                };
+                writeln!(
+                    f,
+                    "Next era: {} {transition} ({})",
+                    next_era.name,
+                    format_supported(is_supported)
+                )?;
+            }
+            _ => writeln!(f, "Next era: none announced")?,
+        }
+
+        writeln!(
+            f,
+            "Supported eras: {}",
+            self.supported_eras
+                .iter()
+                .map(|era| era.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+
+        if let Some(changes) = &self.next_era_changes {
+            if changes.is_empty() {
+                writeln!(f, "Changes introduced by the next era: none")?;
+            } else {
+                writeln!(f, "Changes introduced by the next era:")?;
+                for line in format_signed_entity_types(changes)
+                    .iter()
+                    .chain(changes.behaviors.iter())
+                {
+                    writeln!(f, "  - {line}")?;
+                }
+            }
+        }
+
+        write!(
+            f,
+            "Ready for the era transition: {}",
+            if self.is_ready() { "yes" } else { "no" }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::double::Dummy;
+
+    use super::*;
+
+    #[test]
+    fn report_without_next_era() {
+        let token = EraEpochToken::new(
+            Epoch(10),
+            EraMarker::new(&SupportedEra::dummy().to_string(), Some(Epoch(1))),
+            None,
+        );
+
+        let report = EraReadinessReport::from_era_epoch_token(&token);
+
+        assert!(report.is_current_era_supported);
+        assert_eq!(None, report.next_era);
+        assert_eq!(None, report.epochs_until_transition);
+        assert_eq!(None, report.is_next_era_supported);
+        assert_eq!(None, report.next_era_changes);
+        assert_eq!(SupportedEra::eras(), report.supported_eras);
+        assert!(report.is_ready());
+    }
+
+    #[test]
+    fn report_with_supported_next_era() {
+        let next_era = SupportedEra::eras().last().copied().unwrap();
+        let token = EraEpochToken::new(
+            Epoch(10),
+            EraMarker::new(&SupportedEra::dummy().to_string(), Some(Epoch(1))),
+            Some(EraMarker::new(&next_era.to_string(), Some(Epoch(14)))),
+        );
+
+        let report = EraReadinessReport::from_era_epoch_token(&token);
+
+        assert_eq!(Some(4), report.epochs_until_transition);
+        assert_eq!(Some(true), report.is_next_era_supported);
+        assert_eq!(Some(next_era.changes()), report.next_era_changes);
+        assert!(report.is_ready());
+    }
+
+    #[test]
+    fn report_with_unsupported_next_era() {
+        let token = EraEpochToken::new(
+            Epoch(10),
+            EraMarker::new(&SupportedEra::dummy().to_string(), Some(Epoch(1))),
+            Some(EraMarker::new("unsupported", Some(Epoch(12)))),
+        );
+
+        let report = EraReadinessReport::from_era_epoch_token(&token);
+
+        assert_eq!(Some(2), report.epochs_until_transition);
+        assert_eq!(Some(false), report.is_next_era_supported);
+        assert_eq!(None, report.next_era_changes);
+        assert!(!report.is_ready());
+        assert!(report.to_string().contains("NOT supported"));
+    }
+
+    #[test]
+    fn report_with_unscheduled_next_era() {
+        let token = EraEpochToken::new(
+            Epoch(10),
+            EraMarker::new(&SupportedEra::dummy().to_string(), Some(Epoch(1))),
+            Some(EraMarker::new("unsupported", None)),
+        );
+
+        let report = EraReadinessReport::from_era_epoch_token(&token);
+
+        assert_eq!(None, report.epochs_until_transition);
+        assert_eq!(Some(false), report.is_next_era_supported);
+        assert!(report.to_string().contains("not scheduled yet"));
+    }
+
+    #[test]
+    fn report_with_unsupported_current_era() {
+        let token = EraEpochToken::new(
+            Epoch(10),
+            EraMarker::new("unsupported", Some(Epoch(1))),
+            None,
+        );
+
+        let report = EraReadinessReport::from_era_epoch_token(&token);
+
+        assert!(!report.is_current_era_supported);
+        assert!(!report.is_ready());
+    }
+}
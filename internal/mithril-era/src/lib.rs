@@ -8,6 +8,8 @@
 pub mod adapters;
 mod era_checker;
 mod era_reader;
+mod era_readiness;
 
 pub use era_checker::EraChecker;
 pub use era_reader::*;
+pub use era_readiness::EraReadinessReport;
@@ -119,5 +119,68 @@ delete from block_range_root;
 vacuum;
  "#,
         ),
+        // Migration 10
+        // Add `cardano_block` and `cardano_block_range_root` tables
+        SqlMigration::new(
+            10,
+            r#"
+create table cardano_block (
+    block_hash    text      not null,
+    block_number  integer   not null,
+    slot_number   integer   not null,
+    primary key (block_hash)
+);
+
+create index cardano_block_block_number_index on cardano_block(block_number);
+create index cardano_block_slot_number_index on cardano_block(slot_number);
+
+create table cardano_block_range_root (
+    start         integer   not null,
+    end           integer   not null,
+    merkle_root   text      not null,
+    primary key (start, end)
+);
+"#,
+        ),
+        // Migration 11
+        // Add `cardano_transaction_metadata` and `cardano_transaction_metadata_block_range_root`
+        // tables
+        SqlMigration::new(
+            11,
+            r#"
+create table cardano_transaction_metadata (
+    transaction_hash  text      not null,
+    label             integer   not null,
+    metadata_hash     text      not null,
+    block_number      integer   not null,
+    slot_number       integer   not null,
+    block_hash        text      not null,
+    primary key (transaction_hash, label)
+);
+
+create index cardano_transaction_metadata_block_number_index on cardano_transaction_metadata(block_number);
+create index cardano_transaction_metadata_slot_number_index on cardano_transaction_metadata(slot_number);
+
+create table cardano_transaction_metadata_block_range_root (
+    start         integer   not null,
+    end           integer   not null,
+    merkle_root   text      not null,
+    primary key (start, end)
+);
+"#,
+        ),
+        // Migration 12
+        // Add `cardano_transaction_metadata_scanned_block` table
+        SqlMigration::new(
+            12,
+            r#"
+create table cardano_transaction_metadata_scanned_block (
+    id            integer   not null primary key check (id = 0),
+    block_hash    text      not null,
+    block_number  integer   not null,
+    slot_number   integer   not null
+);
+"#,
+        ),
     ]
 }
@@ -11,6 +11,13 @@ use crate::sqlite::HydrationError;
 /// Helper struct to hydrate common data.
 pub struct Hydrator;
 
+/// Beacon of the signed entity types anchored to a block number
+#[derive(Deserialize)]
+struct EpochBlockNumberBeacon {
+    epoch: Epoch,
+    block_number: BlockNumber,
+}
+
 impl Hydrator {
     /// Read a signed entity beacon column from the database
     pub fn read_signed_entity_beacon_column<U: sqlite::RowIndex + Clone>(
@@ -71,13 +78,7 @@ impl Hydrator {
                 SignedEntityType::CardanoImmutableFilesFull(beacon)
             }
             SignedEntityTypeDiscriminants::CardanoTransactions => {
-                #[derive(Deserialize)]
-                struct CardanoTransactionsBeacon {
-                    epoch: Epoch,
-                    block_number: BlockNumber,
-                }
-
-                let beacon: CardanoTransactionsBeacon =
+                let beacon: EpochBlockNumberBeacon =
                     serde_json::from_str(beacon_str).map_err(|e| {
                         HydrationError::InvalidData(format!(
                             "Invalid Beacon JSON in open_message.beacon: '{beacon_str}'. Error: {e}"
@@ -93,6 +94,24 @@ impl Hydrator {
                 })?;
                 SignedEntityType::CardanoDatabase(beacon)
             }
+            SignedEntityTypeDiscriminants::CardanoBlocks => {
+                let beacon: EpochBlockNumberBeacon =
+                    serde_json::from_str(beacon_str).map_err(|e| {
+                        HydrationError::InvalidData(format!(
+                            "Invalid Beacon JSON in open_message.beacon: '{beacon_str}'. Error: {e}"
+                        ))
+                    })?;
+                SignedEntityType::CardanoBlocks(beacon.epoch, beacon.block_number)
+            }
+            SignedEntityTypeDiscriminants::CardanoTransactionsMetadata => {
+                let beacon: EpochBlockNumberBeacon =
+                    serde_json::from_str(beacon_str).map_err(|e| {
+                        HydrationError::InvalidData(format!(
+                            "Invalid Beacon JSON in open_message.beacon: '{beacon_str}'. Error: {e}"
+                        ))
+                    })?;
+                SignedEntityType::CardanoTransactionsMetadata(beacon.epoch, beacon.block_number)
+            }
         };
 
         Ok(signed_entity)
@@ -126,4 +145,28 @@ mod tests {
 
         assert_eq!(expected, signed_entity);
     }
+
+    #[test]
+    fn hydrate_cardano_blocks_signed_entity_type() {
+        let expected = SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77));
+        let signed_entity = Hydrator::hydrate_signed_entity_type(
+            SignedEntityTypeDiscriminants::CardanoBlocks.index(),
+            &expected.get_json_beacon().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(expected, signed_entity);
+    }
+
+    #[test]
+    fn hydrate_cardano_transactions_metadata_signed_entity_type() {
+        let expected = SignedEntityType::CardanoTransactionsMetadata(Epoch(35), BlockNumber(77));
+        let signed_entity = Hydrator::hydrate_signed_entity_type(
+            SignedEntityTypeDiscriminants::CardanoTransactionsMetadata.index(),
+            &expected.get_json_beacon().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(expected, signed_entity);
+    }
 }
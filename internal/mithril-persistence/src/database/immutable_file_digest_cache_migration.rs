@@ -0,0 +1,28 @@
+//! Migration module for the immutable file digests cache store shared between Mithril nodes
+//!
+use crate::database::SqlMigration;
+
+/// Get all the migrations required by this version of the software.
+/// There shall be one migration per database version. There could be several
+/// statements per migration.
+///
+/// Since the store can be shared between nodes of different types, each keeping track of its own
+/// database version, the migrations must be idempotent.
+pub fn get_migrations() -> Vec<SqlMigration> {
+    vec![
+        // Migration 1
+        // Add the `immutable_file_digest_cache` table.
+        SqlMigration::new(
+            1,
+            r#"
+create table if not exists immutable_file_digest_cache (
+    immutable_file_name     text      not null,
+    file_size               integer   not null,
+    modified_at             integer   not null,
+    digest                  text      not null,
+    primary key (immutable_file_name, file_size, modified_at)
+);
+"#,
+        ),
+    ]
+}
@@ -4,6 +4,7 @@
 pub mod cardano_transaction_migration;
 mod db_version;
 mod hydrator;
+pub mod immutable_file_digest_cache_migration;
 pub(crate) mod query;
 pub mod record;
 pub mod repository;
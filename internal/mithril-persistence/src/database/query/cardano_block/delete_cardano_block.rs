@@ -0,0 +1,84 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::StdResult;
+use mithril_common::entities::BlockNumber;
+
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete [CardanoBlockRecord] from the sqlite database
+pub struct DeleteCardanoBlockQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteCardanoBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection()
+            .expand(SourceAlias::new(&[("{:cardano_block:}", "cardano_block")]));
+
+        format!("delete from cardano_block where {condition} returning {projection}")
+    }
+}
+
+impl DeleteCardanoBlockQuery {
+    pub fn above_block_number_threshold(block_number_threshold: BlockNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(block_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("block_number > ?*", vec![threshold]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::SlotNumber;
+
+    use crate::database::query::{GetCardanoBlockQuery, InsertCardanoBlockQuery};
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_prune_blocks_above_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        connection
+            .fetch_first(
+                InsertCardanoBlockQuery::insert_many(vec![
+                    CardanoBlockRecord::new("block-hash-10", BlockNumber(10), SlotNumber(50)),
+                    CardanoBlockRecord::new("block-hash-11", BlockNumber(11), SlotNumber(51)),
+                    CardanoBlockRecord::new("block-hash-12", BlockNumber(12), SlotNumber(54)),
+                ])
+                .unwrap(),
+            )
+            .unwrap();
+
+        let cursor = connection
+            .fetch(DeleteCardanoBlockQuery::above_block_number_threshold(BlockNumber(10)).unwrap())
+            .unwrap();
+        assert_eq!(2, cursor.count());
+
+        let records: Vec<CardanoBlockRecord> =
+            connection.fetch_collect(GetCardanoBlockQuery::all()).unwrap();
+        assert_eq!(
+            vec![CardanoBlockRecord::new(
+                "block-hash-10",
+                BlockNumber(10),
+                SlotNumber(50)
+            )],
+            records
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::ops::Range;
+
+use sqlite::Value;
+
+use mithril_common::entities::{BlockHash, BlockNumber, SlotNumber};
+
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve [CardanoBlockRecord] from the sqlite database.
+pub struct GetCardanoBlockQuery {
+    condition: WhereCondition,
+}
+
+impl GetCardanoBlockQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_block_hashes(blocks_hashes: Vec<BlockHash>, up_to_or_equal: BlockNumber) -> Self {
+        let hashes_values = blocks_hashes.into_iter().map(Value::String).collect();
+        let condition =
+            WhereCondition::where_in("block_hash", hashes_values).and_where(WhereCondition::new(
+                "block_number <= ?*",
+                vec![Value::Integer(*up_to_or_equal as i64)],
+            ));
+
+        Self { condition }
+    }
+
+    pub fn between_blocks(range: Range<BlockNumber>) -> Self {
+        let condition = WhereCondition::new(
+            "block_number >= ?*",
+            vec![Value::Integer(*range.start as i64)],
+        )
+        .and_where(WhereCondition::new(
+            "block_number < ?*",
+            vec![Value::Integer(*range.end as i64)],
+        ));
+
+        Self { condition }
+    }
+
+    pub fn with_highest_block_number_below_slot_number(slot_number: SlotNumber) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "block_number = (select max(block_number) from cardano_block where slot_number <= ?*)",
+                vec![Value::Integer(*slot_number as i64)],
+            ),
+        }
+    }
+
+    pub fn with_highest_block_number() -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "block_number = (select max(block_number) from cardano_block)",
+                vec![],
+            ),
+        }
+    }
+}
+
+impl Query for GetCardanoBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:cardano_block:}", "cardano_block")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from cardano_block where {condition} order by block_number, block_hash"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::query::InsertCardanoBlockQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use super::*;
+
+    fn insert_blocks(connection: &SqliteConnection, records: Vec<CardanoBlockRecord>) {
+        connection
+            .fetch_first(InsertCardanoBlockQuery::insert_many(records).unwrap())
+            .unwrap();
+    }
+
+    fn test_block_set() -> Vec<CardanoBlockRecord> {
+        vec![
+            CardanoBlockRecord::new("block-hash-10", BlockNumber(10), SlotNumber(50)),
+            CardanoBlockRecord::new("block-hash-11", BlockNumber(11), SlotNumber(51)),
+            CardanoBlockRecord::new("block-hash-12", BlockNumber(12), SlotNumber(54)),
+            CardanoBlockRecord::new("block-hash-13", BlockNumber(13), SlotNumber(55)),
+        ]
+    }
+
+    #[test]
+    fn test_get_all_blocks() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(&connection, test_block_set());
+
+        let records: Vec<CardanoBlockRecord> =
+            connection.fetch_collect(GetCardanoBlockQuery::all()).unwrap();
+
+        assert_eq!(test_block_set(), records);
+    }
+
+    #[test]
+    fn test_get_blocks_by_hashes_up_to_a_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(&connection, test_block_set());
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::by_block_hashes(
+                vec![
+                    "block-hash-10".to_string(),
+                    "block-hash-12".to_string(),
+                    "block-hash-13".to_string(),
+                ],
+                BlockNumber(12),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                CardanoBlockRecord::new("block-hash-10", BlockNumber(10), SlotNumber(50)),
+                CardanoBlockRecord::new("block-hash-12", BlockNumber(12), SlotNumber(54)),
+            ],
+            records
+        );
+    }
+
+    #[test]
+    fn test_get_blocks_between_blocks() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(&connection, test_block_set());
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::between_blocks(
+                BlockNumber(11)..BlockNumber(13),
+            ))
+            .unwrap();
+
+        assert_eq!(test_block_set()[1..3].to_vec(), records);
+    }
+
+    #[test]
+    fn test_get_highest_block() {
+        let connection = cardano_tx_db_connection().unwrap();
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())
+            .unwrap();
+        assert_eq!(None, record);
+
+        insert_blocks(&connection, test_block_set());
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())
+            .unwrap();
+        assert_eq!(test_block_set().last().cloned(), record);
+    }
+
+    #[test]
+    fn test_get_highest_block_below_slot_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(&connection, test_block_set());
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(
+                GetCardanoBlockQuery::with_highest_block_number_below_slot_number(SlotNumber(53)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(CardanoBlockRecord::new(
+                "block-hash-11",
+                BlockNumber(11),
+                SlotNumber(51)
+            )),
+            record
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_cardano_block;
+mod get_cardano_block;
+mod insert_cardano_block;
+
+pub use delete_cardano_block::*;
+pub use get_cardano_block::*;
+pub use insert_cardano_block::*;
@@ -0,0 +1,45 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::StdResult;
+use mithril_common::entities::{BlockNumber, BlockRange};
+
+use crate::database::record::BlockRangeRootRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete the [BlockRangeRootRecord] of the Cardano blocks from the sqlite database
+pub struct DeleteCardanoBlockRangeRootQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteCardanoBlockRangeRootQuery {
+    type Entity = BlockRangeRootRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let aliases = SourceAlias::new(&[("{:block_range_root:}", "cardano_block_range_root")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("delete from cardano_block_range_root where {condition} returning {projection}")
+    }
+}
+
+impl DeleteCardanoBlockRangeRootQuery {
+    pub fn contains_or_above_block_number_threshold(
+        block_number_threshold: BlockNumber,
+    ) -> StdResult<Self> {
+        let block_range = BlockRange::from_block_number(block_number_threshold);
+        let threshold = Value::Integer(block_range.start.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("start >= ?*", vec![threshold]),
+        })
+    }
+}
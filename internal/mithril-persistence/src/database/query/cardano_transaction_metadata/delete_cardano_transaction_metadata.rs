@@ -0,0 +1,110 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::StdResult;
+use mithril_common::entities::SlotNumber;
+
+use crate::database::record::CardanoTransactionMetadataRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete [CardanoTransactionMetadataRecord] from the sqlite database
+pub struct DeleteCardanoTransactionMetadataQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteCardanoTransactionMetadataQuery {
+    type Entity = CardanoTransactionMetadataRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:cardano_transaction_metadata:}",
+            "cardano_transaction_metadata",
+        )]));
+
+        format!("delete from cardano_transaction_metadata where {condition} returning {projection}")
+    }
+}
+
+impl DeleteCardanoTransactionMetadataQuery {
+    pub fn above_slot_number_threshold(slot_number_threshold: SlotNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(slot_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{slot_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("slot_number > ?*", vec![threshold]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::BlockNumber;
+
+    use crate::database::query::{
+        GetCardanoTransactionMetadataQuery, InsertCardanoTransactionMetadataQuery,
+    };
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use super::*;
+
+    fn insert_transactions_metadata(connection: &SqliteConnection) {
+        connection
+            .fetch_first(
+                InsertCardanoTransactionMetadataQuery::insert_many(vec![
+                    CardanoTransactionMetadataRecord::new(
+                        "tx-hash-10",
+                        674,
+                        "metadata-hash-10",
+                        BlockNumber(10),
+                        SlotNumber(50),
+                        "block-hash-10",
+                    ),
+                    CardanoTransactionMetadataRecord::new(
+                        "tx-hash-11",
+                        674,
+                        "metadata-hash-11",
+                        BlockNumber(11),
+                        SlotNumber(51),
+                        "block-hash-11",
+                    ),
+                    CardanoTransactionMetadataRecord::new(
+                        "tx-hash-12",
+                        721,
+                        "metadata-hash-12",
+                        BlockNumber(12),
+                        SlotNumber(54),
+                        "block-hash-12",
+                    ),
+                ])
+                .unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_prune_transactions_metadata_above_slot_number_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_transactions_metadata(&connection);
+
+        let cursor = connection
+            .fetch(
+                DeleteCardanoTransactionMetadataQuery::above_slot_number_threshold(SlotNumber(51))
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(1, cursor.count());
+
+        let records: Vec<CardanoTransactionMetadataRecord> = connection
+            .fetch_collect(GetCardanoTransactionMetadataQuery::all())
+            .unwrap();
+        assert_eq!(2, records.len());
+    }
+}
@@ -0,0 +1,193 @@
+use std::ops::Range;
+
+use sqlite::Value;
+
+use mithril_common::entities::{BlockNumber, TransactionHash};
+
+use crate::database::record::CardanoTransactionMetadataRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve [CardanoTransactionMetadataRecord] from the sqlite database.
+pub struct GetCardanoTransactionMetadataQuery {
+    condition: WhereCondition,
+}
+
+impl GetCardanoTransactionMetadataQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_transaction_hashes(
+        transactions_hashes: Vec<TransactionHash>,
+        up_to_or_equal: BlockNumber,
+    ) -> Self {
+        let hashes_values = transactions_hashes.into_iter().map(Value::String).collect();
+        let condition = WhereCondition::where_in("transaction_hash", hashes_values).and_where(
+            WhereCondition::new(
+                "block_number <= ?*",
+                vec![Value::Integer(*up_to_or_equal as i64)],
+            ),
+        );
+
+        Self { condition }
+    }
+
+    pub fn between_blocks(range: Range<BlockNumber>) -> Self {
+        let condition = WhereCondition::new(
+            "block_number >= ?*",
+            vec![Value::Integer(*range.start as i64)],
+        )
+        .and_where(WhereCondition::new(
+            "block_number < ?*",
+            vec![Value::Integer(*range.end as i64)],
+        ));
+
+        Self { condition }
+    }
+
+    pub fn with_highest_block_number() -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "block_number = (select max(block_number) from cardano_transaction_metadata)",
+                vec![],
+            ),
+        }
+    }
+}
+
+impl Query for GetCardanoTransactionMetadataQuery {
+    type Entity = CardanoTransactionMetadataRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[(
+            "{:cardano_transaction_metadata:}",
+            "cardano_transaction_metadata",
+        )]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from cardano_transaction_metadata where {condition} order by block_number, transaction_hash, label"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::SlotNumber;
+
+    use crate::database::query::InsertCardanoTransactionMetadataQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use super::*;
+
+    fn insert_transactions_metadata(
+        connection: &SqliteConnection,
+        records: Vec<CardanoTransactionMetadataRecord>,
+    ) {
+        connection
+            .fetch_first(InsertCardanoTransactionMetadataQuery::insert_many(records).unwrap())
+            .unwrap();
+    }
+
+    fn test_transactions_metadata_set() -> Vec<CardanoTransactionMetadataRecord> {
+        vec![
+            CardanoTransactionMetadataRecord::new(
+                "tx-hash-10",
+                674,
+                "metadata-hash-10",
+                BlockNumber(10),
+                SlotNumber(50),
+                "block-hash-10",
+            ),
+            CardanoTransactionMetadataRecord::new(
+                "tx-hash-11",
+                674,
+                "metadata-hash-11-a",
+                BlockNumber(11),
+                SlotNumber(51),
+                "block-hash-11",
+            ),
+            CardanoTransactionMetadataRecord::new(
+                "tx-hash-11",
+                721,
+                "metadata-hash-11-b",
+                BlockNumber(11),
+                SlotNumber(51),
+                "block-hash-11",
+            ),
+            CardanoTransactionMetadataRecord::new(
+                "tx-hash-12",
+                674,
+                "metadata-hash-12",
+                BlockNumber(12),
+                SlotNumber(54),
+                "block-hash-12",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_get_all_transactions_metadata() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_transactions_metadata(&connection, test_transactions_metadata_set());
+
+        let records: Vec<CardanoTransactionMetadataRecord> = connection
+            .fetch_collect(GetCardanoTransactionMetadataQuery::all())
+            .unwrap();
+
+        assert_eq!(test_transactions_metadata_set(), records);
+    }
+
+    #[test]
+    fn test_get_transactions_metadata_by_transaction_hashes_up_to_a_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_transactions_metadata(&connection, test_transactions_metadata_set());
+
+        let records: Vec<CardanoTransactionMetadataRecord> = connection
+            .fetch_collect(GetCardanoTransactionMetadataQuery::by_transaction_hashes(
+                vec!["tx-hash-11".to_string(), "tx-hash-12".to_string()],
+                BlockNumber(11),
+            ))
+            .unwrap();
+
+        assert_eq!(test_transactions_metadata_set()[1..3].to_vec(), records);
+    }
+
+    #[test]
+    fn test_get_transactions_metadata_between_blocks() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_transactions_metadata(&connection, test_transactions_metadata_set());
+
+        let records: Vec<CardanoTransactionMetadataRecord> = connection
+            .fetch_collect(GetCardanoTransactionMetadataQuery::between_blocks(
+                BlockNumber(10)..BlockNumber(12),
+            ))
+            .unwrap();
+
+        assert_eq!(test_transactions_metadata_set()[0..3].to_vec(), records);
+    }
+
+    #[test]
+    fn test_get_transactions_metadata_with_highest_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+
+        let record: Option<CardanoTransactionMetadataRecord> = connection
+            .fetch_first(GetCardanoTransactionMetadataQuery::with_highest_block_number())
+            .unwrap();
+        assert_eq!(None, record);
+
+        insert_transactions_metadata(&connection, test_transactions_metadata_set());
+
+        let record: Option<CardanoTransactionMetadataRecord> = connection
+            .fetch_first(GetCardanoTransactionMetadataQuery::with_highest_block_number())
+            .unwrap();
+        assert_eq!(test_transactions_metadata_set().last().cloned(), record);
+    }
+}
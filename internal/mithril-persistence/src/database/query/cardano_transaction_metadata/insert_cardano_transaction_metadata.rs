@@ -0,0 +1,62 @@
+use std::iter::repeat_n;
+
+use sqlite::Value;
+
+use mithril_common::StdResult;
+
+use crate::database::record::CardanoTransactionMetadataRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to insert [CardanoTransactionMetadataRecord] in the sqlite database
+pub struct InsertCardanoTransactionMetadataQuery {
+    condition: WhereCondition,
+}
+
+impl InsertCardanoTransactionMetadataQuery {
+    /// Query that insert multiples records.
+    pub fn insert_many(metadata_records: Vec<CardanoTransactionMetadataRecord>) -> StdResult<Self> {
+        let columns =
+            "(transaction_hash, label, metadata_hash, block_number, slot_number, block_hash)";
+        let values_columns: Vec<&str> =
+            repeat_n("(?*, ?*, ?*, ?*, ?*, ?*)", metadata_records.len()).collect();
+
+        let values: StdResult<Vec<Value>> =
+            metadata_records.into_iter().try_fold(vec![], |mut vec, record| {
+                vec.append(&mut vec![
+                    Value::String(record.transaction_hash),
+                    Value::Integer(record.label.try_into()?),
+                    Value::String(record.metadata_hash),
+                    Value::Integer(record.block_number.try_into()?),
+                    Value::Integer(record.slot_number.try_into()?),
+                    Value::String(record.block_hash),
+                ]);
+                Ok(vec)
+            });
+        let condition = WhereCondition::new(
+            format!("{columns} values {}", values_columns.join(", ")).as_str(),
+            values?,
+        );
+
+        Ok(Self { condition })
+    }
+}
+
+impl Query for InsertCardanoTransactionMetadataQuery {
+    type Entity = CardanoTransactionMetadataRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[(
+            "{:cardano_transaction_metadata:}",
+            "cardano_transaction_metadata",
+        )]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "insert or ignore into cardano_transaction_metadata {condition} returning {projection}"
+        )
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_cardano_transaction_metadata;
+mod get_cardano_transaction_metadata;
+mod insert_cardano_transaction_metadata;
+
+pub use delete_cardano_transaction_metadata::*;
+pub use get_cardano_transaction_metadata::*;
+pub use insert_cardano_transaction_metadata::*;
@@ -0,0 +1,115 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::StdResult;
+use mithril_common::entities::SlotNumber;
+
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete the last block scanned by the cardano transactions metadata import from the
+/// sqlite database.
+pub struct DeleteCardanoTransactionMetadataScannedBlockQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteCardanoTransactionMetadataScannedBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let aliases = SourceAlias::new(&[(
+            "{:cardano_block:}",
+            "cardano_transaction_metadata_scanned_block",
+        )]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "delete from cardano_transaction_metadata_scanned_block where {condition} returning {projection}"
+        )
+    }
+}
+
+impl DeleteCardanoTransactionMetadataScannedBlockQuery {
+    pub fn above_slot_number_threshold(slot_number_threshold: SlotNumber) -> StdResult<Self> {
+        let threshold = Value::Integer(slot_number_threshold.try_into().with_context(|| {
+            format!("Failed to convert threshold `{slot_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("slot_number > ?*", vec![threshold]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::BlockNumber;
+
+    use crate::database::query::{
+        GetCardanoTransactionMetadataScannedBlockQuery,
+        UpsertCardanoTransactionMetadataScannedBlockQuery,
+    };
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    fn insert_scanned_block(connection: &sqlite::ConnectionThreadSafe, slot_number: u64) {
+        connection
+            .fetch_first(
+                UpsertCardanoTransactionMetadataScannedBlockQuery::one(CardanoBlockRecord::new(
+                    "block-hash",
+                    BlockNumber(10),
+                    SlotNumber(slot_number),
+                ))
+                .unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_delete_scanned_block_above_slot_number_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_scanned_block(&connection, 50);
+
+        connection
+            .fetch_first(
+                DeleteCardanoTransactionMetadataScannedBlockQuery::above_slot_number_threshold(
+                    SlotNumber(49),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoTransactionMetadataScannedBlockQuery::last_scanned_block())
+            .unwrap();
+        assert_eq!(None, record);
+    }
+
+    #[test]
+    fn test_keep_scanned_block_below_or_at_slot_number_threshold() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_scanned_block(&connection, 50);
+
+        connection
+            .fetch_first(
+                DeleteCardanoTransactionMetadataScannedBlockQuery::above_slot_number_threshold(
+                    SlotNumber(50),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoTransactionMetadataScannedBlockQuery::last_scanned_block())
+            .unwrap();
+        assert!(record.is_some());
+    }
+}
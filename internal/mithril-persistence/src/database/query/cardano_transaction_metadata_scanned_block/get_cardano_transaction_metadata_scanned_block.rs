@@ -0,0 +1,71 @@
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to retrieve the last block scanned by the cardano transactions metadata import from the
+/// sqlite database.
+pub struct GetCardanoTransactionMetadataScannedBlockQuery {
+    condition: WhereCondition,
+}
+
+impl GetCardanoTransactionMetadataScannedBlockQuery {
+    pub fn last_scanned_block() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+}
+
+impl Query for GetCardanoTransactionMetadataScannedBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[(
+            "{:cardano_block:}",
+            "cardano_transaction_metadata_scanned_block",
+        )]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from cardano_transaction_metadata_scanned_block where {condition}"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{BlockNumber, SlotNumber};
+
+    use crate::database::query::UpsertCardanoTransactionMetadataScannedBlockQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_get_last_scanned_block() {
+        let connection = cardano_tx_db_connection().unwrap();
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoTransactionMetadataScannedBlockQuery::last_scanned_block())
+            .unwrap();
+        assert_eq!(None, record);
+
+        let scanned_block =
+            CardanoBlockRecord::new("block-hash-10", BlockNumber(10), SlotNumber(50));
+        connection
+            .fetch_first(
+                UpsertCardanoTransactionMetadataScannedBlockQuery::one(scanned_block.clone())
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoTransactionMetadataScannedBlockQuery::last_scanned_block())
+            .unwrap();
+        assert_eq!(Some(scanned_block), record);
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_cardano_transaction_metadata_scanned_block;
+mod get_cardano_transaction_metadata_scanned_block;
+mod upsert_cardano_transaction_metadata_scanned_block;
+
+pub use delete_cardano_transaction_metadata_scanned_block::*;
+pub use get_cardano_transaction_metadata_scanned_block::*;
+pub use upsert_cardano_transaction_metadata_scanned_block::*;
@@ -0,0 +1,88 @@
+use sqlite::Value;
+
+use mithril_common::StdResult;
+
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to store the last block scanned by the cardano transactions metadata import in the
+/// sqlite database, replacing the previously stored one.
+pub struct UpsertCardanoTransactionMetadataScannedBlockQuery {
+    condition: WhereCondition,
+}
+
+impl UpsertCardanoTransactionMetadataScannedBlockQuery {
+    pub fn one(record: CardanoBlockRecord) -> StdResult<Self> {
+        // The table holds a single row, always identified by the id `0`
+        let condition = WhereCondition::new(
+            "(id, block_hash, block_number, slot_number) values (0, ?*, ?*, ?*)",
+            vec![
+                Value::String(record.block_hash),
+                Value::Integer(record.block_number.try_into()?),
+                Value::Integer(record.slot_number.try_into()?),
+            ],
+        );
+
+        Ok(Self { condition })
+    }
+}
+
+impl Query for UpsertCardanoTransactionMetadataScannedBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let aliases = SourceAlias::new(&[(
+            "{:cardano_block:}",
+            "cardano_transaction_metadata_scanned_block",
+        )]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "insert or replace into cardano_transaction_metadata_scanned_block {condition} returning {projection}"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{BlockNumber, SlotNumber};
+
+    use crate::database::query::GetCardanoTransactionMetadataScannedBlockQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_upsert_replaces_the_previously_scanned_block() {
+        let connection = cardano_tx_db_connection().unwrap();
+        for record in [
+            CardanoBlockRecord::new("block-hash-10", BlockNumber(10), SlotNumber(50)),
+            CardanoBlockRecord::new("block-hash-12", BlockNumber(12), SlotNumber(54)),
+        ] {
+            connection
+                .fetch_first(
+                    UpsertCardanoTransactionMetadataScannedBlockQuery::one(record).unwrap(),
+                )
+                .unwrap();
+        }
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoTransactionMetadataScannedBlockQuery::last_scanned_block())
+            .unwrap();
+        assert_eq!(
+            vec![CardanoBlockRecord::new(
+                "block-hash-12",
+                BlockNumber(12),
+                SlotNumber(54)
+            )],
+            records
+        );
+    }
+}
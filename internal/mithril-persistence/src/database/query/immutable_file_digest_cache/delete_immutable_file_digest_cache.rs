@@ -0,0 +1,46 @@
+use sqlite::Value;
+
+use mithril_common::entities::ImmutableFileName;
+
+use crate::database::record::ImmutableFileDigestCacheRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete [ImmutableFileDigestCacheRecord] from the sqlite database
+pub struct DeleteImmutableFileDigestCacheQuery {
+    condition: WhereCondition,
+}
+
+impl DeleteImmutableFileDigestCacheQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_immutable_file_names(immutable_file_names: Vec<ImmutableFileName>) -> Self {
+        let names_values = immutable_file_names.into_iter().map(Value::String).collect();
+
+        Self {
+            condition: WhereCondition::where_in("immutable_file_name", names_values),
+        }
+    }
+}
+
+impl Query for DeleteImmutableFileDigestCacheQuery {
+    type Entity = ImmutableFileDigestCacheRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:immutable_file_digest_cache:}",
+            "immutable_file_digest_cache",
+        )]));
+
+        format!("delete from immutable_file_digest_cache where {condition} returning {projection}")
+    }
+}
@@ -0,0 +1,48 @@
+use sqlite::Value;
+
+use mithril_common::entities::ImmutableFileName;
+
+use crate::database::record::ImmutableFileDigestCacheRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve [ImmutableFileDigestCacheRecord] from the sqlite database.
+pub struct GetImmutableFileDigestCacheQuery {
+    condition: WhereCondition,
+}
+
+impl GetImmutableFileDigestCacheQuery {
+    #[cfg(test)]
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_immutable_file_names(immutable_file_names: Vec<ImmutableFileName>) -> Self {
+        let names_values = immutable_file_names.into_iter().map(Value::String).collect();
+
+        Self {
+            condition: WhereCondition::where_in("immutable_file_name", names_values),
+        }
+    }
+}
+
+impl Query for GetImmutableFileDigestCacheQuery {
+    type Entity = ImmutableFileDigestCacheRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[(
+            "{:immutable_file_digest_cache:}",
+            "immutable_file_digest_cache",
+        )]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from immutable_file_digest_cache where {condition} order by immutable_file_name"
+        )
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_immutable_file_digest_cache;
+mod get_immutable_file_digest_cache;
+mod upsert_immutable_file_digest_cache;
+
+pub use delete_immutable_file_digest_cache::*;
+pub use get_immutable_file_digest_cache::*;
+pub use upsert_immutable_file_digest_cache::*;
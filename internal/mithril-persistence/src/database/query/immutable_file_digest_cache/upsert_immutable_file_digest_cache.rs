@@ -0,0 +1,62 @@
+use std::iter::repeat_n;
+
+use sqlite::Value;
+
+use mithril_common::StdResult;
+
+use crate::database::record::ImmutableFileDigestCacheRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to upsert [ImmutableFileDigestCacheRecord] in the sqlite database
+pub struct UpsertImmutableFileDigestCacheQuery {
+    condition: WhereCondition,
+}
+
+impl UpsertImmutableFileDigestCacheQuery {
+    /// Query that upsert multiples records.
+    pub fn many(records: Vec<ImmutableFileDigestCacheRecord>) -> StdResult<Self> {
+        let columns = "(immutable_file_name, file_size, modified_at, digest)";
+        let values_columns: Vec<&str> = repeat_n("(?*, ?*, ?*, ?*)", records.len()).collect();
+
+        let values: StdResult<Vec<Value>> =
+            records.into_iter().try_fold(vec![], |mut vec, record| {
+                vec.append(&mut vec![
+                    Value::String(record.immutable_file_name),
+                    Value::Integer(record.file_size.try_into()?),
+                    Value::Integer(record.modified_at.try_into()?),
+                    Value::String(record.digest),
+                ]);
+                Ok(vec)
+            });
+        let condition = WhereCondition::new(
+            format!("{columns} values {}", values_columns.join(", ")).as_str(),
+            values?,
+        );
+
+        Ok(Self { condition })
+    }
+}
+
+impl Query for UpsertImmutableFileDigestCacheQuery {
+    type Entity = ImmutableFileDigestCacheRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[(
+            "{:immutable_file_digest_cache:}",
+            "immutable_file_digest_cache",
+        )]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            r#"
+insert into immutable_file_digest_cache {condition}
+  on conflict (immutable_file_name, file_size, modified_at) do update set digest = excluded.digest
+returning {projection}
+"#
+        )
+    }
+}
@@ -1,6 +1,18 @@
 //! Shared database queries
 mod block_range_root;
+mod cardano_block;
+mod cardano_block_range_root;
 mod cardano_transaction;
+mod cardano_transaction_metadata;
+mod cardano_transaction_metadata_block_range_root;
+mod cardano_transaction_metadata_scanned_block;
+mod immutable_file_digest_cache;
 
 pub use block_range_root::*;
+pub use cardano_block::*;
+pub use cardano_block_range_root::*;
 pub use cardano_transaction::*;
+pub use cardano_transaction_metadata::*;
+pub use cardano_transaction_metadata_block_range_root::*;
+pub use cardano_transaction_metadata_scanned_block::*;
+pub use immutable_file_digest_cache::*;
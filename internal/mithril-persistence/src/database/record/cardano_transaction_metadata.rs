@@ -0,0 +1,140 @@
+use sqlite::Row;
+
+use mithril_common::entities::{
+    BlockHash, BlockNumber, CardanoTransactionMetadata, MetadataHash, MetadataLabel, SlotNumber,
+    TransactionHash,
+};
+
+use crate::database::Hydrator;
+use crate::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// Cardano Transaction Metadata record is the representation of a metadata label carried by a
+/// cardano transaction.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CardanoTransactionMetadataRecord {
+    /// Hash of the transaction carrying the metadata
+    pub transaction_hash: TransactionHash,
+
+    /// Label of the metadata entry
+    pub label: MetadataLabel,
+
+    /// Hash of the metadatum associated to the label
+    pub metadata_hash: MetadataHash,
+
+    /// Number of the block containing the transaction
+    pub block_number: BlockNumber,
+
+    /// Slot number of the block containing the transaction
+    pub slot_number: SlotNumber,
+
+    /// Hash of the block containing the transaction
+    pub block_hash: BlockHash,
+}
+
+impl CardanoTransactionMetadataRecord {
+    /// CardanoTransactionMetadataRecord factory
+    pub fn new<T: Into<TransactionHash>, U: Into<MetadataHash>, V: Into<BlockHash>>(
+        transaction_hash: T,
+        label: MetadataLabel,
+        metadata_hash: U,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+        block_hash: V,
+    ) -> Self {
+        Self {
+            transaction_hash: transaction_hash.into(),
+            label,
+            metadata_hash: metadata_hash.into(),
+            block_number,
+            slot_number,
+            block_hash: block_hash.into(),
+        }
+    }
+}
+
+impl From<CardanoTransactionMetadata> for CardanoTransactionMetadataRecord {
+    fn from(metadata: CardanoTransactionMetadata) -> Self {
+        Self {
+            transaction_hash: metadata.transaction_hash,
+            label: metadata.label,
+            metadata_hash: metadata.metadata_hash,
+            block_number: metadata.block_number,
+            slot_number: metadata.slot_number,
+            block_hash: metadata.block_hash,
+        }
+    }
+}
+
+impl From<CardanoTransactionMetadataRecord> for CardanoTransactionMetadata {
+    fn from(other: CardanoTransactionMetadataRecord) -> CardanoTransactionMetadata {
+        CardanoTransactionMetadata {
+            transaction_hash: other.transaction_hash,
+            label: other.label,
+            metadata_hash: other.metadata_hash,
+            block_number: other.block_number,
+            slot_number: other.slot_number,
+            block_hash: other.block_hash,
+        }
+    }
+}
+
+impl SqLiteEntity for CardanoTransactionMetadataRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let transaction_hash = row.read::<&str, _>(0);
+        let label =
+            Hydrator::try_to_u64("cardano_transaction_metadata.label", row.read::<i64, _>(1))?;
+        let metadata_hash = row.read::<&str, _>(2);
+        let block_number = Hydrator::try_to_u64(
+            "cardano_transaction_metadata.block_number",
+            row.read::<i64, _>(3),
+        )?;
+        let slot_number = Hydrator::try_to_u64(
+            "cardano_transaction_metadata.slot_number",
+            row.read::<i64, _>(4),
+        )?;
+        let block_hash = row.read::<&str, _>(5);
+
+        Ok(Self {
+            transaction_hash: transaction_hash.to_string(),
+            label,
+            metadata_hash: metadata_hash.to_string(),
+            block_number: BlockNumber(block_number),
+            slot_number: SlotNumber(slot_number),
+            block_hash: block_hash.to_string(),
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            (
+                "transaction_hash",
+                "{:cardano_transaction_metadata:}.transaction_hash",
+                "text",
+            ),
+            ("label", "{:cardano_transaction_metadata:}.label", "int"),
+            (
+                "metadata_hash",
+                "{:cardano_transaction_metadata:}.metadata_hash",
+                "text",
+            ),
+            (
+                "block_number",
+                "{:cardano_transaction_metadata:}.block_number",
+                "int",
+            ),
+            (
+                "slot_number",
+                "{:cardano_transaction_metadata:}.slot_number",
+                "int",
+            ),
+            (
+                "block_hash",
+                "{:cardano_transaction_metadata:}.block_hash",
+                "text",
+            ),
+        ])
+    }
+}
@@ -0,0 +1,69 @@
+use sqlite::Row;
+
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileName};
+
+use crate::database::Hydrator;
+use crate::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// Immutable file digest cache record is the cached digest of an immutable file, identified by
+/// its name and the size and modification time it had when the digest was computed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ImmutableFileDigestCacheRecord {
+    /// Immutable file name
+    pub immutable_file_name: ImmutableFileName,
+
+    /// Size of the immutable file, in bytes
+    pub file_size: u64,
+
+    /// Modification time of the immutable file, in nanoseconds since the Unix epoch
+    pub modified_at: u64,
+
+    /// Digest of the immutable file
+    pub digest: HexEncodedDigest,
+}
+
+impl SqLiteEntity for ImmutableFileDigestCacheRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let immutable_file_name = row.read::<&str, _>(0).to_string();
+        let file_size = Hydrator::try_to_u64(
+            "immutable_file_digest_cache.file_size",
+            row.read::<i64, _>(1),
+        )?;
+        let modified_at = Hydrator::try_to_u64(
+            "immutable_file_digest_cache.modified_at",
+            row.read::<i64, _>(2),
+        )?;
+        let digest = row.read::<&str, _>(3).to_string();
+
+        Ok(Self {
+            immutable_file_name,
+            file_size,
+            modified_at,
+            digest,
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            (
+                "immutable_file_name",
+                "{:immutable_file_digest_cache:}.immutable_file_name",
+                "text",
+            ),
+            (
+                "file_size",
+                "{:immutable_file_digest_cache:}.file_size",
+                "int",
+            ),
+            (
+                "modified_at",
+                "{:immutable_file_digest_cache:}.modified_at",
+                "int",
+            ),
+            ("digest", "{:immutable_file_digest_cache:}.digest", "text"),
+        ])
+    }
+}
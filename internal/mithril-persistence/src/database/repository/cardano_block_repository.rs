@@ -0,0 +1,329 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+
+use mithril_common::StdResult;
+use mithril_common::crypto_helper::{MKTreeNode, MKTreeStorer};
+use mithril_common::entities::{BlockHash, BlockNumber, BlockRange, ChainPoint, SlotNumber};
+use mithril_common::signable_builder::BlockRangeRootRetriever;
+
+use crate::database::query::{
+    DeleteCardanoBlockQuery, DeleteCardanoBlockRangeRootQuery, GetCardanoBlockQuery,
+    GetCardanoBlockRangeRootQuery, InsertCardanoBlockQuery, InsertCardanoBlockRangeRootQuery,
+};
+use crate::database::record::{BlockRangeRootRecord, CardanoBlockRecord};
+use crate::sqlite::{ConnectionExtensions, SqliteConnectionPool};
+
+/// ## Cardano block repository
+///
+/// This is a business oriented layer to perform actions on the database through
+/// queries.
+pub struct CardanoBlockRepository {
+    connection_pool: Arc<SqliteConnectionPool>,
+}
+
+impl CardanoBlockRepository {
+    /// Instantiate service
+    pub fn new(connection_pool: Arc<SqliteConnectionPool>) -> Self {
+        Self { connection_pool }
+    }
+
+    /// Return all the [CardanoBlockRecord]s in the database.
+    pub async fn get_all_blocks(&self) -> StdResult<Vec<CardanoBlockRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoBlockQuery::all())
+    }
+
+    /// Return all the [CardanoBlockRecord]s in the database where block number is in the
+    /// given range.
+    pub async fn get_blocks_in_range_blocks(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<CardanoBlockRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoBlockQuery::between_blocks(range))
+    }
+
+    /// Get the [CardanoBlockRecord] for the given block hashes, up to a block number
+    pub async fn get_blocks_by_hashes<T: Into<BlockHash>>(
+        &self,
+        hashes: Vec<T>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoBlockRecord>> {
+        let query = GetCardanoBlockQuery::by_block_hashes(
+            hashes.into_iter().map(Into::into).collect(),
+            up_to,
+        );
+        self.connection_pool.connection()?.fetch_collect(query)
+    }
+
+    /// Get the highest [ChainPoint] of the cardano blocks stored in the database.
+    pub async fn get_block_highest_chain_point(&self) -> StdResult<Option<ChainPoint>> {
+        let block_with_highest_block_number = self
+            .connection_pool
+            .connection()?
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())?;
+
+        Ok(block_with_highest_block_number.map(|record| {
+            ChainPoint::new(record.slot_number, record.block_number, record.block_hash)
+        }))
+    }
+
+    /// Store the given blocks in the database.
+    ///
+    /// The storage is done in chunks to avoid exceeding sqlite binding limitations.
+    pub async fn store_blocks<T: Into<CardanoBlockRecord> + Clone>(
+        &self,
+        blocks: Vec<T>,
+    ) -> StdResult<()> {
+        const DB_TRANSACTION_SIZE: usize = 100000;
+        for blocks_in_db_transaction_chunk in blocks.chunks(DB_TRANSACTION_SIZE) {
+            let connection = self.connection_pool.connection()?;
+            let transaction = connection.begin_transaction()?;
+
+            // Chunk blocks to avoid an error when we exceed sqlite binding limitations
+            for blocks_in_chunk in blocks_in_db_transaction_chunk.chunks(100) {
+                let records: Vec<CardanoBlockRecord> =
+                    blocks_in_chunk.iter().cloned().map(Into::into).collect();
+                connection
+                    .apply(InsertCardanoBlockQuery::insert_many(records)?)
+                    .with_context(|| "CardanoBlockRepository can not store blocks")?;
+            }
+
+            transaction.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Create new [BlockRangeRootRecord]s of the cardano blocks in the database.
+    pub async fn create_block_range_roots<T: Into<BlockRangeRootRecord>>(
+        &self,
+        block_ranges: Vec<T>,
+    ) -> StdResult<Vec<BlockRangeRootRecord>> {
+        let records: Vec<BlockRangeRootRecord> =
+            block_ranges.into_iter().map(|range| range.into()).collect();
+        let connection = self.connection_pool.connection()?;
+
+        connection.fetch_collect(InsertCardanoBlockRangeRootQuery::insert_many(records)?)
+    }
+
+    /// Retrieve the block range root of the cardano blocks with the highest bounds in the database.
+    pub async fn retrieve_highest_block_range_root(
+        &self,
+    ) -> StdResult<Option<BlockRangeRootRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_first(GetCardanoBlockRangeRootQuery::highest())
+    }
+
+    /// Retrieve all the Block Range Roots of the cardano blocks in database up to the block range
+    /// that contains the given block number.
+    pub async fn retrieve_block_range_roots_up_to(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + '_>> {
+        let block_range_roots = self
+            .connection_pool
+            .connection()?
+            .fetch(GetCardanoBlockRangeRootQuery::contains_or_below_block_number(block_number))?
+            .map(|record| -> (BlockRange, MKTreeNode) { record.into() })
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(block_range_roots.into_iter()))
+    }
+
+    /// Retrieve all the [BlockRangeRootRecord] of the cardano blocks in database.
+    pub fn get_all_block_range_root(&self) -> StdResult<Vec<BlockRangeRootRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoBlockRangeRootQuery::all())
+    }
+
+    /// Remove blocks and block range roots that are in a rolled-back fork
+    ///
+    /// * Remove blocks with block number strictly greater than the given block number
+    /// * Remove block range roots that have lower bound range strictly above the given block number
+    pub async fn remove_rolled_back_blocks_and_block_range_by_block_number(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<()> {
+        let connection = self.connection_pool.connection()?;
+        let transaction = connection.begin_transaction()?;
+        let query = DeleteCardanoBlockQuery::above_block_number_threshold(block_number)?;
+        connection.fetch_first(query)?;
+
+        let query = DeleteCardanoBlockRangeRootQuery::contains_or_above_block_number_threshold(
+            block_number,
+        )?;
+        connection.fetch_first(query)?;
+        transaction.commit()?;
+
+        Ok(())
+    }
+
+    /// Remove blocks and block range roots that are in a rolled-back fork
+    ///
+    /// * Remove blocks with closest block number strictly greater than the given slot number if exists
+    /// * Remove block range roots that have lower bound range strictly above the aforementioned block number
+    pub async fn remove_rolled_back_blocks_and_block_range_by_slot_number(
+        &self,
+        slot_number: SlotNumber,
+    ) -> StdResult<()> {
+        let query = GetCardanoBlockQuery::with_highest_block_number_below_slot_number(slot_number);
+        let record = self.connection_pool.connection()?.fetch_first(query)?;
+
+        if let Some(block_number) = record.map(|r| r.block_number) {
+            self.remove_rolled_back_blocks_and_block_range_by_block_number(block_number)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for CardanoBlockRepository {
+    async fn retrieve_block_range_roots<'a>(
+        &'a self,
+        up_to_beacon: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + 'a>> {
+        self.retrieve_block_range_roots_up_to(up_to_beacon).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeStoreInMemory;
+
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::SqliteConnectionPool;
+
+    use super::*;
+
+    fn repository() -> CardanoBlockRepository {
+        let connection = cardano_tx_db_connection().unwrap();
+        CardanoBlockRepository::new(Arc::new(SqliteConnectionPool::build_from_connection(
+            connection,
+        )))
+    }
+
+    fn blocks(block_numbers: Range<u64>) -> Vec<CardanoBlockRecord> {
+        block_numbers
+            .map(|number| {
+                CardanoBlockRecord::new(
+                    format!("block-hash-{number}"),
+                    BlockNumber(number),
+                    SlotNumber(number * 10),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn repository_store_blocks_and_get_highest_chain_point() {
+        let repository = repository();
+        assert_eq!(
+            None,
+            repository.get_block_highest_chain_point().await.unwrap()
+        );
+
+        repository.store_blocks(blocks(0..250)).await.unwrap();
+
+        assert_eq!(blocks(0..250), repository.get_all_blocks().await.unwrap());
+        assert_eq!(
+            Some(ChainPoint::new(
+                SlotNumber(2490),
+                BlockNumber(249),
+                "block-hash-249"
+            )),
+            repository.get_block_highest_chain_point().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_get_blocks_by_hashes() {
+        let repository = repository();
+        repository.store_blocks(blocks(0..10)).await.unwrap();
+
+        let records = repository
+            .get_blocks_by_hashes(vec!["block-hash-2", "block-hash-8"], BlockNumber(5))
+            .await
+            .unwrap();
+
+        assert_eq!(blocks(2..3), records);
+    }
+
+    #[tokio::test]
+    async fn repository_retrieve_block_range_roots_up_to_a_block_number() {
+        let repository = repository();
+        let block_range_roots = vec![
+            (
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(30)),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(45)),
+                MKTreeNode::from_hex("CCCC").unwrap(),
+            ),
+        ];
+        repository
+            .create_block_range_roots(block_range_roots.clone())
+            .await
+            .unwrap();
+
+        let retrieved_block_range_roots: Vec<_> =
+            BlockRangeRootRetriever::<MKTreeStoreInMemory>::retrieve_block_range_roots(
+                &repository,
+                BlockNumber(45),
+            )
+            .await
+            .unwrap()
+            .collect();
+
+        assert_eq!(
+            block_range_roots[0..2].to_vec(),
+            retrieved_block_range_roots
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_remove_rolled_back_blocks_and_block_range_by_slot_number() {
+        let repository = repository();
+        repository.store_blocks(blocks(0..40)).await.unwrap();
+        repository
+            .create_block_range_roots(vec![
+                (
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    MKTreeNode::from_hex("AAAA").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockNumber(15)),
+                    MKTreeNode::from_hex("BBBB").unwrap(),
+                ),
+            ])
+            .await
+            .unwrap();
+
+        repository
+            .remove_rolled_back_blocks_and_block_range_by_slot_number(SlotNumber(185))
+            .await
+            .unwrap();
+
+        assert_eq!(blocks(0..19), repository.get_all_blocks().await.unwrap());
+        assert_eq!(
+            vec![BlockRangeRootRecord::from((
+                BlockRange::from_block_number(BlockNumber(0)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ))],
+            repository.get_all_block_range_root().unwrap()
+        );
+    }
+}
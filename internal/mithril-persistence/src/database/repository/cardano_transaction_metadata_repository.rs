@@ -0,0 +1,502 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+
+use mithril_common::StdResult;
+use mithril_common::crypto_helper::{MKTreeNode, MKTreeStorer};
+use mithril_common::entities::{BlockNumber, BlockRange, ChainPoint, SlotNumber, TransactionHash};
+use mithril_common::signable_builder::BlockRangeRootRetriever;
+
+use crate::database::query::{
+    DeleteCardanoTransactionMetadataBlockRangeRootQuery, DeleteCardanoTransactionMetadataQuery,
+    DeleteCardanoTransactionMetadataScannedBlockQuery,
+    GetCardanoTransactionMetadataBlockRangeRootQuery, GetCardanoTransactionMetadataQuery,
+    GetCardanoTransactionMetadataScannedBlockQuery,
+    InsertCardanoTransactionMetadataBlockRangeRootQuery, InsertCardanoTransactionMetadataQuery,
+    UpsertCardanoTransactionMetadataScannedBlockQuery,
+};
+use crate::database::record::{
+    BlockRangeRootRecord, CardanoBlockRecord, CardanoTransactionMetadataRecord,
+};
+use crate::sqlite::{ConnectionExtensions, SqliteConnectionPool};
+
+/// ## Cardano transaction metadata repository
+///
+/// This is a business oriented layer to perform actions on the database through
+/// queries.
+pub struct CardanoTransactionMetadataRepository {
+    connection_pool: Arc<SqliteConnectionPool>,
+}
+
+impl CardanoTransactionMetadataRepository {
+    /// Instantiate service
+    pub fn new(connection_pool: Arc<SqliteConnectionPool>) -> Self {
+        Self { connection_pool }
+    }
+
+    /// Return all the [CardanoTransactionMetadataRecord]s in the database.
+    pub async fn get_all_transactions_metadata(
+        &self,
+    ) -> StdResult<Vec<CardanoTransactionMetadataRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoTransactionMetadataQuery::all())
+    }
+
+    /// Return all the [CardanoTransactionMetadataRecord]s in the database where block number is
+    /// in the given range.
+    pub async fn get_transactions_metadata_in_range_blocks(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<CardanoTransactionMetadataRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoTransactionMetadataQuery::between_blocks(range))
+    }
+
+    /// Get the [CardanoTransactionMetadataRecord]s for the given transaction hashes, up to a
+    /// block number
+    pub async fn get_transactions_metadata_by_hashes<T: Into<TransactionHash>>(
+        &self,
+        hashes: Vec<T>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoTransactionMetadataRecord>> {
+        let query = GetCardanoTransactionMetadataQuery::by_transaction_hashes(
+            hashes.into_iter().map(Into::into).collect(),
+            up_to,
+        );
+        self.connection_pool.connection()?.fetch_collect(query)
+    }
+
+    /// Get the highest [ChainPoint] of the cardano transactions metadata stored in the database.
+    pub async fn get_transaction_metadata_highest_chain_point(
+        &self,
+    ) -> StdResult<Option<ChainPoint>> {
+        let metadata_with_highest_block_number = self
+            .connection_pool
+            .connection()?
+            .fetch_first(GetCardanoTransactionMetadataQuery::with_highest_block_number())?;
+
+        Ok(metadata_with_highest_block_number.map(|record| {
+            ChainPoint::new(record.slot_number, record.block_number, record.block_hash)
+        }))
+    }
+
+    /// Get the highest [ChainPoint] scanned by the cardano transactions metadata import.
+    ///
+    /// Metadata are sparse, the last scanned block is usually above the highest stored
+    /// transaction metadata, it is stored so the import can resume from it.
+    pub async fn get_highest_scanned_chain_point(&self) -> StdResult<Option<ChainPoint>> {
+        let last_scanned_block = self
+            .connection_pool
+            .connection()?
+            .fetch_first(GetCardanoTransactionMetadataScannedBlockQuery::last_scanned_block())?
+            .map(|record| {
+                ChainPoint::new(record.slot_number, record.block_number, record.block_hash)
+            });
+        let highest_metadata_chain_point =
+            self.get_transaction_metadata_highest_chain_point().await?;
+
+        Ok(last_scanned_block.max(highest_metadata_chain_point))
+    }
+
+    /// Store the [ChainPoint] of the last block scanned by the cardano transactions metadata
+    /// import, replacing the previously stored one.
+    pub async fn store_scanned_chain_point(&self, chain_point: ChainPoint) -> StdResult<()> {
+        let record = CardanoBlockRecord::new(
+            chain_point.block_hash,
+            chain_point.block_number,
+            chain_point.slot_number,
+        );
+        self.connection_pool
+            .connection()?
+            .apply(UpsertCardanoTransactionMetadataScannedBlockQuery::one(
+                record,
+            )?)
+            .with_context(
+                || "CardanoTransactionMetadataRepository can not store the last scanned block",
+            )?;
+
+        Ok(())
+    }
+
+    /// Store the given transactions metadata in the database.
+    ///
+    /// The storage is done in chunks to avoid exceeding sqlite binding limitations.
+    pub async fn store_transactions_metadata<T: Into<CardanoTransactionMetadataRecord> + Clone>(
+        &self,
+        transactions_metadata: Vec<T>,
+    ) -> StdResult<()> {
+        const DB_TRANSACTION_SIZE: usize = 100000;
+        for metadata_in_db_transaction_chunk in transactions_metadata.chunks(DB_TRANSACTION_SIZE) {
+            let connection = self.connection_pool.connection()?;
+            let transaction = connection.begin_transaction()?;
+
+            // Chunk metadata to avoid an error when we exceed sqlite binding limitations
+            for metadata_in_chunk in metadata_in_db_transaction_chunk.chunks(100) {
+                let records: Vec<CardanoTransactionMetadataRecord> =
+                    metadata_in_chunk.iter().cloned().map(Into::into).collect();
+                connection
+                    .apply(InsertCardanoTransactionMetadataQuery::insert_many(records)?)
+                    .with_context(|| {
+                        "CardanoTransactionMetadataRepository can not store transactions metadata"
+                    })?;
+            }
+
+            transaction.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Create new [BlockRangeRootRecord]s of the cardano transactions metadata in the database.
+    pub async fn create_block_range_roots<T: Into<BlockRangeRootRecord>>(
+        &self,
+        block_ranges: Vec<T>,
+    ) -> StdResult<Vec<BlockRangeRootRecord>> {
+        let records: Vec<BlockRangeRootRecord> =
+            block_ranges.into_iter().map(|range| range.into()).collect();
+        let connection = self.connection_pool.connection()?;
+
+        connection.fetch_collect(
+            InsertCardanoTransactionMetadataBlockRangeRootQuery::insert_many(records)?,
+        )
+    }
+
+    /// Retrieve the block range root of the cardano transactions metadata with the highest bounds
+    /// in the database.
+    pub async fn retrieve_highest_block_range_root(
+        &self,
+    ) -> StdResult<Option<BlockRangeRootRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_first(GetCardanoTransactionMetadataBlockRangeRootQuery::highest())
+    }
+
+    /// Retrieve all the Block Range Roots of the cardano transactions metadata in database up to
+    /// the block range that contains the given block number.
+    pub async fn retrieve_block_range_roots_up_to(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + '_>> {
+        let block_range_roots = self
+            .connection_pool
+            .connection()?
+            .fetch(
+                GetCardanoTransactionMetadataBlockRangeRootQuery::contains_or_below_block_number(
+                    block_number,
+                ),
+            )?
+            .map(|record| -> (BlockRange, MKTreeNode) { record.into() })
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(block_range_roots.into_iter()))
+    }
+
+    /// Retrieve all the [BlockRangeRootRecord] of the cardano transactions metadata in database.
+    pub fn get_all_block_range_root(&self) -> StdResult<Vec<BlockRangeRootRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoTransactionMetadataBlockRangeRootQuery::all())
+    }
+
+    /// Remove transactions metadata and block range roots that are in a rolled-back fork
+    ///
+    /// * Remove transactions metadata with slot number strictly greater than the given slot number
+    /// * Remove block range roots that contain or are above the highest remaining transaction
+    ///   metadata, since they may have lost some of their leaves
+    /// * Remove the last scanned block if its slot number is strictly greater than the given slot
+    ///   number
+    pub async fn remove_rolled_back_transactions_metadata_and_block_range_by_slot_number(
+        &self,
+        slot_number: SlotNumber,
+    ) -> StdResult<()> {
+        let connection = self.connection_pool.connection()?;
+        let transaction = connection.begin_transaction()?;
+        let query =
+            DeleteCardanoTransactionMetadataQuery::above_slot_number_threshold(slot_number)?;
+        connection.fetch_first(query)?;
+        let query = DeleteCardanoTransactionMetadataScannedBlockQuery::above_slot_number_threshold(
+            slot_number,
+        )?;
+        connection.fetch_first(query)?;
+
+        // Metadata are sparse: the block ranges without any metadata don't have a root, so
+        // every root above the highest remaining metadata belongs to the rolled-back fork.
+        let highest_remaining_block_number = connection
+            .fetch_first(GetCardanoTransactionMetadataQuery::with_highest_block_number())?
+            .map(|record| record.block_number)
+            .unwrap_or(BlockNumber(0));
+        let query =
+            DeleteCardanoTransactionMetadataBlockRangeRootQuery::contains_or_above_block_number_threshold(
+                highest_remaining_block_number,
+            )?;
+        connection.fetch_first(query)?;
+        transaction.commit()?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for CardanoTransactionMetadataRepository {
+    async fn retrieve_block_range_roots<'a>(
+        &'a self,
+        up_to_beacon: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + 'a>> {
+        self.retrieve_block_range_roots_up_to(up_to_beacon).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeStoreInMemory;
+
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::SqliteConnectionPool;
+
+    use super::*;
+
+    fn repository() -> CardanoTransactionMetadataRepository {
+        let connection = cardano_tx_db_connection().unwrap();
+        CardanoTransactionMetadataRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        ))
+    }
+
+    fn transactions_metadata(block_numbers: Range<u64>) -> Vec<CardanoTransactionMetadataRecord> {
+        block_numbers
+            .map(|number| {
+                CardanoTransactionMetadataRecord::new(
+                    format!("tx-hash-{number}"),
+                    674,
+                    format!("metadata-hash-{number}"),
+                    BlockNumber(number),
+                    SlotNumber(number * 10),
+                    format!("block-hash-{number}"),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn repository_store_transactions_metadata_and_get_highest_chain_point() {
+        let repository = repository();
+        assert_eq!(
+            None,
+            repository
+                .get_transaction_metadata_highest_chain_point()
+                .await
+                .unwrap()
+        );
+
+        repository
+            .store_transactions_metadata(transactions_metadata(0..250))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            transactions_metadata(0..250),
+            repository.get_all_transactions_metadata().await.unwrap()
+        );
+        assert_eq!(
+            Some(ChainPoint::new(
+                SlotNumber(2490),
+                BlockNumber(249),
+                "block-hash-249"
+            )),
+            repository
+                .get_transaction_metadata_highest_chain_point()
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_highest_scanned_chain_point_is_the_highest_of_the_last_scanned_block_and_the_stored_metadata()
+     {
+        let repository = repository();
+        assert_eq!(
+            None,
+            repository.get_highest_scanned_chain_point().await.unwrap()
+        );
+
+        repository
+            .store_transactions_metadata(transactions_metadata(0..10))
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(ChainPoint::new(
+                SlotNumber(90),
+                BlockNumber(9),
+                "block-hash-9"
+            )),
+            repository.get_highest_scanned_chain_point().await.unwrap()
+        );
+
+        let last_scanned_block = ChainPoint::new(SlotNumber(500), BlockNumber(50), "block-hash-50");
+        repository
+            .store_scanned_chain_point(last_scanned_block.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(last_scanned_block),
+            repository.get_highest_scanned_chain_point().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_remove_last_scanned_block_when_rolling_back_before_it() {
+        let repository = repository();
+        repository
+            .store_transactions_metadata(transactions_metadata(0..10))
+            .await
+            .unwrap();
+        repository
+            .store_scanned_chain_point(ChainPoint::new(
+                SlotNumber(500),
+                BlockNumber(50),
+                "block-hash-50",
+            ))
+            .await
+            .unwrap();
+
+        repository
+            .remove_rolled_back_transactions_metadata_and_block_range_by_slot_number(SlotNumber(
+                200,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(ChainPoint::new(
+                SlotNumber(90),
+                BlockNumber(9),
+                "block-hash-9"
+            )),
+            repository.get_highest_scanned_chain_point().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_get_transactions_metadata_by_hashes() {
+        let repository = repository();
+        repository
+            .store_transactions_metadata(transactions_metadata(0..10))
+            .await
+            .unwrap();
+
+        let records = repository
+            .get_transactions_metadata_by_hashes(vec!["tx-hash-2", "tx-hash-8"], BlockNumber(5))
+            .await
+            .unwrap();
+
+        assert_eq!(transactions_metadata(2..3), records);
+    }
+
+    #[tokio::test]
+    async fn repository_retrieve_block_range_roots_up_to_a_block_number() {
+        let repository = repository();
+        let block_range_roots = vec![
+            (
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(30)),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(45)),
+                MKTreeNode::from_hex("CCCC").unwrap(),
+            ),
+        ];
+        repository
+            .create_block_range_roots(block_range_roots.clone())
+            .await
+            .unwrap();
+
+        let retrieved_block_range_roots: Vec<_> =
+            BlockRangeRootRetriever::<MKTreeStoreInMemory>::retrieve_block_range_roots(
+                &repository,
+                BlockNumber(45),
+            )
+            .await
+            .unwrap()
+            .collect();
+
+        assert_eq!(
+            block_range_roots[0..2].to_vec(),
+            retrieved_block_range_roots
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_remove_rolled_back_transactions_metadata_and_block_range_by_slot_number() {
+        let repository = repository();
+        repository
+            .store_transactions_metadata(transactions_metadata(0..40))
+            .await
+            .unwrap();
+        repository
+            .create_block_range_roots(vec![
+                (
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    MKTreeNode::from_hex("AAAA").unwrap(),
+                ),
+                (
+                    BlockRange::from_block_number(BlockNumber(15)),
+                    MKTreeNode::from_hex("BBBB").unwrap(),
+                ),
+            ])
+            .await
+            .unwrap();
+
+        repository
+            .remove_rolled_back_transactions_metadata_and_block_range_by_slot_number(SlotNumber(
+                185,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            transactions_metadata(0..19),
+            repository.get_all_transactions_metadata().await.unwrap()
+        );
+        assert_eq!(
+            vec![BlockRangeRootRecord::from((
+                BlockRange::from_block_number(BlockNumber(0)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ))],
+            repository.get_all_block_range_root().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_remove_all_block_range_roots_when_rolling_back_before_any_transaction_metadata()
+     {
+        let repository = repository();
+        repository
+            .store_transactions_metadata(transactions_metadata(20..40))
+            .await
+            .unwrap();
+        repository
+            .create_block_range_roots(vec![(
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            )])
+            .await
+            .unwrap();
+
+        repository
+            .remove_rolled_back_transactions_metadata_and_block_range_by_slot_number(SlotNumber(
+                100,
+            ))
+            .await
+            .unwrap();
+
+        assert!(repository.get_all_transactions_metadata().await.unwrap().is_empty());
+        assert!(repository.get_all_block_range_root().unwrap().is_empty());
+    }
+}
@@ -273,6 +273,28 @@ impl CardanoTransactionRepository {
         Ok(())
     }
 
+    /// Prune the transactions of the block ranges older than the given number of blocks (based on
+    /// the block range root stored), keeping their block range roots.
+    ///
+    /// Unlike [prune_transaction][Self::prune_transaction], a block range is never partially
+    /// pruned, so proofs can still be computed for the transactions of the remaining ones.
+    pub async fn prune_transactions_of_block_ranges(
+        &self,
+        number_of_blocks_to_keep: BlockNumber,
+    ) -> StdResult<()> {
+        if let Some(highest_block_range_start) =
+            self.get_highest_start_block_number_for_block_range_roots().await?
+        {
+            let threshold = BlockRange::start(highest_block_range_start - number_of_blocks_to_keep);
+            let query = DeleteCardanoTransactionQuery::below_block_number_threshold(threshold)?;
+
+            let connection = self.connection_pool.connection()?;
+            connection.fetch_first(query)?;
+        }
+
+        Ok(())
+    }
+
     /// Remove transactions and block range roots that are in a rolled-back fork
     ///
     /// * Remove transactions with block number strictly greater than the given block number
@@ -1110,6 +1132,60 @@ mod tests {
         assert_eq!(28, transaction_result.len());
     }
 
+    #[tokio::test]
+    async fn repository_prune_transactions_of_block_ranges() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        ));
+
+        let cardano_transactions: Vec<CardanoTransactionRecord> = CardanoTransactionsBuilder::new()
+            .blocks_per_block_range(15)
+            .build_transactions(53)
+            .into_iter()
+            .map(CardanoTransactionRecord::from)
+            .collect();
+        repository
+            .create_transactions(cardano_transactions.clone())
+            .await
+            .unwrap();
+        repository
+            .create_block_range_roots(vec![(
+                BlockRange::from_block_number(BlockNumber(45)),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            )])
+            .await
+            .unwrap();
+
+        // Pruning with a number of block to keep greater than the highest block range start should
+        // do nothing.
+        repository
+            .prune_transactions_of_block_ranges(BlockNumber(10_000_000))
+            .await
+            .unwrap();
+        let transaction_result = repository.get_all_transactions().await.unwrap();
+        assert_eq!(cardano_transactions, transaction_result);
+
+        // Since the highest block range start is 45, pruning with 20 should remove the
+        // transactions of the block ranges strictly below the block range [15, 30) that contains
+        // the block 25.
+        repository
+            .prune_transactions_of_block_ranges(BlockNumber(20))
+            .await
+            .unwrap();
+        let transaction_result = repository
+            .get_transactions_in_range_blocks(BlockNumber(0)..BlockNumber(15))
+            .await
+            .unwrap();
+        assert_eq!(Vec::<CardanoTransactionRecord>::new(), transaction_result);
+
+        let transaction_result = repository
+            .get_transactions_in_range_blocks(BlockNumber(15)..BlockNumber(1000))
+            .await
+            .unwrap();
+        assert_eq!(38, transaction_result.len());
+    }
+
     #[tokio::test]
     async fn get_highest_start_block_number_for_block_range_roots() {
         let connection = cardano_tx_db_connection().unwrap();
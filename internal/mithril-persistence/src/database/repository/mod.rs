@@ -1,4 +1,12 @@
 //! Shared database repositories
+mod cardano_block_repository;
+mod cardano_transaction_metadata_repository;
 mod cardano_transaction_repository;
+mod sqlite_immutable_file_digest_cache_provider;
+mod sqlite_immutable_file_digest_cache_provider_builder;
 
+pub use cardano_block_repository::*;
+pub use cardano_transaction_metadata_repository::*;
 pub use cardano_transaction_repository::*;
+pub use sqlite_immutable_file_digest_cache_provider::*;
+pub use sqlite_immutable_file_digest_cache_provider_builder::*;
@@ -0,0 +1,427 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+use async_trait::async_trait;
+
+use mithril_cardano_node_internal_database::digesters::cache::{
+    CacheProviderResult, ImmutableDigesterCacheGetError, ImmutableDigesterCacheStoreError,
+    ImmutableFileDigestCacheProvider,
+};
+use mithril_cardano_node_internal_database::entities::ImmutableFile;
+use mithril_common::StdResult;
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileName};
+
+use crate::database::query::{
+    DeleteImmutableFileDigestCacheQuery, GetImmutableFileDigestCacheQuery,
+    UpsertImmutableFileDigestCacheQuery,
+};
+use crate::database::record::ImmutableFileDigestCacheRecord;
+use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+
+/// Key identifying a version of an immutable file: its name, size and modification time.
+type ImmutableFileKey = (ImmutableFileName, u64, u64);
+
+/// A SQLite [ImmutableFileDigestCacheProvider] that can be shared by several processes, such as
+/// an aggregator and a signer reading the same Cardano node database.
+///
+/// The digests are keyed by the immutable file name and the size and modification time of the
+/// file, so a rewritten immutable file is never associated with a stale digest.
+/// Only the latest digest of an immutable file is kept: storing a new digest for a file removes
+/// the digests stored for its previous versions.
+/// Concurrent accesses from several processes rely on the SQLite file locking.
+pub struct SqliteImmutableFileDigestCacheProvider {
+    connection: Arc<SqliteConnection>,
+    immutable_dir: PathBuf,
+}
+
+impl SqliteImmutableFileDigestCacheProvider {
+    /// Number of records upserted per query to avoid exceeding sqlite binding limitations.
+    const UPSERT_CHUNK_SIZE: usize = 100;
+
+    /// Number of immutable file names fetched or deleted per query to avoid exceeding sqlite
+    /// binding limitations.
+    const NAMES_CHUNK_SIZE: usize = 400;
+
+    /// [SqliteImmutableFileDigestCacheProvider] factory
+    ///
+    /// The `immutable_dir` is the directory of the immutable files which digests are stored.
+    pub fn new(connection: Arc<SqliteConnection>, immutable_dir: &Path) -> Self {
+        Self {
+            connection,
+            immutable_dir: immutable_dir.to_path_buf(),
+        }
+    }
+
+    /// Import the digests of a JSON immutable file digests cache, returning the number of
+    /// digests imported.
+    ///
+    /// Only the digests of the immutable files that exist in the immutable directory are
+    /// imported since their size and modification time are needed to key them.
+    pub fn import_json_cache(&self, json_cache_file: &Path) -> StdResult<usize> {
+        let json_content = std::fs::read_to_string(json_cache_file).with_context(|| {
+            format!(
+                "Could not read JSON digests cache file `{}`",
+                json_cache_file.display()
+            )
+        })?;
+        let digest_per_filenames: BTreeMap<ImmutableFileName, HexEncodedDigest> =
+            serde_json::from_str(&json_content).with_context(|| {
+                format!(
+                    "Could not deserialize JSON digests cache file `{}`",
+                    json_cache_file.display()
+                )
+            })?;
+
+        let records = self.build_records(digest_per_filenames.into_iter().collect());
+        let nb_imported_digests = records.len();
+        self.upsert_records(records)?;
+
+        Ok(nb_imported_digests)
+    }
+
+    fn read_file_key(path: &Path, filename: &ImmutableFileName) -> Option<ImmutableFileKey> {
+        let metadata = std::fs::metadata(path).ok()?;
+        let modified_at = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+        Some((
+            filename.clone(),
+            metadata.len(),
+            modified_at.as_nanos().try_into().ok()?,
+        ))
+    }
+
+    fn build_records(
+        &self,
+        digest_per_filenames: Vec<(ImmutableFileName, HexEncodedDigest)>,
+    ) -> Vec<ImmutableFileDigestCacheRecord> {
+        digest_per_filenames
+            .into_iter()
+            .filter_map(|(filename, digest)| {
+                let (immutable_file_name, file_size, modified_at) =
+                    Self::read_file_key(&self.immutable_dir.join(&filename), &filename)?;
+
+                Some(ImmutableFileDigestCacheRecord {
+                    immutable_file_name,
+                    file_size,
+                    modified_at,
+                    digest,
+                })
+            })
+            .collect()
+    }
+
+    fn upsert_records(&self, records: Vec<ImmutableFileDigestCacheRecord>) -> StdResult<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let transaction = self.connection.begin_transaction()?;
+        let names: Vec<ImmutableFileName> = records
+            .iter()
+            .map(|record| record.immutable_file_name.clone())
+            .collect();
+        for names_chunk in names.chunks(Self::NAMES_CHUNK_SIZE) {
+            self.connection.apply(
+                DeleteImmutableFileDigestCacheQuery::by_immutable_file_names(names_chunk.to_vec()),
+            )?;
+        }
+        for records_chunk in records.chunks(Self::UPSERT_CHUNK_SIZE) {
+            self.connection.apply(UpsertImmutableFileDigestCacheQuery::many(
+                records_chunk.to_vec(),
+            )?)?;
+        }
+        transaction.commit()?;
+
+        Ok(())
+    }
+
+    fn get_digests(
+        &self,
+        immutable_file_names: Vec<ImmutableFileName>,
+    ) -> StdResult<HashMap<ImmutableFileKey, HexEncodedDigest>> {
+        let mut records: Vec<ImmutableFileDigestCacheRecord> = vec![];
+        for names_chunk in immutable_file_names.chunks(Self::NAMES_CHUNK_SIZE) {
+            records.extend(self.connection.fetch_collect::<_, Vec<_>>(
+                GetImmutableFileDigestCacheQuery::by_immutable_file_names(names_chunk.to_vec()),
+            )?);
+        }
+
+        Ok(records
+            .into_iter()
+            .map(|record| {
+                (
+                    (
+                        record.immutable_file_name,
+                        record.file_size,
+                        record.modified_at,
+                    ),
+                    record.digest,
+                )
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl ImmutableFileDigestCacheProvider for SqliteImmutableFileDigestCacheProvider {
+    async fn store(
+        &self,
+        digest_per_filenames: Vec<(ImmutableFileName, HexEncodedDigest)>,
+    ) -> CacheProviderResult<()> {
+        let records = self.build_records(digest_per_filenames);
+        self.upsert_records(records)
+            .map_err(ImmutableDigesterCacheStoreError::StoreError)?;
+
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        immutables: Vec<ImmutableFile>,
+    ) -> CacheProviderResult<BTreeMap<ImmutableFile, Option<HexEncodedDigest>>> {
+        let digests = self
+            .get_digests(immutables.iter().map(|i| i.filename.clone()).collect())
+            .map_err(ImmutableDigesterCacheGetError::StoreError)?;
+        let mut result = BTreeMap::new();
+
+        for immutable in immutables {
+            let digest = Self::read_file_key(&immutable.path, &immutable.filename)
+                .and_then(|key| digests.get(&key).cloned());
+            result.insert(immutable, digest);
+        }
+
+        Ok(result)
+    }
+
+    async fn reset(&self) -> CacheProviderResult<()> {
+        self.connection
+            .apply(DeleteImmutableFileDigestCacheQuery::all())
+            .map_err(ImmutableDigesterCacheStoreError::StoreError)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use mithril_cardano_node_internal_database::test::{DummyCardanoDb, DummyCardanoDbBuilder};
+
+    use crate::database::immutable_file_digest_cache_migration;
+    use crate::sqlite::ConnectionBuilder;
+
+    use super::*;
+
+    fn cache_db_connection() -> Arc<SqliteConnection> {
+        let connection = ConnectionBuilder::open_memory()
+            .with_migrations(immutable_file_digest_cache_migration::get_migrations())
+            .build()
+            .unwrap();
+
+        Arc::new(connection)
+    }
+
+    fn stored_records(
+        provider: &SqliteImmutableFileDigestCacheProvider,
+    ) -> Vec<ImmutableFileDigestCacheRecord> {
+        provider
+            .connection
+            .fetch_collect(GetImmutableFileDigestCacheQuery::all())
+            .unwrap()
+    }
+
+    fn build_cardano_db(dir_name: &str) -> DummyCardanoDb {
+        DummyCardanoDbBuilder::new(dir_name)
+            .with_immutables(&[1, 2])
+            .append_immutable_trio()
+            .build()
+    }
+
+    fn digests_of(
+        immutables: &[ImmutableFile],
+        digest_prefix: &str,
+    ) -> Vec<(ImmutableFileName, HexEncodedDigest)> {
+        immutables
+            .iter()
+            .map(|immutable| {
+                (
+                    immutable.filename.clone(),
+                    format!("{digest_prefix}-{}", immutable.filename),
+                )
+            })
+            .collect()
+    }
+
+    fn expected_values(
+        immutables: &[ImmutableFile],
+        digest_prefix: &str,
+    ) -> BTreeMap<ImmutableFile, Option<HexEncodedDigest>> {
+        immutables
+            .iter()
+            .map(|immutable| {
+                (
+                    immutable.clone(),
+                    Some(format!("{digest_prefix}-{}", immutable.filename)),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn store_and_get_values() {
+        let cardano_db = build_cardano_db("sqlite_digest_cache_store_and_get_values");
+        let immutables = cardano_db.get_immutable_files().clone();
+        let provider = SqliteImmutableFileDigestCacheProvider::new(
+            cache_db_connection(),
+            cardano_db.get_immutable_dir(),
+        );
+
+        provider.store(digests_of(&immutables, "digest")).await.unwrap();
+        let result = provider.get(immutables.clone()).await.unwrap();
+
+        assert_eq!(expected_values(&immutables, "digest"), result);
+    }
+
+    #[tokio::test]
+    async fn store_values_of_files_missing_from_the_immutable_dir_is_ignored() {
+        let cardano_db = build_cardano_db(
+            "sqlite_digest_cache_store_values_of_files_missing_from_the_immutable_dir_is_ignored",
+        );
+        let provider = SqliteImmutableFileDigestCacheProvider::new(
+            cache_db_connection(),
+            cardano_db.get_immutable_dir(),
+        );
+
+        provider
+            .store(vec![("99999.chunk".to_string(), "digest".to_string())])
+            .await
+            .unwrap();
+
+        assert!(stored_records(&provider).is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_return_none_for_rewritten_immutable_files() {
+        let cardano_db =
+            build_cardano_db("sqlite_digest_cache_get_return_none_for_rewritten_files");
+        let immutables = cardano_db.get_immutable_files().clone();
+        let provider = SqliteImmutableFileDigestCacheProvider::new(
+            cache_db_connection(),
+            cardano_db.get_immutable_dir(),
+        );
+        provider.store(digests_of(&immutables, "digest")).await.unwrap();
+
+        let rewritten_immutable = &immutables[0];
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&rewritten_immutable.path)
+            .unwrap();
+        writeln!(file, "rewritten content").unwrap();
+
+        let result = provider.get(immutables.clone()).await.unwrap();
+
+        let mut expected = expected_values(&immutables, "digest");
+        expected.insert(rewritten_immutable.clone(), None);
+        assert_eq!(expected, result);
+    }
+
+    #[tokio::test]
+    async fn store_value_of_a_rewritten_immutable_file_remove_its_previous_values() {
+        let cardano_db =
+            build_cardano_db("sqlite_digest_cache_store_value_of_rewritten_file_remove_previous");
+        let immutables = cardano_db.get_immutable_files().clone();
+        let provider = SqliteImmutableFileDigestCacheProvider::new(
+            cache_db_connection(),
+            cardano_db.get_immutable_dir(),
+        );
+        provider.store(digests_of(&immutables, "digest")).await.unwrap();
+
+        let rewritten_immutable = &immutables[0];
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&rewritten_immutable.path)
+            .unwrap();
+        writeln!(file, "rewritten content").unwrap();
+        provider
+            .store(digests_of(&immutables[0..1], "new-digest"))
+            .await
+            .unwrap();
+
+        let rewritten_immutable_records: Vec<_> = stored_records(&provider)
+            .into_iter()
+            .filter(|record| record.immutable_file_name == rewritten_immutable.filename)
+            .map(|record| record.digest)
+            .collect();
+        assert_eq!(
+            vec![format!("new-digest-{}", rewritten_immutable.filename)],
+            rewritten_immutable_records
+        );
+        assert_eq!(immutables.len(), stored_records(&provider).len());
+    }
+
+    #[tokio::test]
+    async fn values_are_shared_between_providers_using_the_same_store() {
+        let cardano_db =
+            build_cardano_db("sqlite_digest_cache_values_are_shared_between_providers");
+        let immutables = cardano_db.get_immutable_files().clone();
+        let connection = cache_db_connection();
+        let provider = SqliteImmutableFileDigestCacheProvider::new(
+            connection.clone(),
+            cardano_db.get_immutable_dir(),
+        );
+        let other_provider =
+            SqliteImmutableFileDigestCacheProvider::new(connection, cardano_db.get_immutable_dir());
+
+        provider.store(digests_of(&immutables, "digest")).await.unwrap();
+        let result = other_provider.get(immutables.clone()).await.unwrap();
+
+        assert_eq!(expected_values(&immutables, "digest"), result);
+    }
+
+    #[tokio::test]
+    async fn reset_clear_all_values() {
+        let cardano_db = build_cardano_db("sqlite_digest_cache_reset_clear_all_values");
+        let immutables = cardano_db.get_immutable_files().clone();
+        let provider = SqliteImmutableFileDigestCacheProvider::new(
+            cache_db_connection(),
+            cardano_db.get_immutable_dir(),
+        );
+        provider.store(digests_of(&immutables, "digest")).await.unwrap();
+
+        provider.reset().await.unwrap();
+
+        assert!(stored_records(&provider).is_empty());
+    }
+
+    #[tokio::test]
+    async fn import_json_cache_values_of_existing_immutable_files() {
+        let cardano_db =
+            build_cardano_db("sqlite_digest_cache_import_json_cache_values_of_existing_files");
+        let immutables = cardano_db.get_immutable_files().clone();
+        let json_cache_file = cardano_db.get_dir().join("immutables_digests_devnet.json");
+        let mut json_values: BTreeMap<_, _> =
+            digests_of(&immutables, "json-digest").into_iter().collect();
+        json_values.insert("99999.chunk".to_string(), "digest".to_string());
+        std::fs::write(
+            &json_cache_file,
+            serde_json::to_string(&json_values).unwrap(),
+        )
+        .unwrap();
+        let provider = SqliteImmutableFileDigestCacheProvider::new(
+            cache_db_connection(),
+            cardano_db.get_immutable_dir(),
+        );
+
+        let nb_imported_digests = provider.import_json_cache(&json_cache_file).unwrap();
+
+        assert_eq!(immutables.len(), nb_imported_digests);
+        assert_eq!(
+            expected_values(&immutables, "json-digest"),
+            provider.get(immutables.clone()).await.unwrap()
+        );
+    }
+}
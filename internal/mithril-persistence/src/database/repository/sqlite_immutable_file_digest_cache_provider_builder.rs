@@ -0,0 +1,232 @@
+use anyhow::Context;
+use slog::{Logger, info};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use mithril_cardano_node_internal_database::digesters::cache::ImmutableFileDigestCacheProvider;
+use mithril_common::StdResult;
+use mithril_common::logging::LoggerExtensions;
+
+use crate::database::repository::SqliteImmutableFileDigestCacheProvider;
+use crate::database::{ApplicationNodeType, immutable_file_digest_cache_migration};
+use crate::sqlite::{ConnectionBuilder, ConnectionOptions};
+
+/// A [SqliteImmutableFileDigestCacheProvider] builder.
+pub struct SqliteImmutableFileDigestCacheProviderBuilder<'a> {
+    sqlite_file: &'a Path,
+    immutable_dir: &'a Path,
+    json_cache_file_to_import: Option<&'a Path>,
+    reset_digests_cache: bool,
+    node_type: ApplicationNodeType,
+    logger: Logger,
+}
+
+impl<'a> SqliteImmutableFileDigestCacheProviderBuilder<'a> {
+    /// Maximum duration to wait for the locks held by the other processes sharing the cache.
+    const BUSY_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// [SqliteImmutableFileDigestCacheProviderBuilder] factory.
+    pub fn new(sqlite_file: &'a Path, immutable_dir: &'a Path) -> Self {
+        Self {
+            sqlite_file,
+            immutable_dir,
+            json_cache_file_to_import: None,
+            reset_digests_cache: false,
+            node_type: ApplicationNodeType::Signer,
+            logger: Logger::root(slog::Discard, slog::o!()),
+        }
+    }
+
+    /// Set a JSON digests cache file to migrate to the provider.
+    ///
+    /// If the file exists its digests are imported then the file is removed.
+    pub fn with_json_cache_to_import(&mut self, json_cache_file: &'a Path) -> &mut Self {
+        self.json_cache_file_to_import = Some(json_cache_file);
+        self
+    }
+
+    /// Set if existing cached values in the provider must be reset.
+    ///
+    /// Since the cache can be shared, this also resets the values of the other processes.
+    pub fn should_reset_digests_cache(&mut self, should_reset: bool) -> &mut Self {
+        self.reset_digests_cache = should_reset;
+        self
+    }
+
+    /// Set the type of the node opening the cache (default: [ApplicationNodeType::Signer]).
+    pub fn with_node_type(&mut self, node_type: ApplicationNodeType) -> &mut Self {
+        self.node_type = node_type;
+        self
+    }
+
+    /// Set the [Logger] to use.
+    pub fn with_logger(&mut self, logger: Logger) -> &mut Self {
+        self.logger = logger.new_with_component_name::<Self>();
+        self
+    }
+
+    /// Build a [SqliteImmutableFileDigestCacheProvider] based on the parameters previously set.
+    pub async fn build(&self) -> StdResult<SqliteImmutableFileDigestCacheProvider> {
+        if let Some(cache_dir) = self.sqlite_file.parent() {
+            std::fs::create_dir_all(cache_dir).with_context(|| {
+                format!(
+                    "Failure when creating cache directory `{}`",
+                    cache_dir.display(),
+                )
+            })?;
+        }
+
+        let connection = ConnectionBuilder::open_file(self.sqlite_file)
+            .with_node_type(self.node_type.clone())
+            .with_options(&[
+                ConnectionOptions::BusyTimeout(Self::BUSY_TIMEOUT),
+                ConnectionOptions::EnableWriteAheadLog,
+            ])
+            .with_migrations(immutable_file_digest_cache_migration::get_migrations())
+            .with_logger(self.logger.clone())
+            .build()
+            .with_context(|| {
+                format!(
+                    "Failure when opening digests cache database `{}`",
+                    self.sqlite_file.display(),
+                )
+            })?;
+        let cache_provider =
+            SqliteImmutableFileDigestCacheProvider::new(Arc::new(connection), self.immutable_dir);
+
+        if self.reset_digests_cache {
+            cache_provider.reset().await.with_context(|| {
+                format!(
+                    "Failure when resetting digests cache database `{}`",
+                    self.sqlite_file.display(),
+                )
+            })?;
+        }
+
+        if let Some(json_cache_file) = self.json_cache_file_to_import.filter(|f| f.exists()) {
+            if !self.reset_digests_cache {
+                let nb_imported_digests = cache_provider.import_json_cache(json_cache_file)?;
+                info!(
+                    self.logger,
+                    "Imported {nb_imported_digests} immutables digests from JSON cache: {}",
+                    json_cache_file.display()
+                );
+            }
+            std::fs::remove_file(json_cache_file).with_context(|| {
+                format!(
+                    "Failure when removing JSON digests cache file `{}`",
+                    json_cache_file.display(),
+                )
+            })?;
+        }
+
+        info!(
+            self.logger,
+            "Storing/Getting immutables digests cache from: {}",
+            self.sqlite_file.display()
+        );
+
+        Ok(cache_provider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use mithril_cardano_node_internal_database::test::DummyCardanoDbBuilder;
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    fn get_test_dir(subdir_name: &str) -> PathBuf {
+        TempDir::create("sqlite_digest_cache_provider_builder", subdir_name)
+    }
+
+    #[tokio::test]
+    async fn create_cache_database_and_its_directory() {
+        let sqlite_file = get_test_dir("create_cache_database_and_its_directory")
+            .join("cache_dir")
+            .join("immutables_digests.sqlite3");
+
+        SqliteImmutableFileDigestCacheProviderBuilder::new(&sqlite_file, Path::new("immutable"))
+            .build()
+            .await
+            .expect("Build should not fail");
+
+        assert!(sqlite_file.exists());
+    }
+
+    #[tokio::test]
+    async fn import_then_remove_json_cache_file() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "sqlite_digest_cache_provider_builder_import_then_remove_json_cache_file",
+        )
+        .with_immutables(&[1])
+        .build();
+        let immutable = cardano_db.get_immutable_files()[0].clone();
+        let test_dir = get_test_dir("import_then_remove_json_cache_file");
+        let json_cache_file = test_dir.join("immutables_digests_devnet.json");
+        std::fs::write(
+            &json_cache_file,
+            serde_json::to_string(&BTreeMap::from([(immutable.filename.clone(), "digest")]))
+                .unwrap(),
+        )
+        .unwrap();
+
+        let provider = SqliteImmutableFileDigestCacheProviderBuilder::new(
+            &test_dir.join("immutables_digests.sqlite3"),
+            cardano_db.get_immutable_dir(),
+        )
+        .with_json_cache_to_import(&json_cache_file)
+        .build()
+        .await
+        .expect("Build should not fail");
+
+        assert!(!json_cache_file.exists());
+        assert_eq!(
+            Some("digest".to_string()),
+            provider.get(vec![immutable.clone()]).await.unwrap()[&immutable]
+        );
+    }
+
+    #[tokio::test]
+    async fn values_are_shared_between_providers_opened_on_the_same_file() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "sqlite_digest_cache_provider_builder_values_are_shared_between_providers",
+        )
+        .with_immutables(&[1])
+        .build();
+        let immutable = cardano_db.get_immutable_files()[0].clone();
+        let sqlite_file =
+            get_test_dir("values_are_shared_between_providers_opened_on_the_same_file")
+                .join("immutables_digests.sqlite3");
+        let signer_provider = SqliteImmutableFileDigestCacheProviderBuilder::new(
+            &sqlite_file,
+            cardano_db.get_immutable_dir(),
+        )
+        .build()
+        .await
+        .unwrap();
+        let aggregator_provider = SqliteImmutableFileDigestCacheProviderBuilder::new(
+            &sqlite_file,
+            cardano_db.get_immutable_dir(),
+        )
+        .with_node_type(ApplicationNodeType::Aggregator)
+        .build()
+        .await
+        .unwrap();
+
+        signer_provider
+            .store(vec![(immutable.filename.clone(), "digest".to_string())])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some("digest".to_string()),
+            aggregator_provider.get(vec![immutable.clone()]).await.unwrap()[&immutable]
+        );
+    }
+}
@@ -147,13 +147,16 @@ impl<'conn> DatabaseVersionChecker<'conn> {
         )? == 1;
 
         if !table_exists {
-            let sql = format!("
-create table db_version (application_type text not null primary key, version integer not null, updated_at text not null);
-insert into db_version (application_type, version, updated_at) values ('{application_type}', 0, '{}');
-", Utc::now().to_rfc3339());
-            connection.execute(sql)?;
+            connection.execute("create table db_version (application_type text not null primary key, version integer not null, updated_at text not null);")?;
         }
 
+        // The database can be shared with other application types, each tracking its own version
+        let sql = format!(
+            "insert or ignore into db_version (application_type, version, updated_at) values ('{application_type}', 0, '{}');",
+            Utc::now().to_rfc3339()
+        );
+        connection.execute(sql)?;
+
         Ok(())
     }
 
@@ -429,6 +432,31 @@ mod tests {
         check_database_version(&connection, 1);
     }
 
+    #[test]
+    fn each_application_type_sharing_a_database_track_its_own_version() {
+        let (_filepath, connection) = create_sqlite_file(current_function!()).unwrap();
+        let migration =
+            SqlMigration::new(1, "create table if not exists whatever (thing_id integer);");
+
+        let mut signer_db_checker =
+            DatabaseVersionChecker::new(discard_logger(), ApplicationNodeType::Signer, &connection);
+        signer_db_checker.add_migration(migration.clone());
+        signer_db_checker.apply().unwrap();
+
+        let mut aggregator_db_checker = create_db_checker(&connection);
+        aggregator_db_checker.add_migration(migration);
+        aggregator_db_checker.apply().unwrap();
+
+        check_database_version(&connection, 1);
+        let signer_version = connection
+            .fetch_first(GetDatabaseVersionQuery::get_application_version(
+                &ApplicationNodeType::Signer,
+            ))
+            .unwrap()
+            .unwrap();
+        assert_eq!(1, signer_version.version);
+    }
+
     #[test]
     /// This test case ensure that when multiple migrations are played and one fails:
     /// * previous migrations are ok and the database version is updated
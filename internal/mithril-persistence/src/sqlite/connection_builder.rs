@@ -1,5 +1,6 @@
 use std::ops::Not;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::Context;
 use slog::{Logger, debug};
@@ -32,6 +33,10 @@ pub enum ConnectionOptions {
     ///
     /// This option take priority over [ConnectionOptions::EnableForeignKeys] if both are enabled.
     ForceDisableForeignKeys,
+
+    /// Wait up to the given duration for the locks held by other connections, including the
+    /// ones of other processes, to be released instead of failing immediately
+    BusyTimeout(Duration),
 }
 
 impl ConnectionBuilder {
@@ -90,6 +95,15 @@ impl ConnectionBuilder {
                 )
             })?;
 
+        if let Some(timeout) = self.options.iter().find_map(|option| match option {
+            ConnectionOptions::BusyTimeout(timeout) => Some(timeout),
+            _ => None,
+        }) {
+            connection
+                .execute(format!("pragma busy_timeout = {};", timeout.as_millis()))
+                .with_context(|| "SQLite initialization: could not set busy timeout.")?;
+        }
+
         if self.options.contains(&ConnectionOptions::EnableWriteAheadLog) {
             connection
                 .execute("pragma journal_mode = wal; pragma synchronous = normal;")
@@ -186,6 +200,18 @@ mod tests {
         assert_eq!(Value::Integer(true.into()), foreign_keys);
     }
 
+    #[test]
+    fn test_open_with_busy_timeout() {
+        let connection = ConnectionBuilder::open_memory()
+            .with_options(&[ConnectionOptions::BusyTimeout(Duration::from_millis(2500))])
+            .build()
+            .unwrap();
+
+        let busy_timeout = execute_single_cell_query(&connection, "pragma busy_timeout;");
+
+        assert_eq!(Value::Integer(2500), busy_timeout);
+    }
+
     #[test]
     fn test_open_file_without_wal_and_foreign_keys() {
         let dirpath = TempDir::create(
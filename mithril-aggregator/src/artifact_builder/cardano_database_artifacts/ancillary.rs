@@ -12,7 +12,7 @@ use mithril_common::{
 
 use crate::{
     DumbUploader, FileUploader,
-    file_uploaders::{CloudUploader, LocalUploader},
+    file_uploaders::{CloudUploader, LocalUploader, MirrorUploadPolicy},
     services::Snapshotter,
     tools::file_archiver::FileArchive,
 };
@@ -87,6 +87,7 @@ pub struct AncillaryUpload {
 /// The archive is uploaded with the provided uploaders.
 pub struct AncillaryArtifactBuilder {
     uploaders: Vec<Arc<dyn AncillaryFileUploader>>,
+    upload_policy: MirrorUploadPolicy,
     snapshotter: Arc<dyn Snapshotter>,
     cardano_network: CardanoNetwork,
     logger: Logger,
@@ -108,12 +109,19 @@ impl AncillaryArtifactBuilder {
 
         Ok(Self {
             uploaders,
+            upload_policy: MirrorUploadPolicy::default(),
             logger: logger.new_with_component_name::<Self>(),
             cardano_network,
             snapshotter,
         })
     }
 
+    /// Set the policy defining how many uploaders must succeed for an upload to be successful.
+    pub fn with_upload_policy(mut self, upload_policy: MirrorUploadPolicy) -> Self {
+        self.upload_policy = upload_policy;
+        self
+    }
+
     pub async fn upload(&self, beacon: &CardanoDbBeacon) -> StdResult<AncillaryUpload> {
         let snapshot = self.create_ancillary_archive(beacon).await?;
         let locations = self.upload_ancillary_archive(&snapshot).await?;
@@ -192,11 +200,9 @@ impl AncillaryArtifactBuilder {
             );
         }
 
-        if locations.is_empty() {
-            return Err(anyhow!(
-                "Failed to upload ancillary archive with all uploaders"
-            ));
-        }
+        self.upload_policy
+            .check(locations.len(), self.uploaders.len())
+            .with_context(|| "Failed to upload ancillary archive")?;
 
         Ok(locations)
     }
@@ -354,6 +360,40 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn upload_ancillary_archive_should_error_with_uploaders_errors_not_allowed_by_the_upload_policy()
+     {
+        let first_uploader = fake_uploader_returning_error();
+        let second_uploader =
+            fake_uploader("archive_path", "an_uri", Some(CompressionAlgorithm::Gzip));
+
+        let uploaders: Vec<Arc<dyn AncillaryFileUploader>> =
+            vec![Arc::new(first_uploader), Arc::new(second_uploader)];
+
+        let builder = AncillaryArtifactBuilder::new(
+            uploaders,
+            Arc::new(DumbSnapshotter::default()),
+            CardanoNetwork::TestNet(123),
+            TestLogger::stdout(),
+        )
+        .unwrap()
+        .with_upload_policy(MirrorUploadPolicy::All);
+
+        let result = builder
+            .upload_ancillary_archive(&FileArchive::new(
+                PathBuf::from("archive_path"),
+                0,
+                0,
+                CompressionAlgorithm::Gzip,
+            ))
+            .await;
+
+        assert!(
+            result.is_err(),
+            "Should return an error when an uploader fails with the 'all' upload policy"
+        );
+    }
+
     #[tokio::test]
     async fn upload_ancillary_archive_should_return_all_uploaders_returned_locations() {
         let first_uploader =
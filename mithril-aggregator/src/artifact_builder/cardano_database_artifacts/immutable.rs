@@ -1,19 +1,28 @@
-use std::{fs, path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::PathBuf,
+    sync::Arc,
+};
 
 use anyhow::{Context, anyhow};
 use async_trait::async_trait;
 use regex::Regex;
-use slog::{Logger, error};
+use slog::{Logger, error, info};
+use tokio::task::JoinSet;
 
 use mithril_common::{
     StdResult,
-    entities::{CompressionAlgorithm, ImmutableFileNumber, ImmutablesLocation, MultiFilesUri},
+    entities::{
+        CompressionAlgorithm, HexEncodedDigest, ImmutableFileNumber, ImmutablesLocation,
+        MultiFilesUri,
+    },
     logging::LoggerExtensions,
 };
 
 use crate::{
     DumbUploader, FileUploader,
-    file_uploaders::{CloudUploader, LocalUploader},
+    file_uploaders::{CloudUploader, LocalUploader, MirrorUploadPolicy},
     services::Snapshotter,
 };
 
@@ -43,6 +52,47 @@ pub trait ImmutableFilesUploader: Send + Sync {
     ) -> StdResult<ImmutablesLocation>;
 }
 
+/// An immutable files archive already uploaded to a location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadedImmutableArchive {
+    /// Number of the immutable files trio of the archive
+    pub immutable_file_number: ImmutableFileNumber,
+    /// Digest of the immutable files trio of the archive
+    pub digest: HexEncodedDigest,
+    /// Size of the uploaded archive
+    pub archive_size: u64,
+}
+
+/// The [ImmutableFileArchiveUploadTracker] trait keeps track of the immutable files archives
+/// already uploaded to each location.
+///
+/// Immutable files never change once completed, so an archive uploaded for a given immutable
+/// file number and digest does not need to be compressed nor uploaded again.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ImmutableFileArchiveUploadTracker: Send + Sync {
+    /// Get the digest of the trio of each immutable file up to the given immutable file number.
+    ///
+    /// Trios with a file without a known digest are not included.
+    async fn get_immutable_trio_digests(
+        &self,
+        up_to_immutable_file_number: ImmutableFileNumber,
+    ) -> StdResult<BTreeMap<ImmutableFileNumber, HexEncodedDigest>>;
+
+    /// Get the archives already uploaded to the given location, by immutable file number.
+    async fn get_uploaded_archives(
+        &self,
+        location_uri: &str,
+    ) -> StdResult<BTreeMap<ImmutableFileNumber, UploadedImmutableArchive>>;
+
+    /// Record that the given archives are uploaded to the given location.
+    async fn record_uploaded_archives(
+        &self,
+        location_uri: &str,
+        archives: Vec<UploadedImmutableArchive>,
+    ) -> StdResult<()>;
+}
+
 #[derive(Debug)]
 pub struct ImmutablesUpload {
     pub locations: Vec<ImmutablesLocation>,
@@ -129,7 +179,10 @@ impl ImmutableFilesUploader for CloudUploader {
 pub struct ImmutableArtifactBuilder {
     immutables_storage_dir: PathBuf,
     uploaders: Vec<Arc<dyn ImmutableFilesUploader>>,
+    upload_policy: MirrorUploadPolicy,
     snapshotter: Arc<dyn Snapshotter>,
+    archives_parallelism: usize,
+    upload_tracker: Option<Arc<dyn ImmutableFileArchiveUploadTracker>>,
     logger: Logger,
 }
 
@@ -158,21 +211,55 @@ impl ImmutableArtifactBuilder {
         Ok(Self {
             immutables_storage_dir,
             uploaders,
+            upload_policy: MirrorUploadPolicy::default(),
             snapshotter,
+            archives_parallelism: 1,
+            upload_tracker: None,
             logger: logger.new_with_component_name::<Self>(),
         })
     }
 
+    /// Set the maximum number of immutable archives created in parallel (minimum 1).
+    pub fn with_archives_parallelism(mut self, archives_parallelism: usize) -> Self {
+        self.archives_parallelism = archives_parallelism.max(1);
+        self
+    }
+
+    /// Set the policy defining how many uploaders must succeed for an upload to be successful.
+    pub fn with_upload_policy(mut self, upload_policy: MirrorUploadPolicy) -> Self {
+        self.upload_policy = upload_policy;
+        self
+    }
+
+    /// Set the tracker used to skip the archives already uploaded.
+    pub fn with_upload_tracker(
+        mut self,
+        upload_tracker: Arc<dyn ImmutableFileArchiveUploadTracker>,
+    ) -> Self {
+        self.upload_tracker = Some(upload_tracker);
+        self
+    }
+
     pub async fn upload(
         &self,
         up_to_immutable_file_number: ImmutableFileNumber,
     ) -> StdResult<ImmutablesUpload> {
-        let (archives_paths, compression_algorithm) = self
-            .immutable_archives_paths_creating_the_missing_ones(up_to_immutable_file_number)
-            .await?;
-        let locations = self
-            .upload_immutable_archives(&archives_paths, compression_algorithm)
-            .await?;
+        let locations = match &self.upload_tracker {
+            Some(upload_tracker) => {
+                self.upload_immutable_archives_not_yet_uploaded(
+                    upload_tracker.as_ref(),
+                    up_to_immutable_file_number,
+                )
+                .await?
+            }
+            None => {
+                let (archives_paths, compression_algorithm) = self
+                    .immutable_archives_paths_creating_the_missing_ones(up_to_immutable_file_number)
+                    .await?;
+                self.upload_immutable_archives(&archives_paths, compression_algorithm)
+                    .await?
+            }
+        };
         let total_size = self
             .snapshotter
             .compute_immutable_files_total_uncompressed_size(up_to_immutable_file_number)
@@ -191,11 +278,29 @@ impl ImmutableArtifactBuilder {
         &self,
         up_to_immutable_file_number: ImmutableFileNumber,
     ) -> StdResult<(Vec<PathBuf>, CompressionAlgorithm)> {
-        let mut archive_paths = vec![];
-        let compression_algorithm = self.snapshotter.compression_algorithm();
         const FIRST_IMMUTABLE_FILE_NUMBER: ImmutableFileNumber = 0;
+        let archive_paths = self
+            .archives_paths_creating_the_missing_ones(
+                (FIRST_IMMUTABLE_FILE_NUMBER..=up_to_immutable_file_number).collect(),
+            )
+            .await?;
 
-        for immutable_file_number in FIRST_IMMUTABLE_FILE_NUMBER..=up_to_immutable_file_number {
+        Ok((
+            archive_paths.into_values().collect(),
+            self.snapshotter.compression_algorithm(),
+        ))
+    }
+
+    async fn archives_paths_creating_the_missing_ones(
+        &self,
+        immutable_file_numbers: BTreeSet<ImmutableFileNumber>,
+    ) -> StdResult<BTreeMap<ImmutableFileNumber, PathBuf>> {
+        let mut archive_paths = BTreeMap::new();
+        let mut archive_creations: JoinSet<StdResult<(ImmutableFileNumber, PathBuf)>> =
+            JoinSet::new();
+        let compression_algorithm = self.snapshotter.compression_algorithm();
+
+        for immutable_file_number in immutable_file_numbers {
             let archive_name_without_extension = format!("{immutable_file_number:05}");
             let archive_name = format!(
                 "{archive_name_without_extension}.{}",
@@ -203,27 +308,191 @@ impl ImmutableArtifactBuilder {
             );
 
             if let Some(existing_archive) = self.retrieve_existing_snapshot_archive(&archive_name) {
-                archive_paths.push(existing_archive);
+                archive_paths.insert(immutable_file_number, existing_archive);
             } else {
-                let snapshot = self
-                    .snapshotter
-                    .snapshot_immutable_trio(immutable_file_number, &archive_name_without_extension)
-                    .await?;
+                if archive_creations.len() >= self.archives_parallelism {
+                    let (number, path) = Self::join_next_archive_creation(&mut archive_creations)
+                        .await?
+                        .expect("JoinSet is not empty");
+                    archive_paths.insert(number, path);
+                }
 
+                let snapshotter = self.snapshotter.clone();
                 let target_path = self.immutables_storage_dir.join(&archive_name);
-                fs::rename(snapshot.get_file_path(), &target_path).with_context(|| {
-                    format!(
-                        "Can not move archive of immutable {immutable_file_number} from '{}' to '{}'",
-                        snapshot.get_file_path().display(),
-                        target_path.display()
-                    )
-                })?;
+                archive_creations.spawn(async move {
+                    let snapshot = snapshotter
+                        .snapshot_immutable_trio(immutable_file_number, &archive_name_without_extension)
+                        .await?;
+
+                    fs::rename(snapshot.get_file_path(), &target_path).with_context(|| {
+                        format!(
+                            "Can not move archive of immutable {immutable_file_number} from '{}' to '{}'",
+                            snapshot.get_file_path().display(),
+                            target_path.display()
+                        )
+                    })?;
+
+                    Ok((immutable_file_number, target_path))
+                });
+            }
+        }
+
+        while let Some((number, path)) =
+            Self::join_next_archive_creation(&mut archive_creations).await?
+        {
+            archive_paths.insert(number, path);
+        }
 
-                archive_paths.push(target_path);
+        Ok(archive_paths)
+    }
+
+    /// Upload the immutable archives, skipping the ones already uploaded to each location.
+    ///
+    /// The archive of the last immutable file is always uploaded as it allows to discover the
+    /// location of each uploader.
+    async fn upload_immutable_archives_not_yet_uploaded(
+        &self,
+        upload_tracker: &dyn ImmutableFileArchiveUploadTracker,
+        up_to_immutable_file_number: ImmutableFileNumber,
+    ) -> StdResult<Vec<ImmutablesLocation>> {
+        const FIRST_IMMUTABLE_FILE_NUMBER: ImmutableFileNumber = 0;
+        let compression_algorithm = self.snapshotter.compression_algorithm();
+        let trio_digests = upload_tracker
+            .get_immutable_trio_digests(up_to_immutable_file_number)
+            .await?;
+        let last_archive_path = self
+            .archives_paths_creating_the_missing_ones(BTreeSet::from([up_to_immutable_file_number]))
+            .await?
+            .remove(&up_to_immutable_file_number)
+            .ok_or_else(|| anyhow!("Missing archive of the last immutable file"))?;
+
+        let mut pending_uploads = Vec::new();
+        for uploader in &self.uploaders {
+            let location = match uploader
+                .batch_upload(
+                    std::slice::from_ref(&last_archive_path),
+                    Some(compression_algorithm),
+                )
+                .await
+            {
+                Ok(location) => location,
+                Err(e) => {
+                    error!(
+                        self.logger,
+                        "Failed to upload immutable archive";
+                        "error" => e.to_string()
+                    );
+                    continue;
+                }
+            };
+            let location_uri = Self::location_uri(&location)?;
+            let uploaded_archives = upload_tracker.get_uploaded_archives(&location_uri).await?;
+            let (already_uploaded, missing): (Vec<_>, Vec<_>) =
+                (FIRST_IMMUTABLE_FILE_NUMBER..up_to_immutable_file_number).partition(
+                    |number| match (trio_digests.get(number), uploaded_archives.get(number)) {
+                        (Some(digest), Some(uploaded)) => &uploaded.digest == digest,
+                        _ => false,
+                    },
+                );
+            let saved_size: u64 = already_uploaded
+                .iter()
+                .filter_map(|number| uploaded_archives.get(number))
+                .map(|uploaded| uploaded.archive_size)
+                .sum();
+            info!(
+                self.logger,
+                "Skipping the upload of {} immutable archives already uploaded", already_uploaded.len();
+                "location" => &location_uri, "bytes_saved" => saved_size
+            );
+
+            pending_uploads.push((uploader, location, location_uri, missing));
+        }
+
+        let missing_numbers: BTreeSet<ImmutableFileNumber> = pending_uploads
+            .iter()
+            .flat_map(|(_, _, _, missing)| missing.iter().copied())
+            .collect();
+        let mut archive_paths =
+            self.archives_paths_creating_the_missing_ones(missing_numbers).await?;
+        archive_paths.insert(up_to_immutable_file_number, last_archive_path);
+
+        let mut locations = Vec::new();
+        for (uploader, location, location_uri, missing) in pending_uploads {
+            if !missing.is_empty() {
+                let paths: Vec<PathBuf> =
+                    missing.iter().map(|number| archive_paths[number].clone()).collect();
+                match uploader.batch_upload(&paths, Some(compression_algorithm)).await {
+                    Ok(missing_location) if missing_location == location => {}
+                    Ok(_) => {
+                        error!(
+                            self.logger,
+                            "Failed to upload immutable archive: location differs from the last immutable archive one";
+                            "location" => &location_uri
+                        );
+                        continue;
+                    }
+                    Err(e) => {
+                        error!(
+                            self.logger,
+                            "Failed to upload immutable archive";
+                            "error" => e.to_string()
+                        );
+                        continue;
+                    }
+                }
+            }
+
+            let uploaded_archives = missing
+                .into_iter()
+                .chain([up_to_immutable_file_number])
+                .filter_map(|number| {
+                    trio_digests.get(&number).map(|digest| (number, digest.clone()))
+                })
+                .map(|(number, digest)| -> StdResult<_> {
+                    Ok(UploadedImmutableArchive {
+                        immutable_file_number: number,
+                        digest,
+                        archive_size: fs::metadata(&archive_paths[&number])?.len(),
+                    })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            upload_tracker
+                .record_uploaded_archives(&location_uri, uploaded_archives)
+                .await?;
+
+            locations.push(location);
+        }
+
+        self.upload_policy
+            .check(locations.len(), self.uploaders.len())
+            .with_context(|| "Failed to upload immutable archive")?;
+
+        Ok(locations)
+    }
+
+    fn location_uri(location: &ImmutablesLocation) -> StdResult<String> {
+        match location {
+            ImmutablesLocation::CloudStorage {
+                uri: MultiFilesUri::Template(template_uri),
+                ..
+            } => Ok(template_uri.0.clone()),
+            ImmutablesLocation::Unknown => {
+                Err(anyhow!("Can not track uploads to an unknown location"))
             }
         }
+    }
 
-        Ok((archive_paths, compression_algorithm))
+    async fn join_next_archive_creation(
+        archive_creations: &mut JoinSet<StdResult<(ImmutableFileNumber, PathBuf)>>,
+    ) -> StdResult<Option<(ImmutableFileNumber, PathBuf)>> {
+        match archive_creations.join_next().await {
+            Some(result) => {
+                let archive =
+                    result.with_context(|| "Immutable archive creation task failed")??;
+                Ok(Some(archive))
+            }
+            None => Ok(None),
+        }
     }
 
     async fn upload_immutable_archives(
@@ -250,11 +519,9 @@ impl ImmutableArtifactBuilder {
             }
         }
 
-        if locations.is_empty() {
-            return Err(anyhow!(
-                "Failed to upload immutable archive with all uploaders"
-            ));
-        }
+        self.upload_policy
+            .check(locations.len(), self.uploaders.len())
+            .with_context(|| "Failed to upload immutable archive")?;
 
         Ok(locations)
     }
@@ -479,6 +746,52 @@ mod tests {
             )
         }
 
+        #[tokio::test]
+        async fn snapshot_immutables_files_in_parallel_keeps_immutable_file_number_order() {
+            let work_dir = get_builder_work_dir(
+                "snapshot_immutables_files_in_parallel_keeps_immutable_file_number_order",
+            );
+            let test_dir = "snapshot_immutables_files_in_parallel_keeps_immutable_file_number_order/cardano_database";
+            let cardano_db = DummyCardanoDbBuilder::new(test_dir)
+                .with_immutables(&[0, 1, 2, 3, 4, 5])
+                .build();
+
+            let db_directory = cardano_db.get_dir().to_path_buf();
+            let snapshotter = CompressedArchiveSnapshotter::new(
+                db_directory.clone(),
+                db_directory.parent().unwrap().join("snapshot_dest"),
+                CompressionAlgorithm::Gzip,
+                Arc::new(FileArchiver::new_for_test(work_dir.join("verification"))),
+                Arc::new(MockAncillarySigner::new()),
+                TestLogger::stdout(),
+            )
+            .unwrap();
+
+            let builder = ImmutableArtifactBuilder::new(
+                work_dir.clone(),
+                vec![Arc::new(MockImmutableFilesUploader::new())],
+                Arc::new(snapshotter),
+                TestLogger::stdout(),
+            )
+            .unwrap()
+            .with_archives_parallelism(3);
+
+            let (archive_paths, _) = builder
+                .immutable_archives_paths_creating_the_missing_ones(5)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                (0..=5)
+                    .map(|number| work_dir.join(format!("{number:05}.tar.gz")))
+                    .collect::<Vec<_>>(),
+                archive_paths
+            );
+            for archive_path in archive_paths {
+                assert!(archive_path.exists());
+            }
+        }
+
         #[tokio::test]
         async fn return_error_when_one_of_the_three_immutable_files_is_missing() {
             let work_dir = get_builder_work_dir(
@@ -852,6 +1165,185 @@ mod tests {
         }
     }
 
+    mod upload_with_tracker {
+        use mockall::predicate::eq;
+
+        use super::*;
+
+        const LOCATION_URI: &str = "http://server/{immutable_file_number}.tar.gz";
+
+        fn location() -> ImmutablesLocation {
+            ImmutablesLocation::CloudStorage {
+                uri: MultiFilesUri::Template(TemplateUri(LOCATION_URI.to_string())),
+                compression_algorithm: Some(CompressionAlgorithm::Gzip),
+            }
+        }
+
+        fn uploaded(number: ImmutableFileNumber, digest: &str) -> UploadedImmutableArchive {
+            UploadedImmutableArchive {
+                immutable_file_number: number,
+                digest: digest.to_string(),
+                archive_size: 1000,
+            }
+        }
+
+        fn build_snapshotter(test_name: &str, work_dir: &Path) -> CompressedArchiveSnapshotter {
+            let cardano_db = DummyCardanoDbBuilder::new(&format!("{test_name}/cardano_database"))
+                .with_immutables(&[0, 1, 2])
+                .build();
+            let db_directory = cardano_db.get_dir().to_path_buf();
+
+            CompressedArchiveSnapshotter::new(
+                db_directory.clone(),
+                db_directory.parent().unwrap().join("snapshot_dest"),
+                CompressionAlgorithm::Gzip,
+                Arc::new(FileArchiver::new_for_test(work_dir.join("verification"))),
+                Arc::new(MockAncillarySigner::new()),
+                TestLogger::stdout(),
+            )
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn skip_compression_and_upload_of_archives_already_uploaded_with_same_digest() {
+            let test_name =
+                "skip_compression_and_upload_of_archives_already_uploaded_with_same_digest";
+            let work_dir = get_builder_work_dir(test_name);
+            let snapshotter = build_snapshotter(test_name, &work_dir);
+
+            let mut uploader = MockImmutableFilesUploader::new();
+            let mut sequence = mockall::Sequence::new();
+            for expected_paths in [
+                vec![work_dir.join("00002.tar.gz")],
+                vec![work_dir.join("00001.tar.gz")],
+            ] {
+                uploader
+                    .expect_batch_upload()
+                    .withf(move |paths, _| paths == expected_paths)
+                    .times(1)
+                    .in_sequence(&mut sequence)
+                    .returning(|_, _| Ok(location()));
+            }
+
+            let mut tracker = MockImmutableFileArchiveUploadTracker::new();
+            tracker
+                .expect_get_immutable_trio_digests()
+                .with(eq(2))
+                .return_once(|_| {
+                    Ok(BTreeMap::from([
+                        (0, "digest-0".to_string()),
+                        (1, "digest-1".to_string()),
+                        (2, "digest-2".to_string()),
+                    ]))
+                });
+            tracker
+                .expect_get_uploaded_archives()
+                .withf(|location_uri| location_uri == LOCATION_URI)
+                .return_once(|_| {
+                    Ok(BTreeMap::from([
+                        (0, uploaded(0, "digest-0")),
+                        (1, uploaded(1, "outdated-digest-1")),
+                    ]))
+                });
+            tracker
+                .expect_record_uploaded_archives()
+                .withf(|location_uri, archives| {
+                    let numbers: Vec<_> =
+                        archives.iter().map(|a| a.immutable_file_number).collect();
+                    location_uri == LOCATION_URI && numbers == vec![1, 2]
+                })
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let builder = ImmutableArtifactBuilder::new(
+                work_dir.clone(),
+                vec![Arc::new(uploader)],
+                Arc::new(snapshotter),
+                TestLogger::stdout(),
+            )
+            .unwrap()
+            .with_upload_tracker(Arc::new(tracker));
+
+            let upload = builder.upload(2).await.unwrap();
+
+            assert_eq!(vec![location()], upload.locations);
+            assert!(!work_dir.join("00000.tar.gz").exists());
+        }
+
+        #[tokio::test]
+        async fn upload_all_archives_when_no_digest_is_known() {
+            let test_name = "upload_all_archives_when_no_digest_is_known";
+            let work_dir = get_builder_work_dir(test_name);
+            let snapshotter = build_snapshotter(test_name, &work_dir);
+
+            let mut uploader = MockImmutableFilesUploader::new();
+            let mut sequence = mockall::Sequence::new();
+            for expected_paths in [
+                vec![work_dir.join("00002.tar.gz")],
+                vec![work_dir.join("00000.tar.gz"), work_dir.join("00001.tar.gz")],
+            ] {
+                uploader
+                    .expect_batch_upload()
+                    .withf(move |paths, _| paths == expected_paths)
+                    .times(1)
+                    .in_sequence(&mut sequence)
+                    .returning(|_, _| Ok(location()));
+            }
+
+            let mut tracker = MockImmutableFileArchiveUploadTracker::new();
+            tracker
+                .expect_get_immutable_trio_digests()
+                .return_once(|_| Ok(BTreeMap::new()));
+            tracker
+                .expect_get_uploaded_archives()
+                .return_once(|_| Ok(BTreeMap::from([(0, uploaded(0, "digest-0"))])));
+            tracker
+                .expect_record_uploaded_archives()
+                .withf(|_, archives| archives.is_empty())
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let builder = ImmutableArtifactBuilder::new(
+                work_dir,
+                vec![Arc::new(uploader)],
+                Arc::new(snapshotter),
+                TestLogger::stdout(),
+            )
+            .unwrap()
+            .with_upload_tracker(Arc::new(tracker));
+
+            let upload = builder.upload(2).await.unwrap();
+
+            assert_eq!(vec![location()], upload.locations);
+        }
+
+        #[tokio::test]
+        async fn error_when_all_uploaders_fail() {
+            let test_name = "tracker_error_when_all_uploaders_fail";
+            let work_dir = get_builder_work_dir(test_name);
+            let snapshotter = build_snapshotter(test_name, &work_dir);
+
+            let mut tracker = MockImmutableFileArchiveUploadTracker::new();
+            tracker
+                .expect_get_immutable_trio_digests()
+                .return_once(|_| Ok(BTreeMap::new()));
+
+            let builder = ImmutableArtifactBuilder::new(
+                work_dir,
+                vec![Arc::new(fake_uploader_returning_error())],
+                Arc::new(snapshotter),
+                TestLogger::stdout(),
+            )
+            .unwrap()
+            .with_upload_tracker(Arc::new(tracker));
+
+            builder
+                .upload(2)
+                .await
+                .expect_err("Should return an error when all uploaders fail");
+        }
+    }
+
     mod batch_upload {
         use mithril_common::test_utils::TempDir;
 
@@ -13,7 +13,7 @@ use mithril_common::{
 };
 
 use super::ArtifactBuilder;
-use crate::{FileUploader, services::Snapshotter, tools::file_archiver::FileArchive};
+use crate::{MirrorUploader, services::Snapshotter, tools::file_archiver::FileArchive};
 
 /// [CardanoImmutableFilesFullArtifact] error
 #[derive(Debug, Error)]
@@ -28,7 +28,7 @@ pub struct CardanoImmutableFilesFullArtifactBuilder {
     cardano_network: CardanoNetwork,
     cardano_node_version: Version,
     snapshotter: Arc<dyn Snapshotter>,
-    snapshot_uploader: Arc<dyn FileUploader>,
+    snapshot_uploader: Arc<MirrorUploader>,
     logger: Logger,
 }
 
@@ -38,7 +38,7 @@ impl CardanoImmutableFilesFullArtifactBuilder {
         cardano_network: CardanoNetwork,
         cardano_node_version: &Version,
         snapshotter: Arc<dyn Snapshotter>,
-        snapshot_uploader: Arc<dyn FileUploader>,
+        snapshot_uploader: Arc<MirrorUploader>,
         logger: Logger,
     ) -> Self {
         Self {
@@ -95,7 +95,7 @@ impl CardanoImmutableFilesFullArtifactBuilder {
         ongoing_snapshot: &FileArchive,
     ) -> StdResult<Vec<FileUri>> {
         debug!(self.logger, ">> upload_snapshot_archive");
-        let location = self.snapshot_uploader.upload(ongoing_snapshot.get_file_path()).await;
+        let locations = self.snapshot_uploader.upload(ongoing_snapshot.get_file_path()).await;
 
         if let Err(error) = tokio::fs::remove_file(ongoing_snapshot.get_file_path()).await {
             warn!(
@@ -104,7 +104,7 @@ impl CardanoImmutableFilesFullArtifactBuilder {
             );
         }
 
-        Ok(vec![location?])
+        locations
     }
 
     fn create_snapshot(
@@ -209,12 +209,23 @@ mod tests {
     use mithril_common::{entities::CompressionAlgorithm, test_utils::fake_data};
 
     use crate::{
-        DumbUploader, file_uploaders::MockFileUploader, services::DumbSnapshotter,
-        test_tools::TestLogger,
+        DumbUploader, FileUploader, MirrorUploadPolicy, file_uploaders::MockFileUploader,
+        services::DumbSnapshotter, test_tools::TestLogger,
     };
 
     use super::*;
 
+    fn mirror_uploader(uploaders: Vec<Arc<dyn FileUploader>>) -> Arc<MirrorUploader> {
+        Arc::new(
+            MirrorUploader::new(
+                uploaders,
+                MirrorUploadPolicy::AtLeastOne,
+                TestLogger::stdout(),
+            )
+            .unwrap(),
+        )
+    }
+
     #[tokio::test]
     async fn should_compute_valid_artifact() {
         let beacon = fake_data::beacon();
@@ -236,7 +247,7 @@ mod tests {
                 fake_data::network(),
                 &Version::parse("1.0.0").unwrap(),
                 dumb_snapshotter.clone(),
-                dumb_snapshot_uploader.clone(),
+                mirror_uploader(vec![dumb_snapshot_uploader.clone()]),
                 TestLogger::stdout(),
             );
         let artifact = cardano_immutable_files_full_artifact_builder
@@ -263,6 +274,32 @@ mod tests {
         assert_eq!(artifact_expected, artifact);
     }
 
+    #[tokio::test]
+    async fn should_compute_artifact_with_the_locations_of_all_mirrored_uploaders() {
+        let beacon = fake_data::beacon();
+        let certificate = fake_data::certificate("certificate-123".to_string());
+        let first_uploader = Arc::new(DumbUploader::default());
+        let second_uploader = Arc::new(DumbUploader::default());
+
+        let cardano_immutable_files_full_artifact_builder =
+            CardanoImmutableFilesFullArtifactBuilder::new(
+                fake_data::network(),
+                &Version::parse("1.0.0").unwrap(),
+                Arc::new(DumbSnapshotter::default()),
+                mirror_uploader(vec![first_uploader.clone(), second_uploader.clone()]),
+                TestLogger::stdout(),
+            );
+        let artifact = cardano_immutable_files_full_artifact_builder
+            .compute_artifact(beacon, &certificate)
+            .await
+            .unwrap();
+
+        assert_eq!(2, artifact.locations.len());
+        assert_eq!(Some(2), artifact.ancillary_locations.map(|l| l.len()));
+        assert_eq!(2, first_uploader.get_last_n_uploads(2).unwrap().len());
+        assert_eq!(2, second_uploader.get_last_n_uploads(2).unwrap().len());
+    }
+
     #[tokio::test]
     async fn snapshot_archive_name_include_beacon_and_network_values() {
         let network = fake_data::network();
@@ -274,7 +311,7 @@ mod tests {
                 network,
                 &Version::parse("1.0.0").unwrap(),
                 Arc::new(DumbSnapshotter::default()),
-                Arc::new(DumbUploader::default()),
+                mirror_uploader(vec![Arc::new(DumbUploader::default())]),
                 TestLogger::stdout(),
             );
 
@@ -306,7 +343,7 @@ mod tests {
                 fake_data::network(),
                 &Version::parse("1.0.0").unwrap(),
                 Arc::new(DumbSnapshotter::default()),
-                Arc::new(DumbUploader::default()),
+                mirror_uploader(vec![Arc::new(DumbUploader::default())]),
                 TestLogger::stdout(),
             );
 
@@ -342,7 +379,7 @@ mod tests {
                 fake_data::network(),
                 &Version::parse("1.0.0").unwrap(),
                 Arc::new(DumbSnapshotter::default()),
-                Arc::new(snapshot_uploader),
+                mirror_uploader(vec![Arc::new(snapshot_uploader)]),
                 TestLogger::stdout(),
             );
 
@@ -0,0 +1,104 @@
+use anyhow::{Context, anyhow};
+use async_trait::async_trait;
+use mithril_common::{
+    StdResult,
+    entities::{
+        BlockNumber, CardanoTransactionsMetadataSnapshot, Certificate, ProtocolMessagePartKey,
+        SignedEntityType,
+    },
+};
+
+use super::ArtifactBuilder;
+
+/// A [CardanoTransactionsMetadataSnapshot] builder
+#[derive(Default)]
+pub struct CardanoTransactionsMetadataArtifactBuilder;
+
+impl CardanoTransactionsMetadataArtifactBuilder {
+    /// CardanoTransactionsMetadata artifact builder factory
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl ArtifactBuilder<BlockNumber, CardanoTransactionsMetadataSnapshot>
+    for CardanoTransactionsMetadataArtifactBuilder
+{
+    async fn compute_artifact(
+        &self,
+        beacon: BlockNumber,
+        certificate: &Certificate,
+    ) -> StdResult<CardanoTransactionsMetadataSnapshot> {
+        let merkle_root = certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::CardanoTransactionsMetadataMerkleRoot)
+            .ok_or(anyhow!(
+                "Can not find CardanoTransactionsMetadataMerkleRoot protocol message part in certificate"
+            ))
+            .with_context(|| {
+                format!(
+                    "Can not compute CardanoTransactionsMetadataSnapshot artifact for signed_entity: {:?}",
+                    SignedEntityType::CardanoTransactionsMetadata(certificate.epoch, beacon)
+                )
+            })?;
+
+        Ok(CardanoTransactionsMetadataSnapshot::new(
+            merkle_root.to_string(),
+            beacon,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::{entities::ProtocolMessage, test_utils::fake_data};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_compute_valid_artifact_with_merkleroot() {
+        let cardano_transactions_metadata_artifact_builder =
+            CardanoTransactionsMetadataArtifactBuilder::new();
+
+        let certificate_with_merkle_root = {
+            let mut protocol_message = ProtocolMessage::new();
+            protocol_message.set_message_part(
+                ProtocolMessagePartKey::CardanoTransactionsMetadataMerkleRoot,
+                "merkleroot".to_string(),
+            );
+            Certificate {
+                protocol_message,
+                ..fake_data::certificate("certificate-123".to_string())
+            }
+        };
+        let beacon = BlockNumber(100);
+
+        let artifact = cardano_transactions_metadata_artifact_builder
+            .compute_artifact(beacon, &certificate_with_merkle_root)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            CardanoTransactionsMetadataSnapshot::new("merkleroot".to_string(), beacon),
+            artifact
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_compute_artifact_without_merkle_root() {
+        let cardano_transactions_metadata_artifact_builder =
+            CardanoTransactionsMetadataArtifactBuilder::new();
+
+        let certificate_without_merkle_root = Certificate {
+            protocol_message: ProtocolMessage::new(),
+            ..fake_data::certificate("certificate-123".to_string())
+        };
+        let beacon = BlockNumber(100);
+
+        cardano_transactions_metadata_artifact_builder
+            .compute_artifact(beacon, &certificate_without_merkle_root)
+            .await
+            .expect_err("The artifact building must fail since there is no CardanoTransactionsMetadataMerkleRoot part in its message.");
+    }
+}
@@ -1,16 +1,20 @@
 //! The module used for building artifact
+mod cardano_blocks;
 mod cardano_database;
 mod cardano_database_artifacts;
 mod cardano_immutable_files_full;
 mod cardano_stake_distribution;
 mod cardano_transactions;
+mod cardano_transactions_metadata;
 mod interface;
 mod mithril_stake_distribution;
 
+pub use cardano_blocks::*;
 pub use cardano_database::*;
 pub use cardano_database_artifacts::*;
 pub use cardano_immutable_files_full::*;
 pub use cardano_stake_distribution::*;
 pub use cardano_transactions::*;
+pub use cardano_transactions_metadata::*;
 pub use interface::*;
 pub use mithril_stake_distribution::*;
@@ -1,16 +1,109 @@
-use std::{collections::HashMap, fs::File, io::Write, path::PathBuf};
+use std::{collections::HashMap, fs::File, io::Write, path::PathBuf, sync::Arc};
 
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use clap::{Parser, Subcommand};
+use config::{ConfigBuilder, Map, Value, builder::DefaultState};
+use serde::{Deserialize, Serialize};
+use slog::{Logger, debug};
+
+use mithril_cardano_node_chain::chain_observer::{ChainObserverRetryPolicy, ChainObserverType};
 use mithril_common::{
     StdResult,
     crypto_helper::{EraMarkersSigner, EraMarkersVerifierSecretKey},
     entities::{Epoch, HexEncodedEraMarkersSecretKey},
 };
-use mithril_doc::StructDoc;
-use slog::{Logger, debug};
+use mithril_doc::{Documenter, StructDoc};
+use mithril_era::{EraReadinessReport, adapters::EraReaderAdapterType};
+
+use crate::{
+    ConfigurationSource, ExecutionEnvironment, dependency_injection::DependenciesBuilder,
+    extract_all, tools::EraTools,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Documenter)]
+pub struct EraCommandConfiguration {
+    /// Cardano CLI tool path
+    #[example = "`cardano-cli`"]
+    pub cardano_cli_path: Option<PathBuf>,
+
+    /// Path of the socket opened by the Cardano node
+    #[example = "`/ipc/node.socket`"]
+    pub cardano_node_socket_path: PathBuf,
 
-use crate::{extract_all, tools::EraTools};
+    /// Cardano Network Magic number
+    ///
+    /// useful for TestNet & DevNet
+    #[example = "`1097911063` or `42`"]
+    pub network_magic: Option<u64>,
+
+    /// Cardano network
+    #[example = "`mainnet` or `preprod` or `devnet`"]
+    network: String,
+
+    /// Cardano chain observer type
+    pub chain_observer_type: ChainObserverType,
+
+    /// Ogmios endpoint, required when the chain observer type is `ogmios`
+    #[example = "`ws://localhost:1337`"]
+    pub ogmios_endpoint: Option<String>,
+
+    /// Era reader adapter type
+    pub era_reader_adapter_type: EraReaderAdapterType,
+
+    /// Era reader adapter parameters
+    pub era_reader_adapter_params: Option<String>,
+}
+
+impl ConfigurationSource for EraCommandConfiguration {
+    fn environment(&self) -> ExecutionEnvironment {
+        ExecutionEnvironment::Production
+    }
+
+    fn cardano_cli_path(&self) -> PathBuf {
+        match self.chain_observer_type {
+            ChainObserverType::CardanoCli => {
+                if let Some(path) = &self.cardano_cli_path {
+                    path.clone()
+                } else {
+                    panic!("Cardano CLI path must be set when using Cardano CLI chain observer")
+                }
+            }
+            _ => PathBuf::new(),
+        }
+    }
+
+    fn cardano_node_socket_path(&self) -> PathBuf {
+        self.cardano_node_socket_path.clone()
+    }
+
+    fn network_magic(&self) -> Option<u64> {
+        self.network_magic
+    }
+
+    fn network(&self) -> String {
+        self.network.clone()
+    }
+
+    fn chain_observer_type(&self) -> ChainObserverType {
+        self.chain_observer_type.clone()
+    }
+
+    fn ogmios_endpoint(&self) -> Option<String> {
+        self.ogmios_endpoint.clone()
+    }
+
+    fn chain_observer_retry_policy(&self) -> ChainObserverRetryPolicy {
+        ChainObserverRetryPolicy::default()
+    }
+
+    fn era_reader_adapter_type(&self) -> EraReaderAdapterType {
+        self.era_reader_adapter_type.clone()
+    }
+
+    fn era_reader_adapter_params(&self) -> Option<String> {
+        self.era_reader_adapter_params.clone()
+    }
+}
 
 /// Era tools
 #[derive(Parser, Debug, Clone)]
@@ -21,8 +114,12 @@ pub struct EraCommand {
 }
 
 impl EraCommand {
-    pub async fn execute(&self, root_logger: Logger) -> StdResult<()> {
-        self.era_subcommand.execute(root_logger).await
+    pub async fn execute(
+        &self,
+        root_logger: Logger,
+        config_builder: ConfigBuilder<DefaultState>,
+    ) -> StdResult<()> {
+        self.era_subcommand.execute(root_logger, config_builder).await
     }
 
     pub fn extract_config(command_path: String) -> HashMap<String, StructDoc> {
@@ -31,7 +128,9 @@ impl EraCommand {
             EraSubCommand,
             List = { ListEraSubCommand },
             GenerateTxDatum = { GenerateTxDatumEraSubCommand },
+            GenerateMarkersPayload = { GenerateMarkersPayloadEraSubCommand },
             GenerateKeypair = { GenerateKeypairEraSubCommand },
+            Readiness = { ReadinessEraSubCommand },
         )
     }
 }
@@ -45,16 +144,28 @@ pub enum EraSubCommand {
     /// Era tx datum generate command.
     GenerateTxDatum(GenerateTxDatumEraSubCommand),
 
+    /// Era markers payload generate command.
+    GenerateMarkersPayload(GenerateMarkersPayloadEraSubCommand),
+
     /// Era keypair generation command.
     GenerateKeypair(GenerateKeypairEraSubCommand),
+
+    /// Era transition readiness report command.
+    Readiness(ReadinessEraSubCommand),
 }
 
 impl EraSubCommand {
-    pub async fn execute(&self, root_logger: Logger) -> StdResult<()> {
+    pub async fn execute(
+        &self,
+        root_logger: Logger,
+        config_builder: ConfigBuilder<DefaultState>,
+    ) -> StdResult<()> {
         match self {
             Self::List(cmd) => cmd.execute(root_logger).await,
             Self::GenerateTxDatum(cmd) => cmd.execute(root_logger).await,
+            Self::GenerateMarkersPayload(cmd) => cmd.execute(root_logger).await,
             Self::GenerateKeypair(cmd) => cmd.execute(root_logger).await,
+            Self::Readiness(cmd) => cmd.execute(root_logger, config_builder).await,
         }
     }
 }
@@ -134,6 +245,53 @@ impl GenerateTxDatumEraSubCommand {
     }
 }
 
+/// Era markers payload generate command, the payload is meant to be published at the URL read
+/// by the 'http' era reader adapter
+#[derive(Parser, Debug, Clone)]
+pub struct GenerateMarkersPayloadEraSubCommand {
+    /// Current Era epoch
+    #[clap(long, env = "CURRENT_ERA_EPOCH")]
+    current_era_epoch: u64,
+
+    /// Next Era epoch start, if exists
+    #[clap(long, env = "NEXT_ERA_EPOCH")]
+    next_era_epoch: Option<u64>,
+
+    /// Era Markers Secret Key
+    #[clap(long, env = "ERA_MARKERS_SECRET_KEY")]
+    era_markers_secret_key: HexEncodedEraMarkersSecretKey,
+
+    /// Target path
+    #[clap(long)]
+    target_path: PathBuf,
+}
+
+impl GenerateMarkersPayloadEraSubCommand {
+    pub async fn execute(&self, root_logger: Logger) -> StdResult<()> {
+        debug!(root_logger, "GENERATEMARKERSPAYLOAD ERA command");
+        let era_tools = EraTools::new();
+
+        let era_markers_secret_key =
+            EraMarkersVerifierSecretKey::from_json_hex(&self.era_markers_secret_key)
+                .with_context(|| "json hex decode of era markers secret key failure")?;
+        let era_markers_signer = EraMarkersSigner::from_secret_key(era_markers_secret_key);
+        let markers_payload = era_tools.generate_markers_payload(
+            Epoch(self.current_era_epoch),
+            self.next_era_epoch.map(Epoch),
+            &era_markers_signer,
+        )?;
+
+        let mut target_file = File::create(&self.target_path)?;
+        target_file.write_all(markers_payload.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn extract_config(_parent: String) -> HashMap<String, StructDoc> {
+        HashMap::new()
+    }
+}
+
 /// Era keypair generation command.
 #[derive(Parser, Debug, Clone)]
 pub struct GenerateKeypairEraSubCommand {
@@ -160,3 +318,57 @@ impl GenerateKeypairEraSubCommand {
         HashMap::new()
     }
 }
+
+/// Era transition readiness report command.
+///
+/// Read the era markers and report whether this binary supports the current and the next era.
+#[derive(Parser, Debug, Clone)]
+pub struct ReadinessEraSubCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+}
+
+impl ReadinessEraSubCommand {
+    pub async fn execute(
+        &self,
+        root_logger: Logger,
+        config_builder: ConfigBuilder<DefaultState>,
+    ) -> StdResult<()> {
+        let config: EraCommandConfiguration = config_builder
+            .build()
+            .with_context(|| "configuration build error")?
+            .try_deserialize()
+            .with_context(|| "configuration deserialize error")?;
+        debug!(root_logger, "READINESS ERA command"; "config" => format!("{config:?}"));
+        let mut dependencies_builder =
+            DependenciesBuilder::new(root_logger.clone(), Arc::new(config));
+        let chain_observer = dependencies_builder.get_chain_observer().await?;
+        let era_reader = dependencies_builder.get_era_reader().await?;
+
+        let current_epoch = chain_observer
+            .get_current_epoch()
+            .await
+            .with_context(|| "era-readiness: can not get current epoch from the chain observer")?
+            .ok_or(anyhow!(
+                "era-readiness: no epoch returned by the chain observer"
+            ))?;
+        let era_epoch_token = era_reader
+            .read_era_epoch_token(current_epoch)
+            .await
+            .with_context(|| "era-readiness: can not read era epoch token")?;
+        let report = EraReadinessReport::from_era_epoch_token(&era_epoch_token);
+
+        if self.json {
+            println!("{}", serde_json::to_string(&report)?);
+        } else {
+            println!("{report}");
+        }
+
+        Ok(())
+    }
+
+    pub fn extract_config(command_path: String) -> HashMap<String, StructDoc> {
+        HashMap::from([(command_path, EraCommandConfiguration::extract())])
+    }
+}
@@ -6,7 +6,7 @@ use config::{ConfigBuilder, Map, Value, builder::DefaultState};
 use serde::{Deserialize, Serialize};
 use slog::{Logger, debug};
 
-use mithril_cardano_node_chain::chain_observer::ChainObserverType;
+use mithril_cardano_node_chain::chain_observer::{ChainObserverRetryPolicy, ChainObserverType};
 use mithril_common::{
     StdResult,
     crypto_helper::{
@@ -44,6 +44,10 @@ pub struct GenesisCommandConfiguration {
     /// Cardano chain observer type
     pub chain_observer_type: ChainObserverType,
 
+    /// Ogmios endpoint, required when the chain observer type is `ogmios`
+    #[example = "`ws://localhost:1337`"]
+    pub ogmios_endpoint: Option<String>,
+
     /// Directory to store aggregator data (Certificates, Snapshots, Protocol Parameters, ...)
     #[example = "`./mithril-aggregator/stores`"]
     pub data_stores_directory: PathBuf,
@@ -83,6 +87,14 @@ impl ConfigurationSource for GenesisCommandConfiguration {
         self.chain_observer_type.clone()
     }
 
+    fn ogmios_endpoint(&self) -> Option<String> {
+        self.ogmios_endpoint.clone()
+    }
+
+    fn chain_observer_retry_policy(&self) -> ChainObserverRetryPolicy {
+        ChainObserverRetryPolicy::default()
+    }
+
     fn data_stores_directory(&self) -> PathBuf {
         self.data_stores_directory.clone()
     }
@@ -380,6 +392,7 @@ mod tests {
             network_magic: Some(42),
             network: "devnet".to_string(),
             chain_observer_type: ChainObserverType::Fake,
+            ogmios_endpoint: None,
             data_stores_directory: temp_dir!().join("stores"),
         };
         let mut dependencies_builder =
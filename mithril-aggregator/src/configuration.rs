@@ -4,20 +4,23 @@ use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
-use mithril_cardano_node_chain::chain_observer::ChainObserverType;
+use mithril_cardano_node_chain::chain_observer::{ChainObserverRetryPolicy, ChainObserverType};
+use mithril_cardano_node_chain::chain_scanner::RollbackPolicy;
 use mithril_cli_helper::{register_config_value, serde_deserialization};
 use mithril_common::crypto_helper::{ManifestSigner, ProtocolGenesisSigner};
 use mithril_common::entities::{
-    BlockNumber, CardanoTransactionsSigningConfig, CompressionAlgorithm,
-    HexEncodedGenesisVerificationKey, HexEncodedKey, ProtocolParameters, SignedEntityConfig,
-    SignedEntityTypeDiscriminants,
+    BlockNumber, CardanoTransactionsLeafFormat, CardanoTransactionsSigningConfig,
+    CompressionAlgorithm, HexEncodedGenesisVerificationKey, HexEncodedKey, ProtocolParameters,
+    SignedEntityConfig, SignedEntityTypeDiscriminants,
 };
 use mithril_common::{CardanoNetwork, StdResult};
 use mithril_doc::{Documenter, DocumenterDefault, StructDoc};
 use mithril_era::adapters::EraReaderAdapterType;
 
 use crate::entities::AggregatorEpochSettings;
+use crate::file_uploaders::MirrorUploadPolicy;
 use crate::http_server::SERVER_BASE_PATH;
 use crate::services::ancillary_signer::GcpCryptoKeyVersionResourceName;
 use crate::tools::DEFAULT_GCP_CREDENTIALS_JSON_ENV_VAR;
@@ -97,6 +100,16 @@ pub trait ConfigurationSource {
         panic!("chain_observer_type is not implemented.");
     }
 
+    /// Ogmios endpoint, required when the chain observer type is `ogmios`
+    fn ogmios_endpoint(&self) -> Option<String> {
+        panic!("ogmios_endpoint is not implemented.");
+    }
+
+    /// Retry policy of the queries of the chain observer
+    fn chain_observer_retry_policy(&self) -> ChainObserverRetryPolicy {
+        panic!("chain_observer_retry_policy is not implemented.");
+    }
+
     /// Protocol parameters
     fn protocol_parameters(&self) -> ProtocolParameters {
         panic!("protocol_parameters is not implemented.");
@@ -107,6 +120,16 @@ pub trait ConfigurationSource {
         panic!("snapshot_uploader_type is not implemented.");
     }
 
+    /// Additional types of snapshot uploaders to which the artifacts are mirrored (comma separated list)
+    fn snapshot_mirror_uploader_types(&self) -> Option<String> {
+        panic!("snapshot_mirror_uploader_types is not implemented.");
+    }
+
+    /// Policy defining how many of the snapshot uploaders must succeed when mirroring artifacts
+    fn snapshot_mirror_upload_policy(&self) -> MirrorUploadPolicy {
+        panic!("snapshot_mirror_upload_policy is not implemented.");
+    }
+
     /// Bucket name where the snapshots are stored if snapshot_uploader_type is Gcp
     fn snapshot_bucket_name(&self) -> Option<String> {
         panic!("snapshot_bucket_name is not implemented.");
@@ -167,6 +190,11 @@ pub trait ConfigurationSource {
         panic!("disable_digests_cache is not implemented.");
     }
 
+    /// Path of a SQLite immutables digests cache shared with the other Mithril nodes
+    fn shared_digests_cache_path(&self) -> Option<PathBuf> {
+        panic!("shared_digests_cache_path is not implemented.");
+    }
+
     /// Max number of records in stores.
     /// When new records are added, oldest records are automatically deleted so
     /// there can always be at max the number of records specified by this
@@ -175,6 +203,13 @@ pub trait ConfigurationSource {
         panic!("store_retention_limit is not implemented.");
     }
 
+    /// Number of epochs for which the artifacts files stored locally are retained.
+    /// The files no longer referenced by a signed entity of a retained epoch are removed, except
+    /// the ones of the last signed entity of each type.
+    fn local_artifacts_retention_limit(&self) -> Option<u64> {
+        panic!("local_artifacts_retention_limit is not implemented.");
+    }
+
     /// Era reader adapter type
     fn era_reader_adapter_type(&self) -> EraReaderAdapterType {
         panic!("era_reader_adapter_type is not implemented.");
@@ -212,6 +247,17 @@ pub trait ConfigurationSource {
         panic!("zstandard_parameters is not implemented.");
     }
 
+    /// Specific parameters when [CompressionAlgorithm] is set to
+    /// [gzip][CompressionAlgorithm::Gzip].
+    fn gzip_parameters(&self) -> Option<GzipCompressionParameters> {
+        panic!("gzip_parameters is not implemented.");
+    }
+
+    /// Maximum number of immutable files archives of the Cardano database produced in parallel.
+    fn cardano_database_immutable_archives_parallelism(&self) -> usize {
+        panic!("cardano_database_immutable_archives_parallelism is not implemented.");
+    }
+
     /// Url to CExplorer list of pools to import as signer in the database.
     fn cexplorer_pools_url(&self) -> Option<String> {
         panic!("cexplorer_pools_url is not implemented.");
@@ -229,7 +275,7 @@ pub trait ConfigurationSource {
         panic!("allow_unparsable_block is not implemented.");
     }
 
-    /// Cardano transactions prover cache pool size
+    /// Cardano transactions prover cache pool size, also used by the Cardano blocks prover
     fn cardano_transactions_prover_cache_pool_size(&self) -> usize {
         panic!("cardano_transactions_prover_cache_pool_size is not implemented.");
     }
@@ -256,6 +302,28 @@ pub trait ConfigurationSource {
         );
     }
 
+    /// Policy applied when a rollback deeper than the security parameter is received while importing transactions.
+    fn cardano_transactions_rollback_policy(&self) -> RollbackPolicy {
+        panic!("cardano_transactions_rollback_policy is not implemented.");
+    }
+
+    /// Also known as `k`, it defines the number of blocks that are required for the blockchain to
+    /// be considered final, preventing any further rollback.
+    fn network_security_parameter(&self) -> BlockNumber {
+        panic!("network_security_parameter is not implemented.");
+    }
+
+    /// Format of the leaves of the Cardano transactions Merkle trees.
+    fn cardano_transactions_leaf_format(&self) -> CardanoTransactionsLeafFormat {
+        panic!("cardano_transactions_leaf_format is not implemented.");
+    }
+
+    /// Number of blocks for which the Cardano transactions are kept, the transactions of older
+    /// block ranges are pruned and only their block range roots are kept ("roots-only" mode).
+    fn cardano_transactions_retention_blocks(&self) -> Option<BlockNumber> {
+        panic!("cardano_transactions_retention_blocks is not implemented.");
+    }
+
     /// Enable metrics server (Prometheus endpoint on /metrics).
     fn enable_metrics_server(&self) -> bool {
         panic!("enable_metrics_server is not implemented.");
@@ -349,10 +417,25 @@ pub trait ConfigurationSource {
 
     /// Check if the HTTP server can serve static directories.
     fn allow_http_serve_directory(&self) -> bool {
-        match self.snapshot_uploader_type() {
-            SnapshotUploaderType::Local => true,
-            SnapshotUploaderType::Gcp => false,
+        self.compute_snapshot_uploader_types()
+            .map(|uploader_types| uploader_types.contains(&SnapshotUploaderType::Local))
+            .unwrap_or(self.snapshot_uploader_type() == SnapshotUploaderType::Local)
+    }
+
+    /// Compute the types of the snapshot uploaders to use: the main one followed by the mirrors.
+    fn compute_snapshot_uploader_types(&self) -> StdResult<Vec<SnapshotUploaderType>> {
+        let mut uploader_types = vec![self.snapshot_uploader_type()];
+
+        if let Some(mirror_uploader_types) = &self.snapshot_mirror_uploader_types() {
+            for uploader_type in mirror_uploader_types.split(',').map(str::trim) {
+                let uploader_type = SnapshotUploaderType::from_str(uploader_type)?;
+                if !uploader_types.contains(&uploader_type) {
+                    uploader_types.push(uploader_type);
+                }
+            }
         }
+
+        Ok(uploader_types)
     }
 
     /// Infer the [AggregatorEpochSettings] from the configuration.
@@ -422,6 +505,17 @@ pub struct ServeCommandConfiguration {
     /// Cardano chain observer type
     pub chain_observer_type: ChainObserverType,
 
+    /// Ogmios endpoint, required when the chain observer type is `ogmios`
+    #[example = "`ws://localhost:1337`"]
+    pub ogmios_endpoint: Option<String>,
+
+    /// Number of attempts of a failed chain observer query
+    pub chain_observer_retry_attempts: u8,
+
+    /// Delay (in milliseconds) before retrying a failed chain observer query, doubled after each
+    /// failed attempt
+    pub chain_observer_retry_initial_delay_ms: u64,
+
     /// Protocol parameters
     #[example = "`{ k: 5, m: 100, phi_f: 0.65 }`"]
     pub protocol_parameters: ProtocolParameters,
@@ -430,6 +524,17 @@ pub struct ServeCommandConfiguration {
     #[example = "`gcp` or `local`"]
     pub snapshot_uploader_type: SnapshotUploaderType,
 
+    /// Additional types of snapshot uploaders to which the artifacts are mirrored (comma
+    /// separated list).
+    ///
+    /// The locations of every successful upload are recorded in the artifacts.
+    #[example = "`local` or `gcp,local`"]
+    pub snapshot_mirror_uploader_types: Option<String>,
+
+    /// Policy defining how many of the snapshot uploaders must succeed when mirroring artifacts
+    #[example = "`at_least_one`, `majority` or `all`"]
+    pub snapshot_mirror_upload_policy: MirrorUploadPolicy,
+
     /// Bucket name where the snapshots are stored if snapshot_uploader_type is Gcp
     pub snapshot_bucket_name: Option<String>,
 
@@ -468,12 +573,28 @@ pub struct ServeCommandConfiguration {
     /// Use the digest caching strategy
     pub disable_digests_cache: bool,
 
+    /// Path of a SQLite immutables digests cache that can be shared with the other Mithril nodes
+    /// reading the same Cardano node database, such as a signer running on the same host.
+    ///
+    /// If set, the digests are looked up first in this cache, copied to the aggregator store, and
+    /// the computed digests are written to both.
+    /// Resetting the digests cache only resets the aggregator store, never this shared cache.
+    #[example = "`/var/cache/mithril/immutables_digests.sqlite3`"]
+    pub shared_digests_cache_path: Option<PathBuf>,
+
     /// Max number of records in stores.
     /// When new records are added, oldest records are automatically deleted so
     /// there can always be at max the number of records specified by this
     /// setting.
     pub store_retention_limit: Option<usize>,
 
+    /// Number of epochs for which the artifacts files stored locally are retained.
+    /// The files no longer referenced by a signed entity of a retained epoch are removed, except
+    /// the ones of the last signed entity of each type.
+    /// Only used with a `local` snapshot uploader.
+    #[example = "`10`"]
+    pub local_artifacts_retention_limit: Option<u64>,
+
     /// Era reader adapter type
     pub era_reader_adapter_type: EraReaderAdapterType,
 
@@ -500,7 +621,7 @@ pub struct ServeCommandConfiguration {
     pub signed_entity_types: Option<String>,
 
     /// Compression algorithm used for the snapshot archive artifacts.
-    #[example = "`gzip` or `zstandard`"]
+    #[example = "`gzip`, `zstandard`, `lz4` or `xz`"]
     pub snapshot_compression_algorithm: CompressionAlgorithm,
 
     /// Specific parameters when [snapshot_compression_algorithm][Self::snapshot_compression_algorithm]
@@ -508,6 +629,14 @@ pub struct ServeCommandConfiguration {
     #[example = "`{ level: 9, number_of_workers: 4 }`"]
     pub zstandard_parameters: Option<ZstandardCompressionParameters>,
 
+    /// Specific parameters when [snapshot_compression_algorithm][Self::snapshot_compression_algorithm]
+    /// is set to [gzip][CompressionAlgorithm::Gzip].
+    #[example = "`{ level: 6, number_of_workers: 4 }`"]
+    pub gzip_parameters: Option<GzipCompressionParameters>,
+
+    /// Maximum number of immutable files archives of the Cardano database produced in parallel.
+    pub cardano_database_immutable_archives_parallelism: usize,
+
     /// Url to CExplorer list of pools to import as signer in the database.
     pub cexplorer_pools_url: Option<String>,
 
@@ -519,7 +648,7 @@ pub struct ServeCommandConfiguration {
     /// Will be ignored on (pre)production networks.
     pub allow_unparsable_block: bool,
 
-    /// Cardano transactions prover cache pool size
+    /// Cardano transactions prover cache pool size, also used by the Cardano blocks prover
     pub cardano_transactions_prover_cache_pool_size: usize,
 
     /// Cardano transactions database connection pool size
@@ -535,6 +664,26 @@ pub struct ServeCommandConfiguration {
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: usize,
 
+    /// Policy applied when a rollback deeper than the
+    /// [network_security_parameter][Self::network_security_parameter] is received while
+    /// importing transactions (`halt`, `alert` or `reimport`).
+    pub cardano_transactions_rollback_policy: RollbackPolicy,
+
+    /// Also known as `k`, it defines the number of blocks that are required for the blockchain to
+    /// be considered final, preventing any further rollback `[default: 2160]`.
+    pub network_security_parameter: BlockNumber,
+
+    /// Format of the leaves of the Cardano transactions Merkle trees (`transaction_hash` or
+    /// `block_inclusion`), it must be the same for all the nodes of the network.
+    pub cardano_transactions_leaf_format: CardanoTransactionsLeafFormat,
+
+    /// Number of blocks for which the Cardano transactions are kept ("roots-only" mode).
+    ///
+    /// If set, the transactions of the block ranges older than this window are pruned after each
+    /// import and only their block range roots are kept: proofs can no longer be computed for
+    /// them and they are reported as non certified.
+    pub cardano_transactions_retention_blocks: Option<BlockNumber>,
+
     /// Enable metrics server (Prometheus endpoint on /metrics).
     pub enable_metrics_server: bool,
 
@@ -569,6 +718,20 @@ pub enum SnapshotUploaderType {
     Local,
 }
 
+impl FromStr for SnapshotUploaderType {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gcp" => Ok(Self::Gcp),
+            "local" => Ok(Self::Local),
+            _ => Err(ConfigError::Message(format!(
+                "Unknown snapshot uploader type {s}"
+            ))),
+        }
+    }
+}
+
 /// [Zstandard][CompressionAlgorithm::Zstandard] specific parameters
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ZstandardCompressionParameters {
@@ -588,6 +751,28 @@ impl Default for ZstandardCompressionParameters {
     }
 }
 
+/// [Gzip][CompressionAlgorithm::Gzip] specific parameters
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GzipCompressionParameters {
+    /// Level of compression, from 0 to 9, default to 6.
+    pub level: u32,
+
+    /// Number of workers when compressing, 0 will disable multithreading, default to 4.
+    ///
+    /// When multithreading is enabled, the data is split in blocks compressed in parallel and
+    /// concatenated in a single gzip stream (as done by `pigz`).
+    pub number_of_workers: u32,
+}
+
+impl Default for GzipCompressionParameters {
+    fn default() -> Self {
+        Self {
+            level: 6,
+            number_of_workers: 4,
+        }
+    }
+}
+
 /// Configuration of the ancillary files signer
 ///
 /// **IMPORTANT**: The cryptographic scheme used is ED25519
@@ -639,12 +824,17 @@ impl ServeCommandConfiguration {
             network_magic: Some(42),
             network: "devnet".to_string(),
             chain_observer_type: ChainObserverType::Fake,
+            ogmios_endpoint: None,
+            chain_observer_retry_attempts: 1,
+            chain_observer_retry_initial_delay_ms: 0,
             protocol_parameters: ProtocolParameters {
                 k: 5,
                 m: 100,
                 phi_f: 0.95,
             },
             snapshot_uploader_type: SnapshotUploaderType::Local,
+            snapshot_mirror_uploader_types: None,
+            snapshot_mirror_upload_policy: MirrorUploadPolicy::AtLeastOne,
             snapshot_bucket_name: None,
             snapshot_use_cdn_domain: false,
             server_ip: "0.0.0.0".to_string(),
@@ -663,7 +853,9 @@ impl ServeCommandConfiguration {
             genesis_verification_key: genesis_verification_key.to_json_hex().unwrap(),
             reset_digests_cache: false,
             disable_digests_cache: false,
+            shared_digests_cache_path: None,
             store_retention_limit: None,
+            local_artifacts_retention_limit: None,
             era_reader_adapter_type: EraReaderAdapterType::Bootstrap,
             era_reader_adapter_params: None,
             ancillary_files_signer_config: AncillaryFilesSignerConfig::SecretKey {
@@ -672,6 +864,8 @@ impl ServeCommandConfiguration {
             signed_entity_types: None,
             snapshot_compression_algorithm: CompressionAlgorithm::Zstandard,
             zstandard_parameters: Some(ZstandardCompressionParameters::default()),
+            gzip_parameters: Some(GzipCompressionParameters::default()),
+            cardano_database_immutable_archives_parallelism: 1,
             cexplorer_pools_url: None,
             signer_importer_run_interval: 1,
             allow_unparsable_block: false,
@@ -683,6 +877,10 @@ impl ServeCommandConfiguration {
             },
             cardano_transactions_prover_max_hashes_allowed_by_request: 100,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 1000,
+            cardano_transactions_rollback_policy: RollbackPolicy::Alert,
+            network_security_parameter: BlockNumber(2160),
+            cardano_transactions_leaf_format: CardanoTransactionsLeafFormat::TransactionHash,
+            cardano_transactions_retention_blocks: None,
             enable_metrics_server: true,
             metrics_server_ip: "0.0.0.0".to_string(),
             metrics_server_port: 9090,
@@ -734,6 +932,19 @@ impl ConfigurationSource for ServeCommandConfiguration {
         self.chain_observer_type.clone()
     }
 
+    fn ogmios_endpoint(&self) -> Option<String> {
+        self.ogmios_endpoint.clone()
+    }
+
+    fn chain_observer_retry_policy(&self) -> ChainObserverRetryPolicy {
+        ChainObserverRetryPolicy {
+            attempts: self.chain_observer_retry_attempts,
+            initial_delay_between_attempts: Duration::from_millis(
+                self.chain_observer_retry_initial_delay_ms,
+            ),
+        }
+    }
+
     fn protocol_parameters(&self) -> ProtocolParameters {
         self.protocol_parameters.clone()
     }
@@ -742,6 +953,14 @@ impl ConfigurationSource for ServeCommandConfiguration {
         self.snapshot_uploader_type
     }
 
+    fn snapshot_mirror_uploader_types(&self) -> Option<String> {
+        self.snapshot_mirror_uploader_types.clone()
+    }
+
+    fn snapshot_mirror_upload_policy(&self) -> MirrorUploadPolicy {
+        self.snapshot_mirror_upload_policy
+    }
+
     fn snapshot_bucket_name(&self) -> Option<String> {
         self.snapshot_bucket_name.clone()
     }
@@ -790,10 +1009,18 @@ impl ConfigurationSource for ServeCommandConfiguration {
         self.disable_digests_cache
     }
 
+    fn shared_digests_cache_path(&self) -> Option<PathBuf> {
+        self.shared_digests_cache_path.clone()
+    }
+
     fn store_retention_limit(&self) -> Option<usize> {
         self.store_retention_limit
     }
 
+    fn local_artifacts_retention_limit(&self) -> Option<u64> {
+        self.local_artifacts_retention_limit
+    }
+
     fn era_reader_adapter_type(&self) -> EraReaderAdapterType {
         self.era_reader_adapter_type.clone()
     }
@@ -818,6 +1045,14 @@ impl ConfigurationSource for ServeCommandConfiguration {
         self.zstandard_parameters
     }
 
+    fn gzip_parameters(&self) -> Option<GzipCompressionParameters> {
+        self.gzip_parameters
+    }
+
+    fn cardano_database_immutable_archives_parallelism(&self) -> usize {
+        self.cardano_database_immutable_archives_parallelism
+    }
+
     fn cexplorer_pools_url(&self) -> Option<String> {
         self.cexplorer_pools_url.clone()
     }
@@ -850,6 +1085,22 @@ impl ConfigurationSource for ServeCommandConfiguration {
         self.cardano_transactions_block_streamer_max_roll_forwards_per_poll
     }
 
+    fn cardano_transactions_rollback_policy(&self) -> RollbackPolicy {
+        self.cardano_transactions_rollback_policy
+    }
+
+    fn network_security_parameter(&self) -> BlockNumber {
+        self.network_security_parameter
+    }
+
+    fn cardano_transactions_leaf_format(&self) -> CardanoTransactionsLeafFormat {
+        self.cardano_transactions_leaf_format
+    }
+
+    fn cardano_transactions_retention_blocks(&self) -> Option<BlockNumber> {
+        self.cardano_transactions_retention_blocks
+    }
+
     fn enable_metrics_server(&self) -> bool {
         self.enable_metrics_server
     }
@@ -903,12 +1154,21 @@ pub struct DefaultConfiguration {
     /// Type of snapshot uploader to use
     pub snapshot_uploader_type: String,
 
+    /// Snapshot mirror upload policy default setting
+    pub snapshot_mirror_upload_policy: String,
+
     /// Era reader adapter type
     pub era_reader_adapter_type: String,
 
     /// Chain observer type
     pub chain_observer_type: String,
 
+    /// Number of attempts of a failed chain observer query
+    pub chain_observer_retry_attempts: u32,
+
+    /// Delay (in milliseconds) before retrying a failed chain observer query
+    pub chain_observer_retry_initial_delay_ms: u64,
+
     /// ImmutableDigesterCacheProvider default setting
     pub reset_digests_cache: String,
 
@@ -918,6 +1178,9 @@ pub struct DefaultConfiguration {
     /// Snapshot compression algorithm default setting
     pub snapshot_compression_algorithm: String,
 
+    /// Maximum number of immutable files archives of the Cardano database produced in parallel
+    pub cardano_database_immutable_archives_parallelism: u32,
+
     /// Use CDN domain to construct snapshot urls default setting (if snapshot_uploader_type is Gcp)
     pub snapshot_use_cdn_domain: String,
 
@@ -929,7 +1192,7 @@ pub struct DefaultConfiguration {
     /// Will be ignored on (pre)production networks.
     pub allow_unparsable_block: String,
 
-    /// Cardano transactions prover cache pool size
+    /// Cardano transactions prover cache pool size, also used by the Cardano blocks prover
     pub cardano_transactions_prover_cache_pool_size: u32,
 
     /// Cardano transactions database connection pool size
@@ -944,6 +1207,16 @@ pub struct DefaultConfiguration {
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: u32,
 
+    /// Policy applied when a rollback deeper than the security parameter is received while importing transactions.
+    pub cardano_transactions_rollback_policy: String,
+
+    /// Also known as `k`, it defines the number of blocks that are required for the blockchain to
+    /// be considered final, preventing any further rollback.
+    pub network_security_parameter: u64,
+
+    /// Format of the leaves of the Cardano transactions Merkle trees.
+    pub cardano_transactions_leaf_format: String,
+
     /// Enable metrics server (Prometheus endpoint on /metrics).
     pub enable_metrics_server: String,
 
@@ -966,11 +1239,15 @@ impl Default for DefaultConfiguration {
             db_directory: "/db".to_string(),
             snapshot_directory: ".".to_string(),
             snapshot_uploader_type: "gcp".to_string(),
+            snapshot_mirror_upload_policy: MirrorUploadPolicy::default().to_string(),
             era_reader_adapter_type: "bootstrap".to_string(),
             chain_observer_type: "pallas".to_string(),
+            chain_observer_retry_attempts: 3,
+            chain_observer_retry_initial_delay_ms: 1000,
             reset_digests_cache: "false".to_string(),
             disable_digests_cache: "false".to_string(),
             snapshot_compression_algorithm: "zstandard".to_string(),
+            cardano_database_immutable_archives_parallelism: 4,
             snapshot_use_cdn_domain: "false".to_string(),
             signer_importer_run_interval: 720,
             allow_unparsable_block: "false".to_string(),
@@ -982,6 +1259,9 @@ impl Default for DefaultConfiguration {
             },
             cardano_transactions_prover_max_hashes_allowed_by_request: 100,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 10000,
+            cardano_transactions_rollback_policy: "alert".to_string(),
+            network_security_parameter: 2160, // 2160 is the mainnet value
+            cardano_transactions_leaf_format: "transaction_hash".to_string(),
             enable_metrics_server: "false".to_string(),
             metrics_server_ip: "0.0.0.0".to_string(),
             metrics_server_port: 9090,
@@ -1022,10 +1302,22 @@ impl Source for DefaultConfiguration {
         register_config_value!(result, &namespace, myself.db_directory);
         register_config_value!(result, &namespace, myself.snapshot_directory);
         register_config_value!(result, &namespace, myself.snapshot_uploader_type);
+        register_config_value!(result, &namespace, myself.snapshot_mirror_upload_policy);
         register_config_value!(result, &namespace, myself.era_reader_adapter_type);
+        register_config_value!(result, &namespace, myself.chain_observer_retry_attempts);
+        register_config_value!(
+            result,
+            &namespace,
+            myself.chain_observer_retry_initial_delay_ms
+        );
         register_config_value!(result, &namespace, myself.reset_digests_cache);
         register_config_value!(result, &namespace, myself.disable_digests_cache);
         register_config_value!(result, &namespace, myself.snapshot_compression_algorithm);
+        register_config_value!(
+            result,
+            &namespace,
+            myself.cardano_database_immutable_archives_parallelism
+        );
         register_config_value!(result, &namespace, myself.snapshot_use_cdn_domain);
         register_config_value!(result, &namespace, myself.signer_importer_run_interval);
         register_config_value!(result, &namespace, myself.allow_unparsable_block);
@@ -1049,6 +1341,13 @@ impl Source for DefaultConfiguration {
             &namespace,
             myself.cardano_transactions_block_streamer_max_roll_forwards_per_poll
         );
+        register_config_value!(
+            result,
+            &namespace,
+            myself.cardano_transactions_rollback_policy
+        );
+        register_config_value!(result, &namespace, myself.network_security_parameter);
+        register_config_value!(result, &namespace, myself.cardano_transactions_leaf_format);
         register_config_value!(result, &namespace, myself.enable_metrics_server);
         register_config_value!(result, &namespace, myself.metrics_server_ip);
         register_config_value!(result, &namespace, myself.metrics_server_port);
@@ -1154,6 +1453,45 @@ mod test {
         };
 
         assert!(!config.allow_http_serve_directory());
+
+        let config = ServeCommandConfiguration {
+            snapshot_uploader_type: SnapshotUploaderType::Gcp,
+            snapshot_mirror_uploader_types: Some("local".to_string()),
+            ..ServeCommandConfiguration::new_sample(temp_dir!())
+        };
+
+        assert!(config.allow_http_serve_directory());
+    }
+
+    #[test]
+    fn compute_snapshot_uploader_types_with_mirrors() {
+        let config = ServeCommandConfiguration {
+            snapshot_uploader_type: SnapshotUploaderType::Gcp,
+            snapshot_mirror_uploader_types: None,
+            ..ServeCommandConfiguration::new_sample(temp_dir!())
+        };
+        assert_eq!(
+            vec![SnapshotUploaderType::Gcp],
+            config.compute_snapshot_uploader_types().unwrap()
+        );
+
+        let config = ServeCommandConfiguration {
+            snapshot_uploader_type: SnapshotUploaderType::Gcp,
+            snapshot_mirror_uploader_types: Some("local, gcp".to_string()),
+            ..ServeCommandConfiguration::new_sample(temp_dir!())
+        };
+        assert_eq!(
+            vec![SnapshotUploaderType::Gcp, SnapshotUploaderType::Local],
+            config.compute_snapshot_uploader_types().unwrap()
+        );
+
+        let config = ServeCommandConfiguration {
+            snapshot_mirror_uploader_types: Some("unknown".to_string()),
+            ..ServeCommandConfiguration::new_sample(temp_dir!())
+        };
+        config
+            .compute_snapshot_uploader_types()
+            .expect_err("Should fail with an unknown uploader type");
     }
 
     #[test]
@@ -239,5 +239,37 @@ create table immutable_file_digest (
 drop table pending_certificate;
         "#,
         ),
+        // Migration 36
+        // Add the `signed_entity_type` record for 'CardanoBlocks'
+        SqlMigration::new(
+            36,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (5, 'Cardano Blocks');
+"#,
+        ),
+        // Migration 37
+        // Add the `signed_entity_type` record for 'CardanoTransactionsMetadata'
+        SqlMigration::new(
+            37,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (6, 'Cardano Transactions Metadata');
+"#,
+        ),
+        // Migration 38
+        // Add the `immutable_file_archive_upload` table.
+        SqlMigration::new(
+            38,
+            r#"
+create table immutable_file_archive_upload (
+    location_uri            text    not null,
+    immutable_file_number   integer not null,
+    digest                  text    not null,
+    archive_size            integer not null,
+    primary key (location_uri, immutable_file_number)
+);
+        "#,
+        ),
     ]
 }
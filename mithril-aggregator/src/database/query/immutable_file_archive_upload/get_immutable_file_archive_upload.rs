@@ -0,0 +1,85 @@
+use sqlite::Value;
+
+use mithril_persistence::sqlite::{Query, WhereCondition};
+
+use crate::database::record::ImmutableFileArchiveUploadRecord;
+
+/// Simple queries to retrieve [ImmutableFileArchiveUploadRecord] from the sqlite database.
+pub struct GetImmutableFileArchiveUploadQuery {
+    condition: WhereCondition,
+}
+
+impl GetImmutableFileArchiveUploadQuery {
+    pub fn by_location_uri(location_uri: &str) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "location_uri = ?*",
+                vec![Value::String(location_uri.to_string())],
+            ),
+        }
+    }
+}
+
+impl Query for GetImmutableFileArchiveUploadQuery {
+    type Entity = ImmutableFileArchiveUploadRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let projection = Self::Entity::expand_projection("immutable_file_archive_upload");
+
+        format!(
+            "select {projection} from immutable_file_archive_upload where {condition} order by immutable_file_number asc"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::UpsertImmutableFileArchiveUploadQuery;
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    fn record(location_uri: &str, immutable_file_number: u64) -> ImmutableFileArchiveUploadRecord {
+        ImmutableFileArchiveUploadRecord {
+            location_uri: location_uri.to_string(),
+            immutable_file_number,
+            digest: format!("digest-{immutable_file_number}"),
+            archive_size: 100 + immutable_file_number,
+        }
+    }
+
+    #[test]
+    fn get_immutable_file_archive_upload_by_location_uri() {
+        let connection = main_db_connection().unwrap();
+        connection
+            .fetch_collect::<_, Vec<_>>(UpsertImmutableFileArchiveUploadQuery::many(vec![
+                record("location-1", 2),
+                record("location-1", 1),
+                record("location-2", 1),
+            ]))
+            .unwrap();
+
+        let records: Vec<ImmutableFileArchiveUploadRecord> = connection
+            .fetch_collect(GetImmutableFileArchiveUploadQuery::by_location_uri(
+                "location-1",
+            ))
+            .unwrap();
+        assert_eq!(
+            vec![record("location-1", 1), record("location-1", 2)],
+            records
+        );
+
+        let records: Vec<ImmutableFileArchiveUploadRecord> = connection
+            .fetch_collect(GetImmutableFileArchiveUploadQuery::by_location_uri(
+                "unknown-location",
+            ))
+            .unwrap();
+        assert_eq!(Vec::<ImmutableFileArchiveUploadRecord>::new(), records);
+    }
+}
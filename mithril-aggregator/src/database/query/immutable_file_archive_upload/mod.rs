@@ -0,0 +1,5 @@
+mod get_immutable_file_archive_upload;
+mod upsert_immutable_file_archive_upload;
+
+pub use get_immutable_file_archive_upload::*;
+pub use upsert_immutable_file_archive_upload::*;
@@ -0,0 +1,56 @@
+use std::iter::repeat_n;
+
+use sqlite::Value;
+
+use mithril_persistence::sqlite::{Query, WhereCondition};
+
+use crate::database::record::ImmutableFileArchiveUploadRecord;
+
+/// Query to upsert [ImmutableFileArchiveUploadRecord] in the sqlite database
+pub struct UpsertImmutableFileArchiveUploadQuery {
+    condition: WhereCondition,
+}
+
+impl UpsertImmutableFileArchiveUploadQuery {
+    pub fn many(records: Vec<ImmutableFileArchiveUploadRecord>) -> Self {
+        let columns = "(location_uri, immutable_file_number, digest, archive_size)";
+        let values_columns: Vec<&str> = repeat_n("(?*, ?*, ?*, ?*)", records.len()).collect();
+        let values = records
+            .into_iter()
+            .flat_map(|record| {
+                vec![
+                    Value::String(record.location_uri),
+                    Value::Integer(record.immutable_file_number as i64),
+                    Value::String(record.digest),
+                    Value::Integer(record.archive_size as i64),
+                ]
+            })
+            .collect();
+        let condition = WhereCondition::new(
+            format!("{columns} values {}", values_columns.join(", ")).as_str(),
+            values,
+        );
+
+        Self { condition }
+    }
+}
+
+impl Query for UpsertImmutableFileArchiveUploadQuery {
+    type Entity = ImmutableFileArchiveUploadRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let projection = Self::Entity::expand_projection("immutable_file_archive_upload");
+
+        format!(
+            r#"
+insert into immutable_file_archive_upload {condition}
+  on conflict (location_uri, immutable_file_number) do update set digest = excluded.digest, archive_size = excluded.archive_size
+returning {projection}
+"#
+        )
+    }
+}
@@ -1,7 +1,7 @@
 use sqlite::Value;
 
 use mithril_common::StdResult;
-use mithril_common::entities::{Epoch, SignedEntityTypeDiscriminants};
+use mithril_common::entities::{BlockNumber, Epoch, SignedEntityTypeDiscriminants};
 use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
 
 use crate::database::record::SignedEntityRecord;
@@ -73,6 +73,19 @@ impl GetSignedEntityRecordQuery {
             ),
         }
     }
+
+    pub fn cardano_transactions_by_block_number(block_number: BlockNumber) -> Self {
+        let signed_entity_type_id =
+            SignedEntityTypeDiscriminants::CardanoTransactions.index() as i64;
+        let block_number = *block_number as i64;
+
+        Self {
+            condition: WhereCondition::new(
+                "signed_entity_type_id = ?* and json_extract(beacon, '$.block_number') = ?*",
+                vec![Value::Integer(signed_entity_type_id), Value::Integer(block_number)],
+            ),
+        }
+    }
 }
 
 impl Query for GetSignedEntityRecordQuery {
@@ -95,7 +108,7 @@ impl Query for GetSignedEntityRecordQuery {
 mod tests {
     use chrono::DateTime;
     use mithril_common::{
-        entities::{CardanoDbBeacon, SignedEntityType},
+        entities::{CardanoDbBeacon, CardanoTransactionsSnapshot, SignedEntityType},
         test_utils::fake_data,
     };
     use mithril_persistence::sqlite::ConnectionExtensions;
@@ -183,6 +196,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cardano_transactions_by_block_number_returns_records_filtered_by_block_number() {
+        let records = [BlockNumber(100), BlockNumber(200), BlockNumber(300)]
+            .into_iter()
+            .map(|block_number| {
+                let snapshot = CardanoTransactionsSnapshot::new(
+                    format!("merkle-root-{block_number}"),
+                    block_number,
+                );
+                SignedEntityRecord {
+                    signed_entity_id: snapshot.hash.clone(),
+                    signed_entity_type: SignedEntityType::CardanoTransactions(
+                        Epoch(4),
+                        block_number,
+                    ),
+                    certificate_id: format!("certificate-{block_number}"),
+                    artifact: serde_json::to_string(&snapshot).unwrap(),
+                    created_at: DateTime::default(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let immutable_files_full_record = {
+            let mut snapshot = fake_data::snapshots(1)[0].clone();
+            snapshot.beacon.immutable_file_number = 200;
+            SignedEntityRecord::from_snapshot(snapshot, "whatever".to_string(), DateTime::default())
+        };
+        let connection =
+            create_database(&[records.clone(), vec![immutable_files_full_record]].concat());
+
+        let records_retrieved: Vec<SignedEntityRecord> = connection
+            .fetch_collect(
+                GetSignedEntityRecordQuery::cardano_transactions_by_block_number(BlockNumber(200)),
+            )
+            .unwrap();
+
+        assert_eq!(vec![records[1].clone()], records_retrieved);
+    }
+
     #[test]
     fn test_get_signed_entity_records() {
         let signed_entity_records = SignedEntityRecord::fake_records(5);
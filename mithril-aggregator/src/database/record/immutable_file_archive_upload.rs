@@ -0,0 +1,79 @@
+use sqlite::Row;
+
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileNumber};
+use mithril_persistence::sqlite::{HydrationError, Projection, SourceAlias, SqLiteEntity};
+
+/// ImmutableFileArchiveUploadRecord is the record that stores an immutable files archive already
+/// uploaded to a location.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ImmutableFileArchiveUploadRecord {
+    /// Uri of the location where the archive was uploaded
+    pub location_uri: String,
+
+    /// Number of the immutable files trio of the archive
+    pub immutable_file_number: ImmutableFileNumber,
+
+    /// Digest of the immutable files trio of the archive
+    pub digest: HexEncodedDigest,
+
+    /// Size of the uploaded archive
+    pub archive_size: u64,
+}
+
+impl ImmutableFileArchiveUploadRecord {
+    /// Construct a [Projection] that will allow to hydrate this `ImmutableFileArchiveUploadRecord` and expend table alias.
+    pub fn expand_projection(table: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:immutable_file_archive_upload:}", table)]);
+        Self::get_projection().expand(aliases)
+    }
+}
+
+impl SqLiteEntity for ImmutableFileArchiveUploadRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let location_uri = row.read::<&str, _>(0).to_string();
+        let immutable_file_number = row.read::<i64, _>(1);
+        let digest = row.read::<&str, _>(2).to_string();
+        let archive_size = row.read::<i64, _>(3);
+
+        Ok(Self {
+            location_uri,
+            immutable_file_number: ImmutableFileNumber::try_from(immutable_file_number).map_err(
+                |e| {
+                    HydrationError::InvalidData(format!(
+                        "Could not cast i64 ({immutable_file_number}) to u64. Error: '{e}'"
+                    ))
+                },
+            )?,
+            digest,
+            archive_size: u64::try_from(archive_size).map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Could not cast i64 ({archive_size}) to u64. Error: '{e}'"
+                ))
+            })?,
+        })
+    }
+
+    fn get_projection() -> Projection {
+        Projection::from(&[
+            (
+                "location_uri",
+                "{:immutable_file_archive_upload:}.location_uri",
+                "text",
+            ),
+            (
+                "immutable_file_number",
+                "{:immutable_file_archive_upload:}.immutable_file_number",
+                "integer",
+            ),
+            ("digest", "{:immutable_file_archive_upload:}.digest", "text"),
+            (
+                "archive_size",
+                "{:immutable_file_archive_upload:}.archive_size",
+                "integer",
+            ),
+        ])
+    }
+}
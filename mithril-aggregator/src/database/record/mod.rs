@@ -3,6 +3,7 @@
 mod buffered_single_signature_record;
 mod certificate;
 mod epoch_settings;
+mod immutable_file_archive_upload;
 mod immutable_file_digest;
 mod open_message;
 mod open_message_with_single_signatures;
@@ -15,6 +16,7 @@ mod stake_pool;
 pub use buffered_single_signature_record::*;
 pub use certificate::*;
 pub use epoch_settings::*;
+pub use immutable_file_archive_upload::*;
 pub use immutable_file_digest::*;
 pub use open_message::*;
 pub use open_message_with_single_signatures::*;
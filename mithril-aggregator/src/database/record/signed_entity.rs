@@ -9,9 +9,11 @@ use mithril_common::entities::{
 #[cfg(test)]
 use mithril_common::entities::{CardanoStakeDistribution, MithrilStakeDistribution};
 use mithril_common::messages::{
+    CardanoBlocksSnapshotListItemMessage, CardanoBlocksSnapshotMessage,
     CardanoDatabaseSnapshotListItemMessage, CardanoDatabaseSnapshotMessage,
     CardanoStakeDistributionListItemMessage, CardanoStakeDistributionMessage,
     CardanoTransactionSnapshotListItemMessage, CardanoTransactionSnapshotMessage,
+    CardanoTransactionsMetadataSnapshotListItemMessage, CardanoTransactionsMetadataSnapshotMessage,
     MithrilStakeDistributionListItemMessage, MithrilStakeDistributionMessage,
     SignerWithStakeMessagePart, SnapshotListItemMessage, SnapshotMessage,
 };
@@ -308,6 +310,102 @@ impl TryFrom<SignedEntityRecord> for CardanoTransactionSnapshotListItemMessage {
     }
 }
 
+impl TryFrom<SignedEntityRecord> for CardanoBlocksSnapshotMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoBlocks {
+            merkle_root: String,
+            block_number: BlockNumber,
+            hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoBlocks>(&value.artifact)?;
+        let message = CardanoBlocksSnapshotMessage {
+            merkle_root: artifact.merkle_root,
+            epoch: value.signed_entity_type.get_epoch(),
+            block_number: artifact.block_number,
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
+impl TryFrom<SignedEntityRecord> for CardanoBlocksSnapshotListItemMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoBlocks {
+            merkle_root: String,
+            block_number: BlockNumber,
+            hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoBlocks>(&value.artifact)?;
+        let message = CardanoBlocksSnapshotListItemMessage {
+            merkle_root: artifact.merkle_root,
+            epoch: value.signed_entity_type.get_epoch(),
+            block_number: artifact.block_number,
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
+impl TryFrom<SignedEntityRecord> for CardanoTransactionsMetadataSnapshotMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoTransactionsMetadata {
+            merkle_root: String,
+            block_number: BlockNumber,
+            hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoTransactionsMetadata>(&value.artifact)?;
+        let message = CardanoTransactionsMetadataSnapshotMessage {
+            merkle_root: artifact.merkle_root,
+            epoch: value.signed_entity_type.get_epoch(),
+            block_number: artifact.block_number,
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
+impl TryFrom<SignedEntityRecord> for CardanoTransactionsMetadataSnapshotListItemMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoTransactionsMetadata {
+            merkle_root: String,
+            block_number: BlockNumber,
+            hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoTransactionsMetadata>(&value.artifact)?;
+        let message = CardanoTransactionsMetadataSnapshotListItemMessage {
+            merkle_root: artifact.merkle_root,
+            epoch: value.signed_entity_type.get_epoch(),
+            block_number: artifact.block_number,
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
 impl TryFrom<SignedEntityRecord> for SnapshotListItemMessage {
     type Error = StdError;
 
@@ -9,7 +9,7 @@ use mithril_common::entities::{
 };
 use mithril_persistence::database::repository::CardanoTransactionRepository;
 
-use crate::services::{TransactionStore, TransactionsRetriever};
+use crate::services::{TransactionPruner, TransactionStore, TransactionsRetriever};
 
 #[async_trait]
 impl TransactionStore for CardanoTransactionRepository {
@@ -56,6 +56,14 @@ impl TransactionStore for CardanoTransactionRepository {
     }
 }
 
+#[async_trait]
+impl TransactionPruner for CardanoTransactionRepository {
+    async fn prune(&self, number_of_blocks_to_keep: BlockNumber) -> StdResult<()> {
+        self.prune_transactions_of_block_ranges(number_of_blocks_to_keep)
+            .await
+    }
+}
+
 #[async_trait]
 impl TransactionsRetriever for CardanoTransactionRepository {
     async fn get_by_hashes(
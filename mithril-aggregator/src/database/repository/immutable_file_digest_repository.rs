@@ -2,21 +2,26 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use sha2::{Digest, Sha256};
 
 use mithril_cardano_node_internal_database::digesters::cache::{
     CacheProviderResult, ImmutableDigesterCacheGetError, ImmutableDigesterCacheStoreError,
     ImmutableFileDigestCacheProvider,
 };
 use mithril_cardano_node_internal_database::entities::ImmutableFile;
+use mithril_cardano_node_internal_database::immutable_trio_names;
 use mithril_common::StdResult;
-use mithril_common::entities::{HexEncodedDigest, ImmutableFileName};
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileName, ImmutableFileNumber};
 use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
 
 use crate::ImmutableFileDigestMapper;
+use crate::artifact_builder::{ImmutableFileArchiveUploadTracker, UploadedImmutableArchive};
 use crate::database::query::{
-    DeleteImmutableFileDigestQuery, GetImmutableFileDigestQuery, UpsertImmutableFileDigestQuery,
+    DeleteImmutableFileDigestQuery, GetImmutableFileArchiveUploadQuery,
+    GetImmutableFileDigestQuery, UpsertImmutableFileArchiveUploadQuery,
+    UpsertImmutableFileDigestQuery,
 };
-use crate::database::record::ImmutableFileDigestRecord;
+use crate::database::record::{ImmutableFileArchiveUploadRecord, ImmutableFileDigestRecord};
 
 /// ImmutableFileDigestRepository store for the immutable file digests.
 pub struct ImmutableFileDigestRepository {
@@ -66,6 +71,43 @@ impl ImmutableFileDigestRepository {
 
         Ok(())
     }
+
+    /// Return all the [ImmutableFileArchiveUploadRecord]s of the given location.
+    pub async fn get_immutable_file_archive_uploads(
+        &self,
+        location_uri: &str,
+    ) -> StdResult<Vec<ImmutableFileArchiveUploadRecord>> {
+        self.connection
+            .fetch_collect(GetImmutableFileArchiveUploadQuery::by_location_uri(
+                location_uri,
+            ))
+    }
+
+    /// Create or update the given [ImmutableFileArchiveUploadRecord]s in the database.
+    pub async fn upsert_immutable_file_archive_uploads(
+        &self,
+        records: Vec<ImmutableFileArchiveUploadRecord>,
+    ) -> StdResult<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        self.connection
+            .apply(UpsertImmutableFileArchiveUploadQuery::many(records))?;
+
+        Ok(())
+    }
+
+    fn compute_trio_digest(
+        immutable_file_number: ImmutableFileNumber,
+        digests: &BTreeMap<ImmutableFileName, HexEncodedDigest>,
+    ) -> Option<HexEncodedDigest> {
+        let mut hasher = Sha256::new();
+        for filename in immutable_trio_names(immutable_file_number) {
+            hasher.update(digests.get(&filename)?.as_bytes());
+        }
+
+        Some(hex::encode(hasher.finalize()))
+    }
 }
 
 #[async_trait]
@@ -125,6 +167,61 @@ impl ImmutableFileDigestMapper for ImmutableFileDigestRepository {
     }
 }
 
+#[async_trait]
+impl ImmutableFileArchiveUploadTracker for ImmutableFileDigestRepository {
+    async fn get_immutable_trio_digests(
+        &self,
+        up_to_immutable_file_number: ImmutableFileNumber,
+    ) -> StdResult<BTreeMap<ImmutableFileNumber, HexEncodedDigest>> {
+        let digests = self.get_immutable_file_digest_map().await?;
+
+        Ok((0..=up_to_immutable_file_number)
+            .filter_map(|number| {
+                Self::compute_trio_digest(number, &digests).map(|digest| (number, digest))
+            })
+            .collect())
+    }
+
+    async fn get_uploaded_archives(
+        &self,
+        location_uri: &str,
+    ) -> StdResult<BTreeMap<ImmutableFileNumber, UploadedImmutableArchive>> {
+        Ok(self
+            .get_immutable_file_archive_uploads(location_uri)
+            .await?
+            .into_iter()
+            .map(|record| {
+                (
+                    record.immutable_file_number,
+                    UploadedImmutableArchive {
+                        immutable_file_number: record.immutable_file_number,
+                        digest: record.digest,
+                        archive_size: record.archive_size,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    async fn record_uploaded_archives(
+        &self,
+        location_uri: &str,
+        archives: Vec<UploadedImmutableArchive>,
+    ) -> StdResult<()> {
+        let records = archives
+            .into_iter()
+            .map(|archive| ImmutableFileArchiveUploadRecord {
+                location_uri: location_uri.to_string(),
+                immutable_file_number: archive.immutable_file_number,
+                digest: archive.digest,
+                archive_size: archive.archive_size,
+            })
+            .collect();
+
+        self.upsert_immutable_file_archive_uploads(records).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -436,4 +533,91 @@ mod tests {
             );
         }
     }
+
+    mod archive_upload_tracker {
+        use super::*;
+
+        async fn store_trio_digests(
+            repository: &ImmutableFileDigestRepository,
+            immutable_file_number: ImmutableFileNumber,
+        ) {
+            for filename in immutable_trio_names(immutable_file_number) {
+                repository
+                    .upsert_immutable_file_digest(&filename, &format!("digest-{filename}"))
+                    .await
+                    .unwrap();
+            }
+        }
+
+        #[tokio::test]
+        async fn get_immutable_trio_digests_only_for_trios_with_all_digests_known() {
+            let repository = ImmutableFileDigestRepository::new(get_connection().await);
+            store_trio_digests(&repository, 1).await;
+            store_trio_digests(&repository, 3).await;
+            repository
+                .upsert_immutable_file_digest(&"00002.chunk".to_string(), "digest-00002.chunk")
+                .await
+                .unwrap();
+
+            let trio_digests = repository.get_immutable_trio_digests(3).await.unwrap();
+
+            assert_eq!(vec![1, 3], trio_digests.keys().copied().collect::<Vec<_>>());
+            assert_ne!(trio_digests[&1], trio_digests[&3]);
+        }
+
+        #[tokio::test]
+        async fn trio_digest_changes_when_a_file_digest_changes() {
+            let repository = ImmutableFileDigestRepository::new(get_connection().await);
+            store_trio_digests(&repository, 1).await;
+            let trio_digest = repository.get_immutable_trio_digests(1).await.unwrap()[&1].clone();
+
+            repository
+                .upsert_immutable_file_digest(&"00001.primary".to_string(), "another-digest")
+                .await
+                .unwrap();
+            let updated_trio_digest =
+                repository.get_immutable_trio_digests(1).await.unwrap()[&1].clone();
+
+            assert_ne!(trio_digest, updated_trio_digest);
+        }
+
+        #[tokio::test]
+        async fn record_and_get_uploaded_archives_by_location() {
+            let repository = ImmutableFileDigestRepository::new(get_connection().await);
+            let archive = |number: ImmutableFileNumber, digest: &str| UploadedImmutableArchive {
+                immutable_file_number: number,
+                digest: digest.to_string(),
+                archive_size: 10 * number,
+            };
+
+            repository
+                .record_uploaded_archives("location-1", vec![archive(1, "a"), archive(2, "b")])
+                .await
+                .unwrap();
+            repository
+                .record_uploaded_archives("location-2", vec![archive(1, "a")])
+                .await
+                .unwrap();
+            repository
+                .record_uploaded_archives("location-1", vec![archive(2, "c")])
+                .await
+                .unwrap();
+
+            assert_eq!(
+                BTreeMap::from([(1, archive(1, "a")), (2, archive(2, "c"))]),
+                repository.get_uploaded_archives("location-1").await.unwrap()
+            );
+            assert_eq!(
+                BTreeMap::from([(1, archive(1, "a"))]),
+                repository.get_uploaded_archives("location-2").await.unwrap()
+            );
+            assert!(
+                repository
+                    .get_uploaded_archives("unknown-location")
+                    .await
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+    }
 }
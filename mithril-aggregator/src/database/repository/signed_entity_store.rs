@@ -4,7 +4,7 @@ use anyhow::Context;
 use async_trait::async_trait;
 
 use mithril_common::StdResult;
-use mithril_common::entities::{Epoch, SignedEntityTypeDiscriminants};
+use mithril_common::entities::{BlockNumber, Epoch, SignedEntityTypeDiscriminants};
 use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
 
 use crate::database::query::{
@@ -50,6 +50,12 @@ pub trait SignedEntityStorer: Sync + Send {
         epoch: Epoch,
     ) -> StdResult<Option<SignedEntityRecord>>;
 
+    /// Get Cardano transactions signed entity by block number
+    async fn get_cardano_transactions_signed_entity_by_block_number(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<Option<SignedEntityRecord>>;
+
     /// Perform an update for all the given signed entities.
     async fn update_signed_entities(
         &self,
@@ -141,6 +147,21 @@ impl SignedEntityStorer for SignedEntityStore {
             .fetch_first(GetSignedEntityRecordQuery::cardano_stake_distribution_by_epoch(epoch))
     }
 
+    async fn get_cardano_transactions_signed_entity_by_block_number(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<Option<SignedEntityRecord>> {
+        self.connection
+            .fetch_first(
+                GetSignedEntityRecordQuery::cardano_transactions_by_block_number(block_number),
+            )
+            .with_context(|| {
+                format!(
+                    "get Cardano transactions signed entity by block number failure, block_number: {block_number}"
+                )
+            })
+    }
+
     async fn update_signed_entities(
         &self,
         signed_entities: Vec<SignedEntityRecord>,
@@ -5,7 +5,7 @@ use tokio::sync::Mutex;
 use mithril_cardano_node_chain::{
     chain_observer::{CardanoCliRunner, ChainObserver, ChainObserverBuilder, ChainObserverType},
     chain_reader::{ChainBlockReader, PallasChainReader},
-    chain_scanner::{BlockScanner, CardanoBlockScanner},
+    chain_scanner::{BlockScanner, CardanoBlockScanner, ChainRollbackHandler},
     test::double::FakeChainObserver,
 };
 use mithril_cardano_node_internal_database::digesters::{
@@ -39,6 +39,12 @@ impl DependenciesBuilder {
                     cardano_node_socket_path,
                     cardano_network,
                     cardano_cli_runner.as_deref(),
+                )
+                .with_ogmios_endpoint(self.configuration.ogmios_endpoint())
+                .with_cache_and_retry(
+                    self.configuration.chain_observer_retry_policy(),
+                    Some(self.get_metrics_service().await?),
+                    self.root_logger(),
                 );
 
                 chain_observer_builder
@@ -73,37 +79,46 @@ impl DependenciesBuilder {
         get_dependency!(self.cardano_cli_runner)
     }
 
-    async fn build_chain_block_reader(&mut self) -> Result<Arc<Mutex<dyn ChainBlockReader>>> {
+    fn create_chain_block_reader(&self) -> Result<Arc<Mutex<dyn ChainBlockReader>>> {
+        let capture_transactions_metadata = self
+            .configuration
+            .compute_allowed_signed_entity_types_discriminants()?
+            .contains(&SignedEntityTypeDiscriminants::CardanoTransactionsMetadata);
         let chain_block_reader = PallasChainReader::new(
             &self.configuration.cardano_node_socket_path(),
             self.configuration.get_network()?,
             self.root_logger(),
-        );
+        )
+        .with_transactions_metadata_capture(capture_transactions_metadata);
 
         Ok(Arc::new(Mutex::new(chain_block_reader)))
     }
 
-    /// Chain reader
-    pub async fn get_chain_block_reader(&mut self) -> Result<Arc<Mutex<dyn ChainBlockReader>>> {
-        get_dependency!(self.chain_block_reader)
-    }
-
-    async fn build_block_scanner(&mut self) -> Result<Arc<dyn BlockScanner>> {
+    /// Create a block scanner with its own chain reader and rollback handler, so that the
+    /// rollbacks are tracked against the blocks scanned by a single importer.
+    ///
+    /// The block scanner of the dependencies is returned instead if it has been set.
+    pub async fn create_block_scanner(&mut self) -> Result<Arc<dyn BlockScanner>> {
+        if let Some(block_scanner) = &self.block_scanner {
+            return Ok(block_scanner.clone());
+        }
+
+        let rollback_handler = ChainRollbackHandler::new(
+            self.configuration.network_security_parameter(),
+            self.configuration.cardano_transactions_rollback_policy(),
+        )
+        .with_metrics_recorder(self.get_metrics_service().await?);
         let block_scanner = CardanoBlockScanner::new(
-            self.get_chain_block_reader().await?,
+            self.create_chain_block_reader()?,
             self.configuration
                 .cardano_transactions_block_streamer_max_roll_forwards_per_poll(),
             self.root_logger(),
-        );
+        )
+        .with_rollback_handler(Arc::new(rollback_handler));
 
         Ok(Arc::new(block_scanner))
     }
 
-    /// Block scanner
-    pub async fn get_block_scanner(&mut self) -> Result<Arc<dyn BlockScanner>> {
-        get_dependency!(self.block_scanner)
-    }
-
     async fn build_immutable_digester(&mut self) -> Result<Arc<dyn ImmutableDigester>> {
         let immutable_digester_cache = match self.configuration.environment() {
             ExecutionEnvironment::Production => Some(self.get_immutable_cache_provider().await?),
@@ -7,7 +7,6 @@ use slog::Logger;
 use std::{path::PathBuf, sync::Arc};
 use tokio::{
     sync::{
-        Mutex,
         mpsc::{UnboundedReceiver, UnboundedSender},
         watch,
     },
@@ -17,7 +16,6 @@ use warp::Filter;
 
 use mithril_cardano_node_chain::{
     chain_observer::{CardanoCliRunner, ChainObserver},
-    chain_reader::ChainBlockReader,
     chain_scanner::BlockScanner,
 };
 use mithril_cardano_node_internal_database::{
@@ -28,11 +26,16 @@ use mithril_common::{
     api_version::APIVersionProvider,
     certificate_chain::CertificateVerifier,
     crypto_helper::ProtocolGenesisVerifier,
-    signable_builder::{SignableBuilderService, SignableSeedBuilder, TransactionsImporter},
+    signable_builder::{
+        BlocksImporter, SignableBuilderService, SignableSeedBuilder, TransactionsImporter,
+        TransactionsMetadataImporter,
+    },
 };
 use mithril_era::{EraChecker, EraReader, EraReaderAdapter};
 use mithril_persistence::{
-    database::repository::CardanoTransactionRepository,
+    database::repository::{
+        CardanoBlockRepository, CardanoTransactionMetadataRepository, CardanoTransactionRepository,
+    },
     sqlite::{SqliteConnection, SqliteConnectionPool},
 };
 use mithril_signed_entity_lock::SignedEntityTypeLock;
@@ -53,10 +56,11 @@ use crate::{
         SignerStore, StakePoolStore,
     },
     event_store::{EventMessage, TransmitterService},
-    file_uploaders::FileUploader,
+    file_uploaders::{LocalArtifactsDiskUsage, MirrorUploader},
     http_server::routes::router::{self, RouterConfig, RouterState},
     services::{
-        AggregatorHTTPClient, CertificateChainSynchronizer, CertifierService, MessageService,
+        AggregatorHTTPClient, CardanoBlocksProverService, CardanoTransactionsMetadataProverService,
+        CertificateChainSynchronizer, CertifierService, MessageService,
         MithrilSignerRegistrationFollower, ProverService, SignedEntityService, SignerSynchronizer,
         Snapshotter, StakeDistributionService, UpkeepService,
     },
@@ -125,8 +129,8 @@ pub struct DependenciesBuilder {
     /// It shall be a private dependency.
     pub stake_store: Option<Arc<StakePoolStore>>,
 
-    /// Snapshot uploader service.
-    pub snapshot_uploader: Option<Arc<dyn FileUploader>>,
+    /// Snapshot uploader service, writing the snapshots to all the configured backends.
+    pub snapshot_uploader: Option<Arc<MirrorUploader>>,
 
     /// Multisigner service.
     pub multi_signer: Option<Arc<dyn MultiSigner>>,
@@ -149,13 +153,17 @@ pub struct DependenciesBuilder {
     /// Chain observer service.
     pub chain_observer: Option<Arc<dyn ChainObserver>>,
 
-    /// Chain block reader
-    pub chain_block_reader: Option<Arc<Mutex<dyn ChainBlockReader>>>,
-
     /// Cardano transactions repository.
     pub transaction_repository: Option<Arc<CardanoTransactionRepository>>,
 
-    /// Cardano block scanner.
+    /// Cardano blocks repository.
+    pub block_repository: Option<Arc<CardanoBlockRepository>>,
+
+    /// Cardano transactions metadata repository.
+    pub transaction_metadata_repository: Option<Arc<CardanoTransactionMetadataRepository>>,
+
+    /// Cardano block scanner shared by all the importers, if not set a dedicated block scanner
+    /// is created for each importer.
     pub block_scanner: Option<Arc<dyn BlockScanner>>,
 
     /// Immutable file digester service.
@@ -260,15 +268,31 @@ pub struct DependenciesBuilder {
     /// Prover service
     pub prover_service: Option<Arc<dyn ProverService>>,
 
+    /// Cardano blocks prover service
+    pub cardano_blocks_prover_service: Option<Arc<dyn CardanoBlocksProverService>>,
+
+    /// Cardano transactions metadata prover service
+    pub cardano_transactions_metadata_prover_service:
+        Option<Arc<dyn CardanoTransactionsMetadataProverService>>,
+
     /// Signed Entity Type Lock
     pub signed_entity_type_lock: Option<Arc<SignedEntityTypeLock>>,
 
     /// Transactions Importer
     pub transactions_importer: Option<Arc<dyn TransactionsImporter>>,
 
+    /// Cardano blocks Importer
+    pub blocks_importer: Option<Arc<dyn BlocksImporter>>,
+
+    /// Cardano transactions metadata Importer
+    pub transactions_metadata_importer: Option<Arc<dyn TransactionsMetadataImporter>>,
+
     /// Upkeep service
     pub upkeep_service: Option<Arc<dyn UpkeepService>>,
 
+    /// Disk usage of the artifacts stored locally
+    pub local_artifacts_disk_usage: Option<Arc<LocalArtifactsDiskUsage>>,
+
     /// Single signer authenticator
     pub single_signature_authenticator: Option<Arc<SingleSignatureAuthenticator>>,
 
@@ -303,9 +327,10 @@ impl DependenciesBuilder {
             epoch_settings_store: None,
             cardano_cli_runner: None,
             chain_observer: None,
-            chain_block_reader: None,
             block_scanner: None,
             transaction_repository: None,
+            block_repository: None,
+            transaction_metadata_repository: None,
             immutable_digester: None,
             immutable_file_observer: None,
             immutable_cache_provider: None,
@@ -339,9 +364,14 @@ impl DependenciesBuilder {
             signed_entity_storer: None,
             message_service: None,
             prover_service: None,
+            cardano_blocks_prover_service: None,
+            cardano_transactions_metadata_prover_service: None,
             signed_entity_type_lock: None,
             transactions_importer: None,
+            blocks_importer: None,
+            transactions_metadata_importer: None,
             upkeep_service: None,
+            local_artifacts_disk_usage: None,
             single_signature_authenticator: None,
             metrics_service: None,
             leader_aggregator_client: None,
@@ -397,8 +427,13 @@ impl DependenciesBuilder {
             signer_getter: self.get_signer_store().await?,
             message_service: self.get_message_service().await?,
             prover_service: self.get_prover_service().await?,
+            cardano_blocks_prover_service: self.get_cardano_blocks_prover_service().await?,
+            cardano_transactions_metadata_prover_service: self
+                .get_cardano_transactions_metadata_prover_service()
+                .await?,
             signed_entity_type_lock: self.get_signed_entity_type_lock().await?,
             upkeep_service: self.get_upkeep_service().await?,
+            local_artifacts_disk_usage: self.get_local_artifacts_disk_usage().await?,
             single_signer_authenticator: self.get_single_signature_authenticator().await?,
             metrics_service: self.get_metrics_service().await?,
         };
@@ -6,9 +6,10 @@ use std::sync::Arc;
 use mithril_common::crypto_helper::ManifestSigner;
 
 use crate::artifact_builder::{
-    AncillaryArtifactBuilder, AncillaryFileUploader, CardanoDatabaseArtifactBuilder,
-    CardanoImmutableFilesFullArtifactBuilder, CardanoStakeDistributionArtifactBuilder,
-    CardanoTransactionsArtifactBuilder, DigestArtifactBuilder, DigestFileUploader,
+    AncillaryArtifactBuilder, AncillaryFileUploader, CardanoBlocksArtifactBuilder,
+    CardanoDatabaseArtifactBuilder, CardanoImmutableFilesFullArtifactBuilder,
+    CardanoStakeDistributionArtifactBuilder, CardanoTransactionsArtifactBuilder,
+    CardanoTransactionsMetadataArtifactBuilder, DigestArtifactBuilder, DigestFileUploader,
     DigestSnapshotter, ImmutableArtifactBuilder, ImmutableFilesUploader,
     MithrilStakeDistributionArtifactBuilder,
 };
@@ -17,6 +18,7 @@ use crate::dependency_injection::builder::SNAPSHOT_ARTIFACTS_DIR;
 use crate::dependency_injection::{DependenciesBuilder, DependenciesBuilderError, Result};
 use crate::file_uploaders::{
     CloudRemotePath, CloudUploader, FileUploadRetryPolicy, GCloudBackendUploader, LocalUploader,
+    MirrorUploader,
 };
 use crate::get_dependency;
 use crate::http_server::{CARDANO_DATABASE_DOWNLOAD_PATH, SNAPSHOT_DOWNLOAD_PATH};
@@ -62,12 +64,20 @@ impl DependenciesBuilder {
             self.build_cardano_database_artifact_builder(cardano_node_version)
                 .await?,
         );
+        let cardano_blocks_prover_service = self.get_cardano_blocks_prover_service().await?;
+        let cardano_blocks_artifact_builder = Arc::new(CardanoBlocksArtifactBuilder::new(
+            cardano_blocks_prover_service.clone(),
+        ));
+        let cardano_transactions_metadata_artifact_builder =
+            Arc::new(CardanoTransactionsMetadataArtifactBuilder::new());
         let dependencies = SignedEntityServiceArtifactsDependencies::new(
             mithril_stake_distribution_artifact_builder,
             cardano_immutable_files_full_artifact_builder,
             cardano_transactions_artifact_builder,
             cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder,
+            cardano_transactions_metadata_artifact_builder,
         );
         let signed_entity_service = Arc::new(MithrilSignedEntityService::new(
             signed_entity_storer,
@@ -87,6 +97,13 @@ impl DependenciesBuilder {
                 .compute_cache(signed_entity.artifact.block_number)
                 .await?;
         }
+        if let Some(signed_entity) =
+            signed_entity_service.get_last_cardano_blocks_snapshot().await?
+        {
+            cardano_blocks_prover_service
+                .compute_cache(signed_entity.artifact.block_number)
+                .await?;
+        }
 
         Ok(signed_entity_service)
     }
@@ -99,11 +116,18 @@ impl DependenciesBuilder {
     async fn build_file_archiver(&mut self) -> Result<Arc<FileArchiver>> {
         let archive_verification_directory =
             std::env::temp_dir().join("mithril_archiver_verify_archive");
-        let file_archiver = Arc::new(FileArchiver::new(
-            self.configuration.zstandard_parameters().unwrap_or_default(),
-            archive_verification_directory,
-            self.root_logger(),
-        ));
+        let file_archiver = Arc::new(
+            FileArchiver::new(
+                self.configuration.zstandard_parameters().unwrap_or_default(),
+                self.configuration.gzip_parameters().unwrap_or_default(),
+                archive_verification_directory,
+                self.root_logger(),
+            )
+            .map_err(|e| DependenciesBuilderError::Initialization {
+                message: "Could not create the file archiver.".to_string(),
+                error: Some(e),
+            })?,
+        );
 
         Ok(file_archiver)
     }
@@ -182,48 +206,65 @@ impl DependenciesBuilder {
         get_dependency!(self.snapshotter)
     }
 
-    async fn build_snapshot_uploader(&mut self) -> Result<Arc<dyn FileUploader>> {
+    async fn build_snapshot_uploaders(&mut self) -> Result<Vec<Arc<dyn FileUploader>>> {
         let logger = self.root_logger();
         if self.configuration.environment() == ExecutionEnvironment::Production {
-            match self.configuration.snapshot_uploader_type() {
-                SnapshotUploaderType::Gcp => {
-                    let allow_overwrite = true;
-                    let remote_folder_path = CloudRemotePath::new("cardano-immutable-files-full");
-
-                    Ok(Arc::new(
-                        self.build_gcp_uploader(remote_folder_path, allow_overwrite).await?,
-                    ))
-                }
-                SnapshotUploaderType::Local => {
-                    let server_url_prefix = self.configuration.get_server_url()?;
-                    let snapshot_url_prefix =
-                        server_url_prefix.sanitize_join(SNAPSHOT_DOWNLOAD_PATH)?;
-                    let snapshot_artifacts_dir =
-                        self.configuration.get_snapshot_dir()?.join(SNAPSHOT_ARTIFACTS_DIR);
-                    std::fs::create_dir_all(&snapshot_artifacts_dir).map_err(|e| {
-                        DependenciesBuilderError::Initialization {
-                            message: format!(
-                                "Cannot create '{snapshot_artifacts_dir:?}' directory."
-                            ),
-                            error: Some(e.into()),
-                        }
-                    })?;
-
-                    Ok(Arc::new(LocalUploader::new(
-                        snapshot_url_prefix,
-                        &snapshot_artifacts_dir,
-                        FileUploadRetryPolicy::default(),
-                        logger,
-                    )))
-                }
+            let mut uploaders: Vec<Arc<dyn FileUploader>> = vec![];
+            for uploader_type in self.configuration.compute_snapshot_uploader_types()? {
+                let uploader: Arc<dyn FileUploader> = match uploader_type {
+                    SnapshotUploaderType::Gcp => {
+                        let allow_overwrite = true;
+                        let remote_folder_path =
+                            CloudRemotePath::new("cardano-immutable-files-full");
+
+                        Arc::new(
+                            self.build_gcp_uploader(remote_folder_path, allow_overwrite).await?,
+                        )
+                    }
+                    SnapshotUploaderType::Local => {
+                        let server_url_prefix = self.configuration.get_server_url()?;
+                        let snapshot_url_prefix =
+                            server_url_prefix.sanitize_join(SNAPSHOT_DOWNLOAD_PATH)?;
+                        let snapshot_artifacts_dir =
+                            self.configuration.get_snapshot_dir()?.join(SNAPSHOT_ARTIFACTS_DIR);
+                        std::fs::create_dir_all(&snapshot_artifacts_dir).map_err(|e| {
+                            DependenciesBuilderError::Initialization {
+                                message: format!(
+                                    "Cannot create '{snapshot_artifacts_dir:?}' directory."
+                                ),
+                                error: Some(e.into()),
+                            }
+                        })?;
+
+                        Arc::new(LocalUploader::new(
+                            snapshot_url_prefix,
+                            &snapshot_artifacts_dir,
+                            FileUploadRetryPolicy::default(),
+                            logger.clone(),
+                        ))
+                    }
+                };
+                uploaders.push(uploader);
             }
+
+            Ok(uploaders)
         } else {
-            Ok(Arc::new(DumbUploader::new(FileUploadRetryPolicy::never())))
+            Ok(vec![Arc::new(DumbUploader::new(
+                FileUploadRetryPolicy::never(),
+            ))])
         }
     }
 
-    /// Get a [FileUploader]
-    pub async fn get_snapshot_uploader(&mut self) -> Result<Arc<dyn FileUploader>> {
+    async fn build_snapshot_uploader(&mut self) -> Result<Arc<MirrorUploader>> {
+        Ok(Arc::new(MirrorUploader::new(
+            self.build_snapshot_uploaders().await?,
+            self.configuration.snapshot_mirror_upload_policy(),
+            self.root_logger(),
+        )?))
+    }
+
+    /// Get a [MirrorUploader] writing the snapshots to all the configured backends
+    pub async fn get_snapshot_uploader(&mut self) -> Result<Arc<MirrorUploader>> {
         get_dependency!(self.snapshot_uploader)
     }
 
@@ -258,37 +299,44 @@ impl DependenciesBuilder {
     ) -> Result<Vec<Arc<dyn AncillaryFileUploader>>> {
         let logger = self.root_logger();
         if self.configuration.environment() == ExecutionEnvironment::Production {
-            match self.configuration.snapshot_uploader_type() {
-                SnapshotUploaderType::Gcp => {
-                    let allow_overwrite = true;
-                    let remote_folder_path =
-                        CloudRemotePath::new("cardano-database").join("ancillary");
-
-                    Ok(vec![Arc::new(
-                        self.build_gcp_uploader(remote_folder_path, allow_overwrite).await?,
-                    )])
-                }
-                SnapshotUploaderType::Local => {
-                    let server_url_prefix = self.configuration.get_server_url()?;
-                    let ancillary_url_prefix = server_url_prefix
-                        .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/ancillary/"))?;
-                    let target_dir = self.get_cardano_db_artifacts_dir()?.join("ancillary");
-
-                    std::fs::create_dir_all(&target_dir).map_err(|e| {
-                        DependenciesBuilderError::Initialization {
-                            message: format!("Cannot create '{target_dir:?}' directory."),
-                            error: Some(e.into()),
-                        }
-                    })?;
-
-                    Ok(vec![Arc::new(LocalUploader::new(
-                        ancillary_url_prefix,
-                        &target_dir,
-                        FileUploadRetryPolicy::default(),
-                        logger,
-                    ))])
-                }
+            let mut uploaders: Vec<Arc<dyn AncillaryFileUploader>> = vec![];
+            for uploader_type in self.configuration.compute_snapshot_uploader_types()? {
+                let uploader: Arc<dyn AncillaryFileUploader> = match uploader_type {
+                    SnapshotUploaderType::Gcp => {
+                        let allow_overwrite = true;
+                        let remote_folder_path =
+                            CloudRemotePath::new("cardano-database").join("ancillary");
+
+                        Arc::new(
+                            self.build_gcp_uploader(remote_folder_path, allow_overwrite).await?,
+                        )
+                    }
+                    SnapshotUploaderType::Local => {
+                        let server_url_prefix = self.configuration.get_server_url()?;
+                        let ancillary_url_prefix = server_url_prefix.sanitize_join(&format!(
+                            "{CARDANO_DATABASE_DOWNLOAD_PATH}/ancillary/"
+                        ))?;
+                        let target_dir = self.get_cardano_db_artifacts_dir()?.join("ancillary");
+
+                        std::fs::create_dir_all(&target_dir).map_err(|e| {
+                            DependenciesBuilderError::Initialization {
+                                message: format!("Cannot create '{target_dir:?}' directory."),
+                                error: Some(e.into()),
+                            }
+                        })?;
+
+                        Arc::new(LocalUploader::new(
+                            ancillary_url_prefix,
+                            &target_dir,
+                            FileUploadRetryPolicy::default(),
+                            logger.clone(),
+                        ))
+                    }
+                };
+                uploaders.push(uploader);
             }
+
+            Ok(uploaders)
         } else {
             Ok(vec![Arc::new(DumbUploader::new(
                 FileUploadRetryPolicy::never(),
@@ -301,28 +349,35 @@ impl DependenciesBuilder {
     ) -> Result<Vec<Arc<dyn ImmutableFilesUploader>>> {
         let logger = self.root_logger();
         if self.configuration.environment() == ExecutionEnvironment::Production {
-            match self.configuration.snapshot_uploader_type() {
-                SnapshotUploaderType::Gcp => {
-                    let allow_overwrite = false;
-                    let remote_folder_path =
-                        CloudRemotePath::new("cardano-database").join("immutable");
-
-                    Ok(vec![Arc::new(
-                        self.build_gcp_uploader(remote_folder_path, allow_overwrite).await?,
-                    )])
-                }
-                SnapshotUploaderType::Local => {
-                    let server_url_prefix = self.configuration.get_server_url()?;
-                    let immutable_url_prefix = server_url_prefix
-                        .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/immutable/"))?;
-
-                    Ok(vec![Arc::new(LocalUploader::new_without_copy(
-                        immutable_url_prefix,
-                        FileUploadRetryPolicy::default(),
-                        logger,
-                    ))])
-                }
+            let mut uploaders: Vec<Arc<dyn ImmutableFilesUploader>> = vec![];
+            for uploader_type in self.configuration.compute_snapshot_uploader_types()? {
+                let uploader: Arc<dyn ImmutableFilesUploader> = match uploader_type {
+                    SnapshotUploaderType::Gcp => {
+                        let allow_overwrite = false;
+                        let remote_folder_path =
+                            CloudRemotePath::new("cardano-database").join("immutable");
+
+                        Arc::new(
+                            self.build_gcp_uploader(remote_folder_path, allow_overwrite).await?,
+                        )
+                    }
+                    SnapshotUploaderType::Local => {
+                        let server_url_prefix = self.configuration.get_server_url()?;
+                        let immutable_url_prefix = server_url_prefix.sanitize_join(&format!(
+                            "{CARDANO_DATABASE_DOWNLOAD_PATH}/immutable/"
+                        ))?;
+
+                        Arc::new(LocalUploader::new_without_copy(
+                            immutable_url_prefix,
+                            FileUploadRetryPolicy::default(),
+                            logger.clone(),
+                        ))
+                    }
+                };
+                uploaders.push(uploader);
             }
+
+            Ok(uploaders)
         } else {
             Ok(vec![Arc::new(DumbUploader::new(
                 FileUploadRetryPolicy::never(),
@@ -335,37 +390,43 @@ impl DependenciesBuilder {
     ) -> Result<Vec<Arc<dyn DigestFileUploader>>> {
         let logger = self.root_logger();
         if self.configuration.environment() == ExecutionEnvironment::Production {
-            match self.configuration.snapshot_uploader_type() {
-                SnapshotUploaderType::Gcp => {
-                    let allow_overwrite = false;
-                    let remote_folder_path =
-                        CloudRemotePath::new("cardano-database").join("digests");
-
-                    Ok(vec![Arc::new(
-                        self.build_gcp_uploader(remote_folder_path, allow_overwrite).await?,
-                    )])
-                }
-                SnapshotUploaderType::Local => {
-                    let server_url_prefix = self.configuration.get_server_url()?;
-                    let digests_url_prefix = server_url_prefix
-                        .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/digests/"))?;
-                    let target_dir = self.get_cardano_db_artifacts_dir()?.join("digests");
-
-                    std::fs::create_dir_all(&target_dir).map_err(|e| {
-                        DependenciesBuilderError::Initialization {
-                            message: format!("Cannot create '{target_dir:?}' directory."),
-                            error: Some(e.into()),
-                        }
-                    })?;
-
-                    Ok(vec![Arc::new(LocalUploader::new(
-                        digests_url_prefix,
-                        &target_dir,
-                        FileUploadRetryPolicy::default(),
-                        logger,
-                    ))])
-                }
+            let mut uploaders: Vec<Arc<dyn DigestFileUploader>> = vec![];
+            for uploader_type in self.configuration.compute_snapshot_uploader_types()? {
+                let uploader: Arc<dyn DigestFileUploader> = match uploader_type {
+                    SnapshotUploaderType::Gcp => {
+                        let allow_overwrite = false;
+                        let remote_folder_path =
+                            CloudRemotePath::new("cardano-database").join("digests");
+
+                        Arc::new(
+                            self.build_gcp_uploader(remote_folder_path, allow_overwrite).await?,
+                        )
+                    }
+                    SnapshotUploaderType::Local => {
+                        let server_url_prefix = self.configuration.get_server_url()?;
+                        let digests_url_prefix = server_url_prefix
+                            .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/digests/"))?;
+                        let target_dir = self.get_cardano_db_artifacts_dir()?.join("digests");
+
+                        std::fs::create_dir_all(&target_dir).map_err(|e| {
+                            DependenciesBuilderError::Initialization {
+                                message: format!("Cannot create '{target_dir:?}' directory."),
+                                error: Some(e.into()),
+                            }
+                        })?;
+
+                        Arc::new(LocalUploader::new(
+                            digests_url_prefix,
+                            &target_dir,
+                            FileUploadRetryPolicy::default(),
+                            logger.clone(),
+                        ))
+                    }
+                };
+                uploaders.push(uploader);
             }
+
+            Ok(uploaders)
         } else {
             Ok(vec![Arc::new(DumbUploader::new(
                 FileUploadRetryPolicy::never(),
@@ -380,19 +441,29 @@ impl DependenciesBuilder {
         let snapshot_dir = self.configuration.get_snapshot_dir()?;
         let immutable_dir = self.get_cardano_db_artifacts_dir()?.join("immutable");
 
-        let ancillary_builder = Arc::new(AncillaryArtifactBuilder::new(
-            self.build_cardano_database_ancillary_uploaders().await?,
-            self.get_snapshotter().await?,
-            self.configuration.get_network()?,
-            self.root_logger(),
-        )?);
+        let ancillary_builder = Arc::new(
+            AncillaryArtifactBuilder::new(
+                self.build_cardano_database_ancillary_uploaders().await?,
+                self.get_snapshotter().await?,
+                self.configuration.get_network()?,
+                self.root_logger(),
+            )?
+            .with_upload_policy(self.configuration.snapshot_mirror_upload_policy()),
+        );
 
-        let immutable_builder = Arc::new(ImmutableArtifactBuilder::new(
-            immutable_dir,
-            self.build_cardano_database_immutable_uploaders().await?,
-            self.get_snapshotter().await?,
-            self.root_logger(),
-        )?);
+        let immutable_builder = Arc::new(
+            ImmutableArtifactBuilder::new(
+                immutable_dir,
+                self.build_cardano_database_immutable_uploaders().await?,
+                self.get_snapshotter().await?,
+                self.root_logger(),
+            )?
+            .with_archives_parallelism(
+                self.configuration.cardano_database_immutable_archives_parallelism(),
+            )
+            .with_upload_tracker(self.build_immutable_file_archive_upload_tracker().await?)
+            .with_upload_policy(self.configuration.snapshot_mirror_upload_policy()),
+        );
 
         let digests_path = snapshot_dir.join("pending_cardano_database_digests");
         let digests_snapshotter = self.build_digests_snapshotter(digests_path.clone()).await?;
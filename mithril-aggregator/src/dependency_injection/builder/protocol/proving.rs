@@ -4,7 +4,11 @@ use mithril_common::crypto_helper::MKTreeStoreInMemory;
 
 use crate::dependency_injection::{DependenciesBuilder, Result};
 use crate::get_dependency;
-use crate::services::{MithrilProverService, ProverService};
+use crate::services::{
+    CardanoBlocksProverService, CardanoTransactionsMetadataProverService,
+    MithrilCardanoBlocksProverService, MithrilCardanoTransactionsMetadataProverService,
+    MithrilProverService, ProverService,
+};
 impl DependenciesBuilder {
     /// Build Prover service
     pub async fn build_prover_service(&mut self) -> Result<Arc<dyn ProverService>> {
@@ -17,7 +21,8 @@ impl DependenciesBuilder {
             block_range_root_retriever,
             mk_map_pool_size,
             logger,
-        );
+        )
+        .with_leaf_format(self.configuration.cardano_transactions_leaf_format());
 
         Ok(Arc::new(prover_service))
     }
@@ -26,4 +31,51 @@ impl DependenciesBuilder {
     pub async fn get_prover_service(&mut self) -> Result<Arc<dyn ProverService>> {
         get_dependency!(self.prover_service)
     }
+
+    /// Build Cardano blocks prover service
+    pub async fn build_cardano_blocks_prover_service(
+        &mut self,
+    ) -> Result<Arc<dyn CardanoBlocksProverService>> {
+        let mk_map_pool_size = self.configuration.cardano_transactions_prover_cache_pool_size();
+        let block_retriever = self.get_block_repository().await?;
+        let block_range_root_retriever = self.get_block_repository().await?;
+        let prover_service = MithrilCardanoBlocksProverService::<MKTreeStoreInMemory>::new(
+            block_retriever,
+            block_range_root_retriever,
+            mk_map_pool_size,
+            self.root_logger(),
+        );
+
+        Ok(Arc::new(prover_service))
+    }
+
+    /// [CardanoBlocksProverService] service
+    pub async fn get_cardano_blocks_prover_service(
+        &mut self,
+    ) -> Result<Arc<dyn CardanoBlocksProverService>> {
+        get_dependency!(self.cardano_blocks_prover_service)
+    }
+
+    /// Build Cardano transactions metadata prover service
+    pub async fn build_cardano_transactions_metadata_prover_service(
+        &mut self,
+    ) -> Result<Arc<dyn CardanoTransactionsMetadataProverService>> {
+        let transactions_metadata_retriever = self.get_transaction_metadata_repository().await?;
+        let block_range_root_retriever = self.get_transaction_metadata_repository().await?;
+        let prover_service =
+            MithrilCardanoTransactionsMetadataProverService::<MKTreeStoreInMemory>::new(
+                transactions_metadata_retriever,
+                block_range_root_retriever,
+                self.root_logger(),
+            );
+
+        Ok(Arc::new(prover_service))
+    }
+
+    /// [CardanoTransactionsMetadataProverService] service
+    pub async fn get_cardano_transactions_metadata_prover_service(
+        &mut self,
+    ) -> Result<Arc<dyn CardanoTransactionsMetadataProverService>> {
+        get_dependency!(self.cardano_transactions_metadata_prover_service)
+    }
 }
@@ -5,14 +5,19 @@ use mithril_cardano_node_internal_database::signable_builder::{
 };
 use mithril_common::crypto_helper::MKTreeStoreInMemory;
 use mithril_common::signable_builder::{
-    CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
+    BlocksImporter, CardanoBlocksSignableBuilder, CardanoStakeDistributionSignableBuilder,
+    CardanoTransactionsMetadataSignableBuilder, CardanoTransactionsSignableBuilder,
     MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder, SignableBuilderService,
     SignableBuilderServiceDependencies, SignableSeedBuilder, TransactionsImporter,
+    TransactionsMetadataImporter,
 };
 
 use crate::dependency_injection::{DependenciesBuilder, Result};
 use crate::get_dependency;
-use crate::services::{AggregatorSignableSeedBuilder, CardanoTransactionsImporter};
+use crate::services::{
+    AggregatorSignableSeedBuilder, CardanoBlocksImporter, CardanoTransactionsImporter,
+    CardanoTransactionsMetadataImporter, TransactionsImporterWithPruner,
+};
 impl DependenciesBuilder {
     async fn build_signable_builder_service(&mut self) -> Result<Arc<dyn SignableBuilderService>> {
         let seed_signable_builder = self.get_signable_seed_builder().await?;
@@ -39,12 +44,26 @@ impl DependenciesBuilder {
             &self.configuration.db_directory(),
             self.root_logger(),
         ));
+        let cardano_blocks_signable_builder =
+            Arc::new(CardanoBlocksSignableBuilder::<MKTreeStoreInMemory>::new(
+                self.get_blocks_importer().await?,
+                self.get_block_repository().await?,
+            ));
+        let cardano_transactions_metadata_signable_builder =
+            Arc::new(CardanoTransactionsMetadataSignableBuilder::<
+                MKTreeStoreInMemory,
+            >::new(
+                self.get_transactions_metadata_importer().await?,
+                self.get_transaction_metadata_repository().await?,
+            ));
         let signable_builders_dependencies = SignableBuilderServiceDependencies::new(
             mithril_stake_distribution_builder,
             immutable_signable_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_signable_builder,
+            cardano_transactions_metadata_signable_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             seed_signable_builder,
@@ -76,17 +95,61 @@ impl DependenciesBuilder {
     }
 
     async fn build_transactions_importer(&mut self) -> Result<Arc<dyn TransactionsImporter>> {
-        let transactions_importer = Arc::new(CardanoTransactionsImporter::new(
-            self.get_block_scanner().await?,
-            self.get_transaction_repository().await?,
+        let transaction_repository = self.get_transaction_repository().await?;
+        let transactions_importer = Arc::new(
+            CardanoTransactionsImporter::new(
+                self.create_block_scanner().await?,
+                transaction_repository.clone(),
+                self.root_logger(),
+            )
+            .with_leaf_format(self.configuration.cardano_transactions_leaf_format()),
+        );
+        let importer_with_pruner = Arc::new(TransactionsImporterWithPruner::new(
+            self.configuration.cardano_transactions_retention_blocks(),
+            transaction_repository,
+            transactions_importer,
             self.root_logger(),
         ));
 
-        Ok(transactions_importer)
+        Ok(importer_with_pruner)
     }
 
     /// Get the [TransactionsImporter] instance
     pub async fn get_transactions_importer(&mut self) -> Result<Arc<dyn TransactionsImporter>> {
         get_dependency!(self.transactions_importer)
     }
+
+    async fn build_blocks_importer(&mut self) -> Result<Arc<dyn BlocksImporter>> {
+        let blocks_importer = Arc::new(CardanoBlocksImporter::new(
+            self.create_block_scanner().await?,
+            self.get_block_repository().await?,
+            self.root_logger(),
+        ));
+
+        Ok(blocks_importer)
+    }
+
+    /// Get the [BlocksImporter] instance
+    pub async fn get_blocks_importer(&mut self) -> Result<Arc<dyn BlocksImporter>> {
+        get_dependency!(self.blocks_importer)
+    }
+
+    async fn build_transactions_metadata_importer(
+        &mut self,
+    ) -> Result<Arc<dyn TransactionsMetadataImporter>> {
+        let transactions_metadata_importer = Arc::new(CardanoTransactionsMetadataImporter::new(
+            self.create_block_scanner().await?,
+            self.get_transaction_metadata_repository().await?,
+            self.root_logger(),
+        ));
+
+        Ok(transactions_metadata_importer)
+    }
+
+    /// Get the [TransactionsMetadataImporter] instance
+    pub async fn get_transactions_metadata_importer(
+        &mut self,
+    ) -> Result<Arc<dyn TransactionsMetadataImporter>> {
+        get_dependency!(self.transactions_metadata_importer)
+    }
 }
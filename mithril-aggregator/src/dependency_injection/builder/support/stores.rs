@@ -3,9 +3,17 @@ use slog::debug;
 use std::sync::Arc;
 use std::time::Duration;
 
-use mithril_cardano_node_internal_database::digesters::cache::ImmutableFileDigestCacheProvider;
-use mithril_persistence::database::repository::CardanoTransactionRepository;
+use mithril_cardano_node_internal_database::IMMUTABLE_DIR;
+use mithril_cardano_node_internal_database::digesters::cache::{
+    ImmutableFileDigestCacheProvider, LayeredImmutableFileDigestCacheProvider,
+};
+use mithril_persistence::database::ApplicationNodeType;
+use mithril_persistence::database::repository::{
+    CardanoBlockRepository, CardanoTransactionMetadataRepository, CardanoTransactionRepository,
+    SqliteImmutableFileDigestCacheProviderBuilder,
+};
 
+use crate::artifact_builder::ImmutableFileArchiveUploadTracker;
 use crate::database::repository::{
     CertificateRepository, EpochSettingsStore, ImmutableFileDigestRepository,
     OpenMessageRepository, SignedEntityStore, SignedEntityStorer, SignerRegistrationStore,
@@ -149,7 +157,29 @@ impl DependenciesBuilder {
                 .with_context(|| "Failure occurred when resetting immutable file digest cache")?;
         }
 
-        Ok(Arc::new(cache_provider))
+        match self.configuration.shared_digests_cache_path() {
+            Some(shared_digests_cache_path) => {
+                let immutable_dir = self.configuration.db_directory().join(IMMUTABLE_DIR);
+                let shared_cache_provider = SqliteImmutableFileDigestCacheProviderBuilder::new(
+                    &shared_digests_cache_path,
+                    &immutable_dir,
+                )
+                .with_node_type(ApplicationNodeType::Aggregator)
+                .with_logger(self.root_logger())
+                .build()
+                .await
+                .with_context(
+                    || "Failure occurred when opening the shared immutable file digest cache",
+                )?;
+
+                Ok(Arc::new(LayeredImmutableFileDigestCacheProvider::new(
+                    Arc::new(cache_provider),
+                    Arc::new(shared_cache_provider),
+                    self.root_logger(),
+                )))
+            }
+            None => Ok(Arc::new(cache_provider)),
+        }
     }
 
     /// Get an [ImmutableFileDigestCacheProvider]
@@ -174,6 +204,36 @@ impl DependenciesBuilder {
         get_dependency!(self.transaction_repository)
     }
 
+    async fn build_block_repository(&mut self) -> Result<Arc<CardanoBlockRepository>> {
+        let block_store = CardanoBlockRepository::new(
+            self.get_sqlite_connection_cardano_transaction_pool().await?,
+        );
+
+        Ok(Arc::new(block_store))
+    }
+
+    /// Cardano block repository.
+    pub async fn get_block_repository(&mut self) -> Result<Arc<CardanoBlockRepository>> {
+        get_dependency!(self.block_repository)
+    }
+
+    async fn build_transaction_metadata_repository(
+        &mut self,
+    ) -> Result<Arc<CardanoTransactionMetadataRepository>> {
+        let transaction_metadata_store = CardanoTransactionMetadataRepository::new(
+            self.get_sqlite_connection_cardano_transaction_pool().await?,
+        );
+
+        Ok(Arc::new(transaction_metadata_store))
+    }
+
+    /// Cardano transaction metadata repository.
+    pub async fn get_transaction_metadata_repository(
+        &mut self,
+    ) -> Result<Arc<CardanoTransactionMetadataRepository>> {
+        get_dependency!(self.transaction_metadata_repository)
+    }
+
     async fn build_immutable_file_digest_mapper(
         &mut self,
     ) -> Result<Arc<dyn ImmutableFileDigestMapper>> {
@@ -189,6 +249,15 @@ impl DependenciesBuilder {
         get_dependency!(self.immutable_file_digest_mapper)
     }
 
+    /// Tracker of the immutable files archives already uploaded.
+    pub async fn build_immutable_file_archive_upload_tracker(
+        &mut self,
+    ) -> Result<Arc<dyn ImmutableFileArchiveUploadTracker>> {
+        let tracker = ImmutableFileDigestRepository::new(self.get_sqlite_connection().await?);
+
+        Ok(Arc::new(tracker))
+    }
+
     async fn build_signer_store(&mut self) -> Result<Arc<SignerStore>> {
         let signer_store = Arc::new(SignerStore::new(self.get_sqlite_connection().await?));
 
@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 use crate::database::repository::SignerRegistrationStore;
+use crate::dependency_injection::builder::SNAPSHOT_ARTIFACTS_DIR;
 use crate::dependency_injection::{DependenciesBuilder, Result};
+use crate::file_uploaders::{LocalArtifactsDiskUsage, LocalArtifactsPruner};
 use crate::get_dependency;
 use crate::services::{AggregatorUpkeepService, EpochPruningTask, UpkeepService};
 
@@ -16,21 +18,56 @@ impl DependenciesBuilder {
         )))
     }
 
+    /// Return a [EpochPruningTask] instance removing the artifacts files stored locally that are
+    /// no longer referenced by a retained signed entity
+    pub async fn get_local_artifacts_pruning_task(&mut self) -> Result<Arc<dyn EpochPruningTask>> {
+        let cardano_db_artifacts_dir = self.get_cardano_db_artifacts_dir()?;
+
+        Ok(Arc::new(LocalArtifactsPruner::new(
+            self.get_signed_entity_storer().await?,
+            self.get_signed_entity_type_lock().await?,
+            vec![
+                self.configuration.get_snapshot_dir()?.join(SNAPSHOT_ARTIFACTS_DIR),
+                cardano_db_artifacts_dir.join("ancillary"),
+                cardano_db_artifacts_dir.join("digests"),
+            ],
+            self.configuration.local_artifacts_retention_limit(),
+            self.get_local_artifacts_disk_usage().await?,
+            self.root_logger(),
+        )))
+    }
+
+    async fn build_local_artifacts_disk_usage(&mut self) -> Result<Arc<LocalArtifactsDiskUsage>> {
+        Ok(Arc::new(LocalArtifactsDiskUsage::new(vec![
+            self.configuration.get_snapshot_dir()?,
+            self.get_cardano_db_artifacts_dir()?,
+        ])))
+    }
+
+    /// Get the [LocalArtifactsDiskUsage] instance
+    pub async fn get_local_artifacts_disk_usage(&mut self) -> Result<Arc<LocalArtifactsDiskUsage>> {
+        get_dependency!(self.local_artifacts_disk_usage)
+    }
+
     async fn build_upkeep_service(&mut self) -> Result<Arc<dyn UpkeepService>> {
         let stake_pool_pruning_task = self.get_stake_store().await?;
         let epoch_settings_pruning_task = self.get_epoch_settings_store().await?;
         let signer_registration_pruning_task = self.get_signer_registration_pruning_task().await?;
+        let mut pruning_tasks: Vec<Arc<dyn EpochPruningTask>> = vec![
+            stake_pool_pruning_task,
+            epoch_settings_pruning_task,
+            signer_registration_pruning_task,
+        ];
+        if self.configuration.allow_http_serve_directory() {
+            pruning_tasks.push(self.get_local_artifacts_pruning_task().await?);
+        }
 
         let upkeep_service = Arc::new(AggregatorUpkeepService::new(
             self.get_sqlite_connection().await?,
             self.get_sqlite_connection_cardano_transaction_pool().await?,
             self.get_event_store_sqlite_connection().await?,
             self.get_signed_entity_type_lock().await?,
-            vec![
-                stake_pool_pruning_task,
-                epoch_settings_pruning_task,
-                signer_registration_pruning_task,
-            ],
+            pruning_tasks,
             self.root_logger(),
         ));
 
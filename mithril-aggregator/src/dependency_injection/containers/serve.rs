@@ -26,7 +26,9 @@ use crate::{
     },
     entities::AggregatorEpochSettings,
     event_store::{EventMessage, TransmitterService},
+    file_uploaders::LocalArtifactsDiskUsage,
     services::{
+        CardanoBlocksProverService, CardanoTransactionsMetadataProverService,
         CertificateChainSynchronizer, CertifierService, EpochService, MessageService,
         ProverService, SignedEntityService, SignerRecorder, SignerSynchronizer,
         StakeDistributionService, UpkeepService,
@@ -115,12 +117,22 @@ pub struct ServeCommandDependenciesContainer {
     /// Prover service
     pub prover_service: Arc<dyn ProverService>,
 
+    /// Cardano blocks prover service
+    pub cardano_blocks_prover_service: Arc<dyn CardanoBlocksProverService>,
+
+    /// Cardano transactions metadata prover service
+    pub cardano_transactions_metadata_prover_service:
+        Arc<dyn CardanoTransactionsMetadataProverService>,
+
     /// Signed Entity Type Lock
     pub signed_entity_type_lock: Arc<SignedEntityTypeLock>,
 
     /// Upkeep service
     pub(crate) upkeep_service: Arc<dyn UpkeepService>,
 
+    /// Disk usage of the artifacts stored locally
+    pub(crate) local_artifacts_disk_usage: Arc<LocalArtifactsDiskUsage>,
+
     /// Single signer authenticator
     pub(crate) single_signer_authenticator: Arc<SingleSignatureAuthenticator>,
 
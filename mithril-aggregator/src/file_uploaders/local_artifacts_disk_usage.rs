@@ -0,0 +1,82 @@
+use anyhow::Context;
+use std::path::PathBuf;
+use tokio::sync::RwLock;
+
+use mithril_common::StdResult;
+
+use crate::tools::file_size;
+
+/// Cache of the disk usage of the artifacts files stored locally by the
+/// [LocalUploader][crate::file_uploaders::LocalUploader].
+///
+/// Walking the artifacts directories is costly, the disk usage is only computed when refreshed,
+/// i.e. after each pruning of the local artifacts files.
+pub struct LocalArtifactsDiskUsage {
+    directories: Vec<PathBuf>,
+    disk_usage: RwLock<Option<u64>>,
+}
+
+impl LocalArtifactsDiskUsage {
+    /// Create a new instance of the local artifacts disk usage cache.
+    pub fn new(directories: Vec<PathBuf>) -> Self {
+        Self {
+            directories,
+            disk_usage: RwLock::new(None),
+        }
+    }
+
+    /// Get the disk usage, in bytes, computed by the last refresh, `None` if never refreshed.
+    pub async fn get(&self) -> Option<u64> {
+        *self.disk_usage.read().await
+    }
+
+    /// Compute the disk usage of the local artifacts directories and cache it.
+    pub async fn refresh(&self) -> StdResult<u64> {
+        let directories = self.directories.clone();
+        let disk_usage = tokio::task::spawn_blocking(move || file_size::compute_size(directories))
+            .await
+            .with_context(|| "Local artifacts disk usage computation thread crashed")??;
+        *self.disk_usage.write().await = Some(disk_usage);
+
+        Ok(disk_usage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::temp_dir_create;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn disk_usage_is_none_before_first_refresh() {
+        let disk_usage = LocalArtifactsDiskUsage::new(vec![temp_dir_create!()]);
+
+        assert_eq!(disk_usage.get().await, None);
+    }
+
+    #[tokio::test]
+    async fn refresh_computes_and_caches_the_disk_usage_of_the_directories() {
+        let temp_dir = temp_dir_create!();
+        let snapshot_dir = temp_dir.join("snapshot");
+        let cardano_db_dir = temp_dir.join("cardano-database");
+        std::fs::create_dir_all(cardano_db_dir.join("immutable")).unwrap();
+        std::fs::create_dir_all(&snapshot_dir).unwrap();
+        std::fs::write(snapshot_dir.join("snapshot.tar.zst"), vec![0; 100]).unwrap();
+        std::fs::write(cardano_db_dir.join("immutable/00001.tar.zst"), vec![0; 20]).unwrap();
+        let disk_usage = LocalArtifactsDiskUsage::new(vec![snapshot_dir.clone(), cardano_db_dir]);
+
+        assert_eq!(disk_usage.refresh().await.unwrap(), 120);
+        assert_eq!(disk_usage.get().await, Some(120));
+
+        std::fs::remove_file(snapshot_dir.join("snapshot.tar.zst")).unwrap();
+        assert_eq!(
+            disk_usage.get().await,
+            Some(120),
+            "cached value should not change until the next refresh"
+        );
+
+        disk_usage.refresh().await.unwrap();
+        assert_eq!(disk_usage.get().await, Some(20));
+    }
+}
@@ -0,0 +1,531 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use slog::{Logger, debug, info};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use mithril_common::StdResult;
+use mithril_common::entities::{
+    AncillaryLocation, CardanoDatabaseSnapshot, DigestLocation, Epoch, SignedEntityType,
+    SignedEntityTypeDiscriminants, Snapshot,
+};
+use mithril_common::logging::LoggerExtensions;
+use mithril_signed_entity_lock::SignedEntityTypeLock;
+
+use crate::database::record::SignedEntityRecord;
+use crate::database::repository::SignedEntityStorer;
+use crate::file_uploaders::LocalArtifactsDiskUsage;
+use crate::services::EpochPruningTask;
+
+/// Signed entity types whose artifacts files are stored by the [LocalUploader][crate::file_uploaders::LocalUploader].
+const LOCALLY_STORED_SIGNED_ENTITY_TYPES: [SignedEntityTypeDiscriminants; 2] = [
+    SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+    SignedEntityTypeDiscriminants::CardanoDatabase,
+];
+
+/// Garbage collector of the artifacts files stored locally by the [LocalUploader][crate::file_uploaders::LocalUploader].
+///
+/// Files of the managed directories that are not referenced by any retained signed entity are
+/// removed, a signed entity being retained if its epoch is within the retention limit.
+/// The last signed entity of each type is always retained.
+///
+/// The [disk usage][LocalArtifactsDiskUsage] of the local artifacts is refreshed after each pruning.
+///
+/// Note: the immutable files archives of the Cardano database are shared by all the snapshots and
+/// must not be part of the managed directories.
+pub struct LocalArtifactsPruner {
+    signed_entity_storer: Arc<dyn SignedEntityStorer>,
+    signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+    managed_directories: Vec<PathBuf>,
+    retention_limit: Option<u64>,
+    disk_usage: Arc<LocalArtifactsDiskUsage>,
+    logger: Logger,
+}
+
+impl LocalArtifactsPruner {
+    /// Create a new instance of the local artifacts pruner.
+    pub fn new(
+        signed_entity_storer: Arc<dyn SignedEntityStorer>,
+        signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+        managed_directories: Vec<PathBuf>,
+        retention_limit: Option<u64>,
+        disk_usage: Arc<LocalArtifactsDiskUsage>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            signed_entity_storer,
+            signed_entity_type_lock,
+            managed_directories,
+            retention_limit,
+            disk_usage,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    async fn is_any_locally_stored_signed_entity_type_locked(&self) -> bool {
+        for signed_entity_type in LOCALLY_STORED_SIGNED_ENTITY_TYPES {
+            if self.signed_entity_type_lock.is_locked(signed_entity_type).await {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    async fn get_referenced_file_names(&self, threshold: Epoch) -> StdResult<HashSet<String>> {
+        let mut referenced_file_names = HashSet::new();
+
+        for signed_entity_type in LOCALLY_STORED_SIGNED_ENTITY_TYPES {
+            let records = self
+                .signed_entity_storer
+                .get_last_signed_entities_by_type(&signed_entity_type, usize::MAX)
+                .await?;
+            let last_record_id = records
+                .iter()
+                .max_by_key(|record| record.created_at)
+                .map(|record| record.signed_entity_id.clone());
+
+            for record in records {
+                if record.signed_entity_type.get_epoch() >= threshold
+                    || Some(&record.signed_entity_id) == last_record_id.as_ref()
+                {
+                    referenced_file_names.extend(
+                        artifact_locations(&record)?
+                            .iter()
+                            .filter_map(|uri| file_name_from_uri(uri)),
+                    );
+                }
+            }
+        }
+
+        Ok(referenced_file_names)
+    }
+
+    fn remove_unreferenced_files(
+        &self,
+        directory: &Path,
+        referenced_file_names: &HashSet<String>,
+    ) -> StdResult<(usize, u64)> {
+        let mut removed_files = 0;
+        let mut freed_bytes = 0;
+
+        if !directory.is_dir() {
+            return Ok((removed_files, freed_bytes));
+        }
+
+        for entry in std::fs::read_dir(directory)
+            .with_context(|| format!("Failed to read directory: {directory:?}"))?
+        {
+            let path = entry
+                .with_context(|| format!("Failed to read directory entry in {directory:?}"))?
+                .path();
+            let Some(file_name) = path.file_name().map(|name| name.to_string_lossy()) else {
+                continue;
+            };
+
+            if path.is_file() && !referenced_file_names.contains(file_name.as_ref()) {
+                let file_size = path.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove file: {path:?}"))?;
+                debug!(self.logger, "Removed unreferenced artifact file"; "path" => path.display());
+
+                removed_files += 1;
+                freed_bytes += file_size;
+            }
+        }
+
+        Ok((removed_files, freed_bytes))
+    }
+
+    async fn prune_unreferenced_files(&self, current_epoch: Epoch) -> StdResult<()> {
+        let Some(retention_limit) = self.retention_limit else {
+            return Ok(());
+        };
+
+        if self.is_any_locally_stored_signed_entity_type_locked().await {
+            info!(
+                self.logger,
+                "Some locally stored entities are locked - Skipping local artifacts files pruning"
+            );
+            return Ok(());
+        }
+
+        let referenced_file_names = self
+            .get_referenced_file_names(current_epoch - retention_limit)
+            .await?;
+
+        let mut total_removed_files = 0;
+        let mut total_freed_bytes = 0;
+        for directory in &self.managed_directories {
+            let (removed_files, freed_bytes) =
+                self.remove_unreferenced_files(directory, &referenced_file_names)?;
+            total_removed_files += removed_files;
+            total_freed_bytes += freed_bytes;
+        }
+
+        info!(
+            self.logger, "Pruned unreferenced local artifacts files";
+            "removed_files" => total_removed_files, "freed_bytes" => total_freed_bytes
+        );
+
+        Ok(())
+    }
+}
+
+/// Extract the locations of the files of the artifact of a signed entity.
+fn artifact_locations(record: &SignedEntityRecord) -> StdResult<Vec<String>> {
+    match record.signed_entity_type {
+        SignedEntityType::CardanoImmutableFilesFull(_) => {
+            let snapshot: Snapshot = serde_json::from_str(&record.artifact).with_context(|| {
+                format!(
+                    "Failed to parse snapshot artifact: '{}'",
+                    record.signed_entity_id
+                )
+            })?;
+
+            Ok(snapshot
+                .locations
+                .into_iter()
+                .chain(snapshot.ancillary_locations.unwrap_or_default())
+                .collect())
+        }
+        SignedEntityType::CardanoDatabase(_) => {
+            let snapshot: CardanoDatabaseSnapshot = serde_json::from_str(&record.artifact)
+                .with_context(|| {
+                    format!(
+                        "Failed to parse Cardano database artifact: '{}'",
+                        record.signed_entity_id
+                    )
+                })?;
+            let digests_uris =
+                snapshot
+                    .digests
+                    .locations
+                    .into_iter()
+                    .filter_map(|location| match location {
+                        DigestLocation::CloudStorage { uri, .. }
+                        | DigestLocation::Aggregator { uri } => Some(uri),
+                        DigestLocation::Unknown => None,
+                    });
+            let ancillary_uris =
+                snapshot
+                    .ancillary
+                    .locations
+                    .into_iter()
+                    .filter_map(|location| match location {
+                        AncillaryLocation::CloudStorage { uri, .. } => Some(uri),
+                        AncillaryLocation::Unknown => None,
+                    });
+
+            Ok(digests_uris.chain(ancillary_uris).collect())
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+fn file_name_from_uri(uri: &str) -> Option<String> {
+    uri.rsplit('/')
+        .next()
+        .filter(|file_name| !file_name.is_empty())
+        .map(|file_name| file_name.to_string())
+}
+
+#[async_trait]
+impl EpochPruningTask for LocalArtifactsPruner {
+    fn pruned_data(&self) -> &'static str {
+        "Local artifacts files"
+    }
+
+    async fn prune(&self, current_epoch: Epoch) -> StdResult<()> {
+        self.prune_unreferenced_files(current_epoch).await?;
+
+        let disk_usage = self.disk_usage.refresh().await?;
+        debug!(self.logger, "Refreshed local artifacts disk usage"; "disk_usage" => disk_usage);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+
+    use mithril_common::entities::{
+        AncillaryLocations, CardanoDbBeacon, CompressionAlgorithm, DigestsLocations,
+    };
+    use mithril_common::temp_dir_create;
+    use mithril_common::test_utils::fake_data;
+
+    use crate::database::repository::MockSignedEntityStorer;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    const SERVER_URL: &str = "http://aggregator:8080";
+
+    fn snapshot_record(
+        epoch: u64,
+        file_name: &str,
+        created_at: DateTime<Utc>,
+    ) -> SignedEntityRecord {
+        let beacon = CardanoDbBeacon::new(epoch, epoch * 10);
+        let snapshot = Snapshot {
+            digest: format!("digest-{epoch}"),
+            beacon,
+            locations: vec![format!("{SERVER_URL}/snapshot_download/{file_name}")],
+            ancillary_locations: None,
+            ..fake_data::snapshots(1)[0].clone()
+        };
+
+        SignedEntityRecord::from_snapshot(snapshot, format!("certificate-{epoch}"), created_at)
+    }
+
+    fn cardano_database_record(
+        epoch: u64,
+        digests_file_name: &str,
+        ancillary_file_name: &str,
+        created_at: DateTime<Utc>,
+    ) -> SignedEntityRecord {
+        let beacon = CardanoDbBeacon::new(epoch, epoch * 10);
+        let snapshot = CardanoDatabaseSnapshot {
+            hash: format!("hash-{epoch}"),
+            beacon: beacon.clone(),
+            digests: DigestsLocations {
+                size_uncompressed: 0,
+                locations: vec![
+                    DigestLocation::CloudStorage {
+                        uri: format!(
+                            "{SERVER_URL}/cardano-database-download/digests/{digests_file_name}"
+                        ),
+                        compression_algorithm: Some(CompressionAlgorithm::Gzip),
+                    },
+                    DigestLocation::Aggregator {
+                        uri: format!("{SERVER_URL}/artifact/cardano-database/digests"),
+                    },
+                ],
+            },
+            ancillary: AncillaryLocations {
+                size_uncompressed: 0,
+                locations: vec![AncillaryLocation::CloudStorage {
+                    uri: format!(
+                        "{SERVER_URL}/cardano-database-download/ancillary/{ancillary_file_name}"
+                    ),
+                    compression_algorithm: Some(CompressionAlgorithm::Gzip),
+                }],
+            },
+            ..fake_data::cardano_database_snapshots(1)[0].clone()
+        };
+
+        SignedEntityRecord {
+            signed_entity_id: snapshot.hash.clone(),
+            signed_entity_type: SignedEntityType::CardanoDatabase(beacon),
+            certificate_id: format!("certificate-{epoch}"),
+            artifact: serde_json::to_string(&snapshot).unwrap(),
+            created_at,
+        }
+    }
+
+    fn signed_entity_storer_returning(records: Vec<SignedEntityRecord>) -> MockSignedEntityStorer {
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_last_signed_entities_by_type()
+            .returning(move |signed_entity_type, _| {
+                Ok(records
+                    .iter()
+                    .filter(|record| {
+                        &SignedEntityTypeDiscriminants::from(&record.signed_entity_type)
+                            == signed_entity_type
+                    })
+                    .cloned()
+                    .collect())
+            });
+
+        storer
+    }
+
+    fn create_files(directory: &Path, file_names: &[&str]) {
+        std::fs::create_dir_all(directory).unwrap();
+        for file_name in file_names {
+            std::fs::write(directory.join(file_name), "content").unwrap();
+        }
+    }
+
+    fn list_files(directory: &Path) -> Vec<String> {
+        let mut files: Vec<String> = std::fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[tokio::test]
+    async fn prune_files_not_referenced_by_signed_entities_within_retention_limit() {
+        let temp_dir = temp_dir_create!();
+        let snapshot_dir = temp_dir.join("cardano-immutable-files-full");
+        let digests_dir = temp_dir.join("cardano-database").join("digests");
+        let ancillary_dir = temp_dir.join("cardano-database").join("ancillary");
+        create_files(&snapshot_dir, &["snapshot-1.tar.gz", "snapshot-9.tar.gz"]);
+        create_files(&digests_dir, &["digests-1.tar.gz", "digests-9.tar.gz"]);
+        create_files(
+            &ancillary_dir,
+            &["ancillary-1.tar.gz", "ancillary-9.tar.gz"],
+        );
+        let now = Utc::now();
+        let storer = signed_entity_storer_returning(vec![
+            snapshot_record(1, "snapshot-1.tar.gz", now - Duration::days(1)),
+            snapshot_record(9, "snapshot-9.tar.gz", now),
+            cardano_database_record(
+                1,
+                "digests-1.tar.gz",
+                "ancillary-1.tar.gz",
+                now - Duration::days(1),
+            ),
+            cardano_database_record(9, "digests-9.tar.gz", "ancillary-9.tar.gz", now),
+        ]);
+        let pruner = LocalArtifactsPruner::new(
+            Arc::new(storer),
+            Arc::new(SignedEntityTypeLock::new()),
+            vec![snapshot_dir.clone(), digests_dir.clone(), ancillary_dir.clone()],
+            Some(5),
+            Arc::new(LocalArtifactsDiskUsage::new(vec![])),
+            TestLogger::stdout(),
+        );
+
+        pruner.prune(Epoch(10)).await.unwrap();
+
+        assert_eq!(vec!["snapshot-9.tar.gz"], list_files(&snapshot_dir));
+        assert_eq!(vec!["digests-9.tar.gz"], list_files(&digests_dir));
+        assert_eq!(vec!["ancillary-9.tar.gz"], list_files(&ancillary_dir));
+    }
+
+    #[tokio::test]
+    async fn always_keep_files_of_the_last_signed_entity() {
+        let temp_dir = temp_dir_create!();
+        create_files(&temp_dir, &["snapshot-1.tar.gz", "snapshot-2.tar.gz"]);
+        let now = Utc::now();
+        let storer = signed_entity_storer_returning(vec![
+            snapshot_record(1, "snapshot-1.tar.gz", now - Duration::days(1)),
+            snapshot_record(2, "snapshot-2.tar.gz", now),
+        ]);
+        let pruner = LocalArtifactsPruner::new(
+            Arc::new(storer),
+            Arc::new(SignedEntityTypeLock::new()),
+            vec![temp_dir.clone()],
+            Some(5),
+            Arc::new(LocalArtifactsDiskUsage::new(vec![])),
+            TestLogger::stdout(),
+        );
+
+        pruner.prune(Epoch(100)).await.unwrap();
+
+        assert_eq!(vec!["snapshot-2.tar.gz"], list_files(&temp_dir));
+    }
+
+    #[tokio::test]
+    async fn do_not_prune_without_retention_limit() {
+        let temp_dir = temp_dir_create!();
+        create_files(&temp_dir, &["snapshot-1.tar.gz", "unreferenced.tar.gz"]);
+        let mut storer = MockSignedEntityStorer::new();
+        storer.expect_get_last_signed_entities_by_type().never();
+        let pruner = LocalArtifactsPruner::new(
+            Arc::new(storer),
+            Arc::new(SignedEntityTypeLock::new()),
+            vec![temp_dir.clone()],
+            None,
+            Arc::new(LocalArtifactsDiskUsage::new(vec![])),
+            TestLogger::stdout(),
+        );
+
+        pruner.prune(Epoch(100)).await.unwrap();
+
+        assert_eq!(
+            vec!["snapshot-1.tar.gz", "unreferenced.tar.gz"],
+            list_files(&temp_dir)
+        );
+    }
+
+    #[tokio::test]
+    async fn do_not_prune_if_a_locally_stored_signed_entity_type_is_locked() {
+        let temp_dir = temp_dir_create!();
+        create_files(&temp_dir, &["unreferenced.tar.gz"]);
+        let mut storer = MockSignedEntityStorer::new();
+        storer.expect_get_last_signed_entities_by_type().never();
+        let signed_entity_type_lock = Arc::new(SignedEntityTypeLock::new());
+        signed_entity_type_lock
+            .lock(SignedEntityTypeDiscriminants::CardanoDatabase)
+            .await;
+        let pruner = LocalArtifactsPruner::new(
+            Arc::new(storer),
+            signed_entity_type_lock,
+            vec![temp_dir.clone()],
+            Some(5),
+            Arc::new(LocalArtifactsDiskUsage::new(vec![])),
+            TestLogger::stdout(),
+        );
+
+        pruner.prune(Epoch(100)).await.unwrap();
+
+        assert_eq!(vec!["unreferenced.tar.gz"], list_files(&temp_dir));
+    }
+
+    #[tokio::test]
+    async fn refresh_disk_usage_after_pruning() {
+        let temp_dir = temp_dir_create!();
+        create_files(&temp_dir, &["snapshot-1.tar.gz", "unreferenced.tar.gz"]);
+        let storer = signed_entity_storer_returning(vec![snapshot_record(
+            1,
+            "snapshot-1.tar.gz",
+            Utc::now(),
+        )]);
+        let disk_usage = Arc::new(LocalArtifactsDiskUsage::new(vec![temp_dir.clone()]));
+        let pruner = LocalArtifactsPruner::new(
+            Arc::new(storer),
+            Arc::new(SignedEntityTypeLock::new()),
+            vec![temp_dir.clone()],
+            Some(5),
+            disk_usage.clone(),
+            TestLogger::stdout(),
+        );
+
+        pruner.prune(Epoch(1)).await.unwrap();
+
+        assert_eq!(
+            disk_usage.get().await,
+            Some("content".len() as u64),
+            "only the referenced file should be accounted"
+        );
+    }
+
+    #[tokio::test]
+    async fn refresh_disk_usage_even_without_retention_limit() {
+        let temp_dir = temp_dir_create!();
+        create_files(&temp_dir, &["snapshot-1.tar.gz", "unreferenced.tar.gz"]);
+        let disk_usage = Arc::new(LocalArtifactsDiskUsage::new(vec![temp_dir.clone()]));
+        let pruner = LocalArtifactsPruner::new(
+            Arc::new(MockSignedEntityStorer::new()),
+            Arc::new(SignedEntityTypeLock::new()),
+            vec![temp_dir.clone()],
+            None,
+            disk_usage.clone(),
+            TestLogger::stdout(),
+        );
+
+        pruner.prune(Epoch(1)).await.unwrap();
+
+        assert_eq!(disk_usage.get().await, Some(2 * "content".len() as u64));
+    }
+
+    #[test]
+    fn extract_file_name_from_uri() {
+        assert_eq!(
+            Some("archive.tar.gz".to_string()),
+            file_name_from_uri("http://aggregator/snapshot_download/archive.tar.gz")
+        );
+        assert_eq!(
+            None,
+            file_name_from_uri("http://aggregator/snapshot_download/")
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use slog::{Logger, error};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use mithril_common::StdResult;
+use mithril_common::entities::FileUri;
+use mithril_common::logging::LoggerExtensions;
+
+use crate::file_uploaders::FileUploader;
+
+/// Policy defining how many backends must succeed for a mirrored upload to be successful.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MirrorUploadPolicy {
+    /// At least one backend must succeed, the failures of the other backends are tolerated.
+    #[default]
+    AtLeastOne,
+    /// A strict majority of the backends must succeed.
+    Majority,
+    /// All the backends must succeed.
+    All,
+}
+
+impl MirrorUploadPolicy {
+    /// Check if the number of successful uploads satisfies the policy.
+    pub fn is_satisfied(&self, successful_uploads: usize, total_backends: usize) -> bool {
+        match self {
+            Self::AtLeastOne => successful_uploads > 0,
+            Self::Majority => successful_uploads * 2 > total_backends,
+            Self::All => successful_uploads > 0 && successful_uploads == total_backends,
+        }
+    }
+
+    /// Return an error if the number of successful uploads does not satisfy the policy.
+    pub fn check(&self, successful_uploads: usize, total_backends: usize) -> StdResult<()> {
+        if self.is_satisfied(successful_uploads, total_backends) {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Upload succeeded on {successful_uploads} of {total_backends} backends, which does not satisfy the '{self}' upload policy"
+            ))
+        }
+    }
+}
+
+impl Display for MirrorUploadPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AtLeastOne => write!(f, "at_least_one"),
+            Self::Majority => write!(f, "majority"),
+            Self::All => write!(f, "all"),
+        }
+    }
+}
+
+impl FromStr for MirrorUploadPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "at_least_one" => Ok(Self::AtLeastOne),
+            "majority" => Ok(Self::Majority),
+            "all" => Ok(Self::All),
+            _ => Err(anyhow!("Unknown mirror upload policy: '{s}'")),
+        }
+    }
+}
+
+/// MirrorUploader is a composite uploader writing each file to several backends.
+///
+/// The locations returned by every successful backend are kept so that clients can pick or fail
+/// over between them, the failures of some backends being tolerated according to the
+/// [MirrorUploadPolicy].
+pub struct MirrorUploader {
+    uploaders: Vec<Arc<dyn FileUploader>>,
+    policy: MirrorUploadPolicy,
+    logger: Logger,
+}
+
+impl MirrorUploader {
+    /// MirrorUploader factory
+    pub fn new(
+        uploaders: Vec<Arc<dyn FileUploader>>,
+        policy: MirrorUploadPolicy,
+        logger: Logger,
+    ) -> StdResult<Self> {
+        if uploaders.is_empty() {
+            return Err(anyhow!(
+                "At least one uploader is required to create a 'MirrorUploader'"
+            ));
+        }
+
+        Ok(Self {
+            uploaders,
+            policy,
+            logger: logger.new_with_component_name::<Self>(),
+        })
+    }
+
+    /// Upload the file to all the backends and return the locations of the successful uploads.
+    pub async fn upload(&self, filepath: &Path) -> StdResult<Vec<FileUri>> {
+        let mut locations = Vec::new();
+        for uploader in &self.uploaders {
+            match uploader.upload(filepath).await {
+                Ok(location) => locations.push(location),
+                Err(e) => {
+                    error!(
+                        self.logger, "Failed to upload file to a mirror backend";
+                        "filepath" => filepath.display(), "error" => ?e
+                    );
+                }
+            }
+        }
+
+        self.policy.check(locations.len(), self.uploaders.len())?;
+
+        Ok(locations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use std::path::PathBuf;
+
+    use crate::file_uploaders::MockFileUploader;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    fn uploader_returning(location: &str) -> Arc<dyn FileUploader> {
+        let location = location.to_string();
+        let mut uploader = MockFileUploader::new();
+        uploader
+            .expect_upload()
+            .with(eq(PathBuf::from("archive.tar.gz")))
+            .return_once(move |_| Ok(FileUri(location)));
+
+        Arc::new(uploader)
+    }
+
+    fn uploader_failing() -> Arc<dyn FileUploader> {
+        let mut uploader = MockFileUploader::new();
+        uploader
+            .expect_upload()
+            .return_once(|_| Err(anyhow!("Failure while uploading...")));
+
+        Arc::new(uploader)
+    }
+
+    #[test]
+    fn policy_is_satisfied_according_to_successful_uploads() {
+        assert!(!MirrorUploadPolicy::AtLeastOne.is_satisfied(0, 3));
+        assert!(MirrorUploadPolicy::AtLeastOne.is_satisfied(1, 3));
+
+        assert!(!MirrorUploadPolicy::Majority.is_satisfied(1, 3));
+        assert!(MirrorUploadPolicy::Majority.is_satisfied(2, 3));
+        assert!(!MirrorUploadPolicy::Majority.is_satisfied(1, 2));
+
+        assert!(!MirrorUploadPolicy::All.is_satisfied(2, 3));
+        assert!(MirrorUploadPolicy::All.is_satisfied(3, 3));
+        assert!(!MirrorUploadPolicy::All.is_satisfied(0, 0));
+    }
+
+    #[test]
+    fn policy_from_str_and_display_are_consistent() {
+        for policy in [
+            MirrorUploadPolicy::AtLeastOne,
+            MirrorUploadPolicy::Majority,
+            MirrorUploadPolicy::All,
+        ] {
+            assert_eq!(policy, policy.to_string().parse().unwrap());
+        }
+
+        MirrorUploadPolicy::from_str("unknown").expect_err("Should fail with unknown policy");
+    }
+
+    #[test]
+    fn create_mirror_uploader_without_uploaders_fails() {
+        let result = MirrorUploader::new(vec![], MirrorUploadPolicy::All, TestLogger::stdout());
+
+        assert!(result.is_err(), "Should fail without uploaders");
+    }
+
+    #[tokio::test]
+    async fn upload_return_the_locations_of_all_backends() {
+        let uploader = MirrorUploader::new(
+            vec![
+                uploader_returning("http://local/archive.tar.gz"),
+                uploader_returning("https://cloud/archive.tar.gz"),
+            ],
+            MirrorUploadPolicy::All,
+            TestLogger::stdout(),
+        )
+        .unwrap();
+
+        let locations = uploader.upload(Path::new("archive.tar.gz")).await.unwrap();
+
+        assert_eq!(
+            vec![
+                FileUri("http://local/archive.tar.gz".to_string()),
+                FileUri("https://cloud/archive.tar.gz".to_string()),
+            ],
+            locations
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_tolerates_backends_failure_allowed_by_the_policy() {
+        let uploader = MirrorUploader::new(
+            vec![uploader_failing(), uploader_returning("https://cloud/archive.tar.gz")],
+            MirrorUploadPolicy::AtLeastOne,
+            TestLogger::stdout(),
+        )
+        .unwrap();
+
+        let locations = uploader.upload(Path::new("archive.tar.gz")).await.unwrap();
+
+        assert_eq!(
+            vec![FileUri("https://cloud/archive.tar.gz".to_string())],
+            locations
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_fails_if_backends_failure_is_not_allowed_by_the_policy() {
+        let uploader = MirrorUploader::new(
+            vec![uploader_failing(), uploader_returning("https://cloud/archive.tar.gz")],
+            MirrorUploadPolicy::All,
+            TestLogger::stdout(),
+        )
+        .unwrap();
+
+        uploader
+            .upload(Path::new("archive.tar.gz"))
+            .await
+            .expect_err("Should fail when a backend fails with the 'all' policy");
+    }
+}
@@ -1,12 +1,18 @@
 mod cloud_uploader;
 mod dumb_uploader;
 mod interface;
+mod local_artifacts_disk_usage;
+mod local_artifacts_pruner;
 mod local_uploader;
+mod mirror_uploader;
 
 pub use cloud_uploader::{CloudRemotePath, CloudUploader, GCloudBackendUploader};
 pub use dumb_uploader::*;
 pub use interface::{FileUploadRetryPolicy, FileUploader};
+pub use local_artifacts_disk_usage::LocalArtifactsDiskUsage;
+pub use local_artifacts_pruner::LocalArtifactsPruner;
 pub use local_uploader::LocalUploader;
+pub use mirror_uploader::{MirrorUploadPolicy, MirrorUploader};
 
 #[cfg(test)]
 pub use interface::MockFileUploader;
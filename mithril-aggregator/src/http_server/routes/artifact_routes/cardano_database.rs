@@ -57,6 +57,7 @@ fn serve_cardano_database_dir(
         .and(middlewares::extract_config(router_state, |config| {
             config.allow_http_serve_directory
         }))
+        .and(warp::header::optional::<String>("if-none-match"))
         .and_then(handlers::ensure_downloaded_file_is_a_cardano_database_artifact)
 }
 
@@ -127,6 +128,7 @@ mod handlers {
         reply: warp::fs::File,
         logger: Logger,
         allow_http_serve_directory: bool,
+        if_none_match: Option<String>,
     ) -> Result<impl warp::Reply, Infallible> {
         let filepath = reply.path().to_path_buf();
         debug!(
@@ -145,7 +147,7 @@ mod handlers {
             || filepath.to_string_lossy().contains("immutable")
             || filepath.to_string_lossy().contains("digests");
         match file_is_a_cardano_database_archive {
-            true => Ok(reply::add_content_disposition_header(reply, &filepath)),
+            true => Ok(reply::local_file(reply, &filepath, if_none_match)),
             false => {
                 warn!(logger,"ensure_downloaded_file_is_a_cardano_database::error"; "error" => "file is not a Cardano database archive");
                 Ok(reply::empty(StatusCode::NOT_FOUND))
@@ -465,4 +467,77 @@ mod tests {
         )
         .unwrap();
     }
+
+    mod serve_cardano_database_dir {
+        use mithril_common::temp_dir_create;
+
+        use crate::http_server::routes::router::RouterConfig;
+
+        use super::*;
+
+        async fn setup_router_serving_directory(
+            cardano_db_artifacts_directory: std::path::PathBuf,
+        ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+            let dependency_manager = initialize_dependencies!().await;
+            let router_state = RouterState::new(
+                Arc::new(dependency_manager),
+                RouterConfig {
+                    cardano_db_artifacts_directory,
+                    allow_http_serve_directory: true,
+                    ..RouterConfig::dummy()
+                },
+            );
+
+            setup_router(router_state)
+        }
+
+        #[tokio::test]
+        async fn serve_partial_content_for_range_request() {
+            let artifacts_dir = temp_dir_create!();
+            std::fs::create_dir(artifacts_dir.join("ancillary")).unwrap();
+            std::fs::write(
+                artifacts_dir.join("ancillary/ancillary.tar.zst"),
+                "0123456789",
+            )
+            .unwrap();
+            let router = setup_router_serving_directory(artifacts_dir).await;
+
+            let response = request()
+                .method(Method::GET.as_str())
+                .path("/cardano-database-download/ancillary/ancillary.tar.zst")
+                .header("range", "bytes=2-5")
+                .reply(&router)
+                .await;
+
+            assert_eq!(StatusCode::PARTIAL_CONTENT, response.status());
+            assert_eq!("2345", response.body());
+        }
+
+        #[tokio::test]
+        async fn serve_not_modified_when_if_none_match_matches_etag() {
+            let artifacts_dir = temp_dir_create!();
+            std::fs::create_dir(artifacts_dir.join("ancillary")).unwrap();
+            std::fs::write(
+                artifacts_dir.join("ancillary/ancillary.tar.zst"),
+                "0123456789",
+            )
+            .unwrap();
+            let router = setup_router_serving_directory(artifacts_dir).await;
+            let path = "/cardano-database-download/ancillary/ancillary.tar.zst";
+
+            let response = request().method(Method::GET.as_str()).path(path).reply(&router).await;
+            assert_eq!(StatusCode::OK, response.status());
+            let etag = response.headers()["ETag"].to_str().unwrap().to_string();
+
+            let response = request()
+                .method(Method::GET.as_str())
+                .path(path)
+                .header("if-none-match", &etag)
+                .reply(&router)
+                .await;
+
+            assert_eq!(StatusCode::NOT_MODIFIED, response.status());
+            assert_eq!(etag, response.headers()["ETag"]);
+        }
+    }
 }
@@ -1,11 +1,20 @@
+use serde::{Deserialize, Serialize};
+use warp::Filter;
+
 use crate::http_server::routes::middlewares;
 use crate::http_server::routes::router::RouterState;
-use warp::Filter;
+
+#[derive(Deserialize, Serialize, Debug)]
+struct BlockRangeRootsQueryParams {
+    from: Option<u64>,
+}
 
 pub fn routes(
     router_state: &RouterState,
 ) -> impl Filter<Extract = (impl warp::Reply + use<>,), Error = warp::Rejection> + Clone + use<> {
-    artifact_cardano_transactions(router_state).or(artifact_cardano_transaction_by_id(router_state))
+    artifact_cardano_transactions(router_state)
+        .or(artifact_cardano_transaction_by_id(router_state))
+        .or(artifact_cardano_transaction_block_range_roots(router_state))
 }
 
 /// GET /artifact/cardano-transactions
@@ -32,19 +41,40 @@ fn artifact_cardano_transaction_by_id(
         .and_then(handlers::get_artifact_by_signed_entity_id)
 }
 
+/// GET /artifact/cardano-transaction/:id/block-range-roots
+fn artifact_cardano_transaction_block_range_roots(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply + use<>,), Error = warp::Rejection> + Clone + use<> {
+    warp::path!("artifact" / "cardano-transaction" / String / "block-range-roots")
+        .and(warp::get())
+        .and(warp::query::<BlockRangeRootsQueryParams>())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_signed_entity_service(router_state))
+        .and(middlewares::with_prover_service(router_state))
+        .and_then(handlers::get_block_range_roots_by_signed_entity_id)
+}
+
 pub mod handlers {
     use slog::{Logger, warn};
     use std::convert::Infallible;
     use std::sync::Arc;
     use warp::http::StatusCode;
 
+    use mithril_common::entities::BlockNumber;
+
     use crate::MetricsService;
     use crate::http_server::routes::middlewares::ClientMetadata;
     use crate::http_server::routes::reply;
-    use crate::services::MessageService;
+    use crate::message_adapters::ToCardanoTransactionsBlockRangeRootsMessageAdapter;
+    use crate::services::{MessageService, ProverService, SignedEntityService};
+
+    use super::BlockRangeRootsQueryParams;
 
     pub const LIST_MAX_ITEMS: usize = 20;
 
+    /// Maximum number of block range roots returned in a page
+    pub const BLOCK_RANGE_ROOTS_PAGE_MAX_ITEMS: usize = 10_000;
+
     /// List Cardano Transactions set artifacts
     pub async fn list_artifacts(
         logger: Logger,
@@ -93,6 +123,54 @@ pub mod handlers {
             }
         }
     }
+
+    /// Get the block range roots of a Cardano transactions set artifact
+    pub async fn get_block_range_roots_by_signed_entity_id(
+        signed_entity_id: String,
+        query_params: BlockRangeRootsQueryParams,
+        logger: Logger,
+        signed_entity_service: Arc<dyn SignedEntityService>,
+        prover_service: Arc<dyn ProverService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        let signed_entity = match signed_entity_service
+            .get_signed_cardano_transaction_snapshot_by_id(&signed_entity_id)
+            .await
+        {
+            Ok(Some(signed_entity)) => signed_entity,
+            Ok(None) => {
+                warn!(
+                    logger,
+                    "get_cardano_transaction_block_range_roots::not_found"
+                );
+                return Ok(reply::empty(StatusCode::NOT_FOUND));
+            }
+            Err(err) => {
+                warn!(logger, "get_cardano_transaction_block_range_roots::error"; "error" => ?err);
+                return Ok(reply::server_error(err));
+            }
+        };
+
+        match prover_service
+            .get_block_range_roots(
+                signed_entity.artifact.block_number,
+                BlockNumber(query_params.from.unwrap_or_default()),
+                BLOCK_RANGE_ROOTS_PAGE_MAX_ITEMS,
+            )
+            .await
+        {
+            Ok(block_range_roots) => Ok(reply::json(
+                &ToCardanoTransactionsBlockRangeRootsMessageAdapter::adapt(
+                    signed_entity,
+                    block_range_roots,
+                ),
+                StatusCode::OK,
+            )),
+            Err(err) => {
+                warn!(logger, "get_cardano_transaction_block_range_roots::error"; "error" => ?err);
+                Ok(reply::server_error(err))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -107,12 +185,20 @@ pub mod tests {
     use mithril_api_spec::APISpec;
     use mithril_common::{
         MITHRIL_CLIENT_TYPE_HEADER, MITHRIL_ORIGIN_TAG_HEADER,
+        entities::{BlockNumber, CardanoTransactionsSnapshot},
         messages::{CardanoTransactionSnapshotListItemMessage, CardanoTransactionSnapshotMessage},
+        signable_builder::SignedEntity,
         test_utils::double::Dummy,
     };
     use mithril_persistence::sqlite::HydrationError;
 
-    use crate::{initialize_dependencies, services::MockMessageService};
+    use crate::{
+        initialize_dependencies,
+        services::{
+            CardanoTransactionsBlockRangeRoots, MockMessageService, MockProverService,
+            MockSignedEntityService,
+        },
+    };
 
     use super::*;
 
@@ -322,4 +408,158 @@ pub mod tests {
         )
         .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_cardano_transaction_block_range_roots_get_ok() {
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_signed_cardano_transaction_snapshot_by_id()
+            .return_once(|_| Ok(Some(SignedEntity::<CardanoTransactionsSnapshot>::dummy())))
+            .once();
+        let mut mock_prover_service = MockProverService::new();
+        mock_prover_service
+            .expect_get_block_range_roots()
+            .return_once(|_, _, _| {
+                Ok(CardanoTransactionsBlockRangeRoots {
+                    block_range_roots: vec![],
+                    next_page_from: None,
+                    last_block_range_transactions: vec![],
+                })
+            })
+            .once();
+        let mut dependency_manager = initialize_dependencies!().await;
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+        dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+        let method = Method::GET.as_str();
+        let path = "/artifact/cardano-transaction/{hash}/block-range-roots";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cardano_transaction_block_range_roots_get_the_page_starting_at_the_given_block_number()
+     {
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_signed_cardano_transaction_snapshot_by_id()
+            .return_once(|_| Ok(Some(SignedEntity::<CardanoTransactionsSnapshot>::dummy())))
+            .once();
+        let mut mock_prover_service = MockProverService::new();
+        mock_prover_service
+            .expect_get_block_range_roots()
+            .withf(|_, from, max_items| {
+                *from == BlockNumber(30) && *max_items == handlers::BLOCK_RANGE_ROOTS_PAGE_MAX_ITEMS
+            })
+            .return_once(|_, _, _| {
+                Ok(CardanoTransactionsBlockRangeRoots {
+                    block_range_roots: vec![],
+                    next_page_from: None,
+                    last_block_range_transactions: vec![],
+                })
+            })
+            .once();
+        let mut dependency_manager = initialize_dependencies!().await;
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+        dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path("/artifact/cardano-transaction/{hash}/block-range-roots?from=30")
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_cardano_transaction_block_range_roots_return_404_not_found_when_no_record() {
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_signed_cardano_transaction_snapshot_by_id()
+            .return_once(|_| Ok(None))
+            .once();
+        let mut dependency_manager = initialize_dependencies!().await;
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+        let method = Method::GET.as_str();
+        let path = "/artifact/cardano-transaction/{hash}/block-range-roots";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::NOT_FOUND,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cardano_transaction_block_range_roots_get_ko() {
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_signed_cardano_transaction_snapshot_by_id()
+            .return_once(|_| Ok(Some(SignedEntity::<CardanoTransactionsSnapshot>::dummy())))
+            .once();
+        let mut mock_prover_service = MockProverService::new();
+        mock_prover_service
+            .expect_get_block_range_roots()
+            .return_once(|_, _, _| Err(anyhow::anyhow!("error")))
+            .once();
+        let mut dependency_manager = initialize_dependencies!().await;
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+        dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+        let method = Method::GET.as_str();
+        let path = "/artifact/cardano-transaction/{hash}/block-range-roots";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
 }
@@ -1,6 +1,8 @@
+pub mod cardano_blocks;
 pub mod cardano_database;
 pub mod cardano_stake_distribution;
 pub mod cardano_transaction;
+pub mod cardano_transactions_metadata;
 pub mod mithril_stake_distribution;
 pub mod snapshot;
 
@@ -61,6 +61,7 @@ fn serve_snapshots_dir(
         .and(middlewares::extract_config(router_state, |config| {
             config.allow_http_serve_directory
         }))
+        .and(warp::header::optional::<String>("if-none-match"))
         .and_then(handlers::ensure_downloaded_file_is_a_snapshot)
 }
 
@@ -129,6 +130,7 @@ mod handlers {
         logger: Logger,
         signed_entity_service: Arc<dyn SignedEntityService>,
         allow_http_serve_directory: bool,
+        if_none_match: Option<String>,
     ) -> Result<impl warp::Reply, Infallible> {
         let filepath = reply.path().to_path_buf();
         debug!(
@@ -144,7 +146,7 @@ mod handlers {
 
         match crate::tools::extract_digest_from_path(&filepath) {
             Ok(digest) => match signed_entity_service.get_signed_snapshot_by_id(&digest).await {
-                Ok(Some(_)) => Ok(reply::add_content_disposition_header(reply, &filepath)),
+                Ok(Some(_)) => Ok(reply::local_file(reply, &filepath, if_none_match)),
                 _ => Ok(reply::empty(StatusCode::NOT_FOUND)),
             },
             Err(err) => {
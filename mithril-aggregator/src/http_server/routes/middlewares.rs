@@ -9,9 +9,13 @@ use mithril_common::{MITHRIL_CLIENT_TYPE_HEADER, MITHRIL_ORIGIN_TAG_HEADER};
 use crate::database::repository::SignerGetter;
 use crate::dependency_injection::EpochServiceWrapper;
 use crate::event_store::{EventMessage, TransmitterService};
+use crate::file_uploaders::LocalArtifactsDiskUsage;
 use crate::http_server::routes::http_server_child_logger;
 use crate::http_server::routes::router::{RouterConfig, RouterState};
-use crate::services::{CertifierService, MessageService, ProverService, SignedEntityService};
+use crate::services::{
+    CardanoBlocksProverService, CardanoTransactionsMetadataProverService, CertifierService,
+    MessageService, ProverService, SignedEntityService,
+};
 use crate::{
     MetricsService, SignerRegisterer, SingleSignatureAuthenticator, VerificationKeyStorer,
 };
@@ -92,6 +96,14 @@ pub fn with_epoch_service(
     warp::any().map(move || epoch_service.clone())
 }
 
+/// With local artifacts disk usage middleware
+pub fn with_local_artifacts_disk_usage(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (Arc<LocalArtifactsDiskUsage>,), Error = Infallible> + Clone + use<> {
+    let local_artifacts_disk_usage = router_state.dependencies.local_artifacts_disk_usage.clone();
+    warp::any().map(move || local_artifacts_disk_usage.clone())
+}
+
 /// With signed entity service
 pub fn with_signed_entity_service(
     router_state: &RouterState,
@@ -132,6 +144,28 @@ pub fn with_prover_service(
     warp::any().map(move || prover_service.clone())
 }
 
+/// With Cardano blocks prover service
+pub fn with_cardano_blocks_prover_service(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (Arc<dyn CardanoBlocksProverService>,), Error = Infallible> + Clone + use<>
+{
+    let prover_service = router_state.dependencies.cardano_blocks_prover_service.clone();
+    warp::any().map(move || prover_service.clone())
+}
+
+/// With Cardano transactions metadata prover service
+pub fn with_cardano_transactions_metadata_prover_service(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (Arc<dyn CardanoTransactionsMetadataProverService>,), Error = Infallible>
++ Clone
++ use<> {
+    let prover_service = router_state
+        .dependencies
+        .cardano_transactions_metadata_prover_service
+        .clone();
+    warp::any().map(move || prover_service.clone())
+}
+
 /// With Single Signature Authenticator
 pub fn with_single_signature_authenticator(
     router_state: &RouterState,
@@ -7,6 +7,7 @@ use crate::http_server::routes::router::RouterState;
 #[derive(Deserialize, Serialize, Debug)]
 struct CardanoTransactionProofQueryParams {
     transaction_hashes: String,
+    snapshot_hash: Option<String>,
 }
 
 impl CardanoTransactionProofQueryParams {
@@ -22,10 +23,49 @@ impl CardanoTransactionProofQueryParams {
     }
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+struct CardanoBlockProofQueryParams {
+    block_hashes: String,
+}
+
+impl CardanoBlockProofQueryParams {
+    pub fn split_blocks_hashes(&self) -> Vec<String> {
+        self.block_hashes.split(',').map(|s| s.to_string()).collect()
+    }
+
+    pub fn sanitize(&self) -> Vec<String> {
+        let mut block_hashes = self.split_blocks_hashes();
+        block_hashes.sort();
+        block_hashes.dedup();
+        block_hashes
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+struct CardanoTransactionMetadataProofQueryParams {
+    transaction_hashes: String,
+}
+
+impl CardanoTransactionMetadataProofQueryParams {
+    pub fn split_transactions_hashes(&self) -> Vec<String> {
+        self.transaction_hashes.split(',').map(|s| s.to_string()).collect()
+    }
+
+    pub fn sanitize(&self) -> Vec<String> {
+        let mut transaction_hashes = self.split_transactions_hashes();
+        transaction_hashes.sort();
+        transaction_hashes.dedup();
+        transaction_hashes
+    }
+}
+
 pub fn routes(
     router_state: &RouterState,
 ) -> impl Filter<Extract = (impl warp::Reply + use<>,), Error = warp::Rejection> + Clone + use<> {
     proof_cardano_transaction(router_state)
+        .or(post_proof_cardano_transaction(router_state))
+        .or(proof_cardano_block(router_state))
+        .or(proof_cardano_transaction_metadata(router_state))
 }
 
 /// GET /proof/cardano-transaction
@@ -44,14 +84,73 @@ fn proof_cardano_transaction(
         .and_then(handlers::proof_cardano_transaction)
 }
 
+/// POST /proof/cardano-transaction
+fn post_proof_cardano_transaction(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply + use<>,), Error = warp::Rejection> + Clone + use<> {
+    warp::path!("proof" / "cardano-transaction")
+        .and(warp::post())
+        .and(middlewares::with_client_metadata(router_state))
+        .and(warp::body::json())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_signed_entity_service(router_state))
+        .and(middlewares::validators::with_prover_transactions_hash_validator(router_state))
+        .and(middlewares::with_prover_service(router_state))
+        .and(middlewares::with_metrics_service(router_state))
+        .and_then(handlers::post_proof_cardano_transaction)
+}
+
+/// GET /proof/cardano-block
+fn proof_cardano_block(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply + use<>,), Error = warp::Rejection> + Clone + use<> {
+    warp::path!("proof" / "cardano-block")
+        .and(warp::get())
+        .and(middlewares::with_client_metadata(router_state))
+        .and(warp::query::<CardanoBlockProofQueryParams>())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_signed_entity_service(router_state))
+        // Block hashes share the format of the transaction hashes (hex encoded, 32 bytes)
+        .and(middlewares::validators::with_prover_transactions_hash_validator(router_state))
+        .and(middlewares::with_cardano_blocks_prover_service(
+            router_state,
+        ))
+        .and(middlewares::with_metrics_service(router_state))
+        .and_then(handlers::proof_cardano_block)
+}
+
+/// GET /proof/cardano-transaction-metadata
+fn proof_cardano_transaction_metadata(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply + use<>,), Error = warp::Rejection> + Clone + use<> {
+    warp::path!("proof" / "cardano-transaction-metadata")
+        .and(warp::get())
+        .and(middlewares::with_client_metadata(router_state))
+        .and(warp::query::<CardanoTransactionMetadataProofQueryParams>())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_signed_entity_service(router_state))
+        .and(middlewares::validators::with_prover_transactions_hash_validator(router_state))
+        .and(middlewares::with_cardano_transactions_metadata_prover_service(router_state))
+        .and(middlewares::with_metrics_service(router_state))
+        .and_then(handlers::proof_cardano_transaction_metadata)
+}
+
 mod handlers {
     use slog::{Logger, debug, warn};
     use std::{convert::Infallible, sync::Arc};
     use warp::http::StatusCode;
 
     use mithril_common::{
-        StdResult, entities::CardanoTransactionsSnapshot,
-        messages::CardanoTransactionsProofsMessage, signable_builder::SignedEntity,
+        StdResult,
+        entities::{
+            BlockNumber, CardanoBlocksSnapshot, CardanoTransactionsMetadataSnapshot,
+            CardanoTransactionsSnapshot,
+        },
+        messages::{
+            CardanoBlocksProofsMessage, CardanoTransactionsMetadataProofsMessage,
+            CardanoTransactionsProofsMessage, CardanoTransactionsProofsRequestMessage,
+        },
+        signable_builder::SignedEntity,
     };
 
     use crate::{
@@ -60,12 +159,21 @@ mod handlers {
             routes::{middlewares::ClientMetadata, reply},
             validators::ProverTransactionsHashValidator,
         },
-        message_adapters::ToCardanoTransactionsProofsMessageAdapter,
-        services::{ProverService, SignedEntityService},
+        message_adapters::{
+            ToCardanoBlocksProofsMessageAdapter, ToCardanoTransactionsMetadataProofsMessageAdapter,
+            ToCardanoTransactionsProofsMessageAdapter,
+        },
+        services::{
+            CardanoBlocksProverService, CardanoTransactionsMetadataProverService, ProverService,
+            SignedEntityService,
+        },
         unwrap_to_internal_server_error,
     };
 
-    use super::CardanoTransactionProofQueryParams;
+    use super::{
+        CardanoBlockProofQueryParams, CardanoTransactionMetadataProofQueryParams,
+        CardanoTransactionProofQueryParams,
+    };
 
     pub async fn proof_cardano_transaction(
         client_metadata: ClientMetadata,
@@ -76,6 +184,72 @@ mod handlers {
         prover_service: Arc<dyn ProverService>,
         metrics_service: Arc<MetricsService>,
     ) -> Result<impl warp::Reply, Infallible> {
+        let transaction_hashes = transaction_parameters.split_transactions_hashes();
+        debug!(
+            logger, ">> proof_cardano_transaction";
+            "transaction_hashes" => &transaction_parameters.transaction_hashes,
+            "snapshot_hash" => ?transaction_parameters.snapshot_hash
+        );
+
+        compute_proofs_reply(
+            client_metadata,
+            &transaction_hashes,
+            transaction_parameters.sanitize(),
+            transaction_parameters.snapshot_hash,
+            None,
+            logger,
+            signed_entity_service,
+            validator,
+            prover_service,
+            metrics_service,
+        )
+        .await
+    }
+
+    pub async fn post_proof_cardano_transaction(
+        client_metadata: ClientMetadata,
+        request_message: CardanoTransactionsProofsRequestMessage,
+        logger: Logger,
+        signed_entity_service: Arc<dyn SignedEntityService>,
+        validator: ProverTransactionsHashValidator,
+        prover_service: Arc<dyn ProverService>,
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!(
+            logger, ">> post_proof_cardano_transaction";
+            "nb_transaction_hashes" => request_message.transaction_hashes.len(),
+            "block_number" => ?request_message.block_number,
+            "snapshot_hash" => ?request_message.snapshot_hash
+        );
+
+        compute_proofs_reply(
+            client_metadata,
+            &request_message.transaction_hashes,
+            request_message.sanitized_transaction_hashes(),
+            request_message.snapshot_hash,
+            request_message.block_number,
+            logger,
+            signed_entity_service,
+            validator,
+            prover_service,
+            metrics_service,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn compute_proofs_reply(
+        client_metadata: ClientMetadata,
+        transaction_hashes: &[String],
+        sanitized_hashes: Vec<String>,
+        snapshot_hash: Option<String>,
+        block_number: Option<BlockNumber>,
+        logger: Logger,
+        signed_entity_service: Arc<dyn SignedEntityService>,
+        validator: ProverTransactionsHashValidator,
+        prover_service: Arc<dyn ProverService>,
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<Box<dyn warp::Reply>, Infallible> {
         metrics_service
             .get_proof_cardano_transaction_total_proofs_served_since_startup()
             .increment(&[
@@ -83,19 +257,11 @@ mod handlers {
                 client_metadata.client_type.as_deref().unwrap_or_default(),
             ]);
 
-        let transaction_hashes = transaction_parameters.split_transactions_hashes();
-        debug!(
-            logger, ">> proof_cardano_transaction";
-            "transaction_hashes" => &transaction_parameters.transaction_hashes
-        );
-
-        if let Err(error) = validator.validate(&transaction_hashes) {
+        if let Err(error) = validator.validate(transaction_hashes) {
             warn!(logger, "proof_cardano_transaction::bad_request");
             return Ok(reply::bad_request(error.label, error.message));
         }
 
-        let sanitized_hashes = transaction_parameters.sanitize();
-
         // Fallback to 0, it should be impossible to have more than u32::MAX transactions.
         metrics_service
             .get_proof_cardano_transaction_total_transactions_served_since_startup()
@@ -108,9 +274,12 @@ mod handlers {
             );
 
         match unwrap_to_internal_server_error!(
-            signed_entity_service
-                .get_last_cardano_transaction_snapshot()
-                .await,
+            get_target_snapshot(
+                signed_entity_service.as_ref(),
+                snapshot_hash.as_deref(),
+                block_number,
+            )
+            .await,
             logger => "proof_cardano_transaction::error"
         ) {
             Some(signed_entity) => {
@@ -121,12 +290,45 @@ mod handlers {
                 Ok(reply::json(&message, StatusCode::OK))
             }
             None => {
-                warn!(logger, "proof_cardano_transaction::not_found");
+                warn!(
+                    logger, "proof_cardano_transaction::not_found";
+                    "snapshot_hash" => ?snapshot_hash,
+                    "block_number" => ?block_number
+                );
                 Ok(reply::empty(StatusCode::NOT_FOUND))
             }
         }
     }
 
+    /// Get the certified Cardano transactions snapshot to compute the proofs against
+    ///
+    /// The latest one is used if neither a snapshot hash nor a block number is given, if both are
+    /// given they must designate the same snapshot.
+    async fn get_target_snapshot(
+        signed_entity_service: &dyn SignedEntityService,
+        snapshot_hash: Option<&str>,
+        block_number: Option<BlockNumber>,
+    ) -> StdResult<Option<SignedEntity<CardanoTransactionsSnapshot>>> {
+        let signed_entity = match (snapshot_hash, block_number) {
+            (Some(snapshot_hash), _) => {
+                signed_entity_service
+                    .get_signed_cardano_transaction_snapshot_by_id(snapshot_hash)
+                    .await?
+            }
+            (None, Some(block_number)) => {
+                signed_entity_service
+                    .get_signed_cardano_transaction_snapshot_by_block_number(block_number)
+                    .await?
+            }
+            (None, None) => signed_entity_service.get_last_cardano_transaction_snapshot().await?,
+        };
+
+        Ok(signed_entity.filter(|signed_entity| {
+            block_number
+                .is_none_or(|block_number| block_number == signed_entity.artifact.block_number)
+        }))
+    }
+
     pub async fn build_response_message(
         prover_service: Arc<dyn ProverService>,
         signed_entity: SignedEntity<CardanoTransactionsSnapshot>,
@@ -146,6 +348,142 @@ mod handlers {
 
         Ok(message)
     }
+
+    pub async fn proof_cardano_block(
+        client_metadata: ClientMetadata,
+        block_parameters: CardanoBlockProofQueryParams,
+        logger: Logger,
+        signed_entity_service: Arc<dyn SignedEntityService>,
+        validator: ProverTransactionsHashValidator,
+        prover_service: Arc<dyn CardanoBlocksProverService>,
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!(
+            logger, ">> proof_cardano_block";
+            "block_hashes" => &block_parameters.block_hashes
+        );
+        metrics_service
+            .get_proof_cardano_blocks_total_proofs_served_since_startup()
+            .increment(&[
+                client_metadata.origin_tag.as_deref().unwrap_or_default(),
+                client_metadata.client_type.as_deref().unwrap_or_default(),
+            ]);
+
+        if let Err(error) = validator.validate(&block_parameters.split_blocks_hashes()) {
+            warn!(logger, "proof_cardano_block::bad_request");
+            return Ok(reply::bad_request(error.label, error.message));
+        }
+
+        match unwrap_to_internal_server_error!(
+            signed_entity_service.get_last_cardano_blocks_snapshot().await,
+            logger => "proof_cardano_block::error"
+        ) {
+            Some(signed_entity) => {
+                let message = unwrap_to_internal_server_error!(
+                    build_blocks_response_message(
+                        prover_service,
+                        signed_entity,
+                        block_parameters.sanitize()
+                    )
+                    .await,
+                    logger => "proof_cardano_block"
+                );
+                Ok(reply::json(&message, StatusCode::OK))
+            }
+            None => {
+                warn!(logger, "proof_cardano_block::not_found");
+                Ok(reply::empty(StatusCode::NOT_FOUND))
+            }
+        }
+    }
+
+    pub async fn build_blocks_response_message(
+        prover_service: Arc<dyn CardanoBlocksProverService>,
+        signed_entity: SignedEntity<CardanoBlocksSnapshot>,
+        block_hashes: Vec<String>,
+    ) -> StdResult<CardanoBlocksProofsMessage> {
+        let blocks_set_proofs = prover_service
+            .compute_blocks_proofs(signed_entity.artifact.block_number, block_hashes.as_slice())
+            .await?;
+        let message = ToCardanoBlocksProofsMessageAdapter::try_adapt(
+            signed_entity,
+            blocks_set_proofs,
+            block_hashes,
+        )?;
+
+        Ok(message)
+    }
+
+    pub async fn proof_cardano_transaction_metadata(
+        client_metadata: ClientMetadata,
+        transaction_parameters: CardanoTransactionMetadataProofQueryParams,
+        logger: Logger,
+        signed_entity_service: Arc<dyn SignedEntityService>,
+        validator: ProverTransactionsHashValidator,
+        prover_service: Arc<dyn CardanoTransactionsMetadataProverService>,
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!(
+            logger, ">> proof_cardano_transaction_metadata";
+            "transaction_hashes" => &transaction_parameters.transaction_hashes
+        );
+        metrics_service
+            .get_proof_cardano_transactions_metadata_total_proofs_served_since_startup()
+            .increment(&[
+                client_metadata.origin_tag.as_deref().unwrap_or_default(),
+                client_metadata.client_type.as_deref().unwrap_or_default(),
+            ]);
+
+        if let Err(error) = validator.validate(&transaction_parameters.split_transactions_hashes())
+        {
+            warn!(logger, "proof_cardano_transaction_metadata::bad_request");
+            return Ok(reply::bad_request(error.label, error.message));
+        }
+
+        match unwrap_to_internal_server_error!(
+            signed_entity_service
+                .get_last_cardano_transactions_metadata_snapshot()
+                .await,
+            logger => "proof_cardano_transaction_metadata::error"
+        ) {
+            Some(signed_entity) => {
+                let message = unwrap_to_internal_server_error!(
+                    build_transactions_metadata_response_message(
+                        prover_service,
+                        signed_entity,
+                        transaction_parameters.sanitize()
+                    )
+                    .await,
+                    logger => "proof_cardano_transaction_metadata"
+                );
+                Ok(reply::json(&message, StatusCode::OK))
+            }
+            None => {
+                warn!(logger, "proof_cardano_transaction_metadata::not_found");
+                Ok(reply::empty(StatusCode::NOT_FOUND))
+            }
+        }
+    }
+
+    pub async fn build_transactions_metadata_response_message(
+        prover_service: Arc<dyn CardanoTransactionsMetadataProverService>,
+        signed_entity: SignedEntity<CardanoTransactionsMetadataSnapshot>,
+        transaction_hashes: Vec<String>,
+    ) -> StdResult<CardanoTransactionsMetadataProofsMessage> {
+        let transactions_metadata_set_proofs = prover_service
+            .compute_transactions_metadata_proofs(
+                signed_entity.artifact.block_number,
+                transaction_hashes.as_slice(),
+            )
+            .await?;
+        let message = ToCardanoTransactionsMetadataProofsMessageAdapter::try_adapt(
+            signed_entity,
+            transactions_metadata_set_proofs,
+            transaction_hashes,
+        )?;
+
+        Ok(message)
+    }
 }
 
 #[cfg(test)]
@@ -162,13 +500,24 @@ mod tests {
     use mithril_api_spec::APISpec;
     use mithril_common::{
         MITHRIL_CLIENT_TYPE_HEADER, MITHRIL_ORIGIN_TAG_HEADER,
-        entities::{BlockNumber, CardanoTransactionsSetProof, CardanoTransactionsSnapshot},
+        entities::{
+            BlockNumber, CardanoBlocksSetProof, CardanoBlocksSnapshot,
+            CardanoTransactionsMetadataSetProof, CardanoTransactionsMetadataSnapshot,
+            CardanoTransactionsSetProof, CardanoTransactionsSnapshot,
+        },
+        messages::CardanoTransactionsProofsRequestMessage,
         signable_builder::SignedEntity,
         test_utils::{assert_equivalent, double::Dummy, fake_data},
     };
 
-    use crate::services::MockProverService;
-    use crate::{initialize_dependencies, services::MockSignedEntityService};
+    use crate::services::{
+        MockCardanoBlocksProverService, MockCardanoTransactionsMetadataProverService,
+        MockProverService,
+    };
+    use crate::{
+        ServeCommandDependenciesContainer, initialize_dependencies,
+        services::MockSignedEntityService,
+    };
 
     use super::*;
 
@@ -429,17 +778,614 @@ mod tests {
         assert_eq!(StatusCode::OK, response.status());
     }
 
-    #[test]
-    fn sanitize_cardano_transaction_proof_query_params_remove_duplicate() {
-        let tx1 = fake_data::transaction_hashes()[0].to_string();
-        let tx2 = fake_data::transaction_hashes()[1].to_string();
+    fn post_request_message(
+        snapshot_hash: Option<String>,
+        block_number: Option<BlockNumber>,
+    ) -> CardanoTransactionsProofsRequestMessage {
+        CardanoTransactionsProofsRequestMessage {
+            transaction_hashes: vec![
+                fake_data::transaction_hashes()[0].to_string(),
+                fake_data::transaction_hashes()[1].to_string(),
+            ],
+            block_number,
+            snapshot_hash,
+        }
+    }
 
-        // We are testing on an unordered list of transaction hashes
-        // as some rust dedup methods only remove consecutive duplicates
-        let params = CardanoTransactionProofQueryParams {
-            transaction_hashes: format!("{tx1},{tx2},{tx2},{tx1},{tx2}",),
-        };
+    fn snapshot_at(block_number: BlockNumber) -> SignedEntity<CardanoTransactionsSnapshot> {
+        let artifact = CardanoTransactionsSnapshot::new(String::new(), block_number);
+        SignedEntity::<CardanoTransactionsSnapshot> {
+            signed_entity_id: artifact.hash.clone(),
+            artifact,
+            ..Dummy::dummy()
+        }
+    }
 
-        assert_equivalent(params.sanitize(), vec![tx1, tx2]);
+    /// Certified snapshots are given in chronological order, the last one is the latest
+    fn dependency_manager_with_snapshots_at(
+        mut dependency_manager: ServeCommandDependenciesContainer,
+        block_numbers: &[BlockNumber],
+    ) -> ServeCommandDependenciesContainer {
+        let snapshots: Vec<_> = block_numbers.iter().map(|bn| snapshot_at(*bn)).collect();
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        let latest_snapshot = snapshots.last().cloned();
+        mock_signed_entity_service
+            .expect_get_last_cardano_transaction_snapshot()
+            .returning(move || Ok(latest_snapshot.clone()));
+        let snapshots_by_id = snapshots.clone();
+        mock_signed_entity_service
+            .expect_get_signed_cardano_transaction_snapshot_by_id()
+            .returning(move |id| {
+                Ok(snapshots_by_id.iter().find(|s| s.signed_entity_id == id).cloned())
+            });
+        mock_signed_entity_service
+            .expect_get_signed_cardano_transaction_snapshot_by_block_number()
+            .returning(move |block_number| {
+                Ok(snapshots
+                    .iter()
+                    .find(|s| s.artifact.block_number == block_number)
+                    .cloned())
+            });
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+        let mut mock_prover_service = MockProverService::new();
+        mock_prover_service
+            .expect_compute_transactions_proofs()
+            .returning(|_, _| Ok(vec![CardanoTransactionsSetProof::dummy()]));
+        dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+        dependency_manager
+    }
+
+    #[tokio::test]
+    async fn post_proof_cardano_transaction_ok() {
+        let dependency_manager = dependency_manager_with_snapshots_at(
+            initialize_dependencies!().await,
+            &[BlockNumber(1000), BlockNumber(2309)],
+        );
+        let request_message = post_request_message(None, None);
+
+        let method = Method::POST.as_str();
+        let path = "/proof/cardano-transaction";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .json(&request_message)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &request_message,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn post_proof_cardano_transaction_pinned_to_the_latest_snapshot_ok() {
+        let dependency_manager = dependency_manager_with_snapshots_at(
+            initialize_dependencies!().await,
+            &[BlockNumber(1000), BlockNumber(2309)],
+        );
+        let request_message = post_request_message(None, Some(BlockNumber(2309)));
+
+        let method = Method::POST.as_str();
+        let path = "/proof/cardano-transaction";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .json(&request_message)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &request_message,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn post_proof_cardano_transaction_pinned_to_an_unknown_snapshot_not_found() {
+        let dependency_manager = dependency_manager_with_snapshots_at(
+            initialize_dependencies!().await,
+            &[BlockNumber(1000), BlockNumber(2309)],
+        );
+        let request_message = post_request_message(None, Some(BlockNumber(1500)));
+
+        let method = Method::POST.as_str();
+        let path = "/proof/cardano-transaction";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .json(&request_message)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &request_message,
+            &response,
+            &StatusCode::NOT_FOUND,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn post_proof_cardano_transaction_pinned_to_a_historical_snapshot_ok() {
+        let mut dependency_manager = dependency_manager_with_snapshots_at(
+            initialize_dependencies!().await,
+            &[BlockNumber(1000), BlockNumber(2309)],
+        );
+        let mut mock_prover_service = MockProverService::new();
+        mock_prover_service
+            .expect_compute_transactions_proofs()
+            .withf(|up_to, _| *up_to == BlockNumber(1000))
+            .returning(|_, _| Ok(vec![CardanoTransactionsSetProof::dummy()]));
+        dependency_manager.prover_service = Arc::new(mock_prover_service);
+        let request_message = post_request_message(
+            Some(snapshot_at(BlockNumber(1000)).artifact.hash),
+            Some(BlockNumber(1000)),
+        );
+
+        let method = Method::POST.as_str();
+        let path = "/proof/cardano-transaction";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .json(&request_message)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &request_message,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn post_proof_cardano_transaction_with_a_snapshot_hash_not_matching_the_block_number_not_found()
+     {
+        let dependency_manager = dependency_manager_with_snapshots_at(
+            initialize_dependencies!().await,
+            &[BlockNumber(1000), BlockNumber(2309)],
+        );
+        let request_message = post_request_message(
+            Some(snapshot_at(BlockNumber(1000)).artifact.hash),
+            Some(BlockNumber(2309)),
+        );
+
+        let response = request()
+            .method(Method::POST.as_str())
+            .path("/proof/cardano-transaction")
+            .json(&request_message)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_transaction_for_a_historical_snapshot_hash_ok() {
+        let mut dependency_manager = dependency_manager_with_snapshots_at(
+            initialize_dependencies!().await,
+            &[BlockNumber(1000), BlockNumber(2309)],
+        );
+        let mut mock_prover_service = MockProverService::new();
+        mock_prover_service
+            .expect_compute_transactions_proofs()
+            .withf(|up_to, _| *up_to == BlockNumber(1000))
+            .returning(|_, _| Ok(vec![CardanoTransactionsSetProof::dummy()]));
+        dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-transaction";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?transaction_hashes={}&snapshot_hash={}",
+                fake_data::transaction_hashes()[0],
+                snapshot_at(BlockNumber(1000)).artifact.hash
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_transaction_for_an_unknown_snapshot_hash_not_found() {
+        let dependency_manager = dependency_manager_with_snapshots_at(
+            initialize_dependencies!().await,
+            &[BlockNumber(2309)],
+        );
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-transaction";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?transaction_hashes={}&snapshot_hash=unknown",
+                fake_data::transaction_hashes()[0],
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::NOT_FOUND,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn post_proof_cardano_transaction_return_bad_request_with_invalid_hashes() {
+        let dependency_manager = initialize_dependencies!().await;
+        let request_message = CardanoTransactionsProofsRequestMessage {
+            transaction_hashes: vec!["invalid://id".to_string(), "tx-456".to_string()],
+            block_number: None,
+            snapshot_hash: None,
+        };
+
+        let method = Method::POST.as_str();
+        let path = "/proof/cardano-transaction";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .json(&request_message)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &request_message,
+            &response,
+            &StatusCode::BAD_REQUEST,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn post_proof_cardano_transaction_deduplicate_hashes() {
+        let tx = fake_data::transaction_hashes()[0].to_string();
+        let mut dependency_manager = dependency_manager_with_snapshots_at(
+            initialize_dependencies!().await,
+            &[BlockNumber(1000), BlockNumber(2309)],
+        );
+        let mut mock_prover_service = MockProverService::new();
+        let txs_expected = vec![tx.clone()];
+        mock_prover_service
+            .expect_compute_transactions_proofs()
+            .withf(move |_, transaction_hashes| transaction_hashes == txs_expected)
+            .returning(|_, _| Ok(vec![CardanoTransactionsSetProof::dummy()]));
+        dependency_manager.prover_service = Arc::new(mock_prover_service);
+
+        let response = request()
+            .method(Method::POST.as_str())
+            .path("/proof/cardano-transaction")
+            .json(&CardanoTransactionsProofsRequestMessage {
+                transaction_hashes: vec![tx.clone(), tx],
+                block_number: None,
+                snapshot_hash: None,
+            })
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[test]
+    fn sanitize_cardano_transaction_proof_query_params_remove_duplicate() {
+        let tx1 = fake_data::transaction_hashes()[0].to_string();
+        let tx2 = fake_data::transaction_hashes()[1].to_string();
+
+        // We are testing on an unordered list of transaction hashes
+        // as some rust dedup methods only remove consecutive duplicates
+        let params = CardanoTransactionProofQueryParams {
+            transaction_hashes: format!("{tx1},{tx2},{tx2},{tx1},{tx2}",),
+            snapshot_hash: None,
+        };
+
+        assert_equivalent(params.sanitize(), vec![tx1, tx2]);
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_block_ok() {
+        let mut dependency_manager = initialize_dependencies!().await;
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_last_cardano_blocks_snapshot()
+            .returning(|| Ok(Some(SignedEntity::<CardanoBlocksSnapshot>::dummy())));
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+        let mut mock_prover_service = MockCardanoBlocksProverService::new();
+        mock_prover_service
+            .expect_compute_blocks_proofs()
+            .returning(|_, _| Ok(vec![CardanoBlocksSetProof::dummy()]));
+        dependency_manager.cardano_blocks_prover_service = Arc::new(mock_prover_service);
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-block";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?block_hashes={},{}",
+                fake_data::transaction_hashes()[0],
+                fake_data::transaction_hashes()[1]
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_block_not_found() {
+        let dependency_manager = initialize_dependencies!().await;
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-block";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?block_hashes={}",
+                fake_data::transaction_hashes()[0]
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::NOT_FOUND,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_block_return_bad_request_with_invalid_hashes() {
+        let dependency_manager = initialize_dependencies!().await;
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-block";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?block_hashes=invalid%3A%2F%2Fid,,block-456"
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::BAD_REQUEST,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_block_route_deduplicate_hashes() {
+        let block_hash = fake_data::transaction_hashes()[0].to_string();
+        let mut dependency_manager = initialize_dependencies!().await;
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_last_cardano_blocks_snapshot()
+            .returning(|| Ok(Some(SignedEntity::<CardanoBlocksSnapshot>::dummy())));
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+        let mut mock_prover_service = MockCardanoBlocksProverService::new();
+        let blocks_expected = vec![block_hash.clone()];
+        mock_prover_service
+            .expect_compute_blocks_proofs()
+            .withf(move |_, block_hashes| block_hashes == blocks_expected)
+            .returning(|_, _| Ok(vec![CardanoBlocksSetProof::dummy()]));
+        dependency_manager.cardano_blocks_prover_service = Arc::new(mock_prover_service);
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-block";
+
+        let response = request()
+            .method(method)
+            .path(&format!("{path}?block_hashes={block_hash},{block_hash}"))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_transaction_metadata_ok() {
+        let mut dependency_manager = initialize_dependencies!().await;
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_last_cardano_transactions_metadata_snapshot()
+            .returning(|| {
+                Ok(Some(
+                    SignedEntity::<CardanoTransactionsMetadataSnapshot>::dummy(),
+                ))
+            });
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+        let mut mock_prover_service = MockCardanoTransactionsMetadataProverService::new();
+        mock_prover_service
+            .expect_compute_transactions_metadata_proofs()
+            .returning(|_, _| Ok(vec![CardanoTransactionsMetadataSetProof::dummy()]));
+        dependency_manager.cardano_transactions_metadata_prover_service =
+            Arc::new(mock_prover_service);
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-transaction-metadata";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?transaction_hashes={},{}",
+                fake_data::transaction_hashes()[0],
+                fake_data::transaction_hashes()[1]
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_transaction_metadata_not_found() {
+        let dependency_manager = initialize_dependencies!().await;
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-transaction-metadata";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?transaction_hashes={}",
+                fake_data::transaction_hashes()[0]
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::NOT_FOUND,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_transaction_metadata_return_bad_request_with_invalid_hashes() {
+        let dependency_manager = initialize_dependencies!().await;
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-transaction-metadata";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?transaction_hashes=invalid%3A%2F%2Fid,,tx-456"
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_default_spec_file_from(crate::http_server::API_SPEC_LOCATION),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::BAD_REQUEST,
+        )
+        .unwrap();
     }
 }
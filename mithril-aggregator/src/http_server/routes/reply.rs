@@ -1,4 +1,5 @@
 use std::path::Path;
+use std::time::UNIX_EPOCH;
 
 use serde::Serialize;
 use warp::http::StatusCode;
@@ -82,6 +83,54 @@ pub fn add_content_disposition_header(
     ))
 }
 
+/// Reply with a file served from the local storage, adding its `ETag` header.
+///
+/// If the given `If-None-Match` header value matches the file `ETag`, a `304 Not Modified`
+/// response is returned instead of the file content.
+pub fn local_file(
+    reply: warp::fs::File,
+    filepath: &Path,
+    if_none_match: Option<String>,
+) -> Box<dyn warp::Reply> {
+    let Some(etag) = compute_file_etag(filepath) else {
+        return add_content_disposition_header(reply, filepath);
+    };
+
+    let is_not_modified = if_none_match.is_some_and(|header| {
+        header
+            .split(',')
+            .map(|value| value.trim().trim_start_matches("W/"))
+            .any(|value| value == etag || value == "*")
+    });
+
+    if is_not_modified {
+        Box::new(warp::reply::with_header(
+            empty(StatusCode::NOT_MODIFIED),
+            "ETag",
+            etag,
+        ))
+    } else {
+        Box::new(warp::reply::with_header(
+            add_content_disposition_header(reply, filepath),
+            "ETag",
+            etag,
+        ))
+    }
+}
+
+/// Compute a strong `ETag` for a file from its size and last modification time.
+fn compute_file_etag(filepath: &Path) -> Option<String> {
+    let metadata = std::fs::metadata(filepath).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+    Some(format!(
+        "\"{:x}-{:x}-{:x}\"",
+        metadata.len(),
+        modified.as_secs(),
+        modified.subsec_nanos()
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::anyhow;
@@ -117,6 +166,79 @@ mod tests {
         assert_eq!(StatusCode::SERVICE_UNAVAILABLE, response.status());
     }
 
+    mod local_file {
+        use std::fs::File;
+        use std::io::Write;
+
+        use mithril_common::temp_dir_create;
+
+        use super::*;
+
+        async fn serve_file(filepath: &Path) -> warp::fs::File {
+            let directory = filepath.parent().unwrap().to_path_buf();
+            let filename = filepath.file_name().unwrap().to_str().unwrap();
+
+            warp::test::request()
+                .path(&format!("/{filename}"))
+                .filter(&warp::fs::dir(directory))
+                .await
+                .unwrap()
+        }
+
+        fn create_file(filepath: &Path) {
+            let mut file = File::create(filepath).unwrap();
+            write!(file, "file content").unwrap();
+        }
+
+        #[tokio::test]
+        async fn add_etag_and_content_disposition_headers() {
+            let filepath = temp_dir_create!().join("archive.tar.gz");
+            create_file(&filepath);
+            let expected_etag = compute_file_etag(&filepath).unwrap();
+
+            let response = local_file(serve_file(&filepath).await, &filepath, None).into_response();
+
+            assert_eq!(StatusCode::OK, response.status());
+            assert_eq!(expected_etag, response.headers()["ETag"]);
+            assert_eq!(
+                "attachment; filename=\"archive.tar.gz\"",
+                response.headers()["Content-Disposition"]
+            );
+        }
+
+        #[tokio::test]
+        async fn reply_not_modified_if_none_match_header_matches_etag() {
+            let filepath = temp_dir_create!().join("archive.tar.gz");
+            create_file(&filepath);
+            let etag = compute_file_etag(&filepath).unwrap();
+
+            let response = local_file(
+                serve_file(&filepath).await,
+                &filepath,
+                Some(format!("\"another-etag\", {etag}")),
+            )
+            .into_response();
+
+            assert_eq!(StatusCode::NOT_MODIFIED, response.status());
+            assert_eq!(etag, response.headers()["ETag"]);
+        }
+
+        #[tokio::test]
+        async fn reply_file_if_none_match_header_does_not_match_etag() {
+            let filepath = temp_dir_create!().join("archive.tar.gz");
+            create_file(&filepath);
+
+            let response = local_file(
+                serve_file(&filepath).await,
+                &filepath,
+                Some("\"another-etag\"".to_string()),
+            )
+            .into_response();
+
+            assert_eq!(StatusCode::OK, response.status());
+        }
+    }
+
     #[test]
     fn test_server_error_convert_signer_registration_round_not_yet_opened_to_550() {
         let err = SignerRegistrationError::RegistrationRoundNotYetOpened;
@@ -1,9 +1,11 @@
+use std::sync::Arc;
 use warp::Filter;
 
 use mithril_common::{StdResult, messages::AggregatorStatusMessage};
 
 use crate::{
     dependency_injection::EpochServiceWrapper,
+    file_uploaders::LocalArtifactsDiskUsage,
     http_server::routes::{middlewares, router::RouterState},
 };
 
@@ -27,6 +29,7 @@ fn status(
         .and(middlewares::extract_config(router_state, |config| {
             config.network.to_string()
         }))
+        .and(middlewares::with_local_artifacts_disk_usage(router_state))
         .and_then(handlers::status)
 }
 
@@ -34,6 +37,7 @@ async fn get_aggregator_status_message(
     epoch_service: EpochServiceWrapper,
     cardano_node_version: String,
     cardano_network: String,
+    local_artifacts_disk_usage: Arc<LocalArtifactsDiskUsage>,
 ) -> StdResult<AggregatorStatusMessage> {
     let epoch_service = epoch_service.read().await;
 
@@ -49,6 +53,7 @@ async fn get_aggregator_status_message(
     let total_next_stakes_signers = epoch_service.total_next_stakes_signers()?;
     let total_cardano_spo = epoch_service.total_spo()?.unwrap_or_default();
     let total_cardano_stake = epoch_service.total_stake()?.unwrap_or_default();
+    let local_artifacts_disk_usage = local_artifacts_disk_usage.get().await;
 
     let message = AggregatorStatusMessage {
         epoch,
@@ -65,6 +70,7 @@ async fn get_aggregator_status_message(
         total_next_stakes_signers,
         total_cardano_spo,
         total_cardano_stake,
+        local_artifacts_disk_usage,
     };
 
     Ok(message)
@@ -76,8 +82,11 @@ mod handlers {
     use slog::{Logger, warn};
     use warp::http::StatusCode;
 
+    use std::sync::Arc;
+
     use crate::{
         dependency_injection::EpochServiceWrapper,
+        file_uploaders::LocalArtifactsDiskUsage,
         http_server::routes::{reply, status::get_aggregator_status_message},
     };
 
@@ -87,10 +96,15 @@ mod handlers {
         epoch_service: EpochServiceWrapper,
         cardano_node_version: String,
         cardano_network: String,
+        local_artifacts_disk_usage: Arc<LocalArtifactsDiskUsage>,
     ) -> Result<impl warp::Reply, Infallible> {
-        let aggregator_status_message =
-            get_aggregator_status_message(epoch_service, cardano_node_version, cardano_network)
-                .await;
+        let aggregator_status_message = get_aggregator_status_message(
+            epoch_service,
+            cardano_node_version,
+            cardano_network,
+            local_artifacts_disk_usage,
+        )
+        .await;
 
         match aggregator_status_message {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -115,6 +129,7 @@ mod tests {
     use mithril_api_spec::APISpec;
     use mithril_common::{
         entities::{Epoch, ProtocolParameters, Stake},
+        temp_dir_create,
         test_utils::{MithrilFixtureBuilder, double::Dummy, fake_data},
     };
 
@@ -137,6 +152,10 @@ mod tests {
         warp::any().and(routes(&state).with(cors))
     }
 
+    fn never_refreshed_disk_usage() -> Arc<LocalArtifactsDiskUsage> {
+        Arc::new(LocalArtifactsDiskUsage::new(vec![]))
+    }
+
     #[tokio::test]
     async fn status_route_ko_500() {
         let dependency_manager = initialize_dependencies!().await;
@@ -217,9 +236,14 @@ mod tests {
         .build();
         let epoch_service = Arc::new(RwLock::new(epoch_service));
 
-        let message = get_aggregator_status_message(epoch_service, String::new(), String::new())
-            .await
-            .unwrap();
+        let message = get_aggregator_status_message(
+            epoch_service,
+            String::new(),
+            String::new(),
+            never_refreshed_disk_usage(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(
             message.protocol_parameters,
@@ -242,9 +266,14 @@ mod tests {
         .build();
         let epoch_service = Arc::new(RwLock::new(epoch_service));
 
-        let message = get_aggregator_status_message(epoch_service, String::new(), String::new())
-            .await
-            .unwrap();
+        let message = get_aggregator_status_message(
+            epoch_service,
+            String::new(),
+            String::new(),
+            never_refreshed_disk_usage(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(message.total_cardano_spo, 0);
         assert_eq!(message.total_cardano_stake, 0);
@@ -262,9 +291,14 @@ mod tests {
         .build();
         let epoch_service = Arc::new(RwLock::new(epoch_service));
 
-        let message = get_aggregator_status_message(epoch_service, String::new(), String::new())
-            .await
-            .unwrap();
+        let message = get_aggregator_status_message(
+            epoch_service,
+            String::new(),
+            String::new(),
+            never_refreshed_disk_usage(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(message.total_signers, total_signers);
         assert_eq!(message.total_next_signers, total_next_signers);
@@ -288,9 +322,14 @@ mod tests {
         .build();
         let epoch_service = Arc::new(RwLock::new(epoch_service));
 
-        let message = get_aggregator_status_message(epoch_service, String::new(), String::new())
-            .await
-            .unwrap();
+        let message = get_aggregator_status_message(
+            epoch_service,
+            String::new(),
+            String::new(),
+            never_refreshed_disk_usage(),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(message.total_stakes_signers, total_stakes_signers);
         assert_eq!(message.total_next_stakes_signers, total_next_stakes_signers);
@@ -305,6 +344,7 @@ mod tests {
             epoch_service,
             "1.0.4".to_string(),
             "network".to_string(),
+            never_refreshed_disk_usage(),
         )
         .await
         .unwrap();
@@ -312,4 +352,42 @@ mod tests {
         assert_eq!(message.cardano_node_version, "1.0.4");
         assert_eq!(message.cardano_network, "network");
     }
+
+    #[tokio::test]
+    async fn local_artifacts_disk_usage_is_none_if_never_refreshed() {
+        let epoch_service = FakeEpochServiceBuilder::dummy(Epoch(3)).build();
+        let epoch_service = Arc::new(RwLock::new(epoch_service));
+
+        let message = get_aggregator_status_message(
+            epoch_service,
+            String::new(),
+            String::new(),
+            never_refreshed_disk_usage(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(message.local_artifacts_disk_usage, None);
+    }
+
+    #[tokio::test]
+    async fn serves_the_last_refreshed_local_artifacts_disk_usage() {
+        let temp_dir = temp_dir_create!();
+        std::fs::write(temp_dir.join("snapshot.tar.zst"), vec![0; 100]).unwrap();
+        let local_artifacts_disk_usage = Arc::new(LocalArtifactsDiskUsage::new(vec![temp_dir]));
+        local_artifacts_disk_usage.refresh().await.unwrap();
+        let epoch_service = FakeEpochServiceBuilder::dummy(Epoch(3)).build();
+        let epoch_service = Arc::new(RwLock::new(epoch_service));
+
+        let message = get_aggregator_status_message(
+            epoch_service,
+            String::new(),
+            String::new(),
+            local_artifacts_disk_usage,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(message.local_artifacts_disk_usage, Some(100));
+    }
 }
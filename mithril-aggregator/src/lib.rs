@@ -33,13 +33,13 @@ mod tools;
 
 pub use crate::artifact_builder::ArtifactBuilder;
 pub use crate::configuration::{
-    ConfigurationSource, DefaultConfiguration, ExecutionEnvironment, ServeCommandConfiguration,
-    SnapshotUploaderType, ZstandardCompressionParameters,
+    ConfigurationSource, DefaultConfiguration, ExecutionEnvironment, GzipCompressionParameters,
+    ServeCommandConfiguration, SnapshotUploaderType, ZstandardCompressionParameters,
 };
 pub use crate::multi_signer::{MultiSigner, MultiSignerImpl};
 pub use commands::{CommandType, MainOpts};
 pub use dependency_injection::ServeCommandDependenciesContainer;
-pub use file_uploaders::{DumbUploader, FileUploader};
+pub use file_uploaders::{DumbUploader, FileUploader, MirrorUploadPolicy, MirrorUploader};
 pub use message_adapters::FromRegisterSignerAdapter;
 pub use metrics::*;
 pub use runtime::{
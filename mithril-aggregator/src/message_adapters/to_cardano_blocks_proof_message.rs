@@ -0,0 +1,105 @@
+use mithril_common::{
+    StdResult,
+    entities::{BlockHash, CardanoBlocksSetProof, CardanoBlocksSnapshot},
+    messages::{CardanoBlocksProofsMessage, CardanoBlocksSetProofMessagePart},
+    signable_builder::SignedEntity,
+};
+
+/// Adapter to spawn [CardanoBlocksProofsMessage] from [CardanoBlocksSetProof] instances.
+pub struct ToCardanoBlocksProofsMessageAdapter;
+
+impl ToCardanoBlocksProofsMessageAdapter {
+    /// Turn an entity instance into message.
+    pub fn try_adapt(
+        signed_entity: SignedEntity<CardanoBlocksSnapshot>,
+        blocks_set_proofs: Vec<CardanoBlocksSetProof>,
+        block_hashes_to_certify: Vec<BlockHash>,
+    ) -> StdResult<CardanoBlocksProofsMessage> {
+        let blocks_hashes_not_certified =
+            compute_not_certified_blocks(&blocks_set_proofs, &block_hashes_to_certify);
+
+        Ok(CardanoBlocksProofsMessage::new(
+            &signed_entity.certificate_id,
+            try_adapt_set_proof_message(blocks_set_proofs)?,
+            blocks_hashes_not_certified,
+            signed_entity.artifact.block_number,
+        ))
+    }
+}
+
+fn compute_not_certified_blocks(
+    blocks_set_proofs: &[CardanoBlocksSetProof],
+    block_hashes_to_certify: &[BlockHash],
+) -> Vec<BlockHash> {
+    let blocks_hashes_certified = blocks_set_proofs
+        .iter()
+        .flat_map(|proof| proof.blocks().iter().map(|block| block.block_hash.clone()))
+        .collect::<Vec<_>>();
+
+    block_hashes_to_certify
+        .iter()
+        .filter(|hash| !blocks_hashes_certified.contains(hash))
+        .cloned()
+        .collect()
+}
+
+fn try_adapt_set_proof_message(
+    blocks_set_proofs: Vec<CardanoBlocksSetProof>,
+) -> StdResult<Vec<CardanoBlocksSetProofMessagePart>> {
+    let mut messages = vec![];
+
+    for set_proof in blocks_set_proofs {
+        messages.push(set_proof.try_into()?);
+    }
+
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeStoreInMemory;
+    use mithril_common::entities::{BlockNumber, CardanoBlock, SlotNumber};
+    use mithril_common::test_utils::double::Dummy;
+
+    use super::*;
+
+    #[test]
+    fn try_adapt_cardano_blocks_proof_to_message() {
+        let blocks = (0..7)
+            .map(|i| {
+                CardanoBlock::new(
+                    format!("block-hash-{i}"),
+                    BlockNumber(10 * i),
+                    SlotNumber(i),
+                )
+            })
+            .collect::<Vec<_>>();
+        let block_hashes = blocks.iter().map(|b| b.block_hash.clone()).collect::<Vec<_>>();
+        let blocks_certified = &blocks[0..5];
+        let block_hashes_non_certified = &block_hashes[5..];
+
+        let blocks_set_proofs = vec![
+            CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(blocks_certified).unwrap(),
+        ];
+
+        let signed_entity = SignedEntity::<CardanoBlocksSnapshot>::dummy();
+
+        let message = ToCardanoBlocksProofsMessageAdapter::try_adapt(
+            signed_entity.clone(),
+            blocks_set_proofs.clone(),
+            block_hashes.clone(),
+        )
+        .unwrap();
+
+        let blocks_set_proof_message_part =
+            blocks_set_proofs.into_iter().map(|p| p.try_into().unwrap()).collect();
+
+        let expected_message = CardanoBlocksProofsMessage::new(
+            &signed_entity.certificate_id,
+            blocks_set_proof_message_part,
+            block_hashes_non_certified.to_vec(),
+            signed_entity.artifact.block_number,
+        );
+        assert_eq!(expected_message, message);
+    }
+}
@@ -0,0 +1,100 @@
+use mithril_common::{
+    entities::CardanoTransactionsSnapshot,
+    messages::{
+        BlockRangeRootMessagePart, CardanoTransactionMessagePart,
+        CardanoTransactionsBlockRangeRootsMessage,
+    },
+    signable_builder::SignedEntity,
+};
+
+use crate::services::CardanoTransactionsBlockRangeRoots;
+
+/// Adapter to spawn [CardanoTransactionsBlockRangeRootsMessage] from [CardanoTransactionsBlockRangeRoots] instances.
+pub struct ToCardanoTransactionsBlockRangeRootsMessageAdapter;
+
+impl ToCardanoTransactionsBlockRangeRootsMessageAdapter {
+    /// Turn an entity instance into message.
+    pub fn adapt(
+        signed_entity: SignedEntity<CardanoTransactionsSnapshot>,
+        block_range_roots: CardanoTransactionsBlockRangeRoots,
+    ) -> CardanoTransactionsBlockRangeRootsMessage {
+        CardanoTransactionsBlockRangeRootsMessage {
+            snapshot_hash: signed_entity.artifact.hash,
+            certificate_hash: signed_entity.certificate_id,
+            block_number: signed_entity.artifact.block_number,
+            block_range_roots: block_range_roots
+                .block_range_roots
+                .into_iter()
+                .map(|(block_range, merkle_root)| BlockRangeRootMessagePart {
+                    start: block_range.start,
+                    end: block_range.end,
+                    merkle_root: merkle_root.to_hex(),
+                })
+                .collect(),
+            next_page_from: block_range_roots.next_page_from,
+            last_block_range_transactions: block_range_roots
+                .last_block_range_transactions
+                .into_iter()
+                .map(|transaction| CardanoTransactionMessagePart {
+                    transaction_hash: transaction.transaction_hash,
+                    block_number: transaction.block_number,
+                    slot_number: transaction.slot_number,
+                    block_hash: transaction.block_hash,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeNode;
+    use mithril_common::entities::{BlockNumber, BlockRange, CardanoTransaction, SlotNumber};
+    use mithril_common::test_utils::double::Dummy;
+
+    use super::*;
+
+    #[test]
+    fn adapt_block_range_roots_to_message() {
+        let signed_entity = SignedEntity::<CardanoTransactionsSnapshot>::dummy();
+        let block_range_roots = CardanoTransactionsBlockRangeRoots {
+            block_range_roots: vec![(
+                BlockRange::from_block_number(BlockNumber(0)),
+                MKTreeNode::from_hex("abcd").unwrap(),
+            )],
+            next_page_from: None,
+            last_block_range_transactions: vec![CardanoTransaction::new(
+                "tx-hash-1",
+                BlockNumber(1),
+                SlotNumber(10),
+                "block-hash-1",
+            )],
+        };
+
+        let message = ToCardanoTransactionsBlockRangeRootsMessageAdapter::adapt(
+            signed_entity.clone(),
+            block_range_roots,
+        );
+
+        assert_eq!(
+            CardanoTransactionsBlockRangeRootsMessage {
+                snapshot_hash: signed_entity.artifact.hash,
+                certificate_hash: signed_entity.certificate_id,
+                block_number: signed_entity.artifact.block_number,
+                block_range_roots: vec![BlockRangeRootMessagePart {
+                    start: BlockNumber(0),
+                    end: BlockRange::LENGTH,
+                    merkle_root: "abcd".to_string(),
+                }],
+                next_page_from: None,
+                last_block_range_transactions: vec![CardanoTransactionMessagePart {
+                    transaction_hash: "tx-hash-1".to_string(),
+                    block_number: BlockNumber(1),
+                    slot_number: SlotNumber(10),
+                    block_hash: "block-hash-1".to_string(),
+                }],
+            },
+            message
+        );
+    }
+}
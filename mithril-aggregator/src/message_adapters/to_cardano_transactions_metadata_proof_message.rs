@@ -0,0 +1,122 @@
+use mithril_common::{
+    StdResult,
+    entities::{
+        CardanoTransactionsMetadataSetProof, CardanoTransactionsMetadataSnapshot, TransactionHash,
+    },
+    messages::{
+        CardanoTransactionsMetadataProofsMessage, CardanoTransactionsMetadataSetProofMessagePart,
+    },
+    signable_builder::SignedEntity,
+};
+
+/// Adapter to spawn [CardanoTransactionsMetadataProofsMessage] from
+/// [CardanoTransactionsMetadataSetProof] instances.
+pub struct ToCardanoTransactionsMetadataProofsMessageAdapter;
+
+impl ToCardanoTransactionsMetadataProofsMessageAdapter {
+    /// Turn an entity instance into message.
+    pub fn try_adapt(
+        signed_entity: SignedEntity<CardanoTransactionsMetadataSnapshot>,
+        transactions_metadata_set_proofs: Vec<CardanoTransactionsMetadataSetProof>,
+        transaction_hashes_to_certify: Vec<TransactionHash>,
+    ) -> StdResult<CardanoTransactionsMetadataProofsMessage> {
+        let transactions_hashes_not_certified = compute_not_certified_transactions(
+            &transactions_metadata_set_proofs,
+            &transaction_hashes_to_certify,
+        );
+
+        Ok(CardanoTransactionsMetadataProofsMessage::new(
+            &signed_entity.certificate_id,
+            try_adapt_set_proof_message(transactions_metadata_set_proofs)?,
+            transactions_hashes_not_certified,
+            signed_entity.artifact.block_number,
+        ))
+    }
+}
+
+fn compute_not_certified_transactions(
+    transactions_metadata_set_proofs: &[CardanoTransactionsMetadataSetProof],
+    transaction_hashes_to_certify: &[TransactionHash],
+) -> Vec<TransactionHash> {
+    let transactions_hashes_certified = transactions_metadata_set_proofs
+        .iter()
+        .flat_map(|proof| proof.metadata().iter().map(|m| m.transaction_hash.clone()))
+        .collect::<Vec<_>>();
+
+    transaction_hashes_to_certify
+        .iter()
+        .filter(|hash| !transactions_hashes_certified.contains(hash))
+        .cloned()
+        .collect()
+}
+
+fn try_adapt_set_proof_message(
+    transactions_metadata_set_proofs: Vec<CardanoTransactionsMetadataSetProof>,
+) -> StdResult<Vec<CardanoTransactionsMetadataSetProofMessagePart>> {
+    let mut messages = vec![];
+
+    for set_proof in transactions_metadata_set_proofs {
+        messages.push(set_proof.try_into()?);
+    }
+
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeStoreInMemory;
+    use mithril_common::entities::{BlockNumber, CardanoTransactionMetadata, SlotNumber};
+    use mithril_common::test_utils::double::Dummy;
+
+    use super::*;
+
+    #[test]
+    fn try_adapt_cardano_transactions_metadata_proof_to_message() {
+        let transactions_metadata = (0..7)
+            .map(|i| {
+                CardanoTransactionMetadata::new(
+                    format!("tx-hash-{i}"),
+                    674,
+                    format!("metadata-hash-{i}"),
+                    BlockNumber(10 * i),
+                    SlotNumber(i),
+                    format!("block-hash-{i}"),
+                )
+            })
+            .collect::<Vec<_>>();
+        let transaction_hashes = transactions_metadata
+            .iter()
+            .map(|m| m.transaction_hash.clone())
+            .collect::<Vec<_>>();
+        let metadata_certified = &transactions_metadata[0..5];
+        let transaction_hashes_non_certified = &transaction_hashes[5..];
+        let transactions_metadata_set_proofs = vec![
+            CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+                metadata_certified,
+            )
+            .unwrap(),
+        ];
+
+        let signed_entity = SignedEntity::<CardanoTransactionsMetadataSnapshot>::dummy();
+
+        let message = ToCardanoTransactionsMetadataProofsMessageAdapter::try_adapt(
+            signed_entity.clone(),
+            transactions_metadata_set_proofs.clone(),
+            transaction_hashes.clone(),
+        )
+        .unwrap();
+
+        let transactions_metadata_set_proof_message_part = transactions_metadata_set_proofs
+            .into_iter()
+            .map(|p| p.try_into().unwrap())
+            .collect();
+
+        let expected_message = CardanoTransactionsMetadataProofsMessage::new(
+            &signed_entity.certificate_id,
+            transactions_metadata_set_proof_message_part,
+            transaction_hashes_non_certified.to_vec(),
+            signed_entity.artifact.block_number,
+        );
+        assert_eq!(expected_message, message);
+    }
+}
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 
+use mithril_cardano_node_chain::chain_observer::ChainObserverCacheMetricsRecorder;
+use mithril_cardano_node_chain::chain_scanner::{ChainRollbackMetricsRecorder, RollbackDepth};
 use mithril_metric::{MetricCounterWithLabels, MetricsServiceExporter, build_metrics_service};
 
-use mithril_metric::metric::{MetricCollector, MetricCounter};
+use mithril_metric::metric::{CounterValue, MetricCollector, MetricCounter};
 use prometheus::proto::{LabelPair, Metric, MetricFamily};
 
 // Those are three differents dimensions, they use the same value to simplify usage in Grafana
@@ -10,6 +12,7 @@ static CLIENT_ORIGIN_TAG_LABEL: &str = "origin_tag";
 static SIGNER_REGISTRATION_ORIGIN_TAG_LABEL: &str = "origin_tag";
 static SIGNER_SIGNATURE_ORIGIN_TAG_LABEL: &str = "origin_tag";
 static CLIENT_TYPE_LABEL: &str = "client_type";
+static CHAIN_OBSERVER_QUERY_LABEL: &str = "query";
 
 build_metrics_service!(
     MetricsService,
@@ -79,6 +82,26 @@ build_metrics_service!(
         "Number of Cardano transaction hashes requested for proof since startup on a Mithril aggregator node",
         &[CLIENT_ORIGIN_TAG_LABEL, CLIENT_TYPE_LABEL]
     ),
+    artifact_detail_cardano_blocks_total_served_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_artifact_detail_cardano_blocks_total_served_since_startup",
+        "Number of Cardano blocks artifact details served since startup on a Mithril aggregator node",
+        &[CLIENT_ORIGIN_TAG_LABEL, CLIENT_TYPE_LABEL]
+    ),
+    proof_cardano_blocks_total_proofs_served_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_proof_cardano_blocks_total_proofs_served_since_startup",
+        "Number of Cardano blocks proofs served since startup on a Mithril aggregator node",
+        &[CLIENT_ORIGIN_TAG_LABEL, CLIENT_TYPE_LABEL]
+    ),
+    artifact_detail_cardano_transactions_metadata_total_served_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_artifact_detail_cardano_transactions_metadata_total_served_since_startup",
+        "Number of Cardano transactions metadata artifact details served since startup on a Mithril aggregator node",
+        &[CLIENT_ORIGIN_TAG_LABEL, CLIENT_TYPE_LABEL]
+    ),
+    proof_cardano_transactions_metadata_total_proofs_served_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_proof_cardano_transactions_metadata_total_proofs_served_since_startup",
+        "Number of Cardano transactions metadata proofs served since startup on a Mithril aggregator node",
+        &[CLIENT_ORIGIN_TAG_LABEL, CLIENT_TYPE_LABEL]
+    ),
     signer_registration_total_received_since_startup:MetricCounterWithLabels(
         "mithril_aggregator_signer_registration_total_received_since_startup",
         "Number of signer registrations received since startup on a Mithril aggregator node",
@@ -113,6 +136,14 @@ build_metrics_service!(
         "mithril_aggregator_artifact_cardano_transaction_total_produced_since_startup",
         "Number of Cardano transaction artifacts produced since startup on a Mithril aggregator node"
     ),
+    artifact_cardano_blocks_total_produced_since_startup:MetricCounter(
+        "mithril_aggregator_artifact_cardano_blocks_total_produced_since_startup",
+        "Number of Cardano blocks artifacts produced since startup on a Mithril aggregator node"
+    ),
+    artifact_cardano_transactions_metadata_total_produced_since_startup:MetricCounter(
+        "mithril_aggregator_artifact_cardano_transactions_metadata_total_produced_since_startup",
+        "Number of Cardano transactions metadata artifacts produced since startup on a Mithril aggregator node"
+    ),
     runtime_cycle_success_since_startup:MetricCounter(
         "mithril_aggregator_runtime_cycle_success_since_startup",
         "Number of successful runtime cycles since startup on a Mithril aggregator"
@@ -120,6 +151,28 @@ build_metrics_service!(
     runtime_cycle_total_since_startup:MetricCounter(
         "mithril_aggregator_runtime_cycle_total_since_startup",
         "Number of runtime cycles since startup on a Mithril aggregator"
+    ),
+    chain_observer_cache_hit_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_chain_observer_cache_hit_since_startup",
+        "Number of chain observer queries served from the cache since startup on a Mithril aggregator node",
+        &[CHAIN_OBSERVER_QUERY_LABEL]
+    ),
+    chain_observer_cache_miss_since_startup:MetricCounterWithLabels(
+        "mithril_aggregator_chain_observer_cache_miss_since_startup",
+        "Number of chain observer queries not served from the cache since startup on a Mithril aggregator node",
+        &[CHAIN_OBSERVER_QUERY_LABEL]
+    ),
+    chain_rollback_since_startup:MetricCounter(
+        "mithril_aggregator_chain_rollback_since_startup",
+        "Number of chain rollbacks received while importing blocks since startup on a Mithril aggregator node"
+    ),
+    chain_rollback_exceeding_security_parameter_since_startup:MetricCounter(
+        "mithril_aggregator_chain_rollback_exceeding_security_parameter_since_startup",
+        "Number of chain rollbacks deeper than the security parameter since startup on a Mithril aggregator node"
+    ),
+    chain_rollback_blocks_since_startup:MetricCounter(
+        "mithril_aggregator_chain_rollback_blocks_since_startup",
+        "Number of blocks rolled back while importing blocks since startup on a Mithril aggregator node"
     )
 
 );
@@ -160,6 +213,28 @@ impl MetricsService {
     }
 }
 
+impl ChainObserverCacheMetricsRecorder for MetricsService {
+    fn record_cache_hit(&self, query: &str) {
+        self.get_chain_observer_cache_hit_since_startup().increment(&[query]);
+    }
+
+    fn record_cache_miss(&self, query: &str) {
+        self.get_chain_observer_cache_miss_since_startup().increment(&[query]);
+    }
+}
+
+impl ChainRollbackMetricsRecorder for MetricsService {
+    fn record_rollback(&self, depth: RollbackDepth, exceeds_security_parameter: bool) {
+        self.get_chain_rollback_since_startup().increment();
+        if exceeds_security_parameter {
+            self.get_chain_rollback_exceeding_security_parameter_since_startup()
+                .increment();
+        }
+        self.get_chain_rollback_blocks_since_startup()
+            .increment_by(depth.lower_bound().try_into().unwrap_or(CounterValue::MAX));
+    }
+}
+
 type LabelName = String;
 type LabelValue = String;
 
@@ -0,0 +1,394 @@
+use async_trait::async_trait;
+use rayon::prelude::*;
+use slog::{Logger, debug, info};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use mithril_common::{
+    StdResult,
+    crypto_helper::{MKTree, MKTreeNode, MKTreeStorer},
+    entities::{BlockHash, BlockNumber, BlockRange, CardanoBlock, CardanoBlocksSetProof},
+    logging::LoggerExtensions,
+    signable_builder::BlockRangeRootRetriever,
+};
+use mithril_resource_pool::{ResourcePool, ResourcePoolItem};
+
+use super::prover::BlockRangeRootsMKMap;
+
+/// Prover service in charge of producing cryptographic proofs for Cardano blocks
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CardanoBlocksProverService: Sync + Send {
+    /// Compute the cryptographic proofs for the given blocks
+    ///
+    /// The proofs are computed against the Cardano blocks snapshot certified at the `up_to`
+    /// block number.
+    async fn compute_blocks_proofs(
+        &self,
+        up_to: BlockNumber,
+        block_hashes: &[BlockHash],
+    ) -> StdResult<Vec<CardanoBlocksSetProof>>;
+
+    /// Compute the cache
+    async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()>;
+}
+
+/// Cardano blocks retriever
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BlocksRetriever: Sync + Send {
+    /// Get a list of blocks by hashes using chronological order
+    async fn get_by_hashes(
+        &self,
+        hashes: Vec<BlockHash>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoBlock>>;
+
+    /// Get by block ranges
+    async fn get_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<CardanoBlock>>;
+}
+
+/// Mithril Cardano blocks prover
+pub struct MithrilCardanoBlocksProverService<S: MKTreeStorer> {
+    block_retriever: Arc<dyn BlocksRetriever>,
+    block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+    mk_map_pool: ResourcePool<BlockRangeRootsMKMap<S>>,
+    /// Discriminant of the Merkle map pool and block number of its cached resources
+    mk_map_pool_beacon: RwLock<Option<(u64, BlockNumber)>>,
+    logger: Logger,
+}
+
+impl<S: MKTreeStorer> MithrilCardanoBlocksProverService<S> {
+    /// Create a new Mithril Cardano blocks prover
+    pub fn new(
+        block_retriever: Arc<dyn BlocksRetriever>,
+        block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+        mk_map_pool_size: usize,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            block_retriever,
+            block_range_root_retriever,
+            mk_map_pool: ResourcePool::new(mk_map_pool_size, vec![]),
+            mk_map_pool_beacon: RwLock::new(None),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Acquire a Merkle map from the pool if its cached resources were computed for the given
+    /// block number
+    fn acquire_cached_mk_map(
+        &self,
+        up_to: BlockNumber,
+    ) -> StdResult<Option<ResourcePoolItem<'_, BlockRangeRootsMKMap<S>>>> {
+        let mk_map_pool_beacon = *self.mk_map_pool_beacon.read().unwrap();
+        match mk_map_pool_beacon {
+            Some((discriminant, block_number)) if block_number == up_to => {
+                let acquire_timeout = Duration::from_millis(1000);
+                let mk_map = self.mk_map_pool.acquire_resource(acquire_timeout)?;
+                // The cache may have been computed again since the beacon was read
+                Ok((mk_map.discriminant() == discriminant).then_some(mk_map))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Get all the blocks of the block ranges that contain the given blocks
+    async fn get_all_blocks_for_block_ranges_of(
+        &self,
+        blocks: &[CardanoBlock],
+    ) -> StdResult<BTreeMap<BlockRange, Vec<CardanoBlock>>> {
+        let block_ranges = blocks
+            .iter()
+            .map(|b| BlockRange::from_block_number(b.block_number))
+            .collect::<BTreeSet<_>>();
+        let mut block_ranges_map: BTreeMap<BlockRange, Vec<CardanoBlock>> = BTreeMap::new();
+        for block in self
+            .block_retriever
+            .get_by_block_ranges(block_ranges.into_iter().collect())
+            .await?
+        {
+            block_ranges_map
+                .entry(BlockRange::from_block_number(block.block_number))
+                .or_default()
+                .push(block);
+        }
+
+        Ok(block_ranges_map)
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> CardanoBlocksProverService for MithrilCardanoBlocksProverService<S> {
+    async fn compute_blocks_proofs(
+        &self,
+        up_to: BlockNumber,
+        block_hashes: &[BlockHash],
+    ) -> StdResult<Vec<CardanoBlocksSetProof>> {
+        // 1 - Retrieve the blocks to prove and all the blocks of their block ranges
+        let blocks_to_prove = self
+            .block_retriever
+            .get_by_hashes(block_hashes.to_vec(), up_to)
+            .await?;
+        if blocks_to_prove.is_empty() {
+            return Ok(vec![]);
+        }
+        let block_ranges_blocks = self.get_all_blocks_for_block_ranges_of(&blocks_to_prove).await?;
+
+        // 2 - Compute the block range roots Merkle map, from the cache if it was computed for the
+        // requested block number or from the stored block range roots otherwise
+        let mut mk_map_pool_item = self.acquire_cached_mk_map(up_to)?;
+        let mut computed_mk_map = None;
+        let mk_map = match mk_map_pool_item.as_mut() {
+            Some(mk_map) => &mut **mk_map,
+            None => {
+                debug!(
+                    self.logger, "Computing the Merkle map of the Cardano blocks";
+                    "up_to_block_number" => *up_to,
+                );
+                computed_mk_map.insert(
+                    self.block_range_root_retriever
+                        .compute_merkle_map_from_block_range_roots(up_to)
+                        .await?,
+                )
+            }
+        };
+
+        // 3 - Enrich the Merkle map with the block ranges Merkle trees
+        for (block_range, blocks) in block_ranges_blocks {
+            let mk_tree = MKTree::<S>::new(&blocks)?;
+            mk_map.replace(block_range, mk_tree.into())?;
+        }
+
+        // 4 - Compute the proof for all blocks
+        let leaves_to_prove: Vec<MKTreeNode> = blocks_to_prove.iter().map(|b| b.into()).collect();
+        let mk_proof = mk_map.compute_proof(&leaves_to_prove)?;
+        if let Some(mk_map) = mk_map_pool_item {
+            self.mk_map_pool.give_back_resource_pool_item(mk_map)?;
+        }
+
+        Ok(vec![CardanoBlocksSetProof::new(blocks_to_prove, mk_proof)])
+    }
+
+    async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()> {
+        let pool_size = self.mk_map_pool.size();
+        info!(
+            self.logger, "Starts computing the Merkle map pool resource of size {pool_size}";
+            "up_to_block_number" => *up_to,
+        );
+        let mk_map_cache = self
+            .block_range_root_retriever
+            .compute_merkle_map_from_block_range_roots(up_to)
+            .await?;
+        let mk_maps_new = (1..=pool_size)
+            .into_par_iter()
+            .map(|i| {
+                debug!(
+                    self.logger,
+                    "Computing the Merkle map pool resource {i}/{pool_size}"
+                );
+                mk_map_cache.clone()
+            })
+            .collect::<Vec<BlockRangeRootsMKMap<S>>>();
+        debug!(self.logger, "Draining the Merkle map pool");
+        *self.mk_map_pool_beacon.write().unwrap() = None;
+        let discriminant_new = self.mk_map_pool.discriminant()? + 1;
+        self.mk_map_pool.set_discriminant(discriminant_new)?;
+        self.mk_map_pool.clear();
+        debug!(
+            self.logger,
+            "Giving back new resources to the Merkle map pool"
+        );
+        mk_maps_new
+            .into_iter()
+            .map(|mk_map| self.mk_map_pool.give_back_resource(mk_map, discriminant_new))
+            .collect::<StdResult<Vec<_>>>()?;
+        *self.mk_map_pool_beacon.write().unwrap() = Some((discriminant_new, up_to));
+        info!(
+            self.logger,
+            "Completed computing the Merkle map pool resource of size {pool_size}"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::{MKMap, MKMapNode, MKTreeStoreInMemory};
+    use mithril_common::entities::SlotNumber;
+    use mockall::{TimesRange, mock};
+
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub BlockRangeRootRetrieverImpl<S: MKTreeStorer> { }
+
+        #[async_trait]
+        impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for BlockRangeRootRetrieverImpl<S> {
+            async fn retrieve_block_range_roots<'a>(
+                &'a self,
+                up_to_beacon: BlockNumber,
+            ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + 'a>>;
+
+            async fn compute_merkle_map_from_block_range_roots(
+                &self,
+                up_to_beacon: BlockNumber,
+            ) -> StdResult<MKMap<BlockRange, MKMapNode<BlockRange, S>, S>>;
+        }
+    }
+
+    fn build_blocks(block_numbers: std::ops::Range<u64>) -> Vec<CardanoBlock> {
+        block_numbers
+            .map(|number| {
+                CardanoBlock::new(
+                    format!("block-hash-{number}"),
+                    BlockNumber(number),
+                    SlotNumber(number * 10),
+                )
+            })
+            .collect()
+    }
+
+    fn compute_mk_map(all_blocks: &[CardanoBlock]) -> BlockRangeRootsMKMap<MKTreeStoreInMemory> {
+        let mut blocks_by_range: BTreeMap<BlockRange, Vec<CardanoBlock>> = BTreeMap::new();
+        for block in all_blocks {
+            blocks_by_range
+                .entry(BlockRange::from_block_number(block.block_number))
+                .or_default()
+                .push(block.clone());
+        }
+        MKMap::new_from_iter(blocks_by_range.into_iter().map(|(range, blocks)| {
+            (
+                range,
+                MKMapNode::TreeNode(
+                    MKTree::<MKTreeStoreInMemory>::new(&blocks)
+                        .unwrap()
+                        .compute_root()
+                        .unwrap(),
+                ),
+            )
+        }))
+        .unwrap()
+    }
+
+    fn build_prover(
+        blocks_to_prove: Vec<CardanoBlock>,
+        all_blocks: Vec<CardanoBlock>,
+    ) -> MithrilCardanoBlocksProverService<MKTreeStoreInMemory> {
+        build_prover_with_mk_map_computations(blocks_to_prove, all_blocks, 0..=1)
+    }
+
+    fn build_prover_with_mk_map_computations(
+        blocks_to_prove: Vec<CardanoBlock>,
+        all_blocks: Vec<CardanoBlock>,
+        mk_map_computations: impl Into<TimesRange>,
+    ) -> MithrilCardanoBlocksProverService<MKTreeStoreInMemory> {
+        let mut block_retriever = MockBlocksRetriever::new();
+        let block_range_blocks: Vec<CardanoBlock> = all_blocks
+            .iter()
+            .filter(|block| {
+                blocks_to_prove.iter().any(|b| {
+                    BlockRange::from_block_number(b.block_number)
+                        == BlockRange::from_block_number(block.block_number)
+                })
+            })
+            .cloned()
+            .collect();
+        block_retriever
+            .expect_get_by_hashes()
+            .returning(move |_, _| Ok(blocks_to_prove.clone()));
+        block_retriever
+            .expect_get_by_block_ranges()
+            .returning(move |_| Ok(block_range_blocks.clone()));
+
+        let mut block_range_root_retriever = MockBlockRangeRootRetrieverImpl::new();
+        block_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .times(mk_map_computations)
+            .returning(move |_| Ok(compute_mk_map(&all_blocks)));
+        let mk_map_pool_size = 1;
+
+        MithrilCardanoBlocksProverService::new(
+            Arc::new(block_retriever),
+            Arc::new(block_range_root_retriever),
+            mk_map_pool_size,
+            TestLogger::stdout(),
+        )
+    }
+
+    #[tokio::test]
+    async fn compute_proof_for_known_blocks() {
+        let all_blocks = build_blocks(0..60);
+        let blocks_to_prove = vec![all_blocks[2].clone(), all_blocks[33].clone()];
+        let prover = build_prover(blocks_to_prove.clone(), all_blocks);
+
+        let blocks_set_proofs = prover
+            .compute_blocks_proofs(
+                BlockNumber(60),
+                &[
+                    "block-hash-2".to_string(),
+                    "block-hash-33".to_string(),
+                    "block-hash-unknown".to_string(),
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, blocks_set_proofs.len());
+        assert_eq!(blocks_to_prove, blocks_set_proofs[0].blocks());
+        blocks_set_proofs[0].verify().unwrap();
+    }
+
+    #[tokio::test]
+    async fn compute_proofs_from_the_cache_computed_for_the_requested_block_number() {
+        let all_blocks = build_blocks(0..60);
+        let blocks_to_prove = vec![all_blocks[2].clone(), all_blocks[33].clone()];
+        let prover = build_prover_with_mk_map_computations(blocks_to_prove.clone(), all_blocks, 1);
+        prover.compute_cache(BlockNumber(60)).await.unwrap();
+
+        for _ in 0..2 {
+            let blocks_set_proofs = prover
+                .compute_blocks_proofs(
+                    BlockNumber(60),
+                    &["block-hash-2".to_string(), "block-hash-33".to_string()],
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(1, blocks_set_proofs.len());
+            assert_eq!(blocks_to_prove, blocks_set_proofs[0].blocks());
+            blocks_set_proofs[0].verify().unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn compute_proof_fails_if_a_block_is_not_in_the_merkle_map() {
+        let all_blocks = build_blocks(0..60);
+        let tampered_block = CardanoBlock::new("block-hash-2", BlockNumber(2), SlotNumber(999));
+        let prover = build_prover(vec![tampered_block], all_blocks);
+
+        prover
+            .compute_blocks_proofs(BlockNumber(60), &["block-hash-2".to_string()])
+            .await
+            .expect_err("compute_blocks_proofs should fail");
+    }
+
+    #[tokio::test]
+    async fn compute_no_proof_if_no_block_is_known() {
+        let prover = build_prover(vec![], build_blocks(0..30));
+
+        let blocks_set_proofs = prover
+            .compute_blocks_proofs(BlockNumber(30), &["block-hash-unknown".to_string()])
+            .await
+            .unwrap();
+
+        assert!(blocks_set_proofs.is_empty());
+    }
+}
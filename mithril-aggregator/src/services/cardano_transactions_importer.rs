@@ -10,13 +10,18 @@ use tokio::{runtime::Handle, sync::Mutex, task};
 use mithril_cardano_node_chain::chain_scanner::{BlockScanner, ChainScannedBlocks};
 use mithril_cardano_node_chain::entities::RawCardanoPoint;
 use mithril_common::StdResult;
-use mithril_common::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
+use mithril_common::crypto_helper::MKTreeNode;
 use mithril_common::entities::{
-    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber,
+    BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionsLeafFormat, ChainPoint,
+    SlotNumber,
 };
 use mithril_common::logging::LoggerExtensions;
 use mithril_common::signable_builder::TransactionsImporter;
 
+/// Maximum number of block ranges whose Merkle roots are computed in parallel, bounding the
+/// number of transactions held in memory while computing them
+const BLOCK_RANGE_ROOTS_BATCH_SIZE: usize = 100;
+
 /// Cardano transactions store
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
@@ -58,6 +63,7 @@ pub struct CardanoTransactionsImporter {
     block_scanner: Arc<dyn BlockScanner>,
     transaction_store: Arc<dyn TransactionStore>,
     last_polled_point: Arc<Mutex<Option<RawCardanoPoint>>>,
+    leaf_format: CardanoTransactionsLeafFormat,
     logger: Logger,
 }
 
@@ -72,10 +78,17 @@ impl CardanoTransactionsImporter {
             block_scanner,
             transaction_store,
             last_polled_point: Arc::new(Mutex::new(None)),
+            leaf_format: CardanoTransactionsLeafFormat::default(),
             logger: logger.new_with_component_name::<Self>(),
         }
     }
 
+    /// Set the format of the leaves of the block ranges Merkle trees
+    pub fn with_leaf_format(mut self, leaf_format: CardanoTransactionsLeafFormat) -> Self {
+        self.leaf_format = leaf_format;
+        self
+    }
+
     async fn start_point(
         &self,
         highest_stored_chain_point: &Option<ChainPoint>,
@@ -134,7 +147,11 @@ impl CardanoTransactionsImporter {
 
                     self.transaction_store.store_transactions(parsed_transactions).await?;
                 }
-                ChainScannedBlocks::RollBackward(slot_number) => {
+                ChainScannedBlocks::RollBackward { slot_number, depth } => {
+                    debug!(
+                        self.logger, "Rolling back Cardano transactions";
+                        "slot_number" => ?slot_number, "depth" => ?depth,
+                    );
                     self.transaction_store
                         .remove_rolled_back_transactions_and_block_range(slot_number)
                         .await?;
@@ -169,7 +186,7 @@ impl CardanoTransactionsImporter {
             "start_block" => *block_ranges.start(), "end_block" => *block_ranges.end(),
         );
 
-        let mut block_ranges_with_merkle_root: Vec<(BlockRange, MKTreeNode)> = vec![];
+        let mut block_ranges_transactions: Vec<(BlockRange, Vec<CardanoTransaction>)> = vec![];
         for block_range in block_ranges {
             let transactions = self
                 .transaction_store
@@ -180,18 +197,29 @@ impl CardanoTransactionsImporter {
                 continue;
             }
 
-            let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&transactions)?.compute_root()?;
-            block_ranges_with_merkle_root.push((block_range, merkle_root));
+            block_ranges_transactions.push((block_range, transactions));
 
-            if block_ranges_with_merkle_root.len() >= 100 {
-                let block_ranges_with_merkle_root_save =
-                    mem::take(&mut block_ranges_with_merkle_root);
-                self.transaction_store
-                    .store_block_range_roots(block_ranges_with_merkle_root_save)
+            if block_ranges_transactions.len() >= BLOCK_RANGE_ROOTS_BATCH_SIZE {
+                let block_ranges_transactions_batch = mem::take(&mut block_ranges_transactions);
+                self.compute_and_store_block_range_roots(block_ranges_transactions_batch)
                     .await?;
             }
         }
 
+        self.compute_and_store_block_range_roots(block_ranges_transactions)
+            .await
+    }
+
+    /// Compute in parallel the Merkle roots of a batch of block ranges, then store them in the
+    /// order of their block ranges.
+    async fn compute_and_store_block_range_roots(
+        &self,
+        block_ranges_transactions: Vec<(BlockRange, Vec<CardanoTransaction>)>,
+    ) -> StdResult<()> {
+        let block_ranges_with_merkle_root = self
+            .leaf_format
+            .compute_block_range_roots(block_ranges_transactions)?;
+
         self.transaction_store
             .store_block_range_roots(block_ranges_with_merkle_root)
             .await
@@ -224,7 +252,7 @@ mod tests {
     use mithril_cardano_node_chain::chain_scanner::BlockStreamer;
     use mithril_cardano_node_chain::entities::ScannedBlock;
     use mithril_cardano_node_chain::test::double::{DumbBlockScanner, DumbBlockStreamer};
-    use mithril_common::crypto_helper::MKTree;
+    use mithril_common::crypto_helper::{MKTree, MKTreeStoreInMemory};
     use mithril_common::entities::{BlockNumber, BlockRangesSequence};
     use mithril_persistence::database::repository::CardanoTransactionRepository;
     use mithril_persistence::sqlite::SqliteConnectionPool;
@@ -737,6 +765,49 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn compute_block_range_merkle_root_with_block_inclusion_leaves() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        )));
+        let up_to_block_number = BlockRange::LENGTH;
+        let blocks = build_blocks(BlockNumber(0), up_to_block_number);
+        let transactions = into_transactions(&blocks);
+        let block_inclusion_leaves: Vec<MKTreeNode> = transactions
+            .iter()
+            .map(|transaction| {
+                CardanoTransactionsLeafFormat::BlockInclusion.compute_leaf(transaction)
+            })
+            .collect();
+        let expected_merkle_root = MKTree::<MKTreeStoreInMemory>::new(&block_inclusion_leaves)
+            .unwrap()
+            .compute_root()
+            .unwrap();
+        repository.store_transactions(transactions).await.unwrap();
+
+        let importer = CardanoTransactionsImporter::new_for_test(
+            Arc::new(MockBlockScannerImpl::new()),
+            repository.clone(),
+        )
+        .with_leaf_format(CardanoTransactionsLeafFormat::BlockInclusion);
+
+        importer
+            .import_block_ranges(up_to_block_number)
+            .await
+            .expect("Transactions Importer should succeed");
+
+        let block_range_roots = repository.get_all_block_range_root().unwrap();
+        assert_eq!(
+            vec![(
+                BlockRange::from_block_number(BlockNumber(0)),
+                expected_merkle_root.clone()
+            )],
+            block_range_roots.into_iter().map(|br| br.into()).collect::<Vec<_>>()
+        );
+        assert_ne!(merkle_root_for_blocks(&blocks), expected_merkle_root);
+    }
+
     #[tokio::test]
     async fn importing_twice_starting_with_nothing_in_a_real_db_should_yield_transactions_in_same_order()
      {
@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use slog::{Logger, debug};
+
+use mithril_common::StdResult;
+use mithril_common::entities::BlockNumber;
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::signable_builder::TransactionsImporter;
+
+/// Cardano transactions pruner
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TransactionPruner: Send + Sync {
+    /// Prune the transactions of the block ranges older than the given number of blocks, keeping
+    /// their block range roots.
+    async fn prune(&self, number_of_blocks_to_keep: BlockNumber) -> StdResult<()>;
+}
+
+/// A decorator of [TransactionsImporter] that prunes the transactions of the block ranges older
+/// than a given number of blocks after running the import ("roots-only" mode).
+///
+/// The block range roots are kept, so certification is not affected, but proofs can no longer be
+/// computed for the pruned transactions: they are reported as non certified.
+///
+/// If the number of blocks to keep is not provided, no pruning is performed.
+pub struct TransactionsImporterWithPruner {
+    number_of_blocks_to_keep: Option<BlockNumber>,
+    transaction_pruner: Arc<dyn TransactionPruner>,
+    wrapped_importer: Arc<dyn TransactionsImporter>,
+    logger: Logger,
+}
+
+impl TransactionsImporterWithPruner {
+    /// Create a new instance of [TransactionsImporterWithPruner].
+    pub fn new(
+        number_of_blocks_to_keep: Option<BlockNumber>,
+        transaction_pruner: Arc<dyn TransactionPruner>,
+        wrapped_importer: Arc<dyn TransactionsImporter>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            number_of_blocks_to_keep,
+            transaction_pruner,
+            wrapped_importer,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionsImporter for TransactionsImporterWithPruner {
+    async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
+        self.wrapped_importer.import(up_to_beacon).await?;
+
+        if let Some(number_of_blocks_to_keep) = self.number_of_blocks_to_keep {
+            debug!(
+                self.logger,
+                "Transaction Import finished - Pruning transactions of block ranges older than the retention window";
+                "number_of_blocks_to_keep" => *number_of_blocks_to_keep,
+            );
+            self.transaction_pruner.prune(number_of_blocks_to_keep).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use mockall::predicate::eq;
+
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub TransactionImporterImpl {}
+
+        #[async_trait]
+        impl TransactionsImporter for TransactionImporterImpl {
+            async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()>;
+        }
+    }
+
+    fn build_importer<P, I>(
+        number_of_blocks_to_keep: Option<BlockNumber>,
+        transaction_pruner_mock_config: P,
+        importer_mock_config: I,
+    ) -> TransactionsImporterWithPruner
+    where
+        P: FnOnce(&mut MockTransactionPruner),
+        I: FnOnce(&mut MockTransactionImporterImpl),
+    {
+        let mut transaction_pruner = MockTransactionPruner::new();
+        transaction_pruner_mock_config(&mut transaction_pruner);
+        let mut transaction_importer = MockTransactionImporterImpl::new();
+        importer_mock_config(&mut transaction_importer);
+
+        TransactionsImporterWithPruner::new(
+            number_of_blocks_to_keep,
+            Arc::new(transaction_pruner),
+            Arc::new(transaction_importer),
+            TestLogger::stdout(),
+        )
+    }
+
+    #[tokio::test]
+    async fn does_not_prune_if_none_is_configured() {
+        let importer = build_importer(
+            None,
+            |mock| {
+                mock.expect_prune().never();
+            },
+            |mock| {
+                mock.expect_import().once().returning(|_| Ok(()));
+            },
+        );
+
+        importer
+            .import(BlockNumber(100))
+            .await
+            .expect("Import should not fail");
+    }
+
+    #[tokio::test]
+    async fn prune_after_import_if_a_block_number_is_configured() {
+        let expected_block_number = BlockNumber(5);
+        let importer = build_importer(
+            Some(expected_block_number),
+            |mock| {
+                mock.expect_prune()
+                    .with(eq(expected_block_number))
+                    .once()
+                    .returning(|_| Ok(()));
+            },
+            |mock| {
+                mock.expect_import().once().returning(|_| Ok(()));
+            },
+        );
+
+        importer
+            .import(BlockNumber(100))
+            .await
+            .expect("Import should not fail");
+    }
+
+    #[tokio::test]
+    async fn does_not_prune_if_import_fails() {
+        let importer = build_importer(
+            Some(BlockNumber(5)),
+            |mock| {
+                mock.expect_prune().never();
+            },
+            |mock| {
+                mock.expect_import()
+                    .once()
+                    .returning(|_| Err(anyhow::anyhow!("import failed")));
+            },
+        );
+
+        importer
+            .import(BlockNumber(100))
+            .await
+            .expect_err("Import should fail");
+    }
+}
@@ -0,0 +1,446 @@
+use std::mem;
+use std::ops::Range;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use slog::{Logger, debug};
+use tokio::{runtime::Handle, sync::Mutex, task};
+
+use mithril_cardano_node_chain::chain_scanner::{BlockScanner, ChainScannedBlocks};
+use mithril_cardano_node_chain::entities::RawCardanoPoint;
+use mithril_common::StdResult;
+use mithril_common::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
+use mithril_common::entities::{
+    BlockNumber, BlockRange, CardanoTransactionMetadata, ChainPoint, SlotNumber,
+};
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::signable_builder::TransactionsMetadataImporter;
+
+/// Cardano transactions metadata store
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TransactionMetadataStore: Send + Sync {
+    /// Get the highest known transaction metadata beacon, including the last scanned block even
+    /// if it doesn't carry any metadata
+    async fn get_highest_beacon(&self) -> StdResult<Option<ChainPoint>>;
+
+    /// Store the chain point of the last scanned block
+    async fn store_scanned_chain_point(&self, chain_point: ChainPoint) -> StdResult<()>;
+
+    /// Get the highest stored block range root bounds
+    async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>>;
+
+    /// Store list of transactions metadata
+    async fn store_transactions_metadata(
+        &self,
+        transactions_metadata: Vec<CardanoTransactionMetadata>,
+    ) -> StdResult<()>;
+
+    /// Get transactions metadata in an interval of blocks
+    async fn get_transactions_metadata_in_range(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<CardanoTransactionMetadata>>;
+
+    /// Store list of block ranges with their corresponding merkle root
+    async fn store_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()>;
+
+    /// Remove transactions metadata and block range roots that are in a rolled-back fork
+    ///
+    /// * Remove transactions metadata with slot number strictly greater than the given slot number
+    /// * Remove block range roots that may contain some of the removed transactions metadata
+    async fn remove_rolled_back_transactions_metadata_and_block_range(
+        &self,
+        slot_number: SlotNumber,
+    ) -> StdResult<()>;
+}
+
+/// Import and store [CardanoTransactionMetadata].
+///
+/// The block scanner must be backed by a chain reader that captures the transactions metadata,
+/// otherwise the scanned blocks don't carry any metadata.
+#[derive(Clone)]
+pub struct CardanoTransactionsMetadataImporter {
+    block_scanner: Arc<dyn BlockScanner>,
+    transaction_metadata_store: Arc<dyn TransactionMetadataStore>,
+    last_polled_point: Arc<Mutex<Option<RawCardanoPoint>>>,
+    logger: Logger,
+}
+
+impl CardanoTransactionsMetadataImporter {
+    /// Constructor
+    pub fn new(
+        block_scanner: Arc<dyn BlockScanner>,
+        transaction_metadata_store: Arc<dyn TransactionMetadataStore>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            block_scanner,
+            transaction_metadata_store,
+            last_polled_point: Arc::new(Mutex::new(None)),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    async fn import_transactions_metadata(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
+        let highest_stored_beacon = self.transaction_metadata_store.get_highest_beacon().await?;
+
+        if highest_stored_beacon
+            .as_ref()
+            .is_some_and(|f| f.block_number >= up_to_beacon)
+        {
+            debug!(
+                self.logger,
+                "No need to retrieve Cardano transactions metadata, the database is up to date for block_number '{up_to_beacon}'",
+            );
+
+            return Ok(());
+        }
+
+        let last_polled_point = self.last_polled_point.lock().await.clone();
+        let from = last_polled_point.or(highest_stored_beacon.as_ref().map(RawCardanoPoint::from));
+        debug!(
+            self.logger, "Retrieving Cardano transactions metadata until block numbered '{up_to_beacon}'";
+            "starting_slot_number" => ?from.as_ref().map(|c| c.slot_number),
+            "highest_stored_block_number" => ?highest_stored_beacon.as_ref().map(|c| c.block_number),
+        );
+
+        let mut streamer = self.block_scanner.scan(from, up_to_beacon).await?;
+        while let Some(blocks) = streamer.poll_next().await? {
+            match blocks {
+                ChainScannedBlocks::RollForwards(forward_blocks) => {
+                    let transactions_metadata: Vec<CardanoTransactionMetadata> = forward_blocks
+                        .iter()
+                        .flat_map(|block| block.transactions_metadata())
+                        .collect();
+
+                    self.transaction_metadata_store
+                        .store_transactions_metadata(transactions_metadata)
+                        .await?;
+
+                    // Most of the blocks don't carry any metadata, the last scanned block is
+                    // stored to resume the import from it instead of the last stored metadata
+                    if let Some(last_scanned_block) = forward_blocks.last() {
+                        self.transaction_metadata_store
+                            .store_scanned_chain_point(ChainPoint::from(last_scanned_block))
+                            .await?;
+                    }
+                }
+                ChainScannedBlocks::RollBackward { slot_number, depth } => {
+                    debug!(
+                        self.logger, "Rolling back Cardano transactions metadata";
+                        "slot_number" => ?slot_number, "depth" => ?depth,
+                    );
+                    self.transaction_metadata_store
+                        .remove_rolled_back_transactions_metadata_and_block_range(slot_number)
+                        .await?;
+                }
+            }
+        }
+
+        if let Some(point) = streamer.last_polled_point() {
+            *self.last_polled_point.lock().await = Some(point);
+        }
+
+        Ok(())
+    }
+
+    async fn import_block_ranges(&self, until: BlockNumber) -> StdResult<()> {
+        let block_ranges =
+            match self.transaction_metadata_store.get_highest_block_range().await?.map(
+                |highest_stored_block_range| {
+                    BlockRange::all_block_ranges_in(
+                        BlockRange::start(highest_stored_block_range.end)..=(until),
+                    )
+                },
+            ) {
+                // No block range root stored yet, start from the beginning
+                None => BlockRange::all_block_ranges_in(BlockNumber(0)..=(until)),
+                // Not enough block to form at least one block range
+                Some(ranges) if ranges.is_empty() => return Ok(()),
+                Some(ranges) => ranges,
+            };
+
+        debug!(
+            self.logger, "Computing Block Range Roots of the Cardano transactions metadata";
+            "start_block" => *block_ranges.start(), "end_block" => *block_ranges.end(),
+        );
+
+        let mut block_ranges_with_merkle_root: Vec<(BlockRange, MKTreeNode)> = vec![];
+        for block_range in block_ranges {
+            let transactions_metadata = self
+                .transaction_metadata_store
+                .get_transactions_metadata_in_range(block_range.start..block_range.end)
+                .await?;
+
+            // Most of the block ranges don't carry any metadata, they don't have a root
+            if transactions_metadata.is_empty() {
+                continue;
+            }
+
+            let merkle_root =
+                MKTree::<MKTreeStoreInMemory>::new(&transactions_metadata)?.compute_root()?;
+            block_ranges_with_merkle_root.push((block_range, merkle_root));
+
+            if block_ranges_with_merkle_root.len() >= 100 {
+                let block_ranges_with_merkle_root_save =
+                    mem::take(&mut block_ranges_with_merkle_root);
+                self.transaction_metadata_store
+                    .store_block_range_roots(block_ranges_with_merkle_root_save)
+                    .await?;
+            }
+        }
+
+        self.transaction_metadata_store
+            .store_block_range_roots(block_ranges_with_merkle_root)
+            .await
+    }
+}
+
+#[async_trait]
+impl TransactionsMetadataImporter for CardanoTransactionsMetadataImporter {
+    async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
+        let importer = self.clone();
+        task::spawn_blocking(move || {
+            Handle::current().block_on(async move {
+                importer.import_transactions_metadata(up_to_beacon).await?;
+                importer.import_block_ranges(up_to_beacon).await?;
+                Ok(())
+            })
+        })
+        .await
+        .with_context(|| "TransactionsMetadataImporter - worker thread crashed")?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+
+    use mithril_cardano_node_chain::chain_scanner::BlockStreamer;
+    use mithril_cardano_node_chain::entities::ScannedBlock;
+    use mithril_cardano_node_chain::test::double::{DumbBlockScanner, DumbBlockStreamer};
+    use mithril_persistence::database::repository::CardanoTransactionMetadataRepository;
+    use mithril_persistence::sqlite::SqliteConnectionPool;
+
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub BlockScannerImpl { }
+
+        #[async_trait]
+        impl BlockScanner for BlockScannerImpl {
+            async fn scan(
+              &self,
+              from: Option<RawCardanoPoint>,
+              until: BlockNumber,
+            ) -> StdResult<Box<dyn BlockStreamer>>;
+        }
+    }
+
+    fn build_blocks(block_numbers: Range<u64>) -> Vec<ScannedBlock> {
+        block_numbers
+            .map(|block_number| {
+                ScannedBlock::new(
+                    format!("block_hash-{block_number}"),
+                    BlockNumber(block_number),
+                    SlotNumber(block_number * 100),
+                    vec![format!("tx_hash-{block_number}")],
+                )
+                .with_transactions_metadata(vec![(
+                    format!("tx_hash-{block_number}"),
+                    674,
+                    format!("metadata_hash-{block_number}"),
+                )])
+            })
+            .collect()
+    }
+
+    fn into_transactions_metadata(blocks: &[ScannedBlock]) -> Vec<CardanoTransactionMetadata> {
+        blocks.iter().flat_map(|b| b.transactions_metadata()).collect()
+    }
+
+    fn repository() -> Arc<CardanoTransactionMetadataRepository> {
+        let connection = cardano_tx_db_connection().unwrap();
+        Arc::new(CardanoTransactionMetadataRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        )))
+    }
+
+    async fn get_all_stored_transactions_metadata(
+        repository: &CardanoTransactionMetadataRepository,
+    ) -> Vec<CardanoTransactionMetadata> {
+        repository
+            .get_all_transactions_metadata()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn if_nothing_stored_parse_and_store_all_transactions_metadata_and_block_ranges() {
+        let repository = repository();
+        let blocks = build_blocks(0..40);
+        let importer = CardanoTransactionsMetadataImporter::new(
+            Arc::new(DumbBlockScanner::new().forwards(vec![blocks.clone()])),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+
+        importer
+            .import(BlockNumber(40))
+            .await
+            .expect("Transactions Metadata Importer should succeed");
+
+        assert_eq!(
+            into_transactions_metadata(&blocks),
+            get_all_stored_transactions_metadata(&repository).await
+        );
+
+        let stored_block_range_roots: Vec<(BlockRange, MKTreeNode)> = repository
+            .get_all_block_range_root()
+            .unwrap()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    MKTree::<MKTreeStoreInMemory>::new(&into_transactions_metadata(&blocks[0..15]))
+                        .unwrap()
+                        .compute_root()
+                        .unwrap()
+                ),
+                (
+                    BlockRange::from_block_number(BlockNumber(15)),
+                    MKTree::<MKTreeStoreInMemory>::new(&into_transactions_metadata(
+                        &blocks[15..30]
+                    ))
+                    .unwrap()
+                    .compute_root()
+                    .unwrap()
+                ),
+            ],
+            stored_block_range_roots
+        );
+    }
+
+    #[tokio::test]
+    async fn block_ranges_without_transactions_metadata_have_no_root() {
+        let repository = repository();
+        let mut blocks = build_blocks(0..15);
+        blocks.extend(
+            build_blocks(15..30)
+                .into_iter()
+                .map(|b| b.with_transactions_metadata::<String, String>(vec![])),
+        );
+        let importer = CardanoTransactionsMetadataImporter::new(
+            Arc::new(DumbBlockScanner::new().forwards(vec![blocks.clone()])),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+
+        importer.import(BlockNumber(30)).await.unwrap();
+
+        let stored_block_ranges: Vec<BlockRange> = repository
+            .get_all_block_range_root()
+            .unwrap()
+            .into_iter()
+            .map(|record| record.range)
+            .collect();
+        assert_eq!(
+            vec![BlockRange::from_block_number(BlockNumber(0))],
+            stored_block_ranges
+        );
+    }
+
+    #[tokio::test]
+    async fn if_all_transactions_metadata_stored_nothing_is_parsed_and_stored() {
+        let repository = repository();
+        let stored_blocks = build_blocks(0..10);
+        repository
+            .store_transactions_metadata(into_transactions_metadata(&stored_blocks))
+            .await
+            .unwrap();
+        let importer = CardanoTransactionsMetadataImporter::new(
+            Arc::new(DumbBlockScanner::new().forwards(vec![build_blocks(10..20)])),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+
+        importer.import_transactions_metadata(BlockNumber(9)).await.unwrap();
+
+        assert_eq!(
+            into_transactions_metadata(&stored_blocks),
+            get_all_stored_transactions_metadata(&repository).await
+        );
+    }
+
+    #[tokio::test]
+    async fn resume_import_from_the_last_scanned_block_even_without_transactions_metadata() {
+        let repository = repository();
+        let blocks_without_metadata: Vec<ScannedBlock> = build_blocks(0..10)
+            .into_iter()
+            .map(|b| b.with_transactions_metadata::<String, String>(vec![]))
+            .collect();
+        let importer = CardanoTransactionsMetadataImporter::new(
+            Arc::new(DumbBlockScanner::new().forwards(vec![blocks_without_metadata.clone()])),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+        importer.import_transactions_metadata(BlockNumber(9)).await.unwrap();
+
+        // A new importer, as after a restart, resumes from the last scanned block
+        let last_scanned_point =
+            RawCardanoPoint::from(&ChainPoint::from(blocks_without_metadata.last().unwrap()));
+        let mut scanner_mock = MockBlockScannerImpl::new();
+        scanner_mock
+            .expect_scan()
+            .withf(move |from, until| {
+                from == &Some(last_scanned_point.clone()) && *until == BlockNumber(20)
+            })
+            .return_once(|_, _| Ok(Box::new(DumbBlockStreamer::new())));
+        let importer = CardanoTransactionsMetadataImporter::new(
+            Arc::new(scanner_mock),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+
+        importer.import_transactions_metadata(BlockNumber(20)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn when_rollbackward_should_remove_transactions_metadata() {
+        let repository = repository();
+        repository
+            .store_transactions_metadata(into_transactions_metadata(&build_blocks(0..10)))
+            .await
+            .unwrap();
+        let chain_point = ChainPoint::new(SlotNumber(500), BlockNumber(5), "block_hash-5");
+        let importer = CardanoTransactionsMetadataImporter::new(
+            Arc::new(DumbBlockScanner::new().backward(chain_point)),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+
+        importer
+            .import_transactions_metadata(BlockNumber(3000))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            into_transactions_metadata(&build_blocks(0..6)),
+            get_all_stored_transactions_metadata(&repository).await
+        );
+    }
+}
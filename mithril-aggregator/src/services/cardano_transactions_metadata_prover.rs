@@ -0,0 +1,280 @@
+use async_trait::async_trait;
+use slog::{Logger, debug};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use mithril_common::{
+    StdResult,
+    crypto_helper::{MKTree, MKTreeNode, MKTreeStorer},
+    entities::{
+        BlockNumber, BlockRange, CardanoTransactionMetadata, CardanoTransactionsMetadataSetProof,
+        TransactionHash,
+    },
+    logging::LoggerExtensions,
+    signable_builder::BlockRangeRootRetriever,
+};
+
+/// Prover service in charge of producing cryptographic proofs for Cardano transactions metadata
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CardanoTransactionsMetadataProverService: Sync + Send {
+    /// Compute the cryptographic proofs for the metadata of the given transactions
+    ///
+    /// The proofs are computed against the Cardano transactions metadata snapshot certified at
+    /// the `up_to` block number.
+    async fn compute_transactions_metadata_proofs(
+        &self,
+        up_to: BlockNumber,
+        transaction_hashes: &[TransactionHash],
+    ) -> StdResult<Vec<CardanoTransactionsMetadataSetProof>>;
+}
+
+/// Cardano transactions metadata retriever
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TransactionsMetadataRetriever: Sync + Send {
+    /// Get a list of transactions metadata by transaction hashes using chronological order
+    async fn get_by_transaction_hashes(
+        &self,
+        hashes: Vec<TransactionHash>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoTransactionMetadata>>;
+
+    /// Get by block ranges
+    async fn get_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<CardanoTransactionMetadata>>;
+}
+
+/// Mithril Cardano transactions metadata prover
+pub struct MithrilCardanoTransactionsMetadataProverService<S: MKTreeStorer> {
+    transactions_metadata_retriever: Arc<dyn TransactionsMetadataRetriever>,
+    block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+    logger: Logger,
+}
+
+impl<S: MKTreeStorer> MithrilCardanoTransactionsMetadataProverService<S> {
+    /// Create a new Mithril Cardano transactions metadata prover
+    pub fn new(
+        transactions_metadata_retriever: Arc<dyn TransactionsMetadataRetriever>,
+        block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            transactions_metadata_retriever,
+            block_range_root_retriever,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Get all the transactions metadata of the block ranges that contain the given ones
+    async fn get_all_transactions_metadata_for_block_ranges_of(
+        &self,
+        transactions_metadata: &[CardanoTransactionMetadata],
+    ) -> StdResult<BTreeMap<BlockRange, Vec<CardanoTransactionMetadata>>> {
+        let block_ranges = transactions_metadata
+            .iter()
+            .map(|m| BlockRange::from_block_number(m.block_number))
+            .collect::<BTreeSet<_>>();
+        let mut block_ranges_map: BTreeMap<BlockRange, Vec<CardanoTransactionMetadata>> =
+            BTreeMap::new();
+        for transaction_metadata in self
+            .transactions_metadata_retriever
+            .get_by_block_ranges(block_ranges.into_iter().collect())
+            .await?
+        {
+            block_ranges_map
+                .entry(BlockRange::from_block_number(
+                    transaction_metadata.block_number,
+                ))
+                .or_default()
+                .push(transaction_metadata);
+        }
+
+        Ok(block_ranges_map)
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> CardanoTransactionsMetadataProverService
+    for MithrilCardanoTransactionsMetadataProverService<S>
+{
+    async fn compute_transactions_metadata_proofs(
+        &self,
+        up_to: BlockNumber,
+        transaction_hashes: &[TransactionHash],
+    ) -> StdResult<Vec<CardanoTransactionsMetadataSetProof>> {
+        // 1 - Retrieve the metadata to prove and all the metadata of their block ranges
+        let metadata_to_prove = self
+            .transactions_metadata_retriever
+            .get_by_transaction_hashes(transaction_hashes.to_vec(), up_to)
+            .await?;
+        if metadata_to_prove.is_empty() {
+            return Ok(vec![]);
+        }
+        let block_ranges_metadata = self
+            .get_all_transactions_metadata_for_block_ranges_of(&metadata_to_prove)
+            .await?;
+
+        // 2 - Compute the block range roots Merkle map from the stored block range roots
+        debug!(
+            self.logger, "Computing the Merkle map of the Cardano transactions metadata";
+            "up_to_block_number" => *up_to,
+        );
+        let mut mk_map = self
+            .block_range_root_retriever
+            .compute_merkle_map_from_block_range_roots(up_to)
+            .await?;
+
+        // 3 - Enrich the Merkle map with the block ranges Merkle trees
+        for (block_range, transactions_metadata) in block_ranges_metadata {
+            let mk_tree = MKTree::<S>::new(&transactions_metadata)?;
+            mk_map.replace(block_range, mk_tree.into())?;
+        }
+
+        // 4 - Compute the proof for all transactions metadata
+        let leaves_to_prove: Vec<MKTreeNode> = metadata_to_prove.iter().map(|m| m.into()).collect();
+        match mk_map.compute_proof(&leaves_to_prove) {
+            Ok(mk_proof) => Ok(vec![CardanoTransactionsMetadataSetProof::new(
+                metadata_to_prove,
+                mk_proof,
+            )]),
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::{MKMap, MKMapNode, MKTreeStoreInMemory};
+    use mithril_common::entities::SlotNumber;
+    use mockall::mock;
+
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub BlockRangeRootRetrieverImpl<S: MKTreeStorer> { }
+
+        #[async_trait]
+        impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for BlockRangeRootRetrieverImpl<S> {
+            async fn retrieve_block_range_roots<'a>(
+                &'a self,
+                up_to_beacon: BlockNumber,
+            ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + 'a>>;
+
+            async fn compute_merkle_map_from_block_range_roots(
+                &self,
+                up_to_beacon: BlockNumber,
+            ) -> StdResult<MKMap<BlockRange, MKMapNode<BlockRange, S>, S>>;
+        }
+    }
+
+    fn build_transactions_metadata(
+        block_numbers: std::ops::Range<u64>,
+    ) -> Vec<CardanoTransactionMetadata> {
+        block_numbers
+            .map(|number| {
+                CardanoTransactionMetadata::new(
+                    format!("tx-hash-{number}"),
+                    674,
+                    format!("metadata-hash-{number}"),
+                    BlockNumber(number),
+                    SlotNumber(number * 10),
+                    format!("block-hash-{number}"),
+                )
+            })
+            .collect()
+    }
+
+    fn build_prover(
+        metadata_to_prove: Vec<CardanoTransactionMetadata>,
+        all_metadata: Vec<CardanoTransactionMetadata>,
+    ) -> MithrilCardanoTransactionsMetadataProverService<MKTreeStoreInMemory> {
+        let mut transactions_metadata_retriever = MockTransactionsMetadataRetriever::new();
+        let block_range_metadata: Vec<CardanoTransactionMetadata> = all_metadata
+            .iter()
+            .filter(|metadata| {
+                metadata_to_prove.iter().any(|m| {
+                    BlockRange::from_block_number(m.block_number)
+                        == BlockRange::from_block_number(metadata.block_number)
+                })
+            })
+            .cloned()
+            .collect();
+        transactions_metadata_retriever
+            .expect_get_by_transaction_hashes()
+            .return_once(move |_, _| Ok(metadata_to_prove));
+        transactions_metadata_retriever
+            .expect_get_by_block_ranges()
+            .return_once(move |_| Ok(block_range_metadata));
+
+        let mut block_range_root_retriever = MockBlockRangeRootRetrieverImpl::new();
+        block_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| {
+                let mut metadata_by_range: BTreeMap<BlockRange, Vec<CardanoTransactionMetadata>> =
+                    BTreeMap::new();
+                for metadata in all_metadata {
+                    metadata_by_range
+                        .entry(BlockRange::from_block_number(metadata.block_number))
+                        .or_default()
+                        .push(metadata);
+                }
+                MKMap::new_from_iter(metadata_by_range.into_iter().map(|(range, metadata)| {
+                    (
+                        range,
+                        MKMapNode::TreeNode(
+                            MKTree::<MKTreeStoreInMemory>::new(&metadata)
+                                .unwrap()
+                                .compute_root()
+                                .unwrap(),
+                        ),
+                    )
+                }))
+            });
+
+        MithrilCardanoTransactionsMetadataProverService::new(
+            Arc::new(transactions_metadata_retriever),
+            Arc::new(block_range_root_retriever),
+            TestLogger::stdout(),
+        )
+    }
+
+    #[tokio::test]
+    async fn compute_proof_for_known_transactions_metadata() {
+        let all_metadata = build_transactions_metadata(0..60);
+        let metadata_to_prove = vec![all_metadata[2].clone(), all_metadata[33].clone()];
+        let prover = build_prover(metadata_to_prove.clone(), all_metadata);
+
+        let metadata_set_proofs = prover
+            .compute_transactions_metadata_proofs(
+                BlockNumber(60),
+                &[
+                    "tx-hash-2".to_string(),
+                    "tx-hash-33".to_string(),
+                    "tx-hash-unknown".to_string(),
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, metadata_set_proofs.len());
+        assert_eq!(metadata_to_prove, metadata_set_proofs[0].metadata());
+        metadata_set_proofs[0].verify().unwrap();
+    }
+
+    #[tokio::test]
+    async fn compute_no_proof_if_no_transaction_metadata_is_known() {
+        let prover = build_prover(vec![], build_transactions_metadata(0..30));
+
+        let metadata_set_proofs = prover
+            .compute_transactions_metadata_proofs(BlockNumber(30), &["tx-hash-unknown".to_string()])
+            .await
+            .unwrap();
+
+        assert!(metadata_set_proofs.is_empty());
+    }
+}
@@ -9,10 +9,12 @@ use mithril_common::{
     StdResult,
     entities::{Epoch, SignedEntityTypeDiscriminants},
     messages::{
+        CardanoBlocksSnapshotListMessage, CardanoBlocksSnapshotMessage,
         CardanoDatabaseDigestListItemMessage, CardanoDatabaseDigestListMessage,
         CardanoDatabaseSnapshotListMessage, CardanoDatabaseSnapshotMessage,
         CardanoStakeDistributionListMessage, CardanoStakeDistributionMessage,
         CardanoTransactionSnapshotListMessage, CardanoTransactionSnapshotMessage,
+        CardanoTransactionsMetadataSnapshotListMessage, CardanoTransactionsMetadataSnapshotMessage,
         CertificateListMessage, CertificateMessage, EpochSettingsMessage,
         MithrilStakeDistributionListMessage, MithrilStakeDistributionMessage, SignerMessagePart,
         SnapshotListMessage, SnapshotMessage,
@@ -100,6 +102,31 @@ pub trait MessageService: Sync + Send {
         limit: usize,
     ) -> StdResult<CardanoTransactionSnapshotListMessage>;
 
+    /// Return the information regarding the Cardano blocks set for the given identifier.
+    async fn get_cardano_blocks_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoBlocksSnapshotMessage>>;
+
+    /// Return the list of the last Cardano blocks set message.
+    async fn get_cardano_blocks_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoBlocksSnapshotListMessage>;
+
+    /// Return the information regarding the Cardano transactions metadata set for the given
+    /// identifier.
+    async fn get_cardano_transactions_metadata_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoTransactionsMetadataSnapshotMessage>>;
+
+    /// Return the list of the last Cardano transactions metadata set message.
+    async fn get_cardano_transactions_metadata_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoTransactionsMetadataSnapshotListMessage>;
+
     /// Return the information regarding the Cardano stake distribution for the given identifier.
     async fn get_cardano_stake_distribution_message(
         &self,
@@ -158,8 +185,13 @@ impl MessageService for MithrilMessageService {
         let current_signers = epoch_service.current_signers()?;
         let next_signers = epoch_service.next_signers()?;
 
-        let cardano_transactions_discriminant =
-            allowed_discriminants.get(&SignedEntityTypeDiscriminants::CardanoTransactions);
+        // The Cardano blocks and the Cardano transactions metadata are signed with the same
+        // signing config as the Cardano transactions
+        let cardano_transactions_discriminant = allowed_discriminants
+            .get(&SignedEntityTypeDiscriminants::CardanoTransactions)
+            .or(allowed_discriminants.get(&SignedEntityTypeDiscriminants::CardanoBlocks))
+            .or(allowed_discriminants
+                .get(&SignedEntityTypeDiscriminants::CardanoTransactionsMetadata));
 
         let cardano_transactions_signing_config = cardano_transactions_discriminant
             .map(|_| epoch_service.current_cardano_transactions_signing_config())
@@ -304,6 +336,50 @@ impl MessageService for MithrilMessageService {
         entities.into_iter().map(|i| i.try_into()).collect()
     }
 
+    async fn get_cardano_blocks_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoBlocksSnapshotMessage>> {
+        let signed_entity = self.signed_entity_storer.get_signed_entity(signed_entity_id).await?;
+
+        signed_entity.map(|v| v.try_into()).transpose()
+    }
+
+    async fn get_cardano_blocks_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoBlocksSnapshotListMessage> {
+        let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoBlocks;
+        let entities = self
+            .signed_entity_storer
+            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .await?;
+
+        entities.into_iter().map(|i| i.try_into()).collect()
+    }
+
+    async fn get_cardano_transactions_metadata_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoTransactionsMetadataSnapshotMessage>> {
+        let signed_entity = self.signed_entity_storer.get_signed_entity(signed_entity_id).await?;
+
+        signed_entity.map(|v| v.try_into()).transpose()
+    }
+
+    async fn get_cardano_transactions_metadata_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoTransactionsMetadataSnapshotListMessage> {
+        let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoTransactionsMetadata;
+        let entities = self
+            .signed_entity_storer
+            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .await?;
+
+        entities.into_iter().map(|i| i.try_into()).collect()
+    }
+
     async fn get_cardano_stake_distribution_message(
         &self,
         signed_entity_id: &str,
@@ -501,6 +577,46 @@ mod tests {
             assert!(message.next_cardano_transactions_signing_config.is_some(),);
         }
 
+        #[tokio::test]
+        async fn get_epoch_settings_message_with_cardano_blocks_enabled() {
+            let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+            let epoch_service = FakeEpochService::from_fixture(Epoch(4), &fixture);
+            let message_service = MessageServiceBuilder::new()
+                .with_epoch_service(epoch_service)
+                .build()
+                .await;
+
+            let message = message_service
+                .get_epoch_settings_message(BTreeSet::from([
+                    SignedEntityTypeDiscriminants::CardanoBlocks,
+                ]))
+                .await
+                .unwrap();
+
+            assert!(message.cardano_transactions_signing_config.is_some());
+            assert!(message.next_cardano_transactions_signing_config.is_some(),);
+        }
+
+        #[tokio::test]
+        async fn get_epoch_settings_message_with_cardano_transactions_metadata_enabled() {
+            let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+            let epoch_service = FakeEpochService::from_fixture(Epoch(4), &fixture);
+            let message_service = MessageServiceBuilder::new()
+                .with_epoch_service(epoch_service)
+                .build()
+                .await;
+
+            let message = message_service
+                .get_epoch_settings_message(BTreeSet::from([
+                    SignedEntityTypeDiscriminants::CardanoTransactionsMetadata,
+                ]))
+                .await
+                .unwrap();
+
+            assert!(message.cardano_transactions_signing_config.is_some());
+            assert!(message.next_cardano_transactions_signing_config.is_some(),);
+        }
+
         #[tokio::test]
         async fn get_epoch_settings_message_with_cardano_transactions_not_enabled() {
             let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
@@ -1012,6 +1128,186 @@ mod tests {
         }
     }
 
+    mod cardano_blocks {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_cardano_blocks() {
+            let record = SignedEntityRecord {
+                signed_entity_id: "signed_entity_id".to_string(),
+                signed_entity_type: SignedEntityType::CardanoBlocks(Epoch(18), BlockNumber(120)),
+                certificate_id: "cert_id".to_string(),
+                artifact: serde_json::to_string(&fake_data::cardano_blocks_snapshot(1)[0]).unwrap(),
+                created_at: Default::default(),
+            };
+            let message: CardanoBlocksSnapshotMessage = record.clone().try_into().unwrap();
+
+            let service = MessageServiceBuilder::new()
+                .with_signed_entity_records(&[record.clone()])
+                .build()
+                .await;
+
+            let response = service
+                .get_cardano_blocks_message(&record.signed_entity_id)
+                .await
+                .unwrap()
+                .expect("A CardanoBlocksSnapshotMessage was expected.");
+
+            assert_eq!(message, response);
+        }
+
+        #[tokio::test]
+        async fn get_cardano_blocks_not_exist() {
+            let service = MessageServiceBuilder::new().build().await;
+
+            let response = service.get_cardano_blocks_message("whatever").await.unwrap();
+
+            assert!(response.is_none());
+        }
+
+        #[tokio::test]
+        async fn get_cardano_blocks_list_message() {
+            let records = vec![
+                SignedEntityRecord {
+                    signed_entity_id: "signed_entity_id-1".to_string(),
+                    signed_entity_type: SignedEntityType::CardanoBlocks(
+                        Epoch(18),
+                        BlockNumber(120),
+                    ),
+                    certificate_id: "cert_id-1".to_string(),
+                    artifact: serde_json::to_string(&fake_data::cardano_blocks_snapshot(1)[0])
+                        .unwrap(),
+                    created_at: Default::default(),
+                },
+                SignedEntityRecord {
+                    signed_entity_id: "signed_entity_id-2".to_string(),
+                    signed_entity_type: SignedEntityType::CardanoTransactions(
+                        Epoch(18),
+                        BlockNumber(120),
+                    ),
+                    certificate_id: "cert_id-2".to_string(),
+                    artifact: serde_json::to_string(
+                        &fake_data::cardano_transactions_snapshot(1)[0],
+                    )
+                    .unwrap(),
+                    created_at: Default::default(),
+                },
+            ];
+            let message: CardanoBlocksSnapshotListMessage =
+                vec![records[0].clone().try_into().unwrap()];
+
+            let service = MessageServiceBuilder::new()
+                .with_signed_entity_records(&records)
+                .build()
+                .await;
+
+            let response = service.get_cardano_blocks_list_message(0).await.unwrap();
+            assert!(response.is_empty());
+
+            let response = service.get_cardano_blocks_list_message(3).await.unwrap();
+            assert_eq!(message, response);
+        }
+    }
+
+    mod cardano_transactions_metadata {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_cardano_transactions_metadata() {
+            let record = SignedEntityRecord {
+                signed_entity_id: "signed_entity_id".to_string(),
+                signed_entity_type: SignedEntityType::CardanoTransactionsMetadata(
+                    Epoch(18),
+                    BlockNumber(120),
+                ),
+                certificate_id: "cert_id".to_string(),
+                artifact: serde_json::to_string(
+                    &fake_data::cardano_transactions_metadata_snapshot(1)[0],
+                )
+                .unwrap(),
+                created_at: Default::default(),
+            };
+            let message: CardanoTransactionsMetadataSnapshotMessage =
+                record.clone().try_into().unwrap();
+
+            let service = MessageServiceBuilder::new()
+                .with_signed_entity_records(&[record.clone()])
+                .build()
+                .await;
+
+            let response = service
+                .get_cardano_transactions_metadata_message(&record.signed_entity_id)
+                .await
+                .unwrap()
+                .expect("A CardanoTransactionsMetadataSnapshotMessage was expected.");
+
+            assert_eq!(message, response);
+        }
+
+        #[tokio::test]
+        async fn get_cardano_transactions_metadata_not_exist() {
+            let service = MessageServiceBuilder::new().build().await;
+
+            let response = service
+                .get_cardano_transactions_metadata_message("whatever")
+                .await
+                .unwrap();
+
+            assert!(response.is_none());
+        }
+
+        #[tokio::test]
+        async fn get_cardano_transactions_metadata_list_message() {
+            let records = vec![
+                SignedEntityRecord {
+                    signed_entity_id: "signed_entity_id-1".to_string(),
+                    signed_entity_type: SignedEntityType::CardanoTransactionsMetadata(
+                        Epoch(18),
+                        BlockNumber(120),
+                    ),
+                    certificate_id: "cert_id-1".to_string(),
+                    artifact: serde_json::to_string(
+                        &fake_data::cardano_transactions_metadata_snapshot(1)[0],
+                    )
+                    .unwrap(),
+                    created_at: Default::default(),
+                },
+                SignedEntityRecord {
+                    signed_entity_id: "signed_entity_id-2".to_string(),
+                    signed_entity_type: SignedEntityType::CardanoTransactions(
+                        Epoch(18),
+                        BlockNumber(120),
+                    ),
+                    certificate_id: "cert_id-2".to_string(),
+                    artifact: serde_json::to_string(
+                        &fake_data::cardano_transactions_snapshot(1)[0],
+                    )
+                    .unwrap(),
+                    created_at: Default::default(),
+                },
+            ];
+            let message: CardanoTransactionsMetadataSnapshotListMessage =
+                vec![records[0].clone().try_into().unwrap()];
+
+            let service = MessageServiceBuilder::new()
+                .with_signed_entity_records(&records)
+                .build()
+                .await;
+
+            let response = service
+                .get_cardano_transactions_metadata_list_message(0)
+                .await
+                .unwrap();
+            assert!(response.is_empty());
+
+            let response = service
+                .get_cardano_transactions_metadata_list_message(3)
+                .await
+                .unwrap();
+            assert_eq!(message, response);
+        }
+    }
+
     mod cardano_stake_distribution {
         use super::*;
 
@@ -10,7 +10,12 @@
 //! Each service is defined by a public API (a trait) that is used in the controllers (runtimes).
 
 mod aggregator_client;
+mod cardano_blocks_importer;
+mod cardano_blocks_prover;
 mod cardano_transactions_importer;
+mod cardano_transactions_importer_with_pruner;
+mod cardano_transactions_metadata_importer;
+mod cardano_transactions_metadata_prover;
 mod certificate_chain_synchronizer;
 mod certifier;
 mod epoch_service;
@@ -27,7 +32,12 @@ mod upkeep;
 mod usage_reporter;
 
 pub use aggregator_client::*;
+pub use cardano_blocks_importer::*;
+pub use cardano_blocks_prover::*;
 pub use cardano_transactions_importer::*;
+pub use cardano_transactions_importer_with_pruner::*;
+pub use cardano_transactions_metadata_importer::*;
+pub use cardano_transactions_metadata_prover::*;
 pub use certificate_chain_synchronizer::*;
 pub use certifier::*;
 pub use epoch_service::*;
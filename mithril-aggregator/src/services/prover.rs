@@ -2,27 +2,32 @@ use async_trait::async_trait;
 use rayon::prelude::*;
 use slog::{Logger, debug, info};
 use std::{
-    collections::{BTreeMap, BTreeSet, HashMap},
-    sync::Arc,
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    sync::{Arc, RwLock},
     time::Duration,
 };
+use tokio::sync::Mutex;
 
 use mithril_common::{
     StdResult,
-    crypto_helper::{MKMap, MKMapNode, MKTree, MKTreeStorer},
+    crypto_helper::{MKMap, MKMapNode, MKTree, MKTreeNode, MKTreeStorer},
     entities::{
-        BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionsSetProof, TransactionHash,
+        BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionBlockPosition,
+        CardanoTransactionsLeafFormat, CardanoTransactionsSetProof, TransactionHash,
     },
     logging::LoggerExtensions,
     signable_builder::BlockRangeRootRetriever,
 };
-use mithril_resource_pool::ResourcePool;
+use mithril_resource_pool::{ResourcePool, ResourcePoolItem};
 
 /// Prover service is the cryptographic engine in charge of producing cryptographic proofs for transactions
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait ProverService: Sync + Send {
     /// Compute the cryptographic proofs for the given transactions
+    ///
+    /// The proofs are computed against the Cardano transactions snapshot certified at the `up_to`
+    /// block number, which can be older than the latest certified one.
     async fn compute_transactions_proofs(
         &self,
         up_to: BlockNumber,
@@ -31,6 +36,30 @@ pub trait ProverService: Sync + Send {
 
     /// Compute the cache
     async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()>;
+
+    /// Get a page of at most `max_items` block range roots of the Cardano transactions snapshot
+    /// certified at the `up_to` block number, starting with the block range that starts at `from`.
+    ///
+    /// The last page comes with the transactions of the last block range of the snapshot.
+    async fn get_block_range_roots(
+        &self,
+        up_to: BlockNumber,
+        from: BlockNumber,
+        max_items: usize,
+    ) -> StdResult<CardanoTransactionsBlockRangeRoots>;
+}
+
+/// Block range roots of a Cardano transactions snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardanoTransactionsBlockRangeRoots {
+    /// Roots of the block ranges of the snapshot, in ascending order
+    pub block_range_roots: Vec<(BlockRange, MKTreeNode)>,
+
+    /// Start of the first block range of the next page, none if this page is the last one
+    pub next_page_from: Option<BlockNumber>,
+
+    /// Transactions of the last block range of the snapshot, empty if this page is not the last one
+    pub last_block_range_transactions: Vec<CardanoTransaction>,
 }
 
 /// Transactions retriever
@@ -51,11 +80,24 @@ pub trait TransactionsRetriever: Sync + Send {
     ) -> StdResult<Vec<CardanoTransaction>>;
 }
 
+pub(crate) type BlockRangeRootsMKMap<S> = MKMap<BlockRange, MKMapNode<BlockRange, S>, S>;
+
+/// Maximum number of Merkle maps of historical Cardano transactions snapshots kept in memory
+const HISTORICAL_MK_MAPS_CACHE_SIZE: usize = 3;
+
 /// Mithril prover
 pub struct MithrilProverService<S: MKTreeStorer> {
     transaction_retriever: Arc<dyn TransactionsRetriever>,
     block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
-    mk_map_pool: ResourcePool<MKMap<BlockRange, MKMapNode<BlockRange, S>, S>>,
+    mk_map_pool: ResourcePool<BlockRangeRootsMKMap<S>>,
+    /// Discriminant of the Merkle map pool and block number of its cached resources
+    mk_map_pool_beacon: RwLock<Option<(u64, BlockNumber)>>,
+    /// Merkle maps of the most recently requested historical snapshots, most recent first.
+    ///
+    /// The lock is held while a historical Merkle map is computed and used, so that at most one
+    /// of them is rebuilt at a time.
+    historical_mk_maps: Mutex<VecDeque<(BlockNumber, BlockRangeRootsMKMap<S>)>>,
+    leaf_format: CardanoTransactionsLeafFormat,
     logger: Logger,
 }
 
@@ -71,25 +113,27 @@ impl<S: MKTreeStorer> MithrilProverService<S> {
             transaction_retriever,
             block_range_root_retriever,
             mk_map_pool: ResourcePool::new(mk_map_pool_size, vec![]),
+            mk_map_pool_beacon: RwLock::new(None),
+            historical_mk_maps: Mutex::new(VecDeque::new()),
+            leaf_format: CardanoTransactionsLeafFormat::default(),
             logger: logger.new_with_component_name::<Self>(),
         }
     }
 
-    async fn get_block_ranges(
-        &self,
-        transaction_hashes: &[TransactionHash],
-        up_to: BlockNumber,
-    ) -> StdResult<Vec<BlockRange>> {
-        let transactions = self
-            .transaction_retriever
-            .get_by_hashes(transaction_hashes.to_vec(), up_to)
-            .await?;
+    /// Set the format of the leaves of the block ranges Merkle trees, it must be the same as the
+    /// one used to compute the stored block range roots
+    pub fn with_leaf_format(mut self, leaf_format: CardanoTransactionsLeafFormat) -> Self {
+        self.leaf_format = leaf_format;
+        self
+    }
+
+    fn get_block_ranges(transactions: &[CardanoTransaction]) -> Vec<BlockRange> {
         let block_ranges = transactions
             .iter()
             .map(|t| BlockRange::from_block_number(t.block_number))
             .collect::<BTreeSet<_>>();
 
-        Ok(block_ranges.into_iter().collect::<Vec<_>>())
+        block_ranges.into_iter().collect::<Vec<_>>()
     }
 
     /// Get all the transactions of the block ranges
@@ -111,6 +155,57 @@ impl<S: MKTreeStorer> MithrilProverService<S> {
 
         Ok(block_ranges_map)
     }
+
+    /// Acquire a Merkle map from the pool if its cached resources were computed for the given
+    /// block number
+    fn acquire_cached_mk_map(
+        &self,
+        up_to: BlockNumber,
+    ) -> StdResult<Option<ResourcePoolItem<'_, BlockRangeRootsMKMap<S>>>> {
+        let mk_map_pool_beacon = *self.mk_map_pool_beacon.read().unwrap();
+        match mk_map_pool_beacon {
+            Some((discriminant, block_number)) if block_number == up_to => {
+                let acquire_timeout = Duration::from_millis(1000);
+                let mk_map = self.mk_map_pool.acquire_resource(acquire_timeout)?;
+                // The cache may have been computed again since the beacon was read
+                Ok((mk_map.discriminant() == discriminant).then_some(mk_map))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Get the Merkle map of a historical Cardano transactions snapshot from the given cache, or
+    /// reconstruct it from the stored block range roots and cache it, evicting the least recently
+    /// used one if the cache is full
+    async fn get_historical_mk_map<'a>(
+        &self,
+        historical_mk_maps: &'a mut VecDeque<(BlockNumber, BlockRangeRootsMKMap<S>)>,
+        up_to: BlockNumber,
+    ) -> StdResult<&'a mut BlockRangeRootsMKMap<S>> {
+        match historical_mk_maps
+            .iter()
+            .position(|(block_number, _)| *block_number == up_to)
+        {
+            Some(index) => {
+                let cached_mk_map = historical_mk_maps.remove(index).unwrap();
+                historical_mk_maps.push_front(cached_mk_map);
+            }
+            None => {
+                debug!(
+                    self.logger, "Computing the Merkle map of a historical Cardano transactions snapshot";
+                    "up_to_block_number" => *up_to,
+                );
+                let mk_map = self
+                    .block_range_root_retriever
+                    .compute_merkle_map_from_block_range_roots(up_to)
+                    .await?;
+                historical_mk_maps.truncate(HISTORICAL_MK_MAPS_CACHE_SIZE - 1);
+                historical_mk_maps.push_front((up_to, mk_map));
+            }
+        }
+
+        Ok(&mut historical_mk_maps.front_mut().unwrap().1)
+    }
 }
 
 #[async_trait]
@@ -121,7 +216,11 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
         transaction_hashes: &[TransactionHash],
     ) -> StdResult<Vec<CardanoTransactionsSetProof>> {
         // 1 - Compute the set of block ranges with transactions to prove
-        let block_ranges_transactions = self.get_block_ranges(transaction_hashes, up_to).await?;
+        let transactions_to_prove = self
+            .transaction_retriever
+            .get_by_hashes(transaction_hashes.to_vec(), up_to)
+            .await?;
+        let block_ranges_transactions = Self::get_block_ranges(&transactions_to_prove);
         let block_range_transactions = self
             .get_all_transactions_for_block_ranges(&block_ranges_transactions)
             .await?;
@@ -130,15 +229,28 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
         let mk_trees: StdResult<Vec<(BlockRange, MKTree<S>)>> = block_range_transactions
             .into_iter()
             .map(|(block_range, transactions)| {
-                let mk_tree = MKTree::new(&transactions)?;
+                let leaves: Vec<MKTreeNode> = transactions
+                    .iter()
+                    .map(|transaction| self.leaf_format.compute_leaf(transaction))
+                    .collect();
+                let mk_tree = MKTree::new(&leaves)?;
                 Ok((block_range, mk_tree))
             })
             .collect();
         let mk_trees = BTreeMap::from_iter(mk_trees?);
 
-        // 3 - Compute block range roots Merkle map
-        let acquire_timeout = Duration::from_millis(1000);
-        let mut mk_map = self.mk_map_pool.acquire_resource(acquire_timeout)?;
+        // 3 - Compute block range roots Merkle map, from the cache if it was computed for the
+        // requested block number or from the historical snapshots cache otherwise
+        let mut mk_map_pool_item = self.acquire_cached_mk_map(up_to)?;
+        let mut historical_mk_maps = None;
+        let mk_map = match mk_map_pool_item.as_mut() {
+            Some(mk_map) => &mut **mk_map,
+            None => {
+                let historical_mk_maps =
+                    historical_mk_maps.insert(self.historical_mk_maps.lock().await);
+                self.get_historical_mk_map(historical_mk_maps, up_to).await?
+            }
+        };
 
         // 4 - Enrich the Merkle map with the block ranges Merkle trees
         for (block_range, mk_tree) in mk_trees {
@@ -146,20 +258,52 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
         }
 
         // 5 - Compute the proof for all transactions
-        match mk_map.compute_proof(transaction_hashes) {
+        let leaves_to_prove: Vec<MKTreeNode> = match self.leaf_format {
+            CardanoTransactionsLeafFormat::TransactionHash => {
+                transaction_hashes.iter().map(|hash| hash.as_str().into()).collect()
+            }
+            CardanoTransactionsLeafFormat::BlockInclusion => transactions_to_prove
+                .iter()
+                .map(|transaction| self.leaf_format.compute_leaf(transaction))
+                .collect(),
+        };
+        match mk_map.compute_proof(&leaves_to_prove) {
             Ok(mk_proof) => {
-                self.mk_map_pool.give_back_resource_pool_item(mk_map)?;
+                if let Some(mk_map) = mk_map_pool_item {
+                    self.mk_map_pool.give_back_resource_pool_item(mk_map)?;
+                }
                 let mk_proof_leaves = mk_proof.leaves();
-                let transaction_hashes_certified: Vec<TransactionHash> = transaction_hashes
-                    .iter()
-                    .filter(|hash| mk_proof_leaves.contains(&hash.as_str().into()))
-                    .cloned()
-                    .collect();
+                let transactions_set_proof = match self.leaf_format {
+                    CardanoTransactionsLeafFormat::TransactionHash => {
+                        let transaction_hashes_certified: Vec<TransactionHash> = transaction_hashes
+                            .iter()
+                            .filter(|hash| mk_proof_leaves.contains(&hash.as_str().into()))
+                            .cloned()
+                            .collect();
+
+                        CardanoTransactionsSetProof::new(transaction_hashes_certified, mk_proof)
+                    }
+                    CardanoTransactionsLeafFormat::BlockInclusion => {
+                        let block_positions_certified: Vec<CardanoTransactionBlockPosition> =
+                            transaction_hashes
+                                .iter()
+                                .filter_map(|hash| {
+                                    transactions_to_prove
+                                        .iter()
+                                        .find(|transaction| &transaction.transaction_hash == hash)
+                                })
+                                .map(CardanoTransactionBlockPosition::from)
+                                .filter(|position| mk_proof_leaves.contains(&position.into()))
+                                .collect();
 
-                Ok(vec![CardanoTransactionsSetProof::new(
-                    transaction_hashes_certified,
-                    mk_proof,
-                )])
+                        CardanoTransactionsSetProof::new_with_block_positions(
+                            block_positions_certified,
+                            mk_proof,
+                        )
+                    }
+                };
+
+                Ok(vec![transactions_set_proof])
             }
             _ => Ok(vec![]),
         }
@@ -184,8 +328,9 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
                 );
                 mk_map_cache.clone()
             })
-            .collect::<Vec<MKMap<_, _, _>>>();
+            .collect::<Vec<BlockRangeRootsMKMap<_>>>();
         debug!(self.logger, "Draining the Merkle map pool");
+        *self.mk_map_pool_beacon.write().unwrap() = None;
         let discriminant_new = self.mk_map_pool.discriminant()? + 1;
         self.mk_map_pool.set_discriminant(discriminant_new)?;
         self.mk_map_pool.clear();
@@ -197,6 +342,7 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
             .into_iter()
             .map(|mk_map| self.mk_map_pool.give_back_resource(mk_map, discriminant_new))
             .collect::<StdResult<Vec<_>>>()?;
+        *self.mk_map_pool_beacon.write().unwrap() = Some((discriminant_new, up_to));
         info!(
             self.logger,
             "Completed computing the Merkle map pool resource of size {pool_size}"
@@ -204,6 +350,46 @@ impl<S: MKTreeStorer> ProverService for MithrilProverService<S> {
 
         Ok(())
     }
+
+    async fn get_block_range_roots(
+        &self,
+        up_to: BlockNumber,
+        from: BlockNumber,
+        max_items: usize,
+    ) -> StdResult<CardanoTransactionsBlockRangeRoots> {
+        // Only the block ranges that were complete when the snapshot was signed are part of it,
+        // one more block range is read to know if there is a next page
+        let mut block_range_roots: Vec<(BlockRange, MKTreeNode)> = self
+            .block_range_root_retriever
+            .retrieve_block_range_roots(up_to)
+            .await?
+            .filter(|(block_range, _)| block_range.end <= up_to + 1)
+            .skip_while(|(block_range, _)| block_range.start < from)
+            .take(max_items.saturating_add(1))
+            .collect();
+        let next_page_from = if block_range_roots.len() > max_items {
+            block_range_roots
+                .drain(max_items..)
+                .next()
+                .map(|(block_range, _)| block_range.start)
+        } else {
+            None
+        };
+        let last_block_range_transactions = match (block_range_roots.last(), next_page_from) {
+            (Some((last_block_range, _)), None) => {
+                self.transaction_retriever
+                    .get_by_block_ranges(vec![last_block_range.clone()])
+                    .await?
+            }
+            _ => vec![],
+        };
+
+        Ok(CardanoTransactionsBlockRangeRoots {
+            block_range_roots,
+            next_page_from,
+            last_block_range_transactions,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -212,7 +398,7 @@ mod tests {
     use mithril_common::crypto_helper::{
         MKMap, MKMapNode, MKTreeNode, MKTreeStoreInMemory, MKTreeStorer,
     };
-    use mithril_common::entities::CardanoTransaction;
+    use mithril_common::entities::{CardanoTransaction, SlotNumber};
     use mithril_common::test_utils::CardanoTransactionsBuilder;
     use mockall::mock;
     use mockall::predicate::eq;
@@ -307,6 +493,29 @@ mod tests {
             .unwrap()
         }
 
+        pub fn compute_mk_map_from_block_ranges_map_with_leaf_format(
+            block_ranges_map: BTreeMap<BlockRange, Vec<CardanoTransaction>>,
+            leaf_format: CardanoTransactionsLeafFormat,
+        ) -> MKMap<BlockRange, MKMapNode<BlockRange, MKTreeStoreInMemory>, MKTreeStoreInMemory>
+        {
+            MKMap::new_from_iter(
+                block_ranges_map.into_iter().map(|(block_range, transactions)| {
+                    let leaves: Vec<MKTreeNode> =
+                        transactions.iter().map(|t| leaf_format.compute_leaf(t)).collect();
+                    (
+                        block_range,
+                        MKMapNode::TreeNode(
+                            MKTree::<MKTreeStoreInMemory>::new(&leaves)
+                                .unwrap()
+                                .compute_root()
+                                .unwrap(),
+                        ),
+                    )
+                }),
+            )
+            .unwrap()
+        }
+
         pub fn compute_beacon_from_transactions(
             transactions: &[CardanoTransaction],
         ) -> BlockNumber {
@@ -598,6 +807,251 @@ mod tests {
             .expect_err("Should have failed because of transaction retriever failure");
     }
 
+    #[tokio::test]
+    async fn compute_proof_against_a_historical_snapshot() {
+        let transactions = CardanoTransactionsBuilder::new()
+            .max_transactions_per_block(1)
+            .blocks_per_block_range(3)
+            .build_block_ranges(5);
+        let historical_transactions = &transactions[..9];
+        let transactions_to_prove =
+            test_data::filter_transactions_for_indices(&[1, 2, 4], &transactions);
+        let test_data = test_data::build_test_data(&transactions_to_prove, &transactions);
+        let historical_test_data =
+            test_data::build_test_data(&transactions_to_prove, historical_transactions);
+        let historical_mk_map_root = test_data::compute_mk_map_from_block_ranges_map(
+            historical_test_data.block_ranges_map.clone(),
+        )
+        .compute_root()
+        .unwrap();
+        let prover = build_prover(
+            |transaction_retriever_mock| {
+                let transaction_hashes_to_prove = test_data.transaction_hashes_to_prove.clone();
+                let transactions_to_prove = transactions_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_hashes()
+                    .with(
+                        eq(transaction_hashes_to_prove),
+                        eq(historical_test_data.beacon),
+                    )
+                    .return_once(move |_, _| Ok(transactions_to_prove));
+
+                let block_ranges_to_prove = test_data.block_ranges_to_prove.clone();
+                let all_transactions_in_block_ranges_to_prove =
+                    test_data.all_transactions_in_block_ranges_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_block_ranges()
+                    .with(eq(block_ranges_to_prove))
+                    .return_once(move |_| Ok(all_transactions_in_block_ranges_to_prove));
+            },
+            |block_range_root_retriever_mock| {
+                let block_ranges_map = test_data.block_ranges_map.clone();
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .with(eq(test_data.beacon))
+                    .return_once(|_| {
+                        Ok(test_data::compute_mk_map_from_block_ranges_map(
+                            block_ranges_map,
+                        ))
+                    });
+                let historical_block_ranges_map = historical_test_data.block_ranges_map.clone();
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .with(eq(historical_test_data.beacon))
+                    .return_once(|_| {
+                        Ok(test_data::compute_mk_map_from_block_ranges_map(
+                            historical_block_ranges_map,
+                        ))
+                    });
+            },
+        );
+        prover.compute_cache(test_data.beacon).await.unwrap();
+
+        let transactions_set_proof = prover
+            .compute_transactions_proofs(
+                historical_test_data.beacon,
+                &test_data.transaction_hashes_to_prove,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(transactions_set_proof.len(), 1);
+        assert_eq!(
+            transactions_set_proof[0].transactions_hashes(),
+            test_data.transaction_hashes_to_prove
+        );
+        assert_eq!(
+            transactions_set_proof[0].merkle_root(),
+            historical_mk_map_root.to_hex()
+        );
+        transactions_set_proof[0].verify().unwrap();
+    }
+
+    #[tokio::test]
+    async fn compute_proofs_against_a_historical_snapshot_reuse_its_cached_merkle_map() {
+        let transactions = CardanoTransactionsBuilder::new()
+            .max_transactions_per_block(1)
+            .blocks_per_block_range(3)
+            .build_block_ranges(5);
+        let historical_transactions = &transactions[..9];
+        let transactions_to_prove =
+            test_data::filter_transactions_for_indices(&[1, 2, 4], &transactions);
+        let historical_test_data =
+            test_data::build_test_data(&transactions_to_prove, historical_transactions);
+        let prover = build_prover(
+            |transaction_retriever_mock| {
+                let transactions_to_prove = transactions_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_hashes()
+                    .returning(move |_, _| Ok(transactions_to_prove.clone()));
+
+                let all_transactions_in_block_ranges_to_prove =
+                    historical_test_data.all_transactions_in_block_ranges_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_block_ranges()
+                    .returning(move |_| Ok(all_transactions_in_block_ranges_to_prove.clone()));
+            },
+            |block_range_root_retriever_mock| {
+                let historical_block_ranges_map = historical_test_data.block_ranges_map.clone();
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .with(eq(historical_test_data.beacon))
+                    .times(1)
+                    .return_once(|_| {
+                        Ok(test_data::compute_mk_map_from_block_ranges_map(
+                            historical_block_ranges_map,
+                        ))
+                    });
+            },
+        );
+
+        for _ in 0..2 {
+            let transactions_set_proof = prover
+                .compute_transactions_proofs(
+                    historical_test_data.beacon,
+                    &historical_test_data.transaction_hashes_to_prove,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(transactions_set_proof.len(), 1);
+            transactions_set_proof[0].verify().unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn compute_proof_with_block_inclusion_leaves() {
+        let transactions = CardanoTransactionsBuilder::new()
+            .max_transactions_per_block(1)
+            .blocks_per_block_range(3)
+            .build_block_ranges(5);
+        let transactions_to_prove =
+            test_data::filter_transactions_for_indices(&[1, 2, 4], &transactions);
+        let mut test_data = test_data::build_test_data(&transactions_to_prove, &transactions);
+        let transaction_hashes_known = test_data.transaction_hashes_to_prove.clone();
+        test_data
+            .transaction_hashes_to_prove
+            .push("tx-unknown-123".to_string());
+        let prover = build_prover(
+            |transaction_retriever_mock| {
+                let transactions_to_prove = transactions_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_hashes()
+                    .return_once(move |_, _| Ok(transactions_to_prove));
+
+                let all_transactions_in_block_ranges_to_prove =
+                    test_data.all_transactions_in_block_ranges_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_block_ranges()
+                    .return_once(move |_| Ok(all_transactions_in_block_ranges_to_prove));
+            },
+            |block_range_root_retriever_mock| {
+                let block_ranges_map = test_data.block_ranges_map.clone();
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .return_once(|_| {
+                        Ok(
+                            test_data::compute_mk_map_from_block_ranges_map_with_leaf_format(
+                                block_ranges_map,
+                                CardanoTransactionsLeafFormat::BlockInclusion,
+                            ),
+                        )
+                    });
+            },
+        )
+        .with_leaf_format(CardanoTransactionsLeafFormat::BlockInclusion);
+        prover.compute_cache(test_data.beacon).await.unwrap();
+
+        let transactions_set_proof = prover
+            .compute_transactions_proofs(test_data.beacon, &test_data.transaction_hashes_to_prove)
+            .await
+            .unwrap();
+
+        assert_eq!(transactions_set_proof.len(), 1);
+        assert_eq!(
+            transactions_set_proof[0].transactions_hashes(),
+            transaction_hashes_known
+        );
+        assert_eq!(
+            transactions_to_prove
+                .iter()
+                .map(CardanoTransactionBlockPosition::from)
+                .collect::<Vec<_>>(),
+            transactions_set_proof[0].transactions_block_positions()
+        );
+        transactions_set_proof[0].verify().unwrap();
+    }
+
+    #[tokio::test]
+    async fn compute_proof_against_the_cached_snapshot_does_not_retrieve_block_range_roots() {
+        let transactions = CardanoTransactionsBuilder::new()
+            .max_transactions_per_block(1)
+            .blocks_per_block_range(3)
+            .build_block_ranges(5);
+        let transactions_to_prove =
+            test_data::filter_transactions_for_indices(&[1, 2, 4], &transactions);
+        let test_data = test_data::build_test_data(&transactions_to_prove, &transactions);
+        let prover = build_prover(
+            |transaction_retriever_mock| {
+                let transactions_to_prove = transactions_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_hashes()
+                    .returning(move |_, _| Ok(transactions_to_prove.clone()));
+
+                let all_transactions_in_block_ranges_to_prove =
+                    test_data.all_transactions_in_block_ranges_to_prove.clone();
+                transaction_retriever_mock
+                    .expect_get_by_block_ranges()
+                    .returning(move |_| Ok(all_transactions_in_block_ranges_to_prove.clone()));
+            },
+            |block_range_root_retriever_mock| {
+                let block_ranges_map = test_data.block_ranges_map.clone();
+                block_range_root_retriever_mock
+                    .expect_compute_merkle_map_from_block_range_roots()
+                    .with(eq(test_data.beacon))
+                    .times(1)
+                    .return_once(|_| {
+                        Ok(test_data::compute_mk_map_from_block_ranges_map(
+                            block_ranges_map,
+                        ))
+                    });
+            },
+        );
+        prover.compute_cache(test_data.beacon).await.unwrap();
+
+        for _ in 0..2 {
+            let transactions_set_proof = prover
+                .compute_transactions_proofs(
+                    test_data.beacon,
+                    &test_data.transaction_hashes_to_prove,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(transactions_set_proof.len(), 1);
+        }
+    }
+
     #[tokio::test]
     async fn cant_compute_proof_if_block_range_root_retriever_fails() {
         let transactions = CardanoTransactionsBuilder::new()
@@ -632,4 +1086,180 @@ mod tests {
             .await
             .expect_err("Should have failed because of block range root retriever failure");
     }
+
+    mod get_block_range_roots {
+        use super::*;
+
+        fn block_range_root(start: u64) -> (BlockRange, MKTreeNode) {
+            (
+                BlockRange::from_block_number(BlockNumber(start)),
+                MKTreeNode::from_hex(&format!("{start:02x}")).unwrap(),
+            )
+        }
+
+        #[tokio::test]
+        async fn returns_block_ranges_complete_at_the_given_block_number_with_transactions_of_the_last_one()
+         {
+            let last_block_range_transactions = vec![CardanoTransaction::new(
+                "tx-hash-20",
+                BlockNumber(20),
+                SlotNumber(200),
+                "block-hash-20",
+            )];
+            let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+                |transaction_retriever_mock| {
+                    let transactions = last_block_range_transactions.clone();
+                    transaction_retriever_mock
+                        .expect_get_by_block_ranges()
+                        .with(eq(vec![BlockRange::from_block_number(BlockNumber(15))]))
+                        .return_once(move |_| Ok(transactions));
+                },
+                |block_range_root_retriever_mock| {
+                    block_range_root_retriever_mock
+                        .expect_retrieve_block_range_roots()
+                        .with(eq(BlockNumber(31)))
+                        .return_once(|_| {
+                            Ok(Box::new(
+                                vec![
+                                    block_range_root(0),
+                                    block_range_root(15),
+                                    block_range_root(30),
+                                ]
+                                .into_iter(),
+                            ))
+                        });
+                },
+            );
+
+            let block_range_roots = prover
+                .get_block_range_roots(BlockNumber(31), BlockNumber(0), 10)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                CardanoTransactionsBlockRangeRoots {
+                    block_range_roots: vec![block_range_root(0), block_range_root(15)],
+                    next_page_from: None,
+                    last_block_range_transactions,
+                },
+                block_range_roots
+            );
+        }
+
+        #[tokio::test]
+        async fn returns_a_page_of_block_ranges_without_transactions_if_it_is_not_the_last_one() {
+            let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+                |transaction_retriever_mock| {
+                    transaction_retriever_mock.expect_get_by_block_ranges().never();
+                },
+                |block_range_root_retriever_mock| {
+                    block_range_root_retriever_mock
+                        .expect_retrieve_block_range_roots()
+                        .with(eq(BlockNumber(61)))
+                        .return_once(|_| {
+                            Ok(Box::new(
+                                vec![
+                                    block_range_root(0),
+                                    block_range_root(15),
+                                    block_range_root(30),
+                                    block_range_root(45),
+                                ]
+                                .into_iter(),
+                            ))
+                        });
+                },
+            );
+
+            let block_range_roots = prover
+                .get_block_range_roots(BlockNumber(61), BlockNumber(15), 2)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                CardanoTransactionsBlockRangeRoots {
+                    block_range_roots: vec![block_range_root(15), block_range_root(30)],
+                    next_page_from: Some(BlockNumber(45)),
+                    last_block_range_transactions: vec![],
+                },
+                block_range_roots
+            );
+        }
+
+        #[tokio::test]
+        async fn returns_the_last_page_of_block_ranges_with_transactions_of_the_last_one() {
+            let last_block_range_transactions = vec![CardanoTransaction::new(
+                "tx-hash-50",
+                BlockNumber(50),
+                SlotNumber(500),
+                "block-hash-50",
+            )];
+            let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+                |transaction_retriever_mock| {
+                    let transactions = last_block_range_transactions.clone();
+                    transaction_retriever_mock
+                        .expect_get_by_block_ranges()
+                        .with(eq(vec![BlockRange::from_block_number(BlockNumber(45))]))
+                        .return_once(move |_| Ok(transactions));
+                },
+                |block_range_root_retriever_mock| {
+                    block_range_root_retriever_mock
+                        .expect_retrieve_block_range_roots()
+                        .with(eq(BlockNumber(61)))
+                        .return_once(|_| {
+                            Ok(Box::new(
+                                vec![
+                                    block_range_root(0),
+                                    block_range_root(15),
+                                    block_range_root(30),
+                                    block_range_root(45),
+                                ]
+                                .into_iter(),
+                            ))
+                        });
+                },
+            );
+
+            let block_range_roots = prover
+                .get_block_range_roots(BlockNumber(61), BlockNumber(45), 2)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                CardanoTransactionsBlockRangeRoots {
+                    block_range_roots: vec![block_range_root(45)],
+                    next_page_from: None,
+                    last_block_range_transactions,
+                },
+                block_range_roots
+            );
+        }
+
+        #[tokio::test]
+        async fn returns_no_transactions_when_there_is_no_block_range_root() {
+            let prover = build_prover::<_, _, MKTreeStoreInMemory>(
+                |transaction_retriever_mock| {
+                    transaction_retriever_mock.expect_get_by_block_ranges().never();
+                },
+                |block_range_root_retriever_mock| {
+                    block_range_root_retriever_mock
+                        .expect_retrieve_block_range_roots()
+                        .return_once(|_| Ok(Box::new(vec![].into_iter())));
+                },
+            );
+
+            let block_range_roots = prover
+                .get_block_range_roots(BlockNumber(10), BlockNumber(0), 10)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                CardanoTransactionsBlockRangeRoots {
+                    block_range_roots: vec![],
+                    next_page_from: None,
+                    last_block_range_transactions: vec![],
+                },
+                block_range_roots
+            );
+        }
+    }
 }
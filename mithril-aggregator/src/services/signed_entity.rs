@@ -12,9 +12,10 @@ use tokio::task::JoinHandle;
 use mithril_common::{
     StdResult,
     entities::{
-        BlockNumber, CardanoDatabaseSnapshot, CardanoDbBeacon, CardanoStakeDistribution,
-        CardanoTransactionsSnapshot, Certificate, Epoch, MithrilStakeDistribution,
-        SignedEntityType, SignedEntityTypeDiscriminants, Snapshot,
+        BlockNumber, CardanoBlocksSnapshot, CardanoDatabaseSnapshot, CardanoDbBeacon,
+        CardanoStakeDistribution, CardanoTransactionsMetadataSnapshot, CardanoTransactionsSnapshot,
+        Certificate, Epoch, MithrilStakeDistribution, SignedEntityType,
+        SignedEntityTypeDiscriminants, Snapshot,
     },
     logging::LoggerExtensions,
     signable_builder::{Artifact, SignedEntity},
@@ -80,12 +81,35 @@ pub trait SignedEntityService: Send + Sync {
         &self,
     ) -> StdResult<Option<SignedEntity<CardanoTransactionsSnapshot>>>;
 
+    /// Return a signed Cardano Transaction Snapshot, or `None` if the signed entity with the
+    /// given id is not a Cardano Transaction Snapshot
+    async fn get_signed_cardano_transaction_snapshot_by_id(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<SignedEntity<CardanoTransactionsSnapshot>>>;
+
+    /// Return the signed Cardano Transaction Snapshot certified at the given block number
+    async fn get_signed_cardano_transaction_snapshot_by_block_number(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<Option<SignedEntity<CardanoTransactionsSnapshot>>>;
+
     /// Return a list of signed Cardano stake distribution ordered by creation
     /// date descending.
     async fn get_last_signed_cardano_stake_distributions(
         &self,
         total: usize,
     ) -> StdResult<Vec<SignedEntity<CardanoStakeDistribution>>>;
+
+    /// Return the last signed Cardano Blocks Snapshot.
+    async fn get_last_cardano_blocks_snapshot(
+        &self,
+    ) -> StdResult<Option<SignedEntity<CardanoBlocksSnapshot>>>;
+
+    /// Return the last signed Cardano Transactions Metadata Snapshot.
+    async fn get_last_cardano_transactions_metadata_snapshot(
+        &self,
+    ) -> StdResult<Option<SignedEntity<CardanoTransactionsMetadataSnapshot>>>;
 }
 
 /// Mithril ArtifactBuilder Service
@@ -103,6 +127,9 @@ pub struct MithrilSignedEntityService {
         Arc<dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>>,
     cardano_database_artifact_builder:
         Arc<dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>>,
+    cardano_blocks_artifact_builder: Arc<dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>>,
+    cardano_transactions_metadata_artifact_builder:
+        Arc<dyn ArtifactBuilder<BlockNumber, CardanoTransactionsMetadataSnapshot>>,
     metrics_service: Arc<MetricsService>,
     logger: Logger,
 }
@@ -119,6 +146,9 @@ pub struct SignedEntityServiceArtifactsDependencies {
         Arc<dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>>,
     cardano_database_artifact_builder:
         Arc<dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>>,
+    cardano_blocks_artifact_builder: Arc<dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>>,
+    cardano_transactions_metadata_artifact_builder:
+        Arc<dyn ArtifactBuilder<BlockNumber, CardanoTransactionsMetadataSnapshot>>,
 }
 
 impl SignedEntityServiceArtifactsDependencies {
@@ -139,6 +169,12 @@ impl SignedEntityServiceArtifactsDependencies {
         cardano_database_artifact_builder: Arc<
             dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>,
         >,
+        cardano_blocks_artifact_builder: Arc<
+            dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>,
+        >,
+        cardano_transactions_metadata_artifact_builder: Arc<
+            dyn ArtifactBuilder<BlockNumber, CardanoTransactionsMetadataSnapshot>,
+        >,
     ) -> Self {
         Self {
             mithril_stake_distribution_artifact_builder,
@@ -146,6 +182,8 @@ impl SignedEntityServiceArtifactsDependencies {
             cardano_transactions_artifact_builder,
             cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder,
+            cardano_transactions_metadata_artifact_builder,
         }
     }
 }
@@ -170,6 +208,9 @@ impl MithrilSignedEntityService {
             cardano_stake_distribution_artifact_builder: dependencies
                 .cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder: dependencies.cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder: dependencies.cardano_blocks_artifact_builder,
+            cardano_transactions_metadata_artifact_builder: dependencies
+                .cardano_transactions_metadata_artifact_builder,
             signed_entity_type_lock,
             metrics_service,
             logger: logger.new_with_component_name::<Self>(),
@@ -275,6 +316,26 @@ impl MithrilSignedEntityService {
                         )
                     })?
             )),
+            SignedEntityType::CardanoBlocks(_epoch, block_number) => Ok(Arc::new(
+                self.cardano_blocks_artifact_builder
+                    .compute_artifact(block_number, certificate)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Signed Entity Service can not compute artifact for entity type: '{signed_entity_type}'"
+                        )
+                    })?,
+            )),
+            SignedEntityType::CardanoTransactionsMetadata(_epoch, block_number) => Ok(Arc::new(
+                self.cardano_transactions_metadata_artifact_builder
+                    .compute_artifact(block_number, certificate)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Signed Entity Service can not compute artifact for entity type: '{signed_entity_type}'"
+                        )
+                    })?,
+            )),
         }
     }
 
@@ -314,6 +375,12 @@ impl MithrilSignedEntityService {
             SignedEntityType::CardanoDatabase(_) => {
                 metrics.get_artifact_cardano_database_total_produced_since_startup()
             }
+            SignedEntityType::CardanoBlocks(_, _) => {
+                metrics.get_artifact_cardano_blocks_total_produced_since_startup()
+            }
+            SignedEntityType::CardanoTransactionsMetadata(_, _) => {
+                metrics.get_artifact_cardano_transactions_metadata_total_produced_since_startup()
+            }
         };
 
         metric_counter.increment();
@@ -479,6 +546,51 @@ impl SignedEntityService for MithrilSignedEntityService {
         }
     }
 
+    async fn get_signed_cardano_transaction_snapshot_by_id(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<SignedEntity<CardanoTransactionsSnapshot>>> {
+        let entity = match self
+            .signed_entity_storer
+            .get_signed_entity(signed_entity_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Signed Entity Service can not get signed entity with id: '{signed_entity_id}'"
+                )
+            })? {
+            Some(entity)
+                if SignedEntityTypeDiscriminants::from(&entity.signed_entity_type)
+                    == SignedEntityTypeDiscriminants::CardanoTransactions =>
+            {
+                Some(entity.try_into()?)
+            }
+            _ => None,
+        };
+
+        Ok(entity)
+    }
+
+    async fn get_signed_cardano_transaction_snapshot_by_block_number(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<Option<SignedEntity<CardanoTransactionsSnapshot>>> {
+        let entity = match self
+            .signed_entity_storer
+            .get_cardano_transactions_signed_entity_by_block_number(block_number)
+            .await
+            .with_context(|| {
+                format!(
+                    "Signed Entity Service can not get Cardano transactions signed entity with block number: '{block_number}'"
+                )
+            })? {
+            Some(entity) => Some(entity.try_into()?),
+            None => None,
+        };
+
+        Ok(entity)
+    }
+
     async fn get_last_signed_cardano_stake_distributions(
         &self,
         total: usize,
@@ -495,6 +607,35 @@ impl SignedEntityService for MithrilSignedEntityService {
 
         Ok(signed_entities)
     }
+
+    async fn get_last_cardano_blocks_snapshot(
+        &self,
+    ) -> StdResult<Option<SignedEntity<CardanoBlocksSnapshot>>> {
+        let mut signed_entities_records = self
+            .get_last_signed_entities(1, &SignedEntityTypeDiscriminants::CardanoBlocks)
+            .await?;
+
+        match signed_entities_records.pop() {
+            Some(record) => Ok(Some(record.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn get_last_cardano_transactions_metadata_snapshot(
+        &self,
+    ) -> StdResult<Option<SignedEntity<CardanoTransactionsMetadataSnapshot>>> {
+        let mut signed_entities_records = self
+            .get_last_signed_entities(
+                1,
+                &SignedEntityTypeDiscriminants::CardanoTransactionsMetadata,
+            )
+            .await?;
+
+        match signed_entities_records.pop() {
+            Some(record) => Ok(Some(record.try_into()?)),
+            None => Ok(None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -555,6 +696,10 @@ mod tests {
             MockArtifactBuilder<Epoch, CardanoStakeDistribution>,
         mock_cardano_database_artifact_builder:
             MockArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>,
+        mock_cardano_blocks_artifact_builder:
+            MockArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>,
+        mock_cardano_transactions_metadata_artifact_builder:
+            MockArtifactBuilder<BlockNumber, CardanoTransactionsMetadataSnapshot>,
     }
 
     impl MockDependencyInjector {
@@ -581,6 +726,14 @@ mod tests {
                     CardanoDbBeacon,
                     CardanoDatabaseSnapshot,
                 >::new(),
+                mock_cardano_blocks_artifact_builder: MockArtifactBuilder::<
+                    BlockNumber,
+                    CardanoBlocksSnapshot,
+                >::new(),
+                mock_cardano_transactions_metadata_artifact_builder: MockArtifactBuilder::<
+                    BlockNumber,
+                    CardanoTransactionsMetadataSnapshot,
+                >::new(),
             }
         }
 
@@ -591,6 +744,8 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
                 Arc::new(self.mock_cardano_database_artifact_builder),
+                Arc::new(self.mock_cardano_blocks_artifact_builder),
+                Arc::new(self.mock_cardano_transactions_metadata_artifact_builder),
             );
             MithrilSignedEntityService::new(
                 Arc::new(self.mock_signed_entity_storer),
@@ -648,6 +803,8 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
                 Arc::new(self.mock_cardano_database_artifact_builder),
+                Arc::new(self.mock_cardano_blocks_artifact_builder),
+                Arc::new(self.mock_cardano_transactions_metadata_artifact_builder),
             );
             MithrilSignedEntityService::new(
                 Arc::new(self.mock_signed_entity_storer),
@@ -712,6 +869,12 @@ mod tests {
             SignedEntityType::CardanoDatabase(_) => metrics_service
                 .get_artifact_cardano_database_total_produced_since_startup()
                 .get(),
+            SignedEntityType::CardanoBlocks(_, _) => metrics_service
+                .get_artifact_cardano_blocks_total_produced_since_startup()
+                .get(),
+            SignedEntityType::CardanoTransactionsMetadata(_, _) => metrics_service
+                .get_artifact_cardano_transactions_metadata_total_produced_since_startup()
+                .get(),
         }
     }
 
@@ -872,6 +1035,28 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn should_store_the_artifact_when_creating_artifact_for_cardano_blocks() {
+        let block_number = BlockNumber(149);
+        generic_test_that_the_artifact_is_stored(
+            SignedEntityType::CardanoBlocks(Epoch(1), block_number),
+            CardanoBlocksSnapshot::new("merkle_root".to_string(), block_number),
+            &|mock_injector| &mut mock_injector.mock_cardano_blocks_artifact_builder,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn should_store_the_artifact_when_creating_artifact_for_cardano_transactions_metadata() {
+        let block_number = BlockNumber(149);
+        generic_test_that_the_artifact_is_stored(
+            SignedEntityType::CardanoTransactionsMetadata(Epoch(1), block_number),
+            CardanoTransactionsMetadataSnapshot::new("merkle_root".to_string(), block_number),
+            &|mock_injector| &mut mock_injector.mock_cardano_transactions_metadata_artifact_builder,
+        )
+        .await;
+    }
+
     #[tokio::test]
     async fn build_cardano_database_artifact_when_given_cardano_database_entity_type() {
         let mut mock_container = MockDependencyInjector::new();
@@ -964,6 +1149,62 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn get_signed_cardano_transaction_snapshot_by_id_return_the_cardano_transactions_snapshot()
+     {
+        let snapshot = CardanoTransactionsSnapshot::new("mk-root".to_string(), BlockNumber(50));
+        let record = SignedEntityRecord {
+            signed_entity_id: snapshot.hash.clone(),
+            signed_entity_type: SignedEntityType::CardanoTransactions(Epoch(2), BlockNumber(50)),
+            certificate_id: "certificate-1".to_string(),
+            artifact: serde_json::to_string(&snapshot).unwrap(),
+            created_at: Utc::now(),
+        };
+        let mut mock_container = MockDependencyInjector::new();
+        mock_container
+            .mock_signed_entity_storer
+            .expect_get_signed_entity()
+            .return_once(|_| Ok(Some(record)));
+        let artifact_builder_service = mock_container.build_artifact_builder_service();
+
+        let signed_entity = artifact_builder_service
+            .get_signed_cardano_transaction_snapshot_by_id(&snapshot.hash)
+            .await
+            .unwrap()
+            .expect("A Cardano transactions snapshot should be returned");
+
+        assert_eq!(snapshot, signed_entity.artifact);
+    }
+
+    #[tokio::test]
+    async fn get_signed_cardano_transaction_snapshot_by_id_return_none_for_another_signed_entity_type()
+     {
+        let snapshot = CardanoBlocksSnapshot::new("mk-root".to_string(), BlockNumber(50));
+        let record = SignedEntityRecord {
+            signed_entity_id: snapshot.hash.clone(),
+            signed_entity_type: SignedEntityType::CardanoBlocks(Epoch(2), BlockNumber(50)),
+            certificate_id: "certificate-1".to_string(),
+            artifact: serde_json::to_string(&snapshot).unwrap(),
+            created_at: Utc::now(),
+        };
+        let mut mock_container = MockDependencyInjector::new();
+        mock_container
+            .mock_signed_entity_storer
+            .expect_get_signed_entity()
+            .return_once(|_| Ok(Some(record)));
+        let artifact_builder_service = mock_container.build_artifact_builder_service();
+
+        let signed_entity = artifact_builder_service
+            .get_signed_cardano_transaction_snapshot_by_id(&snapshot.hash)
+            .await
+            .unwrap();
+
+        assert!(
+            signed_entity.is_none(),
+            "A Cardano blocks snapshot should not be returned as a Cardano transactions snapshot"
+        );
+    }
+
     #[tokio::test]
     async fn create_artifact_for_two_signed_entity_types_in_sequence_not_blocking() {
         let atomic_stop = Arc::new(AtomicBool::new(false));
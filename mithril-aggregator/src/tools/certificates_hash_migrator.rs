@@ -301,6 +301,12 @@ mod test {
                             beacon.epoch, beacon.immutable_file_number
                         )
                     }
+                    SignedEntityType::CardanoBlocks(epoch, block_number) => {
+                        format!("cardano-blocks-{epoch}-{block_number}",)
+                    }
+                    SignedEntityType::CardanoTransactionsMetadata(epoch, block_number) => {
+                        format!("cardano-transactions-metadata-{epoch}-{block_number}",)
+                    }
                 };
 
                 let signed_entity_record = SignedEntityRecord {
@@ -31,6 +31,41 @@ impl EraTools {
         maybe_next_era_epoch: Option<Epoch>,
         era_markers_signer: &EraMarkersSigner,
     ) -> EraToolsResult<String> {
+        let era_markers_payload = self.generate_signed_era_markers_payload(
+            current_era_epoch,
+            maybe_next_era_epoch,
+            era_markers_signer,
+        )?;
+
+        let tx_datum = TxDatumBuilder::new()
+            .add_field(TxDatumFieldValue::Bytes(era_markers_payload.to_json_hex()?))
+            .build()?;
+        Ok(tx_datum.0)
+    }
+
+    /// Generate the JSON era markers payload, to be published for the HTTP era reader adapter,
+    /// with sanity check of epochs
+    pub fn generate_markers_payload(
+        &self,
+        current_era_epoch: Epoch,
+        maybe_next_era_epoch: Option<Epoch>,
+        era_markers_signer: &EraMarkersSigner,
+    ) -> EraToolsResult<String> {
+        let era_markers_payload = self.generate_signed_era_markers_payload(
+            current_era_epoch,
+            maybe_next_era_epoch,
+            era_markers_signer,
+        )?;
+
+        Ok(serde_json::to_string(&era_markers_payload)?)
+    }
+
+    fn generate_signed_era_markers_payload(
+        &self,
+        current_era_epoch: Epoch,
+        maybe_next_era_epoch: Option<Epoch>,
+        era_markers_signer: &EraMarkersSigner,
+    ) -> EraToolsResult<EraMarkersPayloadCardanoChain> {
         if maybe_next_era_epoch.is_some()
             && maybe_next_era_epoch.unwrap_or_default() <= current_era_epoch
         {
@@ -54,10 +89,7 @@ impl EraTools {
         }
         .sign(era_markers_signer)?;
 
-        let tx_datum = TxDatumBuilder::new()
-            .add_field(TxDatumFieldValue::Bytes(era_markers_payload.to_json_hex()?))
-            .build()?;
-        Ok(tx_datum.0)
+        Ok(era_markers_payload)
     }
 
     /// Export the era keypair to a folder and returns the paths to the files (secret key, verification_key)
@@ -119,6 +151,30 @@ mod tests {
             .expect_err("generate_tx_datum should have failed");
     }
 
+    #[test]
+    fn generate_markers_payload_signed_with_given_signer() {
+        let era_markers_signer = EraMarkersSigner::create_deterministic_signer();
+        let era_tools = build_tools();
+        let payload_json = era_tools
+            .generate_markers_payload(Epoch(1), Some(Epoch(5)), &era_markers_signer)
+            .expect("generate_markers_payload should not fail");
+
+        let payload: EraMarkersPayloadCardanoChain =
+            serde_json::from_str(&payload_json).expect("payload should be valid JSON");
+        payload
+            .verify_signature(era_markers_signer.create_verifier().to_verification_key())
+            .expect("payload signature should be valid");
+    }
+
+    #[test]
+    fn generate_markers_payload_wrong_epochs() {
+        let era_markers_signer = EraMarkersSigner::create_deterministic_signer();
+        let era_tools = build_tools();
+        let _ = era_tools
+            .generate_markers_payload(Epoch(3), Some(Epoch(2)), &era_markers_signer)
+            .expect_err("generate_markers_payload should have failed");
+    }
+
     #[test]
     fn test_create_and_save_era_keypair() {
         let temp_dir = get_temp_dir("test_create_and_save_era_keypair");
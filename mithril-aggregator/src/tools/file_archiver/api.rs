@@ -1,11 +1,14 @@
 use anyhow::{Context, anyhow};
 use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use liblzma::{read::XzDecoder, write::XzEncoder};
+use rayon::ThreadPool;
 use slog::{Logger, info, warn};
 use std::{
     fs,
     fs::File,
     io::{Read, Seek, SeekFrom},
     path::{Path, PathBuf},
+    sync::Arc,
 };
 use tar::{Archive, Entry, EntryType};
 use zstd::{Decoder, Encoder};
@@ -14,15 +17,22 @@ use mithril_common::StdResult;
 use mithril_common::entities::CompressionAlgorithm;
 use mithril_common::logging::LoggerExtensions;
 
-use crate::ZstandardCompressionParameters;
 use crate::tools::file_size;
+use crate::{GzipCompressionParameters, ZstandardCompressionParameters};
 
 use super::appender::TarAppender;
+use super::parallel_gzip::{ParallelGzEncoder, build_gzip_workers_pool};
 use super::{ArchiveParameters, FileArchive};
 
+/// Compression level used for the [Xz][CompressionAlgorithm::Xz] archives.
+const XZ_COMPRESSION_LEVEL: u32 = 6;
+
 /// Tool to archive files and directories.
 pub struct FileArchiver {
     zstandard_compression_parameter: ZstandardCompressionParameters,
+    gzip_compression_parameter: GzipCompressionParameters,
+    // Pool of workers shared by all the multithreaded gzip archives, none if multithreading is disabled
+    gzip_workers_pool: Option<Arc<ThreadPool>>,
     // Temporary directory to  the unpacked archive for verification
     verification_temp_dir: PathBuf,
     logger: Logger,
@@ -32,14 +42,17 @@ impl FileArchiver {
     /// Constructs a new `FileArchiver`.
     pub fn new(
         zstandard_compression_parameter: ZstandardCompressionParameters,
+        gzip_compression_parameter: GzipCompressionParameters,
         verification_temp_dir: PathBuf,
         logger: Logger,
-    ) -> Self {
-        Self {
+    ) -> StdResult<Self> {
+        Ok(Self {
             zstandard_compression_parameter,
+            gzip_compression_parameter,
+            gzip_workers_pool: Self::build_gzip_workers_pool(&gzip_compression_parameter)?,
             verification_temp_dir,
             logger: logger.new_with_component_name::<Self>(),
-        }
+        })
     }
 
     #[cfg(test)]
@@ -47,11 +60,34 @@ impl FileArchiver {
         use crate::test_tools::TestLogger;
         Self {
             zstandard_compression_parameter: ZstandardCompressionParameters::default(),
+            gzip_compression_parameter: GzipCompressionParameters::default(),
+            gzip_workers_pool: Self::build_gzip_workers_pool(&GzipCompressionParameters::default())
+                .unwrap(),
             verification_temp_dir,
             logger: TestLogger::stdout(),
         }
     }
 
+    #[cfg(test)]
+    pub fn with_gzip_compression_parameter(
+        mut self,
+        gzip_compression_parameter: GzipCompressionParameters,
+    ) -> Self {
+        self.gzip_compression_parameter = gzip_compression_parameter;
+        self.gzip_workers_pool =
+            Self::build_gzip_workers_pool(&gzip_compression_parameter).unwrap();
+        self
+    }
+
+    fn build_gzip_workers_pool(
+        gzip_compression_parameter: &GzipCompressionParameters,
+    ) -> StdResult<Option<Arc<ThreadPool>>> {
+        match gzip_compression_parameter.number_of_workers {
+            0 => Ok(None),
+            number_of_workers => Ok(Some(Arc::new(build_gzip_workers_pool(number_of_workers)?))),
+        }
+    }
+
     /// Archive the content of a directory.
     pub fn archive<T: TarAppender>(
         &self,
@@ -143,8 +179,30 @@ impl FileArchiver {
         })?;
 
         match compression_algorithm {
+            CompressionAlgorithm::Gzip if self.gzip_workers_pool.is_some() => {
+                let enc = ParallelGzEncoder::new(
+                    tar_file,
+                    Compression::new(self.gzip_compression_parameter.level),
+                    self.gzip_workers_pool.clone().unwrap(),
+                )?;
+                let mut tar = tar::Builder::new(enc);
+
+                appender
+                    .append(&mut tar)
+                    .with_context(|| "ParallelGzEncoder Builder failed to append content")?;
+
+                let gz = tar
+                    .into_inner()
+                    .with_context(|| "ParallelGzEncoder Builder can not write the archive")?;
+                gz.finish().with_context(
+                    || "ParallelGzEncoder can not finish the output stream after writing",
+                )?;
+            }
             CompressionAlgorithm::Gzip => {
-                let enc = GzEncoder::new(tar_file, Compression::default());
+                let enc = GzEncoder::new(
+                    tar_file,
+                    Compression::new(self.gzip_compression_parameter.level),
+                );
                 let mut tar = tar::Builder::new(enc);
 
                 appender
@@ -174,6 +232,34 @@ impl FileArchiver {
                     || "ZstandardEncoder can not finish the output stream after writing",
                 )?;
             }
+            CompressionAlgorithm::Lz4 => {
+                let enc = lz4_flex::frame::FrameEncoder::new(tar_file);
+                let mut tar = tar::Builder::new(enc);
+
+                appender
+                    .append(&mut tar)
+                    .with_context(|| "Lz4Encoder Builder failed to append content")?;
+
+                let lz4 = tar
+                    .into_inner()
+                    .with_context(|| "Lz4Encoder Builder can not write the archive")?;
+                lz4.finish()
+                    .with_context(|| "Lz4Encoder can not finish the output stream after writing")?;
+            }
+            CompressionAlgorithm::Xz => {
+                let enc = XzEncoder::new(tar_file, XZ_COMPRESSION_LEVEL);
+                let mut tar = tar::Builder::new(enc);
+
+                appender
+                    .append(&mut tar)
+                    .with_context(|| "XzEncoder Builder failed to append content")?;
+
+                let xz = tar
+                    .into_inner()
+                    .with_context(|| "XzEncoder Builder can not write the archive")?;
+                xz.finish()
+                    .with_context(|| "XzEncoder can not finish the output stream after writing")?;
+            }
         }
 
         let uncompressed_size = appender.compute_uncompressed_data_size().with_context(|| {
@@ -223,6 +309,14 @@ impl FileArchiver {
                 let archive_decoder = Decoder::new(archive_file_tar)?;
                 Archive::new(Box::new(archive_decoder))
             }
+            CompressionAlgorithm::Lz4 => {
+                let archive_decoder = lz4_flex::frame::FrameDecoder::new(archive_file_tar);
+                Archive::new(Box::new(archive_decoder))
+            }
+            CompressionAlgorithm::Xz => {
+                let archive_decoder = XzDecoder::new(archive_file_tar);
+                Archive::new(Box::new(archive_decoder))
+            }
         };
 
         let unpack_temp_dir = self
@@ -327,6 +421,33 @@ mod tests {
             .expect("verify_archive should not fail");
     }
 
+    #[test]
+    fn should_create_a_valid_archive_with_single_threaded_gzip_compression() {
+        let test_dir = get_test_directory(
+            "should_create_a_valid_archive_with_single_threaded_gzip_compression",
+        );
+        let target_archive = test_dir.join("archive.tar.gz");
+        let archived_directory = test_dir.join(create_dir(&test_dir, "archived_directory"));
+        create_file(&archived_directory, "file_to_archive.txt");
+
+        let file_archiver = FileArchiver::new_for_test(test_dir.join("verification"))
+            .with_gzip_compression_parameter(GzipCompressionParameters {
+                level: 6,
+                number_of_workers: 0,
+            });
+
+        let archive = file_archiver
+            .create_archive(
+                &target_archive,
+                AppenderDirAll::new(archived_directory),
+                CompressionAlgorithm::Gzip,
+            )
+            .expect("create_archive should not fail");
+        file_archiver
+            .verify_archive(&archive)
+            .expect("verify_archive should not fail");
+    }
+
     #[test]
     fn should_create_a_valid_archive_with_zstandard_compression() {
         let test_dir =
@@ -349,6 +470,48 @@ mod tests {
             .expect("verify_archive should not fail");
     }
 
+    #[test]
+    fn should_create_a_valid_archive_with_lz4_compression() {
+        let test_dir = get_test_directory("should_create_a_valid_archive_with_lz4_compression");
+        let target_archive = test_dir.join("archive.tar.lz4");
+        let archived_directory = test_dir.join(create_dir(&test_dir, "archived_directory"));
+        create_file(&archived_directory, "file_to_archive.txt");
+
+        let file_archiver = FileArchiver::new_for_test(test_dir.join("verification"));
+
+        let archive = file_archiver
+            .create_archive(
+                &target_archive,
+                AppenderDirAll::new(archived_directory),
+                CompressionAlgorithm::Lz4,
+            )
+            .expect("create_archive should not fail");
+        file_archiver
+            .verify_archive(&archive)
+            .expect("verify_archive should not fail");
+    }
+
+    #[test]
+    fn should_create_a_valid_archive_with_xz_compression() {
+        let test_dir = get_test_directory("should_create_a_valid_archive_with_xz_compression");
+        let target_archive = test_dir.join("archive.tar.xz");
+        let archived_directory = test_dir.join(create_dir(&test_dir, "archived_directory"));
+        create_file(&archived_directory, "file_to_archive.txt");
+
+        let file_archiver = FileArchiver::new_for_test(test_dir.join("verification"));
+
+        let archive = file_archiver
+            .create_archive(
+                &target_archive,
+                AppenderDirAll::new(archived_directory),
+                CompressionAlgorithm::Xz,
+            )
+            .expect("create_archive should not fail");
+        file_archiver
+            .verify_archive(&archive)
+            .expect("verify_archive should not fail");
+    }
+
     #[test]
     fn should_delete_tmp_file_in_target_directory_if_archiving_fail() {
         let test_dir =
@@ -1,6 +1,7 @@
 mod api;
 pub mod appender;
 mod entities;
+mod parallel_gzip;
 
 pub use api::*;
 pub use entities::*;
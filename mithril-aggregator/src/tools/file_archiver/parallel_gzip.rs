@@ -0,0 +1,253 @@
+use anyhow::{Context, anyhow};
+use flate2::{Compress, Compression, Crc, FlushCompress};
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::io::{self, Write};
+use std::sync::Arc;
+
+use mithril_common::StdResult;
+
+/// Size of the blocks of uncompressed data compressed independently by the workers.
+const DEFAULT_BLOCK_SIZE: usize = 1024 * 1024;
+
+/// Gzip header with no file name, no modification time and an unknown operating system.
+const GZIP_HEADER: [u8; 10] = [0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff];
+
+/// Empty final deflate block using fixed Huffman codes, terminates the deflate stream.
+const DEFLATE_FINAL_EMPTY_BLOCK: [u8; 2] = [0x03, 0x00];
+
+/// Build the pool of workers used by the [ParallelGzEncoder].
+///
+/// The pool is meant to be built once and shared by all the encoders.
+pub fn build_gzip_workers_pool(number_of_workers: u32) -> StdResult<ThreadPool> {
+    if number_of_workers == 0 {
+        return Err(anyhow!("ParallelGzEncoder requires at least one worker"));
+    }
+
+    ThreadPoolBuilder::new()
+        .num_threads(number_of_workers as usize)
+        .thread_name(|index| format!("gzip-worker-{index}"))
+        .build()
+        .with_context(|| "ParallelGzEncoder can not build its workers pool")
+}
+
+/// Gzip encoder compressing blocks of data in parallel (as done by `pigz`).
+///
+/// The data written is split in blocks that are deflated independently by a pool of workers.
+/// Each block is ended by a sync flush so the compressed blocks are byte aligned and can be
+/// concatenated into a single deflate stream, the output is a standard single member gzip stream
+/// that can be read by any gzip decoder.
+pub struct ParallelGzEncoder<W: Write> {
+    writer: W,
+    compression: Compression,
+    block_size: usize,
+    thread_pool: Arc<ThreadPool>,
+    number_of_workers: usize,
+    pending_data: Vec<u8>,
+    crc: Crc,
+}
+
+impl<W: Write> ParallelGzEncoder<W> {
+    /// Create a new `ParallelGzEncoder` writing the compressed stream to the given writer,
+    /// compressing the blocks with the given pool of workers.
+    pub fn new(
+        writer: W,
+        compression: Compression,
+        thread_pool: Arc<ThreadPool>,
+    ) -> StdResult<Self> {
+        Self::with_block_size(writer, compression, thread_pool, DEFAULT_BLOCK_SIZE)
+    }
+
+    fn with_block_size(
+        mut writer: W,
+        compression: Compression,
+        thread_pool: Arc<ThreadPool>,
+        block_size: usize,
+    ) -> StdResult<Self> {
+        let number_of_workers = thread_pool.current_num_threads();
+        writer
+            .write_all(&GZIP_HEADER)
+            .with_context(|| "ParallelGzEncoder can not write the gzip header")?;
+
+        Ok(Self {
+            writer,
+            compression,
+            block_size,
+            thread_pool,
+            number_of_workers,
+            pending_data: Vec::with_capacity(block_size * number_of_workers),
+            crc: Crc::new(),
+        })
+    }
+
+    /// Compress the remaining data and write the end of the gzip stream.
+    ///
+    /// Returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.compress_pending_data()?;
+        let mut writer = self.writer;
+        writer.write_all(&DEFLATE_FINAL_EMPTY_BLOCK)?;
+        writer.write_all(&self.crc.sum().to_le_bytes())?;
+        writer.write_all(&self.crc.amount().to_le_bytes())?;
+        writer.flush()?;
+
+        Ok(writer)
+    }
+
+    fn compress_pending_data(&mut self) -> io::Result<()> {
+        if self.pending_data.is_empty() {
+            return Ok(());
+        }
+
+        self.crc.update(&self.pending_data);
+        let compression = self.compression;
+        let blocks = &self.pending_data;
+        let block_size = self.block_size;
+        let compressed_blocks = self.thread_pool.install(|| {
+            blocks
+                .par_chunks(block_size)
+                .map(|block| deflate_block(block, compression))
+                .collect::<io::Result<Vec<_>>>()
+        })?;
+
+        for compressed_block in compressed_blocks {
+            self.writer.write_all(&compressed_block)?;
+        }
+        self.pending_data.clear();
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for ParallelGzEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let batch_size = self.block_size * self.number_of_workers;
+        let writable_len = buf.len().min(batch_size - self.pending_data.len());
+        self.pending_data.extend_from_slice(&buf[..writable_len]);
+
+        if self.pending_data.len() >= batch_size {
+            self.compress_pending_data()?;
+        }
+
+        Ok(writable_len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Deflate a block of data, ending it with a sync flush so it can be concatenated with
+/// the other compressed blocks.
+fn deflate_block(block: &[u8], compression: Compression) -> io::Result<Vec<u8>> {
+    let mut compressor = Compress::new(compression, false);
+    let mut output = Vec::with_capacity(block.len() / 2 + 64);
+    let mut input = block;
+
+    loop {
+        if output.len() == output.capacity() {
+            output.reserve(block.len() / 4 + 64);
+        }
+        let total_in_before = compressor.total_in();
+        compressor
+            .compress_vec(input, &mut output, FlushCompress::Sync)
+            .map_err(io::Error::other)?;
+        let consumed = (compressor.total_in() - total_in_before) as usize;
+        input = &input[consumed..];
+
+        // The flush is complete when all the input is consumed and the output was not full
+        if input.is_empty() && output.len() < output.capacity() {
+            return Ok(output);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    use super::*;
+
+    fn compress_with_pool(data: &[u8], thread_pool: Arc<ThreadPool>, block_size: usize) -> Vec<u8> {
+        let mut encoder = ParallelGzEncoder::with_block_size(
+            Vec::new(),
+            Compression::default(),
+            thread_pool,
+            block_size,
+        )
+        .unwrap();
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn compress(data: &[u8], number_of_workers: u32, block_size: usize) -> Vec<u8> {
+        let thread_pool = Arc::new(build_gzip_workers_pool(number_of_workers).unwrap());
+        compress_with_pool(data, thread_pool, block_size)
+    }
+
+    fn decompress(compressed: &[u8]) -> Vec<u8> {
+        let mut decompressed = Vec::new();
+        GzDecoder::new(compressed).read_to_end(&mut decompressed).unwrap();
+        decompressed
+    }
+
+    fn sample_data(size: usize) -> Vec<u8> {
+        (0..size).map(|i| ((i * 7) % 251) as u8).collect()
+    }
+
+    #[test]
+    fn compressed_stream_can_be_read_by_a_standard_gzip_decoder() {
+        let data = sample_data(10_000);
+
+        let compressed = compress(&data, 4, 1024);
+
+        assert_eq!(data, decompress(&compressed));
+    }
+
+    #[test]
+    fn compress_data_smaller_than_a_block() {
+        let data = sample_data(100);
+
+        let compressed = compress(&data, 2, 1024);
+
+        assert_eq!(data, decompress(&compressed));
+    }
+
+    #[test]
+    fn compress_empty_data() {
+        let compressed = compress(&[], 2, 1024);
+
+        assert_eq!(Vec::<u8>::new(), decompress(&compressed));
+    }
+
+    #[test]
+    fn compressed_stream_is_the_same_whatever_the_number_of_workers() {
+        let data = sample_data(10_000);
+
+        assert_eq!(compress(&data, 1, 1024), compress(&data, 3, 1024));
+    }
+
+    #[test]
+    fn workers_pool_can_be_shared_by_several_encoders() {
+        let thread_pool = Arc::new(build_gzip_workers_pool(2).unwrap());
+        let data = sample_data(10_000);
+        let other_data = sample_data(5_000);
+
+        let compressed = compress_with_pool(&data, thread_pool.clone(), 1024);
+        let other_compressed = compress_with_pool(&other_data, thread_pool, 1024);
+
+        assert_eq!(data, decompress(&compressed));
+        assert_eq!(other_data, decompress(&other_compressed));
+    }
+
+    #[test]
+    fn fail_to_build_workers_pool_without_workers() {
+        let result = build_gzip_workers_pool(0);
+
+        assert!(
+            result.is_err(),
+            "Building a ParallelGzEncoder workers pool without worker should fail"
+        );
+    }
+}
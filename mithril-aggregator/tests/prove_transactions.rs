@@ -1,9 +1,12 @@
 use mithril_aggregator::ServeCommandConfiguration;
 use mithril_common::{
     entities::{
-        BlockNumber, CardanoTransactionsSigningConfig, ChainPoint, Epoch, ProtocolMessagePartKey,
-        ProtocolParameters, SignedEntityType, SignedEntityTypeDiscriminants, SlotNumber, TimePoint,
+        BlockNumber, CardanoTransactionBlockPosition, CardanoTransactionsLeafFormat,
+        CardanoTransactionsSigningConfig, ChainPoint, Epoch, ProtocolMessage,
+        ProtocolMessagePartKey, ProtocolParameters, SignedEntityType,
+        SignedEntityTypeDiscriminants, SlotNumber, TimePoint,
     },
+    messages::CardanoTransactionsProofsMessage,
     temp_dir,
     test_utils::MithrilFixtureBuilder,
 };
@@ -150,3 +153,136 @@ async fn prove_transactions() {
             .artifact_cardano_transaction_total(1)
     );
 }
+
+#[tokio::test]
+async fn prove_transactions_with_block_inclusion_leaves() {
+    let protocol_parameters = ProtocolParameters {
+        k: 5,
+        m: 150,
+        phi_f: 0.95,
+    };
+    let configuration = ServeCommandConfiguration {
+        protocol_parameters: protocol_parameters.clone(),
+        signed_entity_types: Some(SignedEntityTypeDiscriminants::CardanoTransactions.to_string()),
+        data_stores_directory: get_test_dir("prove_transactions_with_block_inclusion_leaves"),
+        cardano_transactions_signing_config: CardanoTransactionsSigningConfig {
+            security_parameter: BlockNumber(0),
+            step: BlockNumber(30),
+        },
+        cardano_transactions_leaf_format: CardanoTransactionsLeafFormat::BlockInclusion,
+        ..ServeCommandConfiguration::new_sample(temp_dir!())
+    };
+    let mut tester = RuntimeTester::build(
+        TimePoint {
+            epoch: Epoch(1),
+            immutable_file_number: 1,
+            chain_point: ChainPoint {
+                slot_number: SlotNumber(10),
+                block_number: BlockNumber(100),
+                block_hash: "block_hash-100".to_string(),
+            },
+        },
+        configuration,
+    )
+    .await;
+    let observer = tester.observer.clone();
+    let prover = tester.dependencies.prover_service.clone();
+
+    comment!("create signers & declare stake distribution");
+    let fixture = MithrilFixtureBuilder::default()
+        .with_signers(10)
+        .with_protocol_parameters(protocol_parameters.clone())
+        .build();
+    let signers = &fixture.signers_fixture();
+
+    tester.init_state_from_fixture(&fixture).await.unwrap();
+
+    comment!("Bootstrap the genesis certificate");
+    tester.register_genesis_certificate(&fixture).await.unwrap();
+
+    // Lock all signed entity types except CardanoTransactions to limit the scope of the test
+    for entity in SignedEntityTypeDiscriminants::all()
+        .into_iter()
+        .filter(|e| e != &SignedEntityTypeDiscriminants::CardanoTransactions)
+    {
+        tester.dependencies.signed_entity_type_lock.lock(entity).await;
+    }
+
+    comment!("register signers");
+    cycle!(tester, "ready");
+    tester.register_signers(&fixture.signers_fixture()).await.unwrap();
+
+    comment!(
+        "Increase cardano chain block number to 185, 
+        the state machine should be signing CardanoTransactions up to block 179 included"
+    );
+    tester
+        .increase_block_number_and_slot_number(85, SlotNumber(95), BlockNumber(185))
+        .await
+        .unwrap();
+    cycle!(tester, "signing");
+    tester
+        .send_single_signatures(SignedEntityTypeDiscriminants::CardanoTransactions, signers)
+        .await
+        .unwrap();
+
+    comment!("The state machine should issue a certificate for the CardanoTransactions");
+    cycle!(tester, "ready");
+    assert_last_certificate_eq!(
+        tester,
+        ExpectedCertificate::new(
+            Epoch(1),
+            &signers
+                .iter()
+                .map(|s| s.signer_with_stake.clone().into())
+                .collect::<Vec<_>>(),
+            fixture.compute_and_encode_avk(),
+            SignedEntityType::CardanoTransactions(Epoch(1), BlockNumber(179)),
+            ExpectedCertificate::genesis_identifier(Epoch(1)),
+        )
+    );
+
+    comment!("Get the proofs of two transactions and verify them as a client would");
+    let transactions_hashes = vec![tx_hash(120, 1), tx_hash(179, 1)];
+    let last_tx_snapshot = observer.get_last_cardano_transactions_snapshot().await.unwrap();
+    let proofs = prover
+        .compute_transactions_proofs(last_tx_snapshot.artifact.block_number, &transactions_hashes)
+        .await
+        .unwrap();
+    let proof_certificate = observer.get_last_certificate().await.unwrap();
+    assert_eq!(&last_tx_snapshot.certificate_id, &proof_certificate.hash);
+    let message = CardanoTransactionsProofsMessage::new(
+        &proof_certificate.hash,
+        proofs.into_iter().map(|p| p.try_into().unwrap()).collect(),
+        vec![],
+        last_tx_snapshot.artifact.block_number,
+    );
+
+    let verified_transactions = message.verify().unwrap();
+    assert_eq!(
+        vec![
+            CardanoTransactionBlockPosition {
+                transaction_hash: tx_hash(120, 1),
+                block_number: BlockNumber(120),
+                block_hash: hex::encode("block_hash-120"),
+            },
+            CardanoTransactionBlockPosition {
+                transaction_hash: tx_hash(179, 1),
+                block_number: BlockNumber(179),
+                block_hash: hex::encode("block_hash-179"),
+            },
+        ],
+        verified_transactions.certified_block_positions()
+    );
+
+    comment!("The verified transactions should match the message signed by the certificate");
+    let mut protocol_message = ProtocolMessage::new();
+    verified_transactions.fill_protocol_message(&mut protocol_message);
+    assert_eq!(
+        proof_certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::CardanoTransactionsMerkleRoot),
+        protocol_message.get_message_part(&ProtocolMessagePartKey::CardanoTransactionsMerkleRoot),
+        "The proofs merkle root should match the one in the certificate"
+    );
+}
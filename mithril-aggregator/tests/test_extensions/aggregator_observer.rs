@@ -136,6 +136,22 @@ impl AggregatorObserver {
                     .await?
                     .map(|s| s.signed_entity_type)
                     .as_ref()),
+            SignedEntityType::CardanoBlocks(_, _) => Ok(Some(signed_entity_type_expected)
+                == self
+                    .signed_entity_service
+                    .get_last_cardano_blocks_snapshot()
+                    .await?
+                    .map(|s| s.signed_entity_type)
+                    .as_ref()),
+            SignedEntityType::CardanoTransactionsMetadata(_, _) => {
+                Ok(Some(signed_entity_type_expected)
+                    == self
+                        .signed_entity_service
+                        .get_last_cardano_transactions_metadata_snapshot()
+                        .await?
+                        .map(|s| s.signed_entity_type)
+                        .as_ref())
+            }
             SignedEntityType::CardanoStakeDistribution(_) => Ok(Some(signed_entity_type_expected)
                 == self
                     .signed_entity_service
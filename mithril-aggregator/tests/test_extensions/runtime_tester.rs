@@ -6,8 +6,9 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use mithril_aggregator::{
-    AggregatorRuntime, ConfigurationSource, DumbUploader, MetricsService,
-    ServeCommandConfiguration, ServeCommandDependenciesContainer, SignerRegistrationError,
+    AggregatorRuntime, ConfigurationSource, DumbUploader, MetricsService, MirrorUploadPolicy,
+    MirrorUploader, ServeCommandConfiguration, ServeCommandDependenciesContainer,
+    SignerRegistrationError,
     database::{record::SignedEntityRecord, repository::OpenMessageRepository},
     dependency_injection::DependenciesBuilder,
     services::FakeSnapshotter,
@@ -177,7 +178,14 @@ impl RuntimeTester {
             )]));
         let block_scanner = Arc::new(DumbBlockScanner::new());
         let mut deps_builder = DependenciesBuilder::new(logger.clone(), Arc::new(configuration));
-        deps_builder.snapshot_uploader = Some(snapshot_uploader.clone());
+        deps_builder.snapshot_uploader = Some(Arc::new(
+            MirrorUploader::new(
+                vec![snapshot_uploader.clone()],
+                MirrorUploadPolicy::AtLeastOne,
+                logger.clone(),
+            )
+            .unwrap(),
+        ));
         deps_builder.chain_observer = Some(chain_observer.clone());
         deps_builder.immutable_file_observer = Some(immutable_file_observer.clone());
         deps_builder.immutable_digester = Some(digester.clone());
@@ -0,0 +1,255 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{Context, anyhow};
+use clap::Parser;
+use mithril_client::{MithrilResult, cardano_database_client::CardanoDatabaseAuditReport};
+
+use crate::{
+    CommandContext,
+    commands::{cardano_db::shared_steps, client_builder},
+    configuration::{ConfigError, ConfigSource},
+    utils::{self, ExpanderUtils, IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter},
+};
+
+/// Clap command to audit an existing Cardano node database against a certified Cardano db
+/// snapshot, without downloading it.
+#[derive(Parser, Debug, Clone)]
+pub struct CardanoDbAuditCommand {
+    /// Digest of the Cardano db snapshot to audit against or `latest` for the latest artifact
+    ///
+    /// Use the `list` command to get that information.
+    digest: String,
+
+    /// Directory of the Cardano node database to audit.
+    #[clap(long)]
+    db_dir: Option<PathBuf>,
+
+    /// Genesis verification key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+
+    /// Path of the signed manifest of the ancillary files, if set the ancillary files are
+    /// audited too and the `ancillary_verification_key` is required.
+    #[clap(long)]
+    ancillary_manifest: Option<PathBuf>,
+
+    /// Ancillary verification key to verify the signature of the ancillary files manifest.
+    #[clap(long, env = "ANCILLARY_VERIFICATION_KEY")]
+    ancillary_verification_key: Option<String>,
+}
+
+impl CardanoDbAuditCommand {
+    /// Main command execution
+    pub async fn execute(&self, mut context: CommandContext) -> MithrilResult<()> {
+        context.config_parameters_mut().add_source(self)?;
+        self.audit(&context).await
+    }
+
+    async fn audit(&self, context: &CommandContext) -> MithrilResult<()> {
+        let db_dir = context.config_parameters().require("db_dir")?;
+        let db_dir = Path::new(&db_dir);
+        let ancillary_verification_key = match &self.ancillary_manifest {
+            Some(_) => Some(context.config_parameters().require("ancillary_verification_key")?),
+            None => None,
+        };
+
+        let progress_output_type = if context.is_json_output_enabled() {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 2);
+        let client = client_builder(context.config_parameters())?
+            .set_ancillary_verification_key(ancillary_verification_key)
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+                context.logger().clone(),
+            )))
+            .with_logger(context.logger().clone())
+            .build()?;
+
+        client.cardano_database_v2().check_has_immutables(db_dir)?;
+
+        let get_list_of_artifact_ids = || async {
+            let cardano_db_snapshots = client.cardano_database_v2().list().await.with_context(
+                || "Can not get the list of artifacts while retrieving the latest cardano db hash",
+            )?;
+
+            Ok(cardano_db_snapshots
+                .iter()
+                .map(|cardano_db| cardano_db.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        let cardano_db_message = client
+            .cardano_database_v2()
+            .get(
+                &ExpanderUtils::expand_eventual_id_alias(&self.digest, get_list_of_artifact_ids())
+                    .await?,
+            )
+            .await?
+            .with_context(|| format!("Can not get the cardano db for hash: '{}'", self.digest))?;
+
+        let certificate = shared_steps::fetch_certificate_and_verifying_chain(
+            1,
+            &progress_printer,
+            &client,
+            &cardano_db_message.certificate_hash,
+        )
+        .await?;
+
+        progress_printer.report_step(
+            2,
+            "Auditing the Cardano database against the certified digests…",
+        )?;
+        let audit_report = client
+            .cardano_database_v2()
+            .audit(
+                &certificate,
+                &cardano_db_message,
+                db_dir,
+                self.ancillary_manifest.as_deref(),
+            )
+            .await
+            .with_context(|| format!("Can not audit the database at '{}'", db_dir.display()))?;
+
+        Self::log_audit_report(&audit_report, context.is_json_output_enabled())?;
+
+        if audit_report.is_valid() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "The database at '{}' does not match the Cardano db snapshot '{}'",
+                db_dir.display(),
+                cardano_db_message.hash
+            ))
+        }
+    }
+
+    fn log_audit_report(
+        audit_report: &CardanoDatabaseAuditReport,
+        json_output: bool,
+    ) -> MithrilResult<()> {
+        if json_output {
+            println!("{}", serde_json::to_string(audit_report)?);
+            return Ok(());
+        }
+
+        println!(
+            "Audit of the database against the Cardano db snapshot '{}' (last certified immutable file number: {}):",
+            audit_report.cardano_database_hash, audit_report.last_certified_immutable_file_number
+        );
+        println!(
+            "  - verified immutable files: {}",
+            audit_report.verified_immutable_files
+        );
+        for mismatch in &audit_report.mismatching_immutable_files {
+            println!(
+                "  - mismatching immutable file: '{}' (expected digest: '{}', computed digest: '{}')",
+                mismatch.immutable_file_name, mismatch.expected_digest, mismatch.computed_digest
+            );
+        }
+        for immutable_file_name in &audit_report.missing_immutable_files {
+            println!("  - missing immutable file: '{immutable_file_name}'");
+        }
+        if !audit_report.extra_immutable_files.is_empty() {
+            println!(
+                "  - immutable files beyond the certified range: {}",
+                audit_report.extra_immutable_files.len()
+            );
+        }
+        if let Some(ancillary_files) = &audit_report.ancillary_files {
+            if !ancillary_files.valid_manifest_signature {
+                println!("  - the ancillary files manifest signature is invalid");
+            }
+            println!(
+                "  - verified ancillary files: {}",
+                ancillary_files.verified_files
+            );
+            for path in &ancillary_files.tampered_files {
+                println!("  - tampered ancillary file: '{}'", path.display());
+            }
+            for path in &ancillary_files.missing_files {
+                println!("  - missing ancillary file: '{}'", path.display());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ConfigSource for CardanoDbAuditCommand {
+    fn collect(&self) -> Result<HashMap<String, String>, ConfigError> {
+        let mut map = HashMap::new();
+
+        if let Some(db_dir) = self.db_dir.clone() {
+            let param = "db_dir".to_string();
+            map.insert(
+                param.clone(),
+                utils::path_to_string(&db_dir).map_err(|e| ConfigError::Conversion(param, e))?,
+            );
+        }
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                genesis_verification_key,
+            );
+        }
+
+        if let Some(ancillary_verification_key) = self.ancillary_verification_key.clone() {
+            map.insert(
+                "ancillary_verification_key".to_string(),
+                ancillary_verification_key,
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use slog::Logger;
+
+    use crate::ConfigParameters;
+
+    use super::*;
+
+    fn dummy_command() -> CardanoDbAuditCommand {
+        CardanoDbAuditCommand {
+            digest: "whatever_digest".to_string(),
+            db_dir: Some(PathBuf::from("whatever_dir")),
+            genesis_verification_key: "whatever".to_string().into(),
+            ancillary_manifest: None,
+            ancillary_verification_key: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn ancillary_verification_key_is_mandatory_when_ancillary_manifest_is_set() {
+        let command = CardanoDbAuditCommand {
+            ancillary_manifest: Some(PathBuf::from("whatever_manifest.json")),
+            ancillary_verification_key: None,
+            ..dummy_command()
+        };
+        let command_context = CommandContext::new(
+            ConfigParameters::default(),
+            false,
+            true,
+            Logger::root(slog::Discard, slog::o!()),
+        );
+
+        let result = command.execute(command_context).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Parameter 'ancillary_verification_key' is mandatory."
+        );
+    }
+}
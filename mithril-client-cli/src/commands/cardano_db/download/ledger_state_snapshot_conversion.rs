@@ -0,0 +1,129 @@
+use std::path::{Path, PathBuf};
+
+use mithril_client::MithrilResult;
+
+use crate::{
+    commands::tools::{SnapshotConverterCommand, SnapshotConverterSource, UTxOHDFlavor},
+    utils::ProgressPrinter,
+};
+
+/// Conversion of the restored ledger state snapshot to another UTxO-HD flavor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct PreparedLedgerStateSnapshotConversion {
+    pub(super) utxo_hd_flavor: UTxOHDFlavor,
+    pub(super) cardano_node_version: Option<String>,
+    pub(super) distribution_cache_dir: PathBuf,
+    pub(super) github_token: Option<String>,
+    pub(super) snapshot_converter_path: Option<PathBuf>,
+    pub(super) snapshot_converter_config_path: Option<PathBuf>,
+}
+
+impl PreparedLedgerStateSnapshotConversion {
+    pub(super) async fn execute(
+        &self,
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        db_dir: &Path,
+        snapshot_cardano_node_version: &str,
+    ) -> MithrilResult<()> {
+        progress_printer.report_step(
+            step_number,
+            &format!(
+                "Converting the ledger state snapshot to '{}' flavor…",
+                self.utxo_hd_flavor
+            ),
+        )?;
+
+        SnapshotConverterCommand::convert_restored_ledger_state_snapshot(
+            db_dir,
+            &self.utxo_hd_flavor,
+            &self.converter_source(snapshot_cardano_node_version),
+        )
+        .await
+    }
+
+    /// Returns the source of the `snapshot-converter` binary.
+    ///
+    /// If neither a binary nor a Cardano node version were given, the distribution of the Cardano
+    /// node version of the downloaded snapshot is used.
+    fn converter_source(&self, snapshot_cardano_node_version: &str) -> SnapshotConverterSource {
+        match &self.snapshot_converter_path {
+            Some(converter_bin) => SnapshotConverterSource::LocalBinary {
+                converter_bin: converter_bin.clone(),
+                config_path: self.snapshot_converter_config_path.clone(),
+            },
+            None => SnapshotConverterSource::CachedDistribution {
+                cardano_node_version: self
+                    .cardano_node_version
+                    .clone()
+                    .unwrap_or_else(|| snapshot_cardano_node_version.to_string()),
+                cache_dir: self.distribution_cache_dir.clone(),
+                github_token: self.github_token.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_conversion() -> PreparedLedgerStateSnapshotConversion {
+        PreparedLedgerStateSnapshotConversion {
+            utxo_hd_flavor: UTxOHDFlavor::Lmdb,
+            cardano_node_version: None,
+            distribution_cache_dir: PathBuf::from("cache_dir"),
+            github_token: Some("token".to_string()),
+            snapshot_converter_path: None,
+            snapshot_converter_config_path: None,
+        }
+    }
+
+    #[test]
+    fn converter_source_defaults_to_the_distribution_of_the_snapshot_cardano_node_version() {
+        let conversion = dummy_conversion();
+
+        assert_eq!(
+            SnapshotConverterSource::CachedDistribution {
+                cardano_node_version: "10.4.1".to_string(),
+                cache_dir: PathBuf::from("cache_dir"),
+                github_token: Some("token".to_string()),
+            },
+            conversion.converter_source("10.4.1")
+        );
+    }
+
+    #[test]
+    fn converter_source_uses_the_given_cardano_node_version() {
+        let conversion = PreparedLedgerStateSnapshotConversion {
+            cardano_node_version: Some("latest".to_string()),
+            ..dummy_conversion()
+        };
+
+        assert_eq!(
+            SnapshotConverterSource::CachedDistribution {
+                cardano_node_version: "latest".to_string(),
+                cache_dir: PathBuf::from("cache_dir"),
+                github_token: Some("token".to_string()),
+            },
+            conversion.converter_source("10.4.1")
+        );
+    }
+
+    #[test]
+    fn converter_source_uses_the_given_snapshot_converter_binary() {
+        let conversion = PreparedLedgerStateSnapshotConversion {
+            snapshot_converter_path: Some(PathBuf::from("bin/snapshot-converter")),
+            snapshot_converter_config_path: Some(PathBuf::from("config.json")),
+            ..dummy_conversion()
+        };
+
+        assert_eq!(
+            SnapshotConverterSource::LocalBinary {
+                converter_bin: PathBuf::from("bin/snapshot-converter"),
+                config_path: Some(PathBuf::from("config.json")),
+            },
+            conversion.converter_source("10.4.1")
+        );
+    }
+}
@@ -1,6 +1,8 @@
+mod ledger_state_snapshot_conversion;
 mod v1;
 mod v2;
 
+use ledger_state_snapshot_conversion::PreparedLedgerStateSnapshotConversion;
 use v1::PreparedCardanoDbV1Download;
 use v2::PreparedCardanoDbV2Download;
 
@@ -9,10 +11,14 @@ use std::{collections::HashMap, path::PathBuf};
 
 use crate::{
     CommandContext,
-    commands::cardano_db::CardanoDbCommandsBackend,
+    commands::{
+        cardano_db::CardanoDbCommandsBackend,
+        tools::{SnapshotConverterCommand, SnapshotConverterSource, UTxOHDFlavor},
+    },
     configuration::{ConfigError, ConfigSource},
     utils::{self, JSON_CAUTION_KEY},
 };
+use anyhow::anyhow;
 use mithril_client::{MithrilResult, common::ImmutableFileNumber};
 
 const DB_DIRECTORY_NAME: &str = "db";
@@ -68,6 +74,71 @@ pub struct CardanoDbDownloadCommand {
     /// [backend `v2` only] Allow existing files in the download directory to be overridden.
     #[clap(long)]
     allow_override: bool,
+
+    /// Patterns of the locations to try first when a file is available at several locations
+    /// (e.g. an internal mirror), in order of preference.
+    ///
+    /// A location is preferred if its URI contains one of the patterns, the other locations are
+    /// tried if all the preferred ones fail.
+    #[clap(long = "preferred-location", value_delimiter = ',')]
+    preferred_locations: Vec<String>,
+
+    /// [backend `v1` only] Restore the snapshot in streaming mode.
+    ///
+    /// The archive is unpacked on the fly without being stored on the disk, so only the disk
+    /// space of the unpacked files is needed, and the digests of the immutable files are computed
+    /// as they are written.
+    /// The restoration is aborted as soon as an unexpected file is unpacked, or as soon as an
+    /// immutable file does not match its digest certified by a Cardano database `v2` snapshot.
+    /// If no such snapshot is available, a digest mismatch is only detected once the whole
+    /// archive is unpacked.
+    #[clap(long)]
+    streaming: bool,
+
+    /// Convert the restored ledger state snapshot to the given UTxO-HD flavor (`Legacy` or `LMDB`),
+    /// requires `--include-ancillary`.
+    ///
+    /// The conversion is done with the `snapshot-converter` binary of a `cardano-node`
+    /// distribution, falling back to the previous ledger state snapshot if the most recent one
+    /// can not be converted.
+    #[clap(long, requires = "include_ancillary")]
+    utxo_hd_flavor: Option<UTxOHDFlavor>,
+
+    /// Version of the `cardano-node` distribution to download to convert the ledger state snapshot
+    /// (`latest` and `pre-release` are also supported).
+    ///
+    /// If not set, the Cardano node version of the downloaded snapshot is used.
+    #[clap(
+        long,
+        requires = "utxo_hd_flavor",
+        conflicts_with = "snapshot_converter_path"
+    )]
+    cardano_node_version: Option<String>,
+
+    /// Directory where the downloaded `cardano-node` distributions are cached between runs.
+    ///
+    /// If not set, a `mithril-client/cardano-node-distributions` subdirectory of the user cache
+    /// directory is used (or of the temporary directory if the platform has no user cache
+    /// directory).
+    #[clap(long, requires = "utxo_hd_flavor")]
+    cardano_node_distribution_cache_dir: Option<PathBuf>,
+
+    /// Path to a `snapshot-converter` binary to use instead of downloading a `cardano-node`
+    /// distribution (e.g. on offline hosts).
+    #[clap(long, requires = "utxo_hd_flavor")]
+    snapshot_converter_path: Option<PathBuf>,
+
+    /// Path to the Cardano node configuration file to use with the binary given with
+    /// `--snapshot-converter-path`.
+    ///
+    /// If not set, the `share/<network>/config.json` file of the `cardano-node` distribution
+    /// containing the binary is used.
+    #[clap(long, requires = "snapshot_converter_path")]
+    snapshot_converter_config_path: Option<PathBuf>,
+
+    /// GitHub token for authenticated API calls when downloading the `cardano-node` distribution.
+    #[clap(long, env = "GITHUB_TOKEN")]
+    github_token: Option<String>,
 }
 
 impl CardanoDbDownloadCommand {
@@ -105,10 +176,18 @@ impl CardanoDbDownloadCommand {
             download_dir: context.config_parameters().require("download_dir")?,
             include_ancillary: self.include_ancillary,
             ancillary_verification_key,
+            preferred_locations: self.preferred_locations.clone(),
+            streaming: self.streaming,
+            ledger_state_snapshot_conversion: self
+                .prepare_ledger_state_snapshot_conversion(context)?,
         })
     }
 
     fn prepare_v2(&self, context: &CommandContext) -> MithrilResult<PreparedCardanoDbV2Download> {
+        if self.streaming {
+            self.warn_unused_parameter_with_v2_backend(context);
+        }
+
         let ancillary_verification_key = if self.include_ancillary {
             self.warn_ancillary_not_signed_by_mithril(context);
             Some(context.config_parameters().require("ancillary_verification_key")?)
@@ -125,9 +204,43 @@ impl CardanoDbDownloadCommand {
             include_ancillary: self.include_ancillary,
             ancillary_verification_key,
             allow_override: self.allow_override,
+            preferred_locations: self.preferred_locations.clone(),
+            ledger_state_snapshot_conversion: self
+                .prepare_ledger_state_snapshot_conversion(context)?,
         })
     }
 
+    fn prepare_ledger_state_snapshot_conversion(
+        &self,
+        context: &CommandContext,
+    ) -> MithrilResult<Option<PreparedLedgerStateSnapshotConversion>> {
+        let Some(utxo_hd_flavor) = &self.utxo_hd_flavor else {
+            return Ok(None);
+        };
+        context.require_unstable(
+            "cardano-db download --utxo-hd-flavor",
+            Some("LMDB --include-ancillary latest"),
+        )?;
+        if !self.include_ancillary {
+            return Err(anyhow!(
+                "The ledger state snapshot can only be converted if the ancillary files are included, add `--include-ancillary` to the command."
+            ));
+        }
+        SnapshotConverterCommand::check_platform_support()?;
+
+        Ok(Some(PreparedLedgerStateSnapshotConversion {
+            utxo_hd_flavor: utxo_hd_flavor.clone(),
+            cardano_node_version: self.cardano_node_version.clone(),
+            distribution_cache_dir: self
+                .cardano_node_distribution_cache_dir
+                .clone()
+                .unwrap_or_else(SnapshotConverterSource::default_distribution_cache_dir),
+            github_token: self.github_token.clone(),
+            snapshot_converter_path: self.snapshot_converter_path.clone(),
+            snapshot_converter_config_path: self.snapshot_converter_config_path.clone(),
+        }))
+    }
+
     /// Provides guidance on how to enable fast bootstrap by including ancillary files
     fn warn_fast_bootstrap_not_available(&self, context: &CommandContext) {
         if context.is_json_output_enabled() {
@@ -165,6 +278,17 @@ For more information, please refer to the network configuration page of the docu
         }
     }
 
+    fn warn_unused_parameter_with_v2_backend(&self, context: &CommandContext) {
+        let message = "`--streaming` is only available with the `v1` backend. It will be ignored.";
+        if context.is_json_output_enabled() {
+            eprintln!(r#"{{"{JSON_CAUTION_KEY}":"{message}"}}"#);
+        } else {
+            eprintln!("{message}");
+            // Add a blank line to separate this message from the one related to the fast bootstrap that comes next.
+            eprintln!();
+        }
+    }
+
     fn warn_unused_parameter_with_v1_backend(&self, context: &CommandContext) {
         let message = "`--start`, `--end`, and `--allow-override` are only available with the `v2` backend. They will be ignored.";
         if context.is_json_output_enabled() {
@@ -227,6 +351,14 @@ mod tests {
             start: None,
             end: None,
             allow_override: false,
+            preferred_locations: vec![],
+            streaming: false,
+            utxo_hd_flavor: None,
+            cardano_node_version: None,
+            cardano_node_distribution_cache_dir: None,
+            snapshot_converter_path: None,
+            snapshot_converter_config_path: None,
+            github_token: None,
         }
     }
 
@@ -323,6 +455,33 @@ mod tests {
             assert!(result.is_ok());
         }
 
+        #[test]
+        fn preferred_locations_can_be_given_as_a_comma_separated_list() {
+            let command = CardanoDbDownloadCommand::try_parse_from([
+                "download",
+                "whatever_digest",
+                "--download-dir",
+                "whatever_dir",
+                "--preferred-location",
+                "mirror.internal,cdn.example",
+            ])
+            .unwrap();
+            let mut command_context = CommandContext::new(
+                ConfigParameters::default(),
+                false,
+                true,
+                Logger::root(slog::Discard, slog::o!()),
+            );
+            command_context.config_parameters_mut().add_source(&command).unwrap();
+
+            let prepared_command = command.prepare_v2(&command_context).unwrap();
+
+            assert_eq!(
+                vec!["mirror.internal".to_string(), "cdn.example".to_string()],
+                prepared_command.preferred_locations
+            );
+        }
+
         #[test]
         fn db_download_dir_is_mandatory_to_execute_command() {
             let command = CardanoDbDownloadCommand {
@@ -347,4 +506,115 @@ mod tests {
             );
         }
     }
+
+    mod prepare_ledger_state_snapshot_conversion {
+        use super::*;
+
+        fn command_context(unstable_enabled: bool) -> CommandContext {
+            CommandContext::new(
+                ConfigParameters::default(),
+                unstable_enabled,
+                true,
+                Logger::root(slog::Discard, slog::o!()),
+            )
+        }
+
+        #[test]
+        fn no_conversion_if_utxo_hd_flavor_is_not_set() {
+            let command = dummy_command();
+
+            let conversion = command
+                .prepare_ledger_state_snapshot_conversion(&command_context(false))
+                .unwrap();
+
+            assert_eq!(None, conversion);
+        }
+
+        #[test]
+        fn utxo_hd_flavor_requires_unstable() {
+            let command = CardanoDbDownloadCommand {
+                utxo_hd_flavor: Some(UTxOHDFlavor::Lmdb),
+                ..dummy_command()
+            };
+
+            command
+                .prepare_ledger_state_snapshot_conversion(&command_context(false))
+                .expect_err("Conversion should fail without `--unstable`");
+        }
+
+        #[test]
+        fn utxo_hd_flavor_requires_include_ancillary() {
+            CardanoDbDownloadCommand::try_parse_from([
+                "download",
+                "whatever_digest",
+                "--utxo-hd-flavor",
+                "LMDB",
+            ])
+            .expect_err("Parsing should fail without `--include-ancillary`");
+
+            let command = CardanoDbDownloadCommand {
+                include_ancillary: false,
+                utxo_hd_flavor: Some(UTxOHDFlavor::Lmdb),
+                ..dummy_command()
+            };
+            command
+                .prepare_ledger_state_snapshot_conversion(&command_context(true))
+                .expect_err("Conversion should fail without ancillary files");
+        }
+
+        #[cfg(not(all(target_os = "linux", target_arch = "aarch64")))]
+        #[test]
+        fn distribution_cache_dir_defaults_to_the_default_distribution_cache_dir() {
+            let command = CardanoDbDownloadCommand {
+                utxo_hd_flavor: Some(UTxOHDFlavor::Legacy),
+                ..dummy_command()
+            };
+
+            let conversion = command
+                .prepare_ledger_state_snapshot_conversion(&command_context(true))
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(
+                SnapshotConverterSource::default_distribution_cache_dir(),
+                conversion.distribution_cache_dir
+            );
+        }
+
+        #[cfg(not(all(target_os = "linux", target_arch = "aarch64")))]
+        #[test]
+        fn snapshot_converter_options_can_be_given_through_command_line() {
+            let command = CardanoDbDownloadCommand::try_parse_from([
+                "download",
+                "whatever_digest",
+                "--include-ancillary",
+                "--utxo-hd-flavor",
+                "LMDB",
+                "--cardano-node-distribution-cache-dir",
+                "cache_dir",
+                "--snapshot-converter-path",
+                "bin/snapshot-converter",
+                "--snapshot-converter-config-path",
+                "config.json",
+            ])
+            .unwrap();
+
+            let conversion = command
+                .prepare_ledger_state_snapshot_conversion(&command_context(true))
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(
+                PreparedLedgerStateSnapshotConversion {
+                    utxo_hd_flavor: UTxOHDFlavor::Lmdb,
+                    cardano_node_version: None,
+                    distribution_cache_dir: PathBuf::from("cache_dir"),
+                    github_token: command.github_token.clone(),
+                    snapshot_converter_path: Some(PathBuf::from("bin/snapshot-converter")),
+                    snapshot_converter_config_path: Some(PathBuf::from("config.json")),
+                },
+                conversion
+            );
+        }
+    }
 }
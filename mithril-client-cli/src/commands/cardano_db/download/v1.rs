@@ -1,16 +1,24 @@
 use anyhow::{Context, anyhow};
 use slog::{Logger, debug, warn};
-use std::{fs::File, path::Path, sync::Arc};
+use std::{collections::BTreeMap, fs::File, path::Path, sync::Arc};
 
 use mithril_client::{
-    MessageBuilder, MithrilCertificate, MithrilResult, Snapshot, common::ProtocolMessage,
+    CardanoDatabaseSnapshotListItem, Client, MessageBuilder, MithrilCertificate, MithrilResult,
+    Snapshot,
+    common::{
+        HexEncodedDigest, ImmutableFileName, ImmutableFileNumber, ProtocolMessage,
+        ProtocolMessagePartKey,
+    },
     snapshot_client::SnapshotClient,
 };
 
 use crate::{
     CommandContext,
     commands::{
-        cardano_db::{download::DB_DIRECTORY_NAME, shared_steps},
+        cardano_db::{
+            download::{DB_DIRECTORY_NAME, PreparedLedgerStateSnapshotConversion},
+            shared_steps,
+        },
         client_builder,
     },
     utils::{
@@ -25,6 +33,9 @@ pub(super) struct PreparedCardanoDbV1Download {
     pub(super) download_dir: String,
     pub(super) include_ancillary: bool,
     pub(super) ancillary_verification_key: Option<String>,
+    pub(super) preferred_locations: Vec<String>,
+    pub(super) streaming: bool,
+    pub(super) ledger_state_snapshot_conversion: Option<PreparedLedgerStateSnapshotConversion>,
 }
 
 impl PreparedCardanoDbV1Download {
@@ -37,13 +48,19 @@ impl PreparedCardanoDbV1Download {
         } else {
             ProgressOutputType::Tty
         };
-        let progress_printer = ProgressPrinter::new(progress_output_type, 5);
+        let number_of_steps = if self.ledger_state_snapshot_conversion.is_some() {
+            6
+        } else {
+            5
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, number_of_steps);
         let client = client_builder(context.config_parameters())?
             .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
                 progress_output_type,
                 context.logger().clone(),
             )))
             .set_ancillary_verification_key(self.ancillary_verification_key.clone())
+            .with_preferred_locations(self.preferred_locations.clone())
             .with_logger(context.logger().clone())
             .build()?;
 
@@ -67,7 +84,13 @@ impl PreparedCardanoDbV1Download {
             .await?
             .with_context(|| format!("Can not get the cardano db for digest: '{}'", self.digest))?;
 
-        Self::check_local_disk_info(1, &progress_printer, &db_dir, &cardano_db_message)?;
+        Self::check_local_disk_info(
+            1,
+            &progress_printer,
+            &db_dir,
+            &cardano_db_message,
+            self.streaming,
+        )?;
 
         let certificate = shared_steps::fetch_certificate_and_verifying_chain(
             2,
@@ -77,13 +100,26 @@ impl PreparedCardanoDbV1Download {
         )
         .await?;
 
-        Self::download_and_unpack_cardano_db(
+        let certified_digests = if self.streaming {
+            Self::fetch_certified_immutable_files_digests(
+                context.logger(),
+                &client,
+                &cardano_db_message,
+            )
+            .await
+        } else {
+            None
+        };
+
+        let streamed_digest = Self::download_and_unpack_cardano_db(
             context.logger(),
             3,
             &progress_printer,
             client.cardano_database(),
             &cardano_db_message,
             self.include_ancillary,
+            self.streaming,
+            certified_digests,
             &db_dir,
         )
         .await
@@ -94,8 +130,16 @@ impl PreparedCardanoDbV1Download {
             )
         })?;
 
-        let message =
-            Self::compute_cardano_db_message(4, &progress_printer, &certificate, &db_dir).await?;
+        let message = if let Some(streamed_digest) = &streamed_digest {
+            Self::compute_streamed_cardano_db_message(
+                4,
+                &progress_printer,
+                &certificate,
+                streamed_digest,
+            )?
+        } else {
+            Self::compute_cardano_db_message(4, &progress_printer, &certificate, &db_dir).await?
+        };
 
         Self::verify_cardano_db_signature(
             context.logger(),
@@ -108,13 +152,24 @@ impl PreparedCardanoDbV1Download {
         )
         .await?;
 
+        if let Some(conversion) = &self.ledger_state_snapshot_conversion {
+            conversion
+                .execute(
+                    6,
+                    &progress_printer,
+                    &db_dir,
+                    &cardano_db_message.cardano_node_version,
+                )
+                .await?;
+        }
+
         shared_steps::log_download_information(
             &db_dir,
             &cardano_db_message.digest,
             &cardano_db_message.network,
             &cardano_db_message.cardano_node_version,
             context.is_json_output_enabled(),
-            self.include_ancillary,
+            self.include_ancillary && self.ledger_state_snapshot_conversion.is_none(),
         )?;
 
         Ok(())
@@ -125,15 +180,25 @@ impl PreparedCardanoDbV1Download {
         progress_printer: &ProgressPrinter,
         db_dir: &Path,
         cardano_db: &Snapshot,
+        streaming: bool,
     ) -> MithrilResult<()> {
         progress_printer.report_step(step_number, "Checking local disk info…")?;
 
         CardanoDbDownloadChecker::ensure_dir_exist(db_dir)?;
-        if let Err(e) = CardanoDbDownloadChecker::check_prerequisites_for_archive(
-            db_dir,
-            cardano_db.compute_total_size(),
-            cardano_db.compression_algorithm,
-        ) {
+        let check_result = if streaming {
+            CardanoDbDownloadChecker::check_prerequisites_for_streamed_archive(
+                db_dir,
+                cardano_db.compute_total_size(),
+                cardano_db.compression_algorithm,
+            )
+        } else {
+            CardanoDbDownloadChecker::check_prerequisites_for_archive(
+                db_dir,
+                cardano_db.compute_total_size(),
+                cardano_db.compression_algorithm,
+            )
+        };
+        if let Err(e) = check_result {
             progress_printer
                 .report_step(step_number, &CardanoDbUtils::check_disk_space_error(e)?)?;
         }
@@ -141,6 +206,89 @@ impl PreparedCardanoDbV1Download {
         Ok(())
     }
 
+    /// Fetch the digests of the immutable files of the cardano db certified by the Cardano
+    /// database v2 snapshot with the closest immutable file number that covers them, so the
+    /// streamed restore is aborted at the first immutable file that does not match its certified
+    /// digest.
+    ///
+    /// If they can not be fetched, a digest mismatch is only detected once the whole archive is
+    /// unpacked.
+    async fn fetch_certified_immutable_files_digests(
+        logger: &Logger,
+        client: &Client,
+        cardano_db: &Snapshot,
+    ) -> Option<BTreeMap<ImmutableFileName, HexEncodedDigest>> {
+        let fallback_message =
+            "a digest mismatch will only be detected once the whole archive is unpacked";
+        match Self::try_fetch_certified_immutable_files_digests(client, cardano_db).await {
+            Ok(Some(certified_digests)) => Some(certified_digests),
+            Ok(None) => {
+                warn!(
+                    logger,
+                    "No Cardano database v2 snapshot certifies the digests of the immutable files up to '{}', {fallback_message}",
+                    cardano_db.beacon.immutable_file_number
+                );
+                None
+            }
+            Err(error) => {
+                warn!(
+                    logger,
+                    "Could not fetch the certified digests of the immutable files, {fallback_message}";
+                    "error" => ?error
+                );
+                None
+            }
+        }
+    }
+
+    async fn try_fetch_certified_immutable_files_digests(
+        client: &Client,
+        cardano_db: &Snapshot,
+    ) -> MithrilResult<Option<BTreeMap<ImmutableFileName, HexEncodedDigest>>> {
+        let cardano_database_snapshots = client.cardano_database_v2().list().await?;
+        let Some(covering_snapshot) = Self::select_covering_cardano_database_snapshot(
+            &cardano_database_snapshots,
+            cardano_db.beacon.immutable_file_number,
+        ) else {
+            return Ok(None);
+        };
+        let cardano_database_snapshot = client
+            .cardano_database_v2()
+            .get(&covering_snapshot.hash)
+            .await?
+            .with_context(|| {
+                format!(
+                    "Can not get the Cardano database snapshot for hash: '{}'",
+                    covering_snapshot.hash
+                )
+            })?;
+        let certificate = client
+            .certificate()
+            .verify_chain(&cardano_database_snapshot.certificate_hash)
+            .await?;
+        let certified_digests = client
+            .cardano_database_v2()
+            .download_certified_digests(&certificate, &cardano_database_snapshot)
+            .await?;
+
+        Ok(Some(certified_digests))
+    }
+
+    /// Select the Cardano database snapshot with the lowest immutable file number that is not
+    /// lower than the given one, its digests covering all the immutable files up to it.
+    fn select_covering_cardano_database_snapshot(
+        cardano_database_snapshots: &[CardanoDatabaseSnapshotListItem],
+        immutable_file_number: ImmutableFileNumber,
+    ) -> Option<&CardanoDatabaseSnapshotListItem> {
+        cardano_database_snapshots
+            .iter()
+            .filter(|snapshot| snapshot.beacon.immutable_file_number >= immutable_file_number)
+            .min_by_key(|snapshot| snapshot.beacon.immutable_file_number)
+    }
+
+    /// Download and unpack the cardano db, returning the digest computed while unpacking it in
+    /// streaming mode.
+    #[allow(clippy::too_many_arguments)]
     async fn download_and_unpack_cardano_db(
         logger: &Logger,
         step_number: u16,
@@ -148,15 +296,30 @@ impl PreparedCardanoDbV1Download {
         snapshot_client: Arc<SnapshotClient>,
         cardano_db: &Snapshot,
         include_ancillary: bool,
+        streaming: bool,
+        certified_digests: Option<BTreeMap<ImmutableFileName, HexEncodedDigest>>,
         db_dir: &Path,
-    ) -> MithrilResult<()> {
+    ) -> MithrilResult<Option<String>> {
         progress_printer.report_step(step_number, "Downloading and unpacking the cardano db")?;
 
-        if include_ancillary {
+        let streamed_digest = if streaming {
+            Some(
+                snapshot_client
+                    .download_unpack_streaming(
+                        cardano_db,
+                        db_dir,
+                        include_ancillary,
+                        certified_digests,
+                    )
+                    .await?,
+            )
+        } else if include_ancillary {
             snapshot_client.download_unpack_full(cardano_db, db_dir).await?;
+            None
         } else {
             snapshot_client.download_unpack(cardano_db, db_dir).await?;
-        }
+            None
+        };
 
         // The Cardano db download does not fail if the statistic call fails.
         // It would be nice to implement tests to verify the behavior of `add_statistics`
@@ -175,7 +338,7 @@ impl PreparedCardanoDbV1Download {
             );
         };
 
-        Ok(())
+        Ok(streamed_digest)
     }
 
     async fn compute_cardano_db_message(
@@ -197,6 +360,24 @@ impl PreparedCardanoDbV1Download {
         Ok(message)
     }
 
+    /// Compute the cardano db message from the digest computed from the unpacked immutable
+    /// files during the streaming restore.
+    fn compute_streamed_cardano_db_message(
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        certificate: &MithrilCertificate,
+        streamed_digest: &str,
+    ) -> MithrilResult<ProtocolMessage> {
+        progress_printer.report_step(step_number, "Computing the cardano db message")?;
+        let mut message = certificate.protocol_message.clone();
+        message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            streamed_digest.to_string(),
+        );
+
+        Ok(message)
+    }
+
     async fn verify_cardano_db_signature(
         logger: &Logger,
         step_number: u16,
@@ -234,7 +415,7 @@ impl PreparedCardanoDbV1Download {
 mod tests {
     use mithril_client::{
         MithrilCertificateMetadata,
-        common::{CardanoDbBeacon, ProtocolMessagePartKey, SignedEntityType},
+        common::{CardanoDbBeacon, SignedEntityType},
     };
     use mithril_common::test_utils::{TempDir, double::Dummy};
 
@@ -266,6 +447,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn select_the_closest_cardano_database_snapshot_covering_the_immutable_files() {
+        let cardano_database_snapshots: Vec<CardanoDatabaseSnapshotListItem> = [12, 8, 10, 15]
+            .into_iter()
+            .map(|immutable_file_number| CardanoDatabaseSnapshotListItem {
+                hash: format!("hash-{immutable_file_number}"),
+                beacon: CardanoDbBeacon::new(1, immutable_file_number),
+                ..CardanoDatabaseSnapshotListItem::dummy()
+            })
+            .collect();
+
+        let select = |immutable_file_number| {
+            PreparedCardanoDbV1Download::select_covering_cardano_database_snapshot(
+                &cardano_database_snapshots,
+                immutable_file_number,
+            )
+            .map(|snapshot| snapshot.hash.as_str())
+        };
+
+        assert_eq!(Some("hash-10"), select(10));
+        assert_eq!(Some("hash-12"), select(11));
+        assert_eq!(Some("hash-8"), select(1));
+        assert_eq!(None, select(16));
+    }
+
+    #[test]
+    fn compute_streamed_cardano_db_message_use_the_streamed_digest() {
+        let progress_printer = ProgressPrinter::new(ProgressOutputType::Tty, 1);
+        let certificate = dummy_certificate();
+
+        let message = PreparedCardanoDbV1Download::compute_streamed_cardano_db_message(
+            1,
+            &progress_printer,
+            &certificate,
+            "streamed-digest",
+        )
+        .unwrap();
+
+        let mut expected_message = certificate.protocol_message.clone();
+        expected_message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            "streamed-digest".to_string(),
+        );
+        assert_eq!(expected_message, message);
+    }
+
     #[tokio::test]
     async fn verify_cardano_db_signature_should_remove_db_dir_if_messages_mismatch() {
         let progress_printer = ProgressPrinter::new(ProgressOutputType::Tty, 1);
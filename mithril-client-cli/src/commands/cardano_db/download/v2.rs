@@ -15,7 +15,10 @@ use mithril_client::{
 use crate::{
     CommandContext,
     commands::{
-        cardano_db::{download::DB_DIRECTORY_NAME, shared_steps},
+        cardano_db::{
+            download::{DB_DIRECTORY_NAME, PreparedLedgerStateSnapshotConversion},
+            shared_steps,
+        },
         client_builder,
     },
     utils::{
@@ -42,6 +45,8 @@ pub(super) struct PreparedCardanoDbV2Download {
     pub(super) include_ancillary: bool,
     pub(super) ancillary_verification_key: Option<String>,
     pub(super) allow_override: bool,
+    pub(super) preferred_locations: Vec<String>,
+    pub(super) ledger_state_snapshot_conversion: Option<PreparedLedgerStateSnapshotConversion>,
 }
 
 impl PreparedCardanoDbV2Download {
@@ -62,13 +67,19 @@ impl PreparedCardanoDbV2Download {
         } else {
             ProgressOutputType::Tty
         };
-        let progress_printer = ProgressPrinter::new(progress_output_type, 6);
+        let number_of_steps = if self.ledger_state_snapshot_conversion.is_some() {
+            7
+        } else {
+            6
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, number_of_steps);
         let client = client_builder(context.config_parameters())?
             .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
                 progress_output_type,
                 context.logger().clone(),
             )))
             .set_ancillary_verification_key(self.ancillary_verification_key.clone())
+            .with_preferred_locations(self.preferred_locations.clone())
             .with_logger(context.logger().clone())
             .build()?;
 
@@ -154,13 +165,25 @@ impl PreparedCardanoDbV2Download {
         )
         .await?;
 
+        if let Some(conversion) = &self.ledger_state_snapshot_conversion {
+            conversion
+                .execute(
+                    7,
+                    &progress_printer,
+                    &restoration_options.db_dir,
+                    &cardano_db_message.cardano_node_version,
+                )
+                .await?;
+        }
+
         shared_steps::log_download_information(
             &restoration_options.db_dir,
             &cardano_db_message.hash,
             &cardano_db_message.network,
             &cardano_db_message.cardano_node_version,
             context.is_json_output_enabled(),
-            restoration_options.download_unpack_options.include_ancillary,
+            restoration_options.download_unpack_options.include_ancillary
+                && self.ledger_state_snapshot_conversion.is_none(),
         )?;
 
         Ok(())
@@ -1,10 +1,12 @@
 //! Commands for the Cardano db artifact
+mod audit;
 mod download;
 mod list;
 mod shared_steps;
 mod show;
 mod verify;
 
+pub use audit::*;
 pub use download::*;
 pub use list::*;
 pub use show::*;
@@ -40,6 +42,10 @@ pub enum CardanoDbCommands {
     /// Verify a Cardano database content
     #[clap(arg_required_else_help = true)]
     Verify(CardanoDbVerifyCommand),
+
+    /// Audit an existing Cardano node database against a certified Cardano db snapshot
+    #[clap(arg_required_else_help = true)]
+    Audit(CardanoDbAuditCommand),
 }
 
 /// Cardano db snapshots
@@ -61,6 +67,7 @@ impl CardanoDbCommands {
             Self::Download(cmd) => cmd.execute(context).await,
             Self::Snapshot(cmd) => cmd.execute(context).await,
             Self::Verify(cmd) => cmd.execute(context).await,
+            Self::Audit(cmd) => cmd.execute(context).await,
         }
     }
 }
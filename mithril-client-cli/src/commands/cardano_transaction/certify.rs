@@ -2,13 +2,14 @@ use anyhow::{Context, anyhow};
 use clap::Parser;
 use cli_table::{Cell, Table, print_stdout};
 use slog::debug;
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use mithril_client::{
     CardanoTransactionsProofs, MessageBuilder, MithrilCertificate, MithrilResult,
     VerifiedCardanoTransactions, VerifyCardanoTransactionsProofsError, common::TransactionHash,
 };
 
+use super::CardanoTransactionsProofFile;
 use crate::utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter};
 use crate::{
     CommandContext,
@@ -26,6 +27,11 @@ pub struct CardanoTransactionsCertifyCommand {
     /// Hashes of the transactions to certify.
     #[clap(value_delimiter = ',', required = true)]
     transactions_hashes: Vec<String>,
+
+    /// Save the proof and its certificate chain to the given file so it can be verified later
+    /// with the `verify-proof` command.
+    #[clap(long)]
+    save_proof: Option<PathBuf>,
 }
 
 impl CardanoTransactionsCertifyCommand {
@@ -39,7 +45,8 @@ impl CardanoTransactionsCertifyCommand {
         } else {
             ProgressOutputType::Tty
         };
-        let progress_printer = ProgressPrinter::new(progress_output_type, 4);
+        let total_steps = if self.save_proof.is_some() { 5 } else { 4 };
+        let progress_printer = ProgressPrinter::new(progress_output_type, total_steps);
         let client = client_builder(context.config_parameters())?
             .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
                 progress_output_type,
@@ -68,8 +75,31 @@ impl CardanoTransactionsCertifyCommand {
             3,
             "Fetching the associated certificate and verifying the certificate chain…",
         )?;
-        let certificate = client
-            .certificate()
+        // The certificate chain saved with the proof is verified from the fetched certificates,
+        // so they are not fetched twice.
+        let proof_file = match &self.save_proof {
+            Some(_) => Some(
+                CardanoTransactionsProofFile::fetch(&client, cardano_transaction_proof.clone())
+                    .await
+                    .with_context(|| "Can not fetch the certificate chain of the proof")?,
+            ),
+            None => None,
+        };
+        let certificate_client = match &proof_file {
+            Some(proof_file) => proof_file
+                .offline_client_builder(
+                    &context.config_parameters().require("genesis_verification_key")?,
+                )
+                .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                    progress_output_type,
+                    logger.clone(),
+                )))
+                .with_logger(logger.clone())
+                .build()?
+                .certificate(),
+            None => client.certificate(),
+        };
+        let certificate = certificate_client
             .verify_chain(&cardano_transaction_proof.certificate_hash)
             .await
             .with_context(|| {
@@ -86,6 +116,15 @@ impl CardanoTransactionsCertifyCommand {
             &verified_transactions,
         )?;
 
+        if let (Some(save_proof_path), Some(proof_file)) = (&self.save_proof, proof_file) {
+            progress_printer.report_step(
+                5,
+                "Saving the proof and its certificate chain to the proof file…",
+            )?;
+            proof_file.save(save_proof_path)?;
+            debug!(logger, "Proof saved"; "path" => %save_proof_path.display());
+        }
+
         Self::log_certify_information(
             &verified_transactions,
             &cardano_transaction_proof.non_certified_transactions,
@@ -93,7 +132,7 @@ impl CardanoTransactionsCertifyCommand {
         )
     }
 
-    fn verify_proof_validity(
+    pub(super) fn verify_proof_validity(
         step_number: u16,
         progress_printer: &ProgressPrinter,
         cardano_transaction_proof: &CardanoTransactionsProofs,
@@ -110,7 +149,7 @@ Mithril may not have signed those transactions yet, please try again later."
         }
     }
 
-    fn verify_proof_match_certificate(
+    pub(super) fn verify_proof_match_certificate(
         step_number: u16,
         progress_printer: &ProgressPrinter,
         certificate: &MithrilCertificate,
@@ -132,7 +171,7 @@ Mithril may not have signed those transactions yet, please try again later."
         Ok(())
     }
 
-    fn log_certify_information(
+    pub(super) fn log_certify_information(
         verified_transactions: &VerifiedCardanoTransactions,
         non_certified_transactions: &[TransactionHash],
         json_output: bool,
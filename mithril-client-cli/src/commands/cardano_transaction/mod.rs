@@ -1,11 +1,15 @@
 //! Commands for the Cardano Transaction Snapshot artifact & Cardano Transactions Proof
 mod certify;
+mod proof_file;
 mod snapshot_list;
 mod snapshot_show;
+mod verify_proof;
 
 pub use certify::*;
+pub use proof_file::*;
 pub use snapshot_list::*;
 pub use snapshot_show::*;
+pub use verify_proof::*;
 
 use crate::CommandContext;
 use clap::Subcommand;
@@ -22,6 +26,10 @@ pub enum CardanoTransactionCommands {
     /// Certify that a given list of transaction hashes are included in the Cardano transactions set
     #[clap(arg_required_else_help = false)]
     Certify(CardanoTransactionsCertifyCommand),
+
+    /// Verify offline a proof file saved with `certify --save-proof`
+    #[clap(arg_required_else_help = true)]
+    VerifyProof(CardanoTransactionsVerifyProofCommand),
 }
 
 /// Cardano transactions set
@@ -42,6 +50,7 @@ impl CardanoTransactionCommands {
         match self {
             Self::Snapshot(cmd) => cmd.execute(config_builder).await,
             Self::Certify(cmd) => cmd.execute(config_builder).await,
+            Self::VerifyProof(cmd) => cmd.execute(config_builder).await,
         }
     }
 }
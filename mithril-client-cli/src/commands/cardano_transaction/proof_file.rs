@@ -0,0 +1,262 @@
+use anyhow::{Context, anyhow};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fs::File, path::Path, sync::Arc};
+
+use mithril_client::{
+    CardanoTransactionsProofs, Client, ClientBuilder, MithrilCertificate, MithrilResult,
+    aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest},
+};
+
+/// A Cardano transactions proof saved to disk alongside the certificate chain that signs it,
+/// from the proof certificate down to the genesis certificate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardanoTransactionsProofFile {
+    /// The Cardano transactions proof returned by the aggregator
+    pub proofs: CardanoTransactionsProofs,
+
+    /// The certificate chain, starting with the certificate of the proof and ending with the genesis certificate
+    pub certificate_chain: Vec<MithrilCertificate>,
+}
+
+impl CardanoTransactionsProofFile {
+    /// Build a proof file by fetching the certificate chain of the given proof.
+    ///
+    /// The chain is not verified, use a client built with [offline_client_builder][Self::offline_client_builder]
+    /// to verify it from the fetched certificates.
+    pub async fn fetch(client: &Client, proofs: CardanoTransactionsProofs) -> MithrilResult<Self> {
+        let mut certificate_chain = vec![];
+        let mut fetched_certificates_hashes = HashSet::new();
+        let mut certificate_hash = proofs.certificate_hash.clone();
+
+        loop {
+            if !fetched_certificates_hashes.insert(certificate_hash.clone()) {
+                return Err(anyhow!(
+                    "Certificate chain loops on certificate '{certificate_hash}'"
+                ));
+            }
+            let certificate = client
+                .certificate()
+                .get(&certificate_hash)
+                .await?
+                .ok_or_else(|| anyhow!("Certificate '{certificate_hash}' not found"))?;
+            let is_genesis = !certificate.genesis_signature.is_empty();
+            certificate_hash = certificate.previous_hash.clone();
+            certificate_chain.push(certificate);
+
+            if is_genesis {
+                break;
+            }
+        }
+
+        Ok(Self {
+            proofs,
+            certificate_chain,
+        })
+    }
+
+    /// Write the proof file as JSON to the given path.
+    pub fn save(&self, path: &Path) -> MithrilResult<()> {
+        let file = File::create(path)
+            .with_context(|| format!("Can not create proof file: '{}'", path.display()))?;
+        serde_json::to_writer_pretty(file, self)
+            .with_context(|| format!("Can not write proof file: '{}'", path.display()))?;
+
+        Ok(())
+    }
+
+    /// Create a [ClientBuilder] that reads the certificates from the saved certificate chain
+    /// instead of an aggregator.
+    pub fn offline_client_builder(&self, genesis_verification_key: &str) -> ClientBuilder {
+        ClientBuilder::new(genesis_verification_key).with_aggregator_client(Arc::new(
+            SavedCertificateChainAggregatorClient::new(self.certificate_chain.clone()),
+        ))
+    }
+
+    /// Read a proof file previously written with [save][Self::save].
+    pub fn load(path: &Path) -> MithrilResult<Self> {
+        let file = File::open(path)
+            .with_context(|| format!("Can not open proof file: '{}'", path.display()))?;
+        let proof_file = serde_json::from_reader(file)
+            .with_context(|| format!("Can not read proof file: '{}'", path.display()))?;
+
+        Ok(proof_file)
+    }
+}
+
+/// An [AggregatorClient] serving certificates from a saved certificate chain, allowing to verify
+/// a proof file without reaching an aggregator.
+pub struct SavedCertificateChainAggregatorClient {
+    certificate_chain: Vec<MithrilCertificate>,
+}
+
+impl SavedCertificateChainAggregatorClient {
+    /// `SavedCertificateChainAggregatorClient` factory
+    pub fn new(certificate_chain: Vec<MithrilCertificate>) -> Self {
+        Self { certificate_chain }
+    }
+}
+
+#[async_trait]
+impl AggregatorClient for SavedCertificateChainAggregatorClient {
+    async fn get_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        match request {
+            AggregatorRequest::GetCertificate { hash } => {
+                let certificate = self
+                    .certificate_chain
+                    .iter()
+                    .find(|certificate| certificate.hash == hash)
+                    .ok_or_else(|| {
+                        AggregatorClientError::RemoteServerLogical(anyhow!(
+                            "Certificate '{hash}' not found in the saved certificate chain"
+                        ))
+                    })?;
+
+                serde_json::to_string(certificate)
+                    .map_err(|e| AggregatorClientError::SubsystemError(e.into()))
+            }
+            request => Err(AggregatorClientError::SubsystemError(anyhow!(
+                "Request '{request:?}' is not available offline"
+            ))),
+        }
+    }
+
+    async fn post_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        Err(AggregatorClientError::SubsystemError(anyhow!(
+            "Request '{request:?}' is not available offline"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_client::common::BlockNumber;
+    use mithril_common::{
+        temp_dir_create,
+        test_utils::{double::Dummy, fake_keys},
+    };
+
+    use super::*;
+
+    fn fake_certificate(hash: &str, previous_hash: &str) -> MithrilCertificate {
+        MithrilCertificate {
+            hash: hash.to_string(),
+            previous_hash: previous_hash.to_string(),
+            ..MithrilCertificate::dummy()
+        }
+    }
+
+    #[test]
+    fn save_and_load_proof_file() {
+        let path = temp_dir_create!().join("proof.json");
+        let proof_file = CardanoTransactionsProofFile {
+            proofs: CardanoTransactionsProofs::new(
+                "certificate-1",
+                vec![],
+                vec!["tx-unknown".to_string()],
+                BlockNumber(99),
+            ),
+            certificate_chain: vec![
+                fake_certificate("certificate-1", "certificate-0"),
+                fake_certificate("certificate-0", ""),
+            ],
+        };
+
+        proof_file.save(&path).unwrap();
+        let loaded_proof_file = CardanoTransactionsProofFile::load(&path).unwrap();
+
+        assert_eq!(proof_file, loaded_proof_file);
+    }
+
+    #[test]
+    fn load_proof_file_fails_if_file_does_not_exist() {
+        let path = temp_dir_create!().join("not_existing.json");
+
+        CardanoTransactionsProofFile::load(&path)
+            .expect_err("Loading a not existing proof file should fail");
+    }
+
+    #[tokio::test]
+    async fn fetch_fails_if_the_certificate_chain_loops() {
+        let proof_file = CardanoTransactionsProofFile {
+            proofs: CardanoTransactionsProofs::new(
+                "certificate-2",
+                vec![],
+                vec![],
+                BlockNumber(99),
+            ),
+            certificate_chain: vec![
+                fake_certificate("certificate-2", "certificate-1"),
+                fake_certificate("certificate-1", "certificate-2"),
+            ],
+        };
+        let client = proof_file
+            .offline_client_builder(fake_keys::genesis_verification_key()[0])
+            .build()
+            .unwrap();
+
+        let error = CardanoTransactionsProofFile::fetch(&client, proof_file.proofs)
+            .await
+            .expect_err("Fetching a looping certificate chain should fail");
+
+        assert!(
+            error.to_string().contains("loops on certificate 'certificate-2'"),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn saved_chain_client_serves_certificates_of_the_chain() {
+        let certificate = fake_certificate("certificate-1", "certificate-0");
+        let client = SavedCertificateChainAggregatorClient::new(vec![
+            certificate.clone(),
+            fake_certificate("certificate-0", ""),
+        ]);
+
+        let content = client
+            .get_content(AggregatorRequest::GetCertificate {
+                hash: "certificate-1".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            certificate,
+            serde_json::from_str::<MithrilCertificate>(&content).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn saved_chain_client_returns_logical_error_for_unknown_certificate() {
+        let client =
+            SavedCertificateChainAggregatorClient::new(vec![fake_certificate("certificate-0", "")]);
+
+        let error = client
+            .get_content(AggregatorRequest::GetCertificate {
+                hash: "unknown".to_string(),
+            })
+            .await
+            .expect_err("Getting an unknown certificate should fail");
+
+        assert!(
+            matches!(error, AggregatorClientError::RemoteServerLogical(_)),
+            "Expected a RemoteServerLogical error, got: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn saved_chain_client_rejects_other_requests() {
+        let client = SavedCertificateChainAggregatorClient::new(vec![]);
+
+        client
+            .get_content(AggregatorRequest::ListCertificates)
+            .await
+            .expect_err("Listing certificates should not be available offline");
+    }
+}
@@ -0,0 +1,272 @@
+use anyhow::Context;
+use clap::Parser;
+use slog::debug;
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use mithril_client::MithrilResult;
+
+use super::{CardanoTransactionsCertifyCommand, CardanoTransactionsProofFile};
+use crate::utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter};
+use crate::{
+    CommandContext,
+    configuration::{ConfigError, ConfigSource},
+};
+
+/// Clap command to verify offline a Cardano transactions proof saved with `certify --save-proof`
+#[derive(Parser, Debug, Clone)]
+pub struct CardanoTransactionsVerifyProofCommand {
+    /// Genesis verification key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+
+    /// Path of the proof file to verify.
+    proof_file: PathBuf,
+}
+
+impl CardanoTransactionsVerifyProofCommand {
+    /// Cardano transaction verify proof command
+    pub async fn execute(&self, mut context: CommandContext) -> MithrilResult<()> {
+        context.config_parameters_mut().add_source(self)?;
+        let logger = context.logger();
+
+        let progress_output_type = if context.is_json_output_enabled() {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 4);
+
+        progress_printer.report_step(1, "Loading the proof file…")?;
+        let proof_file = CardanoTransactionsProofFile::load(&self.proof_file)?;
+        debug!(logger, "Loaded proof file"; "proof" => ?proof_file.proofs);
+
+        let client = proof_file
+            .offline_client_builder(
+                &context.config_parameters().require("genesis_verification_key")?,
+            )
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+                logger.clone(),
+            )))
+            .with_logger(logger.clone())
+            .build()?;
+
+        let cardano_transaction_proof = proof_file.proofs;
+        let verified_transactions = CardanoTransactionsCertifyCommand::verify_proof_validity(
+            2,
+            &progress_printer,
+            &cardano_transaction_proof,
+        )?;
+
+        progress_printer.report_step(3, "Verifying the saved certificate chain…")?;
+        let certificate = client
+            .certificate()
+            .verify_chain(&cardano_transaction_proof.certificate_hash)
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not verify the saved certificate chain from certificate_hash: '{}'",
+                    verified_transactions.certificate_hash()
+                )
+            })?;
+
+        CardanoTransactionsCertifyCommand::verify_proof_match_certificate(
+            4,
+            &progress_printer,
+            &certificate,
+            &verified_transactions,
+        )?;
+
+        CardanoTransactionsCertifyCommand::log_certify_information(
+            &verified_transactions,
+            &cardano_transaction_proof.non_certified_transactions,
+            context.is_json_output_enabled(),
+        )
+    }
+}
+
+impl ConfigSource for CardanoTransactionsVerifyProofCommand {
+    fn collect(&self) -> Result<HashMap<String, String>, ConfigError> {
+        let mut map = HashMap::new();
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                genesis_verification_key,
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use slog::Logger;
+    use std::path::Path;
+
+    use mithril_client::common::{BlockNumber, ProtocolMessagePartKey};
+    use mithril_client::{CardanoTransactionsProofs, MithrilCertificate};
+    use mithril_common::crypto_helper::{MKTreeStoreInMemory, ProtocolClerk};
+    use mithril_common::entities::{CardanoTransactionsSetProof, CertificateSignature};
+    use mithril_common::messages::CardanoTransactionsSetProofMessagePart;
+    use mithril_common::temp_dir_create;
+    use mithril_common::test_utils::{CertificateChainBuilder, CertificateChainFixture};
+
+    use crate::ConfigParameters;
+
+    use super::*;
+
+    const SIGNED_LATEST_BLOCK_NUMBER: BlockNumber = BlockNumber(99);
+
+    fn fake_set_proof() -> CardanoTransactionsSetProof {
+        CardanoTransactionsSetProof::from_leaves::<MKTreeStoreInMemory>(&[
+            (BlockNumber(10), "tx-1".to_string()),
+            (BlockNumber(20), "tx-2".to_string()),
+        ])
+        .unwrap()
+    }
+
+    /// Build a certificate chain whose latest certificate signs the [fake_set_proof] at the
+    /// [SIGNED_LATEST_BLOCK_NUMBER].
+    fn build_certificate_chain_signing_proof() -> CertificateChainFixture {
+        CertificateChainBuilder::new()
+            .with_total_certificates(4)
+            .with_certificates_per_epoch(2)
+            .with_standard_certificate_processor(&|mut certificate, context| {
+                if !context.is_last_certificate() {
+                    return certificate;
+                }
+
+                certificate.protocol_message = context.compute_protocol_message_seed();
+                certificate.protocol_message.set_message_part(
+                    ProtocolMessagePartKey::CardanoTransactionsMerkleRoot,
+                    fake_set_proof().merkle_root(),
+                );
+                certificate.protocol_message.set_message_part(
+                    ProtocolMessagePartKey::LatestBlockNumber,
+                    SIGNED_LATEST_BLOCK_NUMBER.to_string(),
+                );
+                certificate.signed_message = certificate.protocol_message.compute_hash();
+
+                let signers = context.fixture.signers_fixture();
+                let single_signatures = signers
+                    .iter()
+                    .filter_map(|s| s.protocol_signer.sign(certificate.signed_message.as_bytes()))
+                    .collect::<Vec<_>>();
+                let multi_signature =
+                    ProtocolClerk::new_clerk_from_signer(&signers[0].protocol_signer)
+                        .aggregate_signatures(
+                            &single_signatures,
+                            certificate.signed_message.as_bytes(),
+                        )
+                        .unwrap();
+                if let CertificateSignature::MultiSignature(signed_entity_type, _) =
+                    certificate.signature
+                {
+                    certificate.signature = CertificateSignature::MultiSignature(
+                        signed_entity_type,
+                        multi_signature.into(),
+                    );
+                }
+
+                certificate
+            })
+            .build()
+    }
+
+    fn save_proof_file(
+        path: &Path,
+        latest_block_number: BlockNumber,
+        certificate_chain: &CertificateChainFixture,
+    ) {
+        let certified_transactions: CardanoTransactionsSetProofMessagePart =
+            fake_set_proof().try_into().unwrap();
+        let proof_file = CardanoTransactionsProofFile {
+            proofs: CardanoTransactionsProofs::new(
+                &certificate_chain.latest_certificate().hash,
+                vec![certified_transactions],
+                vec![],
+                latest_block_number,
+            ),
+            certificate_chain: certificate_chain
+                .iter()
+                .map(|certificate| MithrilCertificate::try_from(certificate.clone()).unwrap())
+                .collect(),
+        };
+        proof_file.save(path).unwrap();
+    }
+
+    fn command_context() -> CommandContext {
+        CommandContext::new(
+            ConfigParameters::default(),
+            false,
+            true,
+            Logger::root(slog::Discard, slog::o!()),
+        )
+    }
+
+    #[tokio::test]
+    async fn verify_proof_file_signed_by_its_saved_certificate_chain() {
+        let path = temp_dir_create!().join("proof.json");
+        let certificate_chain = build_certificate_chain_signing_proof();
+        save_proof_file(&path, SIGNED_LATEST_BLOCK_NUMBER, &certificate_chain);
+        let command = CardanoTransactionsVerifyProofCommand {
+            genesis_verification_key: Some(
+                certificate_chain
+                    .genesis_verifier
+                    .to_verification_key()
+                    .to_json_hex()
+                    .unwrap(),
+            ),
+            proof_file: path,
+        };
+
+        command.execute(command_context()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn verify_proof_file_fails_if_its_saved_certificate_chain_is_incomplete() {
+        let path = temp_dir_create!().join("proof.json");
+        let mut certificate_chain = build_certificate_chain_signing_proof();
+        certificate_chain.certificates_chained.pop();
+        save_proof_file(&path, SIGNED_LATEST_BLOCK_NUMBER, &certificate_chain);
+        let command = CardanoTransactionsVerifyProofCommand {
+            genesis_verification_key: Some(
+                certificate_chain
+                    .genesis_verifier
+                    .to_verification_key()
+                    .to_json_hex()
+                    .unwrap(),
+            ),
+            proof_file: path,
+        };
+
+        command
+            .execute(command_context())
+            .await
+            .expect_err("Verifying a proof file without the genesis certificate should fail");
+    }
+
+    #[tokio::test]
+    async fn verify_proof_file_fails_if_the_proof_is_not_signed_by_its_certificate() {
+        let path = temp_dir_create!().join("proof.json");
+        let certificate_chain = build_certificate_chain_signing_proof();
+        save_proof_file(&path, BlockNumber(100), &certificate_chain);
+        let command = CardanoTransactionsVerifyProofCommand {
+            genesis_verification_key: Some(
+                certificate_chain
+                    .genesis_verifier
+                    .to_verification_key()
+                    .to_json_hex()
+                    .unwrap(),
+            ),
+            proof_file: path,
+        };
+
+        command
+            .execute(command_context())
+            .await
+            .expect_err("Verifying a proof not signed by its certificate should fail");
+    }
+}
@@ -7,7 +7,6 @@ mod snapshot_converter;
 
 pub use snapshot_converter::*;
 
-use anyhow::anyhow;
 use clap::Subcommand;
 use mithril_client::MithrilResult;
 
@@ -42,11 +41,7 @@ impl UTxOHDCommands {
     pub async fn execute(&self) -> MithrilResult<()> {
         match self {
             Self::SnapshotConverter(cmd) => {
-                if cfg!(target_os = "linux") && cfg!(target_arch = "aarch64") {
-                    return Err(anyhow!(
-                        "'snapshot-converter' command is not supported on Linux ARM"
-                    ));
-                }
+                SnapshotConverterCommand::check_platform_support()?;
                 cmd.execute().await
             }
         }
@@ -1,6 +1,6 @@
 use std::{
     env, fmt,
-    fs::{create_dir, read_dir, remove_dir_all, rename},
+    fs::{File, create_dir, create_dir_all, read_dir, remove_dir_all, remove_file, rename},
     path::{Path, PathBuf},
     process::Command,
 };
@@ -14,8 +14,8 @@ use mithril_client::{
 };
 
 use crate::utils::{
-    ArchiveUnpacker, GitHubReleaseRetriever, HttpDownloader, ReqwestGitHubApiClient,
-    ReqwestHttpDownloader, copy_dir, remove_dir_contents,
+    ArchiveUnpacker, GitHubAsset, GitHubRelease, GitHubReleaseRetriever, HttpDownloader,
+    ReqwestGitHubApiClient, ReqwestHttpDownloader, copy_dir, remove_dir_contents,
 };
 
 const GITHUB_ORGANIZATION: &str = "IntersectMBO";
@@ -28,6 +28,8 @@ const WORK_DIR: &str = "tmp";
 const CARDANO_DISTRIBUTION_DIR: &str = "cardano-node-distribution";
 const SNAPSHOTS_DIR: &str = "snapshots";
 
+const CACHED_DISTRIBUTION_MARKER_FILE: &str = ".unpacked";
+
 const SNAPSHOT_CONVERTER_BIN_DIR: &str = "bin";
 const SNAPSHOT_CONVERTER_BIN_NAME_UNIX: &str = "snapshot-converter";
 const SNAPSHOT_CONVERTER_BIN_NAME_WINDOWS: &str = "snapshot-converter.exe";
@@ -39,8 +41,9 @@ const PROTOCOL_MAGIC_ID_FILE: &str = "protocolMagicId";
 
 const CONVERSION_FALLBACK_LIMIT: usize = 2;
 
+/// UTxO-HD flavor of a ledger state snapshot.
 #[derive(Debug, Clone, ValueEnum, Eq, PartialEq)]
-enum UTxOHDFlavor {
+pub(crate) enum UTxOHDFlavor {
     #[clap(name = "Legacy")]
     Legacy,
     #[clap(name = "LMDB")]
@@ -90,6 +93,56 @@ impl TryFrom<CardanoNetwork> for CardanoNetworkCliArg {
     }
 }
 
+/// Source of the `snapshot-converter` binary used to convert a restored ledger state snapshot.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum SnapshotConverterSource {
+    /// Download the `cardano-node` distribution of the given version (`latest` and `pre-release`
+    /// are also supported), reusing it if it was already unpacked in the cache directory.
+    CachedDistribution {
+        cardano_node_version: String,
+        cache_dir: PathBuf,
+        github_token: Option<String>,
+    },
+    /// Use a `snapshot-converter` binary already available on the host.
+    ///
+    /// If no configuration file is given, the one of the `cardano-node` distribution containing
+    /// the binary is used.
+    LocalBinary {
+        converter_bin: PathBuf,
+        config_path: Option<PathBuf>,
+    },
+}
+
+impl SnapshotConverterSource {
+    /// Default directory where the downloaded `cardano-node` distributions are cached: the user
+    /// cache directory if it exists on the platform, the temporary directory otherwise.
+    pub(crate) fn default_distribution_cache_dir() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(env::temp_dir)
+            .join("mithril-client")
+            .join("cardano-node-distributions")
+    }
+}
+
+/// Destination of the messages printed while converting a ledger state snapshot.
+///
+/// When the conversion is part of another command, the messages are printed to the standard
+/// error so they do not mix with the command output (i.e. its JSON output).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ConversionMessagesOutput {
+    Stdout,
+    Stderr,
+}
+
+impl ConversionMessagesOutput {
+    fn print(&self, message: &str) {
+        match self {
+            Self::Stdout => println!("{message}"),
+            Self::Stderr => eprintln!("{message}"),
+        }
+    }
+}
+
 #[cfg_attr(test, mockall::automock)]
 trait SnapshotConverter {
     fn convert(&self, input_path: &Path, output_path: &Path) -> MithrilResult<()>;
@@ -220,13 +273,23 @@ impl SnapshotConverterCommand {
                     )
                 })?
             };
+            let converter_bin = SnapshotConverterBin {
+                converter_bin: Self::get_snapshot_converter_binary_path(
+                    &distribution_dir,
+                    env::consts::OS,
+                )?,
+                config_path: Self::get_snapshot_converter_config_path(
+                    &distribution_dir,
+                    &cardano_network,
+                ),
+                utxo_hd_flavor: self.utxo_hd_flavor.clone(),
+            };
             Self::convert_ledger_state_snapshot(
                 &work_dir,
                 &self.db_directory,
-                &distribution_dir,
-                &cardano_network,
-                &self.utxo_hd_flavor,
+                converter_bin,
                 self.commit,
+                ConversionMessagesOutput::Stdout,
             )
             .with_context(|| {
                 format!(
@@ -249,6 +312,96 @@ impl SnapshotConverterCommand {
         result
     }
 
+    /// Convert the most recent ledger state snapshot of a restored Cardano database to the given
+    /// flavor, then replace the ledger state of the database with the converted snapshot.
+    ///
+    /// Since it is called as a step of other commands, its messages are printed to the standard
+    /// error.
+    pub(crate) async fn convert_restored_ledger_state_snapshot(
+        db_dir: &Path,
+        utxo_hd_flavor: &UTxOHDFlavor,
+        converter_source: &SnapshotConverterSource,
+    ) -> MithrilResult<()> {
+        Self::check_platform_support()?;
+        let cardano_network = Self::detect_cardano_network(db_dir).with_context(|| {
+            format!(
+                "Could not detect Cardano network from the database directory: {}",
+                db_dir.display()
+            )
+        })?;
+        let (converter_bin, config_path) = match converter_source {
+            SnapshotConverterSource::CachedDistribution {
+                cardano_node_version,
+                cache_dir,
+                github_token,
+            } => {
+                let distribution_dir = Self::get_cached_cardano_node_distribution(
+                    ReqwestGitHubApiClient::new(github_token.clone())?,
+                    ReqwestHttpDownloader::new()?,
+                    cardano_node_version,
+                    cache_dir,
+                )
+                .await
+                .with_context(|| "Failed to get Cardano node distribution")?;
+
+                (
+                    Self::get_snapshot_converter_binary_path(&distribution_dir, env::consts::OS)?,
+                    Self::get_snapshot_converter_config_path(&distribution_dir, &cardano_network),
+                )
+            }
+            SnapshotConverterSource::LocalBinary {
+                converter_bin,
+                config_path,
+            } => Self::get_local_snapshot_converter_paths(
+                converter_bin,
+                config_path.as_deref(),
+                &cardano_network,
+            )?,
+        };
+
+        let work_dir = db_dir.join(WORK_DIR);
+        create_dir(&work_dir).with_context(|| {
+            format!(
+                "Failed to create snapshot converter work directory: {}",
+                work_dir.display()
+            )
+        })?;
+        let converter_bin = SnapshotConverterBin {
+            converter_bin,
+            config_path,
+            utxo_hd_flavor: utxo_hd_flavor.clone(),
+        };
+        let result = Self::convert_ledger_state_snapshot(
+            &work_dir,
+            db_dir,
+            converter_bin,
+            true,
+            ConversionMessagesOutput::Stderr,
+        )
+        .with_context(|| format!("Failed to convert ledger snapshot to flavor: {utxo_hd_flavor}"));
+
+        if let Err(e) = remove_dir_all(&work_dir) {
+            eprintln!(
+                "Failed to clean up temporary directory {} after execution: {}",
+                work_dir.display(),
+                e
+            );
+        }
+
+        result
+    }
+
+    /// Check that the `snapshot-converter` binary is available for the current platform.
+    pub(crate) fn check_platform_support() -> MithrilResult<()> {
+        if cfg!(target_os = "linux") && cfg!(target_arch = "aarch64") {
+            return Err(anyhow!(
+                "'snapshot-converter' command is not supported on Linux ARM"
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn download_cardano_node_distribution(
         github_api_client: impl GitHubReleaseRetriever,
         http_downloader: impl HttpDownloader,
@@ -256,6 +409,128 @@ impl SnapshotConverterCommand {
         target_dir: &Path,
     ) -> MithrilResult<PathBuf> {
         println!("Downloading Cardano node distribution for tag: '{tag}'...");
+        let asset = Self::get_cardano_node_distribution_asset(&github_api_client, tag).await?;
+        let archive_path = http_downloader
+            .download_file(asset.browser_download_url.parse()?, target_dir, &asset.name)
+            .await?;
+
+        println!(
+            "Distribution downloaded successfully. Archive location: {}",
+            archive_path.display()
+        );
+
+        Ok(archive_path)
+    }
+
+    /// Returns the directory of the unpacked `cardano-node` distribution for the given tag,
+    /// downloading and unpacking it in the cache directory only if it is not already there.
+    ///
+    /// The distributions are cached by release tag: a pinned tag is looked up in the cache
+    /// before calling the GitHub API, so a cached distribution can be used offline, while the
+    /// `latest` and `pre-release` tags are still resolved at each call.
+    ///
+    /// Since it is called as a step of other commands, its messages are printed to the standard
+    /// error.
+    async fn get_cached_cardano_node_distribution(
+        github_api_client: impl GitHubReleaseRetriever,
+        http_downloader: impl HttpDownloader,
+        tag: &str,
+        cache_dir: &Path,
+    ) -> MithrilResult<PathBuf> {
+        let is_pinned_tag = ![LATEST_DISTRIBUTION_TAG, PRERELEASE_DISTRIBUTION_TAG].contains(&tag);
+        if is_pinned_tag && Self::is_distribution_cached(cache_dir, tag) {
+            let distribution_dir = cache_dir.join(tag);
+            eprintln!(
+                "Using cached Cardano node distribution for tag '{tag}': {}",
+                distribution_dir.display()
+            );
+            return Ok(distribution_dir);
+        }
+
+        let release = Self::get_cardano_node_distribution_release(&github_api_client, tag).await?;
+        let distribution_dir = cache_dir.join(&release.tag_name);
+        if Self::is_distribution_cached(cache_dir, &release.tag_name) {
+            eprintln!(
+                "Using cached Cardano node distribution for tag '{tag}': {}",
+                distribution_dir.display()
+            );
+            return Ok(distribution_dir);
+        }
+        let asset = Self::get_release_asset_for_current_platform(&release)?;
+        let marker_file = distribution_dir.join(CACHED_DISTRIBUTION_MARKER_FILE);
+
+        // A distribution directory without marker comes from an interrupted run
+        if distribution_dir.exists() {
+            remove_dir_all(&distribution_dir).with_context(|| {
+                format!(
+                    "Failed to remove incomplete cached distribution: {}",
+                    distribution_dir.display()
+                )
+            })?;
+        }
+        create_dir_all(&distribution_dir).with_context(|| {
+            format!(
+                "Failed to create distribution directory: {}",
+                distribution_dir.display()
+            )
+        })?;
+
+        eprintln!("Downloading Cardano node distribution for tag: '{tag}'...");
+        let archive_path = http_downloader
+            .download_file(
+                asset.browser_download_url.parse()?,
+                &distribution_dir,
+                &asset.name,
+            )
+            .await?;
+        ArchiveUnpacker::default()
+            .unpack(&archive_path, &distribution_dir)
+            .with_context(|| {
+                format!(
+                    "Failed to unpack distribution to directory: {}",
+                    distribution_dir.display()
+                )
+            })?;
+        remove_file(&archive_path).with_context(|| {
+            format!(
+                "Failed to remove distribution archive: {}",
+                archive_path.display()
+            )
+        })?;
+        File::create(&marker_file).with_context(|| {
+            format!(
+                "Failed to create cached distribution marker file: {}",
+                marker_file.display()
+            )
+        })?;
+        eprintln!(
+            "Distribution unpacked successfully to: {}",
+            distribution_dir.display()
+        );
+
+        Ok(distribution_dir)
+    }
+
+    fn is_distribution_cached(cache_dir: &Path, release_tag: &str) -> bool {
+        cache_dir
+            .join(release_tag)
+            .join(CACHED_DISTRIBUTION_MARKER_FILE)
+            .exists()
+    }
+
+    async fn get_cardano_node_distribution_asset(
+        github_api_client: &impl GitHubReleaseRetriever,
+        tag: &str,
+    ) -> MithrilResult<GitHubAsset> {
+        let release = Self::get_cardano_node_distribution_release(github_api_client, tag).await?;
+
+        Self::get_release_asset_for_current_platform(&release)
+    }
+
+    async fn get_cardano_node_distribution_release(
+        github_api_client: &impl GitHubReleaseRetriever,
+        tag: &str,
+    ) -> MithrilResult<GitHubRelease> {
         let release = match tag {
             LATEST_DISTRIBUTION_TAG => github_api_client
                 .get_latest_release(GITHUB_ORGANIZATION, GITHUB_REPOSITORY)
@@ -270,6 +545,13 @@ impl SnapshotConverterCommand {
                 .await
                 .with_context(|| format!("Failed to get release by tag: {tag}"))?,
         };
+
+        Ok(release)
+    }
+
+    fn get_release_asset_for_current_platform(
+        release: &GitHubRelease,
+    ) -> MithrilResult<GitHubAsset> {
         let asset = release
             .get_asset_for_os(env::consts::OS)?
             .ok_or_else(|| anyhow!("No asset found for platform: {}", env::consts::OS))
@@ -279,50 +561,40 @@ impl SnapshotConverterCommand {
                     env::consts::OS
                 )
             })?;
-        let archive_path = http_downloader
-            .download_file(asset.browser_download_url.parse()?, target_dir, &asset.name)
-            .await?;
 
-        println!(
-            "Distribution downloaded successfully. Archive location: {}",
-            archive_path.display()
-        );
-
-        Ok(archive_path)
+        Ok(asset.clone())
     }
 
     fn convert_ledger_state_snapshot(
         work_dir: &Path,
         db_dir: &Path,
-        distribution_dir: &Path,
-        cardano_network: &CardanoNetworkCliArg,
-        utxo_hd_flavor: &UTxOHDFlavor,
+        converter_bin: SnapshotConverterBin,
         commit: bool,
+        messages_output: ConversionMessagesOutput,
     ) -> MithrilResult<()> {
-        println!("Converting ledger state snapshot to '{utxo_hd_flavor}' flavor");
-        let converter_bin =
-            Self::get_snapshot_converter_binary_path(distribution_dir, env::consts::OS)?;
-        let config_path =
-            Self::get_snapshot_converter_config_path(distribution_dir, cardano_network);
+        let utxo_hd_flavor = converter_bin.utxo_hd_flavor.clone();
+        messages_output.print(&format!(
+            "Converting ledger state snapshot to '{utxo_hd_flavor}' flavor"
+        ));
         let snapshots = Self::find_most_recent_snapshots(db_dir, CONVERSION_FALLBACK_LIMIT)?;
-        let converter_bin = SnapshotConverterBin {
-            converter_bin,
-            config_path,
-            utxo_hd_flavor: utxo_hd_flavor.clone(),
-        };
         let converted_snapshot_path = Self::try_convert(
             work_dir,
-            utxo_hd_flavor,
+            &utxo_hd_flavor,
             &snapshots,
             Box::new(converter_bin),
+            messages_output,
         )?;
 
         if commit {
-            Self::commit_converted_snapshot(db_dir, &converted_snapshot_path).with_context(
-                || "Failed to overwrite the ledger state with the converted snapshot.",
-            )?;
+            Self::commit_converted_snapshot(db_dir, &converted_snapshot_path, messages_output)
+                .with_context(
+                    || "Failed to overwrite the ledger state with the converted snapshot.",
+                )?;
         } else {
-            println!("Snapshot location: {}", converted_snapshot_path.display());
+            messages_output.print(&format!(
+                "Snapshot location: {}",
+                converted_snapshot_path.display()
+            ));
         }
 
         Ok(())
@@ -333,12 +605,17 @@ impl SnapshotConverterCommand {
         utxo_hd_flavor: &UTxOHDFlavor,
         snapshots: &[PathBuf],
         converter: Box<dyn SnapshotConverter>,
+        messages_output: ConversionMessagesOutput,
     ) -> MithrilResult<PathBuf> {
         let snapshots_dir = work_dir.join(SNAPSHOTS_DIR);
 
         for (i, snapshot) in snapshots.iter().enumerate() {
             let attempt = i + 1;
-            println!("Converting '{}' (attempt #{})", snapshot.display(), attempt);
+            messages_output.print(&format!(
+                "Converting '{}' (attempt #{})",
+                snapshot.display(),
+                attempt
+            ));
 
             let input_path = copy_dir(snapshot, &snapshots_dir)?;
             let output_path = Self::compute_converted_snapshot_output_path(
@@ -350,10 +627,10 @@ impl SnapshotConverterCommand {
             match converter.convert(&input_path, &output_path) {
                 Ok(()) => {
                     return {
-                        println!(
+                        messages_output.print(&format!(
                             "Successfully converted ledger state snapshot: '{}'",
                             snapshot.display()
-                        );
+                        ));
 
                         Ok(output_path)
                     };
@@ -398,6 +675,47 @@ impl SnapshotConverterCommand {
             .join(SNAPSHOT_CONVERTER_CONFIG_FILE)
     }
 
+    /// Returns the paths of a `snapshot-converter` binary available on the host and of its
+    /// configuration file.
+    ///
+    /// If not given, the configuration file is looked up in the `cardano-node` distribution
+    /// containing the binary.
+    fn get_local_snapshot_converter_paths(
+        converter_bin: &Path,
+        config_path: Option<&Path>,
+        network: &CardanoNetworkCliArg,
+    ) -> MithrilResult<(PathBuf, PathBuf)> {
+        if !converter_bin.is_file() {
+            return Err(anyhow!(
+                "Snapshot converter binary not found: {}",
+                converter_bin.display()
+            ));
+        }
+        let config_path = match config_path {
+            Some(config_path) => config_path.to_path_buf(),
+            None => {
+                let distribution_dir = converter_bin
+                    .parent()
+                    .and_then(Path::parent)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "Could not find the distribution directory of the snapshot converter binary: {}",
+                            converter_bin.display()
+                        )
+                    })?;
+                Self::get_snapshot_converter_config_path(distribution_dir, network)
+            }
+        };
+        if !config_path.is_file() {
+            return Err(anyhow!(
+                "Snapshot converter configuration file not found: {}",
+                config_path.display()
+            ));
+        }
+
+        Ok((converter_bin.to_path_buf(), config_path))
+    }
+
     /// Returns the list of valid ledger snapshot directories sorted in ascending order of slot number.
     ///
     /// Only directories with numeric names are considered valid snapshots.
@@ -482,13 +800,14 @@ impl SnapshotConverterCommand {
     fn commit_converted_snapshot(
         db_dir: &Path,
         converted_snapshot_path: &Path,
+        messages_output: ConversionMessagesOutput,
     ) -> MithrilResult<()> {
         let ledger_dir = db_dir.join(LEDGER_DIR);
-        println!(
+        messages_output.print(&format!(
             "Upgrading and replacing ledger state in {} with converted snapshot: {}",
             ledger_dir.display(),
             converted_snapshot_path.display()
-        );
+        ));
         let filename = converted_snapshot_path
             .file_name()
             .ok_or_else(|| anyhow!("Missing filename in converted snapshot path"))?
@@ -675,6 +994,261 @@ mod tests {
         }
     }
 
+    mod get_cached_cardano_node_distribution {
+        use flate2::{Compression, write::GzEncoder};
+        use mockall::predicate::eq;
+        use tar::{Builder, Header};
+
+        use crate::utils::{
+            ASSET_PLATFORM_LINUX, ASSET_PLATFORM_MACOS, ASSET_PLATFORM_WINDOWS, GitHubRelease,
+            MockGitHubReleaseRetriever, MockHttpDownloader,
+        };
+
+        use super::*;
+
+        fn release_with_tar_gz_assets() -> GitHubRelease {
+            GitHubRelease {
+                tag_name: "10.5.1".to_string(),
+                assets: [ASSET_PLATFORM_LINUX, ASSET_PLATFORM_MACOS, ASSET_PLATFORM_WINDOWS]
+                    .iter()
+                    .map(|platform| GitHubAsset {
+                        name: format!("cardano-node-10.5.1-{platform}.tar.gz"),
+                        browser_download_url: format!("https://release-assets.com/{platform}"),
+                    })
+                    .collect(),
+                ..GitHubRelease::default()
+            }
+        }
+
+        fn github_api_client_returning(release: GitHubRelease) -> MockGitHubReleaseRetriever {
+            let mut github_api_client = MockGitHubReleaseRetriever::new();
+            github_api_client
+                .expect_get_latest_release()
+                .returning(move |_, _| Ok(release.clone()));
+
+            github_api_client
+        }
+
+        fn write_distribution_archive(archive_path: &Path) {
+            let tar_gz_file = File::create(archive_path).unwrap();
+            let encoder = GzEncoder::new(tar_gz_file, Compression::default());
+            let mut tar_builder = Builder::new(encoder);
+            let content = b"whatever binary";
+            let mut header = Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_cksum();
+            tar_builder
+                .append_data(&mut header, "bin/snapshot-converter", &content[..])
+                .unwrap();
+            tar_builder.finish().unwrap();
+        }
+
+        #[tokio::test]
+        async fn downloads_and_unpacks_distribution_if_not_cached() {
+            let cache_dir = temp_dir_create!();
+            let release = release_with_tar_gz_assets();
+            let asset = release.get_asset_for_os(env::consts::OS).unwrap().unwrap().clone();
+            let expected_distribution_dir = cache_dir.join(&release.tag_name);
+
+            let mut http_downloader = MockHttpDownloader::new();
+            http_downloader
+                .expect_download_file()
+                .with(
+                    eq(reqwest::Url::parse(&asset.browser_download_url).unwrap()),
+                    eq(expected_distribution_dir.clone()),
+                    eq(asset.name.clone()),
+                )
+                .times(1)
+                .returning(|_, download_dir, filename| {
+                    let archive_path = download_dir.join(filename);
+                    write_distribution_archive(&archive_path);
+                    Ok(archive_path)
+                });
+
+            let distribution_dir = SnapshotConverterCommand::get_cached_cardano_node_distribution(
+                github_api_client_returning(release),
+                http_downloader,
+                LATEST_DISTRIBUTION_TAG,
+                &cache_dir,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(expected_distribution_dir, distribution_dir);
+            assert!(distribution_dir.join("bin").join("snapshot-converter").exists());
+            assert!(distribution_dir.join(CACHED_DISTRIBUTION_MARKER_FILE).exists());
+            assert!(!distribution_dir.join(&asset.name).exists());
+        }
+
+        #[tokio::test]
+        async fn reuses_cached_distribution_without_downloading_it() {
+            let cache_dir = temp_dir_create!();
+            let release = release_with_tar_gz_assets();
+            let cached_distribution_dir = cache_dir.join(&release.tag_name);
+            create_dir_all(&cached_distribution_dir).unwrap();
+            File::create(cached_distribution_dir.join(CACHED_DISTRIBUTION_MARKER_FILE)).unwrap();
+
+            let mut http_downloader = MockHttpDownloader::new();
+            http_downloader.expect_download_file().never();
+
+            let distribution_dir = SnapshotConverterCommand::get_cached_cardano_node_distribution(
+                github_api_client_returning(release),
+                http_downloader,
+                LATEST_DISTRIBUTION_TAG,
+                &cache_dir,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(cached_distribution_dir, distribution_dir);
+        }
+
+        #[tokio::test]
+        async fn reuses_cached_distribution_of_pinned_tag_without_calling_github_api() {
+            let cache_dir = temp_dir_create!();
+            let cached_distribution_dir = cache_dir.join("10.5.1");
+            create_dir_all(&cached_distribution_dir).unwrap();
+            File::create(cached_distribution_dir.join(CACHED_DISTRIBUTION_MARKER_FILE)).unwrap();
+
+            let mut github_api_client = MockGitHubReleaseRetriever::new();
+            github_api_client.expect_get_release_by_tag().never();
+            let mut http_downloader = MockHttpDownloader::new();
+            http_downloader.expect_download_file().never();
+
+            let distribution_dir = SnapshotConverterCommand::get_cached_cardano_node_distribution(
+                github_api_client,
+                http_downloader,
+                "10.5.1",
+                &cache_dir,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(cached_distribution_dir, distribution_dir);
+        }
+
+        #[tokio::test]
+        async fn replaces_incomplete_cached_distribution() {
+            let cache_dir = temp_dir_create!();
+            let release = release_with_tar_gz_assets();
+            let cached_distribution_dir = cache_dir.join(&release.tag_name);
+            create_dir_all(&cached_distribution_dir).unwrap();
+            File::create(cached_distribution_dir.join("partial_file")).unwrap();
+
+            let mut http_downloader = MockHttpDownloader::new();
+            http_downloader.expect_download_file().times(1).returning(
+                |_, download_dir, filename| {
+                    let archive_path = download_dir.join(filename);
+                    write_distribution_archive(&archive_path);
+                    Ok(archive_path)
+                },
+            );
+
+            let distribution_dir = SnapshotConverterCommand::get_cached_cardano_node_distribution(
+                github_api_client_returning(release),
+                http_downloader,
+                LATEST_DISTRIBUTION_TAG,
+                &cache_dir,
+            )
+            .await
+            .unwrap();
+
+            assert!(!distribution_dir.join("partial_file").exists());
+            assert!(distribution_dir.join(CACHED_DISTRIBUTION_MARKER_FILE).exists());
+        }
+    }
+
+    mod get_local_snapshot_converter_paths {
+        use super::*;
+
+        fn create_distribution(distribution_dir: &Path, network: &CardanoNetworkCliArg) {
+            create_dir_all(distribution_dir.join(SNAPSHOT_CONVERTER_BIN_DIR)).unwrap();
+            File::create(
+                distribution_dir
+                    .join(SNAPSHOT_CONVERTER_BIN_DIR)
+                    .join(SNAPSHOT_CONVERTER_BIN_NAME_UNIX),
+            )
+            .unwrap();
+            let config_path = SnapshotConverterCommand::get_snapshot_converter_config_path(
+                distribution_dir,
+                network,
+            );
+            create_dir_all(config_path.parent().unwrap()).unwrap();
+            File::create(config_path).unwrap();
+        }
+
+        #[test]
+        fn uses_config_of_the_distribution_containing_the_binary_if_not_given() {
+            let distribution_dir = temp_dir_create!();
+            let network = CardanoNetworkCliArg::Preprod;
+            create_distribution(&distribution_dir, &network);
+            let converter_bin = distribution_dir
+                .join(SNAPSHOT_CONVERTER_BIN_DIR)
+                .join(SNAPSHOT_CONVERTER_BIN_NAME_UNIX);
+
+            let (bin_path, config_path) =
+                SnapshotConverterCommand::get_local_snapshot_converter_paths(
+                    &converter_bin,
+                    None,
+                    &network,
+                )
+                .unwrap();
+
+            assert_eq!(converter_bin, bin_path);
+            assert_eq!(
+                SnapshotConverterCommand::get_snapshot_converter_config_path(
+                    &distribution_dir,
+                    &network
+                ),
+                config_path
+            );
+        }
+
+        #[test]
+        fn uses_given_config() {
+            let temp_dir = temp_dir_create!();
+            let converter_bin = temp_dir.join(SNAPSHOT_CONVERTER_BIN_NAME_UNIX);
+            File::create(&converter_bin).unwrap();
+            let given_config_path = temp_dir.join("custom-config.json");
+            File::create(&given_config_path).unwrap();
+
+            let (_, config_path) = SnapshotConverterCommand::get_local_snapshot_converter_paths(
+                &converter_bin,
+                Some(&given_config_path),
+                &CardanoNetworkCliArg::Mainnet,
+            )
+            .unwrap();
+
+            assert_eq!(given_config_path, config_path);
+        }
+
+        #[test]
+        fn fails_if_binary_does_not_exist() {
+            let temp_dir = temp_dir_create!();
+
+            SnapshotConverterCommand::get_local_snapshot_converter_paths(
+                &temp_dir.join(SNAPSHOT_CONVERTER_BIN_NAME_UNIX),
+                None,
+                &CardanoNetworkCliArg::Mainnet,
+            )
+            .expect_err("Should fail if the binary does not exist");
+        }
+
+        #[test]
+        fn fails_if_config_does_not_exist() {
+            let temp_dir = temp_dir_create!();
+            let converter_bin = temp_dir.join(SNAPSHOT_CONVERTER_BIN_NAME_UNIX);
+            File::create(&converter_bin).unwrap();
+
+            SnapshotConverterCommand::get_local_snapshot_converter_paths(
+                &converter_bin,
+                Some(&temp_dir.join("missing-config.json")),
+                &CardanoNetworkCliArg::Mainnet,
+            )
+            .expect_err("Should fail if the configuration file does not exist");
+        }
+    }
+
     mod get_snapshot_converter_binary_path {
         use super::*;
 
@@ -885,8 +1459,12 @@ mod tests {
             File::create(&converted_snapshot).unwrap();
 
             assert!(previous_snapshot.exists());
-            SnapshotConverterCommand::commit_converted_snapshot(&tmp_dir, &converted_snapshot)
-                .unwrap();
+            SnapshotConverterCommand::commit_converted_snapshot(
+                &tmp_dir,
+                &converted_snapshot,
+                ConversionMessagesOutput::Stdout,
+            )
+            .unwrap();
 
             assert!(!previous_snapshot.exists());
             assert!(ledger_dir.join("456").exists());
@@ -903,8 +1481,12 @@ mod tests {
             let converted_snapshot = tmp_dir.join("456");
             File::create(&converted_snapshot).unwrap();
 
-            SnapshotConverterCommand::commit_converted_snapshot(&tmp_dir, &converted_snapshot)
-                .expect_err("Should fail if converted snapshot has invalid filename");
+            SnapshotConverterCommand::commit_converted_snapshot(
+                &tmp_dir,
+                &converted_snapshot,
+                ConversionMessagesOutput::Stdout,
+            )
+            .expect_err("Should fail if converted snapshot has invalid filename");
 
             assert!(previous_snapshot.exists());
         }
@@ -1201,6 +1783,7 @@ mod tests {
                 &UTxOHDFlavor::Lmdb,
                 &snapshots,
                 Box::new(converter),
+                ConversionMessagesOutput::Stdout,
             )
             .unwrap();
         }
@@ -1233,6 +1816,7 @@ mod tests {
                 &UTxOHDFlavor::Lmdb,
                 &snapshots,
                 Box::new(converter),
+                ConversionMessagesOutput::Stdout,
             )
             .expect("Should succeed even if the first conversion fails");
         }
@@ -1252,6 +1836,7 @@ mod tests {
                 &UTxOHDFlavor::Lmdb,
                 &snapshots,
                 Box::new(converter),
+                ConversionMessagesOutput::Stdout,
             )
             .expect_err("Should fail if all conversion attempts fail");
         }
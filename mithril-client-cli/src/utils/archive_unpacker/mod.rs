@@ -1,5 +1,7 @@
 mod interface;
 mod tar_gz_unpacker;
+mod tar_lz4_unpacker;
+mod tar_xz_unpacker;
 mod unpacker;
 mod zip_unpacker;
 
@@ -0,0 +1,102 @@
+use std::{fs::File, path::Path};
+
+use anyhow::Context;
+use lz4_flex::frame::FrameDecoder;
+use tar::Archive;
+
+use mithril_client::MithrilResult;
+
+use super::ArchiveFormat;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct TarLz4Unpacker;
+
+impl ArchiveFormat for TarLz4Unpacker {
+    fn unpack(&self, archive_path: &Path, unpack_dir: &Path) -> MithrilResult<()> {
+        let archive = File::open(archive_path)
+            .with_context(|| format!("Could not open archive file '{}'", archive_path.display()))?;
+        let lz4_decoder = FrameDecoder::new(archive);
+        let mut file_archive = Archive::new(lz4_decoder);
+        file_archive.unpack(unpack_dir).with_context(|| {
+            format!(
+                "Could not unpack '{}' with 'Lz4' to directory '{}'",
+                archive_path.display(),
+                unpack_dir.display()
+            )
+        })?;
+
+        Ok(())
+    }
+
+    fn supports(&self, path: &Path) -> bool {
+        path.extension().and_then(|e| e.to_str()) == Some("lz4")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+
+    use lz4_flex::frame::FrameEncoder;
+    use tar::{Builder, Header};
+
+    use mithril_common::{assert_dir_eq, temp_dir_create};
+
+    use super::*;
+
+    #[test]
+    fn unpack_tar_archive_extracts_all_files() {
+        let temp_dir = temp_dir_create!();
+        let archive_path = temp_dir.join("archive.tar.lz4");
+
+        {
+            let tar_file = File::create(&archive_path).unwrap();
+            let encoder = FrameEncoder::new(tar_file);
+            let mut tar_builder = Builder::new(encoder);
+
+            let content = b"root content";
+            let mut header = Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_cksum();
+            tar_builder
+                .append_data(&mut header, "root.txt", &content[..])
+                .unwrap();
+
+            let content = b"nested content";
+            let mut header = Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_cksum();
+            tar_builder
+                .append_data(&mut header, "nested/dir/nested-file.txt", &content[..])
+                .unwrap();
+
+            tar_builder.into_inner().unwrap().finish().unwrap();
+        }
+
+        TarLz4Unpacker.unpack(&archive_path, &temp_dir).unwrap();
+
+        assert_dir_eq! {
+            &temp_dir,
+            "* nested/
+            ** dir/
+            *** nested-file.txt
+            * archive.tar.lz4
+            * root.txt"
+        };
+
+        let root_file_content = fs::read_to_string(temp_dir.join("root.txt")).unwrap();
+        assert_eq!(root_file_content, "root content");
+
+        let nested_file_content =
+            fs::read_to_string(temp_dir.join("nested/dir/nested-file.txt")).unwrap();
+        assert_eq!(nested_file_content, "nested content");
+    }
+
+    #[test]
+    fn supported_file_extension() {
+        assert!(TarLz4Unpacker.supports(Path::new("archive.tar.lz4")));
+        assert!(TarLz4Unpacker.supports(Path::new("archive.lz4")));
+        assert!(!TarLz4Unpacker.supports(Path::new("archive.tar")));
+        assert!(!TarLz4Unpacker.supports(Path::new("archive.whatever")));
+    }
+}
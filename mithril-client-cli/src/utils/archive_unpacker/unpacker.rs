@@ -4,7 +4,10 @@ use anyhow::anyhow;
 
 use mithril_client::MithrilResult;
 
-use super::{ArchiveFormat, tar_gz_unpacker::TarGzUnpacker, zip_unpacker::ZipUnpacker};
+use super::{
+    ArchiveFormat, tar_gz_unpacker::TarGzUnpacker, tar_lz4_unpacker::TarLz4Unpacker,
+    tar_xz_unpacker::TarXzUnpacker, zip_unpacker::ZipUnpacker,
+};
 
 pub struct ArchiveUnpacker {
     supported_formats: Vec<Box<dyn ArchiveFormat>>,
@@ -13,7 +16,12 @@ pub struct ArchiveUnpacker {
 impl Default for ArchiveUnpacker {
     fn default() -> Self {
         Self {
-            supported_formats: vec![Box::new(TarGzUnpacker), Box::new(ZipUnpacker)],
+            supported_formats: vec![
+                Box::new(TarGzUnpacker),
+                Box::new(TarLz4Unpacker),
+                Box::new(TarXzUnpacker),
+                Box::new(ZipUnpacker),
+            ],
         }
     }
 }
@@ -84,6 +84,21 @@ impl CardanoDbDownloadChecker {
         Self::check_disk_space_for_archive(pathdir, size, compression_algorithm)
     }
 
+    /// Check all prerequisites are met before starting to download and unpack a cardano db
+    /// archive in streaming mode.
+    ///
+    /// The archive is not stored on the disk, so only the space of the unpacked files is needed.
+    pub fn check_prerequisites_for_streamed_archive(
+        pathdir: &Path,
+        size: u64,
+        compression_algorithm: CompressionAlgorithm,
+    ) -> MithrilResult<()> {
+        Self::check_path_is_an_empty_dir(pathdir)?;
+        Self::check_dir_writable(pathdir)?;
+        let unpacked_size = (compression_algorithm.free_space_snapshot_ratio() - 1.0) * size as f64;
+        Self::check_disk_space_for_uncompressed_data(pathdir, unpacked_size as u64)
+    }
+
     /// Check all prerequisites are met before starting to download and unpack cardano db archives.
     pub fn check_prerequisites_for_uncompressed_data(
         pathdir: &Path,
@@ -319,6 +334,30 @@ mod test {
         .expect("check_disk_space_for_uncompressed_data should not fail");
     }
 
+    #[test]
+    fn check_prerequisites_for_streamed_archive_only_require_the_space_of_the_unpacked_files() {
+        let pathdir = create_temporary_empty_directory("streamed_archive").join("target_directory");
+        fs::create_dir_all(&pathdir).unwrap();
+        let free_space = fs2::available_space(&pathdir).unwrap();
+        let compression_algorithm = CompressionAlgorithm::Gzip;
+        // An archive that fits once unpacked but not when stored along its unpacked files
+        let archive_size =
+            (free_space as f64 / compression_algorithm.free_space_snapshot_ratio() * 1.1) as u64;
+
+        CardanoDbDownloadChecker::check_disk_space_for_archive(
+            &pathdir,
+            archive_size,
+            compression_algorithm,
+        )
+        .expect_err("check_disk_space_for_archive should fail");
+        CardanoDbDownloadChecker::check_prerequisites_for_streamed_archive(
+            &pathdir,
+            archive_size,
+            compression_algorithm,
+        )
+        .expect("check_prerequisites_for_streamed_archive should not fail");
+    }
+
     #[test]
     fn check_prerequisites_for_uncompressed_data_return_error_without_allow_override_and_directory_not_empty()
      {
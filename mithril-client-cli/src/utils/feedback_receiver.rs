@@ -114,6 +114,16 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                 }
                 *ancillary_download_progress_reporter = None;
             }
+            MithrilEvent::SnapshotFileDownloadedFromLocation {
+                download_id: _,
+                file_name,
+                location,
+            } => {
+                slog::debug!(
+                    self.logger, "Snapshot file downloaded from location";
+                    "file_name" => file_name, "location" => location
+                );
+            }
             MithrilEvent::CardanoDatabase(cardano_database_event) => match cardano_database_event {
                 MithrilEventCardanoDatabase::Started {
                     download_id: _,
@@ -145,6 +155,16 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                 | MithrilEventCardanoDatabase::ImmutableDownloadProgress { .. } => {
                     // Ignore those events as those downloads are fast enough that we don't need to show progress bars
                 }
+                MithrilEventCardanoDatabase::FileDownloadedFromLocation {
+                    download_id: _,
+                    file_name,
+                    location,
+                } => {
+                    slog::debug!(
+                        self.logger, "File downloaded from location";
+                        "file_name" => file_name, "location" => location
+                    );
+                }
                 MithrilEventCardanoDatabase::ImmutableDownloadCompleted {
                     immutable_file_number: _,
                     download_id: _,
@@ -15,6 +15,7 @@ pub struct GitHubAsset {
 
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct GitHubRelease {
+    pub tag_name: String,
     pub assets: Vec<GitHubAsset>,
     pub prerelease: bool,
 }
@@ -22,9 +22,11 @@ use tokio::sync::RwLock;
 use mithril_common::MITHRIL_API_VERSION_HEADER;
 use mithril_common::entities::{ClientError, ServerError};
 use mithril_common::logging::LoggerExtensions;
-use mithril_common::messages::CardanoDatabaseImmutableFilesRestoredMessage;
+use mithril_common::messages::{
+    CardanoDatabaseImmutableFilesRestoredMessage, CardanoTransactionsProofsRequestMessage,
+};
 
-use crate::common::Epoch;
+use crate::common::{BlockNumber, Epoch};
 use crate::{MithrilError, MithrilResult};
 
 const API_VERSION_MISMATCH_WARNING_MESSAGE: &str = "OpenAPI version may be incompatible, please update Mithril client library to the latest version.";
@@ -112,6 +114,17 @@ pub enum AggregatorRequest {
         transactions_hashes: Vec<String>,
     },
 
+    /// Get proofs that the given set of Cardano transactions is included in the global Cardano
+    /// transactions set, the hashes are sent in the request body
+    PostTransactionsProofs {
+        /// Hashes of the transactions to get proofs for.
+        transactions_hashes: Vec<String>,
+
+        /// Block number of the certified Cardano transactions snapshot to compute the proofs
+        /// against, the latest one if not set.
+        block_number: Option<BlockNumber>,
+    },
+
     /// Get a specific [Cardano transaction snapshot][crate::CardanoTransactionSnapshot]
     GetCardanoTransactionSnapshot {
         /// Hash of the Cardano transaction snapshot to retrieve
@@ -121,6 +134,37 @@ pub enum AggregatorRequest {
     /// Lists the aggregator [Cardano transaction snapshot][crate::CardanoTransactionSnapshot]
     ListCardanoTransactionSnapshots,
 
+    /// Get proofs that the given set of Cardano blocks is included in the global Cardano blocks set
+    GetBlocksProofs {
+        /// Hashes of the blocks to get proofs for.
+        blocks_hashes: Vec<String>,
+    },
+
+    /// Get a specific [Cardano blocks snapshot][crate::CardanoBlocksSnapshot]
+    GetCardanoBlocksSnapshot {
+        /// Hash of the Cardano blocks snapshot to retrieve
+        hash: String,
+    },
+
+    /// Lists the aggregator [Cardano blocks snapshot][crate::CardanoBlocksSnapshot]
+    ListCardanoBlocksSnapshots,
+
+    /// Get proofs that the metadata of the given set of Cardano transactions is included in the
+    /// global Cardano transactions metadata set
+    GetTransactionsMetadataProofs {
+        /// Hashes of the transactions to get metadata proofs for.
+        transactions_hashes: Vec<String>,
+    },
+
+    /// Get a specific [Cardano transactions metadata snapshot][crate::CardanoTransactionsMetadataSnapshot]
+    GetCardanoTransactionsMetadataSnapshot {
+        /// Hash of the Cardano transactions metadata snapshot to retrieve
+        hash: String,
+    },
+
+    /// Lists the aggregator [Cardano transactions metadata snapshot][crate::CardanoTransactionsMetadataSnapshot]
+    ListCardanoTransactionsMetadataSnapshots,
+
     /// Get a specific [Cardano stake distribution][crate::CardanoStakeDistribution] from the aggregator by hash
     GetCardanoStakeDistribution {
         /// Hash of the Cardano stake distribution to retrieve
@@ -185,12 +229,35 @@ impl AggregatorRequest {
                 "proof/cardano-transaction?transaction_hashes={}",
                 transactions_hashes.join(",")
             ),
+            AggregatorRequest::PostTransactionsProofs { .. } => {
+                "proof/cardano-transaction".to_string()
+            }
             AggregatorRequest::GetCardanoTransactionSnapshot { hash } => {
                 format!("artifact/cardano-transaction/{hash}")
             }
             AggregatorRequest::ListCardanoTransactionSnapshots => {
                 "artifact/cardano-transactions".to_string()
             }
+            AggregatorRequest::GetBlocksProofs { blocks_hashes } => format!(
+                "proof/cardano-block?block_hashes={}",
+                blocks_hashes.join(",")
+            ),
+            AggregatorRequest::GetCardanoBlocksSnapshot { hash } => {
+                format!("artifact/cardano-block/{hash}")
+            }
+            AggregatorRequest::ListCardanoBlocksSnapshots => "artifact/cardano-blocks".to_string(),
+            AggregatorRequest::GetTransactionsMetadataProofs {
+                transactions_hashes,
+            } => format!(
+                "proof/cardano-transaction-metadata?transaction_hashes={}",
+                transactions_hashes.join(",")
+            ),
+            AggregatorRequest::GetCardanoTransactionsMetadataSnapshot { hash } => {
+                format!("artifact/cardano-transaction-metadata/{hash}")
+            }
+            AggregatorRequest::ListCardanoTransactionsMetadataSnapshots => {
+                "artifact/cardano-transactions-metadata".to_string()
+            }
             AggregatorRequest::GetCardanoStakeDistribution { hash } => {
                 format!("artifact/cardano-stake-distribution/{hash}")
             }
@@ -216,6 +283,15 @@ impl AggregatorRequest {
                 nb_immutable_files: *number_of_immutables as u32,
             })
             .ok(),
+            AggregatorRequest::PostTransactionsProofs {
+                transactions_hashes,
+                block_number,
+            } => serde_json::to_string(&CardanoTransactionsProofsRequestMessage {
+                transaction_hashes: transactions_hashes.clone(),
+                block_number: *block_number,
+                snapshot_hash: None,
+            })
+            .ok(),
             _ => None,
         }
     }
@@ -642,6 +718,15 @@ mod tests {
             .route()
         );
 
+        assert_eq!(
+            "proof/cardano-transaction".to_string(),
+            AggregatorRequest::PostTransactionsProofs {
+                transactions_hashes: vec!["abc".to_string(), "def".to_string()],
+                block_number: None,
+            }
+            .route()
+        );
+
         assert_eq!(
             "artifact/cardano-transaction/abc".to_string(),
             AggregatorRequest::GetCardanoTransactionSnapshot {
@@ -655,6 +740,48 @@ mod tests {
             AggregatorRequest::ListCardanoTransactionSnapshots.route()
         );
 
+        assert_eq!(
+            "proof/cardano-block?block_hashes=abc,def".to_string(),
+            AggregatorRequest::GetBlocksProofs {
+                blocks_hashes: vec!["abc".to_string(), "def".to_string()]
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/cardano-block/abc".to_string(),
+            AggregatorRequest::GetCardanoBlocksSnapshot {
+                hash: "abc".to_string()
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/cardano-blocks".to_string(),
+            AggregatorRequest::ListCardanoBlocksSnapshots.route()
+        );
+
+        assert_eq!(
+            "proof/cardano-transaction-metadata?transaction_hashes=abc,def".to_string(),
+            AggregatorRequest::GetTransactionsMetadataProofs {
+                transactions_hashes: vec!["abc".to_string(), "def".to_string()]
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/cardano-transaction-metadata/abc".to_string(),
+            AggregatorRequest::GetCardanoTransactionsMetadataSnapshot {
+                hash: "abc".to_string()
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/cardano-transactions-metadata".to_string(),
+            AggregatorRequest::ListCardanoTransactionsMetadataSnapshots.route()
+        );
+
         assert_eq!(
             "artifact/cardano-stake-distribution/abc".to_string(),
             AggregatorRequest::GetCardanoStakeDistribution {
@@ -683,6 +810,7 @@ mod tests {
                 req,
                 AggregatorRequest::IncrementSnapshotStatistic { .. }
                     | AggregatorRequest::IncrementCardanoDatabaseImmutablesRestoredStatistic { .. }
+                    | AggregatorRequest::PostTransactionsProofs { .. }
             )
         }
 
@@ -707,6 +835,22 @@ mod tests {
             .get_body()
         );
 
+        assert_eq!(
+            Some(
+                serde_json::to_string(&CardanoTransactionsProofsRequestMessage {
+                    transaction_hashes: vec!["abc".to_string(), "def".to_string()],
+                    block_number: Some(BlockNumber(123)),
+                    snapshot_hash: None,
+                })
+                .unwrap()
+            ),
+            AggregatorRequest::PostTransactionsProofs {
+                transactions_hashes: vec!["abc".to_string(), "def".to_string()],
+                block_number: Some(BlockNumber(123)),
+            }
+            .get_body()
+        );
+
         for req_that_should_not_have_body in
             AggregatorRequest::iter().filter(that_should_not_have_body)
         {
@@ -0,0 +1,289 @@
+//! A client to retrieve from an aggregator cryptographic proofs of membership for a subset of Cardano blocks.
+//!
+//! In order to do so it defines a [CardanoBlockClient] which exposes the following features:
+//!  - [get_proofs][CardanoBlockClient::get_proofs]: get a [cryptographic proof][CardanoBlocksProofs]
+//!    that the blocks with given hash are included in the global Cardano blocks set.
+//!  - [get][CardanoBlockClient::get_snapshot]: get a [Cardano blocks snapshot][CardanoBlocksSnapshot]
+//!    data from its hash.
+//!  - [list][CardanoBlockClient::list_snapshots]: get the list of the latest available Cardano blocks
+//!    snapshot.
+//!
+//!  **Important:** Verifying a proof **only** means that its cryptography is valid, in order to certify that a Cardano
+//! blocks subset is valid, the associated proof must be tied to a valid Mithril certificate (see the example below).
+//!
+//! # Get and verify Cardano blocks proof
+//!
+//! To get and verify a Cardano blocks proof using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, MessageBuilder};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//!
+//! // 1 - Get a proof from the aggregator and verify it
+//! let cardano_blocks_proof = client.cardano_block().get_proofs(&["block-hash-1", "block-hash-2"]).await?;
+//! println!("Mithril could not certify the following blocks : {:?}", &cardano_blocks_proof.non_certified_blocks);
+//!
+//! let verified_blocks = cardano_blocks_proof.verify()?;
+//!
+//! // 2 - Verify its associated certificate chain
+//! let certificate = client.certificate().verify_chain(&cardano_blocks_proof.certificate_hash).await?;
+//!
+//! // 3 - Ensure that the proof is indeed signed in the associated certificate
+//! let message = MessageBuilder::new().compute_cardano_blocks_proofs_message(&certificate, &verified_blocks);
+//! if certificate.match_message(&message) {
+//!     // All green, Mithril certifies that those blocks are part of the Cardano blocks set.
+//!     println!("Certified blocks : {:?}", verified_blocks.certified_blocks());
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # Get a Cardano blocks snapshot
+//!
+//! To get a Cardano blocks snapshot using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_blocks_snapshot = client.cardano_block().get_snapshot("CARDANO_BLOCKS_SNAPSHOT_HASH").await?.unwrap();
+//!
+//! println!("Cardano blocks snapshot hash={}, epoch={}", cardano_blocks_snapshot.hash, cardano_blocks_snapshot.epoch);
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # List available Cardano blocks snapshots
+//!
+//! To list latest available Cardano blocks snapshots using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_blocks_snapshots = client.cardano_block().list_snapshots().await?;
+//!
+//! for cardano_blocks_snapshot in cardano_blocks_snapshots {
+//!     println!("Cardano blocks snapshot hash={}, epoch={}", cardano_blocks_snapshot.hash, cardano_blocks_snapshot.epoch);
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+
+use anyhow::Context;
+use std::sync::Arc;
+
+use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::{
+    CardanoBlocksProofs, CardanoBlocksSnapshot, CardanoBlocksSnapshotListItem, MithrilResult,
+};
+
+/// HTTP client for CardanoBlocksAPI from the Aggregator
+pub struct CardanoBlockClient {
+    aggregator_client: Arc<dyn AggregatorClient>,
+}
+
+impl CardanoBlockClient {
+    /// Constructs a new `CardanoBlockClient`.
+    pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
+        Self { aggregator_client }
+    }
+
+    /// Get proofs that the given subset of blocks is included in the Cardano blocks set.
+    pub async fn get_proofs<T: ToString>(
+        &self,
+        blocks_hashes: &[T],
+    ) -> MithrilResult<CardanoBlocksProofs> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetBlocksProofs {
+                blocks_hashes: blocks_hashes.iter().map(|h| h.to_string()).collect(),
+            })
+            .await
+            .with_context(|| "CardanoBlockProof Client can not get blocks proofs")?;
+
+        let blocks_proofs: CardanoBlocksProofs = serde_json::from_str(&response)
+            .with_context(|| "CardanoBlockProof Client can not deserialize blocks proofs")?;
+
+        Ok(blocks_proofs)
+    }
+
+    /// Fetch a list of signed Cardano blocks snapshots.
+    pub async fn list_snapshots(&self) -> MithrilResult<Vec<CardanoBlocksSnapshotListItem>> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::ListCardanoBlocksSnapshots)
+            .await
+            .with_context(|| "CardanoBlockClient Client can not get the artifact list")?;
+        let items = serde_json::from_str::<Vec<CardanoBlocksSnapshotListItem>>(&response)
+            .with_context(|| "CardanoBlockClient Client can not deserialize artifact list")?;
+
+        Ok(items)
+    }
+
+    /// Get the given Cardano blocks snapshot data. If it cannot be found, a None is returned.
+    pub async fn get_snapshot(&self, hash: &str) -> MithrilResult<Option<CardanoBlocksSnapshot>> {
+        match self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetCardanoBlocksSnapshot {
+                hash: hash.to_string(),
+            })
+            .await
+        {
+            Ok(content) => {
+                let cardano_blocks_snapshot: CardanoBlocksSnapshot = serde_json::from_str(&content)
+                    .with_context(|| "CardanoBlockClient Client can not deserialize artifact")?;
+
+                Ok(Some(cardano_blocks_snapshot))
+            }
+            Err(AggregatorClientError::RemoteServerLogical(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::{DateTime, Utc};
+    use mockall::predicate::eq;
+
+    use mithril_common::test_utils::double::Dummy;
+
+    use crate::aggregator_client::{AggregatorClientError, MockAggregatorClient};
+    use crate::common::{BlockNumber, Epoch};
+    use crate::{CardanoBlocksProofs, CardanoBlocksSetProof, CardanoBlocksSnapshot};
+
+    use super::*;
+
+    fn fake_messages() -> Vec<CardanoBlocksSnapshotListItem> {
+        vec![
+            CardanoBlocksSnapshotListItem {
+                merkle_root: "mk-123".to_string(),
+                epoch: Epoch(1),
+                block_number: BlockNumber(24),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            },
+            CardanoBlocksSnapshotListItem {
+                merkle_root: "mk-456".to_string(),
+                epoch: Epoch(1),
+                block_number: BlockNumber(24),
+                hash: "hash-456".to_string(),
+                certificate_hash: "cert-hash-456".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn get_cardano_blocks_snapshot_list() {
+        let message = fake_messages();
+        let mut http_client = MockAggregatorClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::ListCardanoBlocksSnapshots))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoBlockClient::new(Arc::new(http_client));
+        let items = client.list_snapshots().await.unwrap();
+
+        assert_eq!(2, items.len());
+        assert_eq!("hash-123".to_string(), items[0].hash);
+        assert_eq!("hash-456".to_string(), items[1].hash);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_blocks_snapshot() {
+        let mut http_client = MockAggregatorClient::new();
+        let message = CardanoBlocksSnapshot {
+            merkle_root: "mk-123".to_string(),
+            epoch: Epoch(1),
+            block_number: BlockNumber(24),
+            hash: "hash-123".to_string(),
+            certificate_hash: "cert-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        };
+        let expected = message.clone();
+        http_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetCardanoBlocksSnapshot {
+                hash: "hash-123".to_string(),
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoBlockClient::new(Arc::new(http_client));
+        let cardano_blocks_snapshot = client
+            .get_snapshot("hash-123")
+            .await
+            .unwrap()
+            .expect("This test returns a cardano blocks snapshot");
+
+        assert_eq!(expected, cardano_blocks_snapshot);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_blocks_snapshot_return_none_when_not_found() {
+        let mut http_client = MockAggregatorClient::new();
+        http_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let client = CardanoBlockClient::new(Arc::new(http_client));
+
+        let cardano_blocks_snapshot = client.get_snapshot("hash-123").await.unwrap();
+
+        assert_eq!(None, cardano_blocks_snapshot);
+    }
+
+    #[tokio::test]
+    async fn test_get_proof_ok() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        let blocks_proofs = CardanoBlocksProofs::new(
+            "cert-hash-123",
+            vec![CardanoBlocksSetProof::dummy()],
+            vec!["block-hash-unknown".to_string()],
+            BlockNumber(99999),
+        );
+        let expected_blocks_proofs = blocks_proofs.clone();
+        aggregator_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetBlocksProofs {
+                blocks_hashes: vec!["block-hash-1".to_string(), "block-hash-2".to_string()],
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&blocks_proofs).unwrap()));
+        let client = CardanoBlockClient::new(Arc::new(aggregator_client));
+
+        let blocks_proofs = client.get_proofs(&["block-hash-1", "block-hash-2"]).await.unwrap();
+
+        assert_eq!(expected_blocks_proofs, blocks_proofs);
+    }
+
+    #[tokio::test]
+    async fn test_get_proof_ko() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+                "an error"
+            )))
+        });
+        let client = CardanoBlockClient::new(Arc::new(aggregator_client));
+
+        client
+            .get_proofs(&["block-hash-1"])
+            .await
+            .expect_err("The certificate client should fail here.");
+    }
+}
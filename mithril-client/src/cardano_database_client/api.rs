@@ -1,4 +1,6 @@
 #[cfg(feature = "fs")]
+use std::collections::BTreeMap;
+#[cfg(feature = "fs")]
 use std::path::Path;
 use std::sync::Arc;
 
@@ -8,6 +10,7 @@ use slog::Logger;
 #[cfg(feature = "fs")]
 use mithril_common::{
     crypto_helper::MKProof,
+    entities::{HexEncodedDigest, ImmutableFileName},
     messages::{CardanoDatabaseSnapshotMessage, CertificateMessage},
 };
 
@@ -20,15 +23,15 @@ use crate::feedback::FeedbackSender;
 #[cfg(feature = "fs")]
 use crate::file_downloader::FileDownloader;
 #[cfg(feature = "fs")]
-use crate::utils::AncillaryVerifier;
+use crate::utils::{AncillaryVerifier, PreferredLocations};
 use crate::{CardanoDatabaseSnapshot, CardanoDatabaseSnapshotListItem, MithrilResult};
 
 use super::fetch::InternalArtifactRetriever;
 use super::statistics::InternalStatisticsSender;
 #[cfg(feature = "fs")]
 use super::{
-    DownloadUnpackOptions, ImmutableFileRange, download_unpack::InternalArtifactDownloader,
-    proving::InternalArtifactProver,
+    CardanoDatabaseAuditReport, DownloadUnpackOptions, ImmutableFileRange,
+    download_unpack::InternalArtifactDownloader, proving::InternalArtifactProver,
 };
 
 /// HTTP client for CardanoDatabase API from the Aggregator
@@ -38,6 +41,8 @@ pub struct CardanoDatabaseClient {
     pub(super) artifact_downloader: InternalArtifactDownloader,
     #[cfg(feature = "fs")]
     pub(super) artifact_prover: InternalArtifactProver,
+    #[cfg(feature = "fs")]
+    pub(super) ancillary_verifier: Option<Arc<AncillaryVerifier>>,
     pub(super) statistics_sender: InternalStatisticsSender,
 }
 
@@ -58,7 +63,7 @@ impl CardanoDatabaseClient {
             #[cfg(feature = "fs")]
             artifact_downloader: InternalArtifactDownloader::new(
                 http_file_downloader.clone(),
-                ancillary_verifier,
+                ancillary_verifier.clone(),
                 feedback_sender.clone(),
                 logger.clone(),
             ),
@@ -67,10 +72,25 @@ impl CardanoDatabaseClient {
                 http_file_downloader.clone(),
                 logger.clone(),
             ),
+            #[cfg(feature = "fs")]
+            ancillary_verifier,
             statistics_sender: InternalStatisticsSender::new(aggregator_client.clone()),
         }
     }
 
+    /// Set the preferred locations used to select the locations to try first when downloading.
+    #[cfg(feature = "fs")]
+    pub(crate) fn with_preferred_locations(
+        mut self,
+        preferred_locations: PreferredLocations,
+    ) -> Self {
+        self.artifact_downloader = self
+            .artifact_downloader
+            .with_preferred_locations(preferred_locations.clone());
+        self.artifact_prover = self.artifact_prover.with_preferred_locations(preferred_locations);
+        self
+    }
+
     /// Fetch a list of signed CardanoDatabase
     pub async fn list(&self) -> MithrilResult<Vec<CardanoDatabaseSnapshotListItem>> {
         self.artifact_retriever.list().await
@@ -119,6 +139,50 @@ impl CardanoDatabaseClient {
             .await
     }
 
+    /// Audit an existing Cardano node database against the given certified Cardano database
+    /// snapshot, without downloading any immutable file.
+    ///
+    /// The ancillary files are audited only if the path of their signed manifest is given.
+    #[cfg(feature = "fs")]
+    pub async fn audit(
+        &self,
+        certificate: &CertificateMessage,
+        cardano_database_snapshot: &CardanoDatabaseSnapshotMessage,
+        database_dir: &Path,
+        ancillary_manifest_path: Option<&Path>,
+    ) -> MithrilResult<CardanoDatabaseAuditReport> {
+        let mut audit_report = self
+            .artifact_prover
+            .audit_immutable_files(certificate, cardano_database_snapshot, database_dir)
+            .await?;
+
+        if let Some(ancillary_manifest_path) = ancillary_manifest_path {
+            let ancillary_verifier = self.ancillary_verifier.as_ref().ok_or(anyhow::anyhow!(
+                "ancillary verifier is not set, please use `set_ancillary_verification_key` when creating the client"
+            ))?;
+            audit_report.ancillary_files = Some(
+                ancillary_verifier
+                    .audit(ancillary_manifest_path, database_dir)
+                    .await?,
+            );
+        }
+
+        Ok(audit_report)
+    }
+
+    /// Download the digests of the immutable files of the given Cardano database snapshot,
+    /// checking that they are the ones certified by the given certificate.
+    #[cfg(feature = "fs")]
+    pub async fn download_certified_digests(
+        &self,
+        certificate: &CertificateMessage,
+        cardano_database_snapshot: &CardanoDatabaseSnapshotMessage,
+    ) -> MithrilResult<BTreeMap<ImmutableFileName, HexEncodedDigest>> {
+        self.artifact_prover
+            .download_certified_digests(certificate, cardano_database_snapshot)
+            .await
+    }
+
     /// Checks if immutable directory exists with at least one immutable in it
     #[cfg(feature = "fs")]
     pub fn check_has_immutables(&self, database_dir: &Path) -> MithrilResult<()> {
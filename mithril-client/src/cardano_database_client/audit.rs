@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileName, ImmutableFileNumber};
+use mithril_common::messages::{CardanoDatabaseSnapshotMessage, CertificateMessage};
+
+/// Report of the audit of a Cardano node database against a certified Cardano database snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoDatabaseAuditReport {
+    /// Hash of the audited Cardano database snapshot
+    pub cardano_database_hash: String,
+
+    /// Hash of the certificate of the audited Cardano database snapshot
+    pub certificate_hash: String,
+
+    /// Last immutable file number certified by the Cardano database snapshot
+    pub last_certified_immutable_file_number: ImmutableFileNumber,
+
+    /// Number of immutable files matching their certified digest
+    pub verified_immutable_files: u64,
+
+    /// Immutable files which digest does not match their certified digest
+    pub mismatching_immutable_files: Vec<ImmutableFileDigestMismatch>,
+
+    /// Certified immutable files that are missing from the database
+    pub missing_immutable_files: Vec<ImmutableFileName>,
+
+    /// Immutable files of the database beyond the certified range
+    pub extra_immutable_files: Vec<ImmutableFileName>,
+
+    /// Audit of the ancillary files, only available if their signed manifest was provided
+    pub ancillary_files: Option<AncillaryFilesAuditReport>,
+}
+
+/// An immutable file which digest does not match its certified digest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImmutableFileDigestMismatch {
+    /// Name of the immutable file
+    pub immutable_file_name: ImmutableFileName,
+
+    /// Digest certified by the Cardano database snapshot
+    pub expected_digest: HexEncodedDigest,
+
+    /// Digest computed from the immutable file of the database
+    pub computed_digest: HexEncodedDigest,
+}
+
+/// Report of the audit of the ancillary files against their signed manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AncillaryFilesAuditReport {
+    /// Whether the manifest is signed with the ancillary verification key
+    pub valid_manifest_signature: bool,
+
+    /// Number of files matching the hash of the manifest
+    pub verified_files: u64,
+
+    /// Files which content does not match the hash of the manifest
+    pub tampered_files: Vec<PathBuf>,
+
+    /// Files of the manifest that are missing from the database or can not be read
+    pub missing_files: Vec<PathBuf>,
+}
+
+impl CardanoDatabaseAuditReport {
+    /// Build the report by comparing the certified digests with the digests computed from the
+    /// immutable files of the database.
+    pub(crate) fn from_digests(
+        certificate: &CertificateMessage,
+        cardano_database_snapshot: &CardanoDatabaseSnapshotMessage,
+        certified_digests: &BTreeMap<ImmutableFileName, HexEncodedDigest>,
+        computed_digests: &BTreeMap<ImmutableFileName, HexEncodedDigest>,
+        extra_immutable_files: Vec<ImmutableFileName>,
+    ) -> Self {
+        let mut verified_immutable_files = 0;
+        let mut mismatching_immutable_files = vec![];
+        let mut missing_immutable_files = vec![];
+
+        for (immutable_file_name, expected_digest) in certified_digests {
+            match computed_digests.get(immutable_file_name) {
+                Some(computed_digest) if computed_digest == expected_digest => {
+                    verified_immutable_files += 1;
+                }
+                Some(computed_digest) => {
+                    mismatching_immutable_files.push(ImmutableFileDigestMismatch {
+                        immutable_file_name: immutable_file_name.clone(),
+                        expected_digest: expected_digest.clone(),
+                        computed_digest: computed_digest.clone(),
+                    });
+                }
+                None => missing_immutable_files.push(immutable_file_name.clone()),
+            }
+        }
+
+        Self {
+            cardano_database_hash: cardano_database_snapshot.hash.clone(),
+            certificate_hash: certificate.hash.clone(),
+            last_certified_immutable_file_number: cardano_database_snapshot
+                .beacon
+                .immutable_file_number,
+            verified_immutable_files,
+            mismatching_immutable_files,
+            missing_immutable_files,
+            extra_immutable_files,
+            ancillary_files: None,
+        }
+    }
+
+    /// Check if the audited database matches the certified Cardano database snapshot.
+    ///
+    /// Extra immutable files do not invalidate the audit since a running Cardano node keeps
+    /// producing immutable files after the certified ones.
+    pub fn is_valid(&self) -> bool {
+        self.mismatching_immutable_files.is_empty()
+            && self.missing_immutable_files.is_empty()
+            && self.ancillary_files.as_ref().is_none_or(|report| report.is_valid())
+    }
+}
+
+impl AncillaryFilesAuditReport {
+    /// Check if the ancillary files match their signed manifest
+    pub fn is_valid(&self) -> bool {
+        self.valid_manifest_signature
+            && self.tampered_files.is_empty()
+            && self.missing_files.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::double::Dummy;
+
+    use super::*;
+
+    fn digests(entries: &[(&str, &str)]) -> BTreeMap<ImmutableFileName, HexEncodedDigest> {
+        entries
+            .iter()
+            .map(|(name, digest)| (name.to_string(), digest.to_string()))
+            .collect()
+    }
+
+    fn valid_ancillary_report() -> AncillaryFilesAuditReport {
+        AncillaryFilesAuditReport {
+            valid_manifest_signature: true,
+            verified_files: 2,
+            tampered_files: vec![],
+            missing_files: vec![],
+        }
+    }
+
+    #[test]
+    fn report_mismatching_and_missing_immutable_files() {
+        let report = CardanoDatabaseAuditReport::from_digests(
+            &CertificateMessage {
+                hash: "certificate-hash".to_string(),
+                ..CertificateMessage::dummy()
+            },
+            &CardanoDatabaseSnapshotMessage {
+                hash: "cardano-database-hash".to_string(),
+                ..CardanoDatabaseSnapshotMessage::dummy()
+            },
+            &digests(&[
+                ("00001.chunk", "digest-1"),
+                ("00001.primary", "digest-2"),
+                ("00001.secondary", "digest-3"),
+            ]),
+            &digests(&[("00001.chunk", "digest-1"), ("00001.primary", "tampered")]),
+            vec!["00002.chunk".to_string()],
+        );
+
+        assert_eq!(
+            CardanoDatabaseAuditReport {
+                cardano_database_hash: "cardano-database-hash".to_string(),
+                certificate_hash: "certificate-hash".to_string(),
+                last_certified_immutable_file_number: CardanoDatabaseSnapshotMessage::dummy()
+                    .beacon
+                    .immutable_file_number,
+                verified_immutable_files: 1,
+                mismatching_immutable_files: vec![ImmutableFileDigestMismatch {
+                    immutable_file_name: "00001.primary".to_string(),
+                    expected_digest: "digest-2".to_string(),
+                    computed_digest: "tampered".to_string(),
+                }],
+                missing_immutable_files: vec!["00001.secondary".to_string()],
+                extra_immutable_files: vec!["00002.chunk".to_string()],
+                ancillary_files: None,
+            },
+            report
+        );
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn report_with_only_extra_immutable_files_is_valid() {
+        let report = CardanoDatabaseAuditReport::from_digests(
+            &CertificateMessage::dummy(),
+            &CardanoDatabaseSnapshotMessage::dummy(),
+            &digests(&[("00001.chunk", "digest-1")]),
+            &digests(&[("00001.chunk", "digest-1")]),
+            vec!["00002.chunk".to_string()],
+        );
+
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn report_with_invalid_ancillary_files_is_invalid() {
+        let report = CardanoDatabaseAuditReport {
+            ancillary_files: Some(valid_ancillary_report()),
+            ..CardanoDatabaseAuditReport::from_digests(
+                &CertificateMessage::dummy(),
+                &CardanoDatabaseSnapshotMessage::dummy(),
+                &digests(&[("00001.chunk", "digest-1")]),
+                &digests(&[("00001.chunk", "digest-1")]),
+                vec![],
+            )
+        };
+        assert!(report.is_valid());
+
+        for invalid_ancillary_report in [
+            AncillaryFilesAuditReport {
+                valid_manifest_signature: false,
+                ..valid_ancillary_report()
+            },
+            AncillaryFilesAuditReport {
+                tampered_files: vec![PathBuf::from("ledger/123")],
+                ..valid_ancillary_report()
+            },
+            AncillaryFilesAuditReport {
+                missing_files: vec![PathBuf::from("ledger/123")],
+                ..valid_ancillary_report()
+            },
+        ] {
+            let report = CardanoDatabaseAuditReport {
+                ancillary_files: Some(invalid_ancillary_report),
+                ..report.clone()
+            };
+            assert!(!report.is_valid());
+        }
+    }
+}
@@ -10,6 +10,7 @@ use mithril_common::entities::{CompressionAlgorithm, ImmutableFileNumber};
 
 use crate::{
     MithrilResult,
+    feedback::{FeedbackSender, MithrilEvent, MithrilEventCardanoDatabase},
     file_downloader::{DownloadEvent, FileDownloader, FileDownloaderUri},
     utils::AncillaryVerifier,
 };
@@ -24,6 +25,7 @@ pub struct DownloadTask {
     pub size_uncompressed: u64,
     pub target_dir: PathBuf,
     pub download_event: DownloadEvent,
+    pub feedback_sender: FeedbackSender,
 }
 
 impl DownloadTask {
@@ -67,6 +69,15 @@ impl DownloadTask {
                     "error" => ?e
                 );
             } else {
+                self.feedback_sender
+                    .send_event(MithrilEvent::CardanoDatabase(
+                        MithrilEventCardanoDatabase::FileDownloadedFromLocation {
+                            download_id: self.download_event.download_id().to_string(),
+                            file_name: self.name(),
+                            location: location_to_try.file_downloader_uri.as_str().to_string(),
+                        },
+                    ))
+                    .await;
                 download_succeeded = true;
                 break;
             }
@@ -164,6 +175,7 @@ mod tests {
     use mithril_common::entities::FileUri;
     use mithril_common::test_utils::{assert_dir_eq, fake_keys, temp_dir_create};
 
+    use crate::feedback::StackFeedbackReceiver;
     use crate::file_downloader::MockFileDownloaderBuilder;
     use crate::test_utils::TestLogger;
 
@@ -212,6 +224,7 @@ mod tests {
                     download_id: "download_id".to_string(),
                     immutable_file_number: 1,
                 },
+                feedback_sender: FeedbackSender::new(&[]),
             };
 
             download_task
@@ -235,6 +248,7 @@ mod tests {
                     .with_success()
                     .build(),
             );
+            let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
 
             let download_task = DownloadTask {
                 kind: DownloadKind::Immutable(1),
@@ -248,9 +262,21 @@ mod tests {
                     download_id: "download_id".to_string(),
                     immutable_file_number: 1,
                 },
+                feedback_sender: FeedbackSender::new(&[feedback_receiver.clone()]),
             };
 
             download_task.build_download_future(logger).await.unwrap();
+
+            assert_eq!(
+                vec![MithrilEvent::CardanoDatabase(
+                    MithrilEventCardanoDatabase::FileDownloadedFromLocation {
+                        download_id: "download_id".to_string(),
+                        file_name: "immutable_file_00001".to_string(),
+                        location: "http://whatever-2/00001.tar.gz".to_string(),
+                    }
+                )],
+                feedback_receiver.stacked_events()
+            );
         }
     }
 
@@ -284,6 +310,7 @@ mod tests {
                 download_event: DownloadEvent::Ancillary {
                     download_id: "download_id".to_string(),
                 },
+                feedback_sender: FeedbackSender::new(&[]),
             };
 
             download_task
@@ -331,6 +358,7 @@ mod tests {
                 download_event: DownloadEvent::Ancillary {
                     download_id: "download_id".to_string(),
                 },
+                feedback_sender: FeedbackSender::new(&[]),
             };
 
             download_task
@@ -372,6 +400,7 @@ mod tests {
                 download_event: DownloadEvent::Ancillary {
                     download_id: "download_id".to_string(),
                 },
+                feedback_sender: FeedbackSender::new(&[]),
             };
 
             download_task
@@ -408,6 +437,7 @@ mod tests {
                 download_event: DownloadEvent::Ancillary {
                     download_id: "download_id".to_string(),
                 },
+                feedback_sender: FeedbackSender::new(&[]),
             };
 
             download_task
@@ -449,6 +479,7 @@ mod tests {
                 download_event: DownloadEvent::Ancillary {
                     download_id: "download_id".to_string(),
                 },
+                feedback_sender: FeedbackSender::new(&[]),
             };
 
             download_task
@@ -16,8 +16,8 @@ use crate::cardano_database_client::ImmutableFileRange;
 use crate::feedback::{FeedbackSender, MithrilEvent, MithrilEventCardanoDatabase};
 use crate::file_downloader::{DownloadEvent, FileDownloader, FileDownloaderUri};
 use crate::utils::{
-    ANCILLARIES_NOT_SIGNED_BY_MITHRIL, AncillaryVerifier, UnexpectedDownloadedFileVerifier,
-    VecDequeExtensions, create_bootstrap_node_files,
+    ANCILLARIES_NOT_SIGNED_BY_MITHRIL, AncillaryVerifier, PreferredLocations,
+    UnexpectedDownloadedFileVerifier, VecDequeExtensions, create_bootstrap_node_files,
 };
 
 use super::DownloadUnpackOptions;
@@ -26,6 +26,7 @@ use super::download_task::{DownloadKind, DownloadTask, LocationToDownload};
 pub struct InternalArtifactDownloader {
     http_file_downloader: Arc<dyn FileDownloader>,
     ancillary_verifier: Option<Arc<AncillaryVerifier>>,
+    preferred_locations: PreferredLocations,
     feedback_sender: FeedbackSender,
     logger: slog::Logger,
 }
@@ -41,11 +42,18 @@ impl InternalArtifactDownloader {
         Self {
             http_file_downloader,
             ancillary_verifier,
+            preferred_locations: PreferredLocations::default(),
             feedback_sender,
             logger,
         }
     }
 
+    /// Set the preferred locations used to select the locations to try first.
+    pub fn with_preferred_locations(mut self, preferred_locations: PreferredLocations) -> Self {
+        self.preferred_locations = preferred_locations;
+        self
+    }
+
     /// Download and unpack the given Cardano database parts data by hash.
     pub async fn download_unpack(
         &self,
@@ -178,6 +186,8 @@ impl InternalArtifactDownloader {
 
             locations_to_try.push(location_to_try);
         }
+        self.preferred_locations
+            .sort(&mut locations_to_try, |l| l.file_downloader_uri.as_str());
 
         Ok(DownloadTask {
             kind: DownloadKind::Immutable(immutable_file_number),
@@ -188,6 +198,7 @@ impl InternalArtifactDownloader {
                 download_id: download_id.to_string(),
                 immutable_file_number,
             },
+            feedback_sender: self.feedback_sender.clone(),
         })
     }
 
@@ -225,6 +236,8 @@ impl InternalArtifactDownloader {
 
             locations_to_try.push(location_to_try);
         }
+        self.preferred_locations
+            .sort(&mut locations_to_try, |l| l.file_downloader_uri.as_str());
 
         Ok(DownloadTask {
             kind: DownloadKind::Ancillary {
@@ -236,6 +249,7 @@ impl InternalArtifactDownloader {
             download_event: DownloadEvent::Ancillary {
                 download_id: download_id.to_string(),
             },
+            feedback_sender: self.feedback_sender.clone(),
         })
     }
 
@@ -681,6 +695,99 @@ mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn building_immutables_download_tasks_try_preferred_locations_first() {
+            let target_dir = temp_dir_create!();
+            let artifact_downloader = InternalArtifactDownloader::new(
+                Arc::new(MockFileDownloader::new()),
+                None,
+                FeedbackSender::new(&[]),
+                TestLogger::stdout(),
+            )
+            .with_preferred_locations(PreferredLocations::new(["mirror.internal"]));
+
+            let tasks = artifact_downloader
+                .build_download_tasks_for_immutables(
+                    &ImmutablesMessagePart {
+                        locations: vec![
+                            ImmutablesLocation::CloudStorage {
+                                uri: MultiFilesUri::Template(TemplateUri(
+                                    "http://cdn/{immutable_file_number}.tar.gz".to_string(),
+                                )),
+                                compression_algorithm: Some(CompressionAlgorithm::Gzip),
+                            },
+                            ImmutablesLocation::CloudStorage {
+                                uri: MultiFilesUri::Template(TemplateUri(
+                                    "http://mirror.internal/{immutable_file_number}.tar.gz"
+                                        .to_string(),
+                                )),
+                                compression_algorithm: Some(CompressionAlgorithm::Gzip),
+                            },
+                        ],
+                        average_size_uncompressed: 0,
+                    },
+                    1..=1,
+                    &target_dir,
+                    "download_id",
+                )
+                .unwrap();
+
+            let tried_uris: Vec<&str> = tasks[0]
+                .locations_to_try
+                .iter()
+                .map(|l| l.file_downloader_uri.as_str())
+                .collect();
+            assert_eq!(
+                vec!["http://mirror.internal/00001.tar.gz", "http://cdn/00001.tar.gz"],
+                tried_uris
+            );
+        }
+
+        #[tokio::test]
+        async fn building_ancillary_download_tasks_try_preferred_locations_first() {
+            let target_dir = temp_dir_create!();
+            let artifact_downloader = InternalArtifactDownloader::new(
+                Arc::new(MockFileDownloader::new()),
+                Some(Arc::new(fake_ancillary_verifier())),
+                FeedbackSender::new(&[]),
+                TestLogger::stdout(),
+            )
+            .with_preferred_locations(PreferredLocations::new(["mirror.internal"]));
+
+            let task = artifact_downloader
+                .new_ancillary_download_task(
+                    &AncillaryMessagePart {
+                        locations: vec![
+                            AncillaryLocation::CloudStorage {
+                                uri: "http://cdn/ancillary.tar.gz".to_string(),
+                                compression_algorithm: Some(CompressionAlgorithm::Gzip),
+                            },
+                            AncillaryLocation::CloudStorage {
+                                uri: "http://mirror.internal/ancillary.tar.gz".to_string(),
+                                compression_algorithm: Some(CompressionAlgorithm::Gzip),
+                            },
+                        ],
+                        size_uncompressed: 0,
+                    },
+                    &target_dir,
+                    "download_id",
+                )
+                .unwrap();
+
+            let tried_uris: Vec<&str> = task
+                .locations_to_try
+                .iter()
+                .map(|l| l.file_downloader_uri.as_str())
+                .collect();
+            assert_eq!(
+                vec![
+                    "http://mirror.internal/ancillary.tar.gz",
+                    "http://cdn/ancillary.tar.gz"
+                ],
+                tried_uris
+            );
+        }
+
         #[tokio::test]
         async fn building_ancillary_download_tasks_fails_if_all_locations_are_unknown() {
             let target_dir = temp_dir_create!();
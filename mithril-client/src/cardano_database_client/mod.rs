@@ -121,6 +121,37 @@
 //! #    Ok(())
 //! # }
 //! ```
+//!
+//! # Audit an existing Cardano node database
+//! **Note:** _Available on crate feature_ **fs** _only._
+//!
+//! To audit the immutable files of an existing Cardano node database against a certified Cardano
+//! database snapshot, without downloading them, the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # #[cfg(feature = "fs")]
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//! use std::path::Path;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY")
+//!     .set_ancillary_verification_key("YOUR_ANCILLARY_VERIFICATION_KEY".to_string())
+//!     .build()?;
+//! let cardano_database_snapshot = client.cardano_database_v2().get("CARDANO_DATABASE_HASH").await?.unwrap();
+//! let certificate = client.certificate().verify_chain(&cardano_database_snapshot.certificate_hash).await?;
+//!
+//! let database_directory = Path::new("/home/user/cardano-node/db/");
+//! let ancillary_manifest = Path::new("/home/user/ancillary_manifest.json");
+//! let audit_report = client
+//!     .cardano_database_v2()
+//!     .audit(&certificate, &cardano_database_snapshot, &database_directory, Some(ancillary_manifest))
+//!     .await?;
+//!
+//! println!("Database matches the certified snapshot: {}", audit_report.is_valid());
+//! #
+//! #    Ok(())
+//! # }
+//! ```
 mod api;
 mod fetch;
 mod statistics;
@@ -130,10 +161,12 @@ pub use api::CardanoDatabaseClient;
 pub(crate) use api::test_dependency_injector::CardanoDatabaseClientDependencyInjector;
 
 cfg_fs! {
+    mod audit;
     mod immutable_file_range;
     mod download_unpack;
     mod proving;
 
+    pub use audit::{AncillaryFilesAuditReport, CardanoDatabaseAuditReport, ImmutableFileDigestMismatch};
     pub use download_unpack::DownloadUnpackOptions;
     pub use immutable_file_range::ImmutableFileRange;
 }
@@ -13,7 +13,7 @@ use mithril_cardano_node_internal_database::{
 };
 use mithril_common::{
     crypto_helper::{MKProof, MKTree, MKTreeNode, MKTreeStoreInMemory},
-    entities::{DigestLocation, HexEncodedDigest, ImmutableFileName},
+    entities::{DigestLocation, HexEncodedDigest, ImmutableFileName, ProtocolMessagePartKey},
     messages::{
         CardanoDatabaseDigestListItemMessage, CardanoDatabaseSnapshotMessage, CertificateMessage,
         DigestsMessagePart,
@@ -24,13 +24,18 @@ use crate::{
     MithrilResult,
     feedback::MithrilEvent,
     file_downloader::{DownloadEvent, FileDownloader, FileDownloaderUri},
-    utils::{create_directory_if_not_exists, delete_directory, read_files_in_directory},
+    utils::{
+        PreferredLocations, create_directory_if_not_exists, delete_directory,
+        read_files_in_directory,
+    },
 };
 
+use super::audit::CardanoDatabaseAuditReport;
 use super::immutable_file_range::ImmutableFileRange;
 
 pub struct InternalArtifactProver {
     http_file_downloader: Arc<dyn FileDownloader>,
+    preferred_locations: PreferredLocations,
     logger: slog::Logger,
 }
 
@@ -39,10 +44,17 @@ impl InternalArtifactProver {
     pub fn new(http_file_downloader: Arc<dyn FileDownloader>, logger: slog::Logger) -> Self {
         Self {
             http_file_downloader,
+            preferred_locations: PreferredLocations::default(),
             logger,
         }
     }
 
+    /// Set the preferred locations used to select the locations to try first.
+    pub fn with_preferred_locations(mut self, preferred_locations: PreferredLocations) -> Self {
+        self.preferred_locations = preferred_locations;
+        self
+    }
+
     /// Compute the Merkle proof of membership for the given immutable file range.
     pub async fn compute_merkle_proof(
         &self,
@@ -84,6 +96,98 @@ impl InternalArtifactProver {
         merkle_tree.compute_proof(&computed_digests)
     }
 
+    /// Audit the immutable files of the given database directory against the digests certified
+    /// for the Cardano database snapshot, without downloading any immutable file.
+    pub async fn audit_immutable_files(
+        &self,
+        certificate: &CertificateMessage,
+        cardano_database_snapshot: &CardanoDatabaseSnapshotMessage,
+        database_dir: &Path,
+    ) -> MithrilResult<CardanoDatabaseAuditReport> {
+        let certified_digests = self
+            .download_certified_digests(certificate, cardano_database_snapshot)
+            .await?;
+
+        let last_immutable_file_number = cardano_database_snapshot.beacon.immutable_file_number;
+        let network = certificate.metadata.network.clone();
+        let immutable_digester = CardanoImmutableDigester::new(network, None, self.logger.clone());
+        let computed_digests = immutable_digester
+            .compute_digests_for_range(database_dir, &(0..=last_immutable_file_number))
+            .await?
+            .entries
+            .into_iter()
+            .map(|(immutable_file, digest)| (immutable_file.filename, digest))
+            .collect::<BTreeMap<_, _>>();
+        let extra_immutable_files = ImmutableFile::list_all_in_dir(database_dir)?
+            .into_iter()
+            .filter(|immutable_file| immutable_file.number > last_immutable_file_number)
+            .map(|immutable_file| immutable_file.filename)
+            .collect();
+
+        Ok(CardanoDatabaseAuditReport::from_digests(
+            certificate,
+            cardano_database_snapshot,
+            &certified_digests,
+            &computed_digests,
+            extra_immutable_files,
+        ))
+    }
+
+    /// Download the digests of the immutable files of the Cardano database snapshot and check
+    /// that they are the ones certified by the certificate.
+    pub async fn download_certified_digests(
+        &self,
+        certificate: &CertificateMessage,
+        cardano_database_snapshot: &CardanoDatabaseSnapshotMessage,
+    ) -> MithrilResult<BTreeMap<ImmutableFileName, HexEncodedDigest>> {
+        let digest_target_dir = Self::digest_target_dir();
+        delete_directory(&digest_target_dir)?;
+        self.download_unpack_digest_file(&cardano_database_snapshot.digests, &digest_target_dir)
+            .await?;
+        let downloaded_digests = self.read_digest_file(&digest_target_dir)?;
+        delete_directory(&digest_target_dir)?;
+
+        let last_immutable_file_number = cardano_database_snapshot.beacon.immutable_file_number;
+        let certified_digests = downloaded_digests
+            .into_iter()
+            .filter(|(immutable_file_name, _)| {
+                match ImmutableFile::new(Path::new(immutable_file_name).to_path_buf()) {
+                    Ok(immutable_file) => immutable_file.number <= last_immutable_file_number,
+                    Err(_) => false,
+                }
+            })
+            .collect::<BTreeMap<_, _>>();
+        Self::verify_certified_digests(certificate, &certified_digests)?;
+
+        Ok(certified_digests)
+    }
+
+    /// Check that the Merkle root computed from the downloaded digests is the one certified by
+    /// the certificate, so they can be trusted.
+    fn verify_certified_digests(
+        certificate: &CertificateMessage,
+        certified_digests: &BTreeMap<ImmutableFileName, HexEncodedDigest>,
+    ) -> MithrilResult<()> {
+        let certified_merkle_root = certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::CardanoDatabaseMerkleRoot)
+            .ok_or(anyhow!(
+                "Certificate '{}' does not certify a Cardano database Merkle root",
+                certificate.hash
+            ))?;
+        let merkle_tree: MKTree<MKTreeStoreInMemory> =
+            MKTree::new(&certified_digests.values().cloned().collect::<Vec<_>>())?;
+
+        if merkle_tree.compute_root()?.to_hex() != *certified_merkle_root {
+            return Err(anyhow!(
+                "Downloaded digests do not match the Merkle root certified by certificate '{}'",
+                certificate.hash
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn download_unpack_digest_file(
         &self,
         digests_locations: &DigestsMessagePart,
@@ -92,6 +196,13 @@ impl InternalArtifactProver {
         create_directory_if_not_exists(digests_file_target_dir)?;
         let mut locations_sorted = digests_locations.sanitized_locations()?;
         locations_sorted.sort();
+        self.preferred_locations
+            .sort(&mut locations_sorted, |location| match location {
+                DigestLocation::CloudStorage { uri, .. } | DigestLocation::Aggregator { uri } => {
+                    uri.as_str()
+                }
+                DigestLocation::Unknown => "",
+            });
         for location in locations_sorted {
             let download_id = MithrilEvent::new_cardano_database_download_id();
             let (file_downloader, compression_algorithm) = match &location {
@@ -351,6 +462,57 @@ mod tests {
         }
     }
 
+    mod verify_certified_digests {
+        use super::*;
+
+        fn certified_digests() -> BTreeMap<ImmutableFileName, HexEncodedDigest> {
+            BTreeMap::from([
+                ("00001.chunk".to_string(), "digest-1".to_string()),
+                ("00001.primary".to_string(), "digest-2".to_string()),
+            ])
+        }
+
+        fn certificate_with_merkle_root(merkle_root: Option<String>) -> CertificateMessage {
+            let mut certificate = CertificateMessage::dummy();
+            certificate.protocol_message = Default::default();
+            if let Some(merkle_root) = merkle_root {
+                certificate.protocol_message.set_message_part(
+                    ProtocolMessagePartKey::CardanoDatabaseMerkleRoot,
+                    merkle_root,
+                );
+            }
+
+            certificate
+        }
+
+        #[test]
+        fn succeeds_if_digests_match_the_certified_merkle_root() {
+            let merkle_tree: MKTree<MKTreeStoreInMemory> =
+                MKTree::new(&certified_digests().into_values().collect::<Vec<_>>()).unwrap();
+            let certificate =
+                certificate_with_merkle_root(Some(merkle_tree.compute_root().unwrap().to_hex()));
+
+            InternalArtifactProver::verify_certified_digests(&certificate, &certified_digests())
+                .unwrap();
+        }
+
+        #[test]
+        fn fails_if_digests_do_not_match_the_certified_merkle_root() {
+            let certificate = certificate_with_merkle_root(Some("another-root".to_string()));
+
+            InternalArtifactProver::verify_certified_digests(&certificate, &certified_digests())
+                .expect_err("Should fail when the digests do not match the certified root");
+        }
+
+        #[test]
+        fn fails_if_certificate_does_not_certify_a_merkle_root() {
+            let certificate = certificate_with_merkle_root(None);
+
+            InternalArtifactProver::verify_certified_digests(&certificate, &certified_digests())
+                .expect_err("Should fail when no Merkle root is certified");
+        }
+    }
+
     mod download_unpack_digest_file {
 
         use mithril_common::entities::CompressionAlgorithm;
@@ -483,6 +645,42 @@ mod tests {
                 .unwrap();
         }
 
+        #[tokio::test]
+        async fn try_preferred_location_first() {
+            let target_dir = Path::new(".");
+            let artifact_prover = InternalArtifactProver::new(
+                Arc::new(
+                    MockFileDownloaderBuilder::default()
+                        .with_file_uri("http://mirror.internal/digest")
+                        .with_compression(None)
+                        .with_times(1)
+                        .with_success()
+                        .build(),
+                ),
+                TestLogger::stdout(),
+            )
+            .with_preferred_locations(PreferredLocations::new(["mirror.internal"]));
+
+            artifact_prover
+                .download_unpack_digest_file(
+                    &DigestsMessagePart {
+                        locations: vec![
+                            DigestLocation::CloudStorage {
+                                uri: "http://whatever-1/digests.json".to_string(),
+                                compression_algorithm: None,
+                            },
+                            DigestLocation::Aggregator {
+                                uri: "http://mirror.internal/digest".to_string(),
+                            },
+                        ],
+                        size_uncompressed: 0,
+                    },
+                    target_dir,
+                )
+                .await
+                .unwrap();
+        }
+
         #[tokio::test]
         async fn should_call_download_with_compression_algorithm() {
             let target_dir = Path::new(".");
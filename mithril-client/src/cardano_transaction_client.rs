@@ -75,13 +75,24 @@
 //! ```
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::BlockNumber;
 use crate::{
     CardanoTransactionSnapshot, CardanoTransactionSnapshotListItem, CardanoTransactionsProofs,
     MithrilResult,
 };
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use std::sync::Arc;
 
+/// Maximum number of transactions hashes sent to the aggregator in a single proofs request,
+/// larger requests are split.
+///
+/// It matches the default maximum number of hashes allowed by request by an aggregator.
+const MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST: usize = 100;
+
+/// Maximum number of attempts to get the proofs of a chunk of a split request, a new attempt is
+/// made when the aggregator fails with a technical error.
+const MAX_SPLIT_PROOFS_REQUEST_ATTEMPTS: usize = 3;
+
 /// HTTP client for CardanoTransactionsAPI from the Aggregator
 pub struct CardanoTransactionClient {
     aggregator_client: Arc<dyn AggregatorClient>,
@@ -94,29 +105,99 @@ impl CardanoTransactionClient {
     }
 
     /// Get proofs that the given subset of transactions is included in the Cardano transactions set.
+    ///
+    /// Large subsets are split in several requests, all computed against the certified Cardano
+    /// transactions snapshot of the first one, and their proofs are merged.
     pub async fn get_proofs<T: ToString>(
         &self,
         transactions_hashes: &[T],
     ) -> MithrilResult<CardanoTransactionsProofs> {
-        match self
+        let transactions_hashes: Vec<String> =
+            transactions_hashes.iter().map(|h| h.to_string()).collect();
+        if transactions_hashes.len() <= MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST {
+            let content = self
+                .aggregator_client
+                .get_content(AggregatorRequest::GetTransactionsProofs {
+                    transactions_hashes,
+                })
+                .await?;
+
+            return Self::deserialize_proofs(&content);
+        }
+
+        let mut chunks = transactions_hashes.chunks(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST);
+        let first_chunk = chunks.next().unwrap_or_default();
+        let content = self
             .aggregator_client
-            .get_content(AggregatorRequest::GetTransactionsProofs {
-                transactions_hashes: transactions_hashes.iter().map(|h| h.to_string()).collect(),
+            .post_content(AggregatorRequest::PostTransactionsProofs {
+                transactions_hashes: first_chunk.to_vec(),
+                block_number: None,
             })
-            .await
-        {
-            Ok(content) => {
-                let transactions_proofs: CardanoTransactionsProofs = serde_json::from_str(&content)
-                    .with_context(
-                        || "CardanoTransactionProof Client can not deserialize transactions proofs",
-                    )?;
+            .await?;
+        let mut transactions_proofs = Self::deserialize_proofs(&content)?;
+        for chunk in chunks {
+            let chunk_proofs = self
+                .fetch_pinned_proofs(chunk, transactions_proofs.latest_block_number)
+                .await?;
+            Self::merge_proofs(&mut transactions_proofs, chunk_proofs)?;
+        }
+
+        Ok(transactions_proofs)
+    }
 
-                Ok(transactions_proofs)
+    /// Fetch the proofs of a chunk of a split request against the snapshot certified at the given
+    /// block number, the request is sent again if it fails with a transient error.
+    async fn fetch_pinned_proofs(
+        &self,
+        transactions_hashes: &[String],
+        block_number: BlockNumber,
+    ) -> MithrilResult<CardanoTransactionsProofs> {
+        let request = AggregatorRequest::PostTransactionsProofs {
+            transactions_hashes: transactions_hashes.to_vec(),
+            block_number: Some(block_number),
+        };
+        let mut attempt = 1;
+        loop {
+            match self.aggregator_client.post_content(request.clone()).await {
+                Ok(content) => return Self::deserialize_proofs(&content),
+                Err(AggregatorClientError::RemoteServerTechnical(_))
+                    if attempt < MAX_SPLIT_PROOFS_REQUEST_ATTEMPTS =>
+                {
+                    attempt += 1;
+                }
+                Err(e) => return Err(e.into()),
             }
-            Err(e) => Err(e.into()),
         }
     }
 
+    fn deserialize_proofs(content: &str) -> MithrilResult<CardanoTransactionsProofs> {
+        serde_json::from_str(content).with_context(
+            || "CardanoTransactionProof Client can not deserialize transactions proofs",
+        )
+    }
+
+    fn merge_proofs(
+        transactions_proofs: &mut CardanoTransactionsProofs,
+        other: CardanoTransactionsProofs,
+    ) -> MithrilResult<()> {
+        if transactions_proofs.certificate_hash != other.certificate_hash {
+            return Err(anyhow!(
+                "CardanoTransactionProof Client can not merge transactions proofs certified by different certificates: '{}' and '{}'",
+                transactions_proofs.certificate_hash,
+                other.certificate_hash
+            ));
+        }
+
+        transactions_proofs
+            .certified_transactions
+            .extend(other.certified_transactions);
+        transactions_proofs
+            .non_certified_transactions
+            .extend(other.non_certified_transactions);
+
+        Ok(())
+    }
+
     /// Fetch a list of signed Cardano transaction snapshots.
     pub async fn list_snapshots(&self) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
         let response = self
@@ -259,6 +340,9 @@ mod tests {
         let expected_transactions_proofs = transactions_proofs.clone();
         aggregator_client
             .expect_get_content()
+            .with(eq(AggregatorRequest::GetTransactionsProofs {
+                transactions_hashes: set_proof.transactions_hashes.clone(),
+            }))
             .return_once(move |_| Ok(serde_json::to_string(&transactions_proofs).unwrap()))
             .times(1);
 
@@ -295,4 +379,204 @@ mod tests {
             .await
             .expect_err("The certificate client should fail here.");
     }
+
+    mod split_proofs_request {
+        use super::*;
+
+        fn transactions_hashes(count: usize) -> Vec<String> {
+            (0..count).map(|i| format!("tx-{i}")).collect()
+        }
+
+        fn proofs_for(
+            certificate_hash: &str,
+            transactions_hashes: &[String],
+            block_number: BlockNumber,
+        ) -> CardanoTransactionsProofs {
+            CardanoTransactionsProofs::new(
+                certificate_hash,
+                vec![CardanoTransactionsSetProof {
+                    transactions_hashes: transactions_hashes.to_vec(),
+                    ..CardanoTransactionsSetProof::dummy()
+                }],
+                vec![],
+                block_number,
+            )
+        }
+
+        fn expect_proofs_request(
+            aggregator_client: &mut MockAggregatorClient,
+            transactions_hashes: &[String],
+            block_number: Option<BlockNumber>,
+            response: CardanoTransactionsProofs,
+        ) {
+            aggregator_client
+                .expect_post_content()
+                .with(eq(AggregatorRequest::PostTransactionsProofs {
+                    transactions_hashes: transactions_hashes.to_vec(),
+                    block_number,
+                }))
+                .return_once(move |_| Ok(serde_json::to_string(&response).unwrap()))
+                .times(1);
+        }
+
+        #[tokio::test]
+        async fn large_request_is_split_and_pinned_to_the_first_response_block_number() {
+            let hashes = transactions_hashes(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST * 2 + 1);
+            let (first, rest) = hashes.split_at(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST);
+            let (second, third) = rest.split_at(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST);
+            let mut aggregator_client = MockAggregatorClient::new();
+            for (chunk, block_number) in [
+                (first, None),
+                (second, Some(BlockNumber(99))),
+                (third, Some(BlockNumber(99))),
+            ] {
+                expect_proofs_request(
+                    &mut aggregator_client,
+                    chunk,
+                    block_number,
+                    proofs_for("cert-hash", chunk, BlockNumber(99)),
+                );
+            }
+
+            let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+            let transactions_proofs = cardano_tx_client.get_proofs(&hashes).await.unwrap();
+
+            assert_eq!("cert-hash", transactions_proofs.certificate_hash);
+            assert_eq!(BlockNumber(99), transactions_proofs.latest_block_number);
+            assert_eq!(hashes, transactions_proofs.transactions_hashes());
+        }
+
+        #[tokio::test]
+        async fn split_request_sends_again_a_chunk_failing_with_a_technical_error_without_fetching_the_previous_ones()
+         {
+            let hashes = transactions_hashes(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST + 1);
+            let (first, second) = hashes.split_at(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST);
+            let mut aggregator_client = MockAggregatorClient::new();
+            let mut sequence = mockall::Sequence::new();
+            expect_proofs_request(
+                &mut aggregator_client,
+                first,
+                None,
+                proofs_for("cert-hash", first, BlockNumber(10)),
+            );
+            aggregator_client
+                .expect_post_content()
+                .with(eq(AggregatorRequest::PostTransactionsProofs {
+                    transactions_hashes: second.to_vec(),
+                    block_number: Some(BlockNumber(10)),
+                }))
+                .times(1)
+                .in_sequence(&mut sequence)
+                .return_once(|_| {
+                    Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+                        "an error"
+                    )))
+                });
+            aggregator_client
+                .expect_post_content()
+                .with(eq(AggregatorRequest::PostTransactionsProofs {
+                    transactions_hashes: second.to_vec(),
+                    block_number: Some(BlockNumber(10)),
+                }))
+                .times(1)
+                .in_sequence(&mut sequence)
+                .return_once({
+                    let proofs = proofs_for("cert-hash", second, BlockNumber(10));
+                    move |_| Ok(serde_json::to_string(&proofs).unwrap())
+                });
+
+            let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+            let transactions_proofs = cardano_tx_client.get_proofs(&hashes).await.unwrap();
+
+            assert_eq!("cert-hash", transactions_proofs.certificate_hash);
+            assert_eq!(hashes, transactions_proofs.transactions_hashes());
+        }
+
+        #[tokio::test]
+        async fn split_request_fails_after_too_many_attempts() {
+            let hashes = transactions_hashes(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST + 1);
+            let (first, second) = hashes.split_at(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST);
+            let mut aggregator_client = MockAggregatorClient::new();
+            expect_proofs_request(
+                &mut aggregator_client,
+                first,
+                None,
+                proofs_for("cert-hash", first, BlockNumber(10)),
+            );
+            aggregator_client
+                .expect_post_content()
+                .with(eq(AggregatorRequest::PostTransactionsProofs {
+                    transactions_hashes: second.to_vec(),
+                    block_number: Some(BlockNumber(10)),
+                }))
+                .returning(|_| {
+                    Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+                        "an error"
+                    )))
+                })
+                .times(MAX_SPLIT_PROOFS_REQUEST_ATTEMPTS);
+
+            let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+            cardano_tx_client
+                .get_proofs(&hashes)
+                .await
+                .expect_err("get_proofs should fail after too many attempts");
+        }
+
+        #[tokio::test]
+        async fn split_request_fails_without_sending_again_a_chunk_rejected_by_the_aggregator() {
+            let hashes = transactions_hashes(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST + 1);
+            let (first, second) = hashes.split_at(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST);
+            let mut aggregator_client = MockAggregatorClient::new();
+            expect_proofs_request(
+                &mut aggregator_client,
+                first,
+                None,
+                proofs_for("cert-hash", first, BlockNumber(10)),
+            );
+            aggregator_client
+                .expect_post_content()
+                .with(eq(AggregatorRequest::PostTransactionsProofs {
+                    transactions_hashes: second.to_vec(),
+                    block_number: Some(BlockNumber(10)),
+                }))
+                .returning(|_| {
+                    Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                        "bad request"
+                    )))
+                })
+                .times(1);
+
+            let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+            cardano_tx_client
+                .get_proofs(&hashes)
+                .await
+                .expect_err("get_proofs should fail if a chunk is rejected by the aggregator");
+        }
+
+        #[tokio::test]
+        async fn split_request_fails_if_proofs_are_certified_by_different_certificates() {
+            let hashes = transactions_hashes(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST + 1);
+            let (first, second) = hashes.split_at(MAX_TRANSACTIONS_HASHES_BY_PROOFS_REQUEST);
+            let mut aggregator_client = MockAggregatorClient::new();
+            expect_proofs_request(
+                &mut aggregator_client,
+                first,
+                None,
+                proofs_for("cert-hash-1", first, BlockNumber(10)),
+            );
+            expect_proofs_request(
+                &mut aggregator_client,
+                second,
+                Some(BlockNumber(10)),
+                proofs_for("cert-hash-2", second, BlockNumber(10)),
+            );
+
+            let cardano_tx_client = CardanoTransactionClient::new(Arc::new(aggregator_client));
+            cardano_tx_client
+                .get_proofs(&hashes)
+                .await
+                .expect_err("get_proofs should fail when merging proofs of different certificates");
+        }
+    }
 }
@@ -0,0 +1,316 @@
+//! A client to retrieve from an aggregator cryptographic proofs of the metadata carried by a subset
+//! of Cardano transactions.
+//!
+//! In order to do so it defines a [CardanoTransactionMetadataClient] which exposes the following features:
+//!  - [get_proofs][CardanoTransactionMetadataClient::get_proofs]: get a [cryptographic proof][CardanoTransactionsMetadataProofs]
+//!    that the metadata labels of the transactions with given hash are included in the global
+//!    Cardano transactions metadata set.
+//!  - [get][CardanoTransactionMetadataClient::get_snapshot]: get a [Cardano transactions metadata snapshot][CardanoTransactionsMetadataSnapshot]
+//!    data from its hash.
+//!  - [list][CardanoTransactionMetadataClient::list_snapshots]: get the list of the latest available
+//!    Cardano transactions metadata snapshot.
+//!
+//!  **Important:** Verifying a proof **only** means that its cryptography is valid, in order to certify that a Cardano
+//! transactions metadata subset is valid, the associated proof must be tied to a valid Mithril certificate (see the example below).
+//!
+//! # Get and verify Cardano transactions metadata proof
+//!
+//! To get and verify a Cardano transactions metadata proof using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, MessageBuilder};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//!
+//! // 1 - Get a proof from the aggregator and verify it
+//! let metadata_proof = client.cardano_transaction_metadata().get_proofs(&["tx-1", "tx-2"]).await?;
+//! println!("Mithril could not certify metadata for the following transactions : {:?}", &metadata_proof.non_certified_transactions);
+//!
+//! let verified_metadata = metadata_proof.verify()?;
+//!
+//! // 2 - Verify its associated certificate chain
+//! let certificate = client.certificate().verify_chain(&metadata_proof.certificate_hash).await?;
+//!
+//! // 3 - Ensure that the proof is indeed signed in the associated certificate
+//! let message = MessageBuilder::new().compute_cardano_transactions_metadata_proofs_message(&certificate, &verified_metadata);
+//! if certificate.match_message(&message) {
+//!     // All green, Mithril certifies that those metadata labels are carried by those transactions.
+//!     println!("Certified metadata : {:?}", verified_metadata.certified_metadata());
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # Get a Cardano transactions metadata snapshot
+//!
+//! To get a Cardano transactions metadata snapshot using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let metadata_snapshot = client.cardano_transaction_metadata().get_snapshot("CARDANO_TRANSACTIONS_METADATA_SNAPSHOT_HASH").await?.unwrap();
+//!
+//! println!("Cardano transactions metadata snapshot hash={}, epoch={}", metadata_snapshot.hash, metadata_snapshot.epoch);
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # List available Cardano transactions metadata snapshots
+//!
+//! To list latest available Cardano transactions metadata snapshots using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let metadata_snapshots = client.cardano_transaction_metadata().list_snapshots().await?;
+//!
+//! for metadata_snapshot in metadata_snapshots {
+//!     println!("Cardano transactions metadata snapshot hash={}, epoch={}", metadata_snapshot.hash, metadata_snapshot.epoch);
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+
+use anyhow::Context;
+use std::sync::Arc;
+
+use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::{
+    CardanoTransactionsMetadataProofs, CardanoTransactionsMetadataSnapshot,
+    CardanoTransactionsMetadataSnapshotListItem, MithrilResult,
+};
+
+/// HTTP client for CardanoTransactionsMetadataAPI from the Aggregator
+pub struct CardanoTransactionMetadataClient {
+    aggregator_client: Arc<dyn AggregatorClient>,
+}
+
+impl CardanoTransactionMetadataClient {
+    /// Constructs a new `CardanoTransactionMetadataClient`.
+    pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
+        Self { aggregator_client }
+    }
+
+    /// Get proofs that the metadata of the given subset of transactions is included in the
+    /// Cardano transactions metadata set.
+    pub async fn get_proofs<T: ToString>(
+        &self,
+        transactions_hashes: &[T],
+    ) -> MithrilResult<CardanoTransactionsMetadataProofs> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetTransactionsMetadataProofs {
+                transactions_hashes: transactions_hashes.iter().map(|h| h.to_string()).collect(),
+            })
+            .await
+            .with_context(|| {
+                "CardanoTransactionMetadataProof Client can not get transactions metadata proofs"
+            })?;
+
+        let metadata_proofs: CardanoTransactionsMetadataProofs = serde_json::from_str(&response)
+            .with_context(|| {
+                "CardanoTransactionMetadataProof Client can not deserialize transactions metadata proofs"
+            })?;
+
+        Ok(metadata_proofs)
+    }
+
+    /// Fetch a list of signed Cardano transactions metadata snapshots.
+    pub async fn list_snapshots(
+        &self,
+    ) -> MithrilResult<Vec<CardanoTransactionsMetadataSnapshotListItem>> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::ListCardanoTransactionsMetadataSnapshots)
+            .await
+            .with_context(
+                || "CardanoTransactionMetadataClient Client can not get the artifact list",
+            )?;
+        let items =
+            serde_json::from_str::<Vec<CardanoTransactionsMetadataSnapshotListItem>>(&response)
+                .with_context(
+                    || "CardanoTransactionMetadataClient Client can not deserialize artifact list",
+                )?;
+
+        Ok(items)
+    }
+
+    /// Get the given Cardano transactions metadata snapshot data. If it cannot be found, a None is returned.
+    pub async fn get_snapshot(
+        &self,
+        hash: &str,
+    ) -> MithrilResult<Option<CardanoTransactionsMetadataSnapshot>> {
+        match self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetCardanoTransactionsMetadataSnapshot {
+                hash: hash.to_string(),
+            })
+            .await
+        {
+            Ok(content) => {
+                let metadata_snapshot: CardanoTransactionsMetadataSnapshot =
+                    serde_json::from_str(&content).with_context(
+                        || "CardanoTransactionMetadataClient Client can not deserialize artifact",
+                    )?;
+
+                Ok(Some(metadata_snapshot))
+            }
+            Err(AggregatorClientError::RemoteServerLogical(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use chrono::{DateTime, Utc};
+    use mockall::predicate::eq;
+
+    use mithril_common::test_utils::double::Dummy;
+
+    use crate::aggregator_client::{AggregatorClientError, MockAggregatorClient};
+    use crate::common::{BlockNumber, Epoch};
+    use crate::{
+        CardanoTransactionsMetadataProofs, CardanoTransactionsMetadataSetProof,
+        CardanoTransactionsMetadataSnapshot,
+    };
+
+    use super::*;
+
+    fn fake_messages() -> Vec<CardanoTransactionsMetadataSnapshotListItem> {
+        vec![
+            CardanoTransactionsMetadataSnapshotListItem {
+                merkle_root: "mk-123".to_string(),
+                epoch: Epoch(1),
+                block_number: BlockNumber(24),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            },
+            CardanoTransactionsMetadataSnapshotListItem {
+                merkle_root: "mk-456".to_string(),
+                epoch: Epoch(1),
+                block_number: BlockNumber(24),
+                hash: "hash-456".to_string(),
+                certificate_hash: "cert-hash-456".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn get_cardano_transactions_metadata_snapshot_list() {
+        let message = fake_messages();
+        let mut http_client = MockAggregatorClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(
+                AggregatorRequest::ListCardanoTransactionsMetadataSnapshots,
+            ))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoTransactionMetadataClient::new(Arc::new(http_client));
+        let items = client.list_snapshots().await.unwrap();
+
+        assert_eq!(2, items.len());
+        assert_eq!("hash-123".to_string(), items[0].hash);
+        assert_eq!("hash-456".to_string(), items[1].hash);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_transactions_metadata_snapshot() {
+        let mut http_client = MockAggregatorClient::new();
+        let message = CardanoTransactionsMetadataSnapshot {
+            merkle_root: "mk-123".to_string(),
+            epoch: Epoch(1),
+            block_number: BlockNumber(24),
+            hash: "hash-123".to_string(),
+            certificate_hash: "cert-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        };
+        let expected = message.clone();
+        http_client
+            .expect_get_content()
+            .with(eq(
+                AggregatorRequest::GetCardanoTransactionsMetadataSnapshot {
+                    hash: "hash-123".to_string(),
+                },
+            ))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoTransactionMetadataClient::new(Arc::new(http_client));
+        let metadata_snapshot = client
+            .get_snapshot("hash-123")
+            .await
+            .unwrap()
+            .expect("This test returns a cardano transactions metadata snapshot");
+
+        assert_eq!(expected, metadata_snapshot);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_transactions_metadata_snapshot_return_none_when_not_found() {
+        let mut http_client = MockAggregatorClient::new();
+        http_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let client = CardanoTransactionMetadataClient::new(Arc::new(http_client));
+
+        let metadata_snapshot = client.get_snapshot("hash-123").await.unwrap();
+
+        assert_eq!(None, metadata_snapshot);
+    }
+
+    #[tokio::test]
+    async fn test_get_proof_ok() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        let metadata_proofs = CardanoTransactionsMetadataProofs::new(
+            "cert-hash-123",
+            vec![CardanoTransactionsMetadataSetProof::dummy()],
+            vec!["tx-unknown".to_string()],
+            BlockNumber(99999),
+        );
+        let expected_metadata_proofs = metadata_proofs.clone();
+        aggregator_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetTransactionsMetadataProofs {
+                transactions_hashes: vec!["tx-1".to_string(), "tx-2".to_string()],
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&metadata_proofs).unwrap()));
+        let client = CardanoTransactionMetadataClient::new(Arc::new(aggregator_client));
+
+        let metadata_proofs = client.get_proofs(&["tx-1", "tx-2"]).await.unwrap();
+
+        assert_eq!(expected_metadata_proofs, metadata_proofs);
+    }
+
+    #[tokio::test]
+    async fn test_get_proof_ko() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+                "an error"
+            )))
+        });
+        let client = CardanoTransactionMetadataClient::new(Arc::new(aggregator_client));
+
+        client
+            .get_proofs(&["tx-1"])
+            .await
+            .expect_err("The certificate client should fail here.");
+    }
+}
@@ -10,9 +10,11 @@ use mithril_common::{MITHRIL_CLIENT_TYPE_HEADER, MITHRIL_ORIGIN_TAG_HEADER};
 
 use crate::MithrilResult;
 use crate::aggregator_client::{AggregatorClient, AggregatorHTTPClient};
+use crate::cardano_block_client::CardanoBlockClient;
 use crate::cardano_database_client::CardanoDatabaseClient;
 use crate::cardano_stake_distribution_client::CardanoStakeDistributionClient;
 use crate::cardano_transaction_client::CardanoTransactionClient;
+use crate::cardano_transaction_metadata_client::CardanoTransactionMetadataClient;
 #[cfg(feature = "unstable")]
 use crate::certificate_client::CertificateVerifierCache;
 use crate::certificate_client::{
@@ -27,7 +29,7 @@ use crate::file_downloader::{
 use crate::mithril_stake_distribution_client::MithrilStakeDistributionClient;
 use crate::snapshot_client::SnapshotClient;
 #[cfg(feature = "fs")]
-use crate::utils::AncillaryVerifier;
+use crate::utils::{AncillaryVerifier, PreferredLocations};
 
 const DEFAULT_CLIENT_TYPE: &str = "LIBRARY";
 
@@ -105,6 +107,8 @@ pub struct Client {
     snapshot_client: Arc<SnapshotClient>,
     cardano_database_client: Arc<CardanoDatabaseClient>,
     cardano_transaction_client: Arc<CardanoTransactionClient>,
+    cardano_block_client: Arc<CardanoBlockClient>,
+    cardano_transaction_metadata_client: Arc<CardanoTransactionMetadataClient>,
     cardano_stake_distribution_client: Arc<CardanoStakeDistributionClient>,
     mithril_era_client: Arc<MithrilEraClient>,
 }
@@ -141,6 +145,16 @@ impl Client {
         self.cardano_transaction_client.clone()
     }
 
+    /// Get the client that fetches and verifies Mithril Cardano blocks proof.
+    pub fn cardano_block(&self) -> Arc<CardanoBlockClient> {
+        self.cardano_block_client.clone()
+    }
+
+    /// Get the client that fetches and verifies Mithril Cardano transactions metadata proof.
+    pub fn cardano_transaction_metadata(&self) -> Arc<CardanoTransactionMetadataClient> {
+        self.cardano_transaction_metadata_client.clone()
+    }
+
     /// Get the client that fetches Cardano stake distributions.
     pub fn cardano_stake_distribution(&self) -> Arc<CardanoStakeDistributionClient> {
         self.cardano_stake_distribution_client.clone()
@@ -160,6 +174,8 @@ pub struct ClientBuilder {
     client_type: Option<String>,
     #[cfg(feature = "fs")]
     ancillary_verification_key: Option<String>,
+    #[cfg(feature = "fs")]
+    preferred_locations: Vec<String>,
     aggregator_client: Option<Arc<dyn AggregatorClient>>,
     certificate_verifier: Option<Arc<dyn CertificateVerifier>>,
     #[cfg(feature = "fs")]
@@ -183,6 +199,8 @@ impl ClientBuilder {
             client_type: None,
             #[cfg(feature = "fs")]
             ancillary_verification_key: None,
+            #[cfg(feature = "fs")]
+            preferred_locations: vec![],
             aggregator_client: None,
             certificate_verifier: None,
             #[cfg(feature = "fs")]
@@ -208,6 +226,8 @@ impl ClientBuilder {
             client_type: None,
             #[cfg(feature = "fs")]
             ancillary_verification_key: None,
+            #[cfg(feature = "fs")]
+            preferred_locations: vec![],
             aggregator_client: None,
             certificate_verifier: None,
             #[cfg(feature = "fs")]
@@ -291,7 +311,10 @@ impl ClientBuilder {
             ))),
         };
 
-        let snapshot_client = Arc::new(SnapshotClient::new(
+        #[cfg(feature = "fs")]
+        let preferred_locations = PreferredLocations::new(self.preferred_locations);
+
+        let snapshot_client = SnapshotClient::new(
             aggregator_client.clone(),
             #[cfg(feature = "fs")]
             http_file_downloader.clone(),
@@ -301,9 +324,12 @@ impl ClientBuilder {
             feedback_sender.clone(),
             #[cfg(feature = "fs")]
             logger.clone(),
-        ));
+        );
+        #[cfg(feature = "fs")]
+        let snapshot_client = snapshot_client.with_preferred_locations(preferred_locations.clone());
+        let snapshot_client = Arc::new(snapshot_client);
 
-        let cardano_database_client = Arc::new(CardanoDatabaseClient::new(
+        let cardano_database_client = CardanoDatabaseClient::new(
             aggregator_client.clone(),
             #[cfg(feature = "fs")]
             http_file_downloader,
@@ -313,11 +339,21 @@ impl ClientBuilder {
             feedback_sender,
             #[cfg(feature = "fs")]
             logger,
-        ));
+        );
+        #[cfg(feature = "fs")]
+        let cardano_database_client =
+            cardano_database_client.with_preferred_locations(preferred_locations);
+        let cardano_database_client = Arc::new(cardano_database_client);
 
         let cardano_transaction_client =
             Arc::new(CardanoTransactionClient::new(aggregator_client.clone()));
 
+        let cardano_block_client = Arc::new(CardanoBlockClient::new(aggregator_client.clone()));
+
+        let cardano_transaction_metadata_client = Arc::new(CardanoTransactionMetadataClient::new(
+            aggregator_client.clone(),
+        ));
+
         let cardano_stake_distribution_client =
             Arc::new(CardanoStakeDistributionClient::new(aggregator_client));
 
@@ -327,6 +363,8 @@ impl ClientBuilder {
             snapshot_client,
             cardano_database_client,
             cardano_transaction_client,
+            cardano_block_client,
+            cardano_transaction_metadata_client,
             cardano_stake_distribution_client,
             mithril_era_client,
         })
@@ -427,6 +465,20 @@ impl ClientBuilder {
             self.ancillary_verification_key = ancillary_verification_key.into();
             self
         }
+
+        /// Set the patterns of the locations to try first when an artifact file is available at
+        /// several locations (e.g. an internal mirror).
+        ///
+        /// A location is preferred if its URI contains one of the patterns, the locations matching
+        /// the first patterns being tried first. The other locations are still tried if all the
+        /// preferred ones fail.
+        pub fn with_preferred_locations<T: Into<String>, I: IntoIterator<Item = T>>(
+            mut self,
+            preferred_locations: I,
+        ) -> ClientBuilder {
+            self.preferred_locations = preferred_locations.into_iter().map(Into::into).collect();
+            self
+        }
     }
 
     /// Set the [Logger] to use.
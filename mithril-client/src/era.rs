@@ -157,6 +157,7 @@ mod tests {
             total_next_stakes_signers: 987654321,
             total_cardano_spo: 7777,
             total_cardano_stake: 888888888,
+            local_artifacts_disk_usage: None,
         }
     }
 
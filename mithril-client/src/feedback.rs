@@ -147,6 +147,15 @@ pub enum MithrilEventCardanoDatabase {
         /// Unique identifier used to track a cardano database download
         download_id: String,
     },
+    /// A file has been downloaded from one of its locations
+    FileDownloadedFromLocation {
+        /// Unique identifier used to track a cardano database download
+        download_id: String,
+        /// Name of the downloaded file
+        file_name: String,
+        /// Location that served the file
+        location: String,
+    },
 }
 
 /// Event that can be reported by a [FeedbackReceiver].
@@ -198,6 +207,15 @@ pub enum MithrilEvent {
         /// Unique identifier used to track this specific snapshot ancillary download
         download_id: String,
     },
+    /// A snapshot file has been downloaded from one of its locations
+    SnapshotFileDownloadedFromLocation {
+        /// Unique identifier used to track this specific snapshot download
+        download_id: String,
+        /// Name of the downloaded file
+        file_name: String,
+        /// Location that served the file
+        location: String,
+    },
 
     /// Cardano database related events
     CardanoDatabase(MithrilEventCardanoDatabase),
@@ -253,6 +271,7 @@ impl MithrilEvent {
             MithrilEvent::SnapshotAncillaryDownloadStarted { download_id, .. } => download_id,
             MithrilEvent::SnapshotAncillaryDownloadProgress { download_id, .. } => download_id,
             MithrilEvent::SnapshotAncillaryDownloadCompleted { download_id } => download_id,
+            MithrilEvent::SnapshotFileDownloadedFromLocation { download_id, .. } => download_id,
             MithrilEvent::CardanoDatabase(MithrilEventCardanoDatabase::Started {
                 download_id,
                 ..
@@ -289,6 +308,9 @@ impl MithrilEvent {
             MithrilEvent::CardanoDatabase(
                 MithrilEventCardanoDatabase::DigestDownloadCompleted { download_id, .. },
             ) => download_id,
+            MithrilEvent::CardanoDatabase(
+                MithrilEventCardanoDatabase::FileDownloadedFromLocation { download_id, .. },
+            ) => download_id,
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id,
             } => certificate_chain_validation_id,
@@ -398,6 +420,16 @@ impl FeedbackReceiver for SlogFeedbackReceiver {
             MithrilEvent::SnapshotAncillaryDownloadCompleted { download_id } => {
                 info!(self.logger, "Snapshot ancillary download completed"; "download_id" => download_id);
             }
+            MithrilEvent::SnapshotFileDownloadedFromLocation {
+                download_id,
+                file_name,
+                location,
+            } => {
+                info!(
+                    self.logger, "Snapshot file downloaded from location";
+                    "file_name" => file_name, "location" => location, "download_id" => download_id,
+                );
+            }
             MithrilEvent::CardanoDatabase(MithrilEventCardanoDatabase::Started {
                 download_id,
                 total_immutable_files,
@@ -500,6 +532,18 @@ impl FeedbackReceiver for SlogFeedbackReceiver {
             ) => {
                 info!(self.logger, "Digest download completed"; "download_id" => download_id);
             }
+            MithrilEvent::CardanoDatabase(
+                MithrilEventCardanoDatabase::FileDownloadedFromLocation {
+                    download_id,
+                    file_name,
+                    location,
+                },
+            ) => {
+                info!(
+                    self.logger, "File downloaded from location";
+                    "file_name" => file_name, "location" => location, "download_id" => download_id,
+                );
+            }
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id,
             } => {
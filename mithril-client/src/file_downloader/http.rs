@@ -1,6 +1,7 @@
 use std::{
     io::{BufReader, Read, Write},
-    path::Path,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
 };
 
 use anyhow::{Context, anyhow};
@@ -20,7 +21,7 @@ use crate::common::CompressionAlgorithm;
 use crate::feedback::FeedbackSender;
 use crate::utils::StreamReader;
 
-use super::{FileDownloader, FileDownloaderUri, interface::DownloadEvent};
+use super::{FileDownloader, FileDownloaderUri, UnpackedFileVerifier, interface::DownloadEvent};
 
 /// A file downloader that only handles download through HTTP.
 pub struct HttpFileDownloader {
@@ -147,13 +148,15 @@ impl HttpFileDownloader {
         compression_algorithm: Option<CompressionAlgorithm>,
         unpack_dir: &Path,
         download_id: String,
+        verifier: Option<Arc<dyn UnpackedFileVerifier>>,
     ) -> StdResult<()> {
         let input = StreamReader::new(stream);
+        let verifier = verifier.as_deref();
         match compression_algorithm {
             Some(CompressionAlgorithm::Gzip) => {
                 let gzip_decoder = GzDecoder::new(input);
-                let mut file_archive = Archive::new(gzip_decoder);
-                file_archive.unpack(unpack_dir).with_context(|| {
+                let file_archive = Archive::new(gzip_decoder);
+                Self::unpack_archive(file_archive, unpack_dir, verifier).with_context(|| {
                     format!(
                         "Could not unpack with 'Gzip' from streamed data to directory '{}'",
                         unpack_dir.display()
@@ -163,14 +166,34 @@ impl HttpFileDownloader {
             Some(CompressionAlgorithm::Zstandard) => {
                 let zstandard_decoder = zstd::Decoder::new(input)
                     .with_context(|| "Unpack failed: Create Zstandard decoder error")?;
-                let mut file_archive = Archive::new(zstandard_decoder);
-                file_archive.unpack(unpack_dir).with_context(|| {
+                let file_archive = Archive::new(zstandard_decoder);
+                Self::unpack_archive(file_archive, unpack_dir, verifier).with_context(|| {
                     format!(
                         "Could not unpack with 'Zstd' from streamed data to directory '{}'",
                         unpack_dir.display()
                     )
                 })?;
             }
+            Some(CompressionAlgorithm::Lz4) => {
+                let lz4_decoder = lz4_flex::frame::FrameDecoder::new(input);
+                let file_archive = Archive::new(lz4_decoder);
+                Self::unpack_archive(file_archive, unpack_dir, verifier).with_context(|| {
+                    format!(
+                        "Could not unpack with 'Lz4' from streamed data to directory '{}'",
+                        unpack_dir.display()
+                    )
+                })?;
+            }
+            Some(CompressionAlgorithm::Xz) => {
+                let xz_decoder = liblzma::read::XzDecoder::new(input);
+                let file_archive = Archive::new(xz_decoder);
+                Self::unpack_archive(file_archive, unpack_dir, verifier).with_context(|| {
+                    format!(
+                        "Could not unpack with 'Xz' from streamed data to directory '{}'",
+                        unpack_dir.display()
+                    )
+                })?;
+            }
             None => {
                 let file_path = unpack_dir.join(download_id);
                 if file_path.exists() {
@@ -187,17 +210,53 @@ impl HttpFileDownloader {
 
         Ok(())
     }
-}
 
-#[async_trait]
-impl FileDownloader for HttpFileDownloader {
-    async fn download_unpack(
+    /// Unpack the archive entry by entry when a verifier is given, so each entry is verified right
+    /// after being written and the unpacking stops at the first invalid entry.
+    fn unpack_archive<R: Read>(
+        mut archive: Archive<R>,
+        unpack_dir: &Path,
+        verifier: Option<&dyn UnpackedFileVerifier>,
+    ) -> StdResult<()> {
+        match verifier {
+            None => archive.unpack(unpack_dir)?,
+            Some(verifier) => {
+                for entry in archive.entries()? {
+                    let mut entry = entry?;
+                    let is_unpacked = entry.unpack_in(unpack_dir)?;
+                    if is_unpacked {
+                        verifier.verify_unpacked_file(
+                            &Self::unpacked_entry_path(unpack_dir, &entry.path()?),
+                            entry.header().entry_type().is_file(),
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Path where an entry is written by [tar::Entry::unpack_in], which skips the root, prefix
+    /// and current directory components of the entry path.
+    fn unpacked_entry_path(unpack_dir: &Path, entry_path: &Path) -> PathBuf {
+        entry_path.components().fold(
+            unpack_dir.to_path_buf(),
+            |path, component| match component {
+                Component::Normal(part) => path.join(part),
+                _ => path,
+            },
+        )
+    }
+
+    async fn download_unpack_with_verifier(
         &self,
         location: &FileDownloaderUri,
         file_size: u64,
         target_dir: &Path,
         compression_algorithm: Option<CompressionAlgorithm>,
         download_event_type: DownloadEvent,
+        verifier: Option<Arc<dyn UnpackedFileVerifier>>,
     ) -> StdResult<()> {
         if !target_dir.is_dir() {
             Err(
@@ -210,17 +269,28 @@ impl FileDownloader for HttpFileDownloader {
         let dest_dir = target_dir.to_path_buf();
         let download_id = download_event_type.download_id().to_owned();
         let unpack_thread = tokio::task::spawn_blocking(move || -> StdResult<()> {
-            Self::unpack_file(receiver, compression_algorithm, &dest_dir, download_id)
+            Self::unpack_file(
+                receiver,
+                compression_algorithm,
+                &dest_dir,
+                download_id,
+                verifier,
+            )
         });
-        if let Some(local_path) = Self::file_scheme_to_local_path(location.as_str()) {
+        let download_result = if let Some(local_path) =
+            Self::file_scheme_to_local_path(location.as_str())
+        {
             self.download_local_file(&local_path, &sender, download_event_type, file_size)
-                .await?;
+                .await
         } else {
             self.download_remote_file(location.as_str(), &sender, download_event_type, file_size)
-                .await?;
-        }
+                .await
+        };
+        // The receiver is only dropped if the unpacking stopped before the end of the stream,
+        // in that case the unpacking error is the root cause of the download failure.
+        let is_unpack_stopped_early = sender.is_disconnected();
         drop(sender);
-        unpack_thread
+        let unpack_result = unpack_thread
             .await
             .with_context(|| {
                 format!(
@@ -228,18 +298,60 @@ impl FileDownloader for HttpFileDownloader {
                     target_dir.display()
                 )
             })?
-            .with_context(|| {
-                format!("Unpack: could not unpack to dir '{}'", target_dir.display())
-            })?;
+            .with_context(|| format!("Unpack: could not unpack to dir '{}'", target_dir.display()));
+        if is_unpack_stopped_early {
+            return unpack_result.and(download_result);
+        }
+        download_result?;
 
-        Ok(())
+        unpack_result
+    }
+}
+
+#[async_trait]
+impl FileDownloader for HttpFileDownloader {
+    async fn download_unpack(
+        &self,
+        location: &FileDownloaderUri,
+        file_size: u64,
+        target_dir: &Path,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        download_event_type: DownloadEvent,
+    ) -> StdResult<()> {
+        self.download_unpack_with_verifier(
+            location,
+            file_size,
+            target_dir,
+            compression_algorithm,
+            download_event_type,
+            None,
+        )
+        .await
+    }
+
+    async fn download_unpack_verify(
+        &self,
+        location: &FileDownloaderUri,
+        file_size: u64,
+        target_dir: &Path,
+        compression_algorithm: CompressionAlgorithm,
+        download_event_type: DownloadEvent,
+        verifier: Arc<dyn UnpackedFileVerifier>,
+    ) -> StdResult<()> {
+        self.download_unpack_with_verifier(
+            location,
+            file_size,
+            target_dir,
+            Some(compression_algorithm),
+            download_event_type,
+            Some(verifier),
+        )
+        .await
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
     use httpmock::MockServer;
 
     use mithril_common::{entities::FileUri, test_utils::TempDir};
@@ -375,4 +487,253 @@ mod tests {
         ];
         assert_eq!(expected_events, feedback_receiver.stacked_events());
     }
+
+    mod download_unpack_verify {
+        use mockall::predicate;
+
+        use crate::file_downloader::MockUnpackedFileVerifier;
+
+        use super::*;
+
+        fn create_gzip_archive(archive_path: &Path, files: &[(&str, &str)]) {
+            let encoder = flate2::write::GzEncoder::new(
+                std::fs::File::create(archive_path).unwrap(),
+                flate2::Compression::default(),
+            );
+            let mut tar_builder = tar::Builder::new(encoder);
+            for (file_name, content) in files {
+                let mut header = tar::Header::new_gnu();
+                header.set_size(content.len() as u64);
+                header.set_mode(0o644);
+                header.set_cksum();
+                tar_builder
+                    .append_data(&mut header, file_name, content.as_bytes())
+                    .unwrap();
+            }
+            tar_builder.into_inner().unwrap().finish().unwrap();
+        }
+
+        fn http_file_downloader() -> HttpFileDownloader {
+            HttpFileDownloader::new(FeedbackSender::new(&[]), TestLogger::stdout()).unwrap()
+        }
+
+        #[tokio::test]
+        async fn call_the_verifier_on_each_unpacked_file() {
+            let temp_dir = TempDir::create(
+                "client-http-downloader",
+                "download_unpack_verify_call_the_verifier_on_each_unpacked_file",
+            );
+            let archive_path = temp_dir.join("archive.tar.gz");
+            create_gzip_archive(
+                &archive_path,
+                &[
+                    ("immutable/00001.chunk", "chunk"),
+                    ("immutable/00001.primary", "primary"),
+                ],
+            );
+            let target_dir = temp_dir.join("target");
+            std::fs::create_dir(&target_dir).unwrap();
+            let mut verifier = MockUnpackedFileVerifier::new();
+            for file_name in ["immutable/00001.chunk", "immutable/00001.primary"] {
+                verifier
+                    .expect_verify_unpacked_file()
+                    .with(
+                        predicate::eq(target_dir.join(file_name)),
+                        predicate::eq(true),
+                    )
+                    .returning(|_, _| Ok(()))
+                    .once();
+            }
+
+            http_file_downloader()
+                .download_unpack_verify(
+                    &local_file_uri(&archive_path),
+                    0,
+                    &target_dir,
+                    CompressionAlgorithm::Gzip,
+                    DownloadEvent::Digest {
+                        download_id: "id".to_string(),
+                    },
+                    Arc::new(verifier),
+                )
+                .await
+                .unwrap();
+
+            assert!(target_dir.join("immutable/00001.chunk").exists());
+            assert!(target_dir.join("immutable/00001.primary").exists());
+        }
+
+        #[tokio::test]
+        async fn call_the_verifier_on_links_as_non_regular_entries() {
+            let temp_dir = TempDir::create(
+                "client-http-downloader",
+                "download_unpack_verify_call_the_verifier_on_links_as_non_regular_entries",
+            );
+            let archive_path = temp_dir.join("archive.tar.gz");
+            let encoder = flate2::write::GzEncoder::new(
+                std::fs::File::create(&archive_path).unwrap(),
+                flate2::Compression::default(),
+            );
+            let mut tar_builder = tar::Builder::new(encoder);
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_size(0);
+            tar_builder
+                .append_link(&mut header, "./immutable/00001.chunk", "00002.chunk")
+                .unwrap();
+            tar_builder.into_inner().unwrap().finish().unwrap();
+            let target_dir = temp_dir.join("target");
+            std::fs::create_dir(&target_dir).unwrap();
+            let mut verifier = MockUnpackedFileVerifier::new();
+            verifier
+                .expect_verify_unpacked_file()
+                .with(
+                    predicate::eq(target_dir.join("immutable/00001.chunk")),
+                    predicate::eq(false),
+                )
+                .returning(|_, _| Ok(()))
+                .once();
+
+            http_file_downloader()
+                .download_unpack_verify(
+                    &local_file_uri(&archive_path),
+                    0,
+                    &target_dir,
+                    CompressionAlgorithm::Gzip,
+                    DownloadEvent::Digest {
+                        download_id: "id".to_string(),
+                    },
+                    Arc::new(verifier),
+                )
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn abort_at_the_first_invalid_file() {
+            let temp_dir = TempDir::create(
+                "client-http-downloader",
+                "download_unpack_verify_abort_at_the_first_invalid_file",
+            );
+            let archive_path = temp_dir.join("archive.tar.gz");
+            create_gzip_archive(
+                &archive_path,
+                &[
+                    ("immutable/00001.chunk", "tampered"),
+                    ("immutable/00001.primary", "primary"),
+                ],
+            );
+            let target_dir = temp_dir.join("target");
+            std::fs::create_dir(&target_dir).unwrap();
+            let mut verifier = MockUnpackedFileVerifier::new();
+            verifier
+                .expect_verify_unpacked_file()
+                .returning(|_, _| Err(anyhow!("Invalid unpacked file")))
+                .once();
+
+            let error = http_file_downloader()
+                .download_unpack_verify(
+                    &local_file_uri(&archive_path),
+                    0,
+                    &target_dir,
+                    CompressionAlgorithm::Gzip,
+                    DownloadEvent::Digest {
+                        download_id: "id".to_string(),
+                    },
+                    Arc::new(verifier),
+                )
+                .await
+                .expect_err("download_unpack_verify should fail when the verifier fails");
+
+            assert!(
+                format!("{error:?}").contains("Invalid unpacked file"),
+                "Error should contain the verifier error, got: {error:?}"
+            );
+            assert!(!target_dir.join("immutable/00001.primary").exists());
+        }
+    }
+
+    mod download_unpack {
+        use super::*;
+
+        fn append_files_to_tar_archive<W: Write>(writer: W, files: &[(&str, &str)]) -> W {
+            let mut tar_builder = tar::Builder::new(writer);
+            for (file_name, content) in files {
+                let mut header = tar::Header::new_gnu();
+                header.set_size(content.len() as u64);
+                header.set_mode(0o644);
+                header.set_cksum();
+                tar_builder
+                    .append_data(&mut header, file_name, content.as_bytes())
+                    .unwrap();
+            }
+
+            tar_builder.into_inner().unwrap()
+        }
+
+        async fn download_unpack_archive(
+            archive_path: &Path,
+            compression_algorithm: CompressionAlgorithm,
+            target_dir: &Path,
+        ) {
+            std::fs::create_dir(target_dir).unwrap();
+            HttpFileDownloader::new(FeedbackSender::new(&[]), TestLogger::stdout())
+                .unwrap()
+                .download_unpack(
+                    &local_file_uri(archive_path),
+                    0,
+                    target_dir,
+                    Some(compression_algorithm),
+                    DownloadEvent::Digest {
+                        download_id: "id".to_string(),
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn unpack_lz4_archive_from_stream() {
+            let temp_dir = TempDir::create(
+                "client-http-downloader",
+                "download_unpack_lz4_archive_from_stream",
+            );
+            let archive_path = temp_dir.join("archive.tar.lz4");
+            let encoder = append_files_to_tar_archive(
+                lz4_flex::frame::FrameEncoder::new(std::fs::File::create(&archive_path).unwrap()),
+                &[("immutable/00001.chunk", "chunk")],
+            );
+            encoder.finish().unwrap();
+            let target_dir = temp_dir.join("target");
+
+            download_unpack_archive(&archive_path, CompressionAlgorithm::Lz4, &target_dir).await;
+
+            assert_eq!(
+                "chunk",
+                std::fs::read_to_string(target_dir.join("immutable/00001.chunk")).unwrap()
+            );
+        }
+
+        #[tokio::test]
+        async fn unpack_xz_archive_from_stream() {
+            let temp_dir = TempDir::create(
+                "client-http-downloader",
+                "download_unpack_xz_archive_from_stream",
+            );
+            let archive_path = temp_dir.join("archive.tar.xz");
+            let encoder = append_files_to_tar_archive(
+                liblzma::write::XzEncoder::new(std::fs::File::create(&archive_path).unwrap(), 6),
+                &[("immutable/00001.chunk", "chunk")],
+            );
+            encoder.finish().unwrap();
+            let target_dir = temp_dir.join("target");
+
+            download_unpack_archive(&archive_path, CompressionAlgorithm::Xz, &target_dir).await;
+
+            assert_eq!(
+                "chunk",
+                std::fs::read_to_string(target_dir.join("immutable/00001.chunk")).unwrap()
+            );
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use std::path::Path;
+use std::sync::Arc;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
@@ -243,6 +244,21 @@ impl DownloadEvent {
     }
 }
 
+/// A verifier of the entries unpacked by a [FileDownloader] while streaming an archive
+#[cfg_attr(test, mockall::automock)]
+pub trait UnpackedFileVerifier: Sync + Send {
+    /// Verify an entry right after it has been written on the disk, returning an error aborts the
+    /// download of the archive.
+    ///
+    /// Every unpacked entry is verified, `is_regular_file` is false for the directories, the links
+    /// and the other special entries.
+    fn verify_unpacked_file(
+        &self,
+        unpacked_file_path: &Path,
+        is_regular_file: bool,
+    ) -> StdResult<()>;
+}
+
 /// A file downloader
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
@@ -257,6 +273,25 @@ pub trait FileDownloader: Sync + Send {
         compression_algorithm: Option<CompressionAlgorithm>,
         download_event_type: DownloadEvent,
     ) -> StdResult<()>;
+
+    /// Download and unpack a compressed archive on the disk, calling the given verifier on each
+    /// unpacked file as soon as it is written so the download is aborted at the first invalid file.
+    ///
+    /// The default implementation fails since streaming verification is not supported.
+    async fn download_unpack_verify(
+        &self,
+        location: &FileDownloaderUri,
+        _file_size: u64,
+        _target_dir: &Path,
+        _compression_algorithm: CompressionAlgorithm,
+        _download_event_type: DownloadEvent,
+        _verifier: Arc<dyn UnpackedFileVerifier>,
+    ) -> StdResult<()> {
+        Err(anyhow!(
+            "Streaming verification is not supported by this file downloader (location: '{}')",
+            location.as_str()
+        ))
+    }
 }
 
 #[cfg(test)]
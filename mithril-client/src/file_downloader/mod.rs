@@ -9,9 +9,9 @@ mod mock_builder;
 mod retry;
 
 pub use http::HttpFileDownloader;
+pub use interface::{DownloadEvent, FileDownloader, FileDownloaderUri, UnpackedFileVerifier};
 #[cfg(test)]
-pub use interface::MockFileDownloader;
-pub use interface::{DownloadEvent, FileDownloader, FileDownloaderUri};
+pub use interface::{MockFileDownloader, MockUnpackedFileVerifier};
 #[cfg(test)]
 pub use mock_builder::{FakeAncillaryFileBuilder, MockFileDownloaderBuilder};
 pub use retry::{FileDownloadRetryPolicy, RetryDownloader};
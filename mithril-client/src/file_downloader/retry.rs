@@ -3,7 +3,7 @@ use std::{path::Path, sync::Arc, time::Duration};
 use async_trait::async_trait;
 use mithril_common::{StdResult, entities::CompressionAlgorithm};
 
-use super::{DownloadEvent, FileDownloader, FileDownloaderUri};
+use super::{DownloadEvent, FileDownloader, FileDownloaderUri, UnpackedFileVerifier};
 
 /// Policy for retrying file downloads.
 #[derive(Debug, PartialEq, Clone)]
@@ -53,33 +53,17 @@ impl RetryDownloader {
             retry_policy,
         }
     }
-}
 
-#[async_trait]
-impl FileDownloader for RetryDownloader {
-    async fn download_unpack(
-        &self,
-        location: &FileDownloaderUri,
-        file_size: u64,
-        target_dir: &Path,
-        compression_algorithm: Option<CompressionAlgorithm>,
-        download_event_type: DownloadEvent,
-    ) -> StdResult<()> {
+    async fn retry<F, Fut>(&self, location: &FileDownloaderUri, download: F) -> StdResult<()>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = StdResult<()>>,
+    {
         let retry_policy = &self.retry_policy;
         let mut nb_attempts = 0;
         loop {
             nb_attempts += 1;
-            match self
-                .file_downloader
-                .download_unpack(
-                    location,
-                    file_size,
-                    target_dir,
-                    compression_algorithm,
-                    download_event_type.clone(),
-                )
-                .await
-            {
+            match download().await {
                 Ok(result) => return Ok(result),
                 Err(e) if nb_attempts >= retry_policy.attempts => {
                     return Err(anyhow::anyhow!(e).context(format!(
@@ -92,6 +76,51 @@ impl FileDownloader for RetryDownloader {
     }
 }
 
+#[async_trait]
+impl FileDownloader for RetryDownloader {
+    async fn download_unpack(
+        &self,
+        location: &FileDownloaderUri,
+        file_size: u64,
+        target_dir: &Path,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        download_event_type: DownloadEvent,
+    ) -> StdResult<()> {
+        self.retry(location, || {
+            self.file_downloader.download_unpack(
+                location,
+                file_size,
+                target_dir,
+                compression_algorithm,
+                download_event_type.clone(),
+            )
+        })
+        .await
+    }
+
+    async fn download_unpack_verify(
+        &self,
+        location: &FileDownloaderUri,
+        file_size: u64,
+        target_dir: &Path,
+        compression_algorithm: CompressionAlgorithm,
+        download_event_type: DownloadEvent,
+        verifier: Arc<dyn UnpackedFileVerifier>,
+    ) -> StdResult<()> {
+        self.retry(location, || {
+            self.file_downloader.download_unpack_verify(
+                location,
+                file_size,
+                target_dir,
+                compression_algorithm,
+                download_event_type.clone(),
+                verifier.clone(),
+            )
+        })
+        .await
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -99,7 +128,9 @@ mod tests {
 
     use mithril_common::entities::FileUri;
 
-    use crate::file_downloader::MockFileDownloaderBuilder;
+    use crate::file_downloader::{
+        MockFileDownloader, MockFileDownloaderBuilder, MockUnpackedFileVerifier,
+    };
 
     use super::*;
 
@@ -192,6 +223,41 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn should_retry_download_with_verification_if_fail() {
+        let mut mock_file_downloader = MockFileDownloader::new();
+        mock_file_downloader
+            .expect_download_unpack_verify()
+            .returning(|_, _, _, _, _, _| Err(anyhow::anyhow!("Download failed")))
+            .times(2);
+        mock_file_downloader
+            .expect_download_unpack_verify()
+            .returning(|_, _, _, _, _, _| Ok(()))
+            .times(1);
+        let retry_downloader = RetryDownloader::new(
+            Arc::new(mock_file_downloader),
+            FileDownloadRetryPolicy {
+                attempts: 3,
+                delay_between_attempts: Duration::from_millis(10),
+            },
+        );
+
+        retry_downloader
+            .download_unpack_verify(
+                &FileDownloaderUri::FileUri(FileUri("http://whatever/snapshot.tar.gz".to_string())),
+                0,
+                Path::new("."),
+                CompressionAlgorithm::Gzip,
+                DownloadEvent::Full {
+                    download_id: "download_id".to_string(),
+                    digest: "digest".to_string(),
+                },
+                Arc::new(MockUnpackedFileVerifier::new()),
+            )
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn should_recall_a_failing_inner_downloader_up_to_the_limit() {
         let mock_file_downloader = MockFileDownloaderBuilder::default()
@@ -9,6 +9,8 @@
 //! - [Cardano Database v1 (aka Snapshot)][snapshot_client]: list, get, download archive and record statistics.
 //! - [Cardano Database v2][cardano_database_client] list, get, download archive and record statistics.
 //! - [Cardano transactions][cardano_transaction_client] list & get snapshot, get proofs.
+//! - [Cardano blocks][cardano_block_client] list & get snapshot, get proofs.
+//! - [Cardano transactions metadata][cardano_transaction_metadata_client] list & get snapshot, get proofs.
 //! - [Cardano stake distribution][cardano_stake_distribution_client] list, get and get by epoch.
 //! - [Mithril stake distribution][mithril_stake_distribution_client] list and get.
 //! - [Certificates][certificate_client] list, get, and chain validation.
@@ -121,9 +123,11 @@ macro_rules! cfg_fs_unstable {
 }
 
 pub mod aggregator_client;
+pub mod cardano_block_client;
 pub mod cardano_database_client;
 pub mod cardano_stake_distribution_client;
 pub mod cardano_transaction_client;
+pub mod cardano_transaction_metadata_client;
 pub mod certificate_client;
 mod client;
 pub mod era;
@@ -17,7 +17,8 @@ use mithril_common::{crypto_helper::MKProof, entities::SignedEntityType};
 
 use crate::{
     CardanoStakeDistribution, MithrilCertificate, MithrilResult, MithrilSigner,
-    MithrilStakeDistribution, VerifiedCardanoTransactions,
+    MithrilStakeDistribution, VerifiedCardanoBlocks, VerifiedCardanoTransactions,
+    VerifiedCardanoTransactionsMetadata,
     common::{ProtocolMessage, ProtocolMessagePartKey},
 };
 
@@ -157,6 +158,28 @@ impl MessageBuilder {
         message
     }
 
+    /// Compute message for a Cardano Blocks Proofs.
+    pub fn compute_cardano_blocks_proofs_message(
+        &self,
+        blocks_proofs_certificate: &MithrilCertificate,
+        verified_blocks: &VerifiedCardanoBlocks,
+    ) -> ProtocolMessage {
+        let mut message = blocks_proofs_certificate.protocol_message.clone();
+        verified_blocks.fill_protocol_message(&mut message);
+        message
+    }
+
+    /// Compute message for a Cardano Transactions Metadata Proofs.
+    pub fn compute_cardano_transactions_metadata_proofs_message(
+        &self,
+        transactions_metadata_proofs_certificate: &MithrilCertificate,
+        verified_transactions_metadata: &VerifiedCardanoTransactionsMetadata,
+    ) -> ProtocolMessage {
+        let mut message = transactions_metadata_proofs_certificate.protocol_message.clone();
+        verified_transactions_metadata.fill_protocol_message(&mut message);
+        message
+    }
+
     /// Compute message for a Cardano stake distribution.
     pub fn compute_cardano_stake_distribution_message(
         &self,
@@ -105,23 +105,26 @@ use anyhow::Context;
 #[cfg(feature = "fs")]
 use slog::Logger;
 #[cfg(feature = "fs")]
+use std::collections::BTreeMap;
+#[cfg(feature = "fs")]
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use thiserror::Error;
 
 #[cfg(feature = "fs")]
-use mithril_common::entities::CompressionAlgorithm;
+use mithril_common::entities::{CompressionAlgorithm, HexEncodedDigest, ImmutableFileName};
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 #[cfg(feature = "fs")]
-use crate::feedback::FeedbackSender;
+use crate::feedback::{FeedbackSender, MithrilEvent};
 #[cfg(feature = "fs")]
-use crate::file_downloader::{DownloadEvent, FileDownloader};
+use crate::file_downloader::{DownloadEvent, FileDownloader, UnpackedFileVerifier};
 #[cfg(feature = "fs")]
 use crate::utils::create_bootstrap_node_files;
 #[cfg(feature = "fs")]
 use crate::utils::{
-    ANCILLARIES_NOT_SIGNED_BY_MITHRIL, AncillaryVerifier, UnexpectedDownloadedFileVerifier,
+    ANCILLARIES_NOT_SIGNED_BY_MITHRIL, AncillaryVerifier, PreferredLocations,
+    UnexpectedDownloadedFileVerifier, UnpackedImmutableFilesDigester,
 };
 use crate::{MithrilResult, Snapshot, SnapshotListItem};
 
@@ -154,7 +157,9 @@ pub struct SnapshotClient {
     #[cfg(feature = "fs")]
     ancillary_verifier: Option<Arc<AncillaryVerifier>>,
     #[cfg(feature = "fs")]
-    _feedback_sender: FeedbackSender,
+    preferred_locations: PreferredLocations,
+    #[cfg(feature = "fs")]
+    feedback_sender: FeedbackSender,
     #[cfg(feature = "fs")]
     logger: Logger,
 }
@@ -174,9 +179,10 @@ impl SnapshotClient {
             http_file_downloader,
             #[cfg(feature = "fs")]
             ancillary_verifier,
-            // The underscore prefix prevents breaking the `SnapshotClient` API compatibility.
             #[cfg(feature = "fs")]
-            _feedback_sender: feedback_sender,
+            preferred_locations: PreferredLocations::default(),
+            #[cfg(feature = "fs")]
+            feedback_sender,
             #[cfg(feature = "fs")]
             logger: mithril_common::logging::LoggerExtensions::new_with_component_name::<Self>(
                 &logger,
@@ -218,6 +224,12 @@ impl SnapshotClient {
     }
 
     cfg_fs! {
+        /// Set the preferred locations used to select the locations to try first when downloading.
+        pub(crate) fn with_preferred_locations(mut self, preferred_locations: PreferredLocations) -> Self {
+            self.preferred_locations = preferred_locations;
+            self
+        }
+
         /// Download and unpack the given snapshot, including its ancillary files, to the given directory
         ///
         /// Ancillary files are the files that are not signed by Mithril but are needed to enable fast
@@ -291,14 +303,120 @@ impl SnapshotClient {
             result
         }
 
+        /// Download and unpack the given snapshot to the given directory in streaming mode, its
+        /// ancillary files being included if `include_ancillary` is set.
+        ///
+        /// The archive is decompressed and unpacked on the fly from the downloaded stream with a
+        /// bounded memory usage, and is never stored on the disk: only the disk space of the
+        /// unpacked files is needed.
+        /// The digest of each immutable file is computed right after it is written so the
+        /// snapshot digest is computed at the end of the download without reading the files
+        /// again, it is returned so it can be verified against the snapshot certificate.
+        /// The download is aborted as soon as an unexpected entry is unpacked (a link, a duplicated
+        /// immutable file or an immutable file beyond the snapshot beacon).
+        ///
+        /// If `certified_digests` are given, for example the [certified digests][crate::cardano_database_client::CardanoDatabaseClient::download_certified_digests]
+        /// of a Cardano database snapshot with the same or a greater immutable file number, each
+        /// immutable file is verified against its certified digest right after it is written and
+        /// the download is aborted at the first mismatch. Otherwise a digest mismatch is only
+        /// detected once the whole archive is unpacked.
+        ///
+        /// **NOTE**: The target directory should already exist, and the user running the binary
+        /// must have read/write access to it.
+        pub async fn download_unpack_streaming(
+            &self,
+            snapshot: &Snapshot,
+            target_dir: &Path,
+            include_ancillary: bool,
+            certified_digests: Option<BTreeMap<ImmutableFileName, HexEncodedDigest>>,
+        ) -> MithrilResult<String> {
+            if include_ancillary && self.ancillary_verifier.is_none() {
+                return Err(SnapshotClientError::MissingAncillaryVerifier.into());
+            }
+
+            let expected_files_after_download = UnexpectedDownloadedFileVerifier::new(
+                target_dir,
+                include_ancillary,
+                snapshot.beacon.immutable_file_number,
+                &self.logger
+            )
+            .compute_expected_state_after_download()
+            .await?;
+
+            // Return the result later so unexpected file removal is always run
+            let result = self
+                .run_download_unpack_streaming(
+                    snapshot,
+                    target_dir,
+                    include_ancillary,
+                    certified_digests,
+                )
+                .await;
+
+            expected_files_after_download
+                .remove_unexpected_files()
+                .await?;
+
+            result
+        }
+
+        async fn run_download_unpack_streaming(
+            &self,
+            snapshot: &Snapshot,
+            target_dir: &Path,
+            include_ancillary: bool,
+            certified_digests: Option<BTreeMap<ImmutableFileName, HexEncodedDigest>>,
+        ) -> MithrilResult<String> {
+            let download_id = MithrilEvent::new_snapshot_download_id();
+            let mut immutable_files_digester = UnpackedImmutableFilesDigester::new(
+                target_dir,
+                snapshot.beacon.immutable_file_number,
+            );
+            if let Some(certified_digests) = certified_digests {
+                immutable_files_digester =
+                    immutable_files_digester.with_certified_digests(certified_digests);
+            }
+            let immutable_files_digester = Arc::new(immutable_files_digester);
+            self.download_unpack_file(
+                &snapshot.digest,
+                &snapshot.locations,
+                snapshot.size,
+                target_dir,
+                snapshot.compression_algorithm,
+                DownloadEvent::Full {
+                    download_id: download_id.clone(),
+                    digest: snapshot.digest.clone(),
+                },
+                Some(immutable_files_digester.clone()),
+            )
+            .await?;
+            let computed_digest = immutable_files_digester
+                .verify_digest(
+                    &snapshot.network,
+                    &snapshot.beacon,
+                    &snapshot.digest,
+                    &self.logger,
+                )
+                .await?;
+
+            if include_ancillary {
+                self.download_unpack_ancillary(snapshot, target_dir, &download_id)
+                    .await?;
+            }
+            create_bootstrap_node_files(
+                &self.logger,
+                target_dir,
+                &snapshot.network,
+            )?;
+            Ok(computed_digest)
+        }
+
         async fn run_download_unpack(
             &self,
             snapshot: &Snapshot,
             target_dir: &Path,
             include_ancillary: bool,
         ) -> MithrilResult<()> {
-            use crate::feedback::MithrilEvent;
-
             let download_id = MithrilEvent::new_snapshot_download_id();
             self.download_unpack_immutables_files(snapshot, target_dir, &download_id)
                 .await?;
@@ -330,6 +448,7 @@ impl SnapshotClient {
                     download_id: download_id.to_string(),
                     digest: snapshot.digest.clone(),
                 },
+                None,
             )
             .await?;
 
@@ -398,6 +517,7 @@ impl SnapshotClient {
                 DownloadEvent::FullAncillary {
                     download_id: download_id.to_string(),
                 },
+                None,
             )
             .await?;
 
@@ -414,6 +534,7 @@ impl SnapshotClient {
             Ok(())
         }
 
+        #[allow(clippy::too_many_arguments)]
         async fn download_unpack_file(
             &self,
             digest: &str,
@@ -422,23 +543,50 @@ impl SnapshotClient {
             target_dir: &Path,
             compression_algorithm: CompressionAlgorithm,
             download_event: DownloadEvent,
+            verifier: Option<Arc<dyn UnpackedFileVerifier>>,
         ) -> MithrilResult<()> {
-            for location in locations {
+            let mut locations = locations.to_vec();
+            self.preferred_locations
+                .sort(&mut locations, |location| location.as_str());
+
+            for location in &locations {
                 let file_downloader_uri = location.to_owned().into();
 
-                match self
-                    .http_file_downloader
-                    .download_unpack(
-                        &file_downloader_uri,
-                        size,
-                        target_dir,
-                        Some(compression_algorithm),
-                        download_event.clone(),
-                    )
-                    .await
-                { Err(error) => {
+                let download_result = match &verifier {
+                    Some(verifier) => {
+                        self.http_file_downloader
+                            .download_unpack_verify(
+                                &file_downloader_uri,
+                                size,
+                                target_dir,
+                                compression_algorithm,
+                                download_event.clone(),
+                                verifier.clone(),
+                            )
+                            .await
+                    }
+                    None => {
+                        self.http_file_downloader
+                            .download_unpack(
+                                &file_downloader_uri,
+                                size,
+                                target_dir,
+                                Some(compression_algorithm),
+                                download_event.clone(),
+                            )
+                            .await
+                    }
+                };
+                match download_result { Err(error) => {
                     slog::warn!(self.logger, "Failed downloading snapshot from '{location}'"; "error" => ?error);
                 } _ => {
+                    self.feedback_sender
+                        .send_event(MithrilEvent::SnapshotFileDownloadedFromLocation {
+                            download_id: download_event.download_id().to_string(),
+                            file_name: Self::downloaded_file_name(&download_event).to_string(),
+                            location: location.to_string(),
+                        })
+                        .await;
                     return Ok(());
                 }}
             }
@@ -452,6 +600,13 @@ impl SnapshotClient {
             .into())
         }
 
+        fn downloaded_file_name(download_event: &DownloadEvent) -> &'static str {
+            match download_event {
+                DownloadEvent::FullAncillary { .. } => "ancillary",
+                _ => "snapshot",
+            }
+        }
+
         fn ancillary_subdir(target_dir: &Path, download_id: &str) -> PathBuf {
             target_dir.join(format!("ancillary-{download_id}"))
         }
@@ -475,7 +630,7 @@ mod tests {
     use crate::{
         aggregator_client::MockAggregatorClient,
         common::CompressionAlgorithm,
-        feedback::MithrilEvent,
+        feedback::{MithrilEvent, StackFeedbackReceiver},
         file_downloader::{MockFileDownloader, MockFileDownloaderBuilder},
         test_utils::TestLogger,
     };
@@ -537,6 +692,7 @@ mod tests {
                     &PathBuf::from("/whatever"),
                     CompressionAlgorithm::Gzip,
                     dummy_download_event(),
+                    None,
                 )
                 .await;
 
@@ -569,6 +725,7 @@ mod tests {
                     &PathBuf::from("/whatever"),
                     CompressionAlgorithm::Gzip,
                     dummy_download_event(),
+                    None,
                 )
                 .await
                 .expect_err("Should fail when all locations fail");
@@ -608,11 +765,83 @@ mod tests {
                     &PathBuf::from("/whatever"),
                     CompressionAlgorithm::Gzip,
                     dummy_download_event(),
+                    None,
                 )
                 .await
                 .expect("Should succeed when fallbacking to another location");
         }
 
+        #[tokio::test]
+        async fn try_preferred_location_first() {
+            let mock_downloader = MockFileDownloaderBuilder::default()
+                .with_file_uri("http://mirror.internal/snapshot")
+                .with_success()
+                .build();
+            let client = SnapshotClient {
+                preferred_locations: PreferredLocations::new(["mirror.internal"]),
+                ..setup_snapshot_client(Arc::new(mock_downloader))
+            };
+
+            client
+                .download_unpack_file(
+                    "test-digest",
+                    &[
+                        "http://example.com/snapshot".to_string(),
+                        "http://mirror.internal/snapshot".to_string(),
+                    ],
+                    19,
+                    &PathBuf::from("/whatever"),
+                    CompressionAlgorithm::Gzip,
+                    dummy_download_event(),
+                    None,
+                )
+                .await
+                .expect("Should succeed by downloading from the preferred location");
+        }
+
+        #[tokio::test]
+        async fn send_feedback_event_with_the_location_that_served_the_file() {
+            let mock_downloader = MockFileDownloaderBuilder::default()
+                .with_file_uri("http://example.com/snapshot1")
+                .with_failure()
+                .next_call()
+                .with_file_uri("http://example.com/snapshot2")
+                .with_success()
+                .build();
+            let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+            let client = SnapshotClient {
+                feedback_sender: FeedbackSender::new(&[feedback_receiver.clone()]),
+                ..setup_snapshot_client(Arc::new(mock_downloader))
+            };
+
+            client
+                .download_unpack_file(
+                    "test-digest",
+                    &[
+                        "http://example.com/snapshot1".to_string(),
+                        "http://example.com/snapshot2".to_string(),
+                    ],
+                    19,
+                    &PathBuf::from("/whatever"),
+                    CompressionAlgorithm::Gzip,
+                    DownloadEvent::FullAncillary {
+                        download_id: "download_id".to_string(),
+                    },
+                    None,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                vec![MithrilEvent::SnapshotFileDownloadedFromLocation {
+                    download_id: "download_id".to_string(),
+                    file_name: "ancillary".to_string(),
+                    location: "http://example.com/snapshot2".to_string(),
+                }],
+                feedback_receiver.stacked_events()
+            );
+        }
+
         #[tokio::test]
         async fn fail_if_location_list_is_empty() {
             let client = setup_snapshot_client(Arc::new(MockFileDownloader::new()));
@@ -625,6 +854,7 @@ mod tests {
                     &PathBuf::from("/whatever"),
                     CompressionAlgorithm::Gzip,
                     dummy_download_event(),
+                    None,
                 )
                 .await
                 .expect_err("Should fail with empty location list");
@@ -694,6 +924,174 @@ mod tests {
         }
     }
 
+    mod download_unpack_streaming {
+        use mithril_cardano_node_internal_database::digesters::{
+            CardanoImmutableDigester, ImmutableDigester,
+        };
+        use mithril_cardano_node_internal_database::test::DummyCardanoDbBuilder;
+        use mithril_common::entities::CardanoDbBeacon;
+
+        use super::*;
+
+        fn mock_downloader_unpacking_immutable_files(
+            immutable_files_paths: Vec<PathBuf>,
+        ) -> MockFileDownloader {
+            let mut mock_downloader = MockFileDownloader::new();
+            mock_downloader.expect_download_unpack_verify().returning(
+                move |_, _, _, _, _, verifier| {
+                    for immutable_file_path in &immutable_files_paths {
+                        verifier.verify_unpacked_file(immutable_file_path, true)?;
+                    }
+                    Ok(())
+                },
+            );
+
+            mock_downloader
+        }
+
+        #[tokio::test]
+        async fn fail_if_ancillary_files_are_included_and_ancillary_verifier_is_not_set() {
+            let client = setup_snapshot_client(Arc::new(MockFileDownloader::new()), None);
+
+            let error = client
+                .download_unpack_streaming(
+                    &Snapshot::dummy(),
+                    &PathBuf::from("/whatever"),
+                    true,
+                    None,
+                )
+                .await
+                .expect_err("Should fail when ancillary verifier is not set");
+
+            assert!(
+                matches!(
+                    error.downcast_ref::<SnapshotClientError>(),
+                    Some(SnapshotClientError::MissingAncillaryVerifier)
+                ),
+                "Expected SnapshotClientError::MissingAncillaryVerifier, but got: {error:#?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn verify_the_snapshot_digest_with_the_digests_computed_while_unpacking() {
+            let cardano_db = DummyCardanoDbBuilder::new(
+                "snapshot_client_verify_the_snapshot_digest_with_the_digests_computed_while_unpacking",
+            )
+            .with_immutables(&[1, 2, 3])
+            .build();
+            let beacon = CardanoDbBeacon::new(1, 3);
+            let snapshot = Snapshot {
+                digest: CardanoImmutableDigester::new(
+                    Snapshot::dummy().network,
+                    None,
+                    TestLogger::stdout(),
+                )
+                .compute_digest(cardano_db.get_dir(), &beacon)
+                .await
+                .unwrap(),
+                beacon,
+                ..Snapshot::dummy()
+            };
+            let mock_downloader = mock_downloader_unpacking_immutable_files(
+                cardano_db
+                    .get_immutable_files()
+                    .iter()
+                    .map(|immutable_file| immutable_file.path.clone())
+                    .collect(),
+            );
+            let client = setup_snapshot_client(Arc::new(mock_downloader), None);
+
+            let computed_digest = client
+                .download_unpack_streaming(&snapshot, cardano_db.get_dir(), false, None)
+                .await
+                .unwrap();
+
+            assert_eq!(snapshot.digest, computed_digest);
+        }
+
+        #[tokio::test]
+        async fn fail_if_the_snapshot_digest_does_not_match_the_unpacked_immutable_files() {
+            let cardano_db = DummyCardanoDbBuilder::new(
+                "snapshot_client_fail_if_the_snapshot_digest_does_not_match_the_unpacked_immutable_files",
+            )
+            .with_immutables(&[1, 2, 3])
+            .build();
+            let snapshot = Snapshot {
+                digest: "another-digest".to_string(),
+                beacon: CardanoDbBeacon::new(1, 3),
+                ..Snapshot::dummy()
+            };
+            let mock_downloader = mock_downloader_unpacking_immutable_files(
+                cardano_db
+                    .get_immutable_files()
+                    .iter()
+                    .map(|immutable_file| immutable_file.path.clone())
+                    .collect(),
+            );
+            let client = setup_snapshot_client(Arc::new(mock_downloader), None);
+
+            client
+                .download_unpack_streaming(&snapshot, cardano_db.get_dir(), false, None)
+                .await
+                .expect_err("Should fail when the snapshot digest does not match");
+        }
+
+        #[tokio::test]
+        async fn fail_if_an_immutable_file_does_not_match_its_certified_digest() {
+            let cardano_db = DummyCardanoDbBuilder::new(
+                "snapshot_client_fail_if_an_immutable_file_does_not_match_its_certified_digest",
+            )
+            .with_immutables(&[1, 2, 3])
+            .build();
+            let beacon = CardanoDbBeacon::new(1, 3);
+            let snapshot = Snapshot {
+                digest: CardanoImmutableDigester::new(
+                    Snapshot::dummy().network,
+                    None,
+                    TestLogger::stdout(),
+                )
+                .compute_digest(cardano_db.get_dir(), &beacon)
+                .await
+                .unwrap(),
+                beacon,
+                ..Snapshot::dummy()
+            };
+            let certified_digests =
+                BTreeMap::from([("00001.chunk".to_string(), "another-digest".to_string())]);
+            let mock_downloader = mock_downloader_unpacking_immutable_files(
+                cardano_db
+                    .get_immutable_files()
+                    .iter()
+                    .map(|immutable_file| immutable_file.path.clone())
+                    .collect(),
+            );
+            let (logger, log_inspector) = TestLogger::memory();
+            let client = SnapshotClient {
+                logger,
+                ..setup_snapshot_client(Arc::new(mock_downloader), None)
+            };
+
+            client
+                .download_unpack_streaming(
+                    &snapshot,
+                    cardano_db.get_dir(),
+                    false,
+                    Some(certified_digests),
+                )
+                .await
+                .expect_err(
+                    "Should fail when an immutable file does not match its certified digest",
+                );
+
+            assert!(
+                log_inspector.contains_log(
+                    "Digest of the immutable file '00001.chunk' does not match its certified digest"
+                ),
+                "Expected log message not found, logs: {log_inspector}"
+            );
+        }
+    }
+
     mod download_unpack {
         use super::*;
 
@@ -58,6 +58,34 @@ pub use mithril_common::messages::CardanoTransactionSnapshotMessage as CardanoTr
 /// List item of a Cardano transaction snapshot.
 pub use mithril_common::messages::CardanoTransactionSnapshotListItemMessage as CardanoTransactionSnapshotListItem;
 
+pub use mithril_common::messages::CardanoBlocksProofsMessage as CardanoBlocksProofs;
+
+pub use mithril_common::messages::CardanoBlocksSetProofMessagePart as CardanoBlocksSetProof;
+
+pub use mithril_common::messages::VerifiedCardanoBlocks;
+
+pub use mithril_common::messages::VerifyCardanoBlocksProofsError;
+
+/// A snapshot that allow to know up to which block Mithril have certified Cardano blocks.
+pub use mithril_common::messages::CardanoBlocksSnapshotMessage as CardanoBlocksSnapshot;
+
+/// List item of a Cardano blocks snapshot.
+pub use mithril_common::messages::CardanoBlocksSnapshotListItemMessage as CardanoBlocksSnapshotListItem;
+
+pub use mithril_common::messages::CardanoTransactionsMetadataProofsMessage as CardanoTransactionsMetadataProofs;
+
+pub use mithril_common::messages::CardanoTransactionsMetadataSetProofMessagePart as CardanoTransactionsMetadataSetProof;
+
+pub use mithril_common::messages::VerifiedCardanoTransactionsMetadata;
+
+pub use mithril_common::messages::VerifyCardanoTransactionsMetadataProofsError;
+
+/// A snapshot that allow to know up to which block Mithril have certified Cardano transactions metadata.
+pub use mithril_common::messages::CardanoTransactionsMetadataSnapshotMessage as CardanoTransactionsMetadataSnapshot;
+
+/// List item of a Cardano transactions metadata snapshot.
+pub use mithril_common::messages::CardanoTransactionsMetadataSnapshotListItemMessage as CardanoTransactionsMetadataSnapshotListItem;
+
 /// A Cardano stake distribution.
 pub use mithril_common::messages::CardanoStakeDistributionMessage as CardanoStakeDistribution;
 
@@ -68,9 +96,10 @@ pub use mithril_common::messages::CardanoStakeDistributionListItemMessage as Car
 pub mod common {
     pub use mithril_common::crypto_helper::MKProof;
     pub use mithril_common::entities::{
-        AncillaryLocation, BlockHash, BlockNumber, CardanoDbBeacon, CardanoNetwork, ChainPoint,
-        CompressionAlgorithm, DigestLocation, Epoch, ImmutableFileNumber, ImmutablesLocation,
-        MagicId, MultiFilesUri, ProtocolMessage, ProtocolMessagePartKey, ProtocolParameters,
+        AncillaryLocation, BlockHash, BlockNumber, CardanoBlock, CardanoDbBeacon, CardanoNetwork,
+        CardanoTransactionBlockPosition, ChainPoint, CompressionAlgorithm, DigestLocation, Epoch,
+        HexEncodedDigest, ImmutableFileName, ImmutableFileNumber, ImmutablesLocation, MagicId,
+        MultiFilesUri, ProtocolMessage, ProtocolMessagePartKey, ProtocolParameters,
         SignedEntityType, SlotNumber, StakeDistribution, SupportedEra, TemplateUri,
         TransactionHash,
     };
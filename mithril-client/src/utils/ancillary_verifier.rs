@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::path::{Path, PathBuf};
 
@@ -9,6 +10,7 @@ use mithril_cardano_node_internal_database::entities::{
 };
 use mithril_common::crypto_helper::{ManifestVerifier, ManifestVerifierVerificationKey};
 
+use crate::cardano_database_client::AncillaryFilesAuditReport;
 use crate::{MithrilError, MithrilResult};
 
 /// Verifies the ancillary files contained in an unpacked ancillary archive
@@ -52,15 +54,7 @@ impl AncillaryVerifier {
     ) -> Result<ValidatedAncillaryManifest, AncillaryVerificationError> {
         let ancillary_manifest_path =
             temp_ancillary_dir.join(AncillaryFilesManifest::ANCILLARY_MANIFEST_FILE_NAME);
-        let manifest_file = File::open(&ancillary_manifest_path)
-            .with_context(|| "Failed to open manifest file")
-            .map_err(|e| {
-                AncillaryVerificationError::ManifestParse(ancillary_manifest_path.clone(), e)
-            })?;
-        let manifest: AncillaryFilesManifest =
-            serde_json::from_reader(&manifest_file).map_err(|e| {
-                AncillaryVerificationError::ManifestParse(ancillary_manifest_path, e.into())
-            })?;
+        let manifest = Self::read_manifest(&ancillary_manifest_path)?;
 
         manifest.verify_data(temp_ancillary_dir).await?;
 
@@ -76,6 +70,63 @@ impl AncillaryVerifier {
             ancillary_files: manifest.files(),
         })
     }
+
+    /// Audits the ancillary files of the given directory against the given signed manifest
+    ///
+    /// Contrary to [verify][Self::verify], the verification does not stop at the first invalid
+    /// file so every tampered or missing file is reported.
+    pub async fn audit(
+        &self,
+        ancillary_manifest_path: &Path,
+        base_directory: &Path,
+    ) -> Result<AncillaryFilesAuditReport, AncillaryVerificationError> {
+        let manifest = Self::read_manifest(ancillary_manifest_path)?;
+        let valid_manifest_signature = manifest.signature().is_some_and(|signature| {
+            self.verifier.verify(&manifest.compute_hash(), &signature).is_ok()
+        });
+
+        let mut report = AncillaryFilesAuditReport {
+            valid_manifest_signature,
+            verified_files: 0,
+            tampered_files: vec![],
+            missing_files: vec![],
+        };
+        for (file, hash) in &manifest.signable_manifest.data {
+            let file_manifest = AncillaryFilesManifest::new_without_signature(BTreeMap::from([(
+                file.clone(),
+                hash.clone(),
+            )]));
+
+            match file_manifest.verify_data(base_directory).await {
+                Ok(()) => report.verified_files += 1,
+                Err(AncillaryFilesManifestVerifyError::FileHashMismatch { .. }) => {
+                    report.tampered_files.push(file.clone())
+                }
+                Err(AncillaryFilesManifestVerifyError::HashCompute { .. }) => {
+                    report.missing_files.push(file.clone())
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn read_manifest(
+        ancillary_manifest_path: &Path,
+    ) -> Result<AncillaryFilesManifest, AncillaryVerificationError> {
+        let manifest_file = File::open(ancillary_manifest_path)
+            .with_context(|| "Failed to open manifest file")
+            .map_err(|e| {
+                AncillaryVerificationError::ManifestParse(ancillary_manifest_path.to_path_buf(), e)
+            })?;
+
+        serde_json::from_reader(&manifest_file).map_err(|e| {
+            AncillaryVerificationError::ManifestParse(
+                ancillary_manifest_path.to_path_buf(),
+                e.into(),
+            )
+        })
+    }
 }
 
 impl ValidatedAncillaryManifest {
@@ -292,6 +343,84 @@ mod tests {
                 validated_manifest
             );
         }
+
+        #[tokio::test]
+        async fn audit_report_all_files_verified_if_manifest_is_valid_and_signed() {
+            let temp_dir = temp_dir_create!();
+            let signer = ManifestSigner::create_deterministic_signer();
+            let manifest = valid_manifest(&temp_dir, &signer);
+            write_manifest(&temp_dir, &manifest);
+
+            let ancillary_verifier = AncillaryVerifier::new(signer.verification_key());
+
+            let report = ancillary_verifier
+                .audit(
+                    &temp_dir.join(AncillaryFilesManifest::ANCILLARY_MANIFEST_FILE_NAME),
+                    &temp_dir,
+                )
+                .await
+                .unwrap();
+            assert_eq!(
+                AncillaryFilesAuditReport {
+                    valid_manifest_signature: true,
+                    verified_files: 2,
+                    tampered_files: vec![],
+                    missing_files: vec![],
+                },
+                report
+            );
+        }
+
+        #[tokio::test]
+        async fn audit_report_every_tampered_and_missing_files() {
+            let temp_dir = temp_dir_create!();
+            let signer = ManifestSigner::create_deterministic_signer();
+            let mut manifest = AncillaryFilesManifest::new_without_signature(BTreeMap::from([
+                generate_manifest_item(&temp_dir, "file1.txt", "content 1"),
+                generate_manifest_item(&temp_dir, "file2.txt", "content 2"),
+                generate_manifest_item(&temp_dir, "file3.txt", "content 3"),
+            ]));
+            manifest.set_signature(signer.sign(&manifest.compute_hash()));
+            let manifest_path = temp_dir.join("manifest_kept_aside.json");
+            serde_json::to_writer(File::create(&manifest_path).unwrap(), &manifest).unwrap();
+            write_file(&temp_dir.join("file1.txt"), "tampered content");
+            std::fs::remove_file(temp_dir.join("file3.txt")).unwrap();
+
+            let ancillary_verifier = AncillaryVerifier::new(signer.verification_key());
+
+            let report = ancillary_verifier.audit(&manifest_path, &temp_dir).await.unwrap();
+            assert_eq!(
+                AncillaryFilesAuditReport {
+                    valid_manifest_signature: true,
+                    verified_files: 1,
+                    tampered_files: vec![PathBuf::from("file1.txt")],
+                    missing_files: vec![PathBuf::from("file3.txt")],
+                },
+                report
+            );
+        }
+
+        #[tokio::test]
+        async fn audit_report_invalid_signature() {
+            let temp_dir = temp_dir_create!();
+            let signer = ManifestSigner::create_deterministic_signer();
+            let manifest = valid_manifest(&temp_dir, &signer);
+            write_manifest(&temp_dir, &manifest);
+
+            let ancillary_verifier = AncillaryVerifier::new(
+                ManifestSigner::create_non_deterministic_signer().verification_key(),
+            );
+
+            let report = ancillary_verifier
+                .audit(
+                    &temp_dir.join(AncillaryFilesManifest::ANCILLARY_MANIFEST_FILE_NAME),
+                    &temp_dir,
+                )
+                .await
+                .unwrap();
+            assert!(!report.valid_manifest_signature);
+            assert_eq!(2, report.verified_files);
+        }
     }
 
     mod moving_validated_manifest {
@@ -6,15 +6,19 @@ cfg_fs! {
 
     mod ancillary_verifier;
     mod fs;
+    mod preferred_locations;
     mod stream_reader;
     mod bootstrap_files;
     mod unexpected_downloaded_file_verifier;
+    mod unpacked_immutable_files_digester;
     mod vec_deque_extensions;
 
     pub use fs::*;
     pub use vec_deque_extensions::VecDequeExtensions;
     pub use ancillary_verifier::AncillaryVerifier;
+    pub use preferred_locations::PreferredLocations;
     pub(crate) use unexpected_downloaded_file_verifier::*;
+    pub use unpacked_immutable_files_digester::UnpackedImmutableFilesDigester;
     pub use stream_reader::*;
     pub use bootstrap_files::*;
 }
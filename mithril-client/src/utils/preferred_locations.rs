@@ -0,0 +1,113 @@
+/// Ordered list of patterns used to select the locations to try first when a file is available
+/// at several locations.
+///
+/// A location matches a pattern if its URI contains it: the locations matching the first pattern
+/// are tried first, then the ones matching the second pattern, and so on, the locations matching
+/// no pattern being tried last.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreferredLocations {
+    patterns: Vec<String>,
+}
+
+impl PreferredLocations {
+    /// Constructs a new `PreferredLocations`, empty patterns are ignored.
+    pub fn new<T: Into<String>, I: IntoIterator<Item = T>>(patterns: I) -> Self {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(Into::into)
+                .filter(|pattern: &String| !pattern.is_empty())
+                .collect(),
+        }
+    }
+
+    fn rank(&self, uri: &str) -> usize {
+        self.patterns
+            .iter()
+            .position(|pattern| uri.contains(pattern.as_str()))
+            .unwrap_or(self.patterns.len())
+    }
+
+    /// Sort the given locations so the preferred ones come first.
+    ///
+    /// The sort is stable: the original order is kept between locations of the same rank.
+    pub fn sort<T, F>(&self, locations: &mut [T], uri: F)
+    where
+        F: Fn(&T) -> &str,
+    {
+        if !self.patterns.is_empty() {
+            locations.sort_by_key(|location| self.rank(uri(location)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(
+        preferred_locations: &PreferredLocations,
+        uris: &[&'static str],
+    ) -> Vec<&'static str> {
+        let mut locations = uris.to_vec();
+        preferred_locations.sort(&mut locations, |uri| *uri);
+
+        locations
+    }
+
+    #[test]
+    fn keep_original_order_without_patterns() {
+        let uris = [
+            "https://cdn/file",
+            "https://mirror.internal/file",
+            "http://local/file",
+        ];
+
+        assert_eq!(uris.to_vec(), sorted(&PreferredLocations::default(), &uris));
+    }
+
+    #[test]
+    fn empty_patterns_are_ignored() {
+        let uris = ["https://cdn/file", "https://mirror.internal/file"];
+
+        assert_eq!(uris.to_vec(), sorted(&PreferredLocations::new([""]), &uris));
+    }
+
+    #[test]
+    fn locations_matching_a_pattern_come_first() {
+        let uris = [
+            "https://cdn/file",
+            "https://mirror.internal/file",
+            "http://local/file",
+        ];
+
+        assert_eq!(
+            vec![
+                "https://mirror.internal/file",
+                "https://cdn/file",
+                "http://local/file"
+            ],
+            sorted(&PreferredLocations::new(["mirror.internal"]), &uris)
+        );
+    }
+
+    #[test]
+    fn locations_are_sorted_according_to_the_patterns_order() {
+        let uris = [
+            "https://cdn/file",
+            "http://local/file",
+            "https://mirror.internal/file",
+            "https://other.internal/file",
+        ];
+
+        assert_eq!(
+            vec![
+                "http://local/file",
+                "https://mirror.internal/file",
+                "https://other.internal/file",
+                "https://cdn/file",
+            ],
+            sorted(&PreferredLocations::new(["local", ".internal"]), &uris)
+        );
+    }
+}
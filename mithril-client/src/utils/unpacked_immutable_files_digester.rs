@@ -0,0 +1,513 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use anyhow::{Context, anyhow};
+use sha2::Sha256;
+use slog::Logger;
+
+use mithril_cardano_node_internal_database::IMMUTABLE_DIR;
+use mithril_cardano_node_internal_database::digesters::cache::MemoryImmutableFileDigestCacheProvider;
+use mithril_cardano_node_internal_database::digesters::{
+    CardanoImmutableDigester, ImmutableDigester,
+};
+use mithril_cardano_node_internal_database::entities::ImmutableFile;
+use mithril_common::StdResult;
+use mithril_common::entities::{
+    CardanoDbBeacon, HexEncodedDigest, ImmutableFileName, ImmutableFileNumber,
+};
+
+use crate::file_downloader::UnpackedFileVerifier;
+
+/// Computes the digests of the immutable files of a snapshot archive while it is unpacked, so
+/// the snapshot digest can be computed and verified at the end of the download without reading
+/// all the files again.
+///
+/// The unpacking is aborted as soon as an unexpected entry is found in the archive: a link, a
+/// non regular file or a duplicated file in the immutable directory, or an immutable file beyond
+/// the last immutable file number of the snapshot.
+/// If the certified digests of the immutable files are known, each immutable file is verified
+/// against its certified digest right after it is written, aborting the unpacking at the first
+/// mismatch, otherwise a digest mismatch is only detected once the whole archive is unpacked.
+pub struct UnpackedImmutableFilesDigester {
+    target_dir: PathBuf,
+    last_immutable_file_number: ImmutableFileNumber,
+    certified_digests: Option<BTreeMap<ImmutableFileName, HexEncodedDigest>>,
+    unpacked_files: Mutex<HashMap<ImmutableFileName, UnpackedImmutableFile>>,
+}
+
+/// Digest of an unpacked immutable file, with the identity of the file it was computed from
+struct UnpackedImmutableFile {
+    digest: HexEncodedDigest,
+    identity: FileIdentity,
+}
+
+/// Identity of a file on the disk, used to check that an unpacked immutable file was not
+/// replaced after its digest was computed
+#[derive(Debug, PartialEq)]
+struct FileIdentity {
+    len: u64,
+    modified: Option<SystemTime>,
+    #[cfg(unix)]
+    inode: u64,
+}
+
+impl FileIdentity {
+    fn of_regular_file(path: &Path) -> StdResult<Self> {
+        let metadata = path
+            .symlink_metadata()
+            .with_context(|| format!("Could not read the metadata of '{}'", path.display()))?;
+        if !metadata.is_file() {
+            return Err(anyhow!("'{}' is not a regular file", path.display()));
+        }
+
+        Ok(Self::from(&metadata))
+    }
+}
+
+impl From<&Metadata> for FileIdentity {
+    fn from(metadata: &Metadata) -> Self {
+        Self {
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+            #[cfg(unix)]
+            inode: std::os::unix::fs::MetadataExt::ino(metadata),
+        }
+    }
+}
+
+impl UnpackedImmutableFilesDigester {
+    /// Constructs a new `UnpackedImmutableFilesDigester` for an archive unpacked in `target_dir`.
+    pub fn new(target_dir: &Path, last_immutable_file_number: ImmutableFileNumber) -> Self {
+        Self {
+            target_dir: target_dir.to_path_buf(),
+            last_immutable_file_number,
+            certified_digests: None,
+            unpacked_files: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set the certified digests of the immutable files, against which each immutable file is
+    /// verified as soon as it is unpacked.
+    pub fn with_certified_digests(
+        mut self,
+        certified_digests: BTreeMap<ImmutableFileName, HexEncodedDigest>,
+    ) -> Self {
+        self.certified_digests = Some(certified_digests);
+        self
+    }
+
+    fn immutable_dir(&self) -> PathBuf {
+        self.target_dir.join(IMMUTABLE_DIR)
+    }
+
+    /// Compute the digest of the unpacked immutable files, reusing the digests computed while
+    /// the files were unpacked, and verify that it matches the expected snapshot digest.
+    ///
+    /// A digest computed while unpacking is only reused if its file was not replaced since.
+    /// The computed digest is returned so it can be checked against a certificate.
+    pub async fn verify_digest(
+        &self,
+        network: &str,
+        beacon: &CardanoDbBeacon,
+        expected_digest: &str,
+        logger: &Logger,
+    ) -> StdResult<HexEncodedDigest> {
+        let unpacked_digests = self.unpacked_digests()?;
+        let digester = CardanoImmutableDigester::new(
+            network.to_string(),
+            Some(Arc::new(MemoryImmutableFileDigestCacheProvider::from(
+                unpacked_digests,
+            ))),
+            logger.clone(),
+        );
+        let computed_digest = digester
+            .compute_digest(&self.target_dir, beacon)
+            .await
+            .with_context(|| {
+                format!(
+                    "Could not compute the digest of the unpacked immutable files in '{}'",
+                    self.target_dir.display()
+                )
+            })?;
+
+        if computed_digest != expected_digest {
+            return Err(anyhow!(
+                "Digest of the unpacked immutable files does not match the snapshot digest, expected: '{expected_digest}', actual: '{computed_digest}'"
+            ));
+        }
+
+        Ok(computed_digest)
+    }
+
+    fn unpacked_digests(&self) -> StdResult<HashMap<ImmutableFileName, HexEncodedDigest>> {
+        let immutable_dir = self.immutable_dir();
+        self.unpacked_files
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(filename, unpacked_file)| {
+                let path = immutable_dir.join(filename);
+                let identity = FileIdentity::of_regular_file(&path)?;
+                if identity != unpacked_file.identity {
+                    return Err(anyhow!(
+                        "Immutable file '{}' was replaced after its digest was computed",
+                        path.display()
+                    ));
+                }
+
+                Ok((filename.clone(), unpacked_file.digest.clone()))
+            })
+            .collect()
+    }
+
+    fn verify_unpacked_non_regular_entry(
+        &self,
+        unpacked_entry_path: &Path,
+        is_in_immutable_dir: bool,
+    ) -> StdResult<()> {
+        let is_directory = unpacked_entry_path
+            .symlink_metadata()
+            .is_ok_and(|metadata| metadata.is_dir());
+        if !is_directory {
+            return Err(anyhow!(
+                "Unexpected link or special file in the archive: '{}'",
+                unpacked_entry_path.display()
+            ));
+        }
+        if is_in_immutable_dir {
+            return Err(anyhow!(
+                "Unexpected directory in the immutable directory: '{}'",
+                unpacked_entry_path.display()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl UnpackedFileVerifier for UnpackedImmutableFilesDigester {
+    fn verify_unpacked_file(
+        &self,
+        unpacked_file_path: &Path,
+        is_regular_file: bool,
+    ) -> StdResult<()> {
+        let is_in_immutable_dir =
+            unpacked_file_path.parent() == Some(self.immutable_dir().as_path());
+        if !is_regular_file {
+            return self.verify_unpacked_non_regular_entry(unpacked_file_path, is_in_immutable_dir);
+        }
+        if !is_in_immutable_dir {
+            return Ok(());
+        }
+
+        let immutable_file =
+            ImmutableFile::new(unpacked_file_path.to_path_buf()).with_context(|| {
+                format!(
+                    "Unexpected file in the immutable directory: '{}'",
+                    unpacked_file_path.display()
+                )
+            })?;
+        if immutable_file.number > self.last_immutable_file_number {
+            return Err(anyhow!(
+                "Unexpected immutable file '{}' beyond the last immutable file number '{}' of the snapshot",
+                immutable_file.filename,
+                self.last_immutable_file_number
+            ));
+        }
+
+        let mut unpacked_files = self.unpacked_files.lock().unwrap();
+        if unpacked_files.contains_key(&immutable_file.filename) {
+            return Err(anyhow!(
+                "Duplicated immutable file '{}' in the archive",
+                immutable_file.filename
+            ));
+        }
+
+        let identity = FileIdentity::of_regular_file(unpacked_file_path)?;
+        let digest = hex::encode(immutable_file.compute_raw_hash::<Sha256>()?);
+        if let Some(certified_digests) = &self.certified_digests {
+            match certified_digests.get(&immutable_file.filename) {
+                Some(certified_digest) if certified_digest == &digest => {}
+                Some(certified_digest) => {
+                    return Err(anyhow!(
+                        "Digest of the immutable file '{}' does not match its certified digest, expected: '{certified_digest}', actual: '{digest}'",
+                        immutable_file.filename
+                    ));
+                }
+                None => {
+                    return Err(anyhow!(
+                        "No certified digest found for the immutable file '{}'",
+                        immutable_file.filename
+                    ));
+                }
+            }
+        }
+        unpacked_files.insert(
+            immutable_file.filename,
+            UnpackedImmutableFile { digest, identity },
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_cardano_node_internal_database::test::DummyCardanoDbBuilder;
+    use mithril_common::temp_dir_create;
+
+    use crate::test_utils::TestLogger;
+
+    use super::*;
+
+    async fn compute_digest(db_dir: &Path, beacon: &CardanoDbBeacon) -> String {
+        CardanoImmutableDigester::new("devnet".to_string(), None, TestLogger::stdout())
+            .compute_digest(db_dir, beacon)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn ignore_files_outside_of_the_immutable_directory() {
+        let digester = UnpackedImmutableFilesDigester::new(Path::new("/db"), 1);
+
+        digester
+            .verify_unpacked_file(Path::new("/db/ledger/123"), true)
+            .unwrap();
+        digester
+            .verify_unpacked_file(Path::new("/db/protocolMagicId"), true)
+            .unwrap();
+
+        assert!(digester.unpacked_files.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn fail_if_a_file_in_the_immutable_directory_is_not_an_immutable_file() {
+        let digester = UnpackedImmutableFilesDigester::new(Path::new("/db"), 1);
+
+        digester
+            .verify_unpacked_file(Path::new("/db/immutable/not_an_immutable.txt"), true)
+            .expect_err("Should fail with a non immutable file in the immutable directory");
+    }
+
+    #[test]
+    fn fail_if_an_immutable_file_is_beyond_the_last_immutable_file_number() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_fail_if_an_immutable_file_is_beyond_the_last_immutable_file_number",
+        )
+        .with_immutables(&[1, 2])
+        .append_immutable_trio()
+        .build();
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 1);
+
+        digester
+            .verify_unpacked_file(&cardano_db.get_immutable_dir().join("00002.chunk"), true)
+            .expect_err("Should fail with an immutable file beyond the snapshot beacon");
+    }
+
+    fn compute_certified_digests(
+        immutable_files: &[ImmutableFile],
+    ) -> BTreeMap<ImmutableFileName, HexEncodedDigest> {
+        immutable_files
+            .iter()
+            .map(|immutable_file| {
+                (
+                    immutable_file.filename.clone(),
+                    hex::encode(immutable_file.compute_raw_hash::<Sha256>().unwrap()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn accept_immutable_files_matching_their_certified_digests() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_accept_immutable_files_matching_their_certified_digests",
+        )
+        .with_immutables(&[1, 2])
+        .build();
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 2)
+            .with_certified_digests(compute_certified_digests(cardano_db.get_immutable_files()));
+
+        for immutable_file in cardano_db.get_immutable_files() {
+            digester.verify_unpacked_file(&immutable_file.path, true).unwrap();
+        }
+    }
+
+    #[test]
+    fn fail_as_soon_as_an_immutable_file_does_not_match_its_certified_digest() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_fail_as_soon_as_an_immutable_file_does_not_match_its_certified_digest",
+        )
+        .with_immutables(&[1, 2])
+        .build();
+        let mut certified_digests = compute_certified_digests(cardano_db.get_immutable_files());
+        certified_digests.insert("00001.chunk".to_string(), "another-digest".to_string());
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 2)
+            .with_certified_digests(certified_digests);
+
+        digester
+            .verify_unpacked_file(&cardano_db.get_immutable_dir().join("00001.chunk"), true)
+            .expect_err("Should fail with an immutable file not matching its certified digest");
+    }
+
+    #[test]
+    fn fail_if_an_immutable_file_has_no_certified_digest() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_fail_if_an_immutable_file_has_no_certified_digest",
+        )
+        .with_immutables(&[1, 2])
+        .build();
+        let mut certified_digests = compute_certified_digests(cardano_db.get_immutable_files());
+        certified_digests.remove("00002.chunk");
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 2)
+            .with_certified_digests(certified_digests);
+
+        digester
+            .verify_unpacked_file(&cardano_db.get_immutable_dir().join("00002.chunk"), true)
+            .expect_err("Should fail with an immutable file without certified digest");
+    }
+
+    #[test]
+    fn fail_if_an_immutable_file_is_duplicated() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_fail_if_an_immutable_file_is_duplicated",
+        )
+        .with_immutables(&[1])
+        .build();
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 1);
+        let chunk_path = cardano_db.get_immutable_dir().join("00001.chunk");
+        digester.verify_unpacked_file(&chunk_path, true).unwrap();
+
+        digester
+            .verify_unpacked_file(&chunk_path, true)
+            .expect_err("Should fail with a duplicated immutable file");
+    }
+
+    #[test]
+    fn accept_directories_outside_of_the_immutable_directory() {
+        let target_dir = temp_dir_create!();
+        std::fs::create_dir_all(target_dir.join(IMMUTABLE_DIR)).unwrap();
+        std::fs::create_dir_all(target_dir.join("ledger")).unwrap();
+        let digester = UnpackedImmutableFilesDigester::new(&target_dir, 1);
+
+        digester
+            .verify_unpacked_file(&target_dir.join(IMMUTABLE_DIR), false)
+            .unwrap();
+        digester
+            .verify_unpacked_file(&target_dir.join("ledger"), false)
+            .unwrap();
+    }
+
+    #[test]
+    fn fail_if_a_directory_is_in_the_immutable_directory() {
+        let target_dir = temp_dir_create!();
+        let directory_path = target_dir.join(IMMUTABLE_DIR).join("00001.chunk");
+        std::fs::create_dir_all(&directory_path).unwrap();
+        let digester = UnpackedImmutableFilesDigester::new(&target_dir, 1);
+
+        digester
+            .verify_unpacked_file(&directory_path, false)
+            .expect_err("Should fail with a directory in the immutable directory");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn fail_if_an_entry_is_a_link() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_fail_if_an_entry_is_a_link",
+        )
+        .with_immutables(&[1, 2])
+        .build();
+        let immutable_link_path = cardano_db.get_immutable_dir().join("00003.chunk");
+        std::os::unix::fs::symlink(
+            cardano_db.get_immutable_dir().join("00001.chunk"),
+            &immutable_link_path,
+        )
+        .unwrap();
+        let ledger_link_path = cardano_db.get_dir().join("ledger-link");
+        std::os::unix::fs::symlink(cardano_db.get_immutable_dir(), &ledger_link_path).unwrap();
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 3);
+
+        digester
+            .verify_unpacked_file(&immutable_link_path, false)
+            .expect_err("Should fail with a link in the immutable directory");
+        digester
+            .verify_unpacked_file(&ledger_link_path, false)
+            .expect_err("Should fail with a link outside of the immutable directory");
+    }
+
+    #[tokio::test]
+    async fn verify_digest_succeeds_with_the_digests_of_all_unpacked_files() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_verify_digest_succeeds_with_the_digests_of_all_unpacked_files",
+        )
+        .with_immutables(&[1, 2, 3])
+        .append_immutable_trio()
+        .build();
+        let beacon = CardanoDbBeacon::new(1, 3);
+        let expected_digest = compute_digest(cardano_db.get_dir(), &beacon).await;
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 3);
+        for immutable_file in cardano_db.get_immutable_files() {
+            digester.verify_unpacked_file(&immutable_file.path, true).unwrap();
+        }
+
+        let computed_digest = digester
+            .verify_digest("devnet", &beacon, &expected_digest, &TestLogger::stdout())
+            .await
+            .unwrap();
+
+        assert_eq!(expected_digest, computed_digest);
+    }
+
+    #[tokio::test]
+    async fn verify_digest_fails_if_the_digest_does_not_match() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_verify_digest_fails_if_the_digest_does_not_match",
+        )
+        .with_immutables(&[1, 2])
+        .append_immutable_trio()
+        .build();
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 2);
+        for immutable_file in cardano_db.get_immutable_files() {
+            digester.verify_unpacked_file(&immutable_file.path, true).unwrap();
+        }
+
+        digester
+            .verify_digest(
+                "devnet",
+                &CardanoDbBeacon::new(1, 2),
+                "another-digest",
+                &TestLogger::stdout(),
+            )
+            .await
+            .expect_err("Should fail if the digest does not match the snapshot digest");
+    }
+
+    #[tokio::test]
+    async fn verify_digest_fails_if_an_unpacked_immutable_file_was_replaced() {
+        let cardano_db = DummyCardanoDbBuilder::new(
+            "unpacked_immutable_files_digester_verify_digest_fails_if_an_unpacked_immutable_file_was_replaced",
+        )
+        .with_immutables(&[1, 2])
+        .append_immutable_trio()
+        .build();
+        let beacon = CardanoDbBeacon::new(1, 2);
+        let expected_digest = compute_digest(cardano_db.get_dir(), &beacon).await;
+        let digester = UnpackedImmutableFilesDigester::new(cardano_db.get_dir(), 2);
+        for immutable_file in cardano_db.get_immutable_files() {
+            digester.verify_unpacked_file(&immutable_file.path, true).unwrap();
+        }
+        let chunk_path = cardano_db.get_immutable_dir().join("00001.chunk");
+        std::fs::remove_file(&chunk_path).unwrap();
+        std::fs::write(&chunk_path, "tampered content").unwrap();
+
+        digester
+            .verify_digest("devnet", &beacon, &expected_digest, &TestLogger::stdout())
+            .await
+            .expect_err("Should fail if an unpacked immutable file was replaced");
+    }
+}
@@ -16,6 +16,7 @@ impl FakeAggregator {
             certified_transactions: vec![CardanoTransactionsSetProof {
                 transactions_hashes: tx_hashes.iter().map(|h| h.to_string()).collect(),
                 proof: ProtocolMkProof::new(proof.to_owned().into()).to_json_hex().unwrap(),
+                transactions_block_positions: vec![],
             }],
             non_certified_transactions: vec![],
             latest_block_number: BlockNumber(9999),
@@ -13,7 +13,10 @@ pub fn routes(proof: CardanoTransactionsProofs) -> Router {
     let state = ProofRoutesState { proof };
 
     Router::new()
-        .route("/proof/cardano-transaction", get(proof_cardano_transaction))
+        .route(
+            "/proof/cardano-transaction",
+            get(proof_cardano_transaction).post(proof_cardano_transaction),
+        )
         .with_state(state)
 }
 
@@ -0,0 +1,85 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+use mithril_common::crypto_helper::MKTreeNode;
+use mithril_common::entities::{
+    BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionsLeafFormat, SlotNumber,
+};
+
+const TOTAL_TRANSACTIONS_PER_BLOCK: u64 = 15;
+const BLOCK_RANGES_BATCH_SIZES: &[u64] = &[10, 100, 1_000];
+
+fn generate_block_ranges_transactions(
+    total_block_ranges: u64,
+) -> Vec<(BlockRange, Vec<CardanoTransaction>)> {
+    (0..total_block_ranges)
+        .map(|block_range_index| {
+            let block_range = BlockRange::from_block_number(BlockRange::LENGTH * block_range_index);
+            let transactions = (*block_range.start..*block_range.end)
+                .flat_map(|block_number| {
+                    (0..TOTAL_TRANSACTIONS_PER_BLOCK).map(move |tx_index| {
+                        CardanoTransaction::new(
+                            format!("tx_hash-{block_number}-{tx_index}"),
+                            BlockNumber(block_number),
+                            SlotNumber(block_number * 10),
+                            format!("block_hash-{block_number}"),
+                        )
+                    })
+                })
+                .collect();
+            (block_range, transactions)
+        })
+        .collect()
+}
+
+fn compute_block_range_roots_sequentially(
+    leaf_format: CardanoTransactionsLeafFormat,
+    block_ranges_transactions: Vec<(BlockRange, Vec<CardanoTransaction>)>,
+) -> Vec<(BlockRange, MKTreeNode)> {
+    block_ranges_transactions
+        .into_iter()
+        .map(|(block_range, transactions)| {
+            let merkle_root = leaf_format.compute_block_range_root(&transactions).unwrap();
+            (block_range, merkle_root)
+        })
+        .collect()
+}
+
+fn compute_block_range_roots(c: &mut Criterion) {
+    let leaf_format = CardanoTransactionsLeafFormat::default();
+    let mut group = c.benchmark_group("compute_block_range_roots");
+    for total_block_ranges in BLOCK_RANGES_BATCH_SIZES {
+        let block_ranges_transactions = generate_block_ranges_transactions(*total_block_ranges);
+        group.throughput(Throughput::Elements(*total_block_ranges));
+        group.bench_with_input(
+            BenchmarkId::new("sequential", total_block_ranges),
+            &block_ranges_transactions,
+            |b, block_ranges_transactions| {
+                b.iter(|| {
+                    compute_block_range_roots_sequentially(
+                        leaf_format,
+                        block_ranges_transactions.clone(),
+                    )
+                });
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parallel", total_block_ranges),
+            &block_ranges_transactions,
+            |b, block_ranges_transactions| {
+                b.iter(|| {
+                    leaf_format
+                        .compute_block_range_roots(block_ranges_transactions.clone())
+                        .unwrap()
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = compute_block_range_roots
+);
+criterion_main!(benches);
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto_helper::MKTreeNode,
+    entities::{BlockHash, BlockNumber, SlotNumber},
+};
+
+/// Cardano block representation, certified by the
+/// [CardanoBlocks][crate::entities::SignedEntityType::CardanoBlocks] signed entity type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoBlock {
+    /// Hash of the block
+    pub block_hash: BlockHash,
+
+    /// Number of the block
+    pub block_number: BlockNumber,
+
+    /// Slot number of the block
+    pub slot_number: SlotNumber,
+}
+
+impl CardanoBlock {
+    /// CardanoBlock factory
+    pub fn new<T: Into<BlockHash>>(
+        block_hash: T,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+    ) -> Self {
+        Self {
+            block_hash: block_hash.into(),
+            block_number,
+            slot_number,
+        }
+    }
+}
+
+impl From<&CardanoBlock> for MKTreeNode {
+    fn from(other: &CardanoBlock) -> Self {
+        MKTreeNode::new(
+            format!(
+                "{}:{}:{}",
+                other.block_hash, other.block_number, other.slot_number
+            )
+            .into_bytes(),
+        )
+    }
+}
+
+impl From<CardanoBlock> for MKTreeNode {
+    fn from(other: CardanoBlock) -> Self {
+        (&other).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_cardano_block_to_merkle_tree_node() {
+        let block = CardanoBlock::new("block-hash-123", BlockNumber(10), SlotNumber(4));
+
+        assert_eq!(
+            MKTreeNode::new("block-hash-123:10:4".as_bytes().to_vec()),
+            MKTreeNode::from(&block)
+        );
+    }
+
+    #[test]
+    fn merkle_tree_node_of_a_block_commits_to_its_number_and_slot_number() {
+        let block = CardanoBlock::new("block-hash-123", BlockNumber(10), SlotNumber(4));
+
+        assert_ne!(
+            MKTreeNode::from(&block),
+            MKTreeNode::from(CardanoBlock {
+                block_number: BlockNumber(11),
+                ..block.clone()
+            })
+        );
+        assert_ne!(
+            MKTreeNode::from(&block),
+            MKTreeNode::from(CardanoBlock {
+                slot_number: SlotNumber(5),
+                ..block.clone()
+            })
+        );
+    }
+}
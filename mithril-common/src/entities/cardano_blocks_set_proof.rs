@@ -0,0 +1,134 @@
+use crate::StdResult;
+use crate::crypto_helper::{MKMapProof, ProtocolMkProof};
+use crate::entities::CardanoBlock;
+
+use super::BlockRange;
+
+cfg_test_tools! {
+    use crate::crypto_helper::{MKMap, MKTree, MKTreeNode, MKMapNode, MKTreeStorer, MKTreeStoreInMemory};
+    use std::collections::HashMap;
+}
+
+/// A cryptographic proof of a set of Cardano blocks is included in the global Cardano blocks set
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardanoBlocksSetProof {
+    /// Certified blocks
+    pub(crate) blocks: Vec<CardanoBlock>,
+
+    /// Proof of the blocks
+    pub(crate) blocks_proof: ProtocolMkProof,
+}
+
+impl CardanoBlocksSetProof {
+    /// CardanoBlocksSetProof factory
+    pub fn new<T: Into<MKMapProof<BlockRange>>>(
+        blocks: Vec<CardanoBlock>,
+        blocks_proof: T,
+    ) -> Self {
+        Self {
+            blocks,
+            blocks_proof: ProtocolMkProof::new(blocks_proof.into()),
+        }
+    }
+
+    /// Return the hex encoded merkle root of this proof
+    pub fn merkle_root(&self) -> String {
+        self.blocks_proof.compute_root().to_hex()
+    }
+
+    /// Get the blocks certified by this proof
+    pub fn blocks(&self) -> &[CardanoBlock] {
+        &self.blocks
+    }
+
+    /// Verify that blocks set proof is valid
+    pub fn verify(&self) -> StdResult<()> {
+        self.blocks_proof.verify()?;
+        for block in &self.blocks {
+            self.blocks_proof.contains(&block.into())?;
+        }
+
+        Ok(())
+    }
+
+    cfg_test_tools! {
+        /// Helper to create a proof from a list of blocks
+        pub fn from_blocks<S: MKTreeStorer>(blocks: &[CardanoBlock]) -> StdResult<Self> {
+            let mut blocks_by_block_ranges: HashMap<BlockRange, Vec<MKTreeNode>> = HashMap::new();
+            for block in blocks {
+                blocks_by_block_ranges
+                    .entry(BlockRange::from_block_number(block.block_number))
+                    .or_default()
+                    .push(block.into());
+            }
+            let mk_map = MKMap::<_, _, MKTreeStoreInMemory>::new_from_iter(
+                blocks_by_block_ranges
+                    .into_iter()
+                    .map(|(block_range, leaves)| -> StdResult<(_, MKMapNode<_, S>)> {
+                        Ok((block_range, MKTree::<S>::new(&leaves)?.into()))
+                    })
+                    .collect::<StdResult<Vec<_>>>()?,
+            )?;
+            let mk_leaves: Vec<MKTreeNode> = blocks.iter().map(|b| b.into()).collect();
+            let mk_proof = mk_map.compute_proof(&mk_leaves)?;
+            Ok(Self::new(blocks.to_vec(), mk_proof))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{BlockNumber, SlotNumber};
+
+    use super::*;
+
+    fn blocks() -> Vec<CardanoBlock> {
+        [0, 1, 10, 20, 22]
+            .into_iter()
+            .map(|block_number| {
+                CardanoBlock::new(
+                    format!("block-hash-{block_number}"),
+                    BlockNumber(block_number),
+                    SlotNumber(block_number * 10),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_verify_where_all_blocks_are_contained_in_the_proof() {
+        let proof = CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks()).unwrap();
+
+        proof.verify().expect("The proof should be valid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_at_least_one_block_is_not_contained_in_the_proof() {
+        let proof = CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks()).unwrap();
+        let mut blocks_tampered = proof.blocks().to_vec();
+        blocks_tampered.push(CardanoBlock::new(
+            "block-hash-123",
+            BlockNumber(123),
+            SlotNumber(1230),
+        ));
+        let proof = CardanoBlocksSetProof {
+            blocks: blocks_tampered,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_a_block_number_is_tampered() {
+        let proof = CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks()).unwrap();
+        let mut blocks_tampered = proof.blocks().to_vec();
+        blocks_tampered[1].block_number = BlockNumber(2);
+        let proof = CardanoBlocksSetProof {
+            blocks: blocks_tampered,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+}
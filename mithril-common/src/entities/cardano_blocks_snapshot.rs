@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::BlockNumber;
+
+/// Snapshot of a set of Cardano blocks
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoBlocksSnapshot {
+    /// Hash of the Cardano blocks set
+    pub hash: String,
+
+    /// Merkle root of the Cardano blocks set
+    pub merkle_root: String,
+
+    /// Beacon of the Cardano blocks set
+    pub block_number: BlockNumber,
+}
+
+impl CardanoBlocksSnapshot {
+    /// Creates a new [CardanoBlocksSnapshot]
+    pub fn new(merkle_root: String, block_number: BlockNumber) -> Self {
+        let mut cardano_blocks_snapshot = Self {
+            merkle_root,
+            block_number,
+            hash: "".to_string(),
+        };
+        cardano_blocks_snapshot.hash = cardano_blocks_snapshot.compute_hash();
+        cardano_blocks_snapshot
+    }
+
+    /// Cardano blocks snapshot hash computation
+    fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(b"cardano-blocks");
+        hasher.update(self.merkle_root.clone().as_bytes());
+        hasher.update(self.block_number.to_be_bytes());
+
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::CardanoTransactionsSnapshot;
+
+    use super::*;
+
+    #[test]
+    fn test_cardano_blocks_snapshot_compute_hash() {
+        let hash_expected = "e6d0e847d74f37d60f08ad934af698cc4ba410aeaa882f0c6eeadb695cbd51c2";
+
+        assert_eq!(
+            hash_expected,
+            CardanoBlocksSnapshot::new("mk-root-123".to_string(), BlockNumber(50)).compute_hash()
+        );
+
+        assert_ne!(
+            hash_expected,
+            CardanoBlocksSnapshot::new("mk-root-456".to_string(), BlockNumber(50)).compute_hash()
+        );
+
+        assert_ne!(
+            hash_expected,
+            CardanoBlocksSnapshot::new("mk-root-123".to_string(), BlockNumber(47)).compute_hash()
+        );
+    }
+
+    #[test]
+    fn hash_differs_from_the_cardano_transactions_snapshot_with_the_same_merkle_root() {
+        assert_ne!(
+            CardanoTransactionsSnapshot::new("mk-root-123".to_string(), BlockNumber(50)).hash,
+            CardanoBlocksSnapshot::new("mk-root-123".to_string(), BlockNumber(50)).hash
+        );
+    }
+}
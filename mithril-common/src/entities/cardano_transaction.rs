@@ -1,11 +1,109 @@
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
 use crate::{
-    crypto_helper::MKTreeNode,
-    entities::{BlockHash, BlockNumber, SlotNumber},
+    StdResult,
+    crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory},
+    entities::{BlockHash, BlockNumber, BlockRange, SlotNumber},
 };
 
 /// TransactionHash is the unique identifier of a cardano transaction.
 pub type TransactionHash = String;
 
+/// Format of the leaves of the Merkle trees built from the Cardano transactions
+///
+/// All the nodes of a network must use the same format, as it changes the certified Merkle root.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardanoTransactionsLeafFormat {
+    /// The leaves commit to the transaction hash only
+    #[default]
+    TransactionHash,
+    /// The leaves commit to the transaction hash and to the number and hash of its block
+    BlockInclusion,
+}
+
+impl CardanoTransactionsLeafFormat {
+    /// Compute the Merkle tree leaf of a transaction
+    pub fn compute_leaf(&self, transaction: &CardanoTransaction) -> MKTreeNode {
+        match self {
+            Self::TransactionHash => transaction.into(),
+            Self::BlockInclusion => CardanoTransactionBlockPosition::from(transaction).into(),
+        }
+    }
+
+    /// Compute the Merkle root of the transactions of a block range
+    pub fn compute_block_range_root(
+        &self,
+        transactions: &[CardanoTransaction],
+    ) -> StdResult<MKTreeNode> {
+        let leaves: Vec<MKTreeNode> = transactions
+            .iter()
+            .map(|transaction| self.compute_leaf(transaction))
+            .collect();
+
+        MKTree::<MKTreeStoreInMemory>::new(&leaves)?.compute_root()
+    }
+
+    /// Compute in parallel the Merkle roots of independent block ranges.
+    ///
+    /// The returned roots are in the same order as the given block ranges.
+    pub fn compute_block_range_roots(
+        &self,
+        block_ranges_transactions: Vec<(BlockRange, Vec<CardanoTransaction>)>,
+    ) -> StdResult<Vec<(BlockRange, MKTreeNode)>> {
+        block_ranges_transactions
+            .into_par_iter()
+            .map(|(block_range, transactions)| {
+                self.compute_block_range_root(&transactions)
+                    .map(|merkle_root| (block_range, merkle_root))
+            })
+            .collect()
+    }
+}
+
+/// Position of a Cardano transaction in the chain, committed by the Merkle tree leaves when
+/// they use the [block inclusion][CardanoTransactionsLeafFormat::BlockInclusion] format
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoTransactionBlockPosition {
+    /// Unique hash of the transaction
+    pub transaction_hash: TransactionHash,
+
+    /// Number of the block that includes the transaction
+    pub block_number: BlockNumber,
+
+    /// Hash of the block that includes the transaction
+    pub block_hash: BlockHash,
+}
+
+impl From<&CardanoTransaction> for CardanoTransactionBlockPosition {
+    fn from(other: &CardanoTransaction) -> Self {
+        Self {
+            transaction_hash: other.transaction_hash.clone(),
+            block_number: other.block_number,
+            block_hash: other.block_hash.clone(),
+        }
+    }
+}
+
+impl From<&CardanoTransactionBlockPosition> for MKTreeNode {
+    fn from(other: &CardanoTransactionBlockPosition) -> Self {
+        MKTreeNode::new(
+            format!(
+                "{}:{}:{}",
+                other.transaction_hash, other.block_number, other.block_hash
+            )
+            .into_bytes(),
+        )
+    }
+}
+
+impl From<CardanoTransactionBlockPosition> for MKTreeNode {
+    fn from(other: CardanoTransactionBlockPosition) -> Self {
+        (&other).into()
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 /// Cardano transaction representation
 pub struct CardanoTransaction {
@@ -67,4 +165,83 @@ mod tests {
         assert_eq!(expected_mk_tree_node, computed_mktree_node);
         assert_ne!(non_expected_mk_tree_node, computed_mktree_node);
     }
+
+    #[test]
+    fn transaction_hash_leaf_format_commits_to_the_transaction_hash_only() {
+        let transaction =
+            CardanoTransaction::new("tx-hash-123", BlockNumber(10), SlotNumber(4), "block_hash");
+
+        assert_eq!(
+            MKTreeNode::from(&transaction),
+            CardanoTransactionsLeafFormat::TransactionHash.compute_leaf(&transaction)
+        );
+    }
+
+    #[test]
+    fn block_inclusion_leaf_format_commits_to_the_block_of_the_transaction() {
+        let transaction =
+            CardanoTransaction::new("tx-hash-123", BlockNumber(10), SlotNumber(4), "block_hash");
+        let leaf = CardanoTransactionsLeafFormat::BlockInclusion.compute_leaf(&transaction);
+
+        assert_eq!(
+            MKTreeNode::new("tx-hash-123:10:block_hash".as_bytes().to_vec()),
+            leaf
+        );
+        assert_ne!(MKTreeNode::from(&transaction), leaf);
+        assert_ne!(
+            CardanoTransactionsLeafFormat::BlockInclusion.compute_leaf(&CardanoTransaction {
+                block_hash: "another_block_hash".to_string(),
+                ..transaction.clone()
+            }),
+            leaf
+        );
+    }
+
+    #[test]
+    fn compute_block_range_roots_in_parallel_keeps_the_block_ranges_order() {
+        let leaf_format = CardanoTransactionsLeafFormat::BlockInclusion;
+        let block_ranges_transactions: Vec<(BlockRange, Vec<CardanoTransaction>)> = (0..50)
+            .map(|index| {
+                let block_number = BlockRange::LENGTH * index;
+                let transactions = (0..index + 1)
+                    .map(|tx_index| {
+                        CardanoTransaction::new(
+                            format!("tx-hash-{block_number}-{tx_index}"),
+                            block_number,
+                            SlotNumber(*block_number * 10),
+                            format!("block_hash-{block_number}"),
+                        )
+                    })
+                    .collect();
+                (BlockRange::from_block_number(block_number), transactions)
+            })
+            .collect();
+        let expected_roots: Vec<(BlockRange, MKTreeNode)> = block_ranges_transactions
+            .iter()
+            .map(|(block_range, transactions)| {
+                (
+                    block_range.clone(),
+                    leaf_format.compute_block_range_root(transactions).unwrap(),
+                )
+            })
+            .collect();
+
+        let roots = leaf_format
+            .compute_block_range_roots(block_ranges_transactions)
+            .unwrap();
+
+        assert_eq!(expected_roots, roots);
+    }
+
+    #[test]
+    fn leaf_format_serialization() {
+        assert_eq!(
+            "\"block_inclusion\"",
+            serde_json::to_string(&CardanoTransactionsLeafFormat::BlockInclusion).unwrap()
+        );
+        assert_eq!(
+            CardanoTransactionsLeafFormat::TransactionHash,
+            serde_json::from_str("\"transaction_hash\"").unwrap()
+        );
+    }
 }
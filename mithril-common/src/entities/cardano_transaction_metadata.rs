@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto_helper::MKTreeNode,
+    entities::{BlockHash, BlockNumber, SlotNumber, TransactionHash},
+};
+
+/// Label of a transaction metadata entry (the key of the top level metadata map)
+pub type MetadataLabel = u64;
+
+/// Hex encoded Blake2b-256 hash of the CBOR encoding of a transaction metadatum
+pub type MetadataHash = String;
+
+/// Metadata label carried by a Cardano transaction, certified by the
+/// [CardanoTransactionsMetadata][crate::entities::SignedEntityType::CardanoTransactionsMetadata]
+/// signed entity type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoTransactionMetadata {
+    /// Hash of the transaction carrying the metadata
+    pub transaction_hash: TransactionHash,
+
+    /// Label of the metadata entry
+    pub label: MetadataLabel,
+
+    /// Hash of the metadatum associated to the label
+    pub metadata_hash: MetadataHash,
+
+    /// Number of the block containing the transaction
+    pub block_number: BlockNumber,
+
+    /// Slot number of the block containing the transaction
+    pub slot_number: SlotNumber,
+
+    /// Hash of the block containing the transaction
+    pub block_hash: BlockHash,
+}
+
+impl CardanoTransactionMetadata {
+    /// CardanoTransactionMetadata factory
+    pub fn new<T: Into<TransactionHash>, U: Into<MetadataHash>, V: Into<BlockHash>>(
+        transaction_hash: T,
+        label: MetadataLabel,
+        metadata_hash: U,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+        block_hash: V,
+    ) -> Self {
+        Self {
+            transaction_hash: transaction_hash.into(),
+            label,
+            metadata_hash: metadata_hash.into(),
+            block_number,
+            slot_number,
+            block_hash: block_hash.into(),
+        }
+    }
+}
+
+impl From<&CardanoTransactionMetadata> for MKTreeNode {
+    fn from(other: &CardanoTransactionMetadata) -> Self {
+        MKTreeNode::new(
+            format!(
+                "{}:{}:{}:{}:{}:{}",
+                other.transaction_hash,
+                other.label,
+                other.metadata_hash,
+                other.block_hash,
+                other.block_number,
+                other.slot_number
+            )
+            .into_bytes(),
+        )
+    }
+}
+
+impl From<CardanoTransactionMetadata> for MKTreeNode {
+    fn from(other: CardanoTransactionMetadata) -> Self {
+        (&other).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_cardano_transaction_metadata_to_merkle_tree_node() {
+        let metadata = CardanoTransactionMetadata::new(
+            "tx-hash-123",
+            674,
+            "metadata-hash-456",
+            BlockNumber(10),
+            SlotNumber(4),
+            "block-hash-10",
+        );
+
+        assert_eq!(
+            MKTreeNode::new(
+                "tx-hash-123:674:metadata-hash-456:block-hash-10:10:4"
+                    .as_bytes()
+                    .to_vec()
+            ),
+            MKTreeNode::from(&metadata)
+        );
+    }
+
+    #[test]
+    fn merkle_tree_node_of_a_metadata_commits_to_its_block() {
+        let metadata = CardanoTransactionMetadata::new(
+            "tx-hash-123",
+            674,
+            "metadata-hash-456",
+            BlockNumber(10),
+            SlotNumber(4),
+            "block-hash-10",
+        );
+
+        assert_ne!(
+            MKTreeNode::from(&metadata),
+            MKTreeNode::from(CardanoTransactionMetadata {
+                block_number: BlockNumber(11),
+                ..metadata.clone()
+            })
+        );
+        assert_ne!(
+            MKTreeNode::from(&metadata),
+            MKTreeNode::from(CardanoTransactionMetadata {
+                slot_number: SlotNumber(5),
+                ..metadata.clone()
+            })
+        );
+        assert_ne!(
+            MKTreeNode::from(&metadata),
+            MKTreeNode::from(CardanoTransactionMetadata {
+                block_hash: "block-hash-11".to_string(),
+                ..metadata.clone()
+            })
+        );
+    }
+
+    #[test]
+    fn merkle_tree_node_of_a_metadata_commits_to_its_label_and_hash() {
+        let metadata = CardanoTransactionMetadata::new(
+            "tx-hash-123",
+            674,
+            "metadata-hash-456",
+            BlockNumber(10),
+            SlotNumber(4),
+            "block-hash-10",
+        );
+
+        assert_ne!(
+            MKTreeNode::from(&metadata),
+            MKTreeNode::from(CardanoTransactionMetadata {
+                label: 675,
+                ..metadata.clone()
+            })
+        );
+        assert_ne!(
+            MKTreeNode::from(&metadata),
+            MKTreeNode::from(CardanoTransactionMetadata {
+                metadata_hash: "metadata-hash-789".to_string(),
+                ..metadata.clone()
+            })
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::StdResult;
+use crate::crypto_helper::{MKMapProof, ProtocolMkProof};
+use crate::entities::CardanoTransactionMetadata;
+
+use super::BlockRange;
+
+cfg_test_tools! {
+    use crate::crypto_helper::{MKMap, MKTree, MKTreeNode, MKMapNode, MKTreeStorer, MKTreeStoreInMemory};
+    use std::collections::HashMap;
+}
+
+/// A cryptographic proof of a set of Cardano transactions metadata is included in the global
+/// Cardano transactions metadata set
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardanoTransactionsMetadataSetProof {
+    /// Certified transactions metadata
+    pub(crate) metadata: Vec<CardanoTransactionMetadata>,
+
+    /// Proof of the transactions metadata
+    pub(crate) metadata_proof: ProtocolMkProof,
+}
+
+impl CardanoTransactionsMetadataSetProof {
+    /// CardanoTransactionsMetadataSetProof factory
+    pub fn new<T: Into<MKMapProof<BlockRange>>>(
+        metadata: Vec<CardanoTransactionMetadata>,
+        metadata_proof: T,
+    ) -> Self {
+        Self {
+            metadata,
+            metadata_proof: ProtocolMkProof::new(metadata_proof.into()),
+        }
+    }
+
+    /// Return the hex encoded merkle root of this proof
+    pub fn merkle_root(&self) -> String {
+        self.metadata_proof.compute_root().to_hex()
+    }
+
+    /// Get the transactions metadata certified by this proof
+    pub fn metadata(&self) -> &[CardanoTransactionMetadata] {
+        &self.metadata
+    }
+
+    /// Verify that transactions metadata set proof is valid
+    pub fn verify(&self) -> StdResult<()> {
+        self.metadata_proof.verify()?;
+        for metadata in &self.metadata {
+            self.metadata_proof.contains(&metadata.into())?;
+        }
+
+        Ok(())
+    }
+
+    cfg_test_tools! {
+        /// Helper to create a proof from a list of transactions metadata
+        pub fn from_metadata<S: MKTreeStorer>(metadata: &[CardanoTransactionMetadata]) -> StdResult<Self> {
+            let mut metadata_by_block_ranges: HashMap<BlockRange, Vec<MKTreeNode>> = HashMap::new();
+            for entry in metadata {
+                metadata_by_block_ranges
+                    .entry(BlockRange::from_block_number(entry.block_number))
+                    .or_default()
+                    .push(entry.into());
+            }
+            let mk_map = MKMap::<_, _, MKTreeStoreInMemory>::new_from_iter(
+                metadata_by_block_ranges
+                    .into_iter()
+                    .map(|(block_range, leaves)| -> StdResult<(_, MKMapNode<_, S>)> {
+                        Ok((block_range, MKTree::<S>::new(&leaves)?.into()))
+                    })
+                    .collect::<StdResult<Vec<_>>>()?,
+            )?;
+            let mk_leaves: Vec<MKTreeNode> = metadata.iter().map(|m| m.into()).collect();
+            let mk_proof = mk_map.compute_proof(&mk_leaves)?;
+            Ok(Self::new(metadata.to_vec(), mk_proof))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{BlockNumber, SlotNumber};
+
+    use super::*;
+
+    fn transactions_metadata() -> Vec<CardanoTransactionMetadata> {
+        [0, 1, 10, 20, 22]
+            .into_iter()
+            .map(|block_number| {
+                CardanoTransactionMetadata::new(
+                    format!("tx-hash-{block_number}"),
+                    674,
+                    format!("metadata-hash-{block_number}"),
+                    BlockNumber(block_number),
+                    SlotNumber(block_number * 10),
+                    format!("block-hash-{block_number}"),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_verify_where_all_metadata_are_contained_in_the_proof() {
+        let proof = CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+            &transactions_metadata(),
+        )
+        .unwrap();
+
+        proof.verify().expect("The proof should be valid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_at_least_one_metadata_is_not_contained_in_the_proof() {
+        let proof = CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+            &transactions_metadata(),
+        )
+        .unwrap();
+        let mut metadata_tampered = proof.metadata().to_vec();
+        metadata_tampered.push(CardanoTransactionMetadata::new(
+            "tx-hash-123",
+            674,
+            "metadata-hash-123",
+            BlockNumber(123),
+            SlotNumber(1230),
+            "block-hash-123",
+        ));
+        let proof = CardanoTransactionsMetadataSetProof {
+            metadata: metadata_tampered,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_a_metadata_hash_is_tampered() {
+        let proof = CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+            &transactions_metadata(),
+        )
+        .unwrap();
+        let mut metadata_tampered = proof.metadata().to_vec();
+        metadata_tampered[1].metadata_hash = "metadata-hash-tampered".to_string();
+        let proof = CardanoTransactionsMetadataSetProof {
+            metadata: metadata_tampered,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::BlockNumber;
+
+/// Snapshot of a set of Cardano transactions metadata
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoTransactionsMetadataSnapshot {
+    /// Hash of the Cardano transactions metadata set
+    pub hash: String,
+
+    /// Merkle root of the Cardano transactions metadata set
+    pub merkle_root: String,
+
+    /// Beacon of the Cardano transactions metadata set
+    pub block_number: BlockNumber,
+}
+
+impl CardanoTransactionsMetadataSnapshot {
+    /// Creates a new [CardanoTransactionsMetadataSnapshot]
+    pub fn new(merkle_root: String, block_number: BlockNumber) -> Self {
+        let mut cardano_transactions_metadata_snapshot = Self {
+            merkle_root,
+            block_number,
+            hash: "".to_string(),
+        };
+        cardano_transactions_metadata_snapshot.hash =
+            cardano_transactions_metadata_snapshot.compute_hash();
+        cardano_transactions_metadata_snapshot
+    }
+
+    /// Cardano transactions metadata snapshot hash computation
+    fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(b"cardano-transactions-metadata");
+        hasher.update(self.merkle_root.clone().as_bytes());
+        hasher.update(self.block_number.to_be_bytes());
+
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::CardanoTransactionsSnapshot;
+
+    use super::*;
+
+    #[test]
+    fn test_cardano_transactions_metadata_snapshot_compute_hash() {
+        let hash_expected = "bc2a525c8bc1f0af0a3c5e651f0d77fa92b2692d6181c40926b15111b9a09f96";
+
+        assert_eq!(
+            hash_expected,
+            CardanoTransactionsMetadataSnapshot::new("mk-root-123".to_string(), BlockNumber(50))
+                .compute_hash()
+        );
+
+        assert_ne!(
+            hash_expected,
+            CardanoTransactionsMetadataSnapshot::new("mk-root-456".to_string(), BlockNumber(50))
+                .compute_hash()
+        );
+
+        assert_ne!(
+            hash_expected,
+            CardanoTransactionsMetadataSnapshot::new("mk-root-123".to_string(), BlockNumber(47))
+                .compute_hash()
+        );
+    }
+
+    #[test]
+    fn hash_differs_from_the_cardano_transactions_snapshot_with_the_same_merkle_root() {
+        assert_ne!(
+            CardanoTransactionsSnapshot::new("mk-root-123".to_string(), BlockNumber(50)).hash,
+            CardanoTransactionsMetadataSnapshot::new("mk-root-123".to_string(), BlockNumber(50))
+                .hash
+        );
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::HashSet;
+
+use anyhow::anyhow;
+
 use crate::StdResult;
 use crate::crypto_helper::{MKMapProof, ProtocolMkProof};
-use crate::entities::TransactionHash;
+use crate::entities::{CardanoTransactionBlockPosition, TransactionHash};
 
 use super::BlockRange;
 
@@ -18,6 +22,11 @@ pub struct CardanoTransactionsSetProof {
 
     /// Proof of the transactions
     pub(crate) transactions_proof: ProtocolMkProof,
+
+    /// Positions in the chain of the certified transactions, only available if the proof was
+    /// computed with [block inclusion][crate::entities::CardanoTransactionsLeafFormat::BlockInclusion]
+    /// leaves
+    pub(crate) transactions_block_positions: Vec<CardanoTransactionBlockPosition>,
 }
 
 impl CardanoTransactionsSetProof {
@@ -29,6 +38,23 @@ impl CardanoTransactionsSetProof {
         Self {
             transactions_hashes,
             transactions_proof: ProtocolMkProof::new(transactions_proof.into()),
+            transactions_block_positions: vec![],
+        }
+    }
+
+    /// CardanoTransactionsSetProof factory for a proof computed with
+    /// [block inclusion][crate::entities::CardanoTransactionsLeafFormat::BlockInclusion] leaves
+    pub fn new_with_block_positions<T: Into<MKMapProof<BlockRange>>>(
+        transactions_block_positions: Vec<CardanoTransactionBlockPosition>,
+        transactions_proof: T,
+    ) -> Self {
+        Self {
+            transactions_hashes: transactions_block_positions
+                .iter()
+                .map(|position| position.transaction_hash.clone())
+                .collect(),
+            transactions_proof: ProtocolMkProof::new(transactions_proof.into()),
+            transactions_block_positions,
         }
     }
 
@@ -42,11 +68,44 @@ impl CardanoTransactionsSetProof {
         &self.transactions_hashes
     }
 
+    /// Get the positions in the chain of the certified transactions, empty if the proof was not
+    /// computed with block inclusion leaves
+    pub fn transactions_block_positions(&self) -> &[CardanoTransactionBlockPosition] {
+        &self.transactions_block_positions
+    }
+
     /// Verify that transactions set proof is valid
     pub fn verify(&self) -> StdResult<()> {
         self.transactions_proof.verify()?;
-        for hash in &self.transactions_hashes {
-            self.transactions_proof.contains(&hash.to_owned().into())?;
+        if self.transactions_block_positions.is_empty() {
+            for hash in &self.transactions_hashes {
+                self.transactions_proof.contains(&hash.to_owned().into())?;
+            }
+        } else {
+            if self.transactions_block_positions.len() != self.transactions_hashes.len() {
+                return Err(anyhow!(
+                    "Number of block positions ({}) does not match the number of transactions ({})",
+                    self.transactions_block_positions.len(),
+                    self.transactions_hashes.len()
+                ));
+            }
+            let mut verified_hashes = HashSet::new();
+            for (hash, position) in self
+                .transactions_hashes
+                .iter()
+                .zip(&self.transactions_block_positions)
+            {
+                if &position.transaction_hash != hash {
+                    return Err(anyhow!(
+                        "Block position of transaction '{}' does not match transaction '{hash}'",
+                        position.transaction_hash
+                    ));
+                }
+                if !verified_hashes.insert(hash) {
+                    return Err(anyhow!("Duplicate block position of transaction '{hash}'"));
+                }
+                self.transactions_proof.contains(&position.into())?;
+            }
         }
 
         Ok(())
@@ -86,6 +145,31 @@ impl CardanoTransactionsSetProof {
             Ok(Self::new(transactions_hashes, mk_proof))
         }
 
+        /// Helper to create a proof with block inclusion leaves from a list of positions
+        pub fn from_block_positions<S: MKTreeStorer>(
+            positions: &[CardanoTransactionBlockPosition],
+        ) -> StdResult<Self> {
+            let mut positions_by_block_ranges: HashMap<BlockRange, Vec<MKTreeNode>> =
+                HashMap::new();
+            for position in positions {
+                positions_by_block_ranges
+                    .entry(BlockRange::from_block_number(position.block_number))
+                    .or_default()
+                    .push(position.into());
+            }
+            let mk_map = MKMap::<_, _, MKTreeStoreInMemory>::new_from_iter(
+                positions_by_block_ranges
+                    .into_iter()
+                    .map(|(block_range, leaves)| -> StdResult<(_, MKMapNode<_, S>)> {
+                        Ok((block_range, MKTree::<S>::new(&leaves)?.into()))
+                    })
+                    .collect::<StdResult<Vec<_>>>()?,
+            )?;
+            let mk_leaves: Vec<MKTreeNode> = positions.iter().map(|p| p.into()).collect();
+            let mk_proof = mk_map.compute_proof(&mk_leaves)?;
+            Ok(Self::new_with_block_positions(positions.to_vec(), mk_proof))
+        }
+
     }
 }
 
@@ -130,4 +214,108 @@ mod tests {
 
         proof.verify().expect_err("The proof should be invalid");
     }
+
+    fn block_positions() -> Vec<CardanoTransactionBlockPosition> {
+        [(0, "tx-1"), (1, "tx-2"), (10, "tx-3"), (20, "tx-4")]
+            .into_iter()
+            .map(|(block_number, hash)| CardanoTransactionBlockPosition {
+                transaction_hash: hash.to_string(),
+                block_number: BlockNumber(block_number),
+                block_hash: format!("block-hash-{block_number}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_verify_where_all_block_positions_are_contained_in_the_proof() {
+        let proof = CardanoTransactionsSetProof::from_block_positions::<MKTreeStoreInMemory>(
+            &block_positions(),
+        )
+        .unwrap();
+
+        proof.verify().expect("The proof should be valid");
+        assert_eq!(
+            vec!["tx-1", "tx-2", "tx-3", "tx-4"],
+            proof.transactions_hashes()
+        );
+    }
+
+    #[test]
+    fn shouldnt_verify_where_a_block_position_is_tampered() {
+        let proof = CardanoTransactionsSetProof::from_block_positions::<MKTreeStoreInMemory>(
+            &block_positions(),
+        )
+        .unwrap();
+        let mut positions_tampered = proof.transactions_block_positions().to_vec();
+        positions_tampered[1].block_hash = "another-block-hash".to_string();
+        let proof = CardanoTransactionsSetProof {
+            transactions_block_positions: positions_tampered,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_a_block_position_is_missing() {
+        let proof = CardanoTransactionsSetProof::from_block_positions::<MKTreeStoreInMemory>(
+            &block_positions(),
+        )
+        .unwrap();
+        let proof = CardanoTransactionsSetProof {
+            transactions_block_positions: proof.transactions_block_positions()[1..].to_vec(),
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_a_block_position_is_not_matching_its_transaction() {
+        let proof = CardanoTransactionsSetProof::from_block_positions::<MKTreeStoreInMemory>(
+            &block_positions(),
+        )
+        .unwrap();
+        let mut positions_swapped = proof.transactions_block_positions().to_vec();
+        positions_swapped.swap(0, 1);
+        let proof = CardanoTransactionsSetProof {
+            transactions_block_positions: positions_swapped,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_a_block_position_is_duplicated() {
+        let proof = CardanoTransactionsSetProof::from_block_positions::<MKTreeStoreInMemory>(
+            &block_positions(),
+        )
+        .unwrap();
+        let mut transactions_hashes = proof.transactions_hashes().to_vec();
+        transactions_hashes.push(transactions_hashes[0].clone());
+        let mut positions = proof.transactions_block_positions().to_vec();
+        positions.push(positions[0].clone());
+        let proof = CardanoTransactionsSetProof {
+            transactions_hashes,
+            transactions_block_positions: positions,
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
+
+    #[test]
+    fn shouldnt_verify_where_an_extra_block_position_is_not_matching_a_transaction() {
+        let proof = CardanoTransactionsSetProof::from_block_positions::<MKTreeStoreInMemory>(
+            &block_positions(),
+        )
+        .unwrap();
+        let proof = CardanoTransactionsSetProof {
+            transactions_hashes: proof.transactions_hashes()[1..].to_vec(),
+            ..proof
+        };
+
+        proof.verify().expect_err("The proof should be invalid");
+    }
 }
@@ -23,6 +23,10 @@ pub enum CompressionAlgorithm {
     Gzip,
     /// Zstandard compression format
     Zstandard,
+    /// LZ4 compression format (frame format), fast to decompress
+    Lz4,
+    /// XZ compression format (LZMA2), smallest archives
+    Xz,
 }
 
 impl CompressionAlgorithm {
@@ -31,6 +35,8 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::Gzip => "tar.gz".to_owned(),
             CompressionAlgorithm::Zstandard => "tar.zst".to_owned(),
+            CompressionAlgorithm::Lz4 => "tar.lz4".to_owned(),
+            CompressionAlgorithm::Xz => "tar.xz".to_owned(),
         }
     }
 
@@ -47,6 +53,8 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::Gzip => 2.5,
             CompressionAlgorithm::Zstandard => 4.0,
+            CompressionAlgorithm::Lz4 => 2.0,
+            CompressionAlgorithm::Xz => 4.5,
         }
     }
 }
@@ -3,12 +3,18 @@
 pub(crate) mod arithmetic_operation_wrapper;
 mod block_number;
 mod block_range;
+mod cardano_block;
+mod cardano_blocks_set_proof;
+mod cardano_blocks_snapshot;
 mod cardano_chain_point;
 mod cardano_database;
 mod cardano_db_beacon;
 mod cardano_network;
 mod cardano_stake_distribution;
 mod cardano_transaction;
+mod cardano_transaction_metadata;
+mod cardano_transactions_metadata_set_proof;
+mod cardano_transactions_metadata_snapshot;
 mod cardano_transactions_set_proof;
 mod cardano_transactions_snapshot;
 mod certificate;
@@ -33,6 +39,9 @@ mod type_alias;
 
 pub use block_number::BlockNumber;
 pub use block_range::{BlockRange, BlockRangeLength, BlockRangesSequence};
+pub use cardano_block::CardanoBlock;
+pub use cardano_blocks_set_proof::CardanoBlocksSetProof;
+pub use cardano_blocks_snapshot::CardanoBlocksSnapshot;
 pub use cardano_chain_point::{BlockHash, ChainPoint};
 pub use cardano_database::{
     AncillaryLocation, AncillaryLocations, CardanoDatabaseSnapshot,
@@ -42,7 +51,13 @@ pub use cardano_database::{
 pub use cardano_db_beacon::CardanoDbBeacon;
 pub use cardano_network::CardanoNetwork;
 pub use cardano_stake_distribution::CardanoStakeDistribution;
-pub use cardano_transaction::{CardanoTransaction, TransactionHash};
+pub use cardano_transaction::{
+    CardanoTransaction, CardanoTransactionBlockPosition, CardanoTransactionsLeafFormat,
+    TransactionHash,
+};
+pub use cardano_transaction_metadata::{CardanoTransactionMetadata, MetadataHash, MetadataLabel};
+pub use cardano_transactions_metadata_set_proof::CardanoTransactionsMetadataSetProof;
+pub use cardano_transactions_metadata_snapshot::CardanoTransactionsMetadataSnapshot;
 pub use cardano_transactions_set_proof::CardanoTransactionsSetProof;
 pub use cardano_transactions_snapshot::CardanoTransactionsSnapshot;
 pub use certificate::{Certificate, CertificateSignature};
@@ -48,6 +48,14 @@ pub enum ProtocolMessagePartKey {
     /// The ProtocolMessage part key associated to the Cardano database Merkle root
     #[serde(rename = "cardano_database_merkle_root")]
     CardanoDatabaseMerkleRoot,
+
+    /// The ProtocolMessage part key associated to the Cardano Blocks Merkle Root
+    #[serde(rename = "cardano_blocks_merkle_root")]
+    CardanoBlocksMerkleRoot,
+
+    /// The ProtocolMessage part key associated to the Cardano Transactions Metadata Merkle Root
+    #[serde(rename = "cardano_transactions_metadata_merkle_root")]
+    CardanoTransactionsMetadataMerkleRoot,
 }
 
 impl Display for ProtocolMessagePartKey {
@@ -64,6 +72,10 @@ impl Display for ProtocolMessagePartKey {
                 write!(f, "cardano_stake_distribution_merkle_root")
             }
             Self::CardanoDatabaseMerkleRoot => write!(f, "cardano_database_merkle_root"),
+            Self::CardanoBlocksMerkleRoot => write!(f, "cardano_blocks_merkle_root"),
+            Self::CardanoTransactionsMetadataMerkleRoot => {
+                write!(f, "cardano_transactions_metadata_merkle_root")
+            }
         }
     }
 }
@@ -218,6 +230,34 @@ mod tests {
         assert_ne!(hash_expected, protocol_message_modified.compute_hash());
     }
 
+    #[test]
+    fn test_protocol_message_compute_hash_include_cardano_blocks_merkle_root() {
+        let protocol_message = build_protocol_message_reference();
+        let hash_expected = protocol_message.compute_hash();
+
+        let mut protocol_message_modified = protocol_message.clone();
+        protocol_message_modified.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            "cardano-blocks-merkle-root-456".to_string(),
+        );
+
+        assert_ne!(hash_expected, protocol_message_modified.compute_hash());
+    }
+
+    #[test]
+    fn test_protocol_message_compute_hash_include_cardano_transactions_metadata_merkle_root() {
+        let protocol_message = build_protocol_message_reference();
+        let hash_expected = protocol_message.compute_hash();
+
+        let mut protocol_message_modified = protocol_message.clone();
+        protocol_message_modified.set_message_part(
+            ProtocolMessagePartKey::CardanoTransactionsMetadataMerkleRoot,
+            "cardano-transactions-metadata-merkle-root-456".to_string(),
+        );
+
+        assert_ne!(hash_expected, protocol_message_modified.compute_hash());
+    }
+
     #[test]
     fn test_protocol_message_compute_hash_include_next_protocol_parameters() {
         let protocol_message = build_protocol_message_reference();
@@ -274,6 +314,14 @@ mod tests {
             ProtocolMessagePartKey::CardanoDatabaseMerkleRoot,
             "cardano-database-merkle-root-123".to_string(),
         );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            "cardano-blocks-merkle-root-123".to_string(),
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoTransactionsMetadataMerkleRoot,
+            "cardano-transactions-metadata-merkle-root-123".to_string(),
+        );
 
         protocol_message
     }
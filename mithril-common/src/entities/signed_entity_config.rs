@@ -75,6 +75,22 @@ impl SignedEntityConfig {
             SignedEntityTypeDiscriminants::CardanoDatabase => SignedEntityType::CardanoDatabase(
                 CardanoDbBeacon::new(*time_point.epoch, time_point.immutable_file_number),
             ),
+            // Cardano blocks are signed at the same pace than the Cardano transactions, so both
+            // signed entities share the same block ranges
+            SignedEntityTypeDiscriminants::CardanoBlocks => SignedEntityType::CardanoBlocks(
+                time_point.epoch,
+                self.cardano_transactions_signing_config
+                    .compute_block_number_to_be_signed(time_point.chain_point.block_number),
+            ),
+            // Transactions metadata are an extension of the Cardano transactions, so they are
+            // signed on the same block ranges
+            SignedEntityTypeDiscriminants::CardanoTransactionsMetadata => {
+                SignedEntityType::CardanoTransactionsMetadata(
+                    time_point.epoch,
+                    self.cardano_transactions_signing_config
+                        .compute_block_number_to_be_signed(time_point.chain_point.block_number),
+                )
+            }
         };
 
         Ok(signed_entity_type)
@@ -233,6 +249,26 @@ mod tests {
                 )
                 .unwrap()
         );
+
+        assert_eq!(
+            SignedEntityType::CardanoBlocks(Epoch(1), BlockNumber(14)),
+            config
+                .time_point_to_signed_entity(
+                    SignedEntityTypeDiscriminants::CardanoBlocks,
+                    &time_point
+                )
+                .unwrap()
+        );
+
+        assert_eq!(
+            SignedEntityType::CardanoTransactionsMetadata(Epoch(1), BlockNumber(14)),
+            config
+                .time_point_to_signed_entity(
+                    SignedEntityTypeDiscriminants::CardanoTransactionsMetadata,
+                    &time_point
+                )
+                .unwrap()
+        );
     }
 
     #[test]
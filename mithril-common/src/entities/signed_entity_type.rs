@@ -30,6 +30,12 @@ const ENTITY_TYPE_CARDANO_TRANSACTIONS: usize = 3;
 /// Database representation of the SignedEntityType::CardanoDatabase value
 const ENTITY_TYPE_CARDANO_DATABASE: usize = 4;
 
+/// Database representation of the SignedEntityType::CardanoBlocks value
+const ENTITY_TYPE_CARDANO_BLOCKS: usize = 5;
+
+/// Database representation of the SignedEntityType::CardanoTransactionsMetadata value
+const ENTITY_TYPE_CARDANO_TRANSACTIONS_METADATA: usize = 6;
+
 /// The signed entity type that represents a type of data signed by the Mithril
 /// protocol Note: Each variant of this enum must be associated to an entry in
 /// the `signed_entity_type` table of the signer/aggregator nodes. The variant
@@ -64,6 +70,12 @@ pub enum SignedEntityType {
 
     /// Cardano Transactions
     CardanoTransactions(Epoch, BlockNumber),
+
+    /// Cardano Blocks
+    CardanoBlocks(Epoch, BlockNumber),
+
+    /// Cardano Transactions Metadata
+    CardanoTransactionsMetadata(Epoch, BlockNumber),
 }
 
 impl SignedEntityType {
@@ -78,7 +90,9 @@ impl SignedEntityType {
             Self::CardanoImmutableFilesFull(b) | Self::CardanoDatabase(b) => b.epoch,
             Self::CardanoStakeDistribution(e)
             | Self::MithrilStakeDistribution(e)
-            | Self::CardanoTransactions(e, _) => *e,
+            | Self::CardanoTransactions(e, _)
+            | Self::CardanoBlocks(e, _)
+            | Self::CardanoTransactionsMetadata(e, _) => *e,
         }
     }
 
@@ -87,7 +101,10 @@ impl SignedEntityType {
         match self {
             Self::CardanoImmutableFilesFull(beacon) | Self::CardanoDatabase(beacon) => beacon.epoch,
             Self::CardanoStakeDistribution(epoch) => epoch.next(),
-            Self::MithrilStakeDistribution(epoch) | Self::CardanoTransactions(epoch, _) => *epoch,
+            Self::MithrilStakeDistribution(epoch)
+            | Self::CardanoTransactions(epoch, _)
+            | Self::CardanoBlocks(epoch, _)
+            | Self::CardanoTransactionsMetadata(epoch, _) => *epoch,
         }
     }
 
@@ -99,6 +116,8 @@ impl SignedEntityType {
             Self::CardanoImmutableFilesFull(_) => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions(_, _) => ENTITY_TYPE_CARDANO_TRANSACTIONS,
             Self::CardanoDatabase(_) => ENTITY_TYPE_CARDANO_DATABASE,
+            Self::CardanoBlocks(_, _) => ENTITY_TYPE_CARDANO_BLOCKS,
+            Self::CardanoTransactionsMetadata(_, _) => ENTITY_TYPE_CARDANO_TRANSACTIONS_METADATA,
         }
     }
 
@@ -111,7 +130,9 @@ impl SignedEntityType {
             Self::CardanoStakeDistribution(value) | Self::MithrilStakeDistribution(value) => {
                 serde_json::to_string(value)?
             }
-            Self::CardanoTransactions(epoch, block_number) => {
+            Self::CardanoTransactions(epoch, block_number)
+            | Self::CardanoBlocks(epoch, block_number)
+            | Self::CardanoTransactionsMetadata(epoch, block_number) => {
                 let json = serde_json::json!({
                     "epoch": epoch,
                     "block_number": block_number,
@@ -130,6 +151,8 @@ impl SignedEntityType {
             Self::CardanoStakeDistribution(_) => Some(Duration::from_secs(600)),
             Self::CardanoTransactions(_, _) => Some(Duration::from_secs(1800)),
             Self::CardanoDatabase(_) => Some(Duration::from_secs(1800)),
+            Self::CardanoBlocks(_, _) => Some(Duration::from_secs(1800)),
+            Self::CardanoTransactionsMetadata(_, _) => Some(Duration::from_secs(1800)),
         }
     }
 
@@ -144,7 +167,9 @@ impl SignedEntityType {
                 hasher.update(&db_beacon.epoch.to_be_bytes());
                 hasher.update(&db_beacon.immutable_file_number.to_be_bytes());
             }
-            SignedEntityType::CardanoTransactions(epoch, block_number) => {
+            SignedEntityType::CardanoTransactions(epoch, block_number)
+            | SignedEntityType::CardanoBlocks(epoch, block_number)
+            | SignedEntityType::CardanoTransactionsMetadata(epoch, block_number) => {
                 hasher.update(&epoch.to_be_bytes());
                 hasher.update(&block_number.to_be_bytes())
             }
@@ -181,6 +206,8 @@ impl SignedEntityTypeDiscriminants {
             Self::CardanoImmutableFilesFull => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions => ENTITY_TYPE_CARDANO_TRANSACTIONS,
             Self::CardanoDatabase => ENTITY_TYPE_CARDANO_DATABASE,
+            Self::CardanoBlocks => ENTITY_TYPE_CARDANO_BLOCKS,
+            Self::CardanoTransactionsMetadata => ENTITY_TYPE_CARDANO_TRANSACTIONS_METADATA,
         }
     }
 
@@ -192,6 +219,8 @@ impl SignedEntityTypeDiscriminants {
             ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL => Ok(Self::CardanoImmutableFilesFull),
             ENTITY_TYPE_CARDANO_TRANSACTIONS => Ok(Self::CardanoTransactions),
             ENTITY_TYPE_CARDANO_DATABASE => Ok(Self::CardanoDatabase),
+            ENTITY_TYPE_CARDANO_BLOCKS => Ok(Self::CardanoBlocks),
+            ENTITY_TYPE_CARDANO_TRANSACTIONS_METADATA => Ok(Self::CardanoTransactionsMetadata),
             index => Err(anyhow!("Invalid entity_type_id {index}.")),
         }
     }
@@ -304,6 +333,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_epoch_when_signed_entity_type_is_signed_for_cardano_blocks_return_epoch_stored_in_signed_entity_type()
+     {
+        let signed_entity_type = SignedEntityType::CardanoBlocks(Epoch(5), BlockNumber(120));
+        assert_eq!(
+            signed_entity_type.get_epoch_when_signed_entity_type_is_signed(),
+            Epoch(5)
+        );
+    }
+
+    #[test]
+    fn get_epoch_when_signed_entity_type_is_signed_for_cardano_transactions_metadata_return_epoch_stored_in_signed_entity_type()
+     {
+        let signed_entity_type =
+            SignedEntityType::CardanoTransactionsMetadata(Epoch(5), BlockNumber(120));
+        assert_eq!(
+            signed_entity_type.get_epoch_when_signed_entity_type_is_signed(),
+            Epoch(5)
+        );
+    }
+
     #[test]
     fn verify_signed_entity_type_properties_are_included_in_computed_hash() {
         fn hash(signed_entity_type: SignedEntityType) -> String {
@@ -374,6 +424,38 @@ mod tests {
                 12, 123
             )))
         );
+
+        let reference_hash = hash(SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77)));
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoBlocks(Epoch(3), BlockNumber(77)))
+        );
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoBlocks(
+                Epoch(35),
+                BlockNumber(98765)
+            ))
+        );
+
+        let reference_hash = hash(SignedEntityType::CardanoTransactionsMetadata(
+            Epoch(35),
+            BlockNumber(77),
+        ));
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoTransactionsMetadata(
+                Epoch(3),
+                BlockNumber(77)
+            ))
+        );
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoTransactionsMetadata(
+                Epoch(35),
+                BlockNumber(98765)
+            ))
+        );
     }
 
     #[test]
@@ -414,6 +496,20 @@ mod tests {
             r#"{"epoch":12,"immutable_file_number":987}"#,
             &cardano_database_full_json
         );
+
+        let cardano_blocks_json = SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77))
+            .get_json_beacon()
+            .unwrap();
+        assert_same_json!(r#"{"epoch":35,"block_number":77}"#, &cardano_blocks_json);
+
+        let cardano_transactions_metadata_json =
+            SignedEntityType::CardanoTransactionsMetadata(Epoch(35), BlockNumber(77))
+                .get_json_beacon()
+                .unwrap();
+        assert_same_json!(
+            r#"{"epoch":35,"block_number":77}"#,
+            &cardano_transactions_metadata_json
+        );
     }
 
     #[test]
@@ -430,10 +526,12 @@ mod tests {
     }
 
     // Expected ord:
-    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoDatabase < CardanoTransactions
+    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoDatabase < CardanoTransactions < CardanoBlocks < CardanoTransactionsMetadata
     #[test]
     fn ordering_discriminant() {
         let mut list = vec![
+            SignedEntityTypeDiscriminants::CardanoTransactionsMetadata,
+            SignedEntityTypeDiscriminants::CardanoBlocks,
             SignedEntityTypeDiscriminants::CardanoStakeDistribution,
             SignedEntityTypeDiscriminants::CardanoDatabase,
             SignedEntityTypeDiscriminants::CardanoTransactions,
@@ -450,6 +548,8 @@ mod tests {
                 SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
                 SignedEntityTypeDiscriminants::CardanoDatabase,
                 SignedEntityTypeDiscriminants::CardanoTransactions,
+                SignedEntityTypeDiscriminants::CardanoBlocks,
+                SignedEntityTypeDiscriminants::CardanoTransactionsMetadata,
             ]
         );
     }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
 
+use crate::entities::SignedEntityTypeDiscriminants;
+
 /// The era that the software is running or will run
 #[derive(
     Display, EnumString, EnumIter, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,
@@ -17,6 +19,36 @@ impl SupportedEra {
     pub fn eras() -> Vec<Self> {
         Self::iter().collect()
     }
+
+    /// Changes of behavior introduced by the era compared to the previous one
+    pub fn changes(&self) -> EraChanges {
+        match self {
+            // First era, there is no previous era to compare with
+            Self::Pythagoras => EraChanges::default(),
+        }
+    }
+}
+
+/// Changes of behavior introduced by a [SupportedEra] compared to the previous one
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EraChanges {
+    /// Signed entity types that are signed starting from the era
+    pub added_signed_entity_types: Vec<SignedEntityTypeDiscriminants>,
+
+    /// Signed entity types that are no longer signed starting from the era
+    pub removed_signed_entity_types: Vec<SignedEntityTypeDiscriminants>,
+
+    /// Description of the other changes of behavior
+    pub behaviors: Vec<String>,
+}
+
+impl EraChanges {
+    /// Check if the era does not change anything compared to the previous one
+    pub fn is_empty(&self) -> bool {
+        self.added_signed_entity_types.is_empty()
+            && self.removed_signed_entity_types.is_empty()
+            && self.behaviors.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -42,4 +74,11 @@ mod tests {
 
         assert_eq!(SupportedEra::dummy(), supported_era);
     }
+
+    #[test]
+    fn first_era_does_not_declare_changes() {
+        let first_era = SupportedEra::eras()[0];
+
+        assert!(first_era.changes().is_empty());
+    }
 }
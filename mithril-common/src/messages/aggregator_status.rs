@@ -48,6 +48,12 @@ pub struct AggregatorStatusMessage {
 
     /// The total stake in Cardano
     pub total_cardano_stake: Stake,
+
+    /// Disk usage, in bytes, of the artifacts stored locally by the aggregator
+    ///
+    /// Only available when the aggregator serves its artifacts from its local storage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_artifacts_disk_usage: Option<u64>,
 }
 
 #[cfg(test)]
@@ -68,7 +74,8 @@ mod tests {
         "total_stakes_signers": 123456789,
         "total_next_stakes_signers": 987654321,
         "total_cardano_spo": 7777,
-        "total_cardano_stake": 888888888
+        "total_cardano_stake": 888888888,
+        "local_artifacts_disk_usage": 123456
         }"#;
 
     fn golden_current_message() -> AggregatorStatusMessage {
@@ -95,6 +102,7 @@ mod tests {
             total_next_stakes_signers: 987654321,
             total_cardano_spo: 7777,
             total_cardano_stake: 888888888,
+            local_artifacts_disk_usage: Some(123456),
         }
     }
 
@@ -0,0 +1,363 @@
+use crate::StdError;
+use crate::entities::{
+    BlockHash, BlockNumber, CardanoBlock, CardanoBlocksSetProof, ProtocolMessage,
+    ProtocolMessagePartKey,
+};
+use crate::messages::CardanoBlocksSetProofMessagePart;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A cryptographic proof for a set of Cardano blocks
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CardanoBlocksProofsMessage {
+    /// Hash of the certificate that validate this proof merkle root
+    pub certificate_hash: String,
+
+    /// Blocks that have been certified
+    pub certified_blocks: Vec<CardanoBlocksSetProofMessagePart>,
+
+    /// Hashes of the blocks that could not be certified
+    pub non_certified_blocks: Vec<BlockHash>,
+
+    /// Latest block number that has been certified
+    pub latest_block_number: BlockNumber,
+}
+
+impl CardanoBlocksProofsMessage {
+    /// Create a new `CardanoBlocksProofsMessage`
+    pub fn new(
+        certificate_hash: &str,
+        certified_blocks: Vec<CardanoBlocksSetProofMessagePart>,
+        non_certified_blocks: Vec<BlockHash>,
+        latest_block_number: BlockNumber,
+    ) -> Self {
+        Self {
+            certificate_hash: certificate_hash.to_string(),
+            certified_blocks,
+            non_certified_blocks,
+            latest_block_number,
+        }
+    }
+
+    /// Hashes of the blocks that have been certified
+    pub fn blocks_hashes(&self) -> Vec<BlockHash> {
+        self.certified_blocks
+            .iter()
+            .flat_map(|cb| cb.blocks.iter().map(|b| b.block_hash.clone()))
+            .collect()
+    }
+
+    /// Verify that all the certified blocks proofs are valid
+    ///
+    /// The following checks will be executed:
+    ///
+    /// 1 - Check that each Merkle proof is valid
+    ///
+    /// 2 - Check that all proofs share the same Merkle root
+    ///
+    /// 3 - Assert that there's at least one certified block
+    pub fn verify(&self) -> Result<VerifiedCardanoBlocks, VerifyCardanoBlocksProofsError> {
+        let mut merkle_root = None;
+
+        for certified_block in &self.certified_blocks {
+            let certified_block: CardanoBlocksSetProof = certified_block
+                .clone()
+                .try_into()
+                .map_err(VerifyCardanoBlocksProofsError::MalformedData)?;
+            certified_block.verify().map_err(|e| {
+                VerifyCardanoBlocksProofsError::InvalidSetProof {
+                    blocks_hashes: certified_block
+                        .blocks()
+                        .iter()
+                        .map(|b| b.block_hash.clone())
+                        .collect(),
+                    source: e,
+                }
+            })?;
+
+            let blocks_merkle_root = Some(certified_block.merkle_root());
+
+            if merkle_root.is_none() {
+                merkle_root = blocks_merkle_root;
+            } else if merkle_root != blocks_merkle_root {
+                return Err(VerifyCardanoBlocksProofsError::NonMatchingMerkleRoot);
+            }
+        }
+
+        Ok(VerifiedCardanoBlocks {
+            certificate_hash: self.certificate_hash.clone(),
+            merkle_root: merkle_root.ok_or(VerifyCardanoBlocksProofsError::NoCertifiedBlock)?,
+            certified_blocks: self.certified_blocks.iter().flat_map(|c| c.blocks.clone()).collect(),
+            latest_block_number: self.latest_block_number,
+        })
+    }
+}
+
+/// Set of blocks verified by [CardanoBlocksProofsMessage::verify].
+///
+/// Can be used to reconstruct part of a [ProtocolMessage] in order to check that
+/// it is indeed signed by a certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedCardanoBlocks {
+    certificate_hash: String,
+    merkle_root: String,
+    certified_blocks: Vec<CardanoBlock>,
+    latest_block_number: BlockNumber,
+}
+
+impl VerifiedCardanoBlocks {
+    /// Hash of the certificate that signs this struct Merkle root.
+    pub fn certificate_hash(&self) -> &str {
+        &self.certificate_hash
+    }
+
+    /// Certified blocks
+    pub fn certified_blocks(&self) -> &[CardanoBlock] {
+        &self.certified_blocks
+    }
+
+    /// Certified block with the given hash, if any
+    pub fn certified_block(&self, block_hash: &str) -> Option<&CardanoBlock> {
+        self.certified_blocks
+            .iter()
+            .find(|block| block.block_hash == block_hash)
+    }
+
+    /// Fill the given [ProtocolMessage] with the data associated with this
+    /// verified blocks set.
+    pub fn fill_protocol_message(&self, message: &mut ProtocolMessage) {
+        message.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            self.merkle_root.clone(),
+        );
+
+        message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            self.latest_block_number.to_string(),
+        );
+    }
+}
+
+/// Error encountered or produced by the [cardano blocks proof verification][CardanoBlocksProofsMessage::verify].
+#[derive(Error, Debug)]
+pub enum VerifyCardanoBlocksProofsError {
+    /// The verification of an individual [CardanoBlocksSetProofMessagePart] failed.
+    #[error("Invalid set proof for blocks hashes: {blocks_hashes:?}")]
+    InvalidSetProof {
+        /// Hashes of the invalid blocks
+        blocks_hashes: Vec<BlockHash>,
+        /// Error source
+        source: StdError,
+    },
+
+    /// No certified blocks set proof to verify
+    #[error("There's no certified block to verify")]
+    NoCertifiedBlock,
+
+    /// Not all certified blocks set proof have the same merkle root.
+    #[error("All certified blocks set proofs must share the same Merkle root")]
+    NonMatchingMerkleRoot,
+
+    /// An individual [CardanoBlocksSetProofMessagePart] could not be converted to a
+    /// [CardanoBlocksSetProof] for verification.
+    #[error("Malformed data or unknown Cardano Set Proof format")]
+    MalformedData(#[source] StdError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::crypto_helper::{MKMap, MKMapNode, MKTreeStoreInMemory};
+    use crate::entities::{BlockRange, SlotNumber};
+    use crate::signable_builder::{
+        CardanoBlocksSignableBuilder, MockBlockRangeRootRetriever, MockBlocksImporter,
+        SignableBuilder,
+    };
+
+    use super::*;
+
+    fn blocks() -> Vec<CardanoBlock> {
+        vec![
+            CardanoBlock::new("block-hash-10", BlockNumber(10), SlotNumber(100)),
+            CardanoBlock::new("block-hash-30", BlockNumber(30), SlotNumber(300)),
+        ]
+    }
+
+    #[test]
+    fn verify_malformed_proofs_fail() {
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![CardanoBlocksSetProofMessagePart {
+                blocks: vec![],
+                proof: "invalid".to_string(),
+            }],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Malformed blocks proofs should fail to verify itself");
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::MalformedData(_)),
+            "Expected 'MalformedData' error but got '{error:?}'"
+        );
+    }
+
+    #[test]
+    fn verify_no_certified_block_fail() {
+        let blocks_proofs =
+            CardanoBlocksProofsMessage::new("whatever", vec![], vec![], BlockNumber(99999));
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Proofs without certified blocks should fail to verify itself");
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::NoCertifiedBlock),
+            "Expected 'NoCertifiedBlock' error but got '{error:?}'"
+        );
+    }
+
+    #[test]
+    fn verify_valid_proofs() {
+        let set_proof =
+            CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks()).unwrap();
+        let expected = VerifiedCardanoBlocks {
+            certificate_hash: "whatever".to_string(),
+            merkle_root: set_proof.merkle_root(),
+            certified_blocks: blocks(),
+            latest_block_number: BlockNumber(99999),
+        };
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![set_proof.try_into().unwrap()],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let verified_blocks = blocks_proofs
+            .verify()
+            .expect("Valid blocks proofs should verify itself");
+
+        assert_eq!(expected, verified_blocks);
+        assert_eq!(
+            Some(&blocks()[1]),
+            verified_blocks.certified_block("block-hash-30")
+        );
+    }
+
+    #[test]
+    fn verify_proofs_with_a_tampered_block_fail() {
+        let set_proof =
+            CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks()).unwrap();
+        let mut set_proof_message: CardanoBlocksSetProofMessagePart = set_proof.try_into().unwrap();
+        set_proof_message.blocks[0].slot_number = SlotNumber(101);
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![set_proof_message],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Invalid blocks proofs should fail to verify itself");
+
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoBlocksProofsError::InvalidSetProof { .. }
+            ),
+            "Expected 'InvalidSetProof' error but got '{error:?}'"
+        );
+    }
+
+    #[test]
+    fn verify_valid_proof_with_different_merkle_root_fail() {
+        let set_proofs = vec![
+            CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks()[..1]).unwrap(),
+            CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks()[1..]).unwrap(),
+        ];
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            set_proofs.into_iter().map(|p| p.try_into().unwrap()).collect(),
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Blocks proofs with non matching merkle root should fail to verify itself");
+
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::NonMatchingMerkleRoot),
+            "Expected 'NonMatchingMerkleRoot' error but got '{error:?}'"
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_hashes_from_verified_cardano_blocks_and_from_signable_builder_are_equals() {
+        let verified_blocks = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![
+                CardanoBlocksSetProof::from_blocks::<MKTreeStoreInMemory>(&blocks())
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+            ],
+            vec![],
+            BlockNumber(99999),
+        )
+        .verify()
+        .unwrap();
+        let mut message = ProtocolMessage::new();
+        verified_blocks.fill_protocol_message(&mut message);
+
+        assert_eq!(
+            message.compute_hash(),
+            from_signable_builder(&blocks(), BlockNumber(99999))
+                .await
+                .compute_hash()
+        );
+        assert_ne!(
+            message.compute_hash(),
+            from_signable_builder(&blocks(), BlockNumber(123456))
+                .await
+                .compute_hash()
+        );
+    }
+
+    async fn from_signable_builder(
+        blocks: &[CardanoBlock],
+        block_number: BlockNumber,
+    ) -> ProtocolMessage {
+        let mut blocks_importer = MockBlocksImporter::new();
+        blocks_importer.expect_import().return_once(move |_| Ok(()));
+        let mut block_range_root_retriever = MockBlockRangeRootRetriever::new();
+
+        let blocks_imported = blocks.to_vec();
+        block_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| {
+                MKMap::<
+                        BlockRange,
+                        MKMapNode<BlockRange, MKTreeStoreInMemory>,
+                        MKTreeStoreInMemory,
+                    >::new_from_iter(blocks_imported.into_iter().map(|block| {
+                        (
+                            BlockRange::from_block_number(block.block_number),
+                            MKMapNode::TreeNode(block.into()),
+                        )
+                    }))
+            });
+        let cardano_blocks_signable_builder = CardanoBlocksSignableBuilder::new(
+            Arc::new(blocks_importer),
+            Arc::new(block_range_root_retriever),
+        );
+        cardano_blocks_signable_builder
+            .compute_protocol_message(block_number)
+            .await
+            .unwrap()
+    }
+}
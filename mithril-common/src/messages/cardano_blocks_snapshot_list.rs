@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{BlockNumber, Epoch};
+
+/// Message structure of a Cardano Blocks Snapshots list
+pub type CardanoBlocksSnapshotListMessage = Vec<CardanoBlocksSnapshotListItemMessage>;
+
+/// Message structure of a Cardano Blocks Snapshot list item
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoBlocksSnapshotListItemMessage {
+    /// Merkle root of the Cardano blocks snapshot
+    pub merkle_root: String,
+
+    /// Epoch of the Cardano blocks snapshot
+    pub epoch: Epoch,
+
+    /// Block number of the Cardano blocks snapshot
+    pub block_number: BlockNumber,
+
+    /// Hash of the Cardano Blocks snapshot
+    pub hash: String,
+
+    /// Hash of the associated certificate
+    pub certificate_hash: String,
+
+    /// DateTime of creation
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message_current() -> CardanoBlocksSnapshotListMessage {
+        vec![CardanoBlocksSnapshotListItemMessage {
+            merkle_root: "mkroot-123".to_string(),
+            epoch: Epoch(7),
+            block_number: BlockNumber(5),
+            hash: "hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }]
+    }
+
+    const CURRENT_JSON: &str = r#"[{
+        "merkle_root": "mkroot-123",
+        "epoch": 7,
+        "block_number": 5,
+        "hash": "hash-123",
+        "certificate_hash": "certificate-hash-123",
+        "created_at": "2023-01-19T13:43:05.618857482Z"
+    }]"#;
+
+    #[test]
+    fn test_current_json_deserialized_into_current_message() {
+        let json = CURRENT_JSON;
+        let message: CardanoBlocksSnapshotListMessage = serde_json::from_str(json).expect(
+                    "This JSON is expected to be successfully parsed into a CardanoBlocksSnapshotListMessage instance.",
+                );
+        assert_eq!(golden_message_current(), message);
+    }
+}
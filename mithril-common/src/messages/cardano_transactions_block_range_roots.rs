@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{BlockHash, BlockNumber, SlotNumber, TransactionHash};
+
+/// Message structure of the block range roots of a certified Cardano transactions snapshot
+///
+/// It allows a signer to bootstrap its Cardano transactions store without scanning the whole chain.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CardanoTransactionsBlockRangeRootsMessage {
+    /// Hash of the Cardano transactions snapshot
+    pub snapshot_hash: String,
+
+    /// Hash of the certificate that signs the Cardano transactions snapshot
+    pub certificate_hash: String,
+
+    /// Block number of the Cardano transactions snapshot
+    pub block_number: BlockNumber,
+
+    /// Roots of the block ranges included in the Cardano transactions snapshot
+    pub block_range_roots: Vec<BlockRangeRootMessagePart>,
+
+    /// Start of the first block range of the next page of block range roots, none if this page
+    /// is the last one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_from: Option<BlockNumber>,
+
+    /// Transactions of the last block range, allowing to resume the import after it
+    ///
+    /// They are only sent with the last page of block range roots.
+    pub last_block_range_transactions: Vec<CardanoTransactionMessagePart>,
+}
+
+/// Block range root of a [CardanoTransactionsBlockRangeRootsMessage]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockRangeRootMessagePart {
+    /// Start of the block range (included)
+    pub start: BlockNumber,
+
+    /// End of the block range (excluded)
+    pub end: BlockNumber,
+
+    /// Hex encoded Merkle root of the transactions of the block range
+    pub merkle_root: String,
+}
+
+/// Cardano transaction of a [CardanoTransactionsBlockRangeRootsMessage]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoTransactionMessagePart {
+    /// Unique hash of the transaction
+    pub transaction_hash: TransactionHash,
+
+    /// Block number of the transaction
+    pub block_number: BlockNumber,
+
+    /// Slot number of the transaction
+    pub slot_number: SlotNumber,
+
+    /// Block hash of the transaction
+    pub block_hash: BlockHash,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message_current() -> CardanoTransactionsBlockRangeRootsMessage {
+        CardanoTransactionsBlockRangeRootsMessage {
+            snapshot_hash: "snapshot-hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            block_number: BlockNumber(31),
+            block_range_roots: vec![
+                BlockRangeRootMessagePart {
+                    start: BlockNumber(0),
+                    end: BlockNumber(15),
+                    merkle_root: "mkroot-1".to_string(),
+                },
+                BlockRangeRootMessagePart {
+                    start: BlockNumber(15),
+                    end: BlockNumber(30),
+                    merkle_root: "mkroot-2".to_string(),
+                },
+            ],
+            next_page_from: Some(BlockNumber(30)),
+            last_block_range_transactions: vec![CardanoTransactionMessagePart {
+                transaction_hash: "tx-hash-123".to_string(),
+                block_number: BlockNumber(29),
+                slot_number: SlotNumber(290),
+                block_hash: "block-hash-29".to_string(),
+            }],
+        }
+    }
+
+    const CURRENT_JSON: &str = r#"{
+        "snapshot_hash": "snapshot-hash-123",
+        "certificate_hash": "certificate-hash-123",
+        "block_number": 31,
+        "block_range_roots": [
+            { "start": 0, "end": 15, "merkle_root": "mkroot-1" },
+            { "start": 15, "end": 30, "merkle_root": "mkroot-2" }
+        ],
+        "next_page_from": 30,
+        "last_block_range_transactions": [
+            {
+                "transaction_hash": "tx-hash-123",
+                "block_number": 29,
+                "slot_number": 290,
+                "block_hash": "block-hash-29"
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_current_json_deserialized_into_current_message() {
+        let json = CURRENT_JSON;
+        let message: CardanoTransactionsBlockRangeRootsMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a CardanoTransactionsBlockRangeRootsMessage instance.",
+        );
+
+        assert_eq!(golden_message_current(), message);
+    }
+}
@@ -0,0 +1,454 @@
+use crate::StdError;
+use crate::entities::{
+    BlockNumber, CardanoTransactionMetadata, CardanoTransactionsMetadataSetProof, MetadataLabel,
+    ProtocolMessage, ProtocolMessagePartKey, TransactionHash,
+};
+use crate::messages::CardanoTransactionsMetadataSetProofMessagePart;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A cryptographic proof for the metadata labels carried by a set of Cardano transactions
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CardanoTransactionsMetadataProofsMessage {
+    /// Hash of the certificate that validate this proof merkle root
+    pub certificate_hash: String,
+
+    /// Transactions metadata that have been certified
+    pub certified_metadata: Vec<CardanoTransactionsMetadataSetProofMessagePart>,
+
+    /// Hashes of the transactions for which no metadata could be certified
+    ///
+    /// Either because the transaction is not yet certified or because it carries no metadata.
+    pub non_certified_transactions: Vec<TransactionHash>,
+
+    /// Latest block number that has been certified
+    pub latest_block_number: BlockNumber,
+}
+
+impl CardanoTransactionsMetadataProofsMessage {
+    /// Create a new `CardanoTransactionsMetadataProofsMessage`
+    pub fn new(
+        certificate_hash: &str,
+        certified_metadata: Vec<CardanoTransactionsMetadataSetProofMessagePart>,
+        non_certified_transactions: Vec<TransactionHash>,
+        latest_block_number: BlockNumber,
+    ) -> Self {
+        Self {
+            certificate_hash: certificate_hash.to_string(),
+            certified_metadata,
+            non_certified_transactions,
+            latest_block_number,
+        }
+    }
+
+    /// Hashes of the transactions that have certified metadata
+    pub fn transactions_hashes(&self) -> Vec<TransactionHash> {
+        let mut hashes: Vec<TransactionHash> = self
+            .certified_metadata
+            .iter()
+            .flat_map(|cm| cm.metadata.iter().map(|m| m.transaction_hash.clone()))
+            .collect();
+        hashes.dedup();
+        hashes
+    }
+
+    /// Verify that all the certified transactions metadata proofs are valid
+    ///
+    /// The following checks will be executed:
+    ///
+    /// 1 - Check that each Merkle proof is valid
+    ///
+    /// 2 - Check that all proofs share the same Merkle root
+    ///
+    /// 3 - Assert that there's at least one certified transaction metadata
+    pub fn verify(
+        &self,
+    ) -> Result<VerifiedCardanoTransactionsMetadata, VerifyCardanoTransactionsMetadataProofsError>
+    {
+        let mut merkle_root = None;
+
+        for certified_metadata in &self.certified_metadata {
+            let certified_metadata: CardanoTransactionsMetadataSetProof = certified_metadata
+                .clone()
+                .try_into()
+                .map_err(VerifyCardanoTransactionsMetadataProofsError::MalformedData)?;
+            certified_metadata.verify().map_err(|e| {
+                VerifyCardanoTransactionsMetadataProofsError::InvalidSetProof {
+                    transactions_hashes: certified_metadata
+                        .metadata()
+                        .iter()
+                        .map(|m| m.transaction_hash.clone())
+                        .collect(),
+                    source: e,
+                }
+            })?;
+
+            let metadata_merkle_root = Some(certified_metadata.merkle_root());
+
+            if merkle_root.is_none() {
+                merkle_root = metadata_merkle_root;
+            } else if merkle_root != metadata_merkle_root {
+                return Err(VerifyCardanoTransactionsMetadataProofsError::NonMatchingMerkleRoot);
+            }
+        }
+
+        Ok(VerifiedCardanoTransactionsMetadata {
+            certificate_hash: self.certificate_hash.clone(),
+            merkle_root: merkle_root
+                .ok_or(VerifyCardanoTransactionsMetadataProofsError::NoCertifiedMetadata)?,
+            certified_metadata: self
+                .certified_metadata
+                .iter()
+                .flat_map(|c| c.metadata.clone())
+                .collect(),
+            latest_block_number: self.latest_block_number,
+        })
+    }
+}
+
+/// Set of transactions metadata verified by [CardanoTransactionsMetadataProofsMessage::verify].
+///
+/// Can be used to reconstruct part of a [ProtocolMessage] in order to check that
+/// it is indeed signed by a certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedCardanoTransactionsMetadata {
+    certificate_hash: String,
+    merkle_root: String,
+    certified_metadata: Vec<CardanoTransactionMetadata>,
+    latest_block_number: BlockNumber,
+}
+
+impl VerifiedCardanoTransactionsMetadata {
+    /// Hash of the certificate that signs this struct Merkle root.
+    pub fn certificate_hash(&self) -> &str {
+        &self.certificate_hash
+    }
+
+    /// Certified transactions metadata
+    pub fn certified_metadata(&self) -> &[CardanoTransactionMetadata] {
+        &self.certified_metadata
+    }
+
+    /// Certified metadata carried by the given transaction under the given label, if any
+    pub fn certified_label(
+        &self,
+        transaction_hash: &str,
+        label: MetadataLabel,
+    ) -> Option<&CardanoTransactionMetadata> {
+        self.certified_metadata
+            .iter()
+            .find(|m| m.transaction_hash == transaction_hash && m.label == label)
+    }
+
+    /// Fill the given [ProtocolMessage] with the data associated with this
+    /// verified transactions metadata set.
+    pub fn fill_protocol_message(&self, message: &mut ProtocolMessage) {
+        message.set_message_part(
+            ProtocolMessagePartKey::CardanoTransactionsMetadataMerkleRoot,
+            self.merkle_root.clone(),
+        );
+
+        message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            self.latest_block_number.to_string(),
+        );
+    }
+}
+
+/// Error encountered or produced by the [cardano transactions metadata proof verification][CardanoTransactionsMetadataProofsMessage::verify].
+#[derive(Error, Debug)]
+pub enum VerifyCardanoTransactionsMetadataProofsError {
+    /// The verification of an individual [CardanoTransactionsMetadataSetProofMessagePart] failed.
+    #[error("Invalid set proof for metadata of transactions hashes: {transactions_hashes:?}")]
+    InvalidSetProof {
+        /// Hashes of the transactions with invalid metadata
+        transactions_hashes: Vec<TransactionHash>,
+        /// Error source
+        source: StdError,
+    },
+
+    /// No certified transactions metadata set proof to verify
+    #[error("There's no certified transaction metadata to verify")]
+    NoCertifiedMetadata,
+
+    /// Not all certified transactions metadata set proof have the same merkle root.
+    #[error("All certified transactions metadata set proofs must share the same Merkle root")]
+    NonMatchingMerkleRoot,
+
+    /// An individual [CardanoTransactionsMetadataSetProofMessagePart] could not be converted to a
+    /// [CardanoTransactionsMetadataSetProof] for verification.
+    #[error("Malformed data or unknown Cardano Set Proof format")]
+    MalformedData(#[source] StdError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::crypto_helper::{MKMap, MKMapNode, MKTreeStoreInMemory};
+    use crate::entities::{BlockRange, SlotNumber};
+    use crate::signable_builder::{
+        CardanoTransactionsMetadataSignableBuilder, MockBlockRangeRootRetriever,
+        MockTransactionsMetadataImporter, SignableBuilder,
+    };
+
+    use super::*;
+
+    fn transactions_metadata() -> Vec<CardanoTransactionMetadata> {
+        vec![
+            CardanoTransactionMetadata::new(
+                "tx-hash-10",
+                674,
+                "metadata-hash-10",
+                BlockNumber(10),
+                SlotNumber(100),
+                "block-hash-10",
+            ),
+            CardanoTransactionMetadata::new(
+                "tx-hash-30",
+                721,
+                "metadata-hash-30",
+                BlockNumber(30),
+                SlotNumber(300),
+                "block-hash-30",
+            ),
+        ]
+    }
+
+    #[test]
+    fn verify_malformed_proofs_fail() {
+        let metadata_proofs = CardanoTransactionsMetadataProofsMessage::new(
+            "whatever",
+            vec![CardanoTransactionsMetadataSetProofMessagePart {
+                metadata: vec![],
+                proof: "invalid".to_string(),
+            }],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = metadata_proofs
+            .verify()
+            .expect_err("Malformed metadata proofs should fail to verify itself");
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsMetadataProofsError::MalformedData(_)
+            ),
+            "Expected 'MalformedData' error but got '{error:?}'"
+        );
+    }
+
+    #[test]
+    fn verify_no_certified_metadata_fail() {
+        let metadata_proofs = CardanoTransactionsMetadataProofsMessage::new(
+            "whatever",
+            vec![],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = metadata_proofs
+            .verify()
+            .expect_err("Proofs without certified metadata should fail to verify itself");
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsMetadataProofsError::NoCertifiedMetadata
+            ),
+            "Expected 'NoCertifiedMetadata' error but got '{error:?}'"
+        );
+    }
+
+    #[test]
+    fn verify_valid_proofs() {
+        let set_proof = CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+            &transactions_metadata(),
+        )
+        .unwrap();
+        let expected = VerifiedCardanoTransactionsMetadata {
+            certificate_hash: "whatever".to_string(),
+            merkle_root: set_proof.merkle_root(),
+            certified_metadata: transactions_metadata(),
+            latest_block_number: BlockNumber(99999),
+        };
+        let metadata_proofs = CardanoTransactionsMetadataProofsMessage::new(
+            "whatever",
+            vec![set_proof.try_into().unwrap()],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let verified_metadata = metadata_proofs
+            .verify()
+            .expect("Valid metadata proofs should verify itself");
+
+        assert_eq!(expected, verified_metadata);
+        assert_eq!(
+            Some(&transactions_metadata()[1]),
+            verified_metadata.certified_label("tx-hash-30", 721)
+        );
+        assert_eq!(None, verified_metadata.certified_label("tx-hash-30", 674));
+    }
+
+    #[test]
+    fn verify_proofs_with_a_tampered_label_fail() {
+        let set_proof = CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+            &transactions_metadata(),
+        )
+        .unwrap();
+        let mut set_proof_message: CardanoTransactionsMetadataSetProofMessagePart =
+            set_proof.try_into().unwrap();
+        set_proof_message.metadata[0].label = 675;
+        let metadata_proofs = CardanoTransactionsMetadataProofsMessage::new(
+            "whatever",
+            vec![set_proof_message],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = metadata_proofs
+            .verify()
+            .expect_err("Invalid metadata proofs should fail to verify itself");
+
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsMetadataProofsError::InvalidSetProof { .. }
+            ),
+            "Expected 'InvalidSetProof' error but got '{error:?}'"
+        );
+    }
+
+    #[test]
+    fn verify_proofs_with_a_tampered_block_number_fail() {
+        let set_proof = CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+            &transactions_metadata(),
+        )
+        .unwrap();
+        let mut set_proof_message: CardanoTransactionsMetadataSetProofMessagePart =
+            set_proof.try_into().unwrap();
+        set_proof_message.metadata[0].block_number = BlockNumber(11);
+        let metadata_proofs = CardanoTransactionsMetadataProofsMessage::new(
+            "whatever",
+            vec![set_proof_message],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = metadata_proofs.verify().expect_err(
+            "Metadata proofs with a tampered block number should fail to verify itself",
+        );
+
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsMetadataProofsError::InvalidSetProof { .. }
+            ),
+            "Expected 'InvalidSetProof' error but got '{error:?}'"
+        );
+    }
+
+    #[test]
+    fn verify_valid_proof_with_different_merkle_root_fail() {
+        let set_proofs = vec![
+            CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+                &transactions_metadata()[..1],
+            )
+            .unwrap(),
+            CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+                &transactions_metadata()[1..],
+            )
+            .unwrap(),
+        ];
+        let metadata_proofs = CardanoTransactionsMetadataProofsMessage::new(
+            "whatever",
+            set_proofs.into_iter().map(|p| p.try_into().unwrap()).collect(),
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = metadata_proofs.verify().expect_err(
+            "Metadata proofs with non matching merkle root should fail to verify itself",
+        );
+
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoTransactionsMetadataProofsError::NonMatchingMerkleRoot
+            ),
+            "Expected 'NonMatchingMerkleRoot' error but got '{error:?}'"
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_hashes_from_verified_cardano_transactions_metadata_and_from_signable_builder_are_equals()
+     {
+        let verified_metadata = CardanoTransactionsMetadataProofsMessage::new(
+            "whatever",
+            vec![
+                CardanoTransactionsMetadataSetProof::from_metadata::<MKTreeStoreInMemory>(
+                    &transactions_metadata(),
+                )
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            ],
+            vec![],
+            BlockNumber(99999),
+        )
+        .verify()
+        .unwrap();
+        let mut message = ProtocolMessage::new();
+        verified_metadata.fill_protocol_message(&mut message);
+
+        assert_eq!(
+            message.compute_hash(),
+            from_signable_builder(&transactions_metadata(), BlockNumber(99999))
+                .await
+                .compute_hash()
+        );
+        assert_ne!(
+            message.compute_hash(),
+            from_signable_builder(&transactions_metadata(), BlockNumber(123456))
+                .await
+                .compute_hash()
+        );
+    }
+
+    async fn from_signable_builder(
+        transactions_metadata: &[CardanoTransactionMetadata],
+        block_number: BlockNumber,
+    ) -> ProtocolMessage {
+        let mut transactions_metadata_importer = MockTransactionsMetadataImporter::new();
+        transactions_metadata_importer
+            .expect_import()
+            .return_once(move |_| Ok(()));
+        let mut block_range_root_retriever = MockBlockRangeRootRetriever::new();
+
+        let metadata_imported = transactions_metadata.to_vec();
+        block_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| {
+                MKMap::<
+                        BlockRange,
+                        MKMapNode<BlockRange, MKTreeStoreInMemory>,
+                        MKTreeStoreInMemory,
+                    >::new_from_iter(metadata_imported.into_iter().map(|metadata| {
+                        (
+                            BlockRange::from_block_number(metadata.block_number),
+                            MKMapNode::TreeNode(metadata.into()),
+                        )
+                    }))
+            });
+        let cardano_transactions_metadata_signable_builder =
+            CardanoTransactionsMetadataSignableBuilder::new(
+                Arc::new(transactions_metadata_importer),
+                Arc::new(block_range_root_retriever),
+            );
+        cardano_transactions_metadata_signable_builder
+            .compute_protocol_message(block_number)
+            .await
+            .unwrap()
+    }
+}
@@ -1,7 +1,7 @@
 use crate::StdError;
 use crate::entities::{
-    BlockNumber, CardanoTransactionsSetProof, ProtocolMessage, ProtocolMessagePartKey,
-    TransactionHash,
+    BlockNumber, CardanoTransactionBlockPosition, CardanoTransactionsSetProof, ProtocolMessage,
+    ProtocolMessagePartKey, TransactionHash,
 };
 use crate::messages::CardanoTransactionsSetProofMessagePart;
 use serde::{Deserialize, Serialize};
@@ -54,6 +54,7 @@ pub struct VerifiedCardanoTransactions {
     certificate_hash: String,
     merkle_root: String,
     certified_transactions: Vec<TransactionHash>,
+    certified_block_positions: Vec<CardanoTransactionBlockPosition>,
     latest_block_number: BlockNumber,
 }
 
@@ -68,6 +69,23 @@ impl VerifiedCardanoTransactions {
         &self.certified_transactions
     }
 
+    /// Certified positions in the chain of the transactions, empty if the proofs were not
+    /// computed with block inclusion leaves
+    pub fn certified_block_positions(&self) -> &[CardanoTransactionBlockPosition] {
+        &self.certified_block_positions
+    }
+
+    /// Certified position in the chain of a transaction, if the proofs were computed with block
+    /// inclusion leaves
+    pub fn certified_block_position(
+        &self,
+        transaction_hash: &str,
+    ) -> Option<&CardanoTransactionBlockPosition> {
+        self.certified_block_positions
+            .iter()
+            .find(|position| position.transaction_hash == transaction_hash)
+    }
+
     /// Fill the given [ProtocolMessage] with the data associated with this
     /// verified transactions set.
     pub fn fill_protocol_message(&self, message: &mut ProtocolMessage) {
@@ -174,6 +192,11 @@ impl CardanoTransactionsProofsMessage {
                 .iter()
                 .flat_map(|c| c.transactions_hashes.clone())
                 .collect(),
+            certified_block_positions: self
+                .certified_transactions
+                .iter()
+                .flat_map(|c| c.transactions_block_positions.clone())
+                .collect(),
             latest_block_number: self.latest_block_number,
         })
     }
@@ -200,6 +223,7 @@ mod tests {
             vec![CardanoTransactionsSetProofMessagePart {
                 transactions_hashes: vec![],
                 proof: "invalid".to_string(),
+                transactions_block_positions: vec![],
             }],
             vec![],
             BlockNumber(99999),
@@ -241,6 +265,7 @@ mod tests {
             certificate_hash: "whatever".to_string(),
             merkle_root: set_proof.merkle_root(),
             certified_transactions: set_proof.transactions_hashes().to_vec(),
+            certified_block_positions: vec![],
             latest_block_number: BlockNumber(99999),
         };
         let txs_proofs = CardanoTransactionsProofsMessage::new(
@@ -255,6 +280,40 @@ mod tests {
         assert_eq!(expected, verified_txs);
     }
 
+    #[test]
+    fn verify_valid_proofs_with_block_positions() {
+        let positions = vec![
+            CardanoTransactionBlockPosition {
+                transaction_hash: "tx-1".to_string(),
+                block_number: BlockNumber(10),
+                block_hash: "block-hash-10".to_string(),
+            },
+            CardanoTransactionBlockPosition {
+                transaction_hash: "tx-2".to_string(),
+                block_number: BlockNumber(30),
+                block_hash: "block-hash-30".to_string(),
+            },
+        ];
+        let set_proof =
+            CardanoTransactionsSetProof::from_block_positions::<MKTreeStoreInMemory>(&positions)
+                .unwrap();
+        let txs_proofs = CardanoTransactionsProofsMessage::new(
+            "whatever",
+            vec![set_proof.try_into().unwrap()],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let verified_txs = txs_proofs.verify().expect("Valid txs proofs should verify itself");
+
+        assert_eq!(positions, verified_txs.certified_block_positions());
+        assert_eq!(
+            Some(&positions[1]),
+            verified_txs.certified_block_position("tx-2")
+        );
+        assert_eq!(None, verified_txs.certified_block_position("tx-3"));
+    }
+
     #[test]
     fn verify_invalid_proofs() {
         let set_proof = CardanoTransactionsSetProof::new(
@@ -353,6 +412,7 @@ mod tests {
             certificate_hash: "whatever".to_string(),
             merkle_root: set_proof.merkle_root(),
             certified_transactions: set_proof.transactions_hashes().to_vec(),
+            certified_block_positions: vec![],
             latest_block_number: BlockNumber(block_number),
         };
 
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{BlockNumber, TransactionHash};
+
+/// Message structure of a request of proofs for a list of Cardano transactions
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CardanoTransactionsProofsRequestMessage {
+    /// Hashes of the transactions to get proofs for
+    pub transaction_hashes: Vec<TransactionHash>,
+
+    /// Block number of the certified Cardano transactions snapshot to compute the proofs against,
+    /// if neither it nor the snapshot hash are set the latest certified snapshot is used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<BlockNumber>,
+
+    /// Hash of the certified Cardano transactions snapshot to compute the proofs against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_hash: Option<String>,
+}
+
+impl CardanoTransactionsProofsRequestMessage {
+    /// Return the requested transaction hashes sorted and without duplicates
+    pub fn sanitized_transaction_hashes(&self) -> Vec<TransactionHash> {
+        let mut transaction_hashes = self.transaction_hashes.clone();
+        transaction_hashes.sort();
+        transaction_hashes.dedup();
+        transaction_hashes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURRENT_JSON: &str = r#"{
+        "transaction_hashes": ["tx-1", "tx-2"],
+        "block_number": 7060000,
+        "snapshot_hash": "snapshot-hash-123"
+    }"#;
+
+    fn golden_message_current() -> CardanoTransactionsProofsRequestMessage {
+        CardanoTransactionsProofsRequestMessage {
+            transaction_hashes: vec!["tx-1".to_string(), "tx-2".to_string()],
+            block_number: Some(BlockNumber(7060000)),
+            snapshot_hash: Some("snapshot-hash-123".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_current_json_deserialized_into_current_message() {
+        let json = CURRENT_JSON;
+        let message: CardanoTransactionsProofsRequestMessage = serde_json::from_str(json).unwrap();
+
+        assert_eq!(golden_message_current(), message);
+    }
+
+    #[test]
+    fn test_json_without_target_snapshot_deserialized_into_message_without_target_snapshot() {
+        let json = r#"{ "transaction_hashes": ["tx-1"] }"#;
+        let message: CardanoTransactionsProofsRequestMessage = serde_json::from_str(json).unwrap();
+
+        assert_eq!(None, message.block_number);
+        assert_eq!(None, message.snapshot_hash);
+    }
+
+    #[test]
+    fn sanitized_transaction_hashes_are_sorted_and_deduplicated() {
+        let message = CardanoTransactionsProofsRequestMessage {
+            transaction_hashes: vec![
+                "tx-2".to_string(),
+                "tx-1".to_string(),
+                "tx-2".to_string(),
+                "tx-1".to_string(),
+            ],
+            block_number: None,
+            snapshot_hash: None,
+        };
+
+        assert_eq!(
+            vec!["tx-1".to_string(), "tx-2".to_string()],
+            message.sanitized_transaction_hashes()
+        );
+    }
+}
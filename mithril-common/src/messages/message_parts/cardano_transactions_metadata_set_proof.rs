@@ -0,0 +1,45 @@
+use crate::{
+    StdError,
+    crypto_helper::ProtocolMkProof,
+    entities::{CardanoTransactionMetadata, CardanoTransactionsMetadataSetProof, HexEncodedKey},
+};
+use serde::{Deserialize, Serialize};
+
+/// A cryptographic proof of a set of Cardano transactions metadata is included in the global
+/// Cardano transactions metadata set
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CardanoTransactionsMetadataSetProofMessagePart {
+    /// Certified transactions metadata
+    pub metadata: Vec<CardanoTransactionMetadata>,
+
+    /// Proof of the transactions metadata
+    pub proof: HexEncodedKey,
+}
+
+impl TryFrom<CardanoTransactionsMetadataSetProof>
+    for CardanoTransactionsMetadataSetProofMessagePart
+{
+    type Error = StdError;
+
+    fn try_from(proof: CardanoTransactionsMetadataSetProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            metadata: proof.metadata,
+            proof: proof.metadata_proof.to_json_hex()?,
+        })
+    }
+}
+
+impl TryFrom<CardanoTransactionsMetadataSetProofMessagePart>
+    for CardanoTransactionsMetadataSetProof
+{
+    type Error = StdError;
+
+    fn try_from(
+        proof: CardanoTransactionsMetadataSetProofMessagePart,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            metadata: proof.metadata,
+            metadata_proof: ProtocolMkProof::from_json_hex(&proof.proof)?,
+        })
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
     StdError,
     crypto_helper::ProtocolMkProof,
-    entities::{CardanoTransactionsSetProof, HexEncodedKey, TransactionHash},
+    entities::{
+        CardanoTransactionBlockPosition, CardanoTransactionsSetProof, HexEncodedKey,
+        TransactionHash,
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +20,12 @@ pub struct CardanoTransactionsSetProofMessagePart {
 
     /// Proof of the transactions
     pub proof: HexEncodedKey,
+
+    /// Positions in the chain of the certified transactions, only available if the proof was
+    /// computed with block inclusion leaves
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(target_family = "wasm", wasm_bindgen(skip))]
+    pub transactions_block_positions: Vec<CardanoTransactionBlockPosition>,
 }
 
 impl TryFrom<CardanoTransactionsSetProof> for CardanoTransactionsSetProofMessagePart {
@@ -26,6 +35,7 @@ impl TryFrom<CardanoTransactionsSetProof> for CardanoTransactionsSetProofMessage
         Ok(Self {
             transactions_hashes: proof.transactions_hashes,
             proof: proof.transactions_proof.to_json_hex()?,
+            transactions_block_positions: proof.transactions_block_positions,
         })
     }
 }
@@ -37,6 +47,7 @@ impl TryFrom<CardanoTransactionsSetProofMessagePart> for CardanoTransactionsSetP
         Ok(Self {
             transactions_hashes: proof.transactions_hashes,
             transactions_proof: ProtocolMkProof::from_json_hex(&proof.proof)?,
+            transactions_block_positions: proof.transactions_block_positions,
         })
     }
 }
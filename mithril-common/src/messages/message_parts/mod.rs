@@ -1,7 +1,11 @@
+mod cardano_blocks_set_proof;
+mod cardano_transactions_metadata_set_proof;
 mod cardano_transactions_set_proof;
 mod certificate_metadata;
 mod signer;
 
+pub use cardano_blocks_set_proof::CardanoBlocksSetProofMessagePart;
+pub use cardano_transactions_metadata_set_proof::CardanoTransactionsMetadataSetProofMessagePart;
 pub use cardano_transactions_set_proof::CardanoTransactionsSetProofMessagePart;
 pub use certificate_metadata::CertificateMetadataMessagePart;
 pub use signer::{SignerMessagePart, SignerWithStakeMessagePart};
@@ -2,6 +2,9 @@
 //! This module aims at providing shared structures for API communications.
 mod aggregator_features;
 mod aggregator_status;
+mod cardano_blocks_proof;
+mod cardano_blocks_snapshot;
+mod cardano_blocks_snapshot_list;
 mod cardano_database;
 mod cardano_database_digest_list;
 mod cardano_database_immutable_files_restored;
@@ -10,7 +13,12 @@ mod cardano_stake_distribution;
 mod cardano_stake_distribution_list;
 mod cardano_transaction_snapshot;
 mod cardano_transaction_snapshot_list;
+mod cardano_transactions_block_range_roots;
+mod cardano_transactions_metadata_proof;
+mod cardano_transactions_metadata_snapshot;
+mod cardano_transactions_metadata_snapshot_list;
 mod cardano_transactions_proof;
+mod cardano_transactions_proofs_request;
 mod certificate;
 mod certificate_list;
 mod epoch_settings;
@@ -28,6 +36,13 @@ pub use aggregator_features::{
     AggregatorCapabilities, AggregatorFeaturesMessage, CardanoTransactionsProverCapabilities,
 };
 pub use aggregator_status::AggregatorStatusMessage;
+pub use cardano_blocks_proof::{
+    CardanoBlocksProofsMessage, VerifiedCardanoBlocks, VerifyCardanoBlocksProofsError,
+};
+pub use cardano_blocks_snapshot::CardanoBlocksSnapshotMessage;
+pub use cardano_blocks_snapshot_list::{
+    CardanoBlocksSnapshotListItemMessage, CardanoBlocksSnapshotListMessage,
+};
 pub use cardano_database::{
     AncillaryMessagePart, CardanoDatabaseSnapshotMessage, DigestsMessagePart, ImmutablesMessagePart,
 };
@@ -46,10 +61,24 @@ pub use cardano_transaction_snapshot::CardanoTransactionSnapshotMessage;
 pub use cardano_transaction_snapshot_list::{
     CardanoTransactionSnapshotListItemMessage, CardanoTransactionSnapshotListMessage,
 };
+pub use cardano_transactions_block_range_roots::{
+    BlockRangeRootMessagePart, CardanoTransactionMessagePart,
+    CardanoTransactionsBlockRangeRootsMessage,
+};
+pub use cardano_transactions_metadata_proof::{
+    CardanoTransactionsMetadataProofsMessage, VerifiedCardanoTransactionsMetadata,
+    VerifyCardanoTransactionsMetadataProofsError,
+};
+pub use cardano_transactions_metadata_snapshot::CardanoTransactionsMetadataSnapshotMessage;
+pub use cardano_transactions_metadata_snapshot_list::{
+    CardanoTransactionsMetadataSnapshotListItemMessage,
+    CardanoTransactionsMetadataSnapshotListMessage,
+};
 pub use cardano_transactions_proof::{
     CardanoTransactionsProofsMessage, VerifiedCardanoTransactions,
     VerifyCardanoTransactionsProofsError,
 };
+pub use cardano_transactions_proofs_request::CardanoTransactionsProofsRequestMessage;
 pub use certificate::CertificateMessage;
 pub use certificate_list::{
     CertificateListItemMessage, CertificateListItemMessageMetadata, CertificateListMessage,
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    StdResult,
+    crypto_helper::MKTreeStorer,
+    entities::{BlockNumber, ProtocolMessage, ProtocolMessagePartKey},
+    signable_builder::{BlockRangeRootRetriever, SignableBuilder},
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Cardano blocks importer
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait BlocksImporter: Send + Sync {
+    /// Import all blocks up to the given beacon into the system
+    async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()>;
+}
+
+/// A [CardanoBlocksSignableBuilder] builder
+///
+/// The blocks are grouped by [block ranges][crate::entities::BlockRange], the same way as the
+/// Cardano transactions, the block range root retriever must provide the Merkle roots of the
+/// blocks of each range.
+pub struct CardanoBlocksSignableBuilder<S: MKTreeStorer> {
+    blocks_importer: Arc<dyn BlocksImporter>,
+    block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+}
+
+impl<S: MKTreeStorer> CardanoBlocksSignableBuilder<S> {
+    /// Constructor
+    pub fn new(
+        blocks_importer: Arc<dyn BlocksImporter>,
+        block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+    ) -> Self {
+        Self {
+            blocks_importer,
+            block_range_root_retriever,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> SignableBuilder<BlockNumber> for CardanoBlocksSignableBuilder<S> {
+    async fn compute_protocol_message(&self, beacon: BlockNumber) -> StdResult<ProtocolMessage> {
+        self.blocks_importer.import(beacon).await?;
+
+        let mk_root = self
+            .block_range_root_retriever
+            .compute_merkle_map_from_block_range_roots(beacon)
+            .await?
+            .compute_root()?;
+
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            mk_root.to_hex(),
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            beacon.to_string(),
+        );
+
+        Ok(protocol_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        crypto_helper::{MKMap, MKMapNode, MKTreeStoreInMemory},
+        entities::{BlockRange, CardanoBlock, SlotNumber},
+        signable_builder::MockBlockRangeRootRetriever,
+    };
+
+    use super::*;
+
+    fn compute_mk_map_from_blocks(
+        blocks: Vec<CardanoBlock>,
+    ) -> MKMap<BlockRange, MKMapNode<BlockRange, MKTreeStoreInMemory>, MKTreeStoreInMemory> {
+        MKMap::new_from_iter(blocks.iter().map(|block| {
+            (
+                BlockRange::from_block_number(block.block_number),
+                MKMapNode::TreeNode(block.into()),
+            )
+        }))
+        .unwrap()
+    }
+
+    /// One block per block range, as the Merkle map holds a single node per block range
+    fn blocks() -> Vec<CardanoBlock> {
+        (0..3)
+            .map(|i| {
+                let block_number = BlockRange::LENGTH * i;
+                CardanoBlock::new(
+                    format!("block-hash-{i}"),
+                    block_number,
+                    SlotNumber(*block_number),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable() {
+        let block_number = BlockNumber(1453);
+        let mk_map = compute_mk_map_from_blocks(blocks());
+        let mut blocks_importer = MockBlocksImporter::new();
+        blocks_importer.expect_import().return_once(move |_| Ok(()));
+        let mut block_range_root_retriever = MockBlockRangeRootRetriever::new();
+        block_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| Ok(compute_mk_map_from_blocks(blocks())));
+
+        let cardano_blocks_signable_builder = CardanoBlocksSignableBuilder::new(
+            Arc::new(blocks_importer),
+            Arc::new(block_range_root_retriever),
+        );
+
+        let signable = cardano_blocks_signable_builder
+            .compute_protocol_message(block_number)
+            .await
+            .unwrap();
+
+        let mut signable_expected = ProtocolMessage::new();
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            mk_map.compute_root().unwrap().to_hex(),
+        );
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            format!("{block_number}"),
+        );
+        assert_eq!(signable_expected, signable);
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable_with_no_block_range_root_return_error() {
+        let mut blocks_importer = MockBlocksImporter::new();
+        blocks_importer.expect_import().return_once(|_| Ok(()));
+        let mut block_range_root_retriever = MockBlockRangeRootRetriever::new();
+        block_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| Ok(compute_mk_map_from_blocks(vec![])));
+        let cardano_blocks_signable_builder = CardanoBlocksSignableBuilder::new(
+            Arc::new(blocks_importer),
+            Arc::new(block_range_root_retriever),
+        );
+
+        let result = cardano_blocks_signable_builder
+            .compute_protocol_message(BlockNumber(50))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
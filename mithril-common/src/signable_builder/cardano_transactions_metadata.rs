@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    StdResult,
+    crypto_helper::MKTreeStorer,
+    entities::{BlockNumber, ProtocolMessage, ProtocolMessagePartKey},
+    signable_builder::{BlockRangeRootRetriever, SignableBuilder},
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Cardano transactions metadata importer
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait TransactionsMetadataImporter: Send + Sync {
+    /// Import all transactions metadata up to the given beacon into the system
+    async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()>;
+}
+
+/// A [CardanoTransactionsMetadataSignableBuilder] builder
+///
+/// The transactions metadata are grouped by [block ranges][crate::entities::BlockRange], the same
+/// way as the Cardano transactions, the block range root retriever must provide the Merkle roots
+/// of the transactions metadata of each range.
+pub struct CardanoTransactionsMetadataSignableBuilder<S: MKTreeStorer> {
+    transactions_metadata_importer: Arc<dyn TransactionsMetadataImporter>,
+    block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+}
+
+impl<S: MKTreeStorer> CardanoTransactionsMetadataSignableBuilder<S> {
+    /// Constructor
+    pub fn new(
+        transactions_metadata_importer: Arc<dyn TransactionsMetadataImporter>,
+        block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+    ) -> Self {
+        Self {
+            transactions_metadata_importer,
+            block_range_root_retriever,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> SignableBuilder<BlockNumber>
+    for CardanoTransactionsMetadataSignableBuilder<S>
+{
+    async fn compute_protocol_message(&self, beacon: BlockNumber) -> StdResult<ProtocolMessage> {
+        self.transactions_metadata_importer.import(beacon).await?;
+
+        let mk_root = self
+            .block_range_root_retriever
+            .compute_merkle_map_from_block_range_roots(beacon)
+            .await?
+            .compute_root()?;
+
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoTransactionsMetadataMerkleRoot,
+            mk_root.to_hex(),
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            beacon.to_string(),
+        );
+
+        Ok(protocol_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        crypto_helper::{MKMap, MKMapNode, MKTreeStoreInMemory},
+        entities::{BlockRange, CardanoTransactionMetadata, SlotNumber},
+        signable_builder::MockBlockRangeRootRetriever,
+    };
+
+    use super::*;
+
+    fn compute_mk_map_from_transactions_metadata(
+        transactions_metadata: Vec<CardanoTransactionMetadata>,
+    ) -> MKMap<BlockRange, MKMapNode<BlockRange, MKTreeStoreInMemory>, MKTreeStoreInMemory> {
+        MKMap::new_from_iter(transactions_metadata.iter().map(|metadata| {
+            (
+                BlockRange::from_block_number(metadata.block_number),
+                MKMapNode::TreeNode(metadata.into()),
+            )
+        }))
+        .unwrap()
+    }
+
+    fn transactions_metadata() -> Vec<CardanoTransactionMetadata> {
+        (0..3)
+            .map(|i| {
+                CardanoTransactionMetadata::new(
+                    format!("tx-hash-{i}"),
+                    674,
+                    format!("metadata-hash-{i}"),
+                    BlockNumber(i),
+                    SlotNumber(i),
+                    format!("block-hash-{i}"),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable() {
+        let block_number = BlockNumber(1453);
+        let mk_map = compute_mk_map_from_transactions_metadata(transactions_metadata());
+        let mut transactions_metadata_importer = MockTransactionsMetadataImporter::new();
+        transactions_metadata_importer
+            .expect_import()
+            .return_once(move |_| Ok(()));
+        let mut block_range_root_retriever = MockBlockRangeRootRetriever::new();
+        block_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| {
+                Ok(compute_mk_map_from_transactions_metadata(
+                    transactions_metadata(),
+                ))
+            });
+
+        let cardano_transactions_metadata_signable_builder =
+            CardanoTransactionsMetadataSignableBuilder::new(
+                Arc::new(transactions_metadata_importer),
+                Arc::new(block_range_root_retriever),
+            );
+
+        let signable = cardano_transactions_metadata_signable_builder
+            .compute_protocol_message(block_number)
+            .await
+            .unwrap();
+
+        let mut signable_expected = ProtocolMessage::new();
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::CardanoTransactionsMetadataMerkleRoot,
+            mk_map.compute_root().unwrap().to_hex(),
+        );
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            format!("{block_number}"),
+        );
+        assert_eq!(signable_expected, signable);
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable_with_no_block_range_root_return_error() {
+        let mut transactions_metadata_importer = MockTransactionsMetadataImporter::new();
+        transactions_metadata_importer.expect_import().return_once(|_| Ok(()));
+        let mut block_range_root_retriever = MockBlockRangeRootRetriever::new();
+        block_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| Ok(compute_mk_map_from_transactions_metadata(vec![])));
+        let cardano_transactions_metadata_signable_builder =
+            CardanoTransactionsMetadataSignableBuilder::new(
+                Arc::new(transactions_metadata_importer),
+                Arc::new(block_range_root_retriever),
+            );
+
+        let result = cardano_transactions_metadata_signable_builder
+            .compute_protocol_message(BlockNumber(50))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -4,9 +4,9 @@ use std::fmt::Debug;
 use crate::{
     StdResult,
     entities::{
-        BlockNumber, CardanoDatabaseSnapshot, CardanoDbBeacon, CardanoStakeDistribution,
-        CardanoTransactionsSnapshot, Epoch, MithrilStakeDistribution, ProtocolMessage,
-        ProtocolMessagePartValue, Snapshot,
+        BlockNumber, CardanoBlocksSnapshot, CardanoDatabaseSnapshot, CardanoDbBeacon,
+        CardanoStakeDistribution, CardanoTransactionsMetadataSnapshot, CardanoTransactionsSnapshot,
+        Epoch, MithrilStakeDistribution, ProtocolMessage, ProtocolMessagePartValue, Snapshot,
     },
 };
 
@@ -54,6 +54,20 @@ impl Beacon for CardanoDbBeacon {}
 
 impl Beacon for Epoch {}
 
+#[cfg_attr(not(target_family = "wasm"), typetag::serde)]
+impl Artifact for CardanoBlocksSnapshot {
+    fn get_id(&self) -> String {
+        self.hash.clone()
+    }
+}
+
+#[cfg_attr(not(target_family = "wasm"), typetag::serde)]
+impl Artifact for CardanoTransactionsMetadataSnapshot {
+    fn get_id(&self) -> String {
+        self.hash.clone()
+    }
+}
+
 #[cfg_attr(not(target_family = "wasm"), typetag::serde)]
 impl Artifact for CardanoDatabaseSnapshot {
     fn get_id(&self) -> String {
@@ -1,14 +1,18 @@
 //! The module used for building signables
 
+mod cardano_blocks;
 mod cardano_stake_distribution;
 mod cardano_transactions;
+mod cardano_transactions_metadata;
 mod interface;
 mod mithril_stake_distribution;
 mod signable_builder_service;
 mod signed_entity;
 
+pub use cardano_blocks::*;
 pub use cardano_stake_distribution::*;
 pub use cardano_transactions::*;
+pub use cardano_transactions_metadata::*;
 pub use interface::*;
 pub use mithril_stake_distribution::*;
 pub use signable_builder_service::*;
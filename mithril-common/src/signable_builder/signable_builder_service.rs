@@ -32,6 +32,8 @@ pub struct MithrilSignableBuilderService {
     cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     cardano_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
     cardano_database_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
+    cardano_blocks_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
+    cardano_transactions_metadata_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     logger: Logger,
 }
 
@@ -42,6 +44,8 @@ pub struct SignableBuilderServiceDependencies {
     cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     cardano_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
     cardano_database_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
+    cardano_blocks_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
+    cardano_transactions_metadata_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
 }
 
 impl SignableBuilderServiceDependencies {
@@ -52,6 +56,8 @@ impl SignableBuilderServiceDependencies {
         cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
         cardano_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
         cardano_database_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
+        cardano_blocks_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
+        cardano_transactions_metadata_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     ) -> Self {
         Self {
             mithril_stake_distribution_builder,
@@ -59,6 +65,8 @@ impl SignableBuilderServiceDependencies {
             cardano_transactions_signable_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_signable_builder,
+            cardano_transactions_metadata_signable_builder,
         }
     }
 }
@@ -78,6 +86,9 @@ impl MithrilSignableBuilderService {
                 .cardano_transactions_signable_builder,
             cardano_stake_distribution_builder: dependencies.cardano_stake_distribution_builder,
             cardano_database_signable_builder: dependencies.cardano_database_signable_builder,
+            cardano_blocks_signable_builder: dependencies.cardano_blocks_signable_builder,
+            cardano_transactions_metadata_signable_builder: dependencies
+                .cardano_transactions_metadata_signable_builder,
             logger: logger.new_with_component_name::<Self>(),
         }
     }
@@ -126,6 +137,20 @@ impl MithrilSignableBuilderService {
                 .with_context(|| format!(
                     "Signable builder service can not compute protocol message for Cardano database with beacon: '{beacon}'"
                 ))?,
+            SignedEntityType::CardanoBlocks(_, block_number) => self
+                .cardano_blocks_signable_builder
+                .compute_protocol_message(block_number)
+                .await
+                .with_context(|| format!(
+                    "Signable builder service can not compute protocol message for Cardano blocks with block_number: '{block_number}'"
+                ))?,
+            SignedEntityType::CardanoTransactionsMetadata(_, block_number) => self
+                .cardano_transactions_metadata_signable_builder
+                .compute_protocol_message(block_number)
+                .await
+                .with_context(|| format!(
+                    "Signable builder service can not compute protocol message for Cardano transactions metadata with block_number: '{block_number}'"
+                ))?,
         };
 
         Ok(protocol_message)
@@ -205,6 +230,8 @@ mod tests {
         mock_cardano_transactions_signable_builder: MockSignableBuilderImpl<BlockNumber>,
         mock_cardano_stake_distribution_signable_builder: MockSignableBuilderImpl<Epoch>,
         mock_cardano_database_signable_builder: MockSignableBuilderImpl<CardanoDbBeacon>,
+        mock_cardano_blocks_signable_builder: MockSignableBuilderImpl<BlockNumber>,
+        mock_cardano_transactions_metadata_signable_builder: MockSignableBuilderImpl<BlockNumber>,
     }
 
     impl MockDependencyInjector {
@@ -216,6 +243,8 @@ mod tests {
                 mock_cardano_stake_distribution_signable_builder: MockSignableBuilderImpl::new(),
                 mock_cardano_transactions_signable_builder: MockSignableBuilderImpl::new(),
                 mock_cardano_database_signable_builder: MockSignableBuilderImpl::new(),
+                mock_cardano_blocks_signable_builder: MockSignableBuilderImpl::new(),
+                mock_cardano_transactions_metadata_signable_builder: MockSignableBuilderImpl::new(),
             }
         }
 
@@ -226,6 +255,8 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_signable_builder),
                 Arc::new(self.mock_cardano_stake_distribution_signable_builder),
                 Arc::new(self.mock_cardano_database_signable_builder),
+                Arc::new(self.mock_cardano_blocks_signable_builder),
+                Arc::new(self.mock_cardano_transactions_metadata_signable_builder),
             );
 
             MithrilSignableBuilderService::new(
@@ -344,4 +375,40 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn build_cardano_blocks_signable_when_given_cardano_blocks_entity_type() {
+        let mut mock_container = build_mock_container();
+        mock_container
+            .mock_cardano_blocks_signable_builder
+            .expect_compute_protocol_message()
+            .once()
+            .return_once(|_| Ok(ProtocolMessage::new()));
+        let signable_builder_service = mock_container.build_signable_builder_service();
+        let signed_entity_type = SignedEntityType::CardanoBlocks(Epoch(5), BlockNumber(1000));
+
+        signable_builder_service
+            .compute_protocol_message(signed_entity_type)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn build_cardano_transactions_metadata_signable_when_given_cardano_transactions_metadata_entity_type()
+     {
+        let mut mock_container = build_mock_container();
+        mock_container
+            .mock_cardano_transactions_metadata_signable_builder
+            .expect_compute_protocol_message()
+            .once()
+            .return_once(|_| Ok(ProtocolMessage::new()));
+        let signable_builder_service = mock_container.build_signable_builder_service();
+        let signed_entity_type =
+            SignedEntityType::CardanoTransactionsMetadata(Epoch(5), BlockNumber(1000));
+
+        signable_builder_service
+            .compute_protocol_message(signed_entity_type)
+            .await
+            .unwrap();
+    }
 }
@@ -35,6 +35,45 @@ mod entities {
         }
     }
 
+    impl Dummy for CardanoBlocksSetProof {
+        /// Return a dummy [CardanoBlocksSetProof] (test-only).
+        fn dummy() -> Self {
+            let blocks: Vec<CardanoBlock> = [0, 1, 10, 20, 22]
+                .into_iter()
+                .map(|block_number| {
+                    CardanoBlock::new(
+                        format!("block-hash-{block_number}"),
+                        BlockNumber(block_number),
+                        SlotNumber(block_number * 10),
+                    )
+                })
+                .collect();
+
+            Self::from_blocks::<MKTreeStoreInMemory>(&blocks).unwrap()
+        }
+    }
+
+    impl Dummy for CardanoTransactionsMetadataSetProof {
+        /// Return a dummy [CardanoTransactionsMetadataSetProof] (test-only).
+        fn dummy() -> Self {
+            let metadata: Vec<CardanoTransactionMetadata> = [0, 1, 10, 20, 22]
+                .into_iter()
+                .map(|block_number| {
+                    CardanoTransactionMetadata::new(
+                        format!("tx-hash-{block_number}"),
+                        674,
+                        format!("metadata-hash-{block_number}"),
+                        BlockNumber(block_number),
+                        SlotNumber(block_number * 10),
+                        format!("block-hash-{block_number}"),
+                    )
+                })
+                .collect();
+
+            Self::from_metadata::<MKTreeStoreInMemory>(&metadata).unwrap()
+        }
+    }
+
     impl Dummy for SignedEntityConfig {
         /// Return a dummy [SignedEntityConfig] (test-only).
         fn dummy() -> Self {
@@ -82,7 +121,8 @@ mod messages {
     use std::collections::BTreeSet;
 
     use crate::entities::{
-        AncillaryLocation, BlockNumber, CardanoDbBeacon, CardanoTransactionsSetProof,
+        AncillaryLocation, BlockNumber, CardanoBlocksSetProof, CardanoDbBeacon,
+        CardanoTransactionsMetadataSetProof, CardanoTransactionsSetProof,
         CardanoTransactionsSigningConfig, CompressionAlgorithm, DigestLocation, Epoch,
         ImmutablesLocation, MultiFilesUri, ProtocolMessage, ProtocolMessagePartKey,
         ProtocolParameters, SignedEntityType, SignedEntityTypeDiscriminants, StakeDistribution,
@@ -92,6 +132,20 @@ mod messages {
 
     use super::*;
 
+    impl Dummy for CardanoBlocksSetProofMessagePart {
+        /// Return a dummy [CardanoBlocksSetProofMessagePart] (test-only).
+        fn dummy() -> Self {
+            CardanoBlocksSetProof::dummy().try_into().unwrap()
+        }
+    }
+
+    impl Dummy for CardanoTransactionsMetadataSetProofMessagePart {
+        /// Return a dummy [CardanoTransactionsMetadataSetProofMessagePart] (test-only).
+        fn dummy() -> Self {
+            CardanoTransactionsMetadataSetProof::dummy().try_into().unwrap()
+        }
+    }
+
     impl Dummy for CardanoTransactionsSetProofMessagePart {
         /// Return a dummy [CardanoTransactionsSetProofMessagePart] (test-only).
         fn dummy() -> Self {
@@ -331,6 +385,70 @@ mod messages {
         }
     }
 
+    impl Dummy for CardanoBlocksSnapshotMessage {
+        /// Return a dummy [CardanoBlocksSnapshotMessage] (test-only).
+        fn dummy() -> Self {
+            Self {
+                merkle_root: "mkroot-123".to_string(),
+                epoch: Epoch(10),
+                block_number: BlockNumber(100),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+
+    impl Dummy for CardanoBlocksSnapshotListItemMessage {
+        /// Return a dummy [CardanoBlocksSnapshotListItemMessage] (test-only).
+        fn dummy() -> Self {
+            Self {
+                merkle_root: "mkroot-123".to_string(),
+                epoch: Epoch(10),
+                block_number: BlockNumber(100),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+
+    impl Dummy for CardanoTransactionsMetadataSnapshotMessage {
+        /// Return a dummy [CardanoTransactionsMetadataSnapshotMessage] (test-only).
+        fn dummy() -> Self {
+            Self {
+                merkle_root: "mkroot-123".to_string(),
+                epoch: Epoch(10),
+                block_number: BlockNumber(100),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+
+    impl Dummy for CardanoTransactionsMetadataSnapshotListItemMessage {
+        /// Return a dummy [CardanoTransactionsMetadataSnapshotListItemMessage] (test-only).
+        fn dummy() -> Self {
+            Self {
+                merkle_root: "mkroot-123".to_string(),
+                epoch: Epoch(10),
+                block_number: BlockNumber(100),
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+
     impl Dummy for CertificateMessage {
         /// Return a dummy [CertificateMessage] (test-only).
         fn dummy() -> Self {
@@ -568,7 +686,8 @@ mod messages {
 
 mod signable_builder {
     use crate::entities::{
-        CardanoDbBeacon, CardanoStakeDistribution, CardanoTransactionsSnapshot, Epoch,
+        CardanoBlocksSnapshot, CardanoDbBeacon, CardanoStakeDistribution,
+        CardanoTransactionsMetadataSnapshot, CardanoTransactionsSnapshot, Epoch,
         MithrilStakeDistribution, SignedEntityType, Snapshot,
     };
     use crate::signable_builder::SignedEntity;
@@ -623,6 +742,44 @@ mod signable_builder {
         }
     }
 
+    impl Dummy for SignedEntity<CardanoBlocksSnapshot> {
+        /// Create a dummy [SignedEntity] for [CardanoBlocksSnapshot] entity
+        fn dummy() -> Self {
+            let block_number = crate::entities::BlockNumber(50);
+            SignedEntity {
+                signed_entity_id: "snapshot-id-123".to_string(),
+                signed_entity_type: SignedEntityType::CardanoBlocks(Epoch(5), block_number),
+                certificate_id: "certificate-hash-123".to_string(),
+                artifact: CardanoBlocksSnapshot::new("mkroot123".to_string(), block_number),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+
+    impl Dummy for SignedEntity<CardanoTransactionsMetadataSnapshot> {
+        /// Create a dummy [SignedEntity] for [CardanoTransactionsMetadataSnapshot] entity
+        fn dummy() -> Self {
+            let block_number = crate::entities::BlockNumber(50);
+            SignedEntity {
+                signed_entity_id: "snapshot-id-123".to_string(),
+                signed_entity_type: SignedEntityType::CardanoTransactionsMetadata(
+                    Epoch(5),
+                    block_number,
+                ),
+                certificate_id: "certificate-hash-123".to_string(),
+                artifact: CardanoTransactionsMetadataSnapshot::new(
+                    "mkroot123".to_string(),
+                    block_number,
+                ),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+
     impl Dummy for SignedEntity<CardanoStakeDistribution> {
         /// Create a dummy [SignedEntity] for [CardanoStakeDistribution] entity
         fn dummy() -> Self {
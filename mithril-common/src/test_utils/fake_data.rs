@@ -229,6 +229,29 @@ pub fn cardano_transactions_snapshot(total: u64) -> Vec<entities::CardanoTransac
         .collect()
 }
 
+/// Fake Cardano Blocks
+pub fn cardano_blocks_snapshot(total: u64) -> Vec<entities::CardanoBlocksSnapshot> {
+    (1..total + 1)
+        .map(|idx| {
+            entities::CardanoBlocksSnapshot::new(format!("merkleroot-{idx}"), BlockNumber(idx))
+        })
+        .collect()
+}
+
+/// Fake Cardano Transactions Metadata
+pub fn cardano_transactions_metadata_snapshot(
+    total: u64,
+) -> Vec<entities::CardanoTransactionsMetadataSnapshot> {
+    (1..total + 1)
+        .map(|idx| {
+            entities::CardanoTransactionsMetadataSnapshot::new(
+                format!("merkleroot-{idx}"),
+                BlockNumber(idx),
+            )
+        })
+        .collect()
+}
+
 /// Fake transaction hashes that have valid length & characters
 pub const fn transaction_hashes<'a>() -> [&'a str; 5] {
     [
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use anyhow::{Context, anyhow};
+use clap::{Parser, Subcommand};
+use slog::{Logger, debug};
+
+use mithril_common::StdResult;
+use mithril_era::{EraReader, EraReadinessReport};
+
+use crate::{Configuration, MetricsService, dependency_injection::DependenciesBuilder};
+
+/// Era tools
+#[derive(Parser, Debug, Clone)]
+pub struct EraCommand {
+    /// commands
+    #[clap(subcommand)]
+    pub era_subcommand: EraSubCommand,
+}
+
+impl EraCommand {
+    /// Execute the era command
+    pub async fn execute(&self, config: &Configuration, root_logger: Logger) -> StdResult<()> {
+        self.era_subcommand.execute(config, root_logger).await
+    }
+}
+
+/// Era subcommands
+#[derive(Debug, Clone, Subcommand)]
+pub enum EraSubCommand {
+    /// Report whether this signer is ready for the upcoming era transition
+    Readiness(ReadinessCommand),
+}
+
+impl EraSubCommand {
+    /// Execute the era subcommand
+    pub async fn execute(&self, config: &Configuration, root_logger: Logger) -> StdResult<()> {
+        match self {
+            Self::Readiness(cmd) => cmd.execute(config, root_logger).await,
+        }
+    }
+}
+
+/// Readiness command
+#[derive(Parser, Debug, Clone)]
+pub struct ReadinessCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+}
+
+impl ReadinessCommand {
+    /// Execute the readiness command
+    pub async fn execute(&self, config: &Configuration, root_logger: Logger) -> StdResult<()> {
+        debug!(root_logger, "ERA READINESS command"; "config" => format!("{config:?}"));
+        let services = DependenciesBuilder::new(config, root_logger.clone());
+        let metrics_service = Arc::new(MetricsService::new(root_logger.clone())?);
+        let chain_observer = services
+            .build_chain_observer(metrics_service)
+            .with_context(|| "Dependencies Builder can not build chain observer")?;
+        let era_reader = EraReader::new(config.build_era_reader_adapter(chain_observer.clone())?);
+
+        let current_epoch = chain_observer
+            .get_current_epoch()
+            .await
+            .with_context(|| "Chain observer can not retrieve current epoch")?
+            .ok_or(anyhow!("Chain observer did not return a current epoch"))?;
+        let era_epoch_token = era_reader
+            .read_era_epoch_token(current_epoch)
+            .await
+            .with_context(|| "Era reader can not read era epoch token")?;
+        let report = EraReadinessReport::from_era_epoch_token(&era_epoch_token);
+
+        if self.json {
+            println!("{}", serde_json::to_string(&report)?);
+        } else {
+            println!("{report}");
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
 mod database_command;
+mod era_command;
 
 pub use database_command::*;
+pub use era_command::*;
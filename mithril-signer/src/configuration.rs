@@ -2,14 +2,15 @@ use anyhow::Context;
 use config::{ConfigError, Map, Source, Value};
 use mithril_doc::{Documenter, DocumenterDefault, StructDoc};
 use serde::{Deserialize, Serialize};
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
-use mithril_cardano_node_chain::chain_observer::ChainObserver;
+use mithril_cardano_node_chain::chain_observer::{ChainObserver, ChainObserverRetryPolicy};
+use mithril_cardano_node_chain::chain_scanner::RollbackPolicy;
 use mithril_cli_helper::register_config_value;
 use mithril_common::{
     CardanoNetwork, StdResult,
     crypto_helper::tests_setup,
-    entities::{BlockNumber, PartyId},
+    entities::{BlockNumber, CardanoTransactionsLeafFormat, PartyId},
 };
 use mithril_era::{
     EraReaderAdapter,
@@ -43,6 +44,18 @@ pub struct Configuration {
     #[example = "`/ipc/dmq.socket`"]
     pub dmq_node_socket_path: Option<PathBuf>,
 
+    /// Ogmios endpoint, if set the chain observer reads the chain data from Ogmios instead of the
+    /// Cardano node socket
+    #[example = "`ws://localhost:1337`"]
+    pub ogmios_endpoint: Option<String>,
+
+    /// Number of attempts of a failed chain observer query
+    pub chain_observer_retry_attempts: u8,
+
+    /// Delay (in milliseconds) before retrying a failed chain observer query, doubled after each
+    /// failed attempt
+    pub chain_observer_retry_initial_delay_ms: u64,
+
     /// Cardano network
     #[example = "`mainnet` or `preprod` or `devnet`"]
     pub network: String,
@@ -101,6 +114,15 @@ pub struct Configuration {
     /// Will be ignored if set in conjunction with `disable_digests_cache`.
     pub reset_digests_cache: bool,
 
+    /// Path of a SQLite immutables digests cache that can be shared with the other Mithril nodes
+    /// reading the same Cardano node database, such as an aggregator running on the same host.
+    ///
+    /// If set, the digests are looked up first in this cache and copied to the JSON immutables
+    /// digests cache, the computed digests are written to both. Resetting the digests cache never
+    /// resets this shared cache.
+    #[example = "`/var/cache/mithril/immutables_digests.sqlite3`"]
+    pub shared_digests_cache_path: Option<PathBuf>,
+
     /// Era reader adapter type
     pub era_reader_adapter_type: EraReaderAdapterType,
 
@@ -134,6 +156,26 @@ pub struct Configuration {
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: usize,
 
+    /// Policy applied when a rollback deeper than the
+    /// [network_security_parameter][Self::network_security_parameter] is received while
+    /// importing transactions (`halt`, `alert` or `reimport`).
+    pub cardano_transactions_rollback_policy: RollbackPolicy,
+
+    /// Format of the leaves of the Cardano transactions Merkle trees (`transaction_hash` or
+    /// `block_inclusion`), it must be the same for all the nodes of the network.
+    pub cardano_transactions_leaf_format: CardanoTransactionsLeafFormat,
+
+    /// If set, the signer captures the metadata of the Cardano transactions when scanning the
+    /// chain, which is required to sign the Cardano transactions metadata `[default: false]`.
+    pub enable_transactions_metadata_capture: bool,
+
+    /// Genesis verification key used to verify the certificate chain of the aggregator Cardano
+    /// transactions snapshot the Cardano transactions store is bootstrapped from.
+    ///
+    /// If set, an empty Cardano transactions store is bootstrapped from the block range roots of
+    /// the latest snapshot certified by the aggregator instead of scanning the whole chain.
+    pub cardano_transactions_bootstrap_genesis_verification_key: Option<String>,
+
     /// Preloading refresh interval in seconds
     pub preloading_refresh_interval_in_seconds: u64,
 
@@ -154,6 +196,9 @@ impl Configuration {
             cardano_cli_path: PathBuf::new(),
             cardano_node_socket_path: PathBuf::new(),
             dmq_node_socket_path: None,
+            ogmios_endpoint: None,
+            chain_observer_retry_attempts: 1,
+            chain_observer_retry_initial_delay_ms: 0,
             db_directory: PathBuf::new(),
             network: "devnet".to_string(),
             network_magic: Some(42),
@@ -169,6 +214,7 @@ impl Configuration {
                 .map(|dir| dir.join("opcert.cert")),
             disable_digests_cache: false,
             reset_digests_cache: false,
+            shared_digests_cache_path: None,
             era_reader_adapter_type: EraReaderAdapterType::Bootstrap,
             era_reader_adapter_params: None,
             enable_metrics_server: true,
@@ -178,6 +224,10 @@ impl Configuration {
             enable_transaction_pruning: false,
             transactions_import_block_chunk_size: BlockNumber(1000),
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 1000,
+            cardano_transactions_rollback_policy: RollbackPolicy::Alert,
+            cardano_transactions_leaf_format: CardanoTransactionsLeafFormat::TransactionHash,
+            enable_transactions_metadata_capture: false,
+            cardano_transactions_bootstrap_genesis_verification_key: None,
             preloading_refresh_interval_in_seconds: 60,
             signature_publisher_config: SignaturePublisherConfig {
                 retry_attempts: 1,
@@ -197,6 +247,16 @@ impl Configuration {
         })
     }
 
+    /// Return the retry policy of the chain observer queries from the configuration.
+    pub fn get_chain_observer_retry_policy(&self) -> ChainObserverRetryPolicy {
+        ChainObserverRetryPolicy {
+            attempts: self.chain_observer_retry_attempts,
+            initial_delay_between_attempts: Duration::from_millis(
+                self.chain_observer_retry_initial_delay_ms,
+            ),
+        }
+    }
+
     /// Create the SQL store directory if not exist and return the path of the
     /// SQLite3 file.
     pub fn get_sqlite_file(&self, sqlite_file_name: &str) -> StdResult<PathBuf> {
@@ -239,6 +299,12 @@ pub struct DefaultConfiguration {
     /// Era reader adapter type
     pub era_reader_adapter_type: String,
 
+    /// Number of attempts of a failed chain observer query
+    pub chain_observer_retry_attempts: u8,
+
+    /// Delay (in milliseconds) before retrying a failed chain observer query
+    pub chain_observer_retry_initial_delay_ms: u64,
+
     /// Metrics HTTP server IP.
     pub metrics_server_ip: String,
 
@@ -259,6 +325,15 @@ pub struct DefaultConfiguration {
 
     /// The maximum number of roll forwards during a poll of the block streamer when importing transactions.
     pub cardano_transactions_block_streamer_max_roll_forwards_per_poll: u32,
+
+    /// Policy applied when a rollback deeper than the network security parameter is received
+    pub cardano_transactions_rollback_policy: String,
+
+    /// Format of the leaves of the Cardano transactions Merkle trees
+    pub cardano_transactions_leaf_format: String,
+
+    /// Enable the capture of the Cardano transactions metadata
+    pub enable_transactions_metadata_capture: bool,
 }
 
 impl DefaultConfiguration {
@@ -271,6 +346,8 @@ impl Default for DefaultConfiguration {
     fn default() -> Self {
         Self {
             era_reader_adapter_type: "bootstrap".to_string(),
+            chain_observer_retry_attempts: 3,
+            chain_observer_retry_initial_delay_ms: 1000,
             metrics_server_ip: "0.0.0.0".to_string(),
             metrics_server_port: 9090,
             network_security_parameter: 2160, // 2160 is the mainnet value
@@ -278,6 +355,9 @@ impl Default for DefaultConfiguration {
             enable_transaction_pruning: true,
             transactions_import_block_chunk_size: 1500,
             cardano_transactions_block_streamer_max_roll_forwards_per_poll: 10000,
+            cardano_transactions_rollback_policy: "alert".to_string(),
+            cardano_transactions_leaf_format: "transaction_hash".to_string(),
+            enable_transactions_metadata_capture: false,
         }
     }
 }
@@ -293,6 +373,12 @@ impl Source for DefaultConfiguration {
         let myself = self.clone();
 
         register_config_value!(result, &namespace, myself.era_reader_adapter_type);
+        register_config_value!(result, &namespace, myself.chain_observer_retry_attempts);
+        register_config_value!(
+            result,
+            &namespace,
+            myself.chain_observer_retry_initial_delay_ms
+        );
         register_config_value!(result, &namespace, myself.metrics_server_ip);
         register_config_value!(result, &namespace, myself.metrics_server_port);
         register_config_value!(result, &namespace, myself.network_security_parameter);
@@ -308,6 +394,17 @@ impl Source for DefaultConfiguration {
             &namespace,
             myself.cardano_transactions_block_streamer_max_roll_forwards_per_poll
         );
+        register_config_value!(
+            result,
+            &namespace,
+            myself.cardano_transactions_rollback_policy
+        );
+        register_config_value!(result, &namespace, myself.cardano_transactions_leaf_format);
+        register_config_value!(
+            result,
+            &namespace,
+            myself.enable_transactions_metadata_capture
+        );
 
         Ok(result)
     }
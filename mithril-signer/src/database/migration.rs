@@ -126,6 +126,38 @@ alter table new_protocol_initializer rename to protocol_initializer;
             r#"
 insert into signed_entity_type (signed_entity_type_id, name) 
     values  (4, 'Cardano Database');
+"#,
+        ),
+        // Migration 8
+        // Add the `signed_entity_type` record for 'CardanoBlocks'
+        SqlMigration::new(
+            8,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (5, 'Cardano Blocks');
+"#,
+        ),
+        // Migration 9
+        // Add the `transactions_import_checkpoint` table
+        SqlMigration::new(
+            9,
+            r#"
+create table transactions_import_checkpoint (
+    block_number  integer   not null,
+    slot_number   integer   not null,
+    block_hash    text      not null,
+    created_at    text      not null,
+    primary key (block_number)
+);
+"#,
+        ),
+        // Migration 10
+        // Add the `signed_entity_type` record for 'CardanoTransactionsMetadata'
+        SqlMigration::new(
+            10,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (6, 'Cardano Transactions Metadata');
 "#,
         ),
     ]
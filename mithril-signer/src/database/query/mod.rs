@@ -3,7 +3,9 @@
 mod protocol_initializer;
 mod signed_beacon;
 mod stake_pool;
+mod transactions_import_checkpoint;
 
 pub use protocol_initializer::*;
 pub use signed_beacon::*;
 pub use stake_pool::*;
+pub use transactions_import_checkpoint::*;
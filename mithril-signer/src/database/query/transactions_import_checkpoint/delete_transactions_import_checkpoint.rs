@@ -0,0 +1,132 @@
+use sqlite::Value;
+
+use mithril_common::entities::BlockNumber;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::TransactionsImportCheckpointRecord;
+
+/// Query to delete old [TransactionsImportCheckpointRecord] from the sqlite database
+pub struct DeleteTransactionsImportCheckpointQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteTransactionsImportCheckpointQuery {
+    type Entity = TransactionsImportCheckpointRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:transactions_import_checkpoint:}",
+            "transactions_import_checkpoint",
+        )]));
+
+        format!(
+            "delete from transactions_import_checkpoint where {condition} returning {projection}"
+        )
+    }
+}
+
+impl DeleteTransactionsImportCheckpointQuery {
+    /// Create the SQL query to delete the checkpoints below the given block number.
+    pub fn below_block_number_threshold(block_number_threshold: BlockNumber) -> Self {
+        let condition = WhereCondition::new(
+            "block_number < ?*",
+            vec![Value::Integer(*block_number_threshold as i64)],
+        );
+
+        Self { condition }
+    }
+
+    /// Create the SQL query to delete the checkpoints above the given block number.
+    pub fn above_block_number_threshold(block_number_threshold: BlockNumber) -> Self {
+        let condition = WhereCondition::new(
+            "block_number > ?*",
+            vec![Value::Integer(*block_number_threshold as i64)],
+        );
+
+        Self { condition }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{ChainPoint, SlotNumber};
+    use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use crate::database::query::{
+        GetTransactionsImportCheckpointQuery, InsertOrReplaceTransactionsImportCheckpointQuery,
+    };
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    fn insert_checkpoints(connection: &SqliteConnection, block_numbers: &[u64]) {
+        for block_number in block_numbers {
+            connection
+                .apply(InsertOrReplaceTransactionsImportCheckpointQuery::one(
+                    TransactionsImportCheckpointRecord::new(
+                        ChainPoint::new(
+                            SlotNumber(block_number * 10),
+                            BlockNumber(*block_number),
+                            format!("block_hash-{block_number}"),
+                        ),
+                        Default::default(),
+                    ),
+                ))
+                .unwrap();
+        }
+    }
+
+    fn remaining_block_numbers(connection: &SqliteConnection) -> Vec<BlockNumber> {
+        connection
+            .fetch(GetTransactionsImportCheckpointQuery::all())
+            .unwrap()
+            .map(|r| r.block_number)
+            .collect()
+    }
+
+    #[test]
+    fn test_delete_below_block_number_threshold() {
+        let connection = main_db_connection().unwrap();
+        insert_checkpoints(&connection, &[5, 10, 15]);
+
+        let deleted: Vec<TransactionsImportCheckpointRecord> = connection
+            .fetch_collect(
+                DeleteTransactionsImportCheckpointQuery::below_block_number_threshold(BlockNumber(
+                    10,
+                )),
+            )
+            .unwrap();
+
+        assert_eq!(1, deleted.len());
+        assert_eq!(
+            vec![BlockNumber(15), BlockNumber(10)],
+            remaining_block_numbers(&connection)
+        );
+    }
+
+    #[test]
+    fn test_delete_above_block_number_threshold() {
+        let connection = main_db_connection().unwrap();
+        insert_checkpoints(&connection, &[5, 10, 15]);
+
+        let deleted: Vec<TransactionsImportCheckpointRecord> = connection
+            .fetch_collect(
+                DeleteTransactionsImportCheckpointQuery::above_block_number_threshold(BlockNumber(
+                    10,
+                )),
+            )
+            .unwrap();
+
+        assert_eq!(1, deleted.len());
+        assert_eq!(
+            vec![BlockNumber(10), BlockNumber(5)],
+            remaining_block_numbers(&connection)
+        );
+    }
+}
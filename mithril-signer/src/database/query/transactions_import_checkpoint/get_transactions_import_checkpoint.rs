@@ -0,0 +1,75 @@
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::TransactionsImportCheckpointRecord;
+
+/// Simple queries to retrieve [TransactionsImportCheckpointRecord] from the sqlite database.
+pub struct GetTransactionsImportCheckpointQuery {
+    condition: WhereCondition,
+}
+
+impl GetTransactionsImportCheckpointQuery {
+    /// Get all checkpoints, the highest block number first.
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+}
+
+impl Query for GetTransactionsImportCheckpointQuery {
+    type Entity = TransactionsImportCheckpointRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[(
+            "{:transactions_import_checkpoint:}",
+            "transactions_import_checkpoint",
+        )]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+        format!(
+            "select {projection} from transactions_import_checkpoint where {condition} order by block_number desc"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{BlockNumber, ChainPoint, SlotNumber};
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::query::InsertOrReplaceTransactionsImportCheckpointQuery;
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn test_get_all_ordered_by_highest_block_number_first() {
+        let connection = main_db_connection().unwrap();
+        for block_number in [5, 20, 10] {
+            connection
+                .apply(InsertOrReplaceTransactionsImportCheckpointQuery::one(
+                    TransactionsImportCheckpointRecord::new(
+                        ChainPoint::new(
+                            SlotNumber(block_number * 10),
+                            BlockNumber(block_number),
+                            format!("block_hash-{block_number}"),
+                        ),
+                        Default::default(),
+                    ),
+                ))
+                .unwrap();
+        }
+
+        let records: Vec<TransactionsImportCheckpointRecord> = connection
+            .fetch_collect(GetTransactionsImportCheckpointQuery::all())
+            .unwrap();
+
+        assert_eq!(
+            vec![BlockNumber(20), BlockNumber(10), BlockNumber(5)],
+            records.into_iter().map(|r| r.block_number).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use sqlite::Value;
+
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::TransactionsImportCheckpointRecord;
+
+/// Query to insert or replace [TransactionsImportCheckpointRecord] in the sqlite database
+pub struct InsertOrReplaceTransactionsImportCheckpointQuery {
+    condition: WhereCondition,
+}
+
+impl InsertOrReplaceTransactionsImportCheckpointQuery {
+    pub fn one(record: TransactionsImportCheckpointRecord) -> Self {
+        let condition = WhereCondition::new(
+            "(block_number, slot_number, block_hash, created_at) values (?*, ?*, ?*, ?*)",
+            vec![
+                Value::Integer(*record.block_number as i64),
+                Value::Integer(*record.slot_number as i64),
+                Value::String(record.block_hash),
+                Value::String(record.created_at.to_rfc3339()),
+            ],
+        );
+
+        Self { condition }
+    }
+}
+
+impl Query for InsertOrReplaceTransactionsImportCheckpointQuery {
+    type Entity = TransactionsImportCheckpointRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let projection = Self::Entity::get_projection().expand(SourceAlias::new(&[(
+            "{:transactions_import_checkpoint:}",
+            "transactions_import_checkpoint",
+        )]));
+
+        format!(
+            "insert or replace into transactions_import_checkpoint {condition} returning {projection}"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use mithril_common::entities::{BlockNumber, ChainPoint, SlotNumber};
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn insert_and_replace_record() {
+        let connection = main_db_connection().unwrap();
+        let record = TransactionsImportCheckpointRecord::new(
+            ChainPoint::new(SlotNumber(100), BlockNumber(10), "block_hash-10"),
+            DateTime::<Utc>::default(),
+        );
+
+        let inserted_record = connection
+            .fetch_first(InsertOrReplaceTransactionsImportCheckpointQuery::one(
+                record.clone(),
+            ))
+            .unwrap();
+        assert_eq!(Some(record.clone()), inserted_record);
+
+        let replacing_record = TransactionsImportCheckpointRecord {
+            block_hash: "block_hash-10-bis".to_string(),
+            ..record
+        };
+        let replaced_record = connection
+            .fetch_first(InsertOrReplaceTransactionsImportCheckpointQuery::one(
+                replacing_record.clone(),
+            ))
+            .unwrap();
+        assert_eq!(Some(replacing_record), replaced_record);
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_transactions_import_checkpoint;
+mod get_transactions_import_checkpoint;
+mod insert_or_replace_transactions_import_checkpoint;
+
+pub use delete_transactions_import_checkpoint::*;
+pub use get_transactions_import_checkpoint::*;
+pub use insert_or_replace_transactions_import_checkpoint::*;
@@ -3,7 +3,9 @@
 mod protocol_initializer_record;
 mod signed_beacon_record;
 mod stake_pool;
+mod transactions_import_checkpoint_record;
 
 pub use protocol_initializer_record::*;
 pub use signed_beacon_record::*;
 pub use stake_pool::*;
+pub use transactions_import_checkpoint_record::*;
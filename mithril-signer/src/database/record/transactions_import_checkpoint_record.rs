@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use sqlite::Row;
+
+use mithril_common::entities::{BlockNumber, ChainPoint, SlotNumber};
+use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// Database record of the last chain point fully imported by the transactions importer
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionsImportCheckpointRecord {
+    /// Block number of the checkpoint
+    pub block_number: BlockNumber,
+
+    /// Slot number of the checkpoint
+    pub slot_number: SlotNumber,
+
+    /// Hash of the block of the checkpoint
+    pub block_hash: String,
+
+    /// Datetime when the checkpoint was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl TransactionsImportCheckpointRecord {
+    /// Create a new record for the given chain point
+    pub fn new(chain_point: ChainPoint, created_at: DateTime<Utc>) -> Self {
+        Self {
+            block_number: chain_point.block_number,
+            slot_number: chain_point.slot_number,
+            block_hash: chain_point.block_hash,
+            created_at,
+        }
+    }
+}
+
+impl From<TransactionsImportCheckpointRecord> for ChainPoint {
+    fn from(record: TransactionsImportCheckpointRecord) -> Self {
+        ChainPoint::new(record.slot_number, record.block_number, record.block_hash)
+    }
+}
+
+impl SqLiteEntity for TransactionsImportCheckpointRecord {
+    fn hydrate(row: Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let block_number = row.read::<i64, _>(0);
+        let slot_number = row.read::<i64, _>(1);
+        let block_hash = row.read::<&str, _>(2).to_string();
+        let created_at = &row.read::<&str, _>(3);
+
+        Ok(Self {
+            block_number: BlockNumber(u64::try_from(block_number).map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Could not cast i64 ({block_number}) to u64. Error: '{e}'"
+                ))
+            })?),
+            slot_number: SlotNumber(u64::try_from(slot_number).map_err(|e| {
+                HydrationError::InvalidData(format!(
+                    "Could not cast i64 ({slot_number}) to u64. Error: '{e}'"
+                ))
+            })?),
+            block_hash,
+            created_at: DateTime::parse_from_rfc3339(created_at)
+                .map_err(|e| {
+                    HydrationError::InvalidData(format!(
+                        "Could not turn string '{created_at}' to rfc3339 Datetime. Error: {e}"
+                    ))
+                })?
+                .with_timezone(&Utc),
+        })
+    }
+
+    fn get_projection() -> Projection {
+        let mut projection = Projection::default();
+        projection.add_field(
+            "block_number",
+            "{:transactions_import_checkpoint:}.block_number",
+            "integer",
+        );
+        projection.add_field(
+            "slot_number",
+            "{:transactions_import_checkpoint:}.slot_number",
+            "integer",
+        );
+        projection.add_field(
+            "block_hash",
+            "{:transactions_import_checkpoint:}.block_hash",
+            "text",
+        );
+        projection.add_field(
+            "created_at",
+            "{:transactions_import_checkpoint:}.created_at",
+            "text",
+        );
+
+        projection
+    }
+}
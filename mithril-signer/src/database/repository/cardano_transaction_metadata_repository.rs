@@ -0,0 +1,62 @@
+use std::ops::Range;
+
+use async_trait::async_trait;
+
+use mithril_common::StdResult;
+use mithril_common::crypto_helper::MKTreeNode;
+use mithril_common::entities::{
+    BlockNumber, BlockRange, CardanoTransactionMetadata, ChainPoint, SlotNumber,
+};
+use mithril_persistence::database::repository::CardanoTransactionMetadataRepository;
+
+use crate::services::TransactionMetadataStore;
+
+#[async_trait]
+impl TransactionMetadataStore for CardanoTransactionMetadataRepository {
+    async fn get_highest_beacon(&self) -> StdResult<Option<ChainPoint>> {
+        self.get_highest_scanned_chain_point().await
+    }
+
+    async fn store_scanned_chain_point(&self, chain_point: ChainPoint) -> StdResult<()> {
+        self.store_scanned_chain_point(chain_point).await
+    }
+
+    async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>> {
+        let record = self.retrieve_highest_block_range_root().await?;
+        Ok(record.map(|record| record.range))
+    }
+
+    async fn store_transactions_metadata(
+        &self,
+        transactions_metadata: Vec<CardanoTransactionMetadata>,
+    ) -> StdResult<()> {
+        self.store_transactions_metadata(transactions_metadata).await
+    }
+
+    async fn get_transactions_metadata_in_range(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<CardanoTransactionMetadata>> {
+        self.get_transactions_metadata_in_range_blocks(range)
+            .await
+            .map(|v| v.into_iter().map(|record| record.into()).collect())
+    }
+
+    async fn store_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()> {
+        if !block_ranges.is_empty() {
+            self.create_block_range_roots(block_ranges).await?;
+        }
+        Ok(())
+    }
+
+    async fn remove_rolled_back_transactions_metadata_and_block_range(
+        &self,
+        slot_number: SlotNumber,
+    ) -> StdResult<()> {
+        self.remove_rolled_back_transactions_metadata_and_block_range_by_slot_number(slot_number)
+            .await
+    }
+}
@@ -1,10 +1,14 @@
 //! Signer related database repositories
 
+mod cardano_block_repository;
+mod cardano_transaction_metadata_repository;
 mod cardano_transaction_repository;
 mod protocol_initializer_repository;
 mod signed_beacon_repository;
 mod stake_pool_store;
+mod transactions_import_checkpoint_repository;
 
 pub use protocol_initializer_repository::*;
 pub use signed_beacon_repository::*;
 pub use stake_pool_store::*;
+pub use transactions_import_checkpoint_repository::*;
@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+use mithril_common::StdResult;
+use mithril_common::entities::ChainPoint;
+use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+
+use crate::database::query::{
+    DeleteTransactionsImportCheckpointQuery, GetTransactionsImportCheckpointQuery,
+    InsertOrReplaceTransactionsImportCheckpointQuery,
+};
+use crate::database::record::TransactionsImportCheckpointRecord;
+use crate::services::TransactionsImportCheckpointStore;
+
+/// A [TransactionsImportCheckpointStore] implementation using SQLite.
+pub struct TransactionsImportCheckpointRepository {
+    connection: Arc<SqliteConnection>,
+}
+
+impl TransactionsImportCheckpointRepository {
+    /// Create a new instance of the `TransactionsImportCheckpointRepository`.
+    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl TransactionsImportCheckpointStore for TransactionsImportCheckpointRepository {
+    async fn get_last_checkpoint(&self) -> StdResult<Option<ChainPoint>> {
+        let record = self
+            .connection
+            .fetch_first(GetTransactionsImportCheckpointQuery::all())?;
+
+        Ok(record.map(|r| r.into()))
+    }
+
+    async fn save_checkpoint(&self, chain_point: ChainPoint) -> StdResult<()> {
+        let block_number = chain_point.block_number;
+        let record = TransactionsImportCheckpointRecord::new(chain_point, Utc::now());
+
+        let transaction = self.connection.begin_transaction()?;
+        self.connection
+            .apply(InsertOrReplaceTransactionsImportCheckpointQuery::one(
+                record,
+            ))?;
+        self.connection.apply(
+            DeleteTransactionsImportCheckpointQuery::below_block_number_threshold(block_number),
+        )?;
+        self.connection.apply(
+            DeleteTransactionsImportCheckpointQuery::above_block_number_threshold(block_number),
+        )?;
+        transaction.commit()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{BlockNumber, SlotNumber};
+
+    use crate::database::record::TransactionsImportCheckpointRecord;
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    fn chain_point(block_number: u64) -> ChainPoint {
+        ChainPoint::new(
+            SlotNumber(block_number * 10),
+            BlockNumber(block_number),
+            format!("block_hash-{block_number}"),
+        )
+    }
+
+    #[tokio::test]
+    async fn get_last_checkpoint_when_none_saved() {
+        let repository =
+            TransactionsImportCheckpointRepository::new(Arc::new(main_db_connection().unwrap()));
+
+        let checkpoint = repository.get_last_checkpoint().await.unwrap();
+
+        assert_eq!(None, checkpoint);
+    }
+
+    #[tokio::test]
+    async fn save_checkpoint_replaces_previous_one() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        let repository = TransactionsImportCheckpointRepository::new(connection.clone());
+
+        repository.save_checkpoint(chain_point(10)).await.unwrap();
+        repository.save_checkpoint(chain_point(20)).await.unwrap();
+
+        assert_eq!(
+            Some(chain_point(20)),
+            repository.get_last_checkpoint().await.unwrap()
+        );
+        let records: Vec<TransactionsImportCheckpointRecord> = connection
+            .fetch_collect(GetTransactionsImportCheckpointQuery::all())
+            .unwrap();
+        assert_eq!(1, records.len());
+    }
+
+    #[tokio::test]
+    async fn save_checkpoint_lower_than_previous_one_after_a_rollback() {
+        let repository =
+            TransactionsImportCheckpointRepository::new(Arc::new(main_db_connection().unwrap()));
+
+        repository.save_checkpoint(chain_point(20)).await.unwrap();
+        repository.save_checkpoint(chain_point(15)).await.unwrap();
+
+        assert_eq!(
+            Some(chain_point(15)),
+            repository.get_last_checkpoint().await.unwrap()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::fs;
 use std::sync::Arc;
 use std::time::Duration;
@@ -9,27 +10,32 @@ use tokio::sync::{Mutex, RwLock};
 use mithril_cardano_node_chain::{
     chain_observer::{CardanoCliRunner, ChainObserver, ChainObserverBuilder, ChainObserverType},
     chain_reader::PallasChainReader,
-    chain_scanner::CardanoBlockScanner,
+    chain_scanner::{BlockScanner, CardanoBlockScanner, ChainRollbackHandler},
 };
 use mithril_cardano_node_internal_database::{
-    ImmutableFileObserver, ImmutableFileSystemObserver,
+    IMMUTABLE_DIR, ImmutableFileObserver, ImmutableFileSystemObserver,
     digesters::CardanoImmutableDigester,
     digesters::cache::{
         ImmutableFileDigestCacheProvider, JsonImmutableFileDigestCacheProviderBuilder,
+        LayeredImmutableFileDigestCacheProvider,
     },
     signable_builder::{CardanoDatabaseSignableBuilder, CardanoImmutableFilesFullSignableBuilder},
 };
 use mithril_common::StdResult;
 use mithril_common::api_version::APIVersionProvider;
+use mithril_common::certificate_chain::MithrilCertificateVerifier;
 use mithril_common::crypto_helper::{
-    KesSigner, KesSignerStandard, OpCert, ProtocolPartyId, SerDeShelleyFileFormat,
+    KesSigner, KesSignerStandard, OpCert, ProtocolGenesisVerificationKey, ProtocolPartyId,
+    SerDeShelleyFileFormat,
 };
+use mithril_common::entities::SignedEntityTypeDiscriminants;
 #[cfg(feature = "future_dmq")]
 use mithril_common::messages::RegisterSignatureMessageDmq;
 use mithril_common::signable_builder::{
-    CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
+    CardanoBlocksSignableBuilder, CardanoStakeDistributionSignableBuilder,
+    CardanoTransactionsMetadataSignableBuilder, CardanoTransactionsSignableBuilder,
     MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
-    SignableBuilderServiceDependencies,
+    SignableBuilderServiceDependencies, TransactionsImporter,
 };
 
 use mithril_era::{EraChecker, EraReader};
@@ -37,7 +43,10 @@ use mithril_signed_entity_lock::SignedEntityTypeLock;
 use mithril_signed_entity_preloader::CardanoTransactionsPreloader;
 use mithril_ticker::{MithrilTickerService, TickerService};
 
-use mithril_persistence::database::repository::CardanoTransactionRepository;
+use mithril_persistence::database::repository::{
+    CardanoBlockRepository, CardanoTransactionMetadataRepository, CardanoTransactionRepository,
+    SqliteImmutableFileDigestCacheProviderBuilder,
+};
 use mithril_persistence::database::{ApplicationNodeType, SqlMigration};
 use mithril_persistence::sqlite::{ConnectionBuilder, SqliteConnection, SqliteConnectionPool};
 
@@ -48,12 +57,15 @@ use crate::dependency_injection::SignerDependencyContainer;
 #[cfg(feature = "future_dmq")]
 use crate::services::SignaturePublisherDmq;
 use crate::services::{
-    AggregatorHTTPClient, CardanoTransactionsImporter,
+    AggregatorCertificateRetriever, AggregatorHTTPClient, AggregatorTransactionsStoreBootstrapper,
+    CardanoBlocksImporter, CardanoTransactionsImporter, CardanoTransactionsMetadataImporter,
     CardanoTransactionsPreloaderActivationSigner, MithrilEpochService, MithrilSingleSigner,
     SignaturePublishRetryPolicy, SignaturePublisherDelayer, SignaturePublisherNoop,
     SignaturePublisherRetrier, SignerCertifierService, SignerSignableSeedBuilder,
     SignerSignedEntityConfigProvider, SignerUpkeepService, TransactionsImporterByChunk,
-    TransactionsImporterWithPruner, TransactionsImporterWithVacuum,
+    TransactionsImporterWithBootstrap, TransactionsImporterWithCheckpoint,
+    TransactionsImporterWithProgress, TransactionsImporterWithPruner,
+    TransactionsImporterWithVacuum, TransactionsStoreBootstrapper,
 };
 use crate::store::MKTreeStoreSqlite;
 use crate::{
@@ -61,16 +73,23 @@ use crate::{
     SQLITE_FILE_CARDANO_TRANSACTION,
 };
 use crate::{
-    database::repository::{ProtocolInitializerRepository, SignedBeaconRepository, StakePoolStore},
+    database::repository::{
+        ProtocolInitializerRepository, SignedBeaconRepository, StakePoolStore,
+        TransactionsImportCheckpointRepository,
+    },
     services::SignaturePublisher,
 };
 
+/// Function building the [ChainObserver] used by the signer.
+type ChainObserverBuilderFn =
+    fn(&Configuration, Arc<MetricsService>, Logger) -> StdResult<Arc<dyn ChainObserver>>;
+
 /// The `DependenciesBuilder` is intended to manage Services instance creation.
 ///
 /// The goal of this is to put all this code out of the way of business code.
 pub struct DependenciesBuilder<'a> {
     config: &'a Configuration,
-    chain_observer_builder: fn(&Configuration) -> StdResult<Arc<dyn ChainObserver>>,
+    chain_observer_builder: ChainObserverBuilderFn,
     immutable_file_observer_builder:
         fn(&Configuration) -> StdResult<Arc<dyn ImmutableFileObserver>>,
     root_logger: Logger,
@@ -79,9 +98,12 @@ pub struct DependenciesBuilder<'a> {
 impl<'a> DependenciesBuilder<'a> {
     /// Create a new `DependenciesBuilder`.
     pub fn new(config: &'a Configuration, root_logger: Logger) -> Self {
-        let chain_observer_builder: fn(&Configuration) -> StdResult<Arc<dyn ChainObserver>> =
-            |config: &Configuration| {
-                let chain_observer_type = ChainObserverType::Pallas;
+        let chain_observer_builder: ChainObserverBuilderFn =
+            |config: &Configuration, metrics_service: Arc<MetricsService>, logger: Logger| {
+                let chain_observer_type = match config.ogmios_endpoint {
+                    Some(_) => ChainObserverType::Ogmios,
+                    None => ChainObserverType::Pallas,
+                };
                 let cardano_cli_path = &config.cardano_cli_path;
                 let cardano_node_socket_path = &config.cardano_node_socket_path;
                 let cardano_network = &config.get_network().with_context(|| {
@@ -98,6 +120,12 @@ impl<'a> DependenciesBuilder<'a> {
                     cardano_node_socket_path,
                     cardano_network,
                     Some(cardano_cli_runner),
+                )
+                .with_ogmios_endpoint(config.ogmios_endpoint.clone())
+                .with_cache_and_retry(
+                    config.get_chain_observer_retry_policy(),
+                    Some(metrics_service),
+                    logger,
                 );
 
                 chain_observer_builder
@@ -140,13 +168,22 @@ impl<'a> DependenciesBuilder<'a> {
     /// Override default chain observer builder.
     pub fn override_chain_observer_builder(
         &mut self,
-        builder: fn(&Configuration) -> StdResult<Arc<dyn ChainObserver>>,
+        builder: ChainObserverBuilderFn,
     ) -> &mut Self {
         self.chain_observer_builder = builder;
 
         self
     }
 
+    /// Signed entity types that can't be signed with the current configuration
+    fn unsupported_discriminants(&self) -> BTreeSet<SignedEntityTypeDiscriminants> {
+        if self.config.enable_transactions_metadata_capture {
+            BTreeSet::new()
+        } else {
+            BTreeSet::from([SignedEntityTypeDiscriminants::CardanoTransactionsMetadata])
+        }
+    }
+
     /// Compute protocol party id
     fn compute_protocol_party_id(&self) -> StdResult<ProtocolPartyId> {
         match &self.config.operational_certificate_path {
@@ -172,16 +209,40 @@ impl<'a> DependenciesBuilder<'a> {
             return Ok(None);
         }
 
+        let json_cache_filename = format!("immutables_digests_{}.json", self.config.network);
         let cache_provider = JsonImmutableFileDigestCacheProviderBuilder::new(
             &self.config.data_stores_directory,
-            &format!("immutables_digests_{}.json", self.config.network),
+            &json_cache_filename,
         )
         .should_reset_digests_cache(self.config.reset_digests_cache)
         .with_logger(self.root_logger())
         .build()
         .await?;
 
-        Ok(Some(Arc::new(cache_provider)))
+        match &self.config.shared_digests_cache_path {
+            Some(shared_digests_cache_path) => {
+                let immutable_dir = self.config.db_directory.join(IMMUTABLE_DIR);
+                let shared_cache_provider = SqliteImmutableFileDigestCacheProviderBuilder::new(
+                    shared_digests_cache_path,
+                    &immutable_dir,
+                )
+                .with_logger(self.root_logger())
+                .build()
+                .await
+                .with_context(
+                    || "Failure occurred when opening the shared immutable file digest cache",
+                )?;
+
+                Ok(Some(Arc::new(
+                    LayeredImmutableFileDigestCacheProvider::new(
+                        Arc::new(cache_provider),
+                        Arc::new(shared_cache_provider),
+                        self.root_logger(),
+                    ),
+                )))
+            }
+            None => Ok(Some(Arc::new(cache_provider))),
+        }
     }
 
     /// Build a SQLite connection.
@@ -201,6 +262,15 @@ impl<'a> DependenciesBuilder<'a> {
         Ok(connection)
     }
 
+    /// Build the chain observer using the configured builder.
+    pub fn build_chain_observer(
+        &self,
+        metrics_service: Arc<MetricsService>,
+    ) -> StdResult<Arc<dyn ChainObserver>> {
+        let builder = self.chain_observer_builder;
+        builder(self.config, metrics_service, self.root_logger())
+    }
+
     /// Build dependencies for the Production environment.
     pub async fn build(&self) -> StdResult<SignerDependencyContainer> {
         if !self.config.data_stores_directory.exists() {
@@ -243,10 +313,8 @@ impl<'a> DependenciesBuilder<'a> {
             sqlite_connection.clone(),
             self.config.store_retention_limit.map(|limit| limit as u64),
         ));
-        let chain_observer = {
-            let builder = self.chain_observer_builder;
-            builder(self.config)?
-        };
+        let metrics_service = Arc::new(MetricsService::new(self.root_logger())?);
+        let chain_observer = self.build_chain_observer(metrics_service.clone())?;
         let ticker_service = {
             let builder = self.immutable_file_observer_builder;
             Arc::new(MithrilTickerService::new(
@@ -286,22 +354,38 @@ impl<'a> DependenciesBuilder<'a> {
         let transaction_store = Arc::new(CardanoTransactionRepository::new(
             sqlite_connection_cardano_transaction_pool.clone(),
         ));
-        let chain_block_reader = PallasChainReader::new(
-            &self.config.cardano_node_socket_path,
-            network,
-            self.root_logger(),
+        // Each importer has its own block scanner, with its own chain reader and rollback
+        // handler, so that the rollbacks are tracked against the blocks scanned by this importer
+        let create_block_scanner = || -> Arc<dyn BlockScanner> {
+            let chain_block_reader = PallasChainReader::new(
+                &self.config.cardano_node_socket_path,
+                network,
+                self.root_logger(),
+            )
+            .with_transactions_metadata_capture(self.config.enable_transactions_metadata_capture);
+            let rollback_handler = ChainRollbackHandler::new(
+                self.config.network_security_parameter,
+                self.config.cardano_transactions_rollback_policy,
+            )
+            .with_metrics_recorder(metrics_service.clone());
+            Arc::new(
+                CardanoBlockScanner::new(
+                    Arc::new(Mutex::new(chain_block_reader)),
+                    self.config
+                        .cardano_transactions_block_streamer_max_roll_forwards_per_poll,
+                    self.root_logger(),
+                )
+                .with_rollback_handler(Arc::new(rollback_handler)),
+            )
+        };
+        let transactions_importer = Arc::new(
+            CardanoTransactionsImporter::new(
+                create_block_scanner(),
+                transaction_store.clone(),
+                self.root_logger(),
+            )
+            .with_leaf_format(self.config.cardano_transactions_leaf_format),
         );
-        let block_scanner = Arc::new(CardanoBlockScanner::new(
-            Arc::new(Mutex::new(chain_block_reader)),
-            self.config
-                .cardano_transactions_block_streamer_max_roll_forwards_per_poll,
-            self.root_logger(),
-        ));
-        let transactions_importer = Arc::new(CardanoTransactionsImporter::new(
-            block_scanner,
-            transaction_store.clone(),
-            self.root_logger(),
-        ));
         // Wrap the transaction importer with decorator to prune the transactions after import
         let transactions_importer = Arc::new(TransactionsImporterWithPruner::new(
             self.config
@@ -311,26 +395,86 @@ impl<'a> DependenciesBuilder<'a> {
             transactions_importer,
             self.root_logger(),
         ));
-        // Wrap the transaction importer with decorator to chunk its workload, so it prunes
-        // transactions after each chunk, reducing the storage footprint
-        let state_machine_transactions_importer = Arc::new(TransactionsImporterByChunk::new(
+        let transactions_import_checkpoint_store = Arc::new(
+            TransactionsImportCheckpointRepository::new(sqlite_connection.clone()),
+        );
+        // Wrap the transaction importer with decorator to save a checkpoint after each import, so
+        // an interrupted import resumes from the last fully imported chunk
+        let transactions_importer = Arc::new(TransactionsImporterWithCheckpoint::new(
+            transactions_import_checkpoint_store.clone(),
             transaction_store.clone(),
-            transactions_importer.clone(),
-            self.config.transactions_import_block_chunk_size,
+            transactions_importer,
             self.root_logger(),
         ));
+        // Wrap the transaction importer with decorator to report the import progress after each chunk
+        let transactions_importer = Arc::new(
+            TransactionsImporterWithProgress::new(
+                transaction_store.clone(),
+                chain_observer.clone(),
+                transactions_importer,
+                self.root_logger(),
+            )
+            .with_progress_recorder(metrics_service.clone()),
+        );
+        // Wrap the transaction importer with decorator to chunk its workload, so it prunes
+        // transactions after each chunk, reducing the storage footprint
+        let transactions_store_bootstrapper: Option<Arc<dyn TransactionsStoreBootstrapper>> =
+            match &self.config.cardano_transactions_bootstrap_genesis_verification_key {
+                Some(genesis_verification_key) => Some(Arc::new(
+                    AggregatorTransactionsStoreBootstrapper::new(
+                        aggregator_client.clone(),
+                        Arc::new(MithrilCertificateVerifier::new(
+                            self.root_logger(),
+                            Arc::new(AggregatorCertificateRetriever::new(
+                                aggregator_client.clone(),
+                            )),
+                        )),
+                        ProtocolGenesisVerificationKey::from_json_hex(genesis_verification_key)
+                            .with_context(|| {
+                                "Could not decode the Cardano transactions bootstrap genesis verification key"
+                            })?,
+                        transaction_store.clone(),
+                        transactions_import_checkpoint_store.clone(),
+                        self.root_logger(),
+                    )
+                    .with_leaf_format(self.config.cardano_transactions_leaf_format),
+                )),
+                None => None,
+            };
+        // Wrap the chunked transaction importers with decorator to bootstrap an empty store from
+        // the latest snapshot certified by the aggregator, it must wrap the chunking so the
+        // bootstrap is not limited to the first chunk
+        let with_bootstrap =
+            |importer: Arc<dyn TransactionsImporter>| -> Arc<dyn TransactionsImporter> {
+                match &transactions_store_bootstrapper {
+                    Some(bootstrapper) => Arc::new(TransactionsImporterWithBootstrap::new(
+                        bootstrapper.clone(),
+                        importer,
+                        self.root_logger(),
+                    )),
+                    None => importer,
+                }
+            };
+        let state_machine_transactions_importer =
+            with_bootstrap(Arc::new(TransactionsImporterByChunk::new(
+                transaction_store.clone(),
+                transactions_importer.clone(),
+                self.config.transactions_import_block_chunk_size,
+                self.root_logger(),
+            )));
         // For the preloader, we want to vacuum the database after each chunk, to reclaim disk space
         // earlier than with just auto_vacuum (that execute only after the end of all import).
-        let preloader_transactions_importer = Arc::new(TransactionsImporterByChunk::new(
-            transaction_store.clone(),
-            Arc::new(TransactionsImporterWithVacuum::new(
-                sqlite_connection_cardano_transaction_pool.clone(),
-                transactions_importer.clone(),
+        let preloader_transactions_importer =
+            with_bootstrap(Arc::new(TransactionsImporterByChunk::new(
+                transaction_store.clone(),
+                Arc::new(TransactionsImporterWithVacuum::new(
+                    sqlite_connection_cardano_transaction_pool.clone(),
+                    transactions_importer.clone(),
+                    self.root_logger(),
+                )),
+                self.config.transactions_import_block_chunk_size,
                 self.root_logger(),
-            )),
-            self.config.transactions_import_block_chunk_size,
-            self.root_logger(),
-        ));
+            )));
         let block_range_root_retriever = transaction_store.clone();
         let cardano_transactions_builder = Arc::new(CardanoTransactionsSignableBuilder::<
             MKTreeStoreSqlite,
@@ -338,6 +482,31 @@ impl<'a> DependenciesBuilder<'a> {
             state_machine_transactions_importer,
             block_range_root_retriever,
         ));
+        let block_store = Arc::new(CardanoBlockRepository::new(
+            sqlite_connection_cardano_transaction_pool.clone(),
+        ));
+        let blocks_importer = Arc::new(CardanoBlocksImporter::new(
+            create_block_scanner(),
+            block_store.clone(),
+            self.root_logger(),
+        ));
+        let cardano_blocks_builder = Arc::new(
+            CardanoBlocksSignableBuilder::<MKTreeStoreSqlite>::new(blocks_importer, block_store),
+        );
+        let transaction_metadata_store = Arc::new(CardanoTransactionMetadataRepository::new(
+            sqlite_connection_cardano_transaction_pool.clone(),
+        ));
+        let transactions_metadata_importer = Arc::new(CardanoTransactionsMetadataImporter::new(
+            create_block_scanner(),
+            transaction_metadata_store.clone(),
+            self.root_logger(),
+        ));
+        let cardano_transactions_metadata_builder =
+            Arc::new(CardanoTransactionsMetadataSignableBuilder::<
+                MKTreeStoreSqlite,
+            >::new(
+                transactions_metadata_importer, transaction_metadata_store
+            ));
         let cardano_stake_distribution_signable_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
         );
@@ -366,13 +535,14 @@ impl<'a> DependenciesBuilder<'a> {
             cardano_transactions_builder,
             cardano_stake_distribution_signable_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
+            cardano_transactions_metadata_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
             signable_builders_dependencies,
             self.root_logger(),
         ));
-        let metrics_service = Arc::new(MetricsService::new(self.root_logger())?);
         let preloader_activation =
             CardanoTransactionsPreloaderActivationSigner::new(aggregator_client.clone());
         let cardano_transactions_preloader = Arc::new(CardanoTransactionsPreloader::new(
@@ -469,7 +639,10 @@ impl<'a> DependenciesBuilder<'a> {
 
         let certifier = Arc::new(SignerCertifierService::new(
             signed_beacon_repository,
-            Arc::new(SignerSignedEntityConfigProvider::new(epoch_service.clone())),
+            Arc::new(
+                SignerSignedEntityConfigProvider::new(epoch_service.clone())
+                    .with_unsupported_discriminants(self.unsupported_discriminants()),
+            ),
             signed_entity_type_lock.clone(),
             single_signer.clone(),
             signature_publisher,
@@ -527,8 +700,10 @@ mod tests {
         };
 
         assert!(!stores_dir.exists());
-        let chain_observer_builder: fn(&Configuration) -> StdResult<Arc<dyn ChainObserver>> =
-            |_config| Ok(Arc::new(FakeChainObserver::new(Some(TimePoint::dummy()))));
+        let chain_observer_builder: ChainObserverBuilderFn =
+            |_config, _metrics_service, _logger| {
+                Ok(Arc::new(FakeChainObserver::new(Some(TimePoint::dummy()))))
+            };
         let immutable_file_observer_builder: fn(
             &Configuration,
         )
@@ -544,4 +719,24 @@ mod tests {
             .expect("service builder build should not fail");
         assert!(stores_dir.exists());
     }
+
+    #[tokio::test]
+    async fn build_shared_digester_cache_provider_when_a_shared_cache_path_is_set() {
+        let test_dir =
+            get_test_dir("build_shared_digester_cache_provider_when_a_shared_cache_path_is_set");
+        let shared_digests_cache_path = test_dir.join("shared").join("digests.sqlite3");
+        let config = Configuration {
+            data_stores_directory: test_dir.join("stores"),
+            shared_digests_cache_path: Some(shared_digests_cache_path.clone()),
+            ..Configuration::new_sample("party-123456")
+        };
+
+        let cache_provider = DependenciesBuilder::new(&config, TestLogger::stdout())
+            .build_digester_cache_provider()
+            .await
+            .unwrap();
+
+        assert!(cache_provider.is_some());
+        assert!(shared_digests_cache_path.exists());
+    }
 }
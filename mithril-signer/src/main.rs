@@ -16,8 +16,8 @@ use mithril_common::StdResult;
 use mithril_doc::{Documenter, DocumenterDefault, GenerateDocCommands, StructDoc};
 use mithril_metric::MetricsServer;
 use mithril_signer::{
-    Configuration, DatabaseCommand, DefaultConfiguration, SignerRunner, SignerState, StateMachine,
-    dependency_injection::DependenciesBuilder,
+    Configuration, DatabaseCommand, DefaultConfiguration, EraCommand, SignerRunner, SignerState,
+    StateMachine, dependency_injection::DependenciesBuilder,
 };
 
 /// CLI args
@@ -136,6 +136,7 @@ fn build_logger(min_level: Level) -> Logger {
 #[derive(Subcommand, Debug, Clone)]
 enum SignerCommands {
     Database(DatabaseCommand),
+    Era(EraCommand),
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
 }
@@ -155,6 +156,8 @@ async fn main() -> StdResult<()> {
     if let Some(cmd) = &args.command {
         match cmd {
             SignerCommands::Database(cmd) => return cmd.execute(root_logger).await,
+            // Executed once the configuration is loaded
+            SignerCommands::Era(_) => {}
             SignerCommands::GenerateDoc(cmd) => {
                 let config_infos = [
                     Args::extract(),
@@ -222,6 +225,10 @@ async fn main() -> StdResult<()> {
         .try_deserialize()
         .with_context(|| "configuration deserialize error")?;
 
+    if let Some(SignerCommands::Era(cmd)) = &args.command {
+        return cmd.execute(&config, root_logger).await;
+    }
+
     let services = DependenciesBuilder::new(&config, root_logger.clone())
         .build()
         .await
@@ -1,6 +1,14 @@
+use mithril_cardano_node_chain::chain_observer::ChainObserverCacheMetricsRecorder;
+use mithril_cardano_node_chain::chain_scanner::{ChainRollbackMetricsRecorder, RollbackDepth};
 use mithril_metric::{MetricsServiceExporter, build_metrics_service};
 
-use mithril_metric::metric::{MetricCollector, MetricCounter, MetricGauge};
+use crate::services::{TransactionsImportProgress, TransactionsImportProgressRecorder};
+
+use mithril_metric::metric::{
+    CounterValue, MetricCollector, MetricCounter, MetricCounterWithLabels, MetricGauge,
+};
+
+static CHAIN_OBSERVER_QUERY_LABEL: &str = "query";
 
 build_metrics_service!(
     MetricsService,
@@ -35,6 +43,83 @@ build_metrics_service!(
     runtime_cycle_total_since_startup_counter:MetricCounter(
         "mithril_signer_runtime_cycle_total_since_startup",
         "Number of runtime cycles since startup on a Mithril signer node"
+    ),
+    chain_observer_cache_hit_since_startup:MetricCounterWithLabels(
+        "mithril_signer_chain_observer_cache_hit_since_startup",
+        "Number of chain observer queries served from the cache since startup on a Mithril signer node",
+        &[CHAIN_OBSERVER_QUERY_LABEL]
+    ),
+    chain_observer_cache_miss_since_startup:MetricCounterWithLabels(
+        "mithril_signer_chain_observer_cache_miss_since_startup",
+        "Number of chain observer queries not served from the cache since startup on a Mithril signer node",
+        &[CHAIN_OBSERVER_QUERY_LABEL]
+    ),
+    chain_rollback_since_startup:MetricCounter(
+        "mithril_signer_chain_rollback_since_startup",
+        "Number of chain rollbacks received while importing blocks since startup on a Mithril signer node"
+    ),
+    chain_rollback_exceeding_security_parameter_since_startup:MetricCounter(
+        "mithril_signer_chain_rollback_exceeding_security_parameter_since_startup",
+        "Number of chain rollbacks deeper than the security parameter since startup on a Mithril signer node"
+    ),
+    chain_rollback_blocks_since_startup:MetricCounter(
+        "mithril_signer_chain_rollback_blocks_since_startup",
+        "Number of blocks rolled back while importing blocks since startup on a Mithril signer node"
+    ),
+    transactions_import_block_number_gauge:MetricGauge(
+        "mithril_signer_transactions_import_block_number",
+        "Highest block number of the Cardano transactions imported on a Mithril signer node"
+    ),
+    transactions_import_tip_block_number_gauge:MetricGauge(
+        "mithril_signer_transactions_import_tip_block_number",
+        "Block number of the tip of the chain when the last Cardano transactions import completed on a Mithril signer node"
+    ),
+    transactions_import_blocks_per_second_gauge:MetricGauge(
+        "mithril_signer_transactions_import_blocks_per_second",
+        "Number of blocks imported per second during the last Cardano transactions import on a Mithril signer node"
+    ),
+    transactions_import_estimated_seconds_to_tip_gauge:MetricGauge(
+        "mithril_signer_transactions_import_estimated_seconds_to_tip",
+        "Estimated number of seconds to import the Cardano transactions up to the tip of the chain on a Mithril signer node"
     )
 
 );
+
+impl ChainObserverCacheMetricsRecorder for MetricsService {
+    fn record_cache_hit(&self, query: &str) {
+        self.get_chain_observer_cache_hit_since_startup().increment(&[query]);
+    }
+
+    fn record_cache_miss(&self, query: &str) {
+        self.get_chain_observer_cache_miss_since_startup().increment(&[query]);
+    }
+}
+
+impl ChainRollbackMetricsRecorder for MetricsService {
+    fn record_rollback(&self, depth: RollbackDepth, exceeds_security_parameter: bool) {
+        self.get_chain_rollback_since_startup().increment();
+        if exceeds_security_parameter {
+            self.get_chain_rollback_exceeding_security_parameter_since_startup()
+                .increment();
+        }
+        self.get_chain_rollback_blocks_since_startup()
+            .increment_by(depth.lower_bound().try_into().unwrap_or(CounterValue::MAX));
+    }
+}
+
+impl TransactionsImportProgressRecorder for MetricsService {
+    fn record_import_progress(&self, progress: &TransactionsImportProgress) {
+        self.get_transactions_import_block_number_gauge()
+            .record(*progress.imported_block_number as f64);
+        if let Some(tip_block_number) = progress.tip_block_number {
+            self.get_transactions_import_tip_block_number_gauge()
+                .record(*tip_block_number as f64);
+        }
+        self.get_transactions_import_blocks_per_second_gauge()
+            .record(progress.blocks_per_second);
+        if let Some(estimated_time_to_tip) = progress.estimated_time_to_tip {
+            self.get_transactions_import_estimated_seconds_to_tip_gauge()
+                .record(estimated_time_to_tip.as_secs_f64());
+        }
+    }
+}
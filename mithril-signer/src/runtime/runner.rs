@@ -349,7 +349,8 @@ mod tests {
         entities::{BlockNumber, BlockRange, Epoch, SignedEntityTypeDiscriminants},
         messages::{AggregatorCapabilities, AggregatorFeaturesMessage},
         signable_builder::{
-            BlockRangeRootRetriever, CardanoStakeDistributionSignableBuilder,
+            BlockRangeRootRetriever, CardanoBlocksSignableBuilder,
+            CardanoStakeDistributionSignableBuilder, CardanoTransactionsMetadataSignableBuilder,
             CardanoTransactionsSignableBuilder, MithrilSignableBuilderService,
             MithrilStakeDistributionSignableBuilder, SignableBuilderServiceDependencies,
         },
@@ -368,9 +369,10 @@ mod tests {
     use crate::database::test_helper::main_db_connection;
     use crate::metrics::MetricsService;
     use crate::services::{
-        CardanoTransactionsImporter, DumbAggregatorClient, MithrilEpochService,
-        MithrilSingleSigner, MockTransactionStore, MockUpkeepService, SignerCertifierService,
-        SignerSignableSeedBuilder, SignerSignedEntityConfigProvider,
+        CardanoBlocksImporter, CardanoTransactionsImporter, CardanoTransactionsMetadataImporter,
+        DumbAggregatorClient, MithrilEpochService, MithrilSingleSigner, MockBlockStore,
+        MockTransactionMetadataStore, MockTransactionStore, MockUpkeepService,
+        SignerCertifierService, SignerSignableSeedBuilder, SignerSignedEntityConfigProvider,
     };
     use crate::test_tools::TestLogger;
 
@@ -476,12 +478,33 @@ mod tests {
             epoch_service.clone(),
             protocol_initializer_store.clone(),
         ));
+        let blocks_importer = Arc::new(CardanoBlocksImporter::new(
+            transaction_parser.clone(),
+            Arc::new(MockBlockStore::new()),
+            logger.clone(),
+        ));
+        let cardano_blocks_builder = Arc::new(CardanoBlocksSignableBuilder::new(
+            blocks_importer,
+            Arc::new(MockBlockRangeRootRetrieverImpl::<MKTreeStoreInMemory>::new()),
+        ));
+        let transactions_metadata_importer = Arc::new(CardanoTransactionsMetadataImporter::new(
+            transaction_parser.clone(),
+            Arc::new(MockTransactionMetadataStore::new()),
+            logger.clone(),
+        ));
+        let cardano_transactions_metadata_builder =
+            Arc::new(CardanoTransactionsMetadataSignableBuilder::new(
+                transactions_metadata_importer,
+                Arc::new(MockBlockRangeRootRetrieverImpl::<MKTreeStoreInMemory>::new()),
+            ));
         let signable_builders_dependencies = SignableBuilderServiceDependencies::new(
             mithril_stake_distribution_signable_builder,
             cardano_immutable_signable_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
+            cardano_transactions_metadata_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use reqwest::header::{self, HeaderValue};
 use reqwest::{self, Client, Proxy, RequestBuilder, Response, StatusCode};
 use semver::Version;
+use serde::de::DeserializeOwned;
 use slog::{Logger, debug, error, warn};
 use std::{io, sync::Arc, time::Duration};
 use thiserror::Error;
@@ -15,7 +16,9 @@ use mithril_common::{
     },
     logging::LoggerExtensions,
     messages::{
-        AggregatorFeaturesMessage, EpochSettingsMessage, TryFromMessageAdapter, TryToMessageAdapter,
+        AggregatorFeaturesMessage, CardanoTransactionSnapshotListItemMessage,
+        CardanoTransactionsBlockRangeRootsMessage, CertificateMessage, EpochSettingsMessage,
+        TryFromMessageAdapter, TryToMessageAdapter,
     },
 };
 
@@ -157,6 +160,23 @@ pub trait AggregatorClient: Sync + Send {
     async fn retrieve_aggregator_features(
         &self,
     ) -> Result<AggregatorFeaturesMessage, AggregatorClientError>;
+
+    /// Retrieves the latest Cardano transactions snapshot from the aggregator
+    async fn retrieve_latest_cardano_transactions_snapshot(
+        &self,
+    ) -> Result<Option<CardanoTransactionSnapshotListItemMessage>, AggregatorClientError>;
+
+    /// Retrieves the block range roots of a Cardano transactions snapshot from the aggregator
+    async fn retrieve_cardano_transactions_block_range_roots(
+        &self,
+        snapshot_hash: &str,
+    ) -> Result<Option<CardanoTransactionsBlockRangeRootsMessage>, AggregatorClientError>;
+
+    /// Retrieves a certificate from the aggregator
+    async fn retrieve_certificate(
+        &self,
+        certificate_hash: &str,
+    ) -> Result<Option<CertificateMessage>, AggregatorClientError>;
 }
 
 /// AggregatorHTTPClient is a http client for an aggregator
@@ -249,6 +269,32 @@ impl AggregatorHTTPClient {
             _ => {}
         }
     }
+
+    /// Get a JSON message from the aggregator, returning `None` if it is not found.
+    async fn get_json_message<T: DeserializeOwned>(
+        &self,
+        url: String,
+    ) -> Result<Option<T>, AggregatorClientError> {
+        let response = self
+            .prepare_request_builder(self.prepare_http_client()?.get(url))
+            .send()
+            .await;
+
+        match response {
+            Ok(response) => match response.status() {
+                StatusCode::OK => {
+                    self.warn_if_api_version_mismatch(&response);
+
+                    Ok(Some(response.json::<T>().await.map_err(|e| {
+                        AggregatorClientError::JsonParseFailed(anyhow!(e))
+                    })?))
+                }
+                StatusCode::NOT_FOUND => Ok(None),
+                _ => Err(AggregatorClientError::from_response(response).await),
+            },
+            Err(err) => Err(AggregatorClientError::RemoteServerUnreachable(anyhow!(err))),
+        }
+    }
 }
 
 #[async_trait]
@@ -376,6 +422,72 @@ impl AggregatorClient for AggregatorHTTPClient {
             Err(err) => Err(AggregatorClientError::RemoteServerUnreachable(anyhow!(err))),
         }
     }
+
+    async fn retrieve_latest_cardano_transactions_snapshot(
+        &self,
+    ) -> Result<Option<CardanoTransactionSnapshotListItemMessage>, AggregatorClientError> {
+        debug!(self.logger, "Retrieve latest Cardano transactions snapshot");
+        let url = format!("{}/artifact/cardano-transactions", self.aggregator_endpoint);
+        let snapshots: Option<Vec<CardanoTransactionSnapshotListItemMessage>> =
+            self.get_json_message(url).await?;
+
+        Ok(snapshots.and_then(|snapshots| snapshots.into_iter().next()))
+    }
+
+    async fn retrieve_cardano_transactions_block_range_roots(
+        &self,
+        snapshot_hash: &str,
+    ) -> Result<Option<CardanoTransactionsBlockRangeRootsMessage>, AggregatorClientError> {
+        debug!(self.logger, "Retrieve Cardano transactions block range roots"; "snapshot_hash" => snapshot_hash);
+        let url = format!(
+            "{}/artifact/cardano-transaction/{snapshot_hash}/block-range-roots",
+            self.aggregator_endpoint
+        );
+        let Some(mut message): Option<CardanoTransactionsBlockRangeRootsMessage> =
+            self.get_json_message(url.clone()).await?
+        else {
+            return Ok(None);
+        };
+
+        // Gather the pages of block range roots into a single message
+        while let Some(next_page_from) = message.next_page_from.take() {
+            if message
+                .block_range_roots
+                .last()
+                .is_some_and(|last| next_page_from < last.end)
+            {
+                return Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                    "Next page of block range roots starts at block number '{next_page_from}', before the end of the current page"
+                )));
+            }
+            let page: CardanoTransactionsBlockRangeRootsMessage = self
+                .get_json_message(format!("{url}?from={next_page_from}"))
+                .await?
+                .ok_or_else(|| {
+                    AggregatorClientError::RemoteServerLogical(anyhow!(
+                        "Page of block range roots starting at block number '{next_page_from}' not found"
+                    ))
+                })?;
+            message.block_range_roots.extend(page.block_range_roots);
+            message.next_page_from = page.next_page_from;
+            message.last_block_range_transactions = page.last_block_range_transactions;
+        }
+
+        Ok(Some(message))
+    }
+
+    async fn retrieve_certificate(
+        &self,
+        certificate_hash: &str,
+    ) -> Result<Option<CertificateMessage>, AggregatorClientError> {
+        debug!(self.logger, "Retrieve certificate"; "certificate_hash" => certificate_hash);
+        let url = format!(
+            "{}/certificate/{certificate_hash}",
+            self.aggregator_endpoint
+        );
+
+        self.get_json_message(url).await
+    }
 }
 
 #[cfg(test)]
@@ -458,6 +570,28 @@ pub(crate) mod dumb {
             let aggregator_features = self.aggregator_features.read().await;
             Ok(aggregator_features.clone())
         }
+
+        async fn retrieve_latest_cardano_transactions_snapshot(
+            &self,
+        ) -> Result<Option<CardanoTransactionSnapshotListItemMessage>, AggregatorClientError>
+        {
+            Ok(None)
+        }
+
+        async fn retrieve_cardano_transactions_block_range_roots(
+            &self,
+            _snapshot_hash: &str,
+        ) -> Result<Option<CardanoTransactionsBlockRangeRootsMessage>, AggregatorClientError>
+        {
+            Ok(None)
+        }
+
+        async fn retrieve_certificate(
+            &self,
+            _certificate_hash: &str,
+        ) -> Result<Option<CertificateMessage>, AggregatorClientError> {
+            Ok(None)
+        }
     }
 }
 
@@ -471,8 +605,10 @@ mod tests {
     use serde_json::json;
 
     use mithril_common::api_version::DummyApiVersionDiscriminantSource;
-    use mithril_common::entities::Epoch;
-    use mithril_common::messages::TryFromMessageAdapter;
+    use mithril_common::entities::{BlockNumber, Epoch, SlotNumber};
+    use mithril_common::messages::{
+        BlockRangeRootMessagePart, CardanoTransactionMessagePart, TryFromMessageAdapter,
+    };
     use mithril_common::test_utils::{MemoryDrainForTestInspector, double::Dummy, fake_data};
 
     use crate::test_tools::TestLogger;
@@ -599,6 +735,196 @@ mod tests {
         assert_is_error!(error, AggregatorClientError::RemoteServerUnreachable(_));
     }
 
+    #[tokio::test]
+    async fn test_latest_cardano_transactions_snapshot_ok_200() {
+        let (server, client) = setup_server_and_client();
+        let snapshot_expected = CardanoTransactionSnapshotListItemMessage::dummy();
+        let _server_mock = server.mock(|when, then| {
+            when.path("/artifact/cardano-transactions");
+            then.status(200).body(
+                json!([
+                    snapshot_expected,
+                    CardanoTransactionSnapshotListItemMessage {
+                        hash: "older-hash".to_string(),
+                        ..CardanoTransactionSnapshotListItemMessage::dummy()
+                    }
+                ])
+                .to_string(),
+            );
+        });
+
+        let snapshot = client.retrieve_latest_cardano_transactions_snapshot().await.unwrap();
+
+        assert_eq!(Some(snapshot_expected), snapshot);
+    }
+
+    #[tokio::test]
+    async fn test_latest_cardano_transactions_snapshot_ok_200_without_snapshot() {
+        let (server, client) = setup_server_and_client();
+        let _server_mock = server.mock(|when, then| {
+            when.path("/artifact/cardano-transactions");
+            then.status(200).body("[]");
+        });
+
+        let snapshot = client.retrieve_latest_cardano_transactions_snapshot().await.unwrap();
+
+        assert_eq!(None, snapshot);
+    }
+
+    #[tokio::test]
+    async fn test_cardano_transactions_block_range_roots_ok_200() {
+        let (server, client) = setup_server_and_client();
+        let message_expected = CardanoTransactionsBlockRangeRootsMessage {
+            snapshot_hash: "snapshot-hash".to_string(),
+            ..CardanoTransactionsBlockRangeRootsMessage::default()
+        };
+        let _server_mock = server.mock(|when, then| {
+            when.path("/artifact/cardano-transaction/snapshot-hash/block-range-roots");
+            then.status(200).body(json!(message_expected).to_string());
+        });
+
+        let message = client
+            .retrieve_cardano_transactions_block_range_roots("snapshot-hash")
+            .await
+            .unwrap();
+
+        assert_eq!(Some(message_expected), message);
+    }
+
+    #[tokio::test]
+    async fn test_cardano_transactions_block_range_roots_gather_all_pages() {
+        let (server, client) = setup_server_and_client();
+        let block_range_root = |start: u64| BlockRangeRootMessagePart {
+            start: BlockNumber(start),
+            end: BlockNumber(start + 15),
+            merkle_root: format!("mkroot-{start}"),
+        };
+        let last_block_range_transactions = vec![CardanoTransactionMessagePart {
+            transaction_hash: "tx-hash-20".to_string(),
+            block_number: BlockNumber(20),
+            slot_number: SlotNumber(200),
+            block_hash: "block-hash-20".to_string(),
+        }];
+        let first_page = CardanoTransactionsBlockRangeRootsMessage {
+            snapshot_hash: "snapshot-hash".to_string(),
+            block_range_roots: vec![block_range_root(0)],
+            next_page_from: Some(BlockNumber(15)),
+            ..CardanoTransactionsBlockRangeRootsMessage::default()
+        };
+        let last_page = CardanoTransactionsBlockRangeRootsMessage {
+            snapshot_hash: "snapshot-hash".to_string(),
+            block_range_roots: vec![block_range_root(15)],
+            last_block_range_transactions: last_block_range_transactions.clone(),
+            ..CardanoTransactionsBlockRangeRootsMessage::default()
+        };
+        let _last_page_mock = server.mock(|when, then| {
+            when.path("/artifact/cardano-transaction/snapshot-hash/block-range-roots")
+                .query_param("from", "15");
+            then.status(200).body(json!(last_page).to_string());
+        });
+        let _first_page_mock = server.mock(|when, then| {
+            when.path("/artifact/cardano-transaction/snapshot-hash/block-range-roots")
+                .matches(|request| request.query_params.as_ref().is_none_or(Vec::is_empty));
+            then.status(200).body(json!(first_page).to_string());
+        });
+
+        let message = client
+            .retrieve_cardano_transactions_block_range_roots("snapshot-hash")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(CardanoTransactionsBlockRangeRootsMessage {
+                snapshot_hash: "snapshot-hash".to_string(),
+                block_range_roots: vec![block_range_root(0), block_range_root(15)],
+                next_page_from: None,
+                last_block_range_transactions,
+                ..CardanoTransactionsBlockRangeRootsMessage::default()
+            }),
+            message
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cardano_transactions_block_range_roots_ko_if_next_page_does_not_follow_the_current_one()
+     {
+        let (server, client) = setup_server_and_client();
+        let page = CardanoTransactionsBlockRangeRootsMessage {
+            snapshot_hash: "snapshot-hash".to_string(),
+            block_range_roots: vec![BlockRangeRootMessagePart {
+                start: BlockNumber(0),
+                end: BlockNumber(15),
+                merkle_root: "mkroot-0".to_string(),
+            }],
+            next_page_from: Some(BlockNumber(0)),
+            ..CardanoTransactionsBlockRangeRootsMessage::default()
+        };
+        let _server_mock = server.mock(|when, then| {
+            when.path("/artifact/cardano-transaction/snapshot-hash/block-range-roots");
+            then.status(200).body(json!(page).to_string());
+        });
+
+        let error = client
+            .retrieve_cardano_transactions_block_range_roots("snapshot-hash")
+            .await
+            .unwrap_err();
+
+        assert_is_error!(error, AggregatorClientError::RemoteServerLogical(_));
+    }
+
+    #[tokio::test]
+    async fn test_cardano_transactions_block_range_roots_ok_404() {
+        let (server, client) = setup_server_and_client();
+        let _server_mock = server.mock(|when, then| {
+            when.path("/artifact/cardano-transaction/snapshot-hash/block-range-roots");
+            then.status(404);
+        });
+
+        let message = client
+            .retrieve_cardano_transactions_block_range_roots("snapshot-hash")
+            .await
+            .unwrap();
+
+        assert_eq!(None, message);
+    }
+
+    #[tokio::test]
+    async fn test_cardano_transactions_block_range_roots_ko_500() {
+        let (server, client) = setup_server_and_client();
+        set_returning_500(&server);
+
+        let error = client
+            .retrieve_cardano_transactions_block_range_roots("snapshot-hash")
+            .await
+            .unwrap_err();
+
+        assert_is_error!(error, AggregatorClientError::RemoteServerTechnical(_));
+    }
+
+    #[tokio::test]
+    async fn test_certificate_ok_200() {
+        let (server, client) = setup_server_and_client();
+        let certificate_expected = CertificateMessage::dummy();
+        let _server_mock = server.mock(|when, then| {
+            when.path(format!("/certificate/{}", certificate_expected.hash));
+            then.status(200).body(json!(certificate_expected).to_string());
+        });
+
+        let certificate = client.retrieve_certificate(&certificate_expected.hash).await.unwrap();
+
+        assert_eq!(Some(certificate_expected), certificate);
+    }
+
+    #[tokio::test]
+    async fn test_certificate_ko_json_serialization() {
+        let (server, client) = setup_server_and_client();
+        set_unparsable_json(&server);
+
+        let error = client.retrieve_certificate("certificate-hash").await.unwrap_err();
+
+        assert_is_error!(error, AggregatorClientError::JsonParseFailed(_));
+    }
+
     #[tokio::test]
     async fn test_epoch_settings_ok_200() {
         let (server, client) = setup_server_and_client();
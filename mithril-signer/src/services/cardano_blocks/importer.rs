@@ -0,0 +1,332 @@
+use std::mem;
+use std::ops::Range;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use slog::{Logger, debug};
+use tokio::{runtime::Handle, sync::Mutex, task};
+
+use mithril_cardano_node_chain::chain_scanner::{BlockScanner, ChainScannedBlocks};
+use mithril_cardano_node_chain::entities::RawCardanoPoint;
+use mithril_common::StdResult;
+use mithril_common::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
+use mithril_common::entities::{BlockNumber, BlockRange, CardanoBlock, ChainPoint, SlotNumber};
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::signable_builder::BlocksImporter;
+
+/// Cardano blocks store
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BlockStore: Send + Sync {
+    /// Get the highest known block beacon
+    async fn get_highest_beacon(&self) -> StdResult<Option<ChainPoint>>;
+
+    /// Get the highest stored block range root bounds
+    async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>>;
+
+    /// Store list of blocks
+    async fn store_blocks(&self, blocks: Vec<CardanoBlock>) -> StdResult<()>;
+
+    /// Get blocks in an interval of blocks
+    async fn get_blocks_in_range(&self, range: Range<BlockNumber>) -> StdResult<Vec<CardanoBlock>>;
+
+    /// Store list of block ranges with their corresponding merkle root
+    async fn store_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()>;
+
+    /// Remove blocks and block range roots that are in a rolled-back fork
+    ///
+    /// * Remove blocks with slot number strictly greater than the given slot number
+    /// * Remove block range roots that have lower bound range strictly above the given slot number
+    async fn remove_rolled_back_blocks_and_block_range(
+        &self,
+        slot_number: SlotNumber,
+    ) -> StdResult<()>;
+}
+
+/// Import and store [CardanoBlock].
+#[derive(Clone)]
+pub struct CardanoBlocksImporter {
+    block_scanner: Arc<dyn BlockScanner>,
+    block_store: Arc<dyn BlockStore>,
+    last_polled_point: Arc<Mutex<Option<RawCardanoPoint>>>,
+    logger: Logger,
+}
+
+impl CardanoBlocksImporter {
+    /// Constructor
+    pub fn new(
+        block_scanner: Arc<dyn BlockScanner>,
+        block_store: Arc<dyn BlockStore>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            block_scanner,
+            block_store,
+            last_polled_point: Arc::new(Mutex::new(None)),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    async fn import_blocks(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
+        let highest_stored_beacon = self.block_store.get_highest_beacon().await?;
+
+        if highest_stored_beacon
+            .as_ref()
+            .is_some_and(|f| f.block_number >= up_to_beacon)
+        {
+            debug!(
+                self.logger,
+                "No need to retrieve Cardano blocks, the database is up to date for block_number '{up_to_beacon}'",
+            );
+
+            return Ok(());
+        }
+
+        let last_polled_point = self.last_polled_point.lock().await.clone();
+        let from = last_polled_point.or(highest_stored_beacon.as_ref().map(RawCardanoPoint::from));
+        debug!(
+            self.logger, "Retrieving Cardano blocks until block numbered '{up_to_beacon}'";
+            "starting_slot_number" => ?from.as_ref().map(|c| c.slot_number),
+            "highest_stored_block_number" => ?highest_stored_beacon.as_ref().map(|c| c.block_number),
+        );
+
+        let mut streamer = self.block_scanner.scan(from, up_to_beacon).await?;
+        while let Some(blocks) = streamer.poll_next().await? {
+            match blocks {
+                ChainScannedBlocks::RollForwards(forward_blocks) => {
+                    let blocks: Vec<CardanoBlock> =
+                        forward_blocks.iter().map(CardanoBlock::from).collect();
+
+                    self.block_store.store_blocks(blocks).await?;
+                }
+                ChainScannedBlocks::RollBackward { slot_number, depth } => {
+                    debug!(
+                        self.logger, "Rolling back Cardano blocks";
+                        "slot_number" => ?slot_number, "depth" => ?depth,
+                    );
+                    self.block_store
+                        .remove_rolled_back_blocks_and_block_range(slot_number)
+                        .await?;
+                }
+            }
+        }
+
+        if let Some(point) = streamer.last_polled_point() {
+            *self.last_polled_point.lock().await = Some(point);
+        }
+
+        Ok(())
+    }
+
+    async fn import_block_ranges(&self, until: BlockNumber) -> StdResult<()> {
+        let block_ranges = match self.block_store.get_highest_block_range().await?.map(
+            |highest_stored_block_range| {
+                BlockRange::all_block_ranges_in(
+                    BlockRange::start(highest_stored_block_range.end)..=(until),
+                )
+            },
+        ) {
+            // No block range root stored yet, start from the beginning
+            None => BlockRange::all_block_ranges_in(BlockNumber(0)..=(until)),
+            // Not enough block to form at least one block range
+            Some(ranges) if ranges.is_empty() => return Ok(()),
+            Some(ranges) => ranges,
+        };
+
+        debug!(
+            self.logger, "Computing Block Range Roots of the Cardano blocks";
+            "start_block" => *block_ranges.start(), "end_block" => *block_ranges.end(),
+        );
+
+        let mut block_ranges_with_merkle_root: Vec<(BlockRange, MKTreeNode)> = vec![];
+        for block_range in block_ranges {
+            let blocks = self
+                .block_store
+                .get_blocks_in_range(block_range.start..block_range.end)
+                .await?;
+
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&blocks)?.compute_root()?;
+            block_ranges_with_merkle_root.push((block_range, merkle_root));
+
+            if block_ranges_with_merkle_root.len() >= 100 {
+                let block_ranges_with_merkle_root_save =
+                    mem::take(&mut block_ranges_with_merkle_root);
+                self.block_store
+                    .store_block_range_roots(block_ranges_with_merkle_root_save)
+                    .await?;
+            }
+        }
+
+        self.block_store
+            .store_block_range_roots(block_ranges_with_merkle_root)
+            .await
+    }
+}
+
+#[async_trait]
+impl BlocksImporter for CardanoBlocksImporter {
+    async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
+        let importer = self.clone();
+        task::spawn_blocking(move || {
+            Handle::current().block_on(async move {
+                importer.import_blocks(up_to_beacon).await?;
+                importer.import_block_ranges(up_to_beacon).await?;
+                Ok(())
+            })
+        })
+        .await
+        .with_context(|| "BlocksImporter - worker thread crashed")?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_cardano_node_chain::entities::ScannedBlock;
+    use mithril_cardano_node_chain::test::double::DumbBlockScanner;
+    use mithril_persistence::database::repository::CardanoBlockRepository;
+    use mithril_persistence::sqlite::SqliteConnectionPool;
+
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    fn build_blocks(block_numbers: Range<u64>) -> Vec<ScannedBlock> {
+        block_numbers
+            .map(|block_number| {
+                ScannedBlock::new(
+                    format!("block_hash-{block_number}"),
+                    BlockNumber(block_number),
+                    SlotNumber(block_number * 100),
+                    vec![format!("tx_hash-{block_number}")],
+                )
+            })
+            .collect()
+    }
+
+    fn into_cardano_blocks(blocks: &[ScannedBlock]) -> Vec<CardanoBlock> {
+        blocks.iter().map(CardanoBlock::from).collect()
+    }
+
+    fn repository() -> Arc<CardanoBlockRepository> {
+        let connection = cardano_tx_db_connection().unwrap();
+        Arc::new(CardanoBlockRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        )))
+    }
+
+    #[tokio::test]
+    async fn if_nothing_stored_parse_and_store_all_blocks_and_block_ranges() {
+        let repository = repository();
+        let blocks = build_blocks(0..40);
+        let importer = CardanoBlocksImporter::new(
+            Arc::new(DumbBlockScanner::new().forwards(vec![blocks.clone()])),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+
+        importer
+            .import(BlockNumber(40))
+            .await
+            .expect("Blocks Importer should succeed");
+
+        let stored_blocks: Vec<CardanoBlock> = repository
+            .get_all_blocks()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert_eq!(into_cardano_blocks(&blocks), stored_blocks);
+
+        let stored_block_range_roots: Vec<(BlockRange, MKTreeNode)> = repository
+            .get_all_block_range_root()
+            .unwrap()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    MKTree::<MKTreeStoreInMemory>::new(&into_cardano_blocks(&blocks[0..15]))
+                        .unwrap()
+                        .compute_root()
+                        .unwrap()
+                ),
+                (
+                    BlockRange::from_block_number(BlockNumber(15)),
+                    MKTree::<MKTreeStoreInMemory>::new(&into_cardano_blocks(&blocks[15..30]))
+                        .unwrap()
+                        .compute_root()
+                        .unwrap()
+                ),
+            ],
+            stored_block_range_roots
+        );
+    }
+
+    #[tokio::test]
+    async fn if_all_blocks_stored_nothing_is_parsed_and_stored() {
+        let repository = repository();
+        let stored_blocks = build_blocks(0..10);
+        repository
+            .store_blocks(into_cardano_blocks(&stored_blocks))
+            .await
+            .unwrap();
+        let importer = CardanoBlocksImporter::new(
+            Arc::new(DumbBlockScanner::new().forwards(vec![build_blocks(10..20)])),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+
+        importer.import_blocks(BlockNumber(9)).await.unwrap();
+
+        assert_eq!(
+            into_cardano_blocks(&stored_blocks),
+            repository
+                .get_all_blocks()
+                .await
+                .unwrap()
+                .into_iter()
+                .map(CardanoBlock::from)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn when_rollbackward_should_remove_blocks() {
+        let repository = repository();
+        repository
+            .store_blocks(into_cardano_blocks(&build_blocks(0..10)))
+            .await
+            .unwrap();
+        let chain_point = ChainPoint::new(SlotNumber(500), BlockNumber(5), "block_hash-5");
+        let importer = CardanoBlocksImporter::new(
+            Arc::new(DumbBlockScanner::new().backward(chain_point)),
+            repository.clone(),
+            TestLogger::stdout(),
+        );
+
+        importer.import_blocks(BlockNumber(3000)).await.unwrap();
+
+        assert_eq!(
+            into_cardano_blocks(&build_blocks(0..6)),
+            repository
+                .get_all_blocks()
+                .await
+                .unwrap()
+                .into_iter()
+                .map(CardanoBlock::from)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,751 @@
+use std::sync::Arc;
+
+use anyhow::{Context, anyhow};
+use async_trait::async_trait;
+use slog::{Logger, debug, info};
+
+use mithril_common::StdResult;
+use mithril_common::certificate_chain::{
+    CertificateRetriever, CertificateRetrieverError, CertificateVerifier,
+};
+use mithril_common::crypto_helper::{
+    MKMap, MKMapNode, MKTree, MKTreeNode, MKTreeStoreInMemory, ProtocolGenesisVerificationKey,
+};
+use mithril_common::entities::{
+    BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionsLeafFormat, Certificate,
+    ChainPoint, ProtocolMessagePartKey,
+};
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::messages::CardanoTransactionsBlockRangeRootsMessage;
+
+use crate::services::{AggregatorClient, TransactionStore, TransactionsImportCheckpointStore};
+
+/// Bootstrapper of the Cardano transactions store
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TransactionsStoreBootstrapper: Send + Sync {
+    /// Bootstrap the Cardano transactions store, if it is empty, with data certified at a block
+    /// number lower than or equal to `up_to`
+    async fn bootstrap(&self, up_to: BlockNumber) -> StdResult<()>;
+}
+
+/// A [TransactionsStoreBootstrapper] that bootstraps the Cardano transactions store from the
+/// block range roots published by the aggregator for its latest certified Cardano transactions
+/// snapshot.
+///
+/// The block range roots are trusted only once the certificate chain of the snapshot is verified
+/// and the Merkle root they compute matches the one signed by the certificate.
+///
+/// The transactions of the last block range set the chain point the import resumes from, it is
+/// saved as the import checkpoint. With a leaf that commits to the transaction hash only, their
+/// block is not certified: the importer checks that the chain continues from this chain point.
+pub struct AggregatorTransactionsStoreBootstrapper {
+    aggregator_client: Arc<dyn AggregatorClient>,
+    certificate_verifier: Arc<dyn CertificateVerifier>,
+    genesis_verification_key: ProtocolGenesisVerificationKey,
+    transaction_store: Arc<dyn TransactionStore>,
+    checkpoint_store: Arc<dyn TransactionsImportCheckpointStore>,
+    leaf_format: CardanoTransactionsLeafFormat,
+    logger: Logger,
+}
+
+impl AggregatorTransactionsStoreBootstrapper {
+    /// Create a new instance of [AggregatorTransactionsStoreBootstrapper].
+    pub fn new(
+        aggregator_client: Arc<dyn AggregatorClient>,
+        certificate_verifier: Arc<dyn CertificateVerifier>,
+        genesis_verification_key: ProtocolGenesisVerificationKey,
+        transaction_store: Arc<dyn TransactionStore>,
+        checkpoint_store: Arc<dyn TransactionsImportCheckpointStore>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            aggregator_client,
+            certificate_verifier,
+            genesis_verification_key,
+            transaction_store,
+            checkpoint_store,
+            leaf_format: CardanoTransactionsLeafFormat::default(),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Set the format of the leaves of the block ranges Merkle trees
+    pub fn with_leaf_format(mut self, leaf_format: CardanoTransactionsLeafFormat) -> Self {
+        self.leaf_format = leaf_format;
+        self
+    }
+
+    async fn is_transaction_store_empty(&self) -> StdResult<bool> {
+        Ok(self.transaction_store.get_highest_beacon().await?.is_none()
+            && self.transaction_store.get_highest_block_range().await?.is_none())
+    }
+
+    /// Verify the block range roots message against the certificate that signs it, and return
+    /// the verified block range roots with the transactions of the last block range.
+    fn verify_block_range_roots(
+        &self,
+        certificate: &Certificate,
+        message: CardanoTransactionsBlockRangeRootsMessage,
+    ) -> StdResult<VerifiedBlockRangeRoots> {
+        let signed_block_number = certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::LatestBlockNumber)
+            .ok_or_else(|| anyhow!("Certificate does not sign a latest block number"))?;
+        if *signed_block_number != message.block_number.to_string() {
+            return Err(anyhow!(
+                "Block number '{}' does not match the certified block number '{signed_block_number}'",
+                message.block_number
+            ));
+        }
+
+        let mut block_range_roots = vec![];
+        for part in message.block_range_roots {
+            let block_range = BlockRange::from_block_number(part.start);
+            if block_range.start != part.start || block_range.end != part.end {
+                return Err(anyhow!(
+                    "Invalid block range: [{}, {})",
+                    part.start,
+                    part.end
+                ));
+            }
+            block_range_roots.push((block_range, MKTreeNode::from_hex(&part.merkle_root)?));
+        }
+
+        let signed_merkle_root = certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::CardanoTransactionsMerkleRoot)
+            .ok_or_else(|| {
+                anyhow!("Certificate does not sign a Cardano transactions Merkle root")
+            })?;
+        let merkle_root =
+            MKMap::<_, MKMapNode<_, MKTreeStoreInMemory>, MKTreeStoreInMemory>::new_from_iter(
+                block_range_roots
+                    .iter()
+                    .map(|(block_range, root)| (block_range.clone(), root.clone().into())),
+            )?
+            .compute_root()?
+            .to_hex();
+        if *signed_merkle_root != merkle_root {
+            return Err(anyhow!(
+                "Merkle root '{merkle_root}' of the block range roots does not match the certified Merkle root '{signed_merkle_root}'"
+            ));
+        }
+
+        let (last_block_range, last_block_range_root) = block_range_roots
+            .last()
+            .ok_or_else(|| anyhow!("No block range root to bootstrap from"))?;
+        let last_block_range_transactions: Vec<CardanoTransaction> = message
+            .last_block_range_transactions
+            .into_iter()
+            .map(|t| {
+                CardanoTransaction::new(
+                    t.transaction_hash,
+                    t.block_number,
+                    t.slot_number,
+                    t.block_hash,
+                )
+            })
+            .collect();
+        if last_block_range_transactions
+            .iter()
+            .any(|t| BlockRange::from_block_number(t.block_number) != *last_block_range)
+        {
+            return Err(anyhow!(
+                "Transactions of the last block range must be in the block range {last_block_range:?}"
+            ));
+        }
+        let leaves: Vec<MKTreeNode> = last_block_range_transactions
+            .iter()
+            .map(|transaction| self.leaf_format.compute_leaf(transaction))
+            .collect();
+        let last_block_range_transactions_root =
+            MKTree::<MKTreeStoreInMemory>::new(&leaves)?.compute_root()?;
+        if last_block_range_transactions_root != *last_block_range_root {
+            return Err(anyhow!(
+                "Transactions of the last block range do not match its Merkle root"
+            ));
+        }
+
+        Ok(VerifiedBlockRangeRoots {
+            block_range_roots,
+            last_block_range_transactions,
+        })
+    }
+}
+
+/// Block range roots verified against the certificate that signs them
+struct VerifiedBlockRangeRoots {
+    block_range_roots: Vec<(BlockRange, MKTreeNode)>,
+    last_block_range_transactions: Vec<CardanoTransaction>,
+}
+
+#[async_trait]
+impl TransactionsStoreBootstrapper for AggregatorTransactionsStoreBootstrapper {
+    async fn bootstrap(&self, up_to: BlockNumber) -> StdResult<()> {
+        if !self.is_transaction_store_empty().await? {
+            debug!(
+                self.logger,
+                "Cardano transactions store is not empty, skipping bootstrap"
+            );
+            return Ok(());
+        }
+
+        let snapshot = match self
+            .aggregator_client
+            .retrieve_latest_cardano_transactions_snapshot()
+            .await?
+        {
+            Some(snapshot) if snapshot.block_number <= up_to => snapshot,
+            snapshot => {
+                info!(
+                    self.logger, "No certified Cardano transactions snapshot to bootstrap from";
+                    "snapshot_block_number" => ?snapshot.map(|s| s.block_number),
+                    "up_to_block_number" => *up_to,
+                );
+                return Ok(());
+            }
+        };
+
+        info!(
+            self.logger, "Bootstrapping Cardano transactions store from the aggregator";
+            "snapshot_hash" => &snapshot.hash,
+            "snapshot_block_number" => *snapshot.block_number,
+        );
+        let block_range_roots_message = self
+            .aggregator_client
+            .retrieve_cardano_transactions_block_range_roots(&snapshot.hash)
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "No block range roots found for snapshot '{}'",
+                    snapshot.hash
+                )
+            })?;
+        let certificate_hash = block_range_roots_message.certificate_hash.clone();
+        let certificate: Certificate = self
+            .aggregator_client
+            .retrieve_certificate(&certificate_hash)
+            .await?
+            .ok_or_else(|| anyhow!("Certificate '{certificate_hash}' not found"))?
+            .try_into()?;
+        self.certificate_verifier
+            .verify_certificate_chain(certificate.clone(), &self.genesis_verification_key)
+            .await
+            .with_context(|| {
+                format!("Can not verify the certificate chain of certificate '{certificate_hash}'")
+            })?;
+
+        let VerifiedBlockRangeRoots {
+            block_range_roots,
+            last_block_range_transactions,
+        } = self
+            .verify_block_range_roots(&certificate, block_range_roots_message)
+            .with_context(|| {
+                format!(
+                    "Can not verify the block range roots of snapshot '{}'",
+                    snapshot.hash
+                )
+            })?;
+
+        // Block range roots are stored first: if the bootstrap is interrupted, the importer
+        // scans the transactions from origin but does not compute the stored block ranges again
+        let nb_block_range_roots = block_range_roots.len();
+        self.transaction_store
+            .store_block_range_roots(block_range_roots)
+            .await?;
+        // The block of the transactions is covered by the certified leaves only with the block
+        // inclusion format, otherwise the importer checks the chain continues from the resume
+        // point and imports the chain again from origin if it does not
+        let resume_point = last_block_range_transactions
+            .iter()
+            .max_by_key(|transaction| (transaction.block_number, transaction.slot_number))
+            .map(|transaction| {
+                ChainPoint::new(
+                    transaction.slot_number,
+                    transaction.block_number,
+                    transaction.block_hash.clone(),
+                )
+            });
+        self.transaction_store
+            .store_transactions(last_block_range_transactions)
+            .await?;
+        if let Some(resume_point) = resume_point {
+            debug!(
+                self.logger, "Saving transactions import checkpoint";
+                "checkpoint" => ?resume_point
+            );
+            self.checkpoint_store.save_checkpoint(resume_point).await?;
+        }
+        info!(
+            self.logger, "Bootstrapped Cardano transactions store from the aggregator";
+            "nb_block_range_roots" => nb_block_range_roots,
+            "snapshot_block_number" => *snapshot.block_number,
+        );
+
+        Ok(())
+    }
+}
+
+/// A [CertificateRetriever] that retrieves the certificates from the aggregator
+pub struct AggregatorCertificateRetriever {
+    aggregator_client: Arc<dyn AggregatorClient>,
+}
+
+impl AggregatorCertificateRetriever {
+    /// Create a new instance of [AggregatorCertificateRetriever].
+    pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
+        Self { aggregator_client }
+    }
+}
+
+#[async_trait]
+impl CertificateRetriever for AggregatorCertificateRetriever {
+    async fn get_certificate_details(
+        &self,
+        certificate_hash: &str,
+    ) -> Result<Certificate, CertificateRetrieverError> {
+        let certificate_message = self
+            .aggregator_client
+            .retrieve_certificate(certificate_hash)
+            .await
+            .map_err(|e| CertificateRetrieverError(e.into()))?
+            .ok_or_else(|| {
+                CertificateRetrieverError(anyhow!("Certificate '{certificate_hash}' not found"))
+            })?;
+
+        certificate_message.try_into().map_err(CertificateRetrieverError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use mockall::predicate::eq;
+
+    use mithril_common::crypto_helper::ProtocolGenesisSigner;
+    use mithril_common::entities::{ProtocolMessage, SlotNumber};
+    use mithril_common::messages::{
+        BlockRangeRootMessagePart, CardanoTransactionMessagePart,
+        CardanoTransactionSnapshotListItemMessage, CertificateMessage,
+    };
+    use mithril_common::test_utils::{double::Dummy, fake_data};
+
+    use crate::services::{
+        MockAggregatorClient, MockTransactionStore, MockTransactionsImportCheckpointStore,
+    };
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub CertificateVerifierImpl {}
+
+        #[async_trait]
+        impl CertificateVerifier for CertificateVerifierImpl {
+            async fn verify_genesis_certificate(
+                &self,
+                genesis_certificate: &Certificate,
+                genesis_verification_key: &ProtocolGenesisVerificationKey,
+            ) -> StdResult<()>;
+
+            async fn verify_standard_certificate(
+                &self,
+                certificate: &Certificate,
+                previous_certificate: &Certificate,
+            ) -> StdResult<()>;
+
+            async fn verify_certificate(
+                &self,
+                certificate: &Certificate,
+                genesis_verification_key: &ProtocolGenesisVerificationKey,
+            ) -> StdResult<Option<Certificate>>;
+
+            async fn verify_certificate_chain(
+                &self,
+                certificate: Certificate,
+                genesis_verification_key: &ProtocolGenesisVerificationKey,
+            ) -> StdResult<()>;
+        }
+    }
+
+    struct TestData {
+        block_range_roots_message: CardanoTransactionsBlockRangeRootsMessage,
+        certificate: Certificate,
+        block_range_roots: Vec<(BlockRange, MKTreeNode)>,
+        last_block_range_transactions: Vec<CardanoTransaction>,
+    }
+
+    fn build_test_data(
+        snapshot_block_number: BlockNumber,
+        leaf_format: CardanoTransactionsLeafFormat,
+    ) -> TestData {
+        let transactions: Vec<CardanoTransaction> = (0..*snapshot_block_number)
+            .map(|block_number| {
+                CardanoTransaction::new(
+                    format!("tx-hash-{block_number}"),
+                    BlockNumber(block_number),
+                    SlotNumber(block_number * 10),
+                    format!("block-hash-{block_number}"),
+                )
+            })
+            .collect();
+        let block_range_roots: Vec<(BlockRange, MKTreeNode)> =
+            BlockRange::all_block_ranges_in(BlockNumber(0)..=snapshot_block_number)
+                .map(|block_range| {
+                    let leaves: Vec<MKTreeNode> = transactions
+                        .iter()
+                        .filter(|t| BlockRange::from_block_number(t.block_number) == block_range)
+                        .map(|t| leaf_format.compute_leaf(t))
+                        .collect();
+                    let root = MKTree::<MKTreeStoreInMemory>::new(&leaves)
+                        .unwrap()
+                        .compute_root()
+                        .unwrap();
+                    (block_range, root)
+                })
+                .collect();
+        let last_block_range = block_range_roots.last().unwrap().0.clone();
+        let last_block_range_transactions: Vec<CardanoTransaction> = transactions
+            .into_iter()
+            .filter(|t| BlockRange::from_block_number(t.block_number) == last_block_range)
+            .collect();
+        let merkle_root =
+            MKMap::<_, MKMapNode<_, MKTreeStoreInMemory>, MKTreeStoreInMemory>::new_from_iter(
+                block_range_roots
+                    .iter()
+                    .map(|(block_range, root)| (block_range.clone(), root.clone().into())),
+            )
+            .unwrap()
+            .compute_root()
+            .unwrap()
+            .to_hex();
+
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoTransactionsMerkleRoot,
+            merkle_root,
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            snapshot_block_number.to_string(),
+        );
+        let certificate = Certificate {
+            protocol_message,
+            ..fake_data::certificate("certificate-hash")
+        };
+
+        TestData {
+            block_range_roots_message: CardanoTransactionsBlockRangeRootsMessage {
+                snapshot_hash: "snapshot-hash".to_string(),
+                certificate_hash: certificate.hash.clone(),
+                block_number: snapshot_block_number,
+                block_range_roots: block_range_roots
+                    .iter()
+                    .map(|(block_range, root)| BlockRangeRootMessagePart {
+                        start: block_range.start,
+                        end: block_range.end,
+                        merkle_root: root.to_hex(),
+                    })
+                    .collect(),
+                next_page_from: None,
+                last_block_range_transactions: last_block_range_transactions
+                    .iter()
+                    .map(|t| CardanoTransactionMessagePart {
+                        transaction_hash: t.transaction_hash.clone(),
+                        block_number: t.block_number,
+                        slot_number: t.slot_number,
+                        block_hash: t.block_hash.clone(),
+                    })
+                    .collect(),
+            },
+            certificate,
+            block_range_roots,
+            last_block_range_transactions,
+        }
+    }
+
+    fn build_bootstrapper<A, V, T>(
+        aggregator_client_mock_config: A,
+        certificate_verifier_mock_config: V,
+        transaction_store_mock_config: T,
+    ) -> AggregatorTransactionsStoreBootstrapper
+    where
+        A: FnOnce(&mut MockAggregatorClient),
+        V: FnOnce(&mut MockCertificateVerifierImpl),
+        T: FnOnce(&mut MockTransactionStore),
+    {
+        build_bootstrapper_with_checkpoint_store(
+            aggregator_client_mock_config,
+            certificate_verifier_mock_config,
+            transaction_store_mock_config,
+            |mock| {
+                mock.expect_save_checkpoint().never();
+            },
+        )
+    }
+
+    fn build_bootstrapper_with_checkpoint_store<A, V, T, C>(
+        aggregator_client_mock_config: A,
+        certificate_verifier_mock_config: V,
+        transaction_store_mock_config: T,
+        checkpoint_store_mock_config: C,
+    ) -> AggregatorTransactionsStoreBootstrapper
+    where
+        A: FnOnce(&mut MockAggregatorClient),
+        V: FnOnce(&mut MockCertificateVerifierImpl),
+        T: FnOnce(&mut MockTransactionStore),
+        C: FnOnce(&mut MockTransactionsImportCheckpointStore),
+    {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client_mock_config(&mut aggregator_client);
+        let mut certificate_verifier = MockCertificateVerifierImpl::new();
+        certificate_verifier_mock_config(&mut certificate_verifier);
+        let mut transaction_store = MockTransactionStore::new();
+        transaction_store_mock_config(&mut transaction_store);
+        let mut checkpoint_store = MockTransactionsImportCheckpointStore::new();
+        checkpoint_store_mock_config(&mut checkpoint_store);
+
+        AggregatorTransactionsStoreBootstrapper::new(
+            Arc::new(aggregator_client),
+            Arc::new(certificate_verifier),
+            ProtocolGenesisSigner::create_deterministic_signer().verification_key(),
+            Arc::new(transaction_store),
+            Arc::new(checkpoint_store),
+            TestLogger::stdout(),
+        )
+    }
+
+    fn mock_aggregator_client(aggregator_client: &mut MockAggregatorClient, test_data: &TestData) {
+        let snapshot = CardanoTransactionSnapshotListItemMessage {
+            hash: test_data.block_range_roots_message.snapshot_hash.clone(),
+            block_number: test_data.block_range_roots_message.block_number,
+            ..CardanoTransactionSnapshotListItemMessage::dummy()
+        };
+        let block_range_roots_message = test_data.block_range_roots_message.clone();
+        let certificate_message: CertificateMessage =
+            test_data.certificate.clone().try_into().unwrap();
+        aggregator_client
+            .expect_retrieve_latest_cardano_transactions_snapshot()
+            .return_once(move || Ok(Some(snapshot)));
+        aggregator_client
+            .expect_retrieve_cardano_transactions_block_range_roots()
+            .return_once(move |_| Ok(Some(block_range_roots_message)));
+        aggregator_client
+            .expect_retrieve_certificate()
+            .return_once(move |_| Ok(Some(certificate_message)));
+    }
+
+    fn mock_empty_transaction_store(transaction_store: &mut MockTransactionStore) {
+        transaction_store.expect_get_highest_beacon().returning(|| Ok(None));
+        transaction_store
+            .expect_get_highest_block_range()
+            .returning(|| Ok(None));
+    }
+
+    async fn assert_store_verified_block_range_roots_and_last_block_range_transactions(
+        leaf_format: CardanoTransactionsLeafFormat,
+    ) {
+        let test_data = build_test_data(BlockNumber(46), leaf_format);
+        let bootstrapper = build_bootstrapper_with_checkpoint_store(
+            |mock| mock_aggregator_client(mock, &test_data),
+            |mock| {
+                mock.expect_verify_certificate_chain().once().returning(|_, _| Ok(()));
+            },
+            |mock| {
+                mock_empty_transaction_store(mock);
+                mock.expect_store_block_range_roots()
+                    .with(eq(test_data.block_range_roots.clone()))
+                    .once()
+                    .returning(|_| Ok(()));
+                mock.expect_store_transactions()
+                    .with(eq(test_data.last_block_range_transactions.clone()))
+                    .once()
+                    .returning(|_| Ok(()));
+            },
+            |mock| {
+                mock.expect_save_checkpoint()
+                    .with(eq(ChainPoint::new(
+                        SlotNumber(440),
+                        BlockNumber(44),
+                        "block-hash-44",
+                    )))
+                    .once()
+                    .returning(|_| Ok(()));
+            },
+        )
+        .with_leaf_format(leaf_format);
+
+        bootstrapper.bootstrap(BlockNumber(100)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn store_verified_block_range_roots_and_last_block_range_transactions_and_save_their_chain_point_as_checkpoint()
+     {
+        assert_store_verified_block_range_roots_and_last_block_range_transactions(
+            CardanoTransactionsLeafFormat::BlockInclusion,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn store_last_block_range_transactions_and_save_their_chain_point_as_checkpoint_even_if_leaves_do_not_certify_their_block()
+     {
+        assert_store_verified_block_range_roots_and_last_block_range_transactions(
+            CardanoTransactionsLeafFormat::TransactionHash,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn fails_if_block_hash_of_last_block_range_transactions_is_not_certified() {
+        let leaf_format = CardanoTransactionsLeafFormat::BlockInclusion;
+        let mut test_data = build_test_data(BlockNumber(46), leaf_format);
+        test_data
+            .block_range_roots_message
+            .last_block_range_transactions
+            .last_mut()
+            .unwrap()
+            .block_hash = "forged-block-hash".to_string();
+        let bootstrapper = build_bootstrapper(
+            |mock| mock_aggregator_client(mock, &test_data),
+            |mock| {
+                mock.expect_verify_certificate_chain().returning(|_, _| Ok(()));
+            },
+            |mock| {
+                mock_empty_transaction_store(mock);
+                mock.expect_store_block_range_roots().never();
+                mock.expect_store_transactions().never();
+            },
+        )
+        .with_leaf_format(leaf_format);
+
+        bootstrapper
+            .bootstrap(BlockNumber(100))
+            .await
+            .expect_err("Bootstrap should fail with a block hash that is not certified");
+    }
+
+    #[tokio::test]
+    async fn does_not_bootstrap_if_transaction_store_is_not_empty() {
+        let bootstrapper = build_bootstrapper(
+            |mock| {
+                mock.expect_retrieve_latest_cardano_transactions_snapshot().never();
+            },
+            |_| {},
+            |mock| {
+                mock.expect_get_highest_beacon().returning(|| Ok(None));
+                mock.expect_get_highest_block_range()
+                    .returning(|| Ok(Some(BlockRange::from_block_number(BlockNumber(0)))));
+                mock.expect_store_block_range_roots().never();
+            },
+        );
+
+        bootstrapper.bootstrap(BlockNumber(100)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn does_not_bootstrap_from_snapshot_above_the_given_block_number() {
+        let test_data = build_test_data(BlockNumber(46), CardanoTransactionsLeafFormat::default());
+        let bootstrapper = build_bootstrapper(
+            |mock| mock_aggregator_client(mock, &test_data),
+            |_| {},
+            |mock| {
+                mock_empty_transaction_store(mock);
+                mock.expect_store_block_range_roots().never();
+            },
+        );
+
+        bootstrapper.bootstrap(BlockNumber(40)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn fails_if_certificate_chain_is_invalid() {
+        let test_data = build_test_data(BlockNumber(46), CardanoTransactionsLeafFormat::default());
+        let bootstrapper = build_bootstrapper(
+            |mock| mock_aggregator_client(mock, &test_data),
+            |mock| {
+                mock.expect_verify_certificate_chain()
+                    .returning(|_, _| Err(anyhow!("invalid chain")));
+            },
+            |mock| {
+                mock_empty_transaction_store(mock);
+                mock.expect_store_block_range_roots().never();
+            },
+        );
+
+        bootstrapper
+            .bootstrap(BlockNumber(100))
+            .await
+            .expect_err("Bootstrap should fail with an invalid certificate chain");
+    }
+
+    #[tokio::test]
+    async fn fails_if_block_range_roots_do_not_match_the_certified_merkle_root() {
+        let mut test_data =
+            build_test_data(BlockNumber(46), CardanoTransactionsLeafFormat::default());
+        test_data.block_range_roots_message.block_range_roots[0].merkle_root = "00".repeat(32);
+        let bootstrapper = build_bootstrapper(
+            |mock| mock_aggregator_client(mock, &test_data),
+            |mock| {
+                mock.expect_verify_certificate_chain().returning(|_, _| Ok(()));
+            },
+            |mock| {
+                mock_empty_transaction_store(mock);
+                mock.expect_store_block_range_roots().never();
+            },
+        );
+
+        bootstrapper
+            .bootstrap(BlockNumber(100))
+            .await
+            .expect_err("Bootstrap should fail with tampered block range roots");
+    }
+
+    #[tokio::test]
+    async fn fails_if_last_block_range_transactions_do_not_match_its_root() {
+        let mut test_data =
+            build_test_data(BlockNumber(46), CardanoTransactionsLeafFormat::default());
+        test_data
+            .block_range_roots_message
+            .last_block_range_transactions
+            .pop();
+        let bootstrapper = build_bootstrapper(
+            |mock| mock_aggregator_client(mock, &test_data),
+            |mock| {
+                mock.expect_verify_certificate_chain().returning(|_, _| Ok(()));
+            },
+            |mock| {
+                mock_empty_transaction_store(mock);
+                mock.expect_store_block_range_roots().never();
+            },
+        );
+
+        bootstrapper
+            .bootstrap(BlockNumber(100))
+            .await
+            .expect_err("Bootstrap should fail with missing transactions in the last block range");
+    }
+
+    #[tokio::test]
+    async fn fails_if_block_number_does_not_match_the_certified_one() {
+        let mut test_data =
+            build_test_data(BlockNumber(46), CardanoTransactionsLeafFormat::default());
+        test_data.block_range_roots_message.block_number = BlockNumber(45);
+        let bootstrapper = build_bootstrapper(
+            |mock| mock_aggregator_client(mock, &test_data),
+            |mock| {
+                mock.expect_verify_certificate_chain().returning(|_, _| Ok(()));
+            },
+            |mock| {
+                mock_empty_transaction_store(mock);
+                mock.expect_store_block_range_roots().never();
+            },
+        );
+
+        bootstrapper
+            .bootstrap(BlockNumber(100))
+            .await
+            .expect_err("Bootstrap should fail with a block number that is not certified");
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use slog::{Logger, warn};
+use tokio::sync::Mutex;
+
+use mithril_common::StdResult;
+use mithril_common::entities::BlockNumber;
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::signable_builder::TransactionsImporter;
+
+use crate::services::TransactionsStoreBootstrapper;
+
+/// A decorator of [TransactionsImporter] that bootstraps the Cardano transactions store before
+/// its first import, so the import resumes from the bootstrapped data instead of scanning the
+/// whole chain.
+///
+/// If the bootstrap fails, the import falls back to scanning the chain.
+pub struct TransactionsImporterWithBootstrap {
+    bootstrapper: Arc<dyn TransactionsStoreBootstrapper>,
+    wrapped_importer: Arc<dyn TransactionsImporter>,
+    has_bootstrapped: Mutex<bool>,
+    logger: Logger,
+}
+
+impl TransactionsImporterWithBootstrap {
+    /// Create a new instance of [TransactionsImporterWithBootstrap].
+    pub fn new(
+        bootstrapper: Arc<dyn TransactionsStoreBootstrapper>,
+        wrapped_importer: Arc<dyn TransactionsImporter>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            bootstrapper,
+            wrapped_importer,
+            has_bootstrapped: Mutex::new(false),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    async fn bootstrap_once(&self, up_to_beacon: BlockNumber) {
+        let mut has_bootstrapped = self.has_bootstrapped.lock().await;
+        if *has_bootstrapped {
+            return;
+        }
+
+        if let Err(error) = self.bootstrapper.bootstrap(up_to_beacon).await {
+            warn!(
+                self.logger, "Cardano transactions store bootstrap failed, falling back to scanning the chain";
+                "error" => ?error
+            );
+        }
+        *has_bootstrapped = true;
+    }
+}
+
+#[async_trait]
+impl TransactionsImporter for TransactionsImporterWithBootstrap {
+    async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
+        self.bootstrap_once(up_to_beacon).await;
+
+        self.wrapped_importer.import(up_to_beacon).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use mockall::mock;
+    use mockall::predicate::eq;
+
+    use crate::services::MockTransactionsStoreBootstrapper;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub TransactionImporterImpl {}
+
+        #[async_trait]
+        impl TransactionsImporter for TransactionImporterImpl {
+            async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()>;
+        }
+    }
+
+    fn build_importer<B, I>(
+        bootstrapper_mock_config: B,
+        importer_mock_config: I,
+    ) -> TransactionsImporterWithBootstrap
+    where
+        B: FnOnce(&mut MockTransactionsStoreBootstrapper),
+        I: FnOnce(&mut MockTransactionImporterImpl),
+    {
+        let mut bootstrapper = MockTransactionsStoreBootstrapper::new();
+        bootstrapper_mock_config(&mut bootstrapper);
+        let mut transaction_importer = MockTransactionImporterImpl::new();
+        importer_mock_config(&mut transaction_importer);
+
+        TransactionsImporterWithBootstrap::new(
+            Arc::new(bootstrapper),
+            Arc::new(transaction_importer),
+            TestLogger::stdout(),
+        )
+    }
+
+    #[tokio::test]
+    async fn bootstrap_before_first_import_only() {
+        let importer = build_importer(
+            |mock| {
+                mock.expect_bootstrap()
+                    .with(eq(BlockNumber(100)))
+                    .once()
+                    .returning(|_| Ok(()));
+            },
+            |mock| {
+                mock.expect_import().times(2).returning(|_| Ok(()));
+            },
+        );
+
+        importer.import(BlockNumber(100)).await.unwrap();
+        importer.import(BlockNumber(200)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn import_even_if_bootstrap_fails() {
+        let importer = build_importer(
+            |mock| {
+                mock.expect_bootstrap()
+                    .once()
+                    .returning(|_| Err(anyhow!("bootstrap failed")));
+            },
+            |mock| {
+                mock.expect_import()
+                    .with(eq(BlockNumber(100)))
+                    .once()
+                    .returning(|_| Ok(()));
+            },
+        );
+
+        importer.import(BlockNumber(100)).await.unwrap();
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use slog::{Logger, debug, info};
+use tokio::sync::Mutex;
+
+use mithril_common::StdResult;
+use mithril_common::entities::{BlockNumber, ChainPoint};
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::signable_builder::TransactionsImporter;
+
+use crate::services::TransactionStore;
+
+/// Store of the checkpoints of the Cardano transactions import
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TransactionsImportCheckpointStore: Send + Sync {
+    /// Get the last saved checkpoint
+    async fn get_last_checkpoint(&self) -> StdResult<Option<ChainPoint>>;
+
+    /// Save a checkpoint, replacing the previously saved one
+    async fn save_checkpoint(&self, chain_point: ChainPoint) -> StdResult<()>;
+}
+
+/// A decorator of [TransactionsImporter] that saves a checkpoint after each successful import and
+/// resumes from the last checkpoint after a crash.
+///
+/// When it runs its first import, the transactions and block range roots stored above the last
+/// checkpoint are discarded: they were stored by an import that did not complete.
+pub struct TransactionsImporterWithCheckpoint {
+    checkpoint_store: Arc<dyn TransactionsImportCheckpointStore>,
+    transaction_store: Arc<dyn TransactionStore>,
+    wrapped_importer: Arc<dyn TransactionsImporter>,
+    has_resumed: Mutex<bool>,
+    logger: Logger,
+}
+
+impl TransactionsImporterWithCheckpoint {
+    /// Create a new instance of [TransactionsImporterWithCheckpoint].
+    pub fn new(
+        checkpoint_store: Arc<dyn TransactionsImportCheckpointStore>,
+        transaction_store: Arc<dyn TransactionStore>,
+        wrapped_importer: Arc<dyn TransactionsImporter>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            checkpoint_store,
+            transaction_store,
+            wrapped_importer,
+            has_resumed: Mutex::new(false),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    async fn resume_from_last_checkpoint(&self) -> StdResult<()> {
+        let mut has_resumed = self.has_resumed.lock().await;
+        if *has_resumed {
+            return Ok(());
+        }
+
+        let last_checkpoint = self.checkpoint_store.get_last_checkpoint().await?;
+        let highest_stored_beacon = self.transaction_store.get_highest_beacon().await?;
+
+        match (last_checkpoint, highest_stored_beacon) {
+            (Some(checkpoint), Some(highest_beacon))
+                if highest_beacon.block_number > checkpoint.block_number =>
+            {
+                info!(
+                    self.logger,
+                    "Resuming transactions import from the last checkpoint, discarding the data of the uncompleted import";
+                    "checkpoint" => ?checkpoint,
+                    "highest_stored_block_number" => *highest_beacon.block_number,
+                );
+                self.transaction_store
+                    .remove_rolled_back_transactions_and_block_range(checkpoint.slot_number)
+                    .await?;
+            }
+            (last_checkpoint, _) => {
+                debug!(
+                    self.logger, "No uncompleted transactions import to discard";
+                    "checkpoint" => ?last_checkpoint,
+                );
+            }
+        }
+
+        *has_resumed = true;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TransactionsImporter for TransactionsImporterWithCheckpoint {
+    async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
+        self.resume_from_last_checkpoint().await?;
+
+        self.wrapped_importer.import(up_to_beacon).await?;
+
+        if let Some(highest_beacon) = self.transaction_store.get_highest_beacon().await? {
+            debug!(
+                self.logger, "Saving transactions import checkpoint";
+                "checkpoint" => ?highest_beacon
+            );
+            self.checkpoint_store.save_checkpoint(highest_beacon).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::mock;
+    use mockall::predicate::eq;
+
+    use mithril_common::entities::SlotNumber;
+
+    use crate::services::MockTransactionStore;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub TransactionImporterImpl {}
+
+        #[async_trait]
+        impl TransactionsImporter for TransactionImporterImpl {
+            async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()>;
+        }
+    }
+
+    fn chain_point(block_number: u64) -> ChainPoint {
+        ChainPoint::new(
+            SlotNumber(block_number * 10),
+            BlockNumber(block_number),
+            format!("block_hash-{block_number}"),
+        )
+    }
+
+    impl TransactionsImporterWithCheckpoint {
+        pub(crate) fn new_with_mock<C, T, I>(
+            checkpoint_store_mock_config: C,
+            transaction_store_mock_config: T,
+            importer_mock_config: I,
+        ) -> Self
+        where
+            C: FnOnce(&mut MockTransactionsImportCheckpointStore),
+            T: FnOnce(&mut MockTransactionStore),
+            I: FnOnce(&mut MockTransactionImporterImpl),
+        {
+            let mut checkpoint_store = MockTransactionsImportCheckpointStore::new();
+            checkpoint_store_mock_config(&mut checkpoint_store);
+            let mut transaction_store = MockTransactionStore::new();
+            transaction_store_mock_config(&mut transaction_store);
+            let mut transaction_importer = MockTransactionImporterImpl::new();
+            importer_mock_config(&mut transaction_importer);
+
+            Self::new(
+                Arc::new(checkpoint_store),
+                Arc::new(transaction_store),
+                Arc::new(transaction_importer),
+                TestLogger::stdout(),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn save_highest_stored_beacon_as_checkpoint_after_import() {
+        let importer = TransactionsImporterWithCheckpoint::new_with_mock(
+            |mock| {
+                mock.expect_get_last_checkpoint().returning(|| Ok(None));
+                mock.expect_save_checkpoint()
+                    .with(eq(chain_point(100)))
+                    .once()
+                    .returning(|_| Ok(()));
+            },
+            |mock| {
+                mock.expect_get_highest_beacon()
+                    .returning(|| Ok(Some(chain_point(100))));
+                mock.expect_remove_rolled_back_transactions_and_block_range().never();
+            },
+            |mock| {
+                mock.expect_import().once().returning(|_| Ok(()));
+            },
+        );
+
+        importer
+            .import(BlockNumber(100))
+            .await
+            .expect("Import should not fail");
+    }
+
+    #[tokio::test]
+    async fn does_not_save_checkpoint_if_import_fails() {
+        let importer = TransactionsImporterWithCheckpoint::new_with_mock(
+            |mock| {
+                mock.expect_get_last_checkpoint().returning(|| Ok(None));
+                mock.expect_save_checkpoint().never();
+            },
+            |mock| {
+                mock.expect_get_highest_beacon().returning(|| Ok(None));
+            },
+            |mock| {
+                mock.expect_import()
+                    .once()
+                    .returning(|_| Err(anyhow::anyhow!("import failed")));
+            },
+        );
+
+        importer
+            .import(BlockNumber(100))
+            .await
+            .expect_err("Import should fail");
+    }
+
+    #[tokio::test]
+    async fn discard_data_stored_above_last_checkpoint_on_first_import_only() {
+        let importer = TransactionsImporterWithCheckpoint::new_with_mock(
+            |mock| {
+                mock.expect_get_last_checkpoint()
+                    .once()
+                    .returning(|| Ok(Some(chain_point(50))));
+                mock.expect_save_checkpoint().returning(|_| Ok(()));
+            },
+            |mock| {
+                mock.expect_get_highest_beacon()
+                    .returning(|| Ok(Some(chain_point(70))));
+                mock.expect_remove_rolled_back_transactions_and_block_range()
+                    .with(eq(SlotNumber(500)))
+                    .once()
+                    .returning(|_| Ok(()));
+            },
+            |mock| {
+                mock.expect_import().times(2).returning(|_| Ok(()));
+            },
+        );
+
+        importer.import(BlockNumber(100)).await.unwrap();
+        importer.import(BlockNumber(200)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn does_not_discard_data_when_highest_stored_beacon_is_the_last_checkpoint() {
+        let importer = TransactionsImporterWithCheckpoint::new_with_mock(
+            |mock| {
+                mock.expect_get_last_checkpoint()
+                    .returning(|| Ok(Some(chain_point(50))));
+                mock.expect_save_checkpoint().returning(|_| Ok(()));
+            },
+            |mock| {
+                mock.expect_get_highest_beacon()
+                    .returning(|| Ok(Some(chain_point(50))));
+                mock.expect_remove_rolled_back_transactions_and_block_range().never();
+            },
+            |mock| {
+                mock.expect_import().once().returning(|_| Ok(()));
+            },
+        );
+
+        importer.import(BlockNumber(100)).await.unwrap();
+    }
+}
@@ -0,0 +1,318 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use slog::{Logger, info, warn};
+
+use mithril_cardano_node_chain::chain_observer::ChainObserver;
+use mithril_common::StdResult;
+use mithril_common::entities::BlockNumber;
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::signable_builder::TransactionsImporter;
+
+use crate::services::HighestTransactionBlockNumberGetter;
+
+/// Progress of the Cardano transactions import
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionsImportProgress {
+    /// Highest block number of the imported transactions
+    pub imported_block_number: BlockNumber,
+
+    /// Block number of the tip of the chain, if known
+    pub tip_block_number: Option<BlockNumber>,
+
+    /// Number of blocks imported per second during the last import
+    pub blocks_per_second: f64,
+
+    /// Estimated time to import the transactions up to the tip of the chain, if it can be computed
+    pub estimated_time_to_tip: Option<Duration>,
+}
+
+impl TransactionsImportProgress {
+    /// Compute the progress of an import that went from `start_block_number` to
+    /// `imported_block_number` in `elapsed` time.
+    pub fn compute(
+        start_block_number: BlockNumber,
+        imported_block_number: BlockNumber,
+        elapsed: Duration,
+        tip_block_number: Option<BlockNumber>,
+    ) -> Self {
+        let imported_blocks = *(imported_block_number - start_block_number);
+        let blocks_per_second = if elapsed.is_zero() {
+            0.0
+        } else {
+            imported_blocks as f64 / elapsed.as_secs_f64()
+        };
+        let estimated_time_to_tip =
+            tip_block_number.filter(|_| blocks_per_second > 0.0).map(|tip| {
+                let remaining_blocks = *(tip - imported_block_number);
+                Duration::from_secs_f64(remaining_blocks as f64 / blocks_per_second)
+            });
+
+        Self {
+            imported_block_number,
+            tip_block_number,
+            blocks_per_second,
+            estimated_time_to_tip,
+        }
+    }
+}
+
+/// Records the progress of the Cardano transactions import
+pub trait TransactionsImportProgressRecorder: Send + Sync {
+    /// Record the progress of the last import
+    fn record_import_progress(&self, progress: &TransactionsImportProgress);
+}
+
+/// A decorator of [TransactionsImporter] that reports the progress of the import, with the
+/// import rate and the estimated time to reach the tip of the chain, after each import.
+pub struct TransactionsImporterWithProgress {
+    highest_transaction_block_number_getter: Arc<dyn HighestTransactionBlockNumberGetter>,
+    chain_observer: Arc<dyn ChainObserver>,
+    wrapped_importer: Arc<dyn TransactionsImporter>,
+    progress_recorder: Option<Arc<dyn TransactionsImportProgressRecorder>>,
+    logger: Logger,
+}
+
+impl TransactionsImporterWithProgress {
+    /// Create a new instance of [TransactionsImporterWithProgress].
+    pub fn new(
+        highest_transaction_block_number_getter: Arc<dyn HighestTransactionBlockNumberGetter>,
+        chain_observer: Arc<dyn ChainObserver>,
+        wrapped_importer: Arc<dyn TransactionsImporter>,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            highest_transaction_block_number_getter,
+            chain_observer,
+            wrapped_importer,
+            progress_recorder: None,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Set the recorder of the import progress
+    pub fn with_progress_recorder(
+        mut self,
+        progress_recorder: Arc<dyn TransactionsImportProgressRecorder>,
+    ) -> Self {
+        self.progress_recorder = Some(progress_recorder);
+        self
+    }
+
+    async fn get_tip_block_number(&self) -> Option<BlockNumber> {
+        match self.chain_observer.get_current_chain_point().await {
+            Ok(chain_point) => chain_point.map(|c| c.block_number),
+            Err(error) => {
+                warn!(
+                    self.logger, "Could not get the tip of the chain to compute the import progress";
+                    "error" => ?error
+                );
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionsImporter for TransactionsImporterWithProgress {
+    async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
+        let start_block_number = self
+            .highest_transaction_block_number_getter
+            .get()
+            .await?
+            .unwrap_or(BlockNumber(0));
+        let start = Instant::now();
+
+        self.wrapped_importer.import(up_to_beacon).await?;
+
+        let imported_block_number = self
+            .highest_transaction_block_number_getter
+            .get()
+            .await?
+            .unwrap_or(BlockNumber(0));
+        let progress = TransactionsImportProgress::compute(
+            start_block_number,
+            imported_block_number,
+            start.elapsed(),
+            self.get_tip_block_number().await,
+        );
+
+        info!(
+            self.logger, "Transactions import progress";
+            "imported_block_number" => *progress.imported_block_number,
+            "tip_block_number" => progress.tip_block_number.map(|b| *b),
+            "blocks_per_second" => format!("{:.2}", progress.blocks_per_second),
+            "estimated_seconds_to_tip" => progress.estimated_time_to_tip.map(|d| d.as_secs()),
+        );
+        if let Some(progress_recorder) = &self.progress_recorder {
+            progress_recorder.record_import_progress(&progress);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use mockall::mock;
+
+    use mithril_cardano_node_chain::test::double::FakeChainObserver;
+    use mithril_common::entities::{ChainPoint, SlotNumber, TimePoint};
+    use mithril_common::test_utils::double::Dummy;
+
+    use crate::services::MockHighestTransactionBlockNumberGetter;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    mock! {
+        pub TransactionImporterImpl {}
+
+        #[async_trait]
+        impl TransactionsImporter for TransactionImporterImpl {
+            async fn import(&self, up_to_beacon: BlockNumber) -> StdResult<()>;
+        }
+    }
+
+    #[derive(Default)]
+    struct CollectingProgressRecorder {
+        recorded: Mutex<Vec<TransactionsImportProgress>>,
+    }
+
+    impl TransactionsImportProgressRecorder for CollectingProgressRecorder {
+        fn record_import_progress(&self, progress: &TransactionsImportProgress) {
+            self.recorded.lock().unwrap().push(progress.clone());
+        }
+    }
+
+    mod compute_progress {
+        use super::*;
+
+        #[test]
+        fn compute_rate_and_estimated_time_to_tip() {
+            let progress = TransactionsImportProgress::compute(
+                BlockNumber(100),
+                BlockNumber(300),
+                Duration::from_secs(10),
+                Some(BlockNumber(1300)),
+            );
+
+            assert_eq!(
+                TransactionsImportProgress {
+                    imported_block_number: BlockNumber(300),
+                    tip_block_number: Some(BlockNumber(1300)),
+                    blocks_per_second: 20.0,
+                    estimated_time_to_tip: Some(Duration::from_secs(50)),
+                },
+                progress
+            );
+        }
+
+        #[test]
+        fn no_estimated_time_to_tip_without_tip() {
+            let progress = TransactionsImportProgress::compute(
+                BlockNumber(100),
+                BlockNumber(300),
+                Duration::from_secs(10),
+                None,
+            );
+
+            assert_eq!(None, progress.estimated_time_to_tip);
+        }
+
+        #[test]
+        fn no_estimated_time_to_tip_when_nothing_was_imported() {
+            let progress = TransactionsImportProgress::compute(
+                BlockNumber(300),
+                BlockNumber(300),
+                Duration::from_secs(10),
+                Some(BlockNumber(1300)),
+            );
+
+            assert_eq!(0.0, progress.blocks_per_second);
+            assert_eq!(None, progress.estimated_time_to_tip);
+        }
+
+        #[test]
+        fn estimated_time_to_tip_is_zero_when_tip_is_reached() {
+            let progress = TransactionsImportProgress::compute(
+                BlockNumber(100),
+                BlockNumber(300),
+                Duration::from_secs(10),
+                Some(BlockNumber(300)),
+            );
+
+            assert_eq!(Some(Duration::ZERO), progress.estimated_time_to_tip);
+        }
+    }
+
+    #[tokio::test]
+    async fn record_progress_after_import() {
+        let mut highest_block_number_getter = MockHighestTransactionBlockNumberGetter::new();
+        let mut seq = mockall::Sequence::new();
+        highest_block_number_getter
+            .expect_get()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|| Ok(Some(BlockNumber(100))));
+        highest_block_number_getter
+            .expect_get()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|| Ok(Some(BlockNumber(300))));
+        let chain_observer = FakeChainObserver::new(Some(TimePoint {
+            chain_point: ChainPoint::new(SlotNumber(10_000), BlockNumber(1000), "tip_hash"),
+            ..TimePoint::dummy()
+        }));
+        let mut wrapped_importer = MockTransactionImporterImpl::new();
+        wrapped_importer.expect_import().once().returning(|_| Ok(()));
+        let progress_recorder = Arc::new(CollectingProgressRecorder::default());
+
+        let importer = TransactionsImporterWithProgress::new(
+            Arc::new(highest_block_number_getter),
+            Arc::new(chain_observer),
+            Arc::new(wrapped_importer),
+            TestLogger::stdout(),
+        )
+        .with_progress_recorder(progress_recorder.clone());
+
+        importer.import(BlockNumber(300)).await.unwrap();
+
+        let recorded = progress_recorder.recorded.lock().unwrap();
+        assert_eq!(1, recorded.len());
+        assert_eq!(BlockNumber(300), recorded[0].imported_block_number);
+        assert_eq!(Some(BlockNumber(1000)), recorded[0].tip_block_number);
+    }
+
+    #[tokio::test]
+    async fn does_not_record_progress_if_import_fails() {
+        let mut highest_block_number_getter = MockHighestTransactionBlockNumberGetter::new();
+        highest_block_number_getter
+            .expect_get()
+            .returning(|| Ok(Some(BlockNumber(100))));
+        let mut wrapped_importer = MockTransactionImporterImpl::new();
+        wrapped_importer
+            .expect_import()
+            .once()
+            .returning(|_| Err(anyhow::anyhow!("import failed")));
+        let progress_recorder = Arc::new(CollectingProgressRecorder::default());
+
+        let importer = TransactionsImporterWithProgress::new(
+            Arc::new(highest_block_number_getter),
+            Arc::new(FakeChainObserver::default()),
+            Arc::new(wrapped_importer),
+            TestLogger::stdout(),
+        )
+        .with_progress_recorder(progress_recorder.clone());
+
+        importer
+            .import(BlockNumber(300))
+            .await
+            .expect_err("Import should fail");
+
+        assert!(progress_recorder.recorded.lock().unwrap().is_empty());
+    }
+}
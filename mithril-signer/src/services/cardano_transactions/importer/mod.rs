@@ -1,9 +1,15 @@
 mod importer_by_chunk;
+mod importer_with_bootstrap;
+mod importer_with_checkpoint;
+mod importer_with_progress;
 mod importer_with_pruner;
 mod importer_with_vacuum;
 mod service;
 
 pub use importer_by_chunk::*;
+pub use importer_with_bootstrap::*;
+pub use importer_with_checkpoint::*;
+pub use importer_with_progress::*;
 pub use importer_with_pruner::*;
 pub use importer_with_vacuum::*;
 pub use service::*;
@@ -4,19 +4,24 @@ use std::sync::Arc;
 
 use anyhow::Context;
 use async_trait::async_trait;
-use slog::{Logger, debug};
+use slog::{Logger, debug, warn};
 use tokio::{runtime::Handle, sync::Mutex, task};
 
 use mithril_cardano_node_chain::chain_scanner::{BlockScanner, ChainScannedBlocks};
 use mithril_cardano_node_chain::entities::RawCardanoPoint;
 use mithril_common::StdResult;
-use mithril_common::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
+use mithril_common::crypto_helper::MKTreeNode;
 use mithril_common::entities::{
-    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber,
+    BlockNumber, BlockRange, CardanoTransaction, CardanoTransactionsLeafFormat, ChainPoint,
+    SlotNumber,
 };
 use mithril_common::logging::LoggerExtensions;
 use mithril_common::signable_builder::TransactionsImporter;
 
+/// Maximum number of block ranges whose Merkle roots are computed in parallel, bounding the
+/// number of transactions held in memory while computing them
+const BLOCK_RANGE_ROOTS_BATCH_SIZE: usize = 100;
+
 /// Cardano transactions store
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
@@ -58,6 +63,7 @@ pub struct CardanoTransactionsImporter {
     block_scanner: Arc<dyn BlockScanner>,
     transaction_store: Arc<dyn TransactionStore>,
     last_polled_point: Arc<Mutex<Option<RawCardanoPoint>>>,
+    leaf_format: CardanoTransactionsLeafFormat,
     logger: Logger,
 }
 
@@ -72,10 +78,17 @@ impl CardanoTransactionsImporter {
             block_scanner,
             transaction_store,
             last_polled_point: Arc::new(Mutex::new(None)),
+            leaf_format: CardanoTransactionsLeafFormat::default(),
             logger: logger.new_with_component_name::<Self>(),
         }
     }
 
+    /// Set the format of the leaves of the block ranges Merkle trees
+    pub fn with_leaf_format(mut self, leaf_format: CardanoTransactionsLeafFormat) -> Self {
+        self.leaf_format = leaf_format;
+        self
+    }
+
     async fn start_point(
         &self,
         highest_stored_chain_point: &Option<ChainPoint>,
@@ -91,8 +104,34 @@ impl CardanoTransactionsImporter {
         Ok(last_polled_point.or(highest_stored_chain_point.as_ref().map(RawCardanoPoint::from)))
     }
 
+    /// Highest block number the chain can continue with when the import resumes from the highest
+    /// stored chain point.
+    ///
+    /// This chain point may be set by a bootstrap that does not certify it: when it is in the
+    /// stored block ranges, the first block imported after it must be in them too, otherwise the
+    /// blocks of the following block ranges would be skipped.
+    async fn max_first_block_number(
+        &self,
+        highest_stored_chain_point: &Option<ChainPoint>,
+    ) -> StdResult<Option<BlockNumber>> {
+        if self.last_polled_point.lock().await.is_some() {
+            return Ok(None);
+        }
+
+        match highest_stored_chain_point {
+            Some(chain_point) => Ok(self
+                .transaction_store
+                .get_highest_block_range()
+                .await?
+                .map(|block_range| block_range.end)
+                .filter(|end| chain_point.block_number < *end)),
+            None => Ok(None),
+        }
+    }
+
     async fn import_transactions(&self, up_to_beacon: BlockNumber) -> StdResult<()> {
         let highest_stored_beacon = self.transaction_store.get_highest_beacon().await?;
+        let max_first_block_number = self.max_first_block_number(&highest_stored_beacon).await?;
         let from = self.start_point(&highest_stored_beacon).await?;
 
         if highest_stored_beacon
@@ -112,21 +151,46 @@ impl CardanoTransactionsImporter {
                 "highest_stored_block_number" => ?highest_stored_beacon.as_ref().map(|c| c.block_number),
             );
 
-            self.parse_and_store_transactions_not_imported_yet(from, up_to_beacon)
-                .await
+            self.parse_and_store_transactions_not_imported_yet(
+                from,
+                max_first_block_number,
+                up_to_beacon,
+            )
+            .await
         }
     }
 
     async fn parse_and_store_transactions_not_imported_yet(
         &self,
         from: Option<RawCardanoPoint>,
+        mut max_first_block_number: Option<BlockNumber>,
         until: BlockNumber,
     ) -> StdResult<()> {
         let mut streamer = self.block_scanner.scan(from, until).await?;
 
         while let Some(blocks) = streamer.poll_next().await? {
+            let max_block_number = max_first_block_number.take();
             match blocks {
                 ChainScannedBlocks::RollForwards(forward_blocks) => {
+                    if max_block_number.is_some_and(|max_block_number| {
+                        forward_blocks
+                            .first()
+                            .is_some_and(|block| block.block_number > max_block_number)
+                    }) {
+                        warn!(
+                            self.logger,
+                            "The chain does not continue from the highest stored chain point, importing it again from its origin";
+                            "first_block_number" => ?forward_blocks.first().map(|b| b.block_number),
+                            "max_block_number" => ?max_block_number,
+                        );
+                        // Discard the stored data as for a rollback to the origin of the chain
+                        self.transaction_store
+                            .remove_rolled_back_transactions_and_block_range(SlotNumber(0))
+                            .await?;
+                        streamer = self.block_scanner.scan(None, until).await?;
+                        continue;
+                    }
+
                     let parsed_transactions: Vec<CardanoTransaction> = forward_blocks
                         .into_iter()
                         .flat_map(|b| b.into_transactions())
@@ -134,7 +198,11 @@ impl CardanoTransactionsImporter {
 
                     self.transaction_store.store_transactions(parsed_transactions).await?;
                 }
-                ChainScannedBlocks::RollBackward(slot_number) => {
+                ChainScannedBlocks::RollBackward { slot_number, depth } => {
+                    debug!(
+                        self.logger, "Rolling back Cardano transactions";
+                        "slot_number" => ?slot_number, "depth" => ?depth,
+                    );
                     self.transaction_store
                         .remove_rolled_back_transactions_and_block_range(slot_number)
                         .await?;
@@ -169,7 +237,7 @@ impl CardanoTransactionsImporter {
             "start_block" => *block_ranges.start(), "end_block" => *block_ranges.end(),
         );
 
-        let mut block_ranges_with_merkle_root: Vec<(BlockRange, MKTreeNode)> = vec![];
+        let mut block_ranges_transactions: Vec<(BlockRange, Vec<CardanoTransaction>)> = vec![];
         for block_range in block_ranges {
             let transactions = self
                 .transaction_store
@@ -180,18 +248,29 @@ impl CardanoTransactionsImporter {
                 continue;
             }
 
-            let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&transactions)?.compute_root()?;
-            block_ranges_with_merkle_root.push((block_range, merkle_root));
+            block_ranges_transactions.push((block_range, transactions));
 
-            if block_ranges_with_merkle_root.len() >= 100 {
-                let block_ranges_with_merkle_root_save =
-                    mem::take(&mut block_ranges_with_merkle_root);
-                self.transaction_store
-                    .store_block_range_roots(block_ranges_with_merkle_root_save)
+            if block_ranges_transactions.len() >= BLOCK_RANGE_ROOTS_BATCH_SIZE {
+                let block_ranges_transactions_batch = mem::take(&mut block_ranges_transactions);
+                self.compute_and_store_block_range_roots(block_ranges_transactions_batch)
                     .await?;
             }
         }
 
+        self.compute_and_store_block_range_roots(block_ranges_transactions)
+            .await
+    }
+
+    /// Compute in parallel the Merkle roots of a batch of block ranges, then store them in the
+    /// order of their block ranges.
+    async fn compute_and_store_block_range_roots(
+        &self,
+        block_ranges_transactions: Vec<(BlockRange, Vec<CardanoTransaction>)>,
+    ) -> StdResult<()> {
+        let block_ranges_with_merkle_root = self
+            .leaf_format
+            .compute_block_range_roots(block_ranges_transactions)?;
+
         self.transaction_store
             .store_block_range_roots(block_ranges_with_merkle_root)
             .await
@@ -224,7 +303,7 @@ mod tests {
     use mithril_cardano_node_chain::chain_scanner::BlockStreamer;
     use mithril_cardano_node_chain::entities::ScannedBlock;
     use mithril_cardano_node_chain::test::double::{DumbBlockScanner, DumbBlockStreamer};
-    use mithril_common::crypto_helper::MKTree;
+    use mithril_common::crypto_helper::{MKTree, MKTreeStoreInMemory};
     use mithril_common::entities::{BlockNumber, BlockRangesSequence};
     use mithril_persistence::database::repository::CardanoTransactionRepository;
     use mithril_persistence::sqlite::SqliteConnectionPool;
@@ -737,6 +816,49 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn compute_block_range_merkle_root_with_block_inclusion_leaves() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        )));
+        let up_to_block_number = BlockRange::LENGTH;
+        let blocks = build_blocks(BlockNumber(0), up_to_block_number);
+        let transactions = into_transactions(&blocks);
+        let block_inclusion_leaves: Vec<MKTreeNode> = transactions
+            .iter()
+            .map(|transaction| {
+                CardanoTransactionsLeafFormat::BlockInclusion.compute_leaf(transaction)
+            })
+            .collect();
+        let expected_merkle_root = MKTree::<MKTreeStoreInMemory>::new(&block_inclusion_leaves)
+            .unwrap()
+            .compute_root()
+            .unwrap();
+        repository.store_transactions(transactions).await.unwrap();
+
+        let importer = CardanoTransactionsImporter::new_for_test(
+            Arc::new(MockBlockScannerImpl::new()),
+            repository.clone(),
+        )
+        .with_leaf_format(CardanoTransactionsLeafFormat::BlockInclusion);
+
+        importer
+            .import_block_ranges(up_to_block_number)
+            .await
+            .expect("Transactions Importer should succeed");
+
+        let block_range_roots = repository.get_all_block_range_root().unwrap();
+        assert_eq!(
+            vec![(
+                BlockRange::from_block_number(BlockNumber(0)),
+                expected_merkle_root.clone()
+            )],
+            block_range_roots.into_iter().map(|br| br.into()).collect::<Vec<_>>()
+        );
+        assert_ne!(merkle_root_for_blocks(&blocks), expected_merkle_root);
+    }
+
     #[tokio::test]
     async fn importing_twice_starting_with_nothing_in_a_real_db_should_yield_transactions_in_same_order()
      {
@@ -947,6 +1069,107 @@ mod tests {
                 start_point_after_import
             );
         }
+
+        async fn repository_with_a_stored_block_range_and_a_transaction_in_it()
+        -> Arc<CardanoTransactionRepository> {
+            let connection = cardano_tx_db_connection().unwrap();
+            let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+                SqliteConnectionPool::build_from_connection(connection),
+            )));
+            repository
+                .store_block_range_roots(vec![(
+                    BlockRange::from_block_number(BlockNumber(0)),
+                    MKTreeNode::from_hex("AAAA").unwrap(),
+                )])
+                .await
+                .unwrap();
+            repository
+                .store_transactions(into_transactions(&build_blocks(
+                    BlockNumber(10),
+                    BlockNumber(1),
+                )))
+                .await
+                .unwrap();
+
+            repository
+        }
+
+        #[tokio::test]
+        async fn resume_from_the_highest_stored_chain_point_if_the_chain_continues_in_the_stored_block_ranges()
+         {
+            let repository = repository_with_a_stored_block_range_and_a_transaction_in_it().await;
+            let highest_stored_chain_point = repository.get_highest_beacon().await.unwrap();
+            let blocks_after_stored_chain_point = build_blocks(BlockNumber(11), BlockNumber(10));
+            let importer = {
+                let scanned_blocks = blocks_after_stored_chain_point.clone();
+                let mut scanner_mock = MockBlockScannerImpl::new();
+                scanner_mock
+                    .expect_scan()
+                    .withf(move |from, _| {
+                        from == &highest_stored_chain_point.as_ref().map(RawCardanoPoint::from)
+                    })
+                    .return_once(move |_, _| {
+                        Ok(Box::new(
+                            DumbBlockStreamer::new().forwards(vec![scanned_blocks]),
+                        ))
+                    })
+                    .once();
+                CardanoTransactionsImporter::new_for_test(
+                    Arc::new(scanner_mock),
+                    repository.clone(),
+                )
+            };
+
+            importer.import_transactions(BlockNumber(20)).await.unwrap();
+
+            let stored_transactions = repository.get_all().await.unwrap();
+            assert_eq!(
+                [
+                    into_transactions(&build_blocks(BlockNumber(10), BlockNumber(1))),
+                    into_transactions(&blocks_after_stored_chain_point)
+                ]
+                .concat(),
+                stored_transactions
+            );
+        }
+
+        #[tokio::test]
+        async fn import_the_chain_again_from_origin_if_it_does_not_continue_in_the_stored_block_ranges_from_the_highest_stored_chain_point()
+         {
+            let repository = repository_with_a_stored_block_range_and_a_transaction_in_it().await;
+            let blocks_from_origin = build_blocks(BlockNumber(0), BlockNumber(21));
+            let importer = {
+                let mut scanner_mock = MockBlockScannerImpl::new();
+                scanner_mock
+                    .expect_scan()
+                    .withf(|from, _| from.is_some())
+                    .return_once(|_, _| {
+                        Ok(Box::new(DumbBlockStreamer::new().forwards(vec![
+                            build_blocks(BlockNumber(16), BlockNumber(5)),
+                        ])))
+                    })
+                    .once();
+                let scanned_blocks = blocks_from_origin.clone();
+                scanner_mock
+                    .expect_scan()
+                    .withf(|from, _| from.is_none())
+                    .return_once(move |_, _| {
+                        Ok(Box::new(
+                            DumbBlockStreamer::new().forwards(vec![scanned_blocks]),
+                        ))
+                    })
+                    .once();
+                CardanoTransactionsImporter::new_for_test(
+                    Arc::new(scanner_mock),
+                    repository.clone(),
+                )
+            };
+
+            importer.import_transactions(BlockNumber(20)).await.unwrap();
+
+            let stored_transactions = repository.get_all().await.unwrap();
+            assert_eq!(into_transactions(&blocks_from_origin), stored_transactions);
+        }
     }
 
     #[tokio::test]
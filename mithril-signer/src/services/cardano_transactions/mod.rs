@@ -1,5 +1,7 @@
+mod bootstrapper;
 mod importer;
 mod preloader_checker;
 
+pub use bootstrapper::*;
 pub use importer::*;
 pub use preloader_checker::*;
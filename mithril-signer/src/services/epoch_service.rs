@@ -288,12 +288,26 @@ impl EpochService for MithrilEpochService {
 /// direct usage of implemented traits methods difficult.
 pub struct SignerSignedEntityConfigProvider {
     epoch_service: EpochServiceWrapper,
+    unsupported_discriminants: BTreeSet<SignedEntityTypeDiscriminants>,
 }
 
 impl SignerSignedEntityConfigProvider {
     /// Create a new instance of the `SignerSignedEntityConfigProvider`.
     pub fn new(epoch_service: EpochServiceWrapper) -> Self {
-        Self { epoch_service }
+        Self {
+            epoch_service,
+            unsupported_discriminants: BTreeSet::new(),
+        }
+    }
+
+    /// Set the signed entity types that the signer can't sign, they are removed from the
+    /// allowed signed entity types sent by the aggregator.
+    pub fn with_unsupported_discriminants(
+        mut self,
+        unsupported_discriminants: BTreeSet<SignedEntityTypeDiscriminants>,
+    ) -> Self {
+        self.unsupported_discriminants = unsupported_discriminants;
+        self
     }
 }
 
@@ -308,7 +322,11 @@ impl SignedEntityConfigProvider for SignerSignedEntityConfigProvider {
             }?;
 
         Ok(SignedEntityConfig {
-            allowed_discriminants: epoch_service.allowed_discriminants()?.clone(),
+            allowed_discriminants: epoch_service
+                .allowed_discriminants()?
+                .difference(&self.unsupported_discriminants)
+                .cloned()
+                .collect(),
             cardano_transactions_signing_config,
         })
     }
@@ -851,9 +869,7 @@ mod tests {
             protocol_initializer_store,
             TestLogger::stdout(),
         )));
-        let config_provider = SignerSignedEntityConfigProvider {
-            epoch_service: epoch_service.clone(),
-        };
+        let config_provider = SignerSignedEntityConfigProvider::new(epoch_service.clone());
 
         // Fail before the first `inform_epoch_settings`
         {
@@ -914,4 +930,39 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn signed_entity_config_does_not_allow_unsupported_discriminants() {
+        let epoch_service = Arc::new(RwLock::new(
+            MithrilEpochService::new_with_dumb_dependencies(),
+        ));
+        epoch_service
+            .write()
+            .await
+            .inform_epoch_settings(
+                SignerEpochSettings {
+                    cardano_transactions_signing_config: Some(
+                        CardanoTransactionsSigningConfig::dummy(),
+                    ),
+                    ..SignerEpochSettings::dummy()
+                },
+                BTreeSet::from([
+                    SignedEntityTypeDiscriminants::CardanoTransactions,
+                    SignedEntityTypeDiscriminants::CardanoTransactionsMetadata,
+                ]),
+            )
+            .await
+            .unwrap();
+        let config_provider = SignerSignedEntityConfigProvider::new(epoch_service)
+            .with_unsupported_discriminants(BTreeSet::from([
+                SignedEntityTypeDiscriminants::CardanoTransactionsMetadata,
+            ]));
+
+        let config = config_provider.get().await.unwrap();
+
+        assert_eq!(
+            BTreeSet::from([SignedEntityTypeDiscriminants::CardanoTransactions]),
+            config.allowed_discriminants
+        );
+    }
 }
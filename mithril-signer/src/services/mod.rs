@@ -4,13 +4,17 @@
 //!
 //! * Aggregator Client: communicate with the Aggregator
 //! * Cardano Transactions: handle Cardano transactions (import, preload, etc.)
+//! * Cardano Blocks: import Cardano blocks
+//! * Cardano Transactions Metadata: import Cardano transactions metadata
 //! * Single Signer: create single signatures
 //! * Upkeep: perform maintenance tasks
 //!
 //! Each service is defined by a public API (a trait) that is used in the controllers (runtimes).
 
 mod aggregator_client;
+mod cardano_blocks;
 mod cardano_transactions;
+mod cardano_transactions_metadata;
 mod certifier;
 mod epoch_service;
 mod signable_builder;
@@ -21,7 +25,9 @@ mod upkeep_service;
 #[cfg(test)]
 pub(crate) use aggregator_client::dumb::DumbAggregatorClient;
 pub use aggregator_client::*;
+pub use cardano_blocks::*;
 pub use cardano_transactions::*;
+pub use cardano_transactions_metadata::*;
 pub use certifier::*;
 pub use epoch_service::*;
 pub use signable_builder::*;
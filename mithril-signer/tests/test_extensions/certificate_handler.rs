@@ -9,7 +9,10 @@ use mithril_common::{
         CardanoTransactionsSigningConfig, Epoch, ProtocolMessage, SignedEntityConfig,
         SignedEntityType, SignedEntityTypeDiscriminants, Signer, SingleSignature, TimePoint,
     },
-    messages::AggregatorFeaturesMessage,
+    messages::{
+        AggregatorFeaturesMessage, CardanoTransactionSnapshotListItemMessage,
+        CardanoTransactionsBlockRangeRootsMessage, CertificateMessage,
+    },
     test_utils::{double::Dummy, fake_data},
 };
 use mithril_ticker::{MithrilTickerService, TickerService};
@@ -162,6 +165,26 @@ impl AggregatorClient for FakeAggregator {
 
         Ok(message)
     }
+
+    async fn retrieve_latest_cardano_transactions_snapshot(
+        &self,
+    ) -> Result<Option<CardanoTransactionSnapshotListItemMessage>, AggregatorClientError> {
+        Ok(None)
+    }
+
+    async fn retrieve_cardano_transactions_block_range_roots(
+        &self,
+        _snapshot_hash: &str,
+    ) -> Result<Option<CardanoTransactionsBlockRangeRootsMessage>, AggregatorClientError> {
+        Ok(None)
+    }
+
+    async fn retrieve_certificate(
+        &self,
+        _certificate_hash: &str,
+    ) -> Result<Option<CertificateMessage>, AggregatorClientError> {
+        Ok(None)
+    }
 }
 
 #[cfg(test)]
@@ -35,7 +35,8 @@ use mithril_common::{
         TimePoint,
     },
     signable_builder::{
-        CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
+        CardanoBlocksSignableBuilder, CardanoStakeDistributionSignableBuilder,
+        CardanoTransactionsMetadataSignableBuilder, CardanoTransactionsSignableBuilder,
         MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
         SignableBuilderServiceDependencies,
     },
@@ -43,7 +44,10 @@ use mithril_common::{
 };
 use mithril_era::{EraChecker, EraMarker, EraReader, adapters::EraReaderDummyAdapter};
 use mithril_persistence::{
-    database::repository::CardanoTransactionRepository, sqlite::SqliteConnectionPool,
+    database::repository::{
+        CardanoBlockRepository, CardanoTransactionMetadataRepository, CardanoTransactionRepository,
+    },
+    sqlite::SqliteConnectionPool,
     store::StakeStorer,
 };
 use mithril_signed_entity_lock::SignedEntityTypeLock;
@@ -57,7 +61,8 @@ use mithril_signer::{
     database::repository::{ProtocolInitializerRepository, SignedBeaconRepository, StakePoolStore},
     dependency_injection::{DependenciesBuilder, SignerDependencyContainer},
     services::{
-        AggregatorClient, CardanoTransactionsImporter, MithrilEpochService, MithrilSingleSigner,
+        AggregatorClient, CardanoBlocksImporter, CardanoTransactionsImporter,
+        CardanoTransactionsMetadataImporter, MithrilEpochService, MithrilSingleSigner,
         SignerCertifierService, SignerSignableSeedBuilder, SignerSignedEntityConfigProvider,
         SignerUpkeepService,
     },
@@ -248,12 +253,39 @@ impl StateMachineTester {
             epoch_service.clone(),
             protocol_initializer_store.clone(),
         ));
+        let block_store = Arc::new(CardanoBlockRepository::new(
+            sqlite_connection_cardano_transaction_pool.clone(),
+        ));
+        let blocks_importer = Arc::new(CardanoBlocksImporter::new(
+            Arc::new(DumbBlockScanner::new()),
+            block_store.clone(),
+            logger.clone(),
+        ));
+        let cardano_blocks_builder = Arc::new(
+            CardanoBlocksSignableBuilder::<MKTreeStoreSqlite>::new(blocks_importer, block_store),
+        );
+        let transaction_metadata_store = Arc::new(CardanoTransactionMetadataRepository::new(
+            sqlite_connection_cardano_transaction_pool.clone(),
+        ));
+        let transactions_metadata_importer = Arc::new(CardanoTransactionsMetadataImporter::new(
+            Arc::new(DumbBlockScanner::new()),
+            transaction_metadata_store.clone(),
+            logger.clone(),
+        ));
+        let cardano_transactions_metadata_builder =
+            Arc::new(CardanoTransactionsMetadataSignableBuilder::<
+                MKTreeStoreSqlite,
+            >::new(
+                transactions_metadata_importer, transaction_metadata_store
+            ));
         let signable_builders_dependencies = SignableBuilderServiceDependencies::new(
             mithril_stake_distribution_signable_builder,
             cardano_immutable_snapshot_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
+            cardano_transactions_metadata_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
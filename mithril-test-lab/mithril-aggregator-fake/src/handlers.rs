@@ -3,7 +3,7 @@
 
 use axum::{
     Router,
-    body::Body,
+    body::{Body, Bytes},
     extract::{Path, Query, Request, State},
     http::{HeaderValue, Response, StatusCode},
     middleware::{Next, from_fn},
@@ -63,7 +63,10 @@ pub async fn aggregator_router() -> Router<SharedState> {
             "/artifact/cardano-database/{hash}",
             get(cardano_database_snapshot),
         )
-        .route("/proof/cardano-transaction", get(cardano_transaction_proof))
+        .route(
+            "/proof/cardano-transaction",
+            get(cardano_transaction_proof).post(post_cardano_transaction_proof),
+        )
         .route("/certificates", get(certificates))
         .route("/certificate/{hash}", get(certificate))
         .route("/statistics/snapshot", post(statistics))
@@ -318,6 +321,32 @@ pub async fn cardano_transaction_proof(
         })
 }
 
+#[derive(serde::Deserialize)]
+pub struct CardanoTransactionProofRequestBody {
+    transaction_hashes: Vec<String>,
+}
+
+/// HTTP: return a cardano transaction proof identified by the transaction hashes of the body.
+pub async fn post_cardano_transaction_proof(
+    State(state): State<SharedState>,
+    body: Bytes,
+) -> Result<Response<Body>, AppError> {
+    // The body is parsed manually as the clients do not always send a JSON content type header
+    let body: CardanoTransactionProofRequestBody =
+        serde_json::from_slice(&body).map_err(|e| AppError::Internal(e.into()))?;
+    let app_state = state.read().await;
+    let transaction_hashes = body.transaction_hashes.join(",");
+
+    app_state
+        .get_cardano_transaction_proofs(&transaction_hashes)
+        .await?
+        .map(|s| s.into_response())
+        .ok_or_else(|| {
+            debug!("cardano transaction proof ctx_hash={transaction_hashes} NOT FOUND.");
+            AppError::NotFound
+        })
+}
+
 /// HTTP: return OK when the client registers download statistics
 pub async fn statistics() -> Result<Response<Body>, AppError> {
     let response = Response::builder().status(StatusCode::CREATED);
@@ -490,6 +519,33 @@ mod tests {
         assert_eq!(StatusCode::OK, response.status());
     }
 
+    #[tokio::test]
+    async fn invalid_cardano_transaction_proof_hash_in_body() {
+        let state: State<SharedState> = State(AppState::default().into());
+        let body = serde_json::json!({ "transaction_hashes": ["whatever"] }).to_string();
+
+        let error = post_cardano_transaction_proof(state, Bytes::from(body))
+        .await
+        .expect_err("The handler was expected to fail since the cardano transaction proof's hash does not exist.");
+
+        assert!(matches!(error, AppError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn existing_cardano_transaction_proof_hash_in_body() {
+        let state: State<SharedState> = State(AppState::default().into());
+        let body = serde_json::json!({
+            "transaction_hashes": [default_values::proof_transaction_hashes()[0]]
+        })
+        .to_string();
+
+        let response = post_cardano_transaction_proof(state, Bytes::from(body))
+        .await
+        .expect("The handler was expected to succeed since the cardano transaction proof's hash does exist.");
+
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
     #[tokio::test]
     async fn existing_cardano_stake_distribution_hash() {
         let state: State<SharedState> = State(AppState::default().into());